@@ -1,8 +1,16 @@
+import java.io.ByteArrayOutputStream;
+import java.io.ObjectOutputStream;
 import java.util.Random;
 
 class GenerateTestData {
-	public static void main(String[] args) {
-		System.out.println("// Rust file generated by Java");
+	public static void main(String[] args) throws Exception {
+		System.out.println("//! Golden values computed by the Java implementations, generated by GenerateTestData.java.");
+		System.out.println("//!");
+		System.out.println("//! Every table of draws from a seeded generator comes with a `_SEED` constant holding the seed, unless the seed is");
+		System.out.println("//! part of the rows. Floating point values are stored as their IEEE 754 bit patterns, as returned by `f32::to_bits`");
+		System.out.println("//! and `f64::to_bits`, so that they can be compared exactly. Tables are only added to and never change, except");
+		System.out.println("//! to fix values that did not match Java.");
+		System.out.println();
 		
 		// nextBytes test
 		{
@@ -172,5 +180,3171 @@ class GenerateTestData {
 			
 			System.out.println("\n];");
 		}
+		
+		// Serialization test
+		{
+			Random rand = new Random(1107);
+			rand.nextGaussian();
+			
+			ByteArrayOutputStream buffer = new ByteArrayOutputStream();
+			ObjectOutputStream stream = new ObjectOutputStream(buffer);
+			stream.writeObject(rand);
+			stream.close();
+			
+			byte[] bytes = buffer.toByteArray();
+			
+			System.out.println("pub const RAND_SERIALIZED_SEED: u64 = 1107;");
+			System.out.println("// State after a single nextGaussian call, with the second gaussian pending");
+			System.out.print("pub const RAND_SERIALIZED: [u8; " + bytes.length + "] = [");
+			
+			for(int i = 0; i < bytes.length; i++) {
+				if(i % 8 == 0) {
+					System.out.print("\n\t");
+				}
+			
+				System.out.printf("0x%02X, ", bytes[i]);
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// StrictMath tests, over random finite bit patterns followed by a function-specific range
+		printStrictMath("LOG", 1230, StrictMath::log, rand -> rand.nextDouble());
+		printStrictMath("SQRT", 1353, StrictMath::sqrt, rand -> rand.nextDouble());
+		printStrictMath("SIN", 1476, StrictMath::sin, rand -> (rand.nextDouble() - 0.5) * Math.scalb(1.0, rand.nextInt(40)));
+		printStrictMath("COS", 1599, StrictMath::cos, rand -> (rand.nextDouble() - 0.5) * Math.scalb(1.0, rand.nextInt(40)));
+		printStrictMath("EXP", 1722, StrictMath::exp, rand -> (rand.nextDouble() - 0.5) * Math.scalb(1.0, rand.nextInt(12)));
+		printStrictMath("ATAN", 1845, StrictMath::atan, rand -> (rand.nextDouble() - 0.5) * Math.scalb(1.0, rand.nextInt(12)));
+		
+		printStrictMath2("ATAN2", 1968, StrictMath::atan2,
+			rand -> rand.nextDouble() - 0.5,
+			rand -> rand.nextDouble() - 0.5);
+		printStrictMath2("POW", 2091, StrictMath::pow,
+			rand -> rand.nextDouble() * Math.scalb(1.0, rand.nextInt(8) - 4),
+			rand -> (rand.nextDouble() - 0.5) * 200);
+		printStrictMath2("POW_NEGATIVE", 2214, StrictMath::pow,
+			rand -> -rand.nextDouble() * 4,
+			rand -> (double) (rand.nextInt(200) - 100));
+		
+		// nextDouble(origin, bound) test, cycling through ranges prone to rounding up to the bound
+		{
+			double[][] ranges = {
+				{0.0, 1.0},
+				{-1.0, 1.0},
+				{1.0, Math.nextUp(1.0)},
+				{1.0, 2.0},
+				{-Double.MIN_VALUE, Double.MIN_VALUE},
+				{0.0, 3 * Double.MIN_VALUE},
+				{Double.MIN_NORMAL, 2 * Double.MIN_NORMAL},
+				{1e15, 1e15 + 1},
+				{-Double.MAX_VALUE / 2, Double.MAX_VALUE / 2},
+				{-1e300, 1e-300},
+				{Math.nextDown(Math.nextDown(1e10)), 1e10},
+				{123.456, 123.456000001}
+			};
+			
+			Random rand = new Random(2583);
+			System.out.println("pub const RAND_RANGE_F64_SEED: u64 = 2583;");
+			System.out.println("// (origin, bound, output) f64 bit representations");
+			System.out.print("pub const RAND_RANGE_F64: [(u64, u64, u64); 384] = [");
+			
+			for(int i = 0; i < 384; i++) {
+				double[] range = ranges[i % ranges.length];
+				
+				System.out.printf("\n\t(0x%016X, 0x%016X, 0x%016X),", Double.doubleToRawLongBits(range[0]), Double.doubleToRawLongBits(range[1]), Double.doubleToRawLongBits(rand.nextDouble(range[0], range[1])));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// nextDouble(bound) test
+		{
+			double[] bounds = {1.0, Double.MIN_VALUE, 3 * Double.MIN_VALUE, Double.MIN_NORMAL, 1e-300, 0.1, 1e300, Double.MAX_VALUE};
+			
+			Random rand = new Random(2706);
+			System.out.println("pub const RAND_BOUND_F64_SEED: u64 = 2706;");
+			System.out.println("// (bound, output) f64 bit representations");
+			System.out.print("pub const RAND_BOUND_F64: [(u64, u64); 256] = [");
+			
+			for(int i = 0; i < 256; i++) {
+				double bound = bounds[i % bounds.length];
+				
+				if(i % 2 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.printf("(0x%016X, 0x%016X), ", Double.doubleToRawLongBits(bound), Double.doubleToRawLongBits(rand.nextDouble(bound)));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Ziggurat tests, with the default RandomGenerator methods drawing from Random.nextLong
+		{
+			Random rand = new Random(2337);
+			java.util.random.RandomGenerator generator = rand::nextLong;
+			System.out.println("pub const ZIGGURAT_GAUSSIAN_SEED: u64 = 2337;");
+			System.out.print("pub const ZIGGURAT_GAUSSIAN: [u64; 2048] = [");
+			
+			for(int i = 0; i < 2048; i++) {
+				if(i % 4 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.printf("0x%016X, ", Double.doubleToRawLongBits(generator.nextGaussian()));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		{
+			Random rand = new Random(2460);
+			java.util.random.RandomGenerator generator = rand::nextLong;
+			System.out.println("pub const ZIGGURAT_EXPONENTIAL_SEED: u64 = 2460;");
+			System.out.print("pub const ZIGGURAT_EXPONENTIAL: [u64; 2048] = [");
+			
+			for(int i = 0; i < 2048; i++) {
+				if(i % 4 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.printf("0x%016X, ", Double.doubleToRawLongBits(generator.nextExponential()));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Xoroshiro128 variants, with JDK seeding and the default RandomGenerator methods on top of nextLong
+		{
+			Xoroshiro128 check = new Xoroshiro128(Xoroshiro128.PLUS_PLUS, 9001);
+			java.util.random.RandomGenerator jdk = java.util.random.RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(9001L);
+			
+			for(int i = 0; i < 1024; i++) {
+				if(check.nextLong() != jdk.nextLong()) {
+					throw new AssertionError("Xoroshiro128++ reference does not match the JDK");
+				}
+			}
+			
+			printXoroshiro("XOROSHIRO128PLUS", Xoroshiro128.PLUS, 2829);
+			printXoroshiro("XOROSHIRO128PLUSPLUS", Xoroshiro128.PLUS_PLUS, 2952);
+			printXoroshiro("XOROSHIRO128STARSTAR", Xoroshiro128.STAR_STAR, 3075);
+		}
+		
+		// Subclass overriding next with a Weyl sequence, through the derived methods and Collections.shuffle
+		{
+			Random rand = new Random() {
+				int counter = 0;
+				
+				@Override
+				protected int next(int bits) {
+					counter += 0x9E3779B9;
+					return counter >>> (32 - bits);
+				}
+			};
+			
+			System.out.print("pub const SUBCLASS_WEYL: [u64; 1024] = [");
+			
+			for(int i = 0; i < 128; i++) {
+				int bound = (i % 2 == 0) ? (1 << (i % 31)) : (i * 104729 + 1);
+				byte[] bytes = new byte[3];
+				rand.nextBytes(bytes);
+				
+				System.out.printf("\n\t0x%016X, 0x%016X, 0x%016X, 0x%016X,", (long) rand.nextInt(), (long) rand.nextInt(bound), rand.nextLong(), rand.nextBoolean() ? 1L : 0L);
+				System.out.printf("\n\t0x%016X, 0x%016X, 0x%016X, 0x%016X,", (long) Float.floatToRawIntBits(rand.nextFloat()), Double.doubleToRawLongBits(rand.nextDouble()), Double.doubleToRawLongBits(rand.nextGaussian()), ((bytes[0] & 0xFFL) | (bytes[1] & 0xFFL) << 8 | (bytes[2] & 0xFFL) << 16));
+			}
+			
+			System.out.println("\n];");
+			
+			java.util.List<Integer> list = new java.util.ArrayList<>();
+			
+			for(int i = 0; i < 52; i++) {
+				list.add(i);
+			}
+			
+			java.util.Collections.shuffle(list, rand);
+			System.out.println("pub const SUBCLASS_WEYL_SHUFFLE: [u8; 52] = " + list + ";");
+			
+			Random shuffled = new Random(3198);
+			list.sort(null);
+			java.util.Collections.shuffle(list, shuffled);
+			System.out.println("pub const RAND_SHUFFLE_SEED: u64 = 3198;");
+			System.out.println("pub const RAND_SHUFFLE: [u8; 52] = " + list + ";");
+		}
+		
+		// Minecraft population seeds for 1.12, 1.13 to 1.17 and 1.18 onwards, over chunk coordinates
+		{
+			Random coordinates = new Random(3321);
+			System.out.print("pub const MC_POPULATION_SEEDS: [(i64, i32, i32, i64, i64, i64); 64] = [");
+			
+			for(int i = 0; i < 64; i++) {
+				long worldSeed = coordinates.nextLong();
+				int chunkX = coordinates.nextInt(60000) - 30000;
+				int chunkZ = coordinates.nextInt(60000) - 30000;
+				
+				Random legacy = new Random(worldSeed);
+				long a = legacy.nextLong() / 2L * 2L + 1L;
+				long b = legacy.nextLong() / 2L * 2L + 1L;
+				long populate = (long) chunkX * a + (long) chunkZ * b ^ worldSeed;
+				
+				Random aquatic = new Random(worldSeed);
+				a = aquatic.nextLong() | 1L;
+				b = aquatic.nextLong() | 1L;
+				long decorate = (long) (chunkX * 16) * a + (long) (chunkZ * 16) * b ^ worldSeed;
+				
+				java.util.random.RandomGenerator xoroshiro = java.util.random.RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(worldSeed);
+				a = (((xoroshiro.nextLong() >>> 32) << 32) + (int) (xoroshiro.nextLong() >>> 32)) | 1L;
+				b = (((xoroshiro.nextLong() >>> 32) << 32) + (int) (xoroshiro.nextLong() >>> 32)) | 1L;
+				long decorateXoroshiro = (long) (chunkX * 16) * a + (long) (chunkZ * 16) * b ^ worldSeed;
+				
+				System.out.printf("\n\t(%d, %d, %d, %d, %d, %d),", worldSeed, chunkX, chunkZ, populate, decorate, decorateXoroshiro);
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Minecraft 1.20 random sequences: MD5 of the id xored into the unmixed world seed, then mixed
+		{
+			String[] ids = {"minecraft:chests/simple_dungeon", "minecraft:entities/zombie", "minecraft:gameplay/fishing", "", "custom:\u00fcn\u00efcode/path", "minecraft:a/rather/long/resource/location/that/needs/two/blocks"};
+			long[] seeds = {0L, 3444L, -4172144997902289642L};
+			
+			System.out.print("pub const MC_RANDOM_SEQUENCES: [(i64, &str, [i64; 4], [i64; 4]); 18] = [");
+			
+			for(long worldSeed : seeds) {
+				for(String id : ids) {
+					System.out.printf("\n\t(%d, \"%s\", [", worldSeed, rustString(id));
+					
+					java.util.random.RandomGenerator keyed = createSequence(worldSeed, id);
+					for(int i = 0; i < 4; i++) {
+						System.out.printf("%d, ", keyed.nextLong());
+					}
+					
+					System.out.print("], [");
+					
+					java.util.random.RandomGenerator unkeyed = createSequence(worldSeed, null);
+					for(int i = 0; i < 4; i++) {
+						System.out.printf("%d, ", unkeyed.nextLong());
+					}
+					
+					System.out.print("]),");
+				}
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// JDK mixing functions over random inputs, plus zero and the sparse inputs that mixGamma fixes up
+		{
+			Random rand = new Random(1640);
+			long[] inputs = new long[16];
+			inputs[1] = 1;
+			
+			for(int i = 2; i < inputs.length; i++) {
+				inputs[i] = rand.nextLong();
+			}
+			
+			System.out.println("pub const MIX_64: [[u64; 4]; " + inputs.length + "] = [");
+			
+			for(long input: inputs) {
+				System.out.printf("\t[0x%016X, 0x%016X, 0x%016X, 0x%016X],\n", input, mixMurmur64(input), mixStafford13(input), mixLea64(input));
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const MIX_32: [[u32; 3]; " + inputs.length + "] = [");
+			
+			for(long input: inputs) {
+				System.out.printf("\t[0x%08X, 0x%08X, 0x%08X],\n", (int) input, mixMurmur32((int) input), mixLea32((int) input));
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const MIX_GAMMA: [[u64; 2]; " + inputs.length + "] = [");
+			
+			for(long input: inputs) {
+				System.out.printf("\t[0x%016X, 0x%016X],\n", input, mixGamma(input));
+			}
+			
+			System.out.println("];");
+		}
+		
+		// SplittableRandom draws, then a split child and the parent after the split
+		{
+			java.util.SplittableRandom rand = new java.util.SplittableRandom(-90210);
+			
+			System.out.println("pub const SPLITMIX_SEED: i64 = -90210;");
+			System.out.print("pub const SPLITMIX_DRAWS: [u64; 20] = [");
+			
+			for(int i = 0; i < 20; i++) {
+				if(i % 4 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				long value;
+				
+				switch(i % 4) {
+					case 0: value = rand.nextLong(); break;
+					case 1: value = rand.nextInt() & 0xffffffffL; break;
+					case 2: value = Double.doubleToRawLongBits(rand.nextDouble()); break;
+					default: value = Float.floatToRawIntBits(rand.nextFloat()); break;
+				}
+				
+				System.out.printf("0x%016X, ", value);
+			}
+			
+			System.out.println("\n];");
+			
+			java.util.SplittableRandom child = rand.split();
+			System.out.print("pub const SPLITMIX_SPLIT: [[i64; 2]; 6] = [");
+			
+			for(int i = 0; i < 6; i++) {
+				System.out.print("\n\t[" + child.nextLong() + ", " + rand.nextLong() + "],");
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// nextBytes of SplittableRandom and Xoroshiro128PlusPlus, over lengths 0 through 17 in turn
+		{
+			java.util.SplittableRandom splittable = new java.util.SplittableRandom(4711);
+			java.util.random.RandomGenerator xoroshiro = java.util.random.RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(4711L);
+			java.io.ByteArrayOutputStream splittableBytes = new java.io.ByteArrayOutputStream();
+			java.io.ByteArrayOutputStream xoroshiroBytes = new java.io.ByteArrayOutputStream();
+			
+			for(int len = 0; len < 18; len++) {
+				byte[] bytes = new byte[len];
+				
+				splittable.nextBytes(bytes);
+				splittableBytes.write(bytes, 0, len);
+				
+				xoroshiro.nextBytes(bytes);
+				xoroshiroBytes.write(bytes, 0, len);
+			}
+			
+			System.out.println("pub const NEXT_BYTES_SEED: u64 = 4711;");
+			
+			for(String name: new String[] { "SPLITMIX_NEXT_BYTES", "XOROSHIRO128PLUSPLUS_NEXT_BYTES" }) {
+				byte[] bytes = (name.startsWith("SPLITMIX") ? splittableBytes : xoroshiroBytes).toByteArray();
+				
+				System.out.print("pub const " + name + ": [u8; " + bytes.length + "] = [");
+				
+				for(int i = 0; i < bytes.length; i++) {
+					if(i % 16 == 0) {
+						System.out.print("\n\t");
+					}
+					
+					System.out.printf("0x%02X, ", bytes[i]);
+				}
+				
+				System.out.println("\n];");
+			}
+		}
+		
+		// next(bits) for every width, through a subclass exposing the protected method
+		{
+			var rand = new Random(555) {
+				int bits(int bits) {
+					return next(bits);
+				}
+			};
+			
+			System.out.println("pub const RAND_NEXT_BITS_SEED: u64 = 555;");
+			System.out.print("pub const RAND_NEXT_BITS: [(u8, i32); 128] = [");
+			
+			for(int i = 0; i < 128; i++) {
+				if(i % 4 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				int bits = 1 + i % 32;
+				System.out.printf("(%d, %d), ", bits, rand.bits(bits));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// nextInt(bound) for every bound from 1 to 128, both powers of two and not
+		{
+			Random rand = new Random(666);
+			System.out.println("pub const RAND_NEXT32_BOUND_SMALL_SEED: u64 = 666;");
+			System.out.print("pub const RAND_NEXT32_BOUND_SMALL: [(i32, i32); 128] = [");
+			
+			for(int i = 0; i < 128; i++) {
+				if(i % 8 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.printf("(%d, %d), ", i + 1, rand.nextInt(i + 1));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// new BigInteger(numBits, rnd), one after another from the same generator
+		{
+			Random rand = new Random(777);
+			int[] widths = { 0, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 100, 127, 128, 129, 255, 1000 };
+			
+			System.out.println("pub const BIG_INTEGER_RANDOM_SEED: u64 = 777;");
+			System.out.print("pub const BIG_INTEGER_RANDOM: [(u32, &str); " + widths.length + "] = [");
+			
+			for(int bits: widths) {
+				System.out.printf("\n\t(%d, \"%s\"), ", bits, new java.math.BigInteger(bits, rand).toString(16));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// new BigInteger(bitLength, certainty, rnd), one after another from the same generator, covering both the
+		// small candidate loop and the sieve, with and without the Lucas test
+		{
+			Random rand = new Random(888);
+			int[][] cases = {
+				{ 2, 100 }, { 3, 100 }, { 4, 100 }, { 5, 1 }, { 6, 100 }, { 7, 100 }, { 8, 0 }, { 16, 100 }, { 31, 20 },
+				{ 32, 100 }, { 33, 100 }, { 64, 100 }, { 94, 100 }, { 95, 100 }, { 96, 1 }, { 99, 100 }, { 100, 100 },
+				{ 128, 100 }, { 160, 3 }, { 256, 100 }, { 300, 50 }
+			};
+			
+			System.out.println("pub const BIG_INTEGER_PRIMES_SEED: u64 = 888;");
+			System.out.print("pub const BIG_INTEGER_PRIMES: [(u32, i32, &str); " + cases.length + "] = [");
+			
+			for(int[] test: cases) {
+				String prime = new java.math.BigInteger(test[0], test[1], rand).toString(16);
+				System.out.printf("\n\t(%d, %d, \"%s\"), ", test[0], test[1], prime);
+			}
+			
+			System.out.println("\n];");
+			System.out.println("pub const BIG_INTEGER_PRIMES_NEXT: i32 = " + rand.nextInt() + ";");
+		}
+		
+		// Commons RNG JDK provider draws, with the saved state part way through the booleans
+		{
+			CommonsRngJdk provider = new CommonsRngJdk(-8361);
+			
+			System.out.println("pub const COMMONS_RNG_SEED: i64 = -8361;");
+			System.out.print("pub const COMMONS_RNG_DRAWS: [u64; 24] = [");
+			
+			for(int i = 0; i < 24; i++) {
+				if(i % 4 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				long value;
+				
+				switch(i % 4) {
+					case 0: value = provider.nextInt() & 0xffffffffL; break;
+					case 1: value = provider.nextLong(); break;
+					case 2: value = Float.floatToRawIntBits(provider.nextFloat()); break;
+					default: value = Double.doubleToRawLongBits(provider.nextDouble()); break;
+				}
+				
+				System.out.printf("0x%016X, ", value);
+			}
+			
+			System.out.println("\n];");
+			System.out.print("pub const COMMONS_RNG_BOOLEANS: [bool; 40] = [");
+			
+			for(int i = 0; i < 40; i++) {
+				if(i % 8 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.print(provider.nextBoolean() + ", ");
+			}
+			
+			System.out.println("\n];");
+			
+			byte[] state = provider.saveState();
+			System.out.print("pub const COMMONS_RNG_STATE: [u8; " + state.length + "] = [");
+			
+			for(int i = 0; i < state.length; i++) {
+				if(i % 8 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.printf("0x%02X, ", state[i]);
+			}
+			
+			System.out.println("\n];");
+			System.out.print("pub const COMMONS_RNG_AFTER_STATE: [bool; 32] = [");
+			
+			for(int i = 0; i < 32; i++) {
+				if(i % 8 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.print(provider.nextBoolean() + ", ");
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Commons Math RandomDataGenerator samplers on a JDKRandomGenerator, each followed by a draw checking the state
+		{
+			double[] means = { 0.25, 1.0, 7.5, 39.99, 40.0, 123.456, 5000.0, 2.5e6 };
+			double[][] gammas = { { 0.3, 1.0 }, { 0.9, 2.5 }, { 1.0, 1.0 }, { 2.5, 0.5 }, { 40.0, 3.0 } };
+			CommonsMath math = new CommonsMath(new Random(7151));
+			
+			System.out.println("pub const COMMONS_MATH_SEED: u64 = 7151;");
+			System.out.println("pub const COMMONS_MATH_POISSON: [(u64, [i64; 6]); " + means.length + "] = [");
+			
+			for(double mean: means) {
+				System.out.printf("\t(0x%016X, [", Double.doubleToRawLongBits(mean));
+				
+				for(int i = 0; i < 5; i++) {
+					System.out.print(math.poisson(mean) + ", ");
+				}
+				
+				System.out.println(math.random.nextLong() + "]),");
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const COMMONS_MATH_EXPONENTIAL: [(u64, [u64; 6]); " + means.length + "] = [");
+			
+			for(double mean: means) {
+				System.out.printf("\t(0x%016X, [", Double.doubleToRawLongBits(mean));
+				
+				for(int i = 0; i < 5; i++) {
+					System.out.printf("0x%016X, ", Double.doubleToRawLongBits(math.exponential(mean)));
+				}
+				
+				System.out.printf("0x%016X]),\n", math.random.nextLong());
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const COMMONS_MATH_GAMMA: [([u64; 2], [u64; 6]); " + gammas.length + "] = [");
+			
+			for(double[] gamma: gammas) {
+				System.out.printf("\t([0x%016X, 0x%016X], [", Double.doubleToRawLongBits(gamma[0]), Double.doubleToRawLongBits(gamma[1]));
+				
+				for(int i = 0; i < 5; i++) {
+					System.out.printf("0x%016X, ", Double.doubleToRawLongBits(math.gamma(gamma[0], gamma[1])));
+				}
+				
+				System.out.printf("0x%016X]),\n", math.random.nextLong());
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const COMMONS_MATH_HEX: [&str; 4] = [\"" + math.hexString(1) + "\", \"" + math.hexString(2) + "\", \"" + math.hexString(7) + "\", \"" + math.hexString(32) + "\"];");
+			System.out.println("pub const COMMONS_MATH_NEXT: i64 = " + math.random.nextLong() + ";");
+		}
+		
+		// Minecraft ImprovedNoise setup, followed by a draw checking the state, and octaves skipped with consumeCount
+		{
+			System.out.println("pub const MC_IMPROVED_NOISE_SEED: u64 = 3690;");
+			
+			Random rand = new Random(3690);
+			ImprovedNoise noise = new ImprovedNoise(rand);
+			
+			System.out.printf("pub const MC_IMPROVED_NOISE_OFFSETS: [u64; 3] = [0x%016X, 0x%016X, 0x%016X];\n", Double.doubleToRawLongBits(noise.xo), Double.doubleToRawLongBits(noise.yo), Double.doubleToRawLongBits(noise.zo));
+			System.out.print("pub const MC_IMPROVED_NOISE_PERMUTATION: [u8; 256] = [");
+			
+			for(int i = 0; i < 256; i++) {
+				if(i % 16 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				System.out.print((noise.p[i] & 0xFF) + ", ");
+			}
+			
+			System.out.println("\n];");
+			System.out.println("pub const MC_IMPROVED_NOISE_NEXT: i64 = " + rand.nextLong() + ";");
+			
+			for(int i = 0; i < 262 * 5; i++) {
+				rand.nextInt();
+			}
+			
+			System.out.println("pub const MC_IMPROVED_NOISE_SKIPPED: i64 = " + rand.nextLong() + ";");
+		}
+		
+		// Minecraft noise samples: ImprovedNoise plain and y scaled, two legacy PerlinNoise octave sums, one with fixed
+		// y, and SimplexNoise in two and three dimensions
+		{
+			System.out.println("pub const MC_NOISE_SEED: u64 = 9054;");
+			
+			Random rand = new Random(9054);
+			ImprovedNoise improved = new ImprovedNoise(rand);
+			PerlinNoise perlin = new PerlinNoise(rand, -3, new double[] { 1.0, 0.0, 2.0, 0.5 });
+			PerlinNoise skipped = new PerlinNoise(rand, -7, new double[] { 1.0, 1.0, 0.0, 1.0 });
+			SimplexNoise simplex = new SimplexNoise(rand);
+			
+			System.out.println("pub const MC_NOISE_NEXT: i64 = " + rand.nextLong() + ";");
+			System.out.printf("pub const MC_NOISE_EDGE_VALUES: [u64; 2] = [0x%016X, 0x%016X];\n", Double.doubleToRawLongBits(perlin.edgeValue(2.0)), Double.doubleToRawLongBits(skipped.edgeValue(2.0)));
+			System.out.println("pub const MC_NOISE: [([u64; 3], [u64; 7]); 64] = [");
+			
+			Random coordinates = new Random(9055);
+			
+			for(int i = 0; i < 64; i++) {
+				double scale = i < 48 ? 64.0 : 1.0E8;
+				double x = (coordinates.nextDouble() - 0.5) * scale;
+				double y = (coordinates.nextDouble() - 0.5) * scale;
+				double z = (coordinates.nextDouble() - 0.5) * scale;
+				
+				double[] values = {
+					improved.noise(x, y, z, 0.0, 0.0),
+					improved.noise(x, y, z, 0.25, 0.5),
+					perlin.getValue(x, y, z, 0.0, 0.0, false),
+					skipped.getValue(x, y, z, 0.0, 0.0, false),
+					perlin.getValue(x, y, z, 0.5, 0.125, true),
+					simplex.getValue(x, z),
+					simplex.getValue(x, y, z)
+				};
+				
+				System.out.printf("\t([0x%016X, 0x%016X, 0x%016X], [", Double.doubleToRawLongBits(x), Double.doubleToRawLongBits(y), Double.doubleToRawLongBits(z));
+				
+				for(int j = 0; j < values.length; j++) {
+					System.out.printf("0x%016X%s", Double.doubleToRawLongBits(values[j]), j + 1 < values.length ? ", " : "");
+				}
+				
+				System.out.println("]),");
+			}
+			
+			System.out.println("];");
+		}
+		
+		// Minecraft 1.12 overworld noise seeding: the 4th octave of scaleNoise, and the state after construction
+		{
+			System.out.println("pub const MC_LEGACY_NOISE_SEED: u64 = 6514;");
+			
+			Random rand = new Random(6514);
+			int[] octaves = { 16, 16, 8, 4, 10, 16, 8 };
+			ImprovedNoise scale = null;
+			
+			for(int i = 0; i < octaves.length; i++) {
+				for(int j = 0; j < octaves[i]; j++) {
+					if(i == 3) {
+						new SimplexNoise(rand);
+					} else {
+						ImprovedNoise noise = new ImprovedNoise(rand);
+						
+						if(i == 4 && j == 3) {
+							scale = noise;
+						}
+					}
+				}
+			}
+			
+			System.out.printf("pub const MC_LEGACY_NOISE_SCALE_OFFSETS: [u64; 3] = [0x%016X, 0x%016X, 0x%016X];\n", Double.doubleToRawLongBits(scale.xo), Double.doubleToRawLongBits(scale.yo), Double.doubleToRawLongBits(scale.zo));
+			System.out.println("pub const MC_LEGACY_NOISE_NEXT: i64 = " + rand.nextLong() + ";");
+		}
+		
+		// Minecraft RandomSource draws, for the legacy and xoroshiro backends
+		{
+			long[] seeds = { 0, 1, -1, 8828, -4172144997902289642L, 6711501738592066281L };
+			
+			for(String backend: new String[] { "LEGACY", "XOROSHIRO" }) {
+				System.out.println("pub const MC_RANDOM_SOURCE_" + backend + ": [(i64, [i64; 24]); " + seeds.length + "] = [");
+				
+				for(long seed: seeds) {
+					McRandomSource source = backend.equals("LEGACY") ? new LegacySource(seed) : new XoroshiroSource(seed);
+					System.out.println("\t(" + seed + ", " + java.util.Arrays.toString(randomSourceDraws(source)) + "),");
+				}
+				
+				System.out.println("];");
+			}
+		}
+		
+		// Minecraft slime chunks, as bit masks over x in [-16, 16) for each z in [-16, 16), followed by distant chunks
+		{
+			long[] seeds = { 0, 12345, -8838183100465624396L };
+			int[][] distant = { { 1875000, -1875000 }, { -1875000, 1875000 }, { 2147483647, -2147483648 }, { 46341, 46341 }, { -100000, 3 } };
+			
+			System.out.println("pub const MC_SLIME_CHUNKS: [(i64, [u32; 32]); " + seeds.length + "] = [");
+			
+			for(long seed: seeds) {
+				System.out.print("\t(" + seed + ", [");
+				
+				for(int z = -16; z < 16; z++) {
+					int mask = 0;
+					
+					for(int x = -16; x < 16; x++) {
+						mask |= (isSlimeChunk(seed, x, z) ? 1 : 0) << (x + 16);
+					}
+					
+					System.out.printf("0x%08X%s", mask, z < 15 ? ", " : "");
+				}
+				
+				System.out.println("]),");
+			}
+			
+			System.out.println("];");
+			System.out.print("pub const MC_SLIME_CHUNKS_BEDROCK: [u32; 32] = [");
+			
+			for(int z = -16; z < 16; z++) {
+				int mask = 0;
+				
+				for(int x = -16; x < 16; x++) {
+					mask |= (isSlimeChunkBedrock(x, z) ? 1 : 0) << (x + 16);
+				}
+				
+				System.out.printf("0x%08X%s", mask, z < 15 ? ", " : "");
+			}
+			
+			System.out.println("];");
+			System.out.println("pub const MC_SLIME_CHUNKS_DISTANT: [(i32, i32, [bool; 4]); " + distant.length + "] = [");
+			
+			for(int[] chunk: distant) {
+				System.out.println("\t(" + chunk[0] + ", " + chunk[1] + ", [" + isSlimeChunk(seeds[0], chunk[0], chunk[1]) + ", " + isSlimeChunk(seeds[1], chunk[0], chunk[1]) + ", " + isSlimeChunk(seeds[2], chunk[0], chunk[1]) + ", " + isSlimeChunkBedrock(chunk[0], chunk[1]) + "]),");
+			}
+			
+			System.out.println("];");
+		}
+		
+		// Minecraft end spikes, as generated by SpikeFeature.SpikeCacheLoader: x, z, radius, height and guarded as 0 or 1
+		{
+			long[] seeds = { 0, 1, -1, 12345, 65536, -6998877665544332211L, 3257840388504953787L };
+			
+			System.out.println("pub const MC_END_SPIKES: [(i64, u16, [[i32; 5]; 10]); " + seeds.length + "] = [");
+			
+			for(long seed: seeds) {
+				long key = new Random(seed).nextLong() & 65535L;
+				java.util.List<Integer> list = new java.util.ArrayList<>();
+				
+				for(int i = 0; i < 10; i++) {
+					list.add(i);
+				}
+				
+				java.util.Collections.shuffle(list, new Random(key));
+				System.out.print("\t(" + seed + ", " + key + ", [");
+				
+				for(int i = 0; i < 10; ++i) {
+					int j = floor(42.0D * Math.cos(2.0D * (-Math.PI + (Math.PI / 10D) * (double) i)));
+					int k = floor(42.0D * Math.sin(2.0D * (-Math.PI + (Math.PI / 10D) * (double) i)));
+					int l = list.get(i);
+					int i1 = 2 + l / 3;
+					int j1 = 76 + l * 3;
+					boolean flag = l == 1 || l == 2;
+					
+					System.out.print("[" + j + ", " + k + ", " + i1 + ", " + j1 + ", " + (flag ? 1 : 0) + "]" + (i < 9 ? ", " : ""));
+				}
+				
+				System.out.println("]),");
+			}
+			
+			System.out.println("];");
+		}
+		
+		// Minecraft enchanting table offers over the stand-in registry: enchantment seed, bookshelves, enchantability, and 1
+		// if the item is a book, with the costs, then for each slot the selected enchantments padded with -1 and the clue
+		{
+			Random params = new Random(4242);
+			StringBuilder offers = new StringBuilder("pub const MC_ENCHANTING_OFFERS: [[[i32; 9]; 3]; 32] = [\n");
+			
+			System.out.println("pub const MC_ENCHANTING: [([i32; 4], [i32; 3]); 32] = [");
+			
+			for(int n = 0; n < 32; n++) {
+				int seed = n < 2 ? (n == 0 ? Integer.MAX_VALUE - 1 : Integer.MIN_VALUE) : params.nextInt();
+				int bookshelves = params.nextInt(20);
+				int enchantability = n == 3 ? 0 : params.nextInt(30) + 1;
+				boolean book = params.nextInt(4) == 0;
+				
+				Random random = new Random((long) seed);
+				int[] costs = new int[3];
+				
+				for(int j = 0; j < 3; ++j) {
+					costs[j] = getEnchantmentCost(random, j, bookshelves, enchantability);
+					
+					if (costs[j] < j + 1) {
+						costs[j] = 0;
+					}
+				}
+				
+				System.out.println("\t([" + seed + ", " + bookshelves + ", " + enchantability + ", " + (book ? 1 : 0) + "], [" + costs[0] + ", " + costs[1] + ", " + costs[2] + "]),");
+				offers.append("\t[");
+				
+				for(int k = 0; k < 3; ++k) {
+					int[] ids = { -1, -1, -1, -1, -1, -1, -1, -1, -1 };
+					
+					if (costs[k] > 0) {
+						random.setSeed((long) (seed + k));
+						java.util.List<Integer> list = selectEnchantment(random, costs[k], enchantability);
+						
+						if (book && list.size() > 1) {
+							list.remove(random.nextInt(list.size()));
+						}
+						
+						if (!list.isEmpty()) {
+							ids[8] = list.get(random.nextInt(list.size()));
+						}
+						
+						for(int i = 0; i < list.size(); i++) {
+							ids[i] = list.get(i);
+						}
+					}
+					
+					offers.append(java.util.Arrays.toString(ids) + (k < 2 ? ", " : ""));
+				}
+				
+				offers.append("],\n");
+			}
+			
+			System.out.println("];");
+			System.out.println(offers + "];");
+		}
+		
+		// Gaussians of the transcription of the game's XoroshiroRandomSource, with every third draw a nextInt() between
+		// the pairs, and the 1000th gaussian of an uninterrupted sequence
+		{
+			long[] seeds = { 0, 1, -1, 2305, -2990443614668268781L, 7597751424082460988L };
+			
+			System.out.println("pub const MC_XOROSHIRO_GAUSSIANS: [(i64, [u64; 48], u64); " + seeds.length + "] = [");
+			
+			for(long seed: seeds) {
+				XoroshiroSource source = new XoroshiroSource(seed);
+				System.out.print("\t(" + seed + ", [");
+				
+				for(int i = 0; i < 48; i++) {
+					long value = i % 3 == 2 ? source.nextInt() : Double.doubleToRawLongBits(source.nextGaussian());
+					System.out.printf("0x%016X%s", value, i < 47 ? ", " : "");
+				}
+				
+				source = new XoroshiroSource(seed);
+				
+				for(int i = 0; i < 999; i++) {
+					source.nextGaussian();
+				}
+				
+				System.out.printf("], 0x%016X),\n", Double.doubleToRawLongBits(source.nextGaussian()));
+			}
+			
+			System.out.println("];");
+		}
+		
+		// Minecraft random spread structure placements, with the salts and spacings of villages and shipwrecks
+		{
+			Random coordinates = new Random(3567);
+			int[][] placements = {{34, 8, 10387312, 0}, {24, 4, 165745295, 0}, {80, 20, 10387313, 1}};
+			
+			System.out.print("pub const MC_RANDOM_SPREAD: [(i64, i32, i32, usize, i32, i32); 96] = [");
+			
+			for(int i = 0; i < 96; i++) {
+				int[] placement = placements[i % 3];
+				long worldSeed = coordinates.nextLong();
+				int chunkX = coordinates.nextInt(200000) - 100000;
+				int chunkZ = coordinates.nextInt(200000) - 100000;
+				
+				int regionX = Math.floorDiv(chunkX, placement[0]);
+				int regionZ = Math.floorDiv(chunkZ, placement[0]);
+				Random rand = new Random((long) regionX * 341873128712L + (long) regionZ * 132897987541L + worldSeed + (long) placement[2]);
+				int limit = placement[0] - placement[1];
+				
+				int offsetX = placement[3] == 0 ? rand.nextInt(limit) : (rand.nextInt(limit) + rand.nextInt(limit)) / 2;
+				int offsetZ = placement[3] == 0 ? rand.nextInt(limit) : (rand.nextInt(limit) + rand.nextInt(limit)) / 2;
+				
+				System.out.printf("\n\t(%d, %d, %d, %d, %d, %d),", worldSeed, chunkX, chunkZ, i % 3, regionX * placement[0] + offsetX, regionZ * placement[0] + offsetZ);
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Samplers of the ext module, drawn in turn from one generator: log-normal (0.25, 0.75), Pareto (1.5, 2.5) and
+		// geometric (0.05), followed by the nextLong() after the last row
+		{
+			long seed = 2718281828L;
+			Random random = new Random(seed);
+			
+			System.out.println("pub const EXT_SAMPLES_SEED: i64 = " + seed + ";");
+			System.out.print("pub const EXT_SAMPLES: [(u64, u64, i64); 256] = [");
+			
+			for(int i = 0; i < 256; i++) {
+				if(i % 2 == 0) {
+					System.out.print("\n\t");
+				}
+				
+				double logNormal = extLogNormal(random, 0.25, 0.75);
+				double pareto = extPareto(random, 1.5, 2.5);
+				long geometric = extGeometric(random, 0.05);
+				
+				System.out.printf("(0x%016X, 0x%016X, %d), ", Double.doubleToRawLongBits(logNormal), Double.doubleToRawLongBits(pareto), geometric);
+			}
+			
+			System.out.println("\n];");
+			System.out.println("pub const EXT_SAMPLES_END: i64 = " + random.nextLong() + ";");
+		}
+		
+		// Biome layer generators of 1.7 through 1.17: world seed, salt, position, and draws with the bounds below
+		{
+			Random inputs = new Random(1993);
+			int[] bounds = {2, 3, 4, 6, 10, 100, 1 << 20, Integer.MAX_VALUE};
+			long[] salts = {1, 10, 100, 200, 1000, 2001, 2002, 4242};
+			
+			System.out.print("pub const MC_LAYER_BOUNDS: [i32; 8] = [");
+			
+			for(int i = 0; i < bounds.length; i++) {
+				System.out.print(bounds[i] + (i < bounds.length - 1 ? ", " : ""));
+			}
+			
+			System.out.println("];");
+			System.out.print("pub const MC_LAYER: [(i64, i64, i64, i64, [i32; 8]); 64] = [");
+			
+			for(int i = 0; i < 64; i++) {
+				long worldSeed = inputs.nextLong();
+				long salt = salts[i % salts.length];
+				long x = inputs.nextInt(2000000) - 1000000;
+				long z = inputs.nextInt(2000000) - 1000000;
+				
+				GenLayer layer = new GenLayer(salt);
+				layer.initWorldGenSeed(worldSeed);
+				layer.initChunkSeed(x, z);
+				
+				System.out.printf("\n\t(%d, %d, %d, %d, [", worldSeed, salt, x, z);
+				
+				for(int j = 0; j < bounds.length; j++) {
+					System.out.print(layer.nextInt(bounds[j]) + (j < bounds.length - 1 ? ", " : ""));
+				}
+				
+				System.out.print("]),");
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Hashed seeds of BiomeManager.obfuscateSeed, which uses Guava's Hashing.sha256().hashLong(seed).asLong()
+		{
+			Random seeds = new Random(115);
+			java.security.MessageDigest sha256 = java.security.MessageDigest.getInstance("SHA-256");
+			
+			System.out.print("pub const MC_HASHED_SEEDS: [(i64, i64); 32] = [");
+			
+			for(int i = 0; i < 32; i++) {
+				long seed = i == 0 ? 0 : i == 1 ? -1 : i == 2 ? "glacier".hashCode() : seeds.nextLong();
+				byte[] digest = sha256.digest(java.nio.ByteBuffer.allocate(8).order(java.nio.ByteOrder.LITTLE_ENDIAN).putLong(seed).array());
+				long hashed = java.nio.ByteBuffer.wrap(digest).order(java.nio.ByteOrder.LITTLE_ENDIAN).getLong();
+				
+				System.out.printf("\n\t(%d, %d),", seed, hashed);
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Derived draws of SplittableRandom, which it inherits from RandomGenerator: nextInt(bound), nextLong(bound),
+		// nextGaussian() and nextExponential() in turn, with the bounds in the rows
+		{
+			long seed = 1717;
+			java.util.SplittableRandom random = new java.util.SplittableRandom(seed);
+			int[] intBounds = {1, 2, 7, 64, 100, 1 << 30, (1 << 30) + 1, Integer.MAX_VALUE};
+			long[] longBounds = {1, 2, 1000, 1L << 40, (1L << 62) + 1, 3000000000000000000L, Long.MAX_VALUE, 6};
+			
+			System.out.println("pub const SPLITMIX_DERIVED_SEED: i64 = " + seed + ";");
+			System.out.println("// (nextInt bound, value, nextLong bound, value, nextGaussian bits, nextExponential bits)");
+			System.out.print("pub const SPLITMIX_DERIVED: [(i32, i32, i64, i64, u64, u64); 64] = [");
+			
+			for(int i = 0; i < 64; i++) {
+				int intBound = intBounds[i % intBounds.length];
+				long longBound = longBounds[(i / intBounds.length) % longBounds.length];
+				int intValue = random.nextInt(intBound);
+				long longValue = random.nextLong(longBound);
+				double gaussian = random.nextGaussian();
+				double exponential = random.nextExponential();
+				
+				System.out.printf("\n\t(%d, %d, %d, %d, 0x%016X, 0x%016X),", intBound, intValue, longBound, longValue, Double.doubleToRawLongBits(gaussian), Double.doubleToRawLongBits(exponential));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// The stream of SplittableRandom.splits(size) split like a parallel pipeline would: the first trySplit takes
+		// [0, 10), the second splits that into [0, 5) and [5, 10), and a third split of the root takes [10, 15). The
+		// row of each index holds nextLong() and nextInt() of the generator at that index.
+		{
+			long seed = 1818;
+			int size = 20;
+			long[] longs = new long[size];
+			int[] ints = new int[size];
+			
+			java.util.Spliterator<java.util.random.RandomGenerator.SplittableGenerator> root = new java.util.SplittableRandom(seed).splits(size).spliterator();
+			java.util.Spliterator<java.util.random.RandomGenerator.SplittableGenerator> first = root.trySplit();
+			java.util.Spliterator<java.util.random.RandomGenerator.SplittableGenerator> lower = first.trySplit();
+			java.util.Spliterator<java.util.random.RandomGenerator.SplittableGenerator> third = root.trySplit();
+			int[] index = {0};
+			
+			for(java.util.Spliterator<java.util.random.RandomGenerator.SplittableGenerator> part: java.util.List.of(lower, first, third, root)) {
+				part.forEachRemaining(generator -> {
+					longs[index[0]] = generator.nextLong();
+					ints[index[0]++] = generator.nextInt();
+				});
+			}
+			
+			System.out.println("pub const SPLITMIX_SPLITS_SEED: i64 = " + seed + ";");
+			System.out.println("// (nextLong, nextInt) of the generator at each index of the split streams");
+			System.out.print("pub const SPLITMIX_SPLITS: [(i64, i32); " + size + "] = [");
+			
+			for(int i = 0; i < size; i++) {
+				System.out.printf("\n\t(%d, %d),", longs[i], ints[i]);
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// FastMath exp, log and pow, at special values and then at random points across their ranges
+		{
+			Random random = new Random(1919);
+			int count = 96;
+			double[] special = { 0.0, -0.0, 1.0, -1.0, 0.5, 2.0, 0.995, 1.005, -745.5, -709.5, 709.5, 710.0, -800.0, 1e-310, Double.MIN_VALUE, Double.MAX_VALUE, Double.POSITIVE_INFINITY, Double.NEGATIVE_INFINITY, Double.NaN };
+			
+			System.out.print("pub const FASTMATH_EXP_LOG: [(u64, u64, u64); " + count + "] = [");
+			
+			for(int i = 0; i < count; i++) {
+				double x = i < special.length ? special[i] : i % 2 == 0 ? (random.nextDouble() - 0.5) * 1500 : Math.exp((random.nextDouble() - 0.5) * 1400);
+				
+				System.out.printf("\n\t(0x%016X, 0x%016X, 0x%016X),", Double.doubleToRawLongBits(x), Double.doubleToRawLongBits(FastMath.exp(x)), Double.doubleToRawLongBits(FastMath.log(x)));
+			}
+			
+			System.out.println("\n];");
+			
+			double[][] specialPow = { { 2.0, 0.5 }, { 2.0, 10.0 }, { -2.0, 3.0 }, { -2.0, 0.5 }, { 0.0, -0.5 }, { -0.0, 3.0 }, { 1.0, Double.POSITIVE_INFINITY }, { 0.5, Double.NEGATIVE_INFINITY }, { 3.0, 1e300 }, { Double.POSITIVE_INFINITY, -0.5 }, { Double.NaN, 0.0 }, { 2.0, Double.NaN }, { 10.0, -400.0 }, { 1e-300, 1.5 } };
+			
+			System.out.print("pub const FASTMATH_POW: [(u64, u64, u64); " + count + "] = [");
+			
+			for(int i = 0; i < count; i++) {
+				double x = i < specialPow.length ? specialPow[i][0] : random.nextDouble() * 8;
+				double y = i < specialPow.length ? specialPow[i][1] : i % 3 == 0 ? random.nextInt(41) - 20 : (random.nextDouble() - 0.5) * 60;
+				
+				System.out.printf("\n\t(0x%016X, 0x%016X, 0x%016X),", Double.doubleToRawLongBits(x), Double.doubleToRawLongBits(y), Double.doubleToRawLongBits(FastMath.pow(x, y)));
+			}
+			
+			System.out.println("\n];");
+			
+			int[] specialExponents = { 0, 1, -1, 1074, -1074, Integer.MIN_VALUE, Integer.MAX_VALUE };
+			
+			System.out.print("pub const FASTMATH_POW_INT: [(u64, i32, u64); " + count + "] = [");
+			
+			for(int i = 0; i < count; i++) {
+				double x = i < specialExponents.length ? (i % 2 == 0 ? 1.0000001 : -0.5) : (random.nextDouble() - 0.5) * 6;
+				int e = i < specialExponents.length ? specialExponents[i] : random.nextInt(81) - 40;
+				
+				System.out.printf("\n\t(0x%016X, %d, 0x%016X),", Double.doubleToRawLongBits(x), e, Double.doubleToRawLongBits(FastMath.pow(x, e)));
+			}
+			
+			System.out.println("\n];");
+		}
+		
+		// Commons RNG JDK provider bounded draws, alternating nextInt(n) and nextLong(n)
+		{
+			CommonsRngJdk provider = new CommonsRngJdk(-8361);
+			int[] intBounds = { 1, 2, 3, 7, 256, 1000, 0x40000001, 0x55555555, Integer.MAX_VALUE };
+			long[] longBounds = { 1, 2, 3, 7, 1L << 40, 1000000007, 0x4000000000000001L, 0x5555555555555555L, Long.MAX_VALUE };
+			
+			System.out.print("pub const COMMONS_RNG_BOUNDED: [(i32, i32, i64, i64); 36] = [");
+			
+			for(int i = 0; i < 36; i++) {
+				int intBound = intBounds[i % intBounds.length];
+				long longBound = longBounds[(i * 4) % longBounds.length];
+				
+				int intValue = provider.nextInt(intBound);
+				long longValue = provider.nextLong(longBound);
+				
+				System.out.printf("\n\t(%d, %d, %d, %d),", intBound, intValue, longBound, longValue);
+			}
+			
+			System.out.println("\n];");
+		}
+	}
+	
+	// GenLayer of 1.12.2, without the layers themselves
+	static class GenLayer {
+		long worldGenSeed;
+		long chunkSeed;
+		long baseSeed;
+		
+		GenLayer(long baseSeed) {
+			this.baseSeed = baseSeed;
+			this.baseSeed *= this.baseSeed * 6364136223846793005L + 1442695040888963407L;
+			this.baseSeed += baseSeed;
+			this.baseSeed *= this.baseSeed * 6364136223846793005L + 1442695040888963407L;
+			this.baseSeed += baseSeed;
+			this.baseSeed *= this.baseSeed * 6364136223846793005L + 1442695040888963407L;
+			this.baseSeed += baseSeed;
+		}
+		
+		void initWorldGenSeed(long seed) {
+			this.worldGenSeed = seed;
+			this.worldGenSeed *= this.worldGenSeed * 6364136223846793005L + 1442695040888963407L;
+			this.worldGenSeed += this.baseSeed;
+			this.worldGenSeed *= this.worldGenSeed * 6364136223846793005L + 1442695040888963407L;
+			this.worldGenSeed += this.baseSeed;
+			this.worldGenSeed *= this.worldGenSeed * 6364136223846793005L + 1442695040888963407L;
+			this.worldGenSeed += this.baseSeed;
+		}
+		
+		void initChunkSeed(long x, long z) {
+			this.chunkSeed = this.worldGenSeed;
+			this.chunkSeed *= this.chunkSeed * 6364136223846793005L + 1442695040888963407L;
+			this.chunkSeed += x;
+			this.chunkSeed *= this.chunkSeed * 6364136223846793005L + 1442695040888963407L;
+			this.chunkSeed += z;
+			this.chunkSeed *= this.chunkSeed * 6364136223846793005L + 1442695040888963407L;
+			this.chunkSeed += x;
+			this.chunkSeed *= this.chunkSeed * 6364136223846793005L + 1442695040888963407L;
+			this.chunkSeed += z;
+		}
+		
+		int nextInt(int bound) {
+			int i = (int) ((this.chunkSeed >> 24) % (long) bound);
+			
+			if(i < 0) {
+				i += bound;
+			}
+			
+			this.chunkSeed *= this.chunkSeed * 6364136223846793005L + 1442695040888963407L;
+			this.chunkSeed += this.worldGenSeed;
+			
+			return i;
+		}
+	}
+	
+	static double extLogNormal(Random random, double mu, double sigma) {
+		while(true) {
+			double v1 = 2 * random.nextDouble() - 1;
+			double v2 = 2 * random.nextDouble() - 1;
+			double s = v1 * v1 + v2 * v2;
+			
+			if(s < 1 && s != 0) {
+				double gaussian = v1 * StrictMath.sqrt(-2 * StrictMath.log(s) / s);
+				
+				return StrictMath.exp(mu + sigma * gaussian);
+			}
+		}
+	}
+	
+	static double extPareto(Random random, double scale, double shape) {
+		return scale * StrictMath.pow(1 - random.nextDouble(), -1 / shape);
+	}
+	
+	static long extGeometric(Random random, double p) {
+		double u = random.nextDouble();
+		
+		return (long) ((0 - StrictMath.log(1 - u)) / (0 - StrictMath.log(1 - p)));
+	}
+	
+	static void printXoroshiro(String name, int variant, long seed) {
+		Xoroshiro128 generator = new Xoroshiro128(variant, seed);
+		
+		System.out.println("pub const " + name + "_SEED: u64 = " + seed + ";");
+		System.out.print("pub const " + name + ": [u64; 2048] = [");
+		
+		for(int i = 0; i < 256; i++) {
+			int bound = (i % 2 == 0) ? (1 << (i % 31)) : (i * 104729 + 1);
+			
+			System.out.printf("\n\t0x%016X, 0x%016X, 0x%016X, 0x%016X,", generator.nextLong(), (long) generator.nextInt(), (long) generator.nextInt(bound), generator.nextBoolean() ? 1L : 0L);
+			System.out.printf("\n\t0x%016X, 0x%016X, 0x%016X, 0x%016X,", (long) Float.floatToRawIntBits(generator.nextFloat()), Double.doubleToRawLongBits(generator.nextDouble()), Double.doubleToRawLongBits(generator.nextGaussian()), Double.doubleToRawLongBits(generator.nextExponential()));
+		}
+		
+		System.out.println("\n];");
+		
+		Xoroshiro128 raw = new Xoroshiro128(variant, 1, 2);
+		
+		System.out.print("pub const " + name + "_RAW: [u64; 8] = [");
+		
+		for(int i = 0; i < 8; i++) {
+			System.out.printf("0x%016X, ", raw.nextLong());
+		}
+		
+		System.out.println("];");
+	}
+	
+	/// Xoroshiro128 with the seeding of the JDK implementation of Xoroshiro128PlusPlus, and the output functions of
+	/// the reference C implementations of each scrambler.
+	static class Xoroshiro128 implements java.util.random.RandomGenerator {
+		static final int PLUS = 0;
+		static final int PLUS_PLUS = 1;
+		static final int STAR_STAR = 2;
+		
+		final int variant;
+		long x0;
+		long x1;
+		
+		Xoroshiro128(int variant, long seed) {
+			this(variant, mixStafford13(seed ^ 0x6A09E667F3BCC909L), mixStafford13((seed ^ 0x6A09E667F3BCC909L) + 0x9E3779B97F4A7C15L));
+		}
+		
+		Xoroshiro128(int variant, long x0, long x1) {
+			this.variant = variant;
+			this.x0 = x0;
+			this.x1 = x1;
+			
+			if((x0 | x1) == 0) {
+				this.x0 = 0x9E3779B97F4A7C15L;
+				this.x1 = 0x6A09E667F3BCC909L;
+			}
+		}
+		
+		static long mixStafford13(long z) {
+			z = (z ^ (z >>> 30)) * 0xBF58476D1CE4E5B9L;
+			z = (z ^ (z >>> 27)) * 0x94D049BB133111EBL;
+			return z ^ (z >>> 31);
+		}
+		
+		public long nextLong() {
+			final long s0 = x0;
+			long s1 = x1;
+			final long result;
+			
+			switch(variant) {
+				case PLUS: result = s0 + s1; break;
+				case PLUS_PLUS: result = Long.rotateLeft(s0 + s1, 17) + s0; break;
+				default: result = Long.rotateLeft(s0 * 5, 7) * 9; break;
+			}
+			
+			s1 ^= s0;
+			
+			if(variant == PLUS_PLUS) {
+				x0 = Long.rotateLeft(s0, 49) ^ s1 ^ (s1 << 21);
+				x1 = Long.rotateLeft(s1, 28);
+			} else {
+				x0 = Long.rotateLeft(s0, 24) ^ s1 ^ (s1 << 16);
+				x1 = Long.rotateLeft(s1, 37);
+			}
+			
+			return result;
+		}
+	}
+	
+	static final int[][] GRADIENT = {
+		{1, 1, 0}, {-1, 1, 0}, {1, -1, 0}, {-1, -1, 0},
+		{1, 0, 1}, {-1, 0, 1}, {1, 0, -1}, {-1, 0, -1},
+		{0, 1, 1}, {0, -1, 1}, {0, 1, -1}, {0, -1, -1},
+		{1, 1, 0}, {0, -1, 1}, {-1, 1, 0}, {0, -1, -1}
+	};
+	
+	static int floor(double d) {
+		int i = (int) d;
+		return d < (double) i ? i - 1 : i;
+	}
+	
+	static long lfloor(double d) {
+		long i = (long) d;
+		return d < (double) i ? i - 1L : i;
+	}
+	
+	static double smoothstep(double d) {
+		return d * d * d * (d * (d * 6.0D - 15.0D) + 10.0D);
+	}
+	
+	static double lerp(double delta, double start, double end) {
+		return start + delta * (end - start);
+	}
+	
+	static double lerp2(double dx, double dy, double a, double b, double c, double d) {
+		return lerp(dy, lerp(dx, a, b), lerp(dx, c, d));
+	}
+	
+	static double dot(int[] g, double x, double y, double z) {
+		return (double) g[0] * x + (double) g[1] * y + (double) g[2] * z;
+	}
+	
+	/// Port of Minecraft's ImprovedNoise
+	static class ImprovedNoise {
+		final double xo;
+		final double yo;
+		final double zo;
+		final byte[] p = new byte[256];
+		
+		ImprovedNoise(Random random) {
+			this(new LegacySource(random));
+		}
+		
+		ImprovedNoise(McRandomSource random) {
+			xo = random.nextDouble() * 256.0;
+			yo = random.nextDouble() * 256.0;
+			zo = random.nextDouble() * 256.0;
+			
+			for(int i = 0; i < 256; i++) {
+				p[i] = (byte) i;
+			}
+			
+			for(int k = 0; k < 256; k++) {
+				int j = random.nextInt(256 - k);
+				byte b = p[k];
+				p[k] = p[k + j];
+				p[k + j] = b;
+			}
+		}
+		
+		double noise(double x, double y, double z, double yScale, double yMax) {
+			double d0 = x + this.xo;
+			double d1 = y + this.yo;
+			double d2 = z + this.zo;
+			int i = floor(d0);
+			int j = floor(d1);
+			int k = floor(d2);
+			double d3 = d0 - (double) i;
+			double d4 = d1 - (double) j;
+			double d5 = d2 - (double) k;
+			double d6;
+			
+			if (yScale != 0.0D) {
+				double d7;
+				
+				if (yMax >= 0.0D && yMax < d4) {
+					d7 = yMax;
+				} else {
+					d7 = d4;
+				}
+				
+				d6 = (double) floor(d7 / yScale + (double) 1.0E-7F) * yScale;
+			} else {
+				d6 = 0.0D;
+			}
+			
+			return this.sampleAndLerp(i, j, k, d3, d4 - d6, d5, d4);
+		}
+		
+		int p(int index) {
+			return this.p[index & 255] & 255;
+		}
+		
+		static double gradDot(int hash, double x, double y, double z) {
+			return dot(GRADIENT[hash & 15], x, y, z);
+		}
+		
+		double sampleAndLerp(int gridX, int gridY, int gridZ, double deltaX, double weirdDeltaY, double deltaZ, double deltaY) {
+			int i = this.p(gridX);
+			int j = this.p(gridX + 1);
+			int k = this.p(i + gridY);
+			int l = this.p(i + gridY + 1);
+			int i1 = this.p(j + gridY);
+			int j1 = this.p(j + gridY + 1);
+			double d0 = gradDot(this.p(k + gridZ), deltaX, weirdDeltaY, deltaZ);
+			double d1 = gradDot(this.p(i1 + gridZ), deltaX - 1.0D, weirdDeltaY, deltaZ);
+			double d2 = gradDot(this.p(l + gridZ), deltaX, weirdDeltaY - 1.0D, deltaZ);
+			double d3 = gradDot(this.p(j1 + gridZ), deltaX - 1.0D, weirdDeltaY - 1.0D, deltaZ);
+			double d4 = gradDot(this.p(k + gridZ + 1), deltaX, weirdDeltaY, deltaZ - 1.0D);
+			double d5 = gradDot(this.p(i1 + gridZ + 1), deltaX - 1.0D, weirdDeltaY, deltaZ - 1.0D);
+			double d6 = gradDot(this.p(l + gridZ + 1), deltaX, weirdDeltaY - 1.0D, deltaZ - 1.0D);
+			double d7 = gradDot(this.p(j1 + gridZ + 1), deltaX - 1.0D, weirdDeltaY - 1.0D, deltaZ - 1.0D);
+			double d8 = smoothstep(deltaX);
+			double d9 = smoothstep(deltaY);
+			double d10 = smoothstep(deltaZ);
+			return lerp(d10, lerp2(d8, d9, d0, d1, d2, d3), lerp2(d8, d9, d4, d5, d6, d7));
+		}
+	}
+	
+	/// Port of Minecraft's PerlinNoise, with the legacy construction from a single generator
+	static class PerlinNoise {
+		final ImprovedNoise[] noiseLevels;
+		final double[] amplitudes;
+		final double lowestFreqInputFactor;
+		final double lowestFreqValueFactor;
+		
+		PerlinNoise(Random random, int firstOctave, double[] amplitudes) {
+			this.amplitudes = amplitudes;
+			int i = amplitudes.length;
+			int j = -firstOctave;
+			this.noiseLevels = new ImprovedNoise[i];
+			ImprovedNoise improvednoise = new ImprovedNoise(random);
+			
+			if (j >= 0 && j < i) {
+				double d0 = amplitudes[j];
+				
+				if (d0 != 0.0D) {
+					this.noiseLevels[j] = improvednoise;
+				}
+			}
+			
+			for(int i1 = j - 1; i1 >= 0; --i1) {
+				if (i1 < i) {
+					double d1 = amplitudes[i1];
+					
+					if (d1 != 0.0D) {
+						this.noiseLevels[i1] = new ImprovedNoise(random);
+					} else {
+						skipOctave(random);
+					}
+				} else {
+					skipOctave(random);
+				}
+			}
+			
+			if (j < i - 1) {
+				throw new IllegalStateException("Positive octaves are temporarily disabled");
+			}
+			
+			this.lowestFreqInputFactor = Math.pow(2.0D, (double) (-j));
+			this.lowestFreqValueFactor = Math.pow(2.0D, (double) (i - 1)) / (Math.pow(2.0D, (double) i) - 1.0D);
+		}
+		
+		/// PerlinNoise.create, with octaves from a positional factory
+		PerlinNoise(McRandomSource random, int firstOctave, double[] amplitudes) {
+			this.amplitudes = amplitudes;
+			int i = amplitudes.length;
+			int j = -firstOctave;
+			this.noiseLevels = new ImprovedNoise[i];
+			McPositionalFactory factory = random.forkPositional();
+			
+			for(int k = 0; k < i; ++k) {
+				if (amplitudes[k] != 0.0D) {
+					int l = firstOctave + k;
+					this.noiseLevels[k] = new ImprovedNoise(factory.fromHashOf("octave_" + l));
+				}
+			}
+			
+			this.lowestFreqInputFactor = Math.pow(2.0D, (double) (-j));
+			this.lowestFreqValueFactor = Math.pow(2.0D, (double) (i - 1)) / (Math.pow(2.0D, (double) i) - 1.0D);
+		}
+		
+		static void skipOctave(Random random) {
+			for(int i = 0; i < 262; i++) {
+				random.nextInt();
+			}
+		}
+		
+		static double wrap(double value) {
+			return value - (double) lfloor(value / 3.3554432E7D + 0.5D) * 3.3554432E7D;
+		}
+		
+		double getValue(double x, double y, double z, double yScale, double yMax, boolean useFixedY) {
+			double d0 = 0.0D;
+			double d1 = this.lowestFreqInputFactor;
+			double d2 = this.lowestFreqValueFactor;
+			
+			for(int i = 0; i < this.noiseLevels.length; ++i) {
+				ImprovedNoise improvednoise = this.noiseLevels[i];
+				
+				if (improvednoise != null) {
+					double d3 = improvednoise.noise(wrap(x * d1), useFixedY ? -improvednoise.yo : wrap(y * d1), wrap(z * d1), yScale * d1, yMax * d1);
+					d0 += this.amplitudes[i] * d3 * d2;
+				}
+				
+				d1 *= 2.0D;
+				d2 /= 2.0D;
+			}
+			
+			return d0;
+		}
+		
+		double edgeValue(double bound) {
+			double d0 = 0.0D;
+			double d1 = this.lowestFreqValueFactor;
+			
+			for(int i = 0; i < this.noiseLevels.length; ++i) {
+				if (this.noiseLevels[i] != null) {
+					d0 += this.amplitudes[i] * bound * d1;
+				}
+				
+				d1 /= 2.0D;
+			}
+			
+			return d0;
+		}
+	}
+	
+	/// Port of Minecraft's SimplexNoise
+	static class SimplexNoise {
+		static final double SQRT_3 = Math.sqrt(3.0D);
+		static final double F2 = 0.5D * (SQRT_3 - 1.0D);
+		static final double G2 = (3.0D - SQRT_3) / 6.0D;
+		final int[] p = new int[512];
+		final double xo;
+		final double yo;
+		final double zo;
+		
+		SimplexNoise(Random random) {
+			this.xo = random.nextDouble() * 256.0D;
+			this.yo = random.nextDouble() * 256.0D;
+			this.zo = random.nextDouble() * 256.0D;
+			
+			for(int i = 0; i < 256; this.p[i] = i++) {
+			}
+			
+			for(int l = 0; l < 256; ++l) {
+				int j = random.nextInt(256 - l);
+				int k = this.p[l];
+				this.p[l] = this.p[j + l];
+				this.p[j + l] = k;
+			}
+		}
+		
+		int p(int i) {
+			return this.p[i & 255];
+		}
+		
+		double getCornerNoise3D(int gradientIndex, double x, double y, double z, double offset) {
+			double d1 = offset - x * x - y * y - z * z;
+			double d0;
+			
+			if (d1 < 0.0D) {
+				d0 = 0.0D;
+			} else {
+				d1 *= d1;
+				d0 = d1 * d1 * dot(GRADIENT[gradientIndex], x, y, z);
+			}
+			
+			return d0;
+		}
+		
+		double getValue(double x, double y) {
+			double d0 = (x + y) * F2;
+			int i = floor(x + d0);
+			int j = floor(y + d0);
+			double d1 = (double) (i + j) * G2;
+			double d2 = (double) i - d1;
+			double d3 = (double) j - d1;
+			double d4 = x - d2;
+			double d5 = y - d3;
+			int k;
+			int l;
+			
+			if (d4 > d5) {
+				k = 1;
+				l = 0;
+			} else {
+				k = 0;
+				l = 1;
+			}
+			
+			double d6 = d4 - (double) k + G2;
+			double d7 = d5 - (double) l + G2;
+			double d8 = d4 - 1.0D + 2.0D * G2;
+			double d9 = d5 - 1.0D + 2.0D * G2;
+			int i1 = i & 255;
+			int j1 = j & 255;
+			int k1 = this.p(i1 + this.p(j1)) % 12;
+			int l1 = this.p(i1 + k + this.p(j1 + l)) % 12;
+			int i2 = this.p(i1 + 1 + this.p(j1 + 1)) % 12;
+			double d10 = this.getCornerNoise3D(k1, d4, d5, 0.0D, 0.5D);
+			double d11 = this.getCornerNoise3D(l1, d6, d7, 0.0D, 0.5D);
+			double d12 = this.getCornerNoise3D(i2, d8, d9, 0.0D, 0.5D);
+			return 70.0D * (d10 + d11 + d12);
+		}
+		
+		double getValue(double x, double y, double z) {
+			double d1 = (x + y + z) * 0.3333333333333333D;
+			int i = floor(x + d1);
+			int j = floor(y + d1);
+			int k = floor(z + d1);
+			double d3 = (double) (i + j + k) * 0.16666666666666666D;
+			double d4 = (double) i - d3;
+			double d5 = (double) j - d3;
+			double d6 = (double) k - d3;
+			double d7 = x - d4;
+			double d8 = y - d5;
+			double d9 = z - d6;
+			int l, i1, j1, k1, l1, i2;
+			
+			if (d7 >= d8) {
+				if (d8 >= d9) {
+					l = 1; i1 = 0; j1 = 0; k1 = 1; l1 = 1; i2 = 0;
+				} else if (d7 >= d9) {
+					l = 1; i1 = 0; j1 = 0; k1 = 1; l1 = 0; i2 = 1;
+				} else {
+					l = 0; i1 = 0; j1 = 1; k1 = 1; l1 = 0; i2 = 1;
+				}
+			} else if (d8 < d9) {
+				l = 0; i1 = 0; j1 = 1; k1 = 0; l1 = 1; i2 = 1;
+			} else if (d7 < d9) {
+				l = 0; i1 = 1; j1 = 0; k1 = 0; l1 = 1; i2 = 1;
+			} else {
+				l = 0; i1 = 1; j1 = 0; k1 = 1; l1 = 1; i2 = 0;
+			}
+			
+			double d10 = d7 - (double) l + 0.16666666666666666D;
+			double d11 = d8 - (double) i1 + 0.16666666666666666D;
+			double d12 = d9 - (double) j1 + 0.16666666666666666D;
+			double d13 = d7 - (double) k1 + 0.3333333333333333D;
+			double d14 = d8 - (double) l1 + 0.3333333333333333D;
+			double d15 = d9 - (double) i2 + 0.3333333333333333D;
+			double d16 = d7 - 1.0D + 0.5D;
+			double d17 = d8 - 1.0D + 0.5D;
+			double d18 = d9 - 1.0D + 0.5D;
+			int j2 = i & 255;
+			int k2 = j & 255;
+			int l2 = k & 255;
+			int i3 = this.p(j2 + this.p(k2 + this.p(l2))) % 12;
+			int j3 = this.p(j2 + l + this.p(k2 + i1 + this.p(l2 + j1))) % 12;
+			int k3 = this.p(j2 + k1 + this.p(k2 + l1 + this.p(l2 + i2))) % 12;
+			int l3 = this.p(j2 + 1 + this.p(k2 + 1 + this.p(l2 + 1))) % 12;
+			double d19 = this.getCornerNoise3D(i3, d7, d8, d9, 0.6D);
+			double d20 = this.getCornerNoise3D(j3, d10, d11, d12, 0.6D);
+			double d21 = this.getCornerNoise3D(k3, d13, d14, d15, 0.6D);
+			double d22 = this.getCornerNoise3D(l3, d16, d17, d18, 0.6D);
+			return 32.0D * (d19 + d20 + d21 + d22);
+		}
+	}
+	
+	/// Minecraft's RandomSource
+	interface McRandomSource {
+		McRandomSource fork();
+		McPositionalFactory forkPositional();
+		int nextInt();
+		int nextInt(int bound);
+		long nextLong();
+		boolean nextBoolean();
+		float nextFloat();
+		double nextDouble();
+		double nextGaussian();
+		
+		default int nextIntBetweenInclusive(int min, int max) {
+			return this.nextInt(max - min + 1) + min;
+		}
+		
+		default double triangle(double center, double width) {
+			return center + width * (this.nextDouble() - this.nextDouble());
+		}
+		
+		default void consumeCount(int count) {
+			for(int i = 0; i < count; ++i) {
+				this.nextInt();
+			}
+		}
+	}
+	
+	interface McPositionalFactory {
+		McRandomSource at(int x, int y, int z);
+		McRandomSource fromHashOf(String name);
+		McRandomSource fromSeed(long seed);
+	}
+	
+	/// Mth.getSeed
+	static long positionSeed(int x, int y, int z) {
+		long i = (long) (x * 3129871) ^ (long) z * 116129781L ^ (long) y;
+		i = i * i * 42317861L + i * 11L;
+		return i >> 16;
+	}
+	
+	/// Transcription of the game's MarsagliaPolarGaussian
+	static class MarsagliaPolarGaussian {
+		final McRandomSource randomSource;
+		double nextNextGaussian;
+		boolean haveNextNextGaussian;
+		
+		MarsagliaPolarGaussian(McRandomSource randomSource) {
+			this.randomSource = randomSource;
+		}
+		
+		double nextGaussian() {
+			if (this.haveNextNextGaussian) {
+				this.haveNextNextGaussian = false;
+				return this.nextNextGaussian;
+			}
+			
+			while(true) {
+				double d0 = 2.0D * this.randomSource.nextDouble() - 1.0D;
+				double d1 = 2.0D * this.randomSource.nextDouble() - 1.0D;
+				double d2 = d0 * d0 + d1 * d1;
+				
+				if (d2 < 1.0D && d2 != 0.0D) {
+					double d3 = StrictMath.sqrt(-2.0D * StrictMath.log(d2) / d2);
+					this.nextNextGaussian = d1 * d3;
+					this.haveNextNextGaussian = true;
+					return d0 * d3;
+				}
+			}
+		}
+	}
+	
+	/// Transcription of the game's LegacyRandomSource, drawing from a shared java.util.Random through BitRandomSource's formulas
+	static class LegacySource implements McRandomSource {
+		final Random random;
+		final MarsagliaPolarGaussian gaussianSource = new MarsagliaPolarGaussian(this);
+		
+		LegacySource(Random random) {
+			this.random = random;
+		}
+		
+		LegacySource(long seed) {
+			this(new Random(seed));
+		}
+		
+		int next(int bits) {
+			return bits == 32 ? this.random.nextInt() : this.random.nextInt() >>> (32 - bits);
+		}
+		
+		public McRandomSource fork() {
+			return new LegacySource(this.nextLong());
+		}
+		
+		public McPositionalFactory forkPositional() {
+			long seed = this.nextLong();
+			
+			return new McPositionalFactory() {
+				public McRandomSource at(int x, int y, int z) {
+					return new LegacySource(positionSeed(x, y, z) ^ seed);
+				}
+				
+				public McRandomSource fromHashOf(String name) {
+					return new LegacySource((long) name.hashCode() ^ seed);
+				}
+				
+				public McRandomSource fromSeed(long s) {
+					return new LegacySource(s);
+				}
+			};
+		}
+		
+		public int nextInt() {
+			return this.next(32);
+		}
+		
+		public int nextInt(int bound) {
+			if ((bound & -bound) == bound) {
+				return (int) ((long) bound * (long) this.next(31) >> 31);
+			}
+			
+			int i;
+			int j;
+			
+			do {
+				i = this.next(31);
+				j = i % bound;
+			} while(i - j + (bound - 1) < 0);
+			
+			return j;
+		}
+		
+		public long nextLong() {
+			int i = this.next(32);
+			int j = this.next(32);
+			long k = (long) i << 32;
+			return k + (long) j;
+		}
+		
+		public boolean nextBoolean() {
+			return this.next(1) != 0;
+		}
+		
+		public float nextFloat() {
+			return (float) this.next(24) * 5.9604645E-8F;
+		}
+		
+		public double nextDouble() {
+			int i = this.next(26);
+			int j = this.next(27);
+			long k = ((long) i << 27) + (long) j;
+			return (double) k * (double) 1.110223E-16F;
+		}
+		
+		public double nextGaussian() {
+			return this.gaussianSource.nextGaussian();
+		}
+	}
+	
+	/// Transcription of the game's XoroshiroRandomSource, as the game's classes are not available to the generator
+	static class XoroshiroSource implements McRandomSource {
+		final Xoroshiro128 randomNumberGenerator;
+		final MarsagliaPolarGaussian gaussianSource = new MarsagliaPolarGaussian(this);
+		
+		XoroshiroSource(long seed) {
+			this.randomNumberGenerator = new Xoroshiro128(Xoroshiro128.PLUS_PLUS, seed);
+		}
+		
+		XoroshiroSource(long lo, long hi) {
+			this.randomNumberGenerator = new Xoroshiro128(Xoroshiro128.PLUS_PLUS, lo, hi);
+		}
+		
+		public McRandomSource fork() {
+			return new XoroshiroSource(this.randomNumberGenerator.nextLong(), this.randomNumberGenerator.nextLong());
+		}
+		
+		public McPositionalFactory forkPositional() {
+			long seedLo = this.randomNumberGenerator.nextLong();
+			long seedHi = this.randomNumberGenerator.nextLong();
+			
+			return new McPositionalFactory() {
+				public McRandomSource at(int x, int y, int z) {
+					return new XoroshiroSource(positionSeed(x, y, z) ^ seedLo, seedHi);
+				}
+				
+				public McRandomSource fromHashOf(String name) {
+					try {
+						byte[] digest = java.security.MessageDigest.getInstance("MD5").digest(name.getBytes(java.nio.charset.StandardCharsets.UTF_8));
+						long lo = java.nio.ByteBuffer.wrap(digest, 0, 8).getLong();
+						long hi = java.nio.ByteBuffer.wrap(digest, 8, 8).getLong();
+						return new XoroshiroSource(lo ^ seedLo, hi ^ seedHi);
+					} catch(java.security.NoSuchAlgorithmException e) {
+						throw new RuntimeException(e);
+					}
+				}
+				
+				public McRandomSource fromSeed(long seed) {
+					return new XoroshiroSource(seed ^ seedLo, seed ^ seedHi);
+				}
+			};
+		}
+		
+		public int nextInt() {
+			return (int) this.randomNumberGenerator.nextLong();
+		}
+		
+		public int nextInt(int bound) {
+			long i = Integer.toUnsignedLong(this.nextInt());
+			long j = i * (long) bound;
+			long k = j & 4294967295L;
+			
+			if (k < (long) bound) {
+				for(int l = Integer.remainderUnsigned(~bound + 1, bound); k < (long) l; k = j & 4294967295L) {
+					i = Integer.toUnsignedLong(this.nextInt());
+					j = i * (long) bound;
+				}
+			}
+			
+			long i1 = j >> 32;
+			return (int) i1;
+		}
+		
+		public long nextLong() {
+			return this.randomNumberGenerator.nextLong();
+		}
+		
+		public boolean nextBoolean() {
+			return (this.randomNumberGenerator.nextLong() & 1L) != 0L;
+		}
+		
+		long nextBits(int bits) {
+			return this.randomNumberGenerator.nextLong() >>> 64 - bits;
+		}
+		
+		public float nextFloat() {
+			return (float) this.nextBits(24) * 5.9604645E-8F;
+		}
+		
+		public double nextDouble() {
+			return (double) this.nextBits(53) * (double) 1.110223E-16F;
+		}
+		
+		public double nextGaussian() {
+			return this.gaussianSource.nextGaussian();
+		}
+		
+		public void consumeCount(int count) {
+			for(int i = 0; i < count; ++i) {
+				this.randomNumberGenerator.nextLong();
+			}
+		}
+	}
+	
+	/// A mix of every RandomSource draw, with floats and doubles as raw bits
+	static long[] randomSourceDraws(McRandomSource source) {
+		long[] draws = new long[24];
+		int i = 0;
+		
+		draws[i++] = source.nextInt();
+		draws[i++] = source.nextInt(10);
+		draws[i++] = source.nextInt(1 << 20);
+		draws[i++] = source.nextInt(2000000001);
+		draws[i++] = source.nextInt(2000000001);
+		draws[i++] = source.nextInt(2000000001);
+		draws[i++] = source.nextIntBetweenInclusive(-5, 5);
+		draws[i++] = source.nextLong();
+		draws[i++] = source.nextBoolean() ? 1 : 0;
+		draws[i++] = Float.floatToRawIntBits(source.nextFloat());
+		draws[i++] = Double.doubleToRawLongBits(source.nextDouble());
+		draws[i++] = Double.doubleToRawLongBits(source.nextGaussian());
+		draws[i++] = Double.doubleToRawLongBits(source.nextGaussian());
+		draws[i++] = Double.doubleToRawLongBits(source.nextGaussian());
+		draws[i++] = Double.doubleToRawLongBits(source.triangle(4.0, 2.5));
+		source.consumeCount(3);
+		draws[i++] = source.nextLong();
+		draws[i++] = source.fork().nextLong();
+		
+		McPositionalFactory factory = source.forkPositional();
+		
+		draws[i++] = factory.at(1, -2, 3).nextLong();
+		draws[i++] = factory.at(-30000000, 320, 29999999).nextLong();
+		draws[i++] = factory.fromHashOf("minecraft:ore_gold").nextLong();
+		draws[i++] = factory.fromSeed(7).nextLong();
+		draws[i++] = positionSeed(-30000000, 320, 29999999);
+		
+		PerlinNoise noise = new PerlinNoise(source, -3, new double[] { 1.0, 1.0, 0.0, 1.0 });
+		
+		draws[i++] = Double.doubleToRawLongBits(noise.getValue(1.5, 2.25, -3.75, 0.0, 0.0, false));
+		draws[i++] = source.nextLong();
+		
+		return draws;
+	}
+	
+	/// First output of std::mt19937 seeded with seed
+	static int mt19937First(int seed) {
+		int[] mt = new int[624];
+		mt[0] = seed;
+		
+		for(int i = 1; i < 624; i++) {
+			mt[i] = 1812433253 * (mt[i - 1] ^ (mt[i - 1] >>> 30)) + i;
+		}
+		
+		int y = (mt[0] & 0x80000000) | (mt[1] & 0x7FFFFFFF);
+		y = mt[397] ^ (y >>> 1) ^ ((y & 1) != 0 ? 0x9908B0DF : 0);
+		y ^= y >>> 11;
+		y ^= (y << 7) & 0x9D2C5680;
+		y ^= (y << 15) & 0xEFC60000;
+		return y ^ (y >>> 18);
+	}
+	
+	/// Bedrock Edition slime chunks
+	static boolean isSlimeChunkBedrock(int x, int z) {
+		return Integer.remainderUnsigned(mt19937First((x * 0x1F1F1F1F) ^ z), 10) == 0;
+	}
+	
+	/// Java Edition slime chunks, as in WorldgenRandom.seedSlimeChunk
+	static boolean isSlimeChunk(long seed, int x, int z) {
+		return new Random(seed + (long) (x * x * 4987142) + (long) (x * 5947611) + (long) (z * z) * 4392871L + (long) (z * 389711) ^ 987234911L).nextInt(10) == 0;
+	}
+	
+	/// EnchantmentHelper.getEnchantmentCost
+	static int getEnchantmentCost(Random random, int slot, int bookshelves, int enchantability) {
+		if (enchantability <= 0) {
+			return 0;
+		}
+		
+		if (bookshelves > 15) {
+			bookshelves = 15;
+		}
+		
+		int j = random.nextInt(8) + 1 + (bookshelves >> 1) + random.nextInt(bookshelves + 1);
+		
+		if (slot == 0) {
+			return Math.max(j / 3, 1);
+		} else {
+			return slot == 1 ? j * 2 / 3 + 1 : Math.max(j, bookshelves * 2);
+		}
+	}
+	
+	// A stand-in registry: enchantment i is available above level 4i, and 1 and 2 are mutually exclusive
+	static final int[] ENCHANTMENT_WEIGHTS = { 10, 5, 2, 1, 5, 2, 1 };
+	
+	static boolean compatible(int a, int b) {
+		return a != b && !(a + b == 3 && (a == 1 || a == 2));
+	}
+	
+	/// EnchantmentHelper.selectEnchantment over the stand-in registry
+	static java.util.List<Integer> selectEnchantment(Random random, int level, int enchantability) {
+		java.util.List<Integer> list = new java.util.ArrayList<>();
+		
+		if (enchantability <= 0) {
+			return list;
+		}
+		
+		level += 1 + random.nextInt(enchantability / 4 + 1) + random.nextInt(enchantability / 4 + 1);
+		float f = (random.nextFloat() + random.nextFloat() - 1.0F) * 0.15F;
+		level = Math.max(Math.round((float) level + (float) level * f), 1);
+		
+		java.util.List<Integer> list1 = new java.util.ArrayList<>();
+		
+		for(int i = 0; i < ENCHANTMENT_WEIGHTS.length; i++) {
+			if (level > 4 * i) {
+				list1.add(i);
+			}
+		}
+		
+		if (!list1.isEmpty()) {
+			list.add(getRandomItem(random, list1));
+			
+			while(random.nextInt(50) <= level) {
+				int last = list.get(list.size() - 1);
+				list1.removeIf(e -> !compatible(last, e));
+				
+				if (list1.isEmpty()) {
+					break;
+				}
+				
+				list.add(getRandomItem(random, list1));
+				level /= 2;
+			}
+		}
+		
+		return list;
+	}
+	
+	/// WeightedRandom.getRandomItem
+	static int getRandomItem(Random random, java.util.List<Integer> list) {
+		int total = 0;
+		
+		for(int e: list) {
+			total += ENCHANTMENT_WEIGHTS[e];
+		}
+		
+		int weight = random.nextInt(total);
+		
+		for(int e: list) {
+			weight -= ENCHANTMENT_WEIGHTS[e];
+			
+			if (weight < 0) {
+				return e;
+			}
+		}
+		
+		throw new IllegalStateException();
+	}
+	
+	/// Transcription of exp, log and pow of Commons Math 3.6.1 FastMath, with the tables computed by FastMathCalc at
+	/// class initialization, as FastMath does when RECOMPUTE_TABLES_AT_RUNTIME is set
+	static final class FastMath {
+		static final double LN_2_A = 0.693147063255310059;
+		static final double LN_2_B = 1.17304635250823482e-7;
+		static final double HEX_40000000 = 1073741824.0;
+		static final double TWO_POWER_52 = 4503599627370496.0;
+		static final int EXP_INT_TABLE_MAX_INDEX = 750;
+		static final int EXP_INT_TABLE_LEN = EXP_INT_TABLE_MAX_INDEX * 2;
+		static final int EXP_FRAC_TABLE_LEN = 1025;
+		static final int LN_MANT_LEN = 1024;
+		static final long MASK_DOUBLE_EXPONENT = 0x7ff0000000000000L;
+		static final long MASK_DOUBLE_MANTISSA = 0x000fffffffffffffL;
+		static final long IMPLICIT_HIGH_BIT = 0x0010000000000000L;
+	
+		static final double[][] LN_QUICK_COEF = {
+			{1.0, 5.669184079525E-24},
+			{-0.25, -0.25},
+			{0.3333333134651184, 1.986821492305628E-8},
+			{-0.25, -6.663542893624021E-14},
+			{0.19999998807907104, 1.1921056801463227E-8},
+			{-0.1666666567325592, -7.800414592973399E-9},
+			{0.1428571343421936, 5.650007086920087E-9},
+			{-0.12502530217170715, -7.44321345601866E-11},
+			{0.11113807559013367, 9.219544613762692E-9},
+		};
+	
+		static final double[][] LN_HI_PREC_COEF = {
+			{1.0, -6.032174644509064E-23},
+			{-0.25, -0.25},
+			{0.3333333134651184, 1.9868161777724352E-8},
+			{-0.2499999701976776, -2.957007209750105E-8},
+			{0.19999954104423523, 1.5830993332061267E-10},
+			{-0.16624879837036133, -2.6033824355191673E-8}
+		};
+	
+		static final double[] EXP_INT_TABLE_A = new double[EXP_INT_TABLE_LEN];
+		static final double[] EXP_INT_TABLE_B = new double[EXP_INT_TABLE_LEN];
+		static final double[] EXP_FRAC_TABLE_A = new double[EXP_FRAC_TABLE_LEN];
+		static final double[] EXP_FRAC_TABLE_B = new double[EXP_FRAC_TABLE_LEN];
+		static final double[][] LN_MANT = new double[LN_MANT_LEN][];
+	
+		static {
+			final double tmp[] = new double[2];
+			final double recip[] = new double[2];
+		
+			for (int i = 0; i < EXP_INT_TABLE_MAX_INDEX; i++) {
+				FastMathCalc.expint(i, tmp);
+				EXP_INT_TABLE_A[i + EXP_INT_TABLE_MAX_INDEX] = tmp[0];
+				EXP_INT_TABLE_B[i + EXP_INT_TABLE_MAX_INDEX] = tmp[1];
+			
+				if (i != 0) {
+					FastMathCalc.splitReciprocal(tmp, recip);
+					EXP_INT_TABLE_A[EXP_INT_TABLE_MAX_INDEX - i] = recip[0];
+					EXP_INT_TABLE_B[EXP_INT_TABLE_MAX_INDEX - i] = recip[1];
+				}
+			}
+		
+			final double factor = 1d / (EXP_FRAC_TABLE_LEN - 1);
+		
+			for (int i = 0; i < EXP_FRAC_TABLE_A.length; i++) {
+				FastMathCalc.slowexp(i * factor, tmp);
+				EXP_FRAC_TABLE_A[i] = tmp[0];
+				EXP_FRAC_TABLE_B[i] = tmp[1];
+			}
+		
+			for (int i = 0; i < LN_MANT_LEN; i++) {
+				final double d = Double.longBitsToDouble((((long) i) << 42) | 0x3ff0000000000000L);
+				LN_MANT[i] = FastMathCalc.slowLog(d);
+			}
+		}
+	
+		static double exp(double x) {
+			return exp(x, 0.0, null);
+		}
+	
+		static double exp(double x, double extra, double[] hiPrec) {
+			double intPartA;
+			double intPartB;
+			int intVal = (int) x;
+		
+			if (x < 0.0) {
+				if (x < -746d) {
+					if (hiPrec != null) {
+						hiPrec[0] = 0.0;
+						hiPrec[1] = 0.0;
+					}
+					return 0.0;
+				}
+			
+				if (intVal < -709) {
+					final double result = exp(x+40.19140625, extra, hiPrec) / 285040095144011776.0;
+					if (hiPrec != null) {
+						hiPrec[0] /= 285040095144011776.0;
+						hiPrec[1] /= 285040095144011776.0;
+					}
+					return result;
+				}
+			
+				if (intVal == -709) {
+					final double result = exp(x+1.494140625, extra, hiPrec) / 4.455505956692756620;
+					if (hiPrec != null) {
+						hiPrec[0] /= 4.455505956692756620;
+						hiPrec[1] /= 4.455505956692756620;
+					}
+					return result;
+				}
+			
+				intVal--;
+			} else {
+				if (intVal > 709) {
+					if (hiPrec != null) {
+						hiPrec[0] = Double.POSITIVE_INFINITY;
+						hiPrec[1] = 0.0;
+					}
+					return Double.POSITIVE_INFINITY;
+				}
+			}
+		
+			intPartA = EXP_INT_TABLE_A[EXP_INT_TABLE_MAX_INDEX+intVal];
+			intPartB = EXP_INT_TABLE_B[EXP_INT_TABLE_MAX_INDEX+intVal];
+		
+			final int intFrac = (int) ((x - intVal) * 1024.0);
+			final double fracPartA = EXP_FRAC_TABLE_A[intFrac];
+			final double fracPartB = EXP_FRAC_TABLE_B[intFrac];
+		
+			final double epsilon = x - (intVal + intFrac / 1024.0);
+		
+			double z = 0.04168701738764507;
+			z = z * epsilon + 0.1666666505023083;
+			z = z * epsilon + 0.5000000000042687;
+			z = z * epsilon + 1.0;
+			z = z * epsilon + -3.940510424527919E-20;
+		
+			double tempA = intPartA * fracPartA;
+			double tempB = intPartA * fracPartB + intPartB * fracPartA + intPartB * fracPartB;
+		
+			final double tempC = tempB + tempA;
+		
+			if (tempC == Double.POSITIVE_INFINITY) {
+				return Double.POSITIVE_INFINITY;
+			}
+		
+			final double result;
+			if (extra != 0.0) {
+				result = tempC*extra*z + tempC*extra + tempC*z + tempB + tempA;
+			} else {
+				result = tempC*z + tempB + tempA;
+			}
+		
+			if (hiPrec != null) {
+				hiPrec[0] = tempA;
+				hiPrec[1] = tempC*extra*z + tempC*extra + tempC*z + tempB;
+			}
+		
+			return result;
+		}
+	
+		static double log(final double x) {
+			return log(x, null);
+		}
+	
+		static double log(final double x, final double[] hiPrec) {
+			if (x==0) {
+				return Double.NEGATIVE_INFINITY;
+			}
+			long bits = Double.doubleToRawLongBits(x);
+		
+			if (((bits & 0x8000000000000000L) != 0 || x != x) && x != 0.0) {
+				if (hiPrec != null) {
+					hiPrec[0] = Double.NaN;
+				}
+				return Double.NaN;
+			}
+		
+			if (x == Double.POSITIVE_INFINITY) {
+				if (hiPrec != null) {
+					hiPrec[0] = Double.POSITIVE_INFINITY;
+				}
+				return Double.POSITIVE_INFINITY;
+			}
+		
+			int exp = (int)(bits >> 52)-1023;
+		
+			if ((bits & 0x7ff0000000000000L) == 0) {
+				if (x == 0) {
+					if (hiPrec != null) {
+						hiPrec[0] = Double.NEGATIVE_INFINITY;
+					}
+					return Double.NEGATIVE_INFINITY;
+				}
+			
+				bits <<= 1;
+				while ( (bits & 0x0010000000000000L) == 0) {
+					--exp;
+					bits <<= 1;
+				}
+			}
+		
+			if ((exp == -1 || exp == 0) && x < 1.01 && x > 0.99 && hiPrec == null) {
+				double xa = x - 1.0;
+				double xb = xa - x + 1.0;
+				double tmp = xa * HEX_40000000;
+				double aa = xa + tmp - tmp;
+				double ab = xa - aa;
+				xa = aa;
+				xb = ab;
+			
+				final double[] lnCoef_last = LN_QUICK_COEF[LN_QUICK_COEF.length - 1];
+				double ya = lnCoef_last[0];
+				double yb = lnCoef_last[1];
+			
+				for (int i = LN_QUICK_COEF.length - 2; i >= 0; i--) {
+					aa = ya * xa;
+					ab = ya * xb + yb * xa + yb * xb;
+					tmp = aa * HEX_40000000;
+					ya = aa + tmp - tmp;
+					yb = aa - ya + ab;
+				
+					final double[] lnCoef_i = LN_QUICK_COEF[i];
+					aa = ya + lnCoef_i[0];
+					ab = yb + lnCoef_i[1];
+					tmp = aa * HEX_40000000;
+					ya = aa + tmp - tmp;
+					yb = aa - ya + ab;
+				}
+			
+				aa = ya * xa;
+				ab = ya * xb + yb * xa + yb * xb;
+				tmp = aa * HEX_40000000;
+				ya = aa + tmp - tmp;
+				yb = aa - ya + ab;
+			
+				return ya + yb;
+			}
+		
+			final double[] lnm = LN_MANT[(int)((bits & 0x000ffc0000000000L) >> 42)];
+		
+			final double epsilon = (bits & 0x3ffffffffffL) / (TWO_POWER_52 + (bits & 0x000ffc0000000000L));
+		
+			double lnza = 0.0;
+			double lnzb = 0.0;
+		
+			if (hiPrec != null) {
+				double tmp = epsilon * HEX_40000000;
+				double aa = epsilon + tmp - tmp;
+				double ab = epsilon - aa;
+				double xa = aa;
+				double xb = ab;
+			
+				final double numer = bits & 0x3ffffffffffL;
+				final double denom = TWO_POWER_52 + (bits & 0x000ffc0000000000L);
+				aa = numer - xa*denom - xb * denom;
+				xb += aa / denom;
+			
+				final double[] lnCoef_last = LN_HI_PREC_COEF[LN_HI_PREC_COEF.length-1];
+				double ya = lnCoef_last[0];
+				double yb = lnCoef_last[1];
+			
+				for (int i = LN_HI_PREC_COEF.length - 2; i >= 0; i--) {
+					aa = ya * xa;
+					ab = ya * xb + yb * xa + yb * xb;
+					tmp = aa * HEX_40000000;
+					ya = aa + tmp - tmp;
+					yb = aa - ya + ab;
+				
+					final double[] lnCoef_i = LN_HI_PREC_COEF[i];
+					aa = ya + lnCoef_i[0];
+					ab = yb + lnCoef_i[1];
+					tmp = aa * HEX_40000000;
+					ya = aa + tmp - tmp;
+					yb = aa - ya + ab;
+				}
+			
+				aa = ya * xa;
+				ab = ya * xb + yb * xa + yb * xb;
+			
+				lnza = aa + ab;
+				lnzb = -(lnza - aa - ab);
+			} else {
+				lnza = -0.16624882440418567;
+				lnza = lnza * epsilon + 0.19999954120254515;
+				lnza = lnza * epsilon + -0.2499999997677497;
+				lnza = lnza * epsilon + 0.3333333333332802;
+				lnza = lnza * epsilon + -0.5;
+				lnza = lnza * epsilon + 1.0;
+				lnza *= epsilon;
+			}
+		
+			double a = LN_2_A*exp;
+			double b = 0.0;
+			double c = a+lnm[0];
+			double d = -(c-a-lnm[0]);
+			a = c;
+			b += d;
+		
+			c = a + lnza;
+			d = -(c - a - lnza);
+			a = c;
+			b += d;
+		
+			c = a + LN_2_B*exp;
+			d = -(c - a - LN_2_B*exp);
+			a = c;
+			b += d;
+		
+			c = a + lnm[1];
+			d = -(c - a - lnm[1]);
+			a = c;
+			b += d;
+		
+			c = a + lnzb;
+			d = -(c - a - lnzb);
+			a = c;
+			b += d;
+		
+			if (hiPrec != null) {
+				hiPrec[0] = a;
+				hiPrec[1] = b;
+			}
+		
+			return a + b;
+		}
+	
+		static double pow(final double x, final double y) {
+			if (y == 0) {
+				return 1.0;
+			} else {
+				final long yBits        = Double.doubleToRawLongBits(y);
+				final int  yRawExp      = (int) ((yBits & MASK_DOUBLE_EXPONENT) >> 52);
+				final long yRawMantissa = yBits & MASK_DOUBLE_MANTISSA;
+				final long xBits        = Double.doubleToRawLongBits(x);
+				final int  xRawExp      = (int) ((xBits & MASK_DOUBLE_EXPONENT) >> 52);
+				final long xRawMantissa = xBits & MASK_DOUBLE_MANTISSA;
+			
+				if (yRawExp > 1085) {
+					if ((yRawExp == 2047 && yRawMantissa != 0) ||
+						(xRawExp == 2047 && xRawMantissa != 0)) {
+						return Double.NaN;
+					} else if (xRawExp == 1023 && xRawMantissa == 0) {
+						if (yRawExp == 2047) {
+							return Double.NaN;
+						} else {
+							return 1.0;
+						}
+					} else {
+						if ((y > 0) ^ (xRawExp < 1023)) {
+							return Double.POSITIVE_INFINITY;
+						} else {
+							return +0.0;
+						}
+					}
+				} else {
+					if (yRawExp >= 1023) {
+						final long yFullMantissa = IMPLICIT_HIGH_BIT | yRawMantissa;
+						if (yRawExp < 1075) {
+							final long integralMask = (-1L) << (1075 - yRawExp);
+							if ((yFullMantissa & integralMask) == yFullMantissa) {
+								final long l = yFullMantissa >> (1075 - yRawExp);
+								return pow(x, (y < 0) ? -l : l);
+							}
+						} else {
+							final long l =  yFullMantissa << (yRawExp - 1075);
+							return pow(x, (y < 0) ? -l : l);
+						}
+					}
+				
+					if (x == 0) {
+						return y < 0 ? Double.POSITIVE_INFINITY : +0.0;
+					} else if (xRawExp == 2047) {
+						if (xRawMantissa == 0) {
+							return (y < 0) ? +0.0 : Double.POSITIVE_INFINITY;
+						} else {
+							return Double.NaN;
+						}
+					} else if (x < 0) {
+						return Double.NaN;
+					} else {
+						final double tmp = y * HEX_40000000;
+						final double ya = (y + tmp) - tmp;
+						final double yb = y - ya;
+					
+						final double lns[] = new double[2];
+						final double lores = log(x, lns);
+						if (Double.isInfinite(lores)) {
+							return lores;
+						}
+					
+						double lna = lns[0];
+						double lnb = lns[1];
+					
+						final double tmp1 = lna * HEX_40000000;
+						final double tmp2 = (lna + tmp1) - tmp1;
+						lnb += lna - tmp2;
+						lna = tmp2;
+					
+						final double aa = lna * ya;
+						final double ab = lna * yb + lnb * ya + lnb * yb;
+					
+						lna = aa+ab;
+						lnb = -(lna - aa - ab);
+					
+						double z = 1.0 / 120.0;
+						z = z * lnb + (1.0 / 24.0);
+						z = z * lnb + (1.0 / 6.0);
+						z = z * lnb + 0.5;
+						z = z * lnb + 1.0;
+						z *= lnb;
+					
+						return exp(lna, z, null);
+					}
+				}
+			}
+		}
+	
+		static double pow(double d, int e) {
+			return pow(d, (long) e);
+		}
+	
+		static double pow(double d, long e) {
+			if (e == 0) {
+				return 1.0;
+			} else if (e > 0) {
+				return new Split(d).pow(e).full;
+			} else {
+				return new Split(d).reciprocal().pow(-e).full;
+			}
+		}
+	
+		static final class Split {
+			static final Split NAN = new Split(Double.NaN, 0);
+			static final Split POSITIVE_INFINITY = new Split(Double.POSITIVE_INFINITY, 0);
+			static final Split NEGATIVE_INFINITY = new Split(Double.NEGATIVE_INFINITY, 0);
+		
+			final double full;
+			final double high;
+			final double low;
+		
+			Split(final double x) {
+				full = x;
+				high = Double.longBitsToDouble(Double.doubleToRawLongBits(x) & ((-1L) << 27));
+				low  = x - high;
+			}
+		
+			Split(final double high, final double low) {
+				this(high == 0.0 ? (low == 0.0 && Double.doubleToRawLongBits(high) == Long.MIN_VALUE ? -0.0 : low) : high + low, high, low);
+			}
+		
+			Split(final double full, final double high, final double low) {
+				this.full = full;
+				this.high = high;
+				this.low  = low;
+			}
+		
+			Split multiply(final Split b) {
+				final Split  mulBasic  = new Split(full * b.full);
+				final double mulError  = low * b.low - (((mulBasic.full - high * b.high) - low * b.high) - high * b.low);
+				return new Split(mulBasic.high, mulBasic.low + mulError);
+			}
+		
+			Split reciprocal() {
+				final double approximateInv = 1.0 / full;
+				final Split  splitInv       = new Split(approximateInv);
+				final Split product = multiply(splitInv);
+				final double error  = (product.high - 1) + product.low;
+				return Double.isNaN(error) ? splitInv : new Split(splitInv.high, splitInv.low - error / full);
+			}
+		
+			Split pow(final long e) {
+				Split result = new Split(1);
+				Split d2p = new Split(full, high, low);
+			
+				for (long p = e; p != 0; p >>>= 1) {
+					if ((p & 0x1) != 0) {
+						result = result.multiply(d2p);
+					}
+					d2p = d2p.multiply(d2p);
+				}
+			
+				if (Double.isNaN(result.full)) {
+					if (Double.isNaN(full)) {
+						return Split.NAN;
+					} else {
+						if (Math.abs(full) < 1) {
+							return new Split(Math.copySign(0.0, full), 0.0);
+						} else if (full < 0 && (e & 0x1) == 1) {
+							return Split.NEGATIVE_INFINITY;
+						} else {
+							return Split.POSITIVE_INFINITY;
+						}
+					}
+				} else {
+					return result;
+				}
+			}
+		}
+	}
+
+	/// Transcription of the table computations of Commons Math 3.6.1 FastMathCalc
+	static final class FastMathCalc {
+		static final double HEX_40000000 = 1073741824.0;
+	
+		static final double FACT[] = new double[] {
+			+1.0d, +1.0d, +2.0d, +6.0d, +24.0d, +120.0d, +720.0d, +5040.0d, +40320.0d, +362880.0d, +3628800.0d,
+			+39916800.0d, +479001600.0d, +6227020800.0d, +87178291200.0d, +1307674368000.0d, +20922789888000.0d,
+			+355687428096000.0d, +6402373705728000.0d, +121645100408832000.0d,
+		};
+	
+		static final double LN_SPLIT_COEF[][] = {
+			{2.0, 0.0},
+			{0.6666666269302368, 3.9736429850260626E-8},
+			{0.3999999761581421, 2.3841857910019882E-8},
+			{0.2857142686843872, 1.7029898543501842E-8},
+			{0.2222222089767456, 1.3245471311735498E-8},
+			{0.1818181574344635, 2.4384203044354907E-8},
+			{0.1538461446762085, 9.140260083262505E-9},
+			{0.13333332538604736, 9.220590270857665E-9},
+			{0.11764700710773468, 1.2393345855018391E-8},
+			{0.10526403784751892, 8.251545029714408E-9},
+			{0.0952233225107193, 1.2675934823758863E-8},
+			{0.08713622391223907, 1.1430250008909141E-8},
+			{0.07842259109020233, 2.404307984052299E-9},
+			{0.08371849358081818, 1.176342548272881E-8},
+			{0.030589580535888672, 1.2958646899018938E-9},
+			{0.14982303977012634, 1.225743062930824E-8},
+		};
+	
+		static double slowexp(final double x, final double result[]) {
+			final double xs[] = new double[2];
+			final double ys[] = new double[2];
+			final double facts[] = new double[2];
+			final double as[] = new double[2];
+			split(x, xs);
+			ys[0] = ys[1] = 0.0;
+		
+			for (int i = FACT.length-1; i >= 0; i--) {
+				splitMult(xs, ys, as);
+				ys[0] = as[0];
+				ys[1] = as[1];
+			
+				split(FACT[i], as);
+				splitReciprocal(as, facts);
+			
+				splitAdd(ys, facts, as);
+				ys[0] = as[0];
+				ys[1] = as[1];
+			}
+		
+			if (result != null) {
+				result[0] = ys[0];
+				result[1] = ys[1];
+			}
+		
+			return ys[0] + ys[1];
+		}
+	
+		static void split(final double d, final double split[]) {
+			if (d < 8e298 && d > -8e298) {
+				final double a = d * HEX_40000000;
+				split[0] = (d + a) - a;
+				split[1] = d - split[0];
+			} else {
+				final double a = d * 9.31322574615478515625E-10;
+				split[0] = (d + a - d) * HEX_40000000;
+				split[1] = d - split[0];
+			}
+		}
+	
+		static void resplit(final double a[]) {
+			final double c = a[0] + a[1];
+			final double d = -(c - a[0] - a[1]);
+		
+			if (c < 8e298 && c > -8e298) {
+				double z = c * HEX_40000000;
+				a[0] = (c + z) - z;
+				a[1] = c - a[0] + d;
+			} else {
+				double z = c * 9.31322574615478515625E-10;
+				a[0] = (c + z - c) * HEX_40000000;
+				a[1] = c - a[0] + d;
+			}
+		}
+	
+		static void splitMult(double a[], double b[], double ans[]) {
+			ans[0] = a[0] * b[0];
+			ans[1] = a[0] * b[1] + a[1] * b[0] + a[1] * b[1];
+			resplit(ans);
+		}
+	
+		static void splitAdd(final double a[], final double b[], final double ans[]) {
+			ans[0] = a[0] + b[0];
+			ans[1] = a[1] + b[1];
+			resplit(ans);
+		}
+	
+		static void splitReciprocal(final double in[], final double result[]) {
+			final double b = 1.0/4194304.0;
+			final double a = 1.0 - b;
+		
+			if (in[0] == 0.0) {
+				in[0] = in[1];
+				in[1] = 0.0;
+			}
+		
+			result[0] = a / in[0];
+			result[1] = (b*in[0]-a*in[1]) / (in[0]*in[0] + in[0]*in[1]);
+		
+			if (result[1] != result[1]) {
+				result[1] = 0.0;
+			}
+		
+			resplit(result);
+		
+			for (int i = 0; i < 2; i++) {
+				double err = 1.0 - result[0] * in[0] - result[0] * in[1] -
+					result[1] * in[0] - result[1] * in[1];
+				err *= result[0] + result[1];
+				result[1] += err;
+			}
+		}
+	
+		static void quadMult(final double a[], final double b[], final double result[]) {
+			final double xs[] = new double[2];
+			final double ys[] = new double[2];
+			final double zs[] = new double[2];
+		
+			split(a[0], xs);
+			split(b[0], ys);
+			splitMult(xs, ys, zs);
+		
+			result[0] = zs[0];
+			result[1] = zs[1];
+		
+			split(b[1], ys);
+			splitMult(xs, ys, zs);
+		
+			double tmp = result[0] + zs[0];
+			result[1] -= tmp - result[0] - zs[0];
+			result[0] = tmp;
+			tmp = result[0] + zs[1];
+			result[1] -= tmp - result[0] - zs[1];
+			result[0] = tmp;
+		
+			split(a[1], xs);
+			split(b[0], ys);
+			splitMult(xs, ys, zs);
+		
+			tmp = result[0] + zs[0];
+			result[1] -= tmp - result[0] - zs[0];
+			result[0] = tmp;
+			tmp = result[0] + zs[1];
+			result[1] -= tmp - result[0] - zs[1];
+			result[0] = tmp;
+		
+			split(a[1], xs);
+			split(b[1], ys);
+			splitMult(xs, ys, zs);
+		
+			tmp = result[0] + zs[0];
+			result[1] -= tmp - result[0] - zs[0];
+			result[0] = tmp;
+			tmp = result[0] + zs[1];
+			result[1] -= tmp - result[0] - zs[1];
+			result[0] = tmp;
+		}
+	
+		static double expint(int p, final double result[]) {
+			final double xs[] = new double[2];
+			final double as[] = new double[2];
+			final double ys[] = new double[2];
+		
+			xs[0] = 2.718281828459045;
+			xs[1] = 1.4456468917292502E-16;
+		
+			split(1.0, ys);
+		
+			while (p > 0) {
+				if ((p & 1) != 0) {
+					quadMult(ys, xs, as);
+					ys[0] = as[0]; ys[1] = as[1];
+				}
+			
+				quadMult(xs, xs, as);
+				xs[0] = as[0]; xs[1] = as[1];
+			
+				p >>= 1;
+			}
+		
+			if (result != null) {
+				result[0] = ys[0];
+				result[1] = ys[1];
+			
+				resplit(result);
+			}
+		
+			return ys[0] + ys[1];
+		}
+	
+		static double[] slowLog(double xi) {
+			double x[] = new double[2];
+			double x2[] = new double[2];
+			double y[] = new double[2];
+			double a[] = new double[2];
+		
+			split(xi, x);
+		
+			x[0] += 1.0;
+			resplit(x);
+			splitReciprocal(x, a);
+			x[0] -= 2.0;
+			resplit(x);
+			splitMult(x, a, y);
+			x[0] = y[0];
+			x[1] = y[1];
+		
+			splitMult(x, x, x2);
+		
+			y[0] = LN_SPLIT_COEF[LN_SPLIT_COEF.length-1][0];
+			y[1] = LN_SPLIT_COEF[LN_SPLIT_COEF.length-1][1];
+		
+			for (int i = LN_SPLIT_COEF.length-2; i >= 0; i--) {
+				splitMult(y, x2, a);
+				y[0] = a[0];
+				y[1] = a[1];
+				splitAdd(y, LN_SPLIT_COEF[i], a);
+				y[0] = a[0];
+				y[1] = a[1];
+			}
+		
+			splitMult(y, x, a);
+			y[0] = a[0];
+			y[1] = a[1];
+		
+			return y;
+		}
+	}
+	
+	/// Transcription of the Commons Math 3.6.1 samplers behind RandomDataGenerator. FastMath.sqrt, floor and ceil are
+	/// those of Math, so StrictMath stands in for them
+	static class CommonsMath {
+		static final double[] EXPONENTIAL_SA_QI;
+		static final long[] FACTORIALS = new long[21];
+		
+		static {
+			FACTORIALS[0] = 1;
+			
+			for(int i = 1; i < 21; i++) {
+				FACTORIALS[i] = FACTORIALS[i - 1] * i;
+			}
+			
+			final double LN2 = FastMath.log(2);
+			double qi = 0;
+			int i = 1;
+			java.util.ArrayList<Double> ra = new java.util.ArrayList<>();
+			
+			while (qi < 1) {
+				qi += FastMath.pow(LN2, i) / FACTORIALS[i];
+				ra.add(qi);
+				++i;
+			}
+			
+			EXPONENTIAL_SA_QI = ra.stream().mapToDouble(Double::doubleValue).toArray();
+		}
+		
+		final Random random;
+		
+		CommonsMath(Random random) {
+			this.random = random;
+		}
+		
+		double exponential(double mean) {
+			double a = 0;
+			double u = random.nextDouble();
+			
+			while (u < 0.5) {
+				a += EXPONENTIAL_SA_QI[0];
+				u *= 2;
+			}
+			
+			u += u - 1;
+			
+			if (u <= EXPONENTIAL_SA_QI[0]) {
+				return mean * (a + u);
+			}
+			
+			int i = 0;
+			double u2 = random.nextDouble();
+			double umin = u2;
+			
+			do {
+				++i;
+				u2 = random.nextDouble();
+				
+				if (u2 < umin) {
+					umin = u2;
+				}
+			} while (u > EXPONENTIAL_SA_QI[i]);
+			
+			return mean * (a + umin * EXPONENTIAL_SA_QI[0]);
+		}
+		
+		double gamma(double shape, double scale) {
+			if (shape < 1) {
+				while (true) {
+					final double u = random.nextDouble();
+					final double bGS = 1 + shape / Math.E;
+					final double p = bGS * u;
+					
+					if (p <= 1) {
+						final double x = FastMath.pow(p, 1 / shape);
+						final double u2 = random.nextDouble();
+						
+						if (u2 > FastMath.exp(-x)) {
+							continue;
+						} else {
+							return scale * x;
+						}
+					} else {
+						final double x = -1 * FastMath.log((bGS - p) / shape);
+						final double u2 = random.nextDouble();
+						
+						if (u2 > FastMath.pow(x, shape - 1)) {
+							continue;
+						} else {
+							return scale * x;
+						}
+					}
+				}
+			}
+			
+			final double d = shape - 0.333333333333333333;
+			final double c = 1 / (3 * StrictMath.sqrt(d));
+			
+			while (true) {
+				final double x = random.nextGaussian();
+				final double v = (1 + c * x) * (1 + c * x) * (1 + c * x);
+				
+				if (v <= 0) {
+					continue;
+				}
+				
+				final double x2 = x * x;
+				final double u = random.nextDouble();
+				
+				if (u < 1 - 0.0331 * x2 * x2) {
+					return scale * d * v;
+				}
+				
+				if (FastMath.log(u) < 0.5 * x2 + d * (1 - v + FastMath.log(v))) {
+					return scale * d * v;
+				}
+			}
+		}
+		
+		static double factorialLog(int n) {
+			if (n < 21) {
+				return FastMath.log(FACTORIALS[n]);
+			}
+			
+			double logSum = 0;
+			
+			for (int i = 2; i <= n; i++) {
+				logSum += FastMath.log(i);
+			}
+			
+			return logSum;
+		}
+		
+		long poisson(double meanPoisson) {
+			return (long) (int) Math.min(nextPoisson(meanPoisson), Integer.MAX_VALUE);
+		}
+		
+		long nextPoisson(double meanPoisson) {
+			final double pivot = 40.0d;
+			
+			if (meanPoisson < pivot) {
+				double p = FastMath.exp(-meanPoisson);
+				long n = 0;
+				double r = 1.0d;
+				double rnd = 1.0d;
+				
+				while (n < 1000 * meanPoisson) {
+					rnd = random.nextDouble();
+					r *= rnd;
+					
+					if (r >= p) {
+						n++;
+					} else {
+						return n;
+					}
+				}
+				
+				return n;
+			} else {
+				final double lambda = StrictMath.floor(meanPoisson);
+				final double lambdaFractional = meanPoisson - lambda;
+				final double logLambda = FastMath.log(lambda);
+				final double logLambdaFactorial = factorialLog((int) lambda);
+				final long y2 = lambdaFractional < Double.MIN_VALUE ? 0 : nextPoisson(lambdaFractional);
+				final double delta = StrictMath.sqrt(lambda * FastMath.log(32 * lambda / Math.PI + 1));
+				final double halfDelta = delta / 2;
+				final double twolpd = 2 * lambda + delta;
+				final double a1 = StrictMath.sqrt(Math.PI * twolpd) * FastMath.exp(1 / (8 * lambda));
+				final double a2 = (twolpd / delta) * FastMath.exp(-delta * (1 + delta) / twolpd);
+				final double aSum = a1 + a2 + 1;
+				final double p1 = a1 / aSum;
+				final double p2 = a2 / aSum;
+				final double c1 = 1 / (8 * lambda);
+				
+				double x = 0;
+				double y = 0;
+				double v = 0;
+				int a = 0;
+				double t = 0;
+				double qr = 0;
+				double qa = 0;
+				
+				for (;;) {
+					final double u = random.nextDouble();
+					
+					if (u <= p1) {
+						final double n = random.nextGaussian();
+						x = n * StrictMath.sqrt(lambda + halfDelta) - 0.5d;
+						
+						if (x > delta || x < -lambda) {
+							continue;
+						}
+						
+						y = x < 0 ? StrictMath.floor(x) : StrictMath.ceil(x);
+						final double e = exponential(1.0);
+						v = -e - (n * n / 2) + c1;
+					} else {
+						if (u > p1 + p2) {
+							y = lambda;
+							break;
+						} else {
+							x = delta + (twolpd / delta) * exponential(1.0);
+							y = StrictMath.ceil(x);
+							v = -exponential(1.0) - delta * (x + 1) / twolpd;
+						}
+					}
+					
+					a = x < 0 ? 1 : 0;
+					t = y * (y + 1) / (2 * lambda);
+					
+					if (v < -t && a == 0) {
+						y = lambda + y;
+						break;
+					}
+					
+					qr = t * ((2 * y + 1) / (6 * lambda) - 1);
+					qa = qr - (t * t) / (3 * (lambda + a * (y + 1)));
+					
+					if (v < qa) {
+						y = lambda + y;
+						break;
+					}
+					
+					if (v > qr) {
+						continue;
+					}
+					
+					if (v < y * logLambda - factorialLog((int) (y + lambda)) + logLambdaFactorial) {
+						y = lambda + y;
+						break;
+					}
+				}
+				
+				return y2 + (long) y;
+			}
+		}
+		
+		String hexString(int len) {
+			StringBuilder outBuffer = new StringBuilder();
+			byte[] randomBytes = new byte[(len / 2) + 1];
+			random.nextBytes(randomBytes);
+			
+			for (int i = 0; i < randomBytes.length; i++) {
+				Integer c = Integer.valueOf(randomBytes[i]);
+				String hex = Integer.toHexString(c.intValue() + 128);
+				
+				if (hex.length() == 1) {
+					hex = "0" + hex;
+				}
+				
+				outBuffer.append(hex);
+			}
+			
+			return outBuffer.toString().substring(0, len);
+		}
+	}
+	
+	/// Transcription of Commons RNG's JDKRandom provider and the draws it inherits from IntProvider
+	static class CommonsRngJdk {
+		final Random delegate;
+		int booleanSource = 1;
+		
+		CommonsRngJdk(long seed) {
+			delegate = new Random(seed);
+		}
+		
+		int nextInt() {
+			return delegate.nextInt();
+		}
+		
+		long nextLong() {
+			return ((long) nextInt() << 32) | (nextInt() & 0xffffffffL);
+		}
+		
+		boolean nextBoolean() {
+			int bits = booleanSource;
+			
+			if (bits == 1) {
+				bits = nextInt();
+				booleanSource = Integer.MIN_VALUE | (bits >>> 1);
+				return (bits & 0x1) == 1;
+			}
+			
+			booleanSource = bits >>> 1;
+			return (bits & 0x1) == 1;
+		}
+		
+		float nextFloat() {
+			return (nextInt() >>> 8) * 0x1.0p-24f;
+		}
+		
+		double nextDouble() {
+			final long high = ((long) (nextInt() >>> 6)) << 27;
+			final int low = nextInt() >>> 5;
+			return (high | low) * 0x1.0p-53d;
+		}
+		
+		int nextInt(int n) {
+			long m = (nextInt() & 0xffffffffL) * n;
+			long l = m & 0xffffffffL;
+			
+			if (l < n) {
+				final long t = (1L << 32) % n;
+				
+				while (l < t) {
+					m = (nextInt() & 0xffffffffL) * n;
+					l = m & 0xffffffffL;
+				}
+			}
+			
+			return (int) (m >>> 32);
+		}
+		
+		long nextLong(long n) {
+			long bits;
+			long val;
+			
+			do {
+				bits = nextLong() >>> 1;
+				val = bits % n;
+			} while (bits - val + (n - 1) < 0);
+			
+			return val;
+		}
+		
+		byte[] saveState() throws java.io.IOException {
+			ByteArrayOutputStream bos = new ByteArrayOutputStream();
+			ObjectOutputStream oos = new ObjectOutputStream(bos);
+			oos.writeObject(delegate);
+			oos.close();
+			
+			byte[] serialized = bos.toByteArray();
+			return java.nio.ByteBuffer.allocate(8 + serialized.length).putInt(serialized.length).put(serialized).putInt(booleanSource).array();
+		}
+	}
+	
+	/// The mixing functions of jdk.internal.util.random.RandomSupport, which is not exported from java.base
+	static long mixMurmur64(long z) {
+		z = (z ^ (z >>> 33)) * 0xff51afd7ed558ccdL;
+		z = (z ^ (z >>> 33)) * 0xc4ceb9fe1a85ec53L;
+		return z ^ (z >>> 33);
+	}
+	
+	static long mixStafford13(long z) {
+		z = (z ^ (z >>> 30)) * 0xbf58476d1ce4e5b9L;
+		z = (z ^ (z >>> 27)) * 0x94d049bb133111ebL;
+		return z ^ (z >>> 31);
+	}
+	
+	static long mixLea64(long z) {
+		z = (z ^ (z >>> 32)) * 0xdaba0b6eb09322e3L;
+		z = (z ^ (z >>> 32)) * 0xdaba0b6eb09322e3L;
+		return z ^ (z >>> 32);
+	}
+	
+	static int mixMurmur32(int z) {
+		z = (z ^ (z >>> 16)) * 0x85ebca6b;
+		z = (z ^ (z >>> 13)) * 0xc2b2ae35;
+		return z ^ (z >>> 16);
+	}
+	
+	static int mixLea32(int z) {
+		z = (z ^ (z >>> 16)) * 0xd36d884b;
+		z = (z ^ (z >>> 16)) * 0xd36d884b;
+		return z ^ (z >>> 16);
+	}
+	
+	static long mixGamma(long z) {
+		z = (z ^ (z >>> 33)) * 0xff51afd7ed558ccdL;
+		z = (z ^ (z >>> 33)) * 0xc4ceb9fe1a85ec53L;
+		z = (z ^ (z >>> 33)) | 1L;
+		int n = Long.bitCount(z ^ (z >>> 1));
+		return (n < 24) ? z ^ 0xaaaaaaaaaaaaaaaaL : z;
+	}
+	
+	static String rustString(String string) {
+		StringBuilder builder = new StringBuilder();
+		
+		for(char c : string.toCharArray()) {
+			if(c < 128) {
+				builder.append(c);
+			} else {
+				builder.append(String.format("\\u{%x}", (int) c));
+			}
+		}
+		
+		return builder.toString();
+	}
+	
+	static java.util.random.RandomGenerator createSequence(long worldSeed, String id) {
+		long lo = worldSeed ^ 0x6A09E667F3BCC909L;
+		long hi = lo + 0x9E3779B97F4A7C15L;
+		
+		if(id != null) {
+			try {
+				byte[] digest = java.security.MessageDigest.getInstance("MD5").digest(id.getBytes(java.nio.charset.StandardCharsets.UTF_8));
+				java.nio.ByteBuffer buffer = java.nio.ByteBuffer.wrap(digest);
+				lo ^= buffer.getLong();
+				hi ^= buffer.getLong();
+			} catch(java.security.NoSuchAlgorithmException e) {
+				throw new AssertionError(e);
+			}
+		}
+		
+		return new Xoroshiro128(Xoroshiro128.PLUS_PLUS, Xoroshiro128.mixStafford13(lo), Xoroshiro128.mixStafford13(hi));
+	}
+	
+	static double nextFiniteBits(Random rand) {
+		double x;
+		
+		do {
+			x = Double.longBitsToDouble(rand.nextLong());
+		} while(Double.isNaN(x) || Double.isInfinite(x));
+		
+		return x;
+	}
+	
+	static void printStrictMath(String name, long seed, java.util.function.DoubleUnaryOperator function, java.util.function.ToDoubleFunction<Random> range) {
+		Random rand = new Random(seed);
+		System.out.println("// (input, output) f64 bit representations");
+		System.out.print("pub const STRICTMATH_" + name + ": [(u64, u64); 256] = [");
+		
+		for(int i = 0; i < 256; i++) {
+			double x = i < 128 ? nextFiniteBits(rand) : range.applyAsDouble(rand);
+			
+			if(i % 2 == 0) {
+				System.out.print("\n\t");
+			}
+			
+			System.out.printf("(0x%016X, 0x%016X), ", Double.doubleToRawLongBits(x), Double.doubleToRawLongBits(function.applyAsDouble(x)));
+		}
+		
+		System.out.println("\n];");
+	}
+	
+	static void printStrictMath2(String name, long seed, java.util.function.DoubleBinaryOperator function, java.util.function.ToDoubleFunction<Random> rangeX, java.util.function.ToDoubleFunction<Random> rangeY) {
+		Random rand = new Random(seed);
+		System.out.println("// (x, y, output) f64 bit representations");
+		System.out.print("pub const STRICTMATH_" + name + ": [(u64, u64, u64); 256] = [");
+		
+		for(int i = 0; i < 256; i++) {
+			double x = i < 64 ? nextFiniteBits(rand) : rangeX.applyAsDouble(rand);
+			double y = i < 64 ? nextFiniteBits(rand) : rangeY.applyAsDouble(rand);
+			
+			System.out.printf("\n\t(0x%016X, 0x%016X, 0x%016X),", Double.doubleToRawLongBits(x), Double.doubleToRawLongBits(y), Double.doubleToRawLongBits(function.applyAsDouble(x, y)));
+		}
+		
+		System.out.println("\n];");
 	}
 }
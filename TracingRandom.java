@@ -0,0 +1,170 @@
+import java.io.PrintStream;
+import java.util.Random;
+
+/**
+ * Drop-in replacement for java.util.Random writing every call made to it as a line of the trace format of the
+ * java_rand::exchange module, so that the calls of the Java program and of its Rust port can be compared.
+ *
+ * Calls made by the methods of Random to each other, such as nextGaussian calling nextDouble, are not written. Only
+ * the calls made by the program are, with the index counting them from 0.
+ *
+ * Running the class records a short trace of every method, as an example:
+ *
+ *     java TracingRandom.java 42 > trace.jsonl
+ */
+class TracingRandom extends Random {
+	private final PrintStream out;
+	private long index = 0;
+	private int depth = 0;
+
+	TracingRandom(long seed, PrintStream out) {
+		super(seed);
+		this.out = out;
+	}
+
+	private void enter() {
+		depth++;
+	}
+
+	private void exit(String method, String args, String result) {
+		depth--;
+
+		if(depth == 0 && out != null) {
+			out.println("{\"index\":" + index + ",\"method\":\"" + method + "\",\"args\":[" + args + "],"
+				+ "\"result\":" + result + "}");
+			index++;
+		}
+	}
+
+	@Override
+	protected int next(int bits) {
+		// Called by the other methods of Random while they are running, which then write their own line.
+		enter();
+		int value = super.next(bits);
+		exit("next", Integer.toString(bits), Integer.toString(value));
+		return value;
+	}
+
+	@Override
+	public void nextBytes(byte[] bytes) {
+		enter();
+		super.nextBytes(bytes);
+
+		StringBuilder result = new StringBuilder("[");
+
+		for(int i = 0; i < bytes.length; i++) {
+			result.append(i == 0 ? "" : ",").append(bytes[i]);
+		}
+
+		exit("nextBytes", Integer.toString(bytes.length), result.append("]").toString());
+	}
+
+	@Override
+	public int nextInt() {
+		enter();
+		int value = super.nextInt();
+		exit("nextInt", "", Integer.toString(value));
+		return value;
+	}
+
+	@Override
+	public int nextInt(int bound) {
+		enter();
+
+		try {
+			int value = super.nextInt(bound);
+			exit("nextInt", Integer.toString(bound), Integer.toString(value));
+			return value;
+		} catch(IllegalArgumentException e) {
+			depth--;
+			throw e;
+		}
+	}
+
+	@Override
+	public long nextLong() {
+		enter();
+		long value = super.nextLong();
+		exit("nextLong", "", Long.toString(value));
+		return value;
+	}
+
+	@Override
+	public boolean nextBoolean() {
+		enter();
+		boolean value = super.nextBoolean();
+		exit("nextBoolean", "", Boolean.toString(value));
+		return value;
+	}
+
+	@Override
+	public float nextFloat() {
+		enter();
+		float value = super.nextFloat();
+		// Written as a double, which parses back to the same value
+		exit("nextFloat", "", Double.toString(value));
+		return value;
+	}
+
+	@Override
+	public double nextDouble() {
+		enter();
+		double value = super.nextDouble();
+		exit("nextDouble", "", Double.toString(value));
+		return value;
+	}
+
+	@Override
+	public double nextDouble(double bound) {
+		enter();
+
+		try {
+			double value = super.nextDouble(bound);
+			exit("nextDouble", Double.toString(bound), Double.toString(value));
+			return value;
+		} catch(IllegalArgumentException e) {
+			depth--;
+			throw e;
+		}
+	}
+
+	@Override
+	public double nextDouble(double origin, double bound) {
+		enter();
+
+		try {
+			double value = super.nextDouble(origin, bound);
+			exit("nextDouble", Double.toString(origin) + "," + Double.toString(bound), Double.toString(value));
+			return value;
+		} catch(IllegalArgumentException e) {
+			depth--;
+			throw e;
+		}
+	}
+
+	@Override
+	public synchronized double nextGaussian() {
+		enter();
+		double value = super.nextGaussian();
+		exit("nextGaussian", "", Double.toString(value));
+		return value;
+	}
+
+	public static void main(String[] args) {
+		TracingRandom random = new TracingRandom(args.length > 0 ? Long.parseLong(args[0]) : 0, System.out);
+
+		random.next(17);
+		random.nextBytes(new byte[7]);
+		random.nextInt();
+		random.nextInt(10);
+		random.nextInt(1 << 20);
+		random.nextLong();
+		random.nextBoolean();
+		random.nextFloat();
+		random.nextDouble();
+		random.nextDouble(1e-5);
+		random.nextDouble(-100.0, 100.0);
+		random.nextGaussian();
+		random.nextGaussian();
+	}
+}
@@ -0,0 +1,57 @@
+/* C API for java-rand, built with the `ffi` feature. Mirrors src/ffi.rs. */
+
+#ifndef JAVA_RAND_H
+#define JAVA_RAND_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Opaque generator handle. Handles must not be used from multiple threads at once. */
+typedef struct JavaRandom JavaRandom;
+
+/* Creates a generator, equivalent to `new Random(seed)`. Release it with javarand_free. */
+JavaRandom *javarand_new(int64_t seed);
+
+/* Releases a generator. Passing NULL is a no-op. */
+void javarand_free(JavaRandom *random);
+
+/* Equivalent to `setSeed(seed)`. */
+void javarand_set_seed(JavaRandom *random, int64_t seed);
+
+/* Equivalent to `next(bits)`. Returns 0 without stepping the generator if bits is over 48. */
+int32_t javarand_next(JavaRandom *random, uint8_t bits);
+
+/* Equivalent to `nextBytes(bytes)` over a buffer of len bytes. */
+void javarand_next_bytes(JavaRandom *random, uint8_t *bytes, size_t len);
+
+/* Equivalent to `nextInt()`. */
+int32_t javarand_next_int(JavaRandom *random);
+
+/* Equivalent to `nextInt(bound)`. Returns -1 without stepping the generator if bound is not positive. */
+int32_t javarand_next_int_bound(JavaRandom *random, int32_t bound);
+
+/* Equivalent to `nextLong()`. */
+int64_t javarand_next_long(JavaRandom *random);
+
+/* Equivalent to `nextBoolean()`. */
+bool javarand_next_boolean(JavaRandom *random);
+
+/* Equivalent to `nextFloat()`. */
+float javarand_next_float(JavaRandom *random);
+
+/* Equivalent to `nextDouble()`. */
+double javarand_next_double(JavaRandom *random);
+
+/* Equivalent to `nextGaussian()`. */
+double javarand_next_gaussian(JavaRandom *random);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
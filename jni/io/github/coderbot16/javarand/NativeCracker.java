@@ -0,0 +1,45 @@
+package io.github.coderbot16.javarand;
+
+/**
+ * Native methods recovering {@code java.util.Random} seeds from observed values with the java-rand cracker. The
+ * observed calls are recorded in order, then {@link #solve} finds the candidates, which are read with
+ * {@link #candidateSeed}.
+ */
+public final class NativeCracker {
+	static {
+		System.loadLibrary("java_rand");
+	}
+
+	private NativeCracker() {
+	}
+
+	/** Creates a cracker without observations. */
+	public static native long create();
+
+	/** Releases a cracker. Passing 0 is a no-op. */
+	public static native void free(long handle);
+
+	public static native void nextInt(long handle, int value);
+
+	public static native void nextIntBound(long handle, int bound, int value);
+
+	public static native void nextLong(long handle, long value);
+
+	public static native void nextBoolean(long handle, boolean value);
+
+	public static native void nextFloat(long handle, float value);
+
+	public static native void nextDouble(long handle, double value);
+
+	/** Records that the generator was stepped {@code steps} times without observing the results. */
+	public static native void skip(long handle, long steps);
+
+	/**
+	 * Returns the number of candidates, -1 if a bound was not positive, or -2 if the observations leave too many
+	 * candidates to enumerate.
+	 */
+	public static native int solve(long handle);
+
+	/** Returns the seed, reduced to 48 bits, of the candidate at {@code index}, or -1 if out of range. */
+	public static native long candidateSeed(long handle, int index);
+}
@@ -0,0 +1,50 @@
+package io.github.coderbot16.javarand;
+
+/**
+ * Native methods over the java-rand generator, built with the {@code jni-export} feature. Generators are passed as
+ * handles created by {@link #create} and released by {@link #free}; a handle must not be used from multiple threads
+ * at once.
+ */
+public final class NativeRandom {
+	static {
+		System.loadLibrary("java_rand");
+	}
+
+	private NativeRandom() {
+	}
+
+	/** Creates a generator, equivalent to {@code new Random(seed)}. */
+	public static native long create(long seed);
+
+	/** Releases a generator. Passing 0 is a no-op. */
+	public static native void free(long handle);
+
+	public static native void setSeed(long handle, long seed);
+
+	/** Steps the generator {@code steps} times in logarithmic time, or backwards if negative. */
+	public static native void advance(long handle, long steps);
+
+	/** Returns 0 without stepping the generator if {@code bits} is not between 0 and 48. */
+	public static native int next(long handle, int bits);
+
+	public static native int nextInt(long handle);
+
+	/** Returns -1 without stepping the generator if {@code bound} is not positive. */
+	public static native int nextIntBound(long handle, int bound);
+
+	public static native long nextLong(long handle);
+
+	public static native boolean nextBoolean(long handle);
+
+	public static native float nextFloat(long handle);
+
+	public static native double nextDouble(long handle);
+
+	public static native double nextGaussian(long handle);
+
+	/** Fills {@code values} with the results of calling {@code nextInt()} for each element in order. */
+	public static native void fillInt(long handle, int[] values);
+
+	/** Fills {@code values} with the results of calling {@code nextGaussian()} for each element in order. */
+	public static native void fillGaussian(long handle, double[] values);
+}
@@ -0,0 +1,306 @@
+//! Batched stepping. Stepping the generator `k` times is itself an affine map, `state * A^k + C_k`, so the next
+//! `LANES` states can all be computed from the current one with independent multiplications instead of a chain of
+//! dependent ones.
+//!
+//! The bulk fills also have kernels that step many independent lanes of states, written so that the compiler
+//! vectorizes them. The multiplications need 64-bit lanes, which are only fast with AVX2 and AVX-512, so these
+//! kernels are compiled for those instruction sets, and the best one the processor supports is selected at runtime.
+//! The same binary then runs everywhere without `target-feature` flags. Detection needs `std`: without it, a vector
+//! kernel is only used if the crate is compiled with its instruction set enabled. Other targets, including AArch64,
+//! whose NEON has no 64-bit multiplication, use the scalar kernel. Every kernel gives the same values.
+
+use {float, lcg, strictmath};
+use {Random, MASK, MULTIPLIER, INCREMENT};
+
+/// Number of states computed per batch.
+pub const LANES: usize = 8;
+
+/// Returns the multiplier and increment of the affine map that steps the generator `steps` times.
+pub const fn affine_steps(steps: u64) -> (u64, u64) {
+	lcg::affine_power(MULTIPLIER, INCREMENT, MASK, steps)
+}
+
+/// Affine maps stepping the generator 1 through `LANES` times.
+const COEFFICIENTS: [(u64, u64); LANES] = {
+	let mut coefficients = [(0, 0); LANES];
+	let mut index = 0;
+
+	while index < LANES {
+		coefficients[index] = affine_steps(index as u64 + 1);
+		index += 1;
+	}
+
+	coefficients
+};
+
+/// Number of polar method attempts drawn per block by `fill_gaussian`.
+const GAUSSIAN_ATTEMPTS: usize = 64;
+
+/// Instruction sets the bulk kernels are compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kernel {
+	Scalar,
+	Avx2,
+	/// AVX-512 with the DQ extension, which has 64-bit multiplications
+	Avx512
+}
+
+impl Kernel {
+	/// Returns the best kernel supported by the processor running the program.
+	#[inline]
+	pub fn detect() -> Kernel {
+		#[cfg(all(feature = "std", target_arch = "x86_64"))]
+		{
+			if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512dq") {
+				return Kernel::Avx512;
+			}
+
+			if is_x86_feature_detected!("avx2") {
+				return Kernel::Avx2;
+			}
+		}
+
+		#[cfg(not(feature = "std"))]
+		{
+			if cfg!(all(target_arch = "x86_64", target_feature = "avx512f", target_feature = "avx512dq")) {
+				return Kernel::Avx512;
+			}
+
+			if cfg!(all(target_arch = "x86_64", target_feature = "avx2")) {
+				return Kernel::Avx2;
+			}
+		}
+
+		Kernel::Scalar
+	}
+}
+
+/// Steps `state` `LANES` times, returning every intermediate state.
+#[inline(always)]
+fn next_states(state: &mut u64) -> [u64; LANES] {
+	let mut states = [0; LANES];
+
+	for (next, &(multiplier, increment)) in states.iter_mut().zip(COEFFICIENTS.iter()) {
+		*next = state.wrapping_mul(multiplier).wrapping_add(increment) & MASK;
+	}
+
+	*state = states[LANES - 1];
+
+	states
+}
+
+/// Fills whole batches of `LANES` values with the upper 32 bits of the next states, converted by `convert`,
+/// returning the values left over. The multiplications of a batch only depend on the state before it, which suits
+/// scalar code.
+#[inline(always)]
+fn fill_scalar<'a, T, F: Fn(u32) -> T>(state: &mut u64, values: &'a mut [T], convert: F) -> &'a mut [T] {
+	let mut chunks = values.chunks_exact_mut(LANES);
+
+	for chunk in &mut chunks {
+		for (value, next) in chunk.iter_mut().zip(next_states(state).iter()) {
+			*value = convert((next >> 16) as u32);
+		}
+	}
+
+	chunks.into_remainder()
+}
+
+/// Like `fill_scalar`, with batches of `WIDTH` values. Instead of computing every batch from the state before it,
+/// each of the `WIDTH` states is stepped `WIDTH` times from its value in the previous batch, so that the states are
+/// independent lanes.
+#[inline(always)]
+fn fill_lanes<'a, T, F: Fn(u32) -> T, const WIDTH: usize>(state: &mut u64, values: &'a mut [T], convert: F)
+	-> &'a mut [T] {
+	let mut chunks = values.chunks_exact_mut(WIDTH);
+
+	if chunks.len() > 0 {
+		let (multiplier, increment) = affine_steps(WIDTH as u64);
+		let mut states = [0; WIDTH];
+
+		for (index, next) in states.iter_mut().enumerate() {
+			let (multiplier, increment) = affine_steps(index as u64 + 1);
+			*next = state.wrapping_mul(multiplier).wrapping_add(increment) & MASK;
+		}
+
+		for chunk in &mut chunks {
+			for (value, next) in chunk.iter_mut().zip(states.iter()) {
+				*value = convert((next >> 16) as u32);
+			}
+
+			*state = states[WIDTH - 1];
+
+			for next in states.iter_mut() {
+				*next = next.wrapping_mul(multiplier).wrapping_add(increment) & MASK;
+			}
+		}
+	}
+
+	chunks.into_remainder()
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn fill_avx2<'a, T, F: Fn(u32) -> T>(state: &mut u64, values: &'a mut [T], convert: F) -> &'a mut [T] {
+	fill_lanes::<T, F, 16>(state, values, convert)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f,avx512dq")]
+unsafe fn fill_avx512<'a, T, F: Fn(u32) -> T>(state: &mut u64, values: &'a mut [T], convert: F) -> &'a mut [T] {
+	fill_lanes::<T, F, 64>(state, values, convert)
+}
+
+/// Fills whole batches with the vector kernel of the processor if the slice is long enough for it, then batches of
+/// `LANES` values with the scalar kernel, returning the values left over. Every kernel produces the values of calling
+/// `next_u32` repeatedly.
+#[inline(always)]
+fn fill<'a, T, F: Fn(u32) -> T + Copy>(state: &mut u64, values: &'a mut [T], convert: F) -> &'a mut [T] {
+	let rest = if values.len() < 16 { values } else { fill_with(Kernel::detect(), state, values, convert) };
+
+	fill_scalar(state, rest, convert)
+}
+
+/// Fills whole batches with the given kernel, returning the values left over. Scalar batches are left to `fill`.
+#[inline]
+pub fn fill_with<'a, T, F: Fn(u32) -> T>(kernel: Kernel, state: &mut u64, out: &'a mut [T], convert: F) -> &'a mut [T] {
+	match kernel {
+		// SAFETY: the kernels are only selected if the processor supports their instruction set
+		#[cfg(target_arch = "x86_64")]
+		Kernel::Avx2 => unsafe { fill_avx2(state, out, convert) },
+		#[cfg(target_arch = "x86_64")]
+		Kernel::Avx512 => unsafe { fill_avx512(state, out, convert) },
+		_ => out
+	}
+}
+
+/// Fills `states` with the next states, stepping `state` once per element.
+#[inline]
+fn fill_states(state: &mut u64, states: &mut [u64]) {
+	let mut chunks = states.chunks_exact_mut(LANES);
+
+	for chunk in &mut chunks {
+		chunk.copy_from_slice(&next_states(state));
+	}
+
+	for next in chunks.into_remainder() {
+		*state = state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT) & MASK;
+		*next = *state;
+	}
+}
+
+/// Returns the `nextDouble` that would be computed from the two states after the calls to `next(26)` and `next(27)`.
+#[inline(always)]
+fn f64_from_states(high: u64, low: u64) -> f64 {
+	float::unit_f64(((high >> 22) << 27) + (low >> 21))
+}
+
+/// Square root as computed by `StrictMath.sqrt`. IEEE 754 requires square roots to be correctly rounded, so the
+/// hardware instruction gives the same results as the bit by bit `strictmath::sqrt`, much faster. It is used with
+/// `std`, where it is available, unless the `deterministic` feature rules out anything but integer arithmetic.
+#[inline(always)]
+fn sqrt(x: f64) -> f64 {
+	#[cfg(all(feature = "std", not(feature = "deterministic")))]
+	{
+		x.sqrt()
+	}
+
+	#[cfg(not(all(feature = "std", not(feature = "deterministic"))))]
+	{
+		strictmath::sqrt(x)
+	}
+}
+
+impl Random {
+	/// Fills the slice with gaussians, equivalent to calling `next_gaussian` for each element in order, including
+	/// returning a pending gaussian first and leaving the second value of the last pair pending.
+	///
+	/// The uniform variates of many polar method attempts are computed at once with batched stepping, and the
+	/// logarithms and square roots of the accepted attempts are then computed in a separate loop, which keeps the
+	/// rejection branches out of the arithmetic. With `std`, the square roots use the hardware instruction, which is
+	/// correctly rounded like `strictmath::sqrt`. The generator is left in the state after the last attempt used, so
+	/// the serial sequence is preserved exactly.
+	pub fn fill_gaussian(&mut self, values: &mut [f64]) {
+		self.fill_gaussian_with(values, |s| sqrt(-2.0 * strictmath::log(s) / s))
+	}
+
+	/// Like `fill_gaussian`, but computes the logarithm with the platform's `ln`, which is faster than the port of
+	/// `StrictMath.log` but is **not compatible with Java**: the results may differ from `next_gaussian` in the last
+	/// bit, and between platforms. The uniform variates and the number of steps are the same as with
+	/// `fill_gaussian`, so the generator is left in the same state.
+	#[cfg(feature = "std")]
+	pub fn fill_gaussian_fast(&mut self, values: &mut [f64]) {
+		self.fill_gaussian_with(values, |s| (-2.0 * s.ln() / s).sqrt())
+	}
+
+	fn fill_gaussian_with<F: Fn(f64) -> f64>(&mut self, values: &mut [f64], multiplier: F) {
+		if values.is_empty() {
+			return;
+		}
+
+		let mut index = 0;
+
+		if let Some(next) = self.next_gaussian.take() {
+			values[0] = next;
+			index = 1;
+		}
+
+		let mut states = [0; 4 * GAUSSIAN_ATTEMPTS];
+		let mut accepted = [(0.0, 0.0, 0.0, 0); GAUSSIAN_ATTEMPTS];
+
+		while index < values.len() {
+			// Every accepted attempt gives 2 values. About 21% of the attempts are rejected, so half again as many
+			// attempts as pairs usually only takes one block.
+			let pairs = (values.len() - index).div_ceil(2);
+			let attempts = (pairs + pairs / 2 + 1).min(GAUSSIAN_ATTEMPTS);
+			let states = &mut states[..4 * attempts];
+
+			let mut state = self.state;
+			fill_states(&mut state, states);
+
+			let mut count = 0;
+
+			for (attempt, chunk) in states.chunks_exact(4).enumerate() {
+				let v0 = 2.0 * f64_from_states(chunk[0], chunk[1]) - 1.0;
+				let v1 = 2.0 * f64_from_states(chunk[2], chunk[3]) - 1.0;
+				let s = v0*v0 + v1*v1;
+
+				if s < 1.0 && s != 0.0 && count < pairs {
+					accepted[count] = (v0, v1, s, attempt);
+					count += 1;
+				}
+			}
+
+			// Only the attempts up to the last one used are consumed
+			self.state = if count == pairs { states[4 * accepted[count - 1].3 + 3] } else { state };
+
+			for &(v0, v1, s, _) in &accepted[..count] {
+				let multiplier = multiplier(s);
+
+				values[index] = v0 * multiplier;
+
+				match values.get_mut(index + 1) {
+					Some(value) => *value = v1 * multiplier,
+					None => self.next_gaussian = Some(v1 * multiplier)
+				}
+
+				index += 2;
+			}
+		}
+	}
+
+	/// Fills the slice with uniformly distributed unsigned 32-bit integers, equivalent to calling `next_u32` for each
+	/// element in order, but computing several of them at once.
+	pub fn fill_u32(&mut self, values: &mut [u32]) {
+		for value in fill(&mut self.state, values, |value| value) {
+			*value = self.next_u32();
+		}
+	}
+
+	/// Fills the slice with uniformly distributed signed 32-bit integers, equivalent to calling `next_i32` for each
+	/// element in order, but computing several of them at once.
+	pub fn fill_i32(&mut self, values: &mut [i32]) {
+		for value in fill(&mut self.state, values, |value| value as i32) {
+			*value = self.next_i32();
+		}
+	}
+}
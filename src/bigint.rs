@@ -0,0 +1,677 @@
+//! Parity with the `java.math.BigInteger` constructors that take a `Random`.
+//!
+//! Only the draws are reproduced: results are returned as big endian magnitudes, in the order expected by
+//! `BigUint::from_bytes_be` of the `num-bigint` crate. Prime generation needs arithmetic on the candidates, done by a
+//! small private natural number type. Its results only decide which candidates are accepted, so it only has to be
+//! correct, not to compute the same intermediate values as `BigInteger`.
+
+use core::cmp::Ordering;
+use std::vec::Vec;
+
+use Random;
+
+/// Bit lengths below this are generated by drawing whole candidates, and longer ones by sieving a range.
+const SMALL_PRIME_THRESHOLD: u32 = 95;
+
+/// `3 * 5 * 7 * ... * 41`
+const SMALL_PRIME_PRODUCT: u64 = 152125131763605;
+
+/// Odd primes whose multiples are rejected from small candidates before testing them.
+const SMALL_PRIMES: [u64; 12] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Length of the sieve of odd numbers whose unmarked entries are the primes used to sieve large candidates.
+const SMALL_SIEVE_LENGTH: usize = 9600;
+
+impl Random {
+	/// Returns the magnitude of `new BigInteger(numBits, rnd)` as big endian bytes, a uniformly distributed integer in
+	/// the range [0, 2^num_bits). Java fills `(num_bits + 7) / 8` bytes with `nextBytes` and clears the excess high
+	/// bits of the first byte, so the result always has that length, with leading zero bytes where the value is small.
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// // new BigInteger(12, new Random(1)).toString(16) is "3d5"
+	/// assert_eq!(Random::new(1).next_big_uint_bytes(12), [0x03, 0xD5]);
+	/// ```
+	pub fn next_big_uint_bytes(&mut self, num_bits: u32) -> Vec<u8> {
+		let num_bytes = (num_bits as u64).div_ceil(8) as usize;
+		let mut bytes = vec![0; num_bytes];
+
+		if num_bytes > 0 {
+			self.next_bytes(&mut bytes);
+
+			let excess_bits = 8 * num_bytes as u32 - num_bits;
+			bytes[0] &= (0xFFu32 >> excess_bits) as u8;
+		}
+
+		bytes
+	}
+
+	/// Returns the magnitude of `new BigInteger(bitLength, certainty, rnd)` as big endian bytes, a probable prime of
+	/// exactly `bit_length` bits. `BigInteger.probablePrime(bitLength, rnd)` is the same with a certainty of 100.
+	///
+	/// The generator is consumed exactly as in Java: candidates below 95 bits are drawn with `nextInt`, longer ones
+	/// are found by sieving a range above a `new BigInteger(bitLength, rnd)` draw, and every Miller-Rabin round draws
+	/// its base with `new BigInteger(bitLength, rnd)` until it falls in range. The rounds are chosen from the
+	/// certainty and the bit length as in Java, and candidates of 100 bits or more must also pass a Lucas test.
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// // BigInteger.probablePrime(16, new Random(1)).toString(16) is "f13d"
+	/// assert_eq!(Random::new(1).next_probable_prime_bytes(16, 100), [0xF1, 0x3D]);
+	/// ```
+	///
+	/// # Panics
+	/// If `bit_length` is less than 2, or too large for the sieve as in Java, the function panics.
+	pub fn next_probable_prime_bytes(&mut self, bit_length: u32, certainty: i32) -> Vec<u8> {
+		if bit_length < 2 {
+			panic!("Bit length must be at least 2")
+		}
+
+		let prime = if bit_length < SMALL_PRIME_THRESHOLD {
+			self.small_prime(bit_length, certainty)
+		} else {
+			self.large_prime(bit_length, certainty)
+		};
+
+		prime.to_be_bytes()
+	}
+
+	/// `new BigInteger(numBits, rnd)`
+	fn next_natural(&mut self, num_bits: u32) -> Natural {
+		Natural::from_be_bytes(&self.next_big_uint_bytes(num_bits))
+	}
+
+	fn small_prime(&mut self, bit_length: u32, certainty: i32) -> Natural {
+		let mag_len = bit_length.div_ceil(32) as usize;
+		let high_bit = 1u32 << ((bit_length + 31) & 0x1F);
+		let high_mask = (high_bit << 1).wrapping_sub(1);
+
+		let mut words = vec![0; mag_len];
+
+		loop {
+			for word in words.iter_mut() {
+				*word = self.next_u32();
+			}
+
+			words[0] = (words[0] & high_mask) | high_bit;
+
+			if bit_length > 2 {
+				words[mag_len - 1] |= 1;
+			}
+
+			let candidate = Natural::from_be_words(&words);
+
+			if bit_length > 6 {
+				let r = candidate.rem(&Natural::from_u64(SMALL_PRIME_PRODUCT)).to_u64();
+
+				if SMALL_PRIMES.iter().any(|&prime| r.is_multiple_of(prime)) {
+					continue;
+				}
+			}
+
+			// All candidates of 2 and 3 bits are prime by now
+			if bit_length < 4 || candidate.prime_to_certainty(certainty, self) {
+				return candidate;
+			}
+		}
+	}
+
+	fn large_prime(&mut self, bit_length: u32, certainty: i32) -> Natural {
+		if bit_length > 500_000_000 {
+			panic!("Prime search implementation restriction on bit length")
+		}
+
+		let search_len = (bit_length / 20 * 64) as usize;
+
+		let mut base = self.next_natural(bit_length);
+		base.set_bit(bit_length - 1);
+		base.clear_bit(0);
+
+		loop {
+			if let Some(candidate) = sieve(&base, search_len).retrieve(&base, certainty, self) {
+				if candidate.bit_length() == bit_length {
+					return candidate;
+				}
+			}
+
+			base = base.add(&Natural::from_u64(2 * search_len as u64));
+
+			if base.bit_length() != bit_length {
+				base = self.next_natural(bit_length);
+				base.set_bit(bit_length - 1);
+			}
+
+			base.clear_bit(0);
+		}
+	}
+}
+
+/// Marks of a range of odd numbers, `BitSieve` in Java. Entry `i` stands for `2 * i + 1` in the small sieve, and for
+/// `base + 2 * i + 1` in the sieve of a large candidate, where a set bit marks a known composite.
+struct Sieve {
+	bits: Vec<u64>,
+	length: usize
+}
+
+impl Sieve {
+	fn new(length: usize) -> Self {
+		Sieve {
+			bits: vec![0; (length - 1) / 64 + 1],
+			length
+		}
+	}
+
+	fn get(&self, index: usize) -> bool {
+		self.bits[index / 64] & (1 << (index % 64)) != 0
+	}
+
+	fn set(&mut self, index: usize) {
+		self.bits[index / 64] |= 1 << (index % 64);
+	}
+
+	/// Returns the first unmarked index from `start`. Like Java, this ignores the last entry unless it is the start.
+	fn search(&self, start: usize) -> Option<usize> {
+		if start >= self.length {
+			return None;
+		}
+
+		(start..(start + 1).max(self.length - 1)).find(|&index| !self.get(index))
+	}
+
+	/// Marks every `step`th entry from `start`.
+	fn mark(&mut self, start: usize, step: usize) {
+		(start..self.length).step_by(step).for_each(|index| self.set(index));
+	}
+
+	/// Tests the unmarked candidates in order, returning the first that passes. Like Java, this also tests the
+	/// entries past the length in the last word.
+	fn retrieve(&self, base: &Natural, certainty: i32, random: &mut Random) -> Option<Natural> {
+		let mut offset = 1;
+
+		for &word in &self.bits {
+			let mut unmarked = !word;
+
+			for _ in 0..64 {
+				if unmarked & 1 == 1 {
+					let candidate = base.add(&Natural::from_u64(offset));
+
+					if candidate.prime_to_certainty(certainty, random) {
+						return Some(candidate);
+					}
+				}
+
+				unmarked >>= 1;
+				offset += 2;
+			}
+		}
+
+		None
+	}
+}
+
+/// The sieve of the odd primes below `2 * SMALL_SIEVE_LENGTH`, with 1 marked.
+fn small_sieve() -> Sieve {
+	let mut sieve = Sieve::new(SMALL_SIEVE_LENGTH);
+	sieve.set(0);
+
+	let mut index = 1;
+	let mut prime = 3;
+
+	loop {
+		sieve.mark(index + prime, prime);
+
+		match sieve.search(index + 1) {
+			Some(next) if 2 * next + 1 < SMALL_SIEVE_LENGTH => {
+				index = next;
+				prime = 2 * next + 1;
+			},
+			_ => return sieve
+		}
+	}
+}
+
+/// Sieves the `length` odd numbers after the even `base` with the primes of the small sieve.
+fn sieve(base: &Natural, length: usize) -> Sieve {
+	let primes = small_sieve();
+	let mut sieve = Sieve::new(length);
+	let mut next = primes.search(0);
+
+	while let Some(index) = next {
+		let prime = 2 * index + 1;
+
+		// The first odd multiple of the prime after the base
+		let mut start = prime - base.rem_u32(prime as u32) as usize;
+
+		if start.is_multiple_of(2) {
+			start += prime;
+		}
+
+		sieve.mark((start - 1) / 2, prime);
+		next = primes.search(index + 1);
+	}
+
+	sieve
+}
+
+/// Arbitrary precision natural number, as little endian 32-bit limbs without high zero limbs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Natural {
+	limbs: Vec<u32>
+}
+
+impl Natural {
+	fn from_limbs(mut limbs: Vec<u32>) -> Self {
+		while limbs.last() == Some(&0) {
+			limbs.pop();
+		}
+
+		Natural { limbs }
+	}
+
+	fn from_u64(value: u64) -> Self {
+		Natural::from_limbs(vec![value as u32, (value >> 32) as u32])
+	}
+
+	/// From big endian words, the order of `BigInteger.mag`.
+	fn from_be_words(words: &[u32]) -> Self {
+		Natural::from_limbs(words.iter().rev().cloned().collect())
+	}
+
+	fn from_be_bytes(bytes: &[u8]) -> Self {
+		let limbs = bytes.rchunks(4).map(|chunk| chunk.iter().fold(0, |limb, &byte| (limb << 8) | byte as u32));
+
+		Natural::from_limbs(limbs.collect())
+	}
+
+	/// Returns the big endian bytes, without leading zeros.
+	fn to_be_bytes(&self) -> Vec<u8> {
+		let bytes = self.limbs.iter().rev().flat_map(|limb| limb.to_be_bytes());
+
+		bytes.skip_while(|&byte| byte == 0).collect()
+	}
+
+	/// Returns the low 64 bits.
+	fn to_u64(&self) -> u64 {
+		self.limbs.iter().take(2).rev().fold(0, |value, &limb| (value << 32) | limb as u64)
+	}
+
+	fn is_one(&self) -> bool {
+		self.limbs == [1]
+	}
+
+	fn bit_length(&self) -> u32 {
+		match self.limbs.last() {
+			Some(&high) => 32 * self.limbs.len() as u32 - high.leading_zeros(),
+			None => 0
+		}
+	}
+
+	fn test_bit(&self, bit: u32) -> bool {
+		self.limbs.get(bit as usize / 32).is_some_and(|&limb| limb & (1 << (bit % 32)) != 0)
+	}
+
+	fn set_bit(&mut self, bit: u32) {
+		let index = bit as usize / 32;
+
+		if index >= self.limbs.len() {
+			self.limbs.resize(index + 1, 0);
+		}
+
+		self.limbs[index] |= 1 << (bit % 32);
+	}
+
+	fn clear_bit(&mut self, bit: u32) {
+		if let Some(limb) = self.limbs.get_mut(bit as usize / 32) {
+			*limb &= !(1 << (bit % 32));
+		}
+
+		let limbs = core::mem::take(&mut self.limbs);
+		*self = Natural::from_limbs(limbs);
+	}
+
+	/// Returns the index of the lowest set bit. The number must not be zero.
+	fn trailing_zeros(&self) -> u32 {
+		let index = self.limbs.iter().position(|&limb| limb != 0).unwrap();
+
+		32 * index as u32 + self.limbs[index].trailing_zeros()
+	}
+
+	fn shr(&self, bits: u32) -> Natural {
+		let words = bits as usize / 32;
+		let bits = bits % 32;
+
+		if words >= self.limbs.len() {
+			return Natural::from_limbs(Vec::new());
+		}
+
+		let limbs = &self.limbs[words..];
+		let shifted = (0..limbs.len()).map(|index| {
+			let high = limbs.get(index + 1).map_or(0, |&high| ((high as u64) << 32 >> bits) as u32);
+
+			if bits == 0 { limbs[index] } else { (limbs[index] >> bits) | high }
+		});
+
+		Natural::from_limbs(shifted.collect())
+	}
+
+	fn add(&self, other: &Natural) -> Natural {
+		let len = self.limbs.len().max(other.limbs.len());
+		let mut limbs = Vec::with_capacity(len + 1);
+		let mut carry = 0;
+
+		for index in 0..len {
+			let a = *self.limbs.get(index).unwrap_or(&0) as u64;
+			let b = *other.limbs.get(index).unwrap_or(&0) as u64;
+			let sum = a + b + carry;
+
+			limbs.push(sum as u32);
+			carry = sum >> 32;
+		}
+
+		limbs.push(carry as u32);
+		Natural::from_limbs(limbs)
+	}
+
+	/// Returns `self - other`, which must not be negative.
+	fn sub(&self, other: &Natural) -> Natural {
+		let mut limbs = Vec::with_capacity(self.limbs.len());
+		let mut borrow = 0;
+
+		for (index, &limb) in self.limbs.iter().enumerate() {
+			let difference = limb as i64 - *other.limbs.get(index).unwrap_or(&0) as i64 - borrow;
+
+			limbs.push(difference as u32);
+			borrow = -(difference >> 32);
+		}
+
+		Natural::from_limbs(limbs)
+	}
+
+	fn mul(&self, other: &Natural) -> Natural {
+		let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+
+		for (i, &a) in self.limbs.iter().enumerate() {
+			let mut carry = 0;
+
+			for (j, &b) in other.limbs.iter().enumerate() {
+				let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+
+				limbs[i + j] = product as u32;
+				carry = product >> 32;
+			}
+
+			limbs[i + other.limbs.len()] = carry as u32;
+		}
+
+		Natural::from_limbs(limbs)
+	}
+
+	fn rem_u32(&self, divisor: u32) -> u32 {
+		let divisor = divisor as u64;
+
+		self.limbs.iter().rev().fold(0, |remainder, &limb| ((remainder << 32) | limb as u64) % divisor) as u32
+	}
+
+	/// Returns `self mod modulus` with Knuth's algorithm D. The modulus must not be zero.
+	fn rem(&self, modulus: &Natural) -> Natural {
+		if *self < *modulus {
+			return self.clone();
+		}
+
+		if modulus.limbs.len() == 1 {
+			return Natural::from_u64(self.rem_u32(modulus.limbs[0]) as u64);
+		}
+
+		// Normalize so that the high limb of the divisor has its top bit set
+		let shift = modulus.limbs.last().unwrap().leading_zeros();
+		let v = shl_limbs(&modulus.limbs, shift);
+		let mut u = shl_limbs(&self.limbs, shift);
+
+		u.resize(self.limbs.len() + 1, 0);
+
+		let n = v.len();
+		let (high, second) = (v[n - 1] as u64, v[n - 2] as u64);
+
+		for j in (0..u.len() - n).rev() {
+			let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+			let mut quotient = numerator / high;
+			let mut remainder = numerator % high;
+
+			while quotient >> 32 != 0 || quotient * second > ((remainder << 32) | u[j + n - 2] as u64) {
+				quotient -= 1;
+				remainder += high;
+
+				if remainder >> 32 != 0 {
+					break;
+				}
+			}
+
+			// Subtract quotient * v from the window of u
+			let mut borrow = 0;
+			let mut carry = 0;
+
+			for i in 0..n {
+				let product = quotient * v[i] as u64 + carry;
+				carry = product >> 32;
+
+				let difference = u[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+				u[i + j] = difference as u32;
+				borrow = -(difference >> 32);
+			}
+
+			let difference = u[j + n] as i64 - borrow - carry as i64;
+			u[j + n] = difference as u32;
+
+			// The estimate was one too large, add v back
+			if difference < 0 {
+				let mut carry = 0;
+
+				for i in 0..n {
+					let sum = u[i + j] as u64 + v[i] as u64 + carry;
+
+					u[i + j] = sum as u32;
+					carry = sum >> 32;
+				}
+
+				u[j + n] = u[j + n].wrapping_add(carry as u32);
+			}
+		}
+
+		u.truncate(n);
+		Natural::from_limbs(u).shr(shift)
+	}
+
+	fn mod_pow(&self, exponent: &Natural, modulus: &Natural) -> Natural {
+		let base = self.rem(modulus);
+		let mut result = Natural::from_u64(1).rem(modulus);
+
+		for bit in (0..exponent.bit_length()).rev() {
+			result = result.mul(&result).rem(modulus);
+
+			if exponent.test_bit(bit) {
+				result = result.mul(&base).rem(modulus);
+			}
+		}
+
+		result
+	}
+
+	/// `BigInteger.primeToCertainty`
+	fn prime_to_certainty(&self, certainty: i32, random: &mut Random) -> bool {
+		let n = (certainty.min(i32::MAX - 1) + 1) / 2;
+		let size_in_bits = self.bit_length();
+
+		if size_in_bits < 100 {
+			return self.passes_miller_rabin(n.min(50), random);
+		}
+
+		let rounds = match size_in_bits {
+			0..=255 => 27,
+			256..=511 => 15,
+			512..=767 => 8,
+			768..=1023 => 4,
+			_ => 2
+		};
+
+		self.passes_miller_rabin(n.min(rounds), random) && self.passes_lucas()
+	}
+
+	/// `BigInteger.passesMillerRabin`, drawing each base uniformly from (1, self).
+	fn passes_miller_rabin(&self, iterations: i32, random: &mut Random) -> bool {
+		let minus_one = self.sub(&Natural::from_u64(1));
+		let a = minus_one.trailing_zeros();
+		let m = minus_one.shr(a);
+
+		for _ in 0..iterations {
+			let b = loop {
+				let b = random.next_natural(self.bit_length());
+
+				if (b.limbs.len() > 1 || b.to_u64() > 1) && b < *self {
+					break b;
+				}
+			};
+
+			let mut j = 0;
+			let mut z = b.mod_pow(&m, self);
+
+			while !((j == 0 && z.is_one()) || z == minus_one) {
+				j += 1;
+
+				if (j > 1 && z.is_one()) || j == a {
+					return false;
+				}
+
+				z = z.mul(&z).rem(self);
+			}
+		}
+
+		true
+	}
+
+	/// `BigInteger.passesLucasLehmer`, a Lucas probable prime test with the first D in 5, -7, 9, -11, ... whose
+	/// Jacobi symbol is -1. The sequence is computed with residues instead of the signed values of Java, which gives
+	/// the same final residue.
+	fn passes_lucas(&self) -> bool {
+		let mut d: i64 = 5;
+
+		while jacobi(d, self) != -1 {
+			d = if d < 0 { -d + 2 } else { -(d + 2) };
+		}
+
+		let k = self.add(&Natural::from_u64(1));
+		let d = if d < 0 {
+			self.sub(&Natural::from_u64((-d) as u64).rem(self))
+		} else {
+			Natural::from_u64(d as u64).rem(self)
+		};
+
+		let halve = |value: Natural| if value.test_bit(0) { value.add(self).shr(1) } else { value.shr(1) };
+
+		let mut u = Natural::from_u64(1);
+		let mut v = Natural::from_u64(1);
+
+		for bit in (0..k.bit_length() - 1).rev() {
+			let u2 = u.mul(&v).rem(self);
+			let v2 = halve(v.mul(&v).add(&d.mul(&u.mul(&u))).rem(self));
+
+			u = u2;
+			v = v2;
+
+			if k.test_bit(bit) {
+				let u2 = halve(u.add(&v).rem(self));
+				let v2 = halve(v.add(&d.mul(&u)).rem(self));
+
+				u = u2;
+				v = v2;
+			}
+		}
+
+		u.rem(self).limbs.is_empty()
+	}
+}
+
+impl PartialOrd for Natural {
+	fn partial_cmp(&self, other: &Natural) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Natural {
+	fn cmp(&self, other: &Natural) -> Ordering {
+		self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+	}
+}
+
+/// Shifts little endian limbs left by less than 32 bits, growing them by a limb if the high bits overflow.
+fn shl_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+	let mut shifted = Vec::with_capacity(limbs.len() + 1);
+	let mut carry = 0;
+
+	for &limb in limbs {
+		let wide = ((limb as u64) << shift) | carry;
+
+		shifted.push(wide as u32);
+		carry = wide >> 32;
+	}
+
+	if carry != 0 {
+		shifted.push(carry as u32);
+	}
+
+	shifted
+}
+
+/// The Jacobi symbol (d / n) for an odd positive `n`.
+fn jacobi(d: i64, n: &Natural) -> i32 {
+	let mut result = 1;
+	let low = n.limbs[0] as u64;
+
+	// (-1 / n) is -1 when n is 3 mod 4
+	if d < 0 && low % 4 == 3 {
+		result = -result;
+	}
+
+	let mut a = n.rem_u32(d.unsigned_abs() as u32) as u64;
+	let mut m = d.unsigned_abs();
+
+	// Reciprocity turns (|d| / n) into (n mod |d| / |d|), after taking out the factors of 2 of |d|
+	while m.is_multiple_of(2) {
+		m /= 2;
+
+		if low % 8 == 3 || low % 8 == 5 {
+			result = -result;
+		}
+	}
+
+	if m == 1 {
+		return result;
+	}
+
+	if m % 4 == 3 && low % 4 == 3 {
+		result = -result;
+	}
+
+	a %= m;
+
+	while a != 0 {
+		while a.is_multiple_of(2) {
+			a /= 2;
+
+			if m % 8 == 3 || m % 8 == 5 {
+				result = -result;
+			}
+		}
+
+		core::mem::swap(&mut a, &mut m);
+
+		if a % 4 == 3 && m % 4 == 3 {
+			result = -result;
+		}
+
+		a %= m;
+	}
+
+	if m == 1 { result } else { 0 }
+}
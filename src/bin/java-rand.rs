@@ -0,0 +1,754 @@
+//! Command line tool printing `java.util.Random` sequences, for cross-checking against Java programs.
+
+extern crate java_rand;
+
+use java_rand::crack::Cracker;
+use java_rand::minecraft::{self, McVersion, SpreadType, Structure};
+use java_rand::{Error, Random};
+use std::env;
+use std::fmt::LowerExp;
+use std::io::{self, Read, Write};
+use std::process;
+
+const USAGE: &str = "usage: java-rand <command> --seed <seed> [--count <n>] [--bound <bound>]
+       java-rand crack <command> [--bound <bound>] < values
+       java-rand sync <command> --seed <seed> [--bound <bound>] [--search <steps>] < values
+       java-rand convert <conversion> <value>
+       java-rand map <slime|structure> --seed <seed> [--x <chunk>] [--z <chunk>] [--width <chunks>]
+                     [--height <chunks>] [--format <ascii|ppm>] [--scale <pixels>]
+                     [--spacing <chunks>] [--separation <chunks>] [--salt <salt>] [--triangular]
+
+commands:
+    next-int       nextInt(), or nextInt(bound) with --bound
+    next-long      nextLong()
+    next-float     nextFloat()
+    next-double    nextDouble()
+    next-boolean   nextBoolean()
+    gaussian       nextGaussian()
+    next-bytes     nextBytes(new byte[count]), printed as hex
+
+Seeds are passed to the Random(long) constructor and may be decimal or 0x-prefixed hex.
+Values are printed one per line, formatted like Java's toString.
+
+crack reads whitespace separated values returned by consecutive calls of one of the
+commands other than gaussian and next-bytes, and prints the low 48 bits of every seed
+that produces them, in hex. Two ints, one long or double, three floats or a dozen
+values of nextInt(16) are usually enough; bounds other than powers of two only rule
+out candidates found from other values.
+
+sync reads a reference sequence of values returned by consecutive calls of a command,
+such as a log written by a Java program, and finds the number of steps of the generator
+after which the sequence starts, trying up to --search steps (default 1000000). It then
+reports how many values match from there and the first value that differs.
+
+conversions:
+    text           the world seed Minecraft uses for text typed into the seed field
+    hash           a text seed that Minecraft hashes to the given seed, which must fit in an int
+    seed           the internal state, in hex, of Random(seed)
+    state          the seed, reduced to 48 bits and in hex, that starts Random in the given state
+    structure      the world seeds sharing the low 48 bits of the seed that nextLong() can return,
+                   the seeds of worlds created without entering one
+    sisters        all 65536 world seeds sharing the low 48 bits of the seed
+
+map renders the chunks from (--x, --z), by default (-32, -32), over --width by --height
+chunks, by default 64 by 64, with x to the right and z downwards. slime marks the slime
+chunks of a world seed. structure marks the chunk where each region of a random spread
+placement attempts to place a structure, on a checkerboard of regions; the placement
+defaults to villages, spacing 34, separation 8 and salt 10387312, and --triangular
+selects the spread of end cities and ocean monuments. The map is printed as text with
+# for marked chunks, or with --format ppm as a binary PPM image of --scale pixels per
+chunk, by default 4.";
+
+struct Options {
+	seed: Option<u64>,
+	count: usize,
+	bound: Option<i32>,
+	search: Option<u64>
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+	let parsed = if value.starts_with("0x") || value.starts_with("0X") {
+		u64::from_str_radix(&value[2..], 16).ok()
+	} else {
+		value.parse::<i64>().map(|seed| seed as u64).ok()
+	};
+
+	parsed.ok_or_else(|| format!("invalid seed: {}", value))
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+	let mut seed = None;
+	let mut count = 1;
+	let mut bound = None;
+	let mut search = None;
+
+	let mut args = args.iter();
+
+	while let Some(arg) = args.next() {
+		let value = args.next().ok_or_else(|| format!("missing value for {}", arg))?;
+
+		match &arg[..] {
+			"--seed" => seed = Some(parse_seed(value)?),
+			"--count" => count = value.parse().map_err(|_| format!("invalid count: {}", value))?,
+			"--bound" => {
+				let parsed = value.parse().map_err(|_| format!("invalid bound: {}", value))?;
+
+				if parsed <= 0 {
+					return Err("bound must be positive".to_string());
+				}
+
+				bound = Some(parsed)
+			},
+			"--search" => search = Some(value.parse().map_err(|_| format!("invalid search: {}", value))?),
+			_ => return Err(format!("unknown option: {}", arg))
+		}
+	}
+
+	Ok(Options {
+		seed,
+		count,
+		bound,
+		search
+	})
+}
+
+/// Formats a float like Java's `Double.toString` and `Float.toString`: the shortest digits that round-trip, in
+/// plain notation for magnitudes in [10^-3, 10^7), and in computerized scientific notation otherwise.
+fn java_float_string<F: LowerExp + PartialEq + Into<f64> + Copy>(value: F) -> String {
+	let value_f64 = value.into();
+
+	if value_f64.is_nan() {
+		return "NaN".to_string();
+	} else if value_f64.is_infinite() {
+		return if value_f64 > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+	} else if value_f64 == 0.0 {
+		return if value_f64.is_sign_negative() { "-0.0" } else { "0.0" }.to_string();
+	}
+
+	let scientific = format!("{:e}", value);
+	let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap());
+	let exponent: i32 = exponent[1..].parse().unwrap();
+
+	let (sign, mantissa) = match mantissa.strip_prefix('-') {
+		Some(magnitude) => ("-", magnitude),
+		None => ("", mantissa)
+	};
+	let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+
+	if (-3..7).contains(&exponent) {
+		let (integer, fraction) = if exponent < 0 {
+			("0".to_string(), "0".repeat((-exponent - 1) as usize) + &digits)
+		} else if digits.len() as i32 > exponent + 1 {
+			let (integer, fraction) = digits.split_at(exponent as usize + 1);
+
+			(integer.to_string(), fraction.to_string())
+		} else {
+			(digits.clone() + &"0".repeat((exponent + 1) as usize - digits.len()), "0".to_string())
+		};
+
+		format!("{}{}.{}", sign, integer, fraction)
+	} else {
+		let fraction = if digits.len() > 1 { &digits[1..] } else { "0" };
+
+		format!("{}{}.{}E{}", sign, &digits[..1], fraction, exponent)
+	}
+}
+
+/// Returns the next value of `command` formatted like Java's toString, or None if `command` draws single values.
+fn draw(command: &str, bound: Option<i32>, random: &mut Random) -> Option<String> {
+	Some(match (command, bound) {
+		("next-int", None) => random.next_i32().to_string(),
+		("next-int", Some(bound)) => random.next_i32_bound(bound).to_string(),
+		("next-long", _) => random.next_i64().to_string(),
+		("next-float", _) => java_float_string(random.next_f32()),
+		("next-double", _) => java_float_string(random.next_f64()),
+		("next-boolean", _) => random.next_bool().to_string(),
+		("gaussian", _) => java_float_string(random.next_gaussian()),
+		_ => return None
+	})
+}
+
+/// Returns the seeds, reduced to 48 bits, of the generators whose calls of `command` return the whitespace separated
+/// `values`.
+fn crack(command: &str, bound: Option<i32>, values: &str) -> Result<Vec<u64>, String> {
+	let mut cracker = Cracker::new();
+
+	for value in values.split_whitespace() {
+		let invalid = || format!("invalid value for {}: {}", command, value);
+
+		match (command, bound) {
+			("next-int", None) => cracker.next_i32(value.parse().map_err(|_| invalid())?),
+			("next-int", Some(bound)) => cracker.next_i32_bound(bound, value.parse().map_err(|_| invalid())?),
+			("next-long", _) => cracker.next_i64(value.parse().map_err(|_| invalid())?),
+			("next-float", _) => cracker.next_f32(value.parse().map_err(|_| invalid())?),
+			("next-double", _) => cracker.next_f64(value.parse().map_err(|_| invalid())?),
+			("next-boolean", _) => cracker.next_bool(value.parse().map_err(|_| invalid())?),
+			_ => return Err(format!("cannot crack {}", command))
+		};
+	}
+
+	match cracker.solve() {
+		Ok(candidates) => Ok(candidates.iter().map(|random| random.save().state()[0] ^ 0x5DEECE66D).collect()),
+		Err(Error::TooManyCandidates) => Err("too few values to determine the seed".to_string()),
+		Err(error) => Err(error.to_string())
+	}
+}
+
+/// Where a reference sequence appears in the output of a generator, as found by `sync`
+#[derive(Debug, PartialEq)]
+struct Sync {
+	/// Steps of the generator before the first call matching the reference
+	offset: u64,
+	/// Number of reference values matched from the offset
+	matched: usize,
+	/// The first reference value that differs and the value drawn in its place
+	divergence: Option<(String, String)>
+}
+
+/// Finds the offset, up to `search` steps after seeding, from which the calls of `command` match the longest prefix
+/// of the whitespace separated `reference`, preferring the smallest offset.
+fn sync(command: &str, bound: Option<i32>, seed: u64, reference: &str, search: u64) -> Result<Sync, String> {
+	let mut random = Random::new(seed);
+
+	if draw(command, bound, &mut random.clone()).is_none() || command == "next-bytes" {
+		return Err(format!("cannot sync {}", command));
+	}
+
+	// Normalize the reference to the formatting of draw, so that 1.0E-4 and 0.0001 compare equal
+	let reference = reference.split_whitespace()
+		.map(|value| {
+			let invalid = || format!("invalid value for {}: {}", command, value);
+
+			Ok(match command {
+				"next-int" => value.parse::<i32>().map_err(|_| invalid())?.to_string(),
+				"next-long" => value.parse::<i64>().map_err(|_| invalid())?.to_string(),
+				"next-float" => java_float_string(value.parse::<f32>().map_err(|_| invalid())?),
+				"next-double" | "gaussian" => java_float_string(value.parse::<f64>().map_err(|_| invalid())?),
+				_ => value.parse::<bool>().map_err(|_| invalid())?.to_string()
+			})
+		})
+		.collect::<Result<Vec<String>, String>>()?;
+
+	if reference.is_empty() {
+		return Err("the reference sequence is empty".to_string());
+	}
+
+	let mut best: Option<Sync> = None;
+
+	for offset in 0..=search {
+		let mut candidate = random.clone();
+		let mut matched = 0;
+		let mut divergence = None;
+
+		for expected in &reference {
+			let actual = draw(command, bound, &mut candidate).unwrap();
+
+			if actual != *expected {
+				divergence = Some((expected.clone(), actual));
+				break;
+			}
+
+			matched += 1;
+		}
+
+		if matched > best.as_ref().map_or(0, |best| best.matched) {
+			let complete = divergence.is_none();
+			best = Some(Sync { offset, matched, divergence });
+
+			if complete {
+				break;
+			}
+		}
+
+		random.next(1);
+	}
+
+	best.ok_or_else(|| format!("the first value does not appear within {} steps", search))
+}
+
+/// Returns a text seed without digits that `String.hashCode()` maps to `hash`.
+fn text_for_hash(hash: i32) -> String {
+	// Seven characters from the 31 consecutive ones starting at 'A' write any value in base 31 after subtracting
+	// the hash of "AAAAAAA", since 31^7 exceeds 2^32
+	let offset = (0..7).fold(0u32, |hash, _| hash.wrapping_mul(31).wrapping_add('A' as u32));
+	let mut remaining = (hash as u32).wrapping_sub(offset);
+	let mut text = ['A'; 7];
+
+	for character in text.iter_mut().rev() {
+		*character = (b'A' + (remaining % 31) as u8) as char;
+		remaining /= 31;
+	}
+
+	text.iter().collect()
+}
+
+/// What `map` renders
+struct MapOptions {
+	seed: i64,
+	x: i32,
+	z: i32,
+	width: usize,
+	height: usize,
+	ppm: bool,
+	scale: usize,
+	/// Spacing, separation, salt and spread of a structure placement, or None for slime chunks
+	placement: Option<(i32, i32, i32, SpreadType)>
+}
+
+fn parse_map_options(kind: &str, args: &[String]) -> Result<MapOptions, String> {
+	let mut map = MapOptions {
+		seed: 0,
+		x: -32,
+		z: -32,
+		width: 64,
+		height: 64,
+		ppm: false,
+		scale: 4,
+		placement: None
+	};
+
+	let mut seed = None;
+	let village = Structure::Village.placement(McVersion::From1_18).unwrap();
+	let (mut spacing, mut separation, mut salt, mut spread) =
+		(village.spacing, village.separation, village.salt, village.spread);
+
+	let mut args = args.iter();
+
+	while let Some(arg) = args.next() {
+		if arg == "--triangular" {
+			spread = SpreadType::Triangular;
+			continue;
+		}
+
+		let value = args.next().ok_or_else(|| format!("missing value for {}", arg))?;
+		let invalid = || format!("invalid value for {}: {}", arg, value);
+
+		match &arg[..] {
+			"--seed" => seed = Some(parse_seed(value)? as i64),
+			"--x" => map.x = value.parse().map_err(|_| invalid())?,
+			"--z" => map.z = value.parse().map_err(|_| invalid())?,
+			"--width" => map.width = value.parse().map_err(|_| invalid())?,
+			"--height" => map.height = value.parse().map_err(|_| invalid())?,
+			"--scale" => map.scale = value.parse().map_err(|_| invalid())?,
+			"--format" => map.ppm = match &value[..] {
+				"ascii" => false,
+				"ppm" => true,
+				_ => return Err(invalid())
+			},
+			"--spacing" => spacing = value.parse().map_err(|_| invalid())?,
+			"--separation" => separation = value.parse().map_err(|_| invalid())?,
+			"--salt" => salt = value.parse().map_err(|_| invalid())?,
+			_ => return Err(format!("unknown option: {}", arg))
+		}
+	}
+
+	map.seed = seed.ok_or("missing --seed")?;
+
+	if map.width == 0 || map.height == 0 || map.scale == 0 {
+		return Err("width, height and scale must be positive".to_string());
+	}
+
+	match kind {
+		"slime" => (),
+		"structure" => {
+			if separation < 0 || spacing <= separation {
+				return Err("spacing must be greater than separation, which must not be negative".to_string());
+			}
+
+			map.placement = Some((spacing, separation, salt, spread));
+		},
+		_ => return Err(format!("unknown map: {}", kind))
+	}
+
+	Ok(map)
+}
+
+/// Returns the rendered map, as text or as a binary PPM image.
+fn render(map: &MapOptions) -> Vec<u8> {
+	// 0 for an unmarked chunk on a light region, 1 on a dark region, and 2 for a marked chunk
+	let mut cells = Vec::with_capacity(map.width * map.height);
+
+	for row in 0..map.height {
+		for column in 0..map.width {
+			let chunk_x = map.x.wrapping_add(column as i32);
+			let chunk_z = map.z.wrapping_add(row as i32);
+
+			cells.push(match map.placement {
+				None => if minecraft::is_slime_chunk(map.seed, chunk_x, chunk_z) { 2 } else { 0 },
+				Some((spacing, separation, salt, spread)) => {
+					let attempt =
+						minecraft::random_spread_chunk(map.seed, chunk_x, chunk_z, spacing, separation, salt, spread);
+
+					if attempt == (chunk_x, chunk_z) {
+						2
+					} else {
+						(chunk_x.div_euclid(spacing) + chunk_z.div_euclid(spacing)).rem_euclid(2) as u8
+					}
+				}
+			});
+		}
+	}
+
+	if !map.ppm {
+		let mut text = Vec::with_capacity((map.width + 1) * map.height);
+
+		for row in cells.chunks(map.width) {
+			text.extend(row.iter().map(|&cell| [b'.', b',', b'#'][cell as usize]));
+			text.push(b'\n');
+		}
+
+		return text;
+	}
+
+	const COLORS: [[u8; 3]; 3] = [[0xF0, 0xF0, 0xF0], [0xC8, 0xC8, 0xC8], [0x3C, 0xB0, 0x3C]];
+
+	let mut image = format!("P6\n{} {}\n255\n", map.width * map.scale, map.height * map.scale).into_bytes();
+
+	for row in cells.chunks(map.width) {
+		for _ in 0..map.scale {
+			for &cell in row {
+				for _ in 0..map.scale {
+					image.extend_from_slice(&COLORS[cell as usize]);
+				}
+			}
+		}
+	}
+
+	image
+}
+
+/// Returns the lines printed by `convert`.
+fn convert(conversion: &str, value: &str) -> Result<Vec<String>, String> {
+	let seed = || parse_seed(value).map(|seed| seed as i64);
+
+	Ok(match conversion {
+		"text" => vec![minecraft::text_seed(value).ok_or("empty text gives a random seed")?.to_string()],
+		"hash" => {
+			let seed = seed()?;
+
+			if seed as i32 as i64 != seed {
+				return Err(format!("{} does not fit in an int", seed));
+			}
+
+			vec![text_for_hash(seed as i32)]
+		},
+		"seed" => vec![format!("{:#014x}", Random::new(seed()? as u64).save().state()[0])],
+		"state" => {
+			let state = seed()? as u64;
+
+			if state >> 48 != 0 {
+				return Err(format!("state does not fit in 48 bits: {}", value));
+			}
+
+			vec![format!("{:#014x}", state ^ 0x5DEECE66D)]
+		},
+		"structure" => {
+			let mut seeds: Vec<i64> = minecraft::next_long_seeds(seed()?).collect();
+			seeds.sort_unstable();
+
+			seeds.iter().map(i64::to_string).collect()
+		},
+		"sisters" => minecraft::sister_seeds(seed()?).map(|seed| seed.to_string()).collect(),
+		_ => return Err(format!("unknown conversion: {}", conversion))
+	})
+}
+
+/// Why a command stopped before finishing.
+enum Failure {
+	/// The reader of stdout has gone away, as when the output is piped into `head`, so the program exits quietly.
+	Closed,
+	/// An error reported with the usage.
+	Message(String)
+}
+
+impl From<String> for Failure {
+	fn from(message: String) -> Self {
+		Failure::Message(message)
+	}
+}
+
+impl<'a> From<&'a str> for Failure {
+	fn from(message: &'a str) -> Self {
+		Failure::Message(message.to_string())
+	}
+}
+
+/// Converts a failed write to stdout into a failure, which is quiet if the reader has gone away.
+fn write_error(error: io::Error) -> Failure {
+	if error.kind() == io::ErrorKind::BrokenPipe {
+		return Failure::Closed;
+	}
+
+	Failure::Message(error.to_string())
+}
+
+fn run(command: &str, target: Option<&String>, options: &Options, out: &mut impl Write) -> Result<(), Failure> {
+	if command == "crack" {
+		let target = target.ok_or("missing command to crack")?;
+
+		if options.seed.is_some() || options.search.is_some() {
+			return Err("--seed and --search are not supported by crack".into());
+		} else if options.bound.is_some() && target != "next-int" {
+			return Err(format!("--bound is not supported by {}", target).into());
+		}
+
+		let mut values = String::new();
+		io::stdin().read_to_string(&mut values).map_err(|error| error.to_string())?;
+
+		let seeds = crack(target, options.bound, &values)?;
+
+		if seeds.is_empty() {
+			return Err("no seed produces these values".into());
+		}
+
+		for seed in seeds {
+			writeln!(out, "{:#014x}", seed).map_err(write_error)?;
+		}
+
+		return Ok(());
+	}
+
+	if command == "sync" {
+		let target = target.ok_or("missing command to sync")?;
+		let seed = options.seed.ok_or("missing --seed")?;
+
+		if options.bound.is_some() && target != "next-int" {
+			return Err(format!("--bound is not supported by {}", target).into());
+		}
+
+		let mut reference = String::new();
+		io::stdin().read_to_string(&mut reference).map_err(|error| error.to_string())?;
+
+		let report = sync(target, options.bound, seed, &reference, options.search.unwrap_or(1_000_000))?;
+
+		writeln!(out, "sequence starts after {} steps", report.offset).map_err(write_error)?;
+
+		match report.divergence {
+			None => writeln!(out, "all {} values match", report.matched).map_err(write_error)?,
+			Some((expected, actual)) => {
+				writeln!(out, "{} values match", report.matched).map_err(write_error)?;
+				writeln!(out, "first divergence at value {}: expected {}, got {}", report.matched + 1, expected, actual)
+					.map_err(write_error)?
+			}
+		}
+
+		return Ok(());
+	}
+
+	if options.search.is_some() {
+		return Err(format!("--search is not supported by {}", command).into());
+	}
+
+	if options.bound.is_some() && command != "next-int" {
+		return Err(format!("--bound is not supported by {}", command).into());
+	}
+
+	let mut random = Random::new(options.seed.ok_or("missing --seed")?);
+
+	if command == "next-bytes" {
+		let mut bytes = vec![0; options.count];
+		random.next_bytes(&mut bytes);
+
+		for byte in bytes {
+			writeln!(out, "{:02x}", byte).map_err(write_error)?;
+		}
+
+		return Ok(());
+	}
+
+	if draw(command, options.bound, &mut random.clone()).is_none() {
+		return Err(format!("unknown command: {}", command).into());
+	}
+
+	for _ in 0..options.count {
+		writeln!(out, "{}", draw(command, options.bound, &mut random).unwrap()).map_err(write_error)?;
+	}
+
+	Ok(())
+}
+
+fn main() {
+	let args: Vec<String> = env::args().skip(1).collect();
+	let stdout = io::stdout();
+	let mut out = stdout.lock();
+
+	let result = match args.split_first() {
+		// The value may be a negative seed or arbitrary text, so it is never treated as an option
+		Some((command, rest)) if command == "convert" => match rest {
+			[conversion, value] => convert(conversion, value).map_err(Failure::from).and_then(|lines| {
+				lines.iter().try_for_each(|line| writeln!(out, "{}", line)).map_err(write_error)
+			}),
+			_ => Err("convert takes a conversion and a value".into())
+		},
+		Some((command, rest)) if command == "map" => match rest.split_first() {
+			Some((kind, rest)) => parse_map_options(kind, rest).map_err(Failure::from).and_then(|map| {
+				out.write_all(&render(&map)).map_err(write_error)
+			}),
+			None => Err("missing map to render".into())
+		},
+		Some((command, rest)) if command != "--help" && command != "-h" => {
+			// crack and sync take the command that produced the values before the options
+			let (target, rest) = match rest.split_first() {
+				Some((target, rest)) if (command == "crack" || command == "sync") && !target.starts_with("--") => {
+					(Some(target), rest)
+				},
+				_ => (None, rest)
+			};
+
+			parse_options(rest).map_err(Failure::from).and_then(|options| run(command, target, &options, &mut out))
+		},
+		_ => writeln!(out, "{}", USAGE).map_err(write_error)
+	};
+
+	let result = result.and_then(|_| out.flush().map_err(write_error));
+
+	match result {
+		Ok(()) | Err(Failure::Closed) => (),
+		Err(Failure::Message(message)) => {
+			eprintln!("error: {}\n\n{}", message, USAGE);
+			process::exit(2);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{convert, crack, java_float_string, parse_map_options, render, sync, text_for_hash, Sync};
+	use java_rand::minecraft;
+	use java_rand::Random;
+
+	#[test]
+	fn test_java_float_string() {
+		assert_eq!(java_float_string(0.7231742029971469f64), "0.7231742029971469");
+		assert_eq!(java_float_string(1.0f64), "1.0");
+		assert_eq!(java_float_string(-2.5f64), "-2.5");
+		assert_eq!(java_float_string(1234567.0f64), "1234567.0");
+		assert_eq!(java_float_string(12345678.0f64), "1.2345678E7");
+		assert_eq!(java_float_string(0.001f64), "0.001");
+		assert_eq!(java_float_string(0.0001f64), "1.0E-4");
+		assert_eq!(java_float_string(-0.000123f64), "-1.23E-4");
+		assert_eq!(java_float_string(0.1f32), "0.1");
+		assert_eq!(java_float_string(-0.0f64), "-0.0");
+	}
+
+	#[test]
+	fn test_crack() {
+		let mut random = Random::new(42);
+		let values = format!("{}\n{}\n", random.next_i32(), random.next_i32());
+
+		assert_eq!(crack("next-int", None, &values), Ok(vec![42]));
+
+		let mut random = Random::new(0xFEED_5EED);
+		let values: Vec<String> = (0..14).map(|_| random.next_i32_bound(16).to_string()).collect();
+
+		assert_eq!(crack("next-int", Some(16), &values.join(" ")), Ok(vec![0xFEED_5EED]));
+
+		let mut random = Random::new(7);
+		let values = format!("{} {} {}", random.next_f32(), random.next_f32(), random.next_f32());
+
+		assert_eq!(crack("next-float", None, &values), Ok(vec![7]));
+		assert!(crack("next-boolean", None, "true false").is_err());
+		assert!(crack("next-long", None, "1.5").is_err());
+		assert!(crack("gaussian", None, "0.5").is_err());
+	}
+
+	#[test]
+	fn test_sync() {
+		let mut random = Random::new(42);
+		random.next_i64();
+
+		let values: Vec<String> = (0..20).map(|_| random.next_f64().to_string()).collect();
+		let reference = values.join("\n");
+
+		assert_eq!(
+			sync("next-double", None, 42, &reference, 100),
+			Ok(Sync { offset: 2, matched: 20, divergence: None })
+		);
+
+		// An extra call in the port shifts the rest of the sequence
+		let mut random = Random::new(42);
+		let mut values: Vec<String> = (0..5).map(|_| random.next_i32().to_string()).collect();
+		random.next_i32();
+		values.extend((0..5).map(|_| random.next_i32().to_string()));
+
+		let report = sync("next-int", None, 42, &values.join(" "), 0).unwrap();
+
+		assert_eq!(report.offset, 0);
+		assert_eq!(report.matched, 5);
+		assert!(report.divergence.is_some());
+
+		assert_eq!(
+			sync("next-float", None, 42, "1.0E-4", 10),
+			Err("the first value does not appear within 10 steps".to_string())
+		);
+		assert!(sync("next-bytes", None, 42, "00", 10).is_err());
+		assert!(sync("next-int", None, 42, "", 10).is_err());
+	}
+
+	#[test]
+	fn test_convert() {
+		assert_eq!(convert("text", "glacier"), Ok(vec!["108181935".to_string()]));
+		assert_eq!(convert("text", " -42 "), Ok(vec!["-42".to_string()]));
+		assert!(convert("text", "").is_err());
+
+		for &hash in &[0, 1, -1, 108_181_935, i32::MIN, i32::MAX] {
+			let text = text_for_hash(hash);
+
+			assert_eq!(convert("text", &text), Ok(vec![hash.to_string()]), "{}", text);
+		}
+
+		assert_eq!(convert("hash", "108181935"), Ok(vec![text_for_hash(108_181_935)]));
+		assert!(convert("hash", "4294967296").is_err());
+
+		assert_eq!(convert("seed", "0"), Ok(vec!["0x0005deece66d".to_string()]));
+		assert_eq!(convert("state", "0x5deece66d"), Ok(vec!["0x000000000000".to_string()]));
+		assert!(convert("state", "0x1000000000000").is_err());
+
+		let world_seed = Random::new(42).next_i64();
+		let structure = convert("structure", &(world_seed & 0xFFFF_FFFF_FFFF).to_string()).unwrap();
+
+		assert!(structure.contains(&world_seed.to_string()));
+
+		let sisters = convert("sisters", "-1").unwrap();
+
+		assert_eq!(sisters.len(), 1 << 16);
+		assert_eq!(sisters[0], "281474976710655");
+		assert_eq!(sisters[sisters.len() - 1], "-1");
+		assert!(convert("reverse", "1").is_err());
+	}
+
+	#[test]
+	fn test_render() {
+		let args = |args: &str| args.split_whitespace().map(str::to_string).collect::<Vec<String>>();
+
+		let slime = parse_map_options("slime", &args("--seed 42 --x -3 --z 5 --width 7 --height 4")).unwrap();
+		let text = String::from_utf8(render(&slime)).unwrap();
+
+		for (row, line) in text.lines().enumerate() {
+			for (column, cell) in line.chars().enumerate() {
+				let expected = minecraft::is_slime_chunk(42, column as i32 - 3, row as i32 + 5);
+
+				assert_eq!(cell == '#', expected, "chunk {} {}", column, row);
+			}
+		}
+
+		assert_eq!(text.lines().count(), 4);
+
+		let small = parse_map_options("slime", &args("--seed 42 --width 3 --height 2 --format ppm --scale 2")).unwrap();
+		let image = render(&small);
+
+		assert!(image.starts_with(b"P6\n6 4\n255\n"));
+		assert_eq!(image.len(), b"P6\n6 4\n255\n".len() + 6 * 4 * 3);
+
+		// One attempt per region, and every region of the window is fully visible
+		let villages = parse_map_options("structure", &args("--seed 42 --x 0 --z 0 --width 68 --height 34")).unwrap();
+		let text = String::from_utf8(render(&villages)).unwrap();
+
+		assert_eq!(text.matches('#').count(), 2);
+		assert!(text.contains(','));
+
+		assert!(parse_map_options("structure", &args("--seed 1 --spacing 4 --separation 4")).is_err());
+		assert!(parse_map_options("biome", &args("--seed 1")).is_err());
+		assert!(parse_map_options("slime", &args("--width 3")).is_err());
+	}
+}
@@ -0,0 +1,73 @@
+use Random;
+
+/// Reads of any width from 1 to 64 bits, served from whole `nextInt` calls.
+///
+/// The generator is consumed as a stream of `next(32)` values, the same calls as `next_i32`, concatenated most
+/// significant bit first. Each read takes the next bits of that stream, with the first bit as the most significant
+/// bit of the result, so bits left over from one call are used by the following reads instead of being thrown away.
+/// For example, reads of 5, 17 and 10 bits together consume exactly one call. Bits still buffered when the reader is
+/// dropped are lost. The lower 16 bits of the state are never used, as with `BufferedBools`.
+#[derive(Debug)]
+pub struct BitReader<'a> {
+	random: &'a mut Random,
+	/// The unread bits of the last call, left aligned
+	block: u32,
+	remaining: u8
+}
+
+impl<'a> BitReader<'a> {
+	pub fn new(random: &'a mut Random) -> Self {
+		BitReader {
+			random,
+			block: 0,
+			remaining: 0
+		}
+	}
+
+	/// Returns the next `bits` bits of the stream as the low bits of the result.
+	///
+	/// # Panics
+	/// If `bits` is not between 1 and 64, the function panics.
+	pub fn read(&mut self, bits: u8) -> u64 {
+		if !(1..=64).contains(&bits) {
+			panic!("Bits must be between 1 and 64")
+		}
+
+		let mut value = 0u64;
+		let mut needed = bits;
+
+		while needed > 0 {
+			if self.remaining == 0 {
+				self.block = self.random.next_u32();
+				self.remaining = 32;
+			}
+
+			let taken = needed.min(self.remaining);
+
+			value = (value << taken) | (self.block as u64 >> (32 - taken));
+			self.block = ((self.block as u64) << taken) as u32;
+			self.remaining -= taken;
+			needed -= taken;
+		}
+
+		value
+	}
+
+	/// Returns the next bit of the stream.
+	#[inline]
+	pub fn read_bool(&mut self) -> bool {
+		self.read(1) != 0
+	}
+
+	/// Returns the number of bits left from the last call, which the next reads use before calling the generator.
+	#[inline]
+	pub fn buffered(&self) -> u8 {
+		self.remaining
+	}
+
+	/// Discards the bits left from the last call, so that the next read starts on a fresh call.
+	#[inline]
+	pub fn align(&mut self) {
+		self.remaining = 0;
+	}
+}
@@ -0,0 +1,51 @@
+use Random;
+
+/// Endless iterator over coin flips served from a buffer, for workloads that need many booleans but not Java call
+/// for call parity.
+///
+/// **This does not match Java.** Each step of the generator is consumed as a `nextInt`, whose 32 bits are then handed
+/// out one by one, most significant first, so one step serves 32 booleans instead of one. The lower 16 bits of the
+/// state are not used: their periods are too short to make good coin flips.
+#[derive(Debug)]
+pub struct BufferedBools<'a> {
+	random: &'a mut Random,
+	block: u32,
+	remaining: u8
+}
+
+impl<'a> BufferedBools<'a> {
+	pub fn new(random: &'a mut Random) -> Self {
+		BufferedBools {
+			random,
+			block: 0,
+			remaining: 0
+		}
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false.
+	#[inline]
+	pub fn next_bool(&mut self) -> bool {
+		if self.remaining == 0 {
+			self.block = self.random.next_u32();
+			self.remaining = 32;
+		}
+
+		let item = self.block & 0x80000000 != 0;
+		self.block <<= 1;
+		self.remaining -= 1;
+
+		item
+	}
+}
+
+impl<'a> Iterator for BufferedBools<'a> {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<bool> {
+		Some(self.next_bool())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use {Error, Random, MASK};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+	Seed(u64),
+	State(u64),
+	#[cfg(feature = "std")]
+	Time
+}
+
+/// Builder for a `Random`, created with `Random::builder()`, that gathers the different ways of choosing a seed in
+/// one place.
+///
+/// Only `Random` is built so far. Building the other generators of the crate, and an option choosing between the
+/// strict and platform math of the gaussians, are deferred: the generators do not share a way of being seeded yet,
+/// and `Random` has no per-instance math setting to carry the option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomBuilder {
+	source: Option<Source>
+}
+
+impl RandomBuilder {
+	pub fn new() -> Self {
+		RandomBuilder { source: None }
+	}
+
+	/// Seeds the generator like `new Random(seed)`.
+	pub fn seed(mut self, seed: u64) -> Self {
+		self.source = Some(Source::Seed(seed));
+		self
+	}
+
+	/// Seeds the generator like `new Random(seed)`, taking the seed as a Java `long`.
+	pub fn seed_i64(self, seed: i64) -> Self {
+		self.seed(seed as u64)
+	}
+
+	/// Seeds the generator from text the way Minecraft handles the world seed field, see `text_seed`. Unlike
+	/// Minecraft, text that is empty once trimmed is hashed as well, giving the seed 0, rather than choosing a random
+	/// seed.
+	pub fn seed_text(self, text: &str) -> Self {
+		self.seed_i64(text_seed(text).unwrap_or(0))
+	}
+
+	/// Starts the generator at the raw internal state, bypassing the scrambling done by `new Random(seed)`. Bits above
+	/// the low 48 are ignored.
+	pub fn state(mut self, state: u64) -> Self {
+		self.source = Some(Source::State(state));
+		self
+	}
+
+	/// Seeds the generator from the clock, like `new Random()` does in Java.
+	#[cfg(feature = "std")]
+	pub fn time_seeded(mut self) -> Self {
+		self.source = Some(Source::Time);
+		self
+	}
+
+	/// Creates the generator. Without a seed source, it is seeded from the clock as with `time_seeded`.
+	///
+	/// # Panics
+	/// Without the `std` feature there is no clock, and the function panics if no seed source was chosen.
+	pub fn build(&self) -> Random {
+		match self.try_build() {
+			Ok(random) => random,
+			Err(_) => panic!("A seed source is required without std")
+		}
+	}
+
+	/// Like `build`, but returns `Error::MissingSeed` instead of panicking.
+	pub fn try_build(&self) -> Result<Random, Error> {
+		match self.source {
+			Some(Source::Seed(seed)) => Ok(Random::new(seed)),
+			Some(Source::State(state)) => Ok(Random {
+				state: state & MASK,
+				next_gaussian: None
+			}),
+			#[cfg(feature = "std")]
+			Some(Source::Time) | None => Ok(Random::new(time_seed())),
+			#[cfg(not(feature = "std"))]
+			None => Err(Error::MissingSeed)
+		}
+	}
+}
+
+impl Default for RandomBuilder {
+	fn default() -> Self {
+		RandomBuilder::new()
+	}
+}
+
+impl Random {
+	/// Returns a builder for choosing how the generator is seeded.
+	pub fn builder() -> RandomBuilder {
+		RandomBuilder::new()
+	}
+}
+
+/// Returns the seed of a world whose seed field contains `text`, following `WorldOptions.parseSeed`. Surrounding
+/// whitespace is trimmed, text that parses as a `long` is used directly, and anything else is hashed with
+/// `String.hashCode()`. Empty text gives None, for which the game picks a random seed.
+pub(crate) fn text_seed(text: &str) -> Option<i64> {
+	// String.trim() removes every character up to and including the space
+	let text = text.trim_matches(|c| c <= ' ');
+
+	if text.is_empty() {
+		return None;
+	}
+
+	Some(text.parse::<i64>().unwrap_or_else(|_| java_string_hash(text) as i64))
+}
+
+/// Computes `String.hashCode()` over the UTF-16 code units of `text`.
+pub fn java_string_hash(text: &str) -> i32 {
+	text.encode_utf16().fold(0i32, |hash, unit| hash.wrapping_mul(31).wrapping_add(unit as i32))
+}
+
+/// Returns a new seed in the manner of the `Random()` constructor: the next value of a global uniquifier, which
+/// separates generators created within the same clock tick, mixed with the current time in nanoseconds.
+#[cfg(feature = "std")]
+pub fn time_seed() -> u64 {
+	static SEED_UNIQUIFIER: AtomicU64 = AtomicU64::new(8682522807148012);
+
+	let previous = SEED_UNIQUIFIER.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+		Some(current.wrapping_mul(1181783497276652981))
+	});
+
+	let uniquifier = match previous {
+		Ok(current) | Err(current) => current.wrapping_mul(1181783497276652981)
+	};
+
+	let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos() as u64).unwrap_or(0);
+
+	uniquifier ^ nanos
+}
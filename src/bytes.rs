@@ -0,0 +1,132 @@
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use {batch, Random};
+
+/// Endless iterator over the `nextBytes` stream of a generator.
+///
+/// Bytes come out in the same order as a single `next_bytes` call over the total number of bytes taken: each int is
+/// consumed low byte first, and its leftover bytes are held until the next call.
+#[derive(Debug)]
+pub struct Bytes<'a> {
+	random: &'a mut Random,
+	block: u32,
+	remaining: u8
+}
+
+impl<'a> Bytes<'a> {
+	pub fn new(random: &'a mut Random) -> Self {
+		Bytes {
+			random,
+			block: 0,
+			remaining: 0
+		}
+	}
+}
+
+impl<'a> Iterator for Bytes<'a> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		if self.remaining == 0 {
+			self.block = self.random.next_u32();
+			self.remaining = 4;
+		}
+
+		let item = (self.block & 0xFF) as u8;
+		self.block >>= 8;
+		self.remaining -= 1;
+
+		Some(item)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl Random {
+	/// Fills the byte array with random bytes, writing each int low byte first. This is `next_bytes` under a name that
+	/// states its byte order, and matches `nextBytes`.
+	#[inline]
+	pub fn next_bytes_le(&mut self, bytes: &mut [u8]) {
+		self.next_bytes(bytes)
+	}
+
+	/// Fills the byte array with random bytes, writing each int high byte first. This matches Java code that writes
+	/// `nextInt()` values into a buffer, such as `ByteBuffer.putInt` or `DataOutputStream.writeInt`, rather than
+	/// `nextBytes`. A partial int at the end is filled with its highest bytes.
+	pub fn next_bytes_be(&mut self, bytes: &mut [u8]) {
+		let mut batches = bytes.chunks_exact_mut(4 * batch::LANES);
+		let mut blocks = [0; batch::LANES];
+
+		for batch in &mut batches {
+			self.fill_u32(&mut blocks);
+
+			for (chunk, block) in batch.chunks_exact_mut(4).zip(blocks.iter()) {
+				chunk.copy_from_slice(&block.to_be_bytes());
+			}
+		}
+
+		for chunk in batches.into_remainder().chunks_mut(4) {
+			let len = chunk.len();
+
+			chunk.copy_from_slice(&self.next_u32().to_be_bytes()[..len]);
+		}
+	}
+
+	/// Returns the bytes of a `next_i32` value, low byte first, the same bytes `nextBytes` writes for one int.
+	#[inline]
+	pub const fn next_i32_le_bytes(&mut self) -> [u8; 4] {
+		self.next_i32().to_le_bytes()
+	}
+
+	/// Returns the bytes of a `next_i32` value, high byte first, as written by `ByteBuffer.putInt(random.nextInt())`.
+	#[inline]
+	pub const fn next_i32_be_bytes(&mut self) -> [u8; 4] {
+		self.next_i32().to_be_bytes()
+	}
+
+	/// Returns the bytes of a `next_i64` value, low byte first, as written by a little endian
+	/// `ByteBuffer.putLong(random.nextLong())`.
+	#[inline]
+	pub const fn next_i64_le_bytes(&mut self) -> [u8; 8] {
+		self.next_i64().to_le_bytes()
+	}
+
+	/// Returns the bytes of a `next_i64` value, high byte first, as written by `ByteBuffer.putLong(random.nextLong())`.
+	#[inline]
+	pub const fn next_i64_be_bytes(&mut self) -> [u8; 8] {
+		self.next_i64().to_be_bytes()
+	}
+}
+
+/// Adapter yielding the endless `nextBytes` stream of a generator through `std::io::Read`.
+///
+/// Bytes come out in the same order as [`Bytes`], no matter how the reads are split up. Every read fills the whole
+/// buffer.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RandomReader<'a> {
+	bytes: Bytes<'a>
+}
+
+#[cfg(feature = "std")]
+impl<'a> RandomReader<'a> {
+	pub fn new(random: &'a mut Random) -> Self {
+		RandomReader {
+			bytes: Bytes::new(random)
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> Read for RandomReader<'a> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		for (item, byte) in buf.iter_mut().zip(&mut self.bytes) {
+			*item = byte;
+		}
+
+		Ok(buf.len())
+	}
+}
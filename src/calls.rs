@@ -0,0 +1,30 @@
+//! Number of calls to `Random::next` consumed by each method, for modelling the generator without running it.
+//!
+//! Methods that can reject values, `next_i32_bound` and `next_gaussian`, have no fixed count. Their `*_counted`
+//! variants return the count alongside the value instead.
+
+/// `next_i32`, `next_u32`
+pub const NEXT_I32: u32 = 1;
+
+/// `next_i64`, `next_u64`
+pub const NEXT_I64: u32 = 2;
+
+/// `next_bool`
+pub const NEXT_BOOL: u32 = 1;
+
+/// `next_f32`
+pub const NEXT_F32: u32 = 1;
+
+/// `next_f64`
+pub const NEXT_F64: u32 = 2;
+
+/// `next_i32_bound` and `next_u32_bound` when the bound is a power of two. Other bounds take at least this many.
+pub const NEXT_I32_BOUND_MIN: u32 = 1;
+
+/// Each attempt at a gaussian pair takes two doubles, and a pair is generated for every other `next_gaussian`.
+pub const NEXT_GAUSSIAN_PER_ATTEMPT: u32 = 2 * NEXT_F64;
+
+/// Returns the number of calls `next_bytes` makes to fill `len` bytes, one per started block of 4 bytes.
+pub const fn next_bytes(len: usize) -> usize {
+	len.div_ceil(4)
+}
@@ -0,0 +1,223 @@
+//! Snapshots of the full state of a generator, including any pending gaussian, that can be restored later or stored
+//! in a stable binary form.
+//!
+//! For save files, every generator also has `to_bytes` and `from_bytes`, which use the versioned form of its
+//! checkpoint: a format version byte followed by the 26 bytes of `Checkpoint::to_bytes`. The version is
+//! `Checkpoint::VERSION`, currently 1. Later versions of the crate will keep decoding every earlier version, so saved
+//! states remain readable even if the internal representation of the generators changes.
+//!
+//! `GwtRandom` and `JavaLcg` run the algorithm of `java.util.Random` too, and share the checkpoints of `Random`. Other
+//! instantiations of `Lcg` have none, as the binary form has no room for their constants.
+
+use {Error, Random, MASK};
+
+/// Identifies the algorithm a `Checkpoint` was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+	/// `java.util.Random`, the 48-bit LCG of `Random`
+	JavaRandom,
+	/// `Xoroshiro128Plus`
+	Xoroshiro128Plus,
+	/// `Xoroshiro128PlusPlus`
+	Xoroshiro128PlusPlus,
+	/// `Xoroshiro128StarStar`
+	Xoroshiro128StarStar,
+	/// SplitMix64, the algorithm of `SplittableRandom`
+	SplitMix64
+}
+
+impl Algorithm {
+	/// Returns the tag used for the algorithm in the binary form of a `Checkpoint`.
+	pub fn tag(self) -> u8 {
+		match self {
+			Algorithm::JavaRandom => 1,
+			Algorithm::Xoroshiro128Plus => 2,
+			Algorithm::Xoroshiro128PlusPlus => 3,
+			Algorithm::Xoroshiro128StarStar => 4,
+			Algorithm::SplitMix64 => 5
+		}
+	}
+
+	/// Returns the algorithm with the given tag, if any.
+	pub fn from_tag(tag: u8) -> Option<Self> {
+		match tag {
+			1 => Some(Algorithm::JavaRandom),
+			2 => Some(Algorithm::Xoroshiro128Plus),
+			3 => Some(Algorithm::Xoroshiro128PlusPlus),
+			4 => Some(Algorithm::Xoroshiro128StarStar),
+			5 => Some(Algorithm::SplitMix64),
+			_ => None
+		}
+	}
+}
+
+/// Saved state of a generator, created with `save` and applied with `restore`.
+///
+/// The binary form is 26 bytes: the algorithm tag, a byte that is 1 if a gaussian is pending and 0 otherwise, two
+/// big endian words of state, and the bits of the pending gaussian, big endian, or zero if there is none. For
+/// `Random`, the first word holds the 48-bit state and the second is zero, and for SplitMix64 the words are the state
+/// and the gamma. The form will not change between versions.
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint {
+	algorithm: Algorithm,
+	state: [u64; 2],
+	next_gaussian: Option<f64>
+}
+
+/// Checkpoints are equal if they restore to identical generators, with pending gaussians compared bit for bit.
+impl PartialEq for Checkpoint {
+	fn eq(&self, other: &Checkpoint) -> bool {
+		self.algorithm == other.algorithm && self.state == other.state &&
+			self.next_gaussian.map(f64::to_bits) == other.next_gaussian.map(f64::to_bits)
+	}
+}
+
+impl Eq for Checkpoint {}
+
+impl Checkpoint {
+	/// Creates a checkpoint from its parts, returning None if they are not a state the algorithm can be in. Only
+	/// `Random` can have a pending gaussian, which must be finite, and the gamma of SplitMix64 must be odd.
+	pub fn new(algorithm: Algorithm, state: [u64; 2], next_gaussian: Option<f64>) -> Option<Self> {
+		let valid = match algorithm {
+			Algorithm::JavaRandom => {
+				state[0] <= MASK && state[1] == 0 && next_gaussian.is_none_or(f64::is_finite)
+			},
+			Algorithm::Xoroshiro128Plus | Algorithm::Xoroshiro128PlusPlus | Algorithm::Xoroshiro128StarStar => {
+				next_gaussian.is_none() && state != [0, 0]
+			},
+			Algorithm::SplitMix64 => next_gaussian.is_none() && state[1] & 1 == 1
+		};
+
+		if valid {
+			Some(Checkpoint { algorithm, state, next_gaussian })
+		} else {
+			None
+		}
+	}
+
+	/// Returns the algorithm of the generator the checkpoint was taken from.
+	pub fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	/// Returns the raw state words of the generator.
+	pub fn state(&self) -> [u64; 2] {
+		self.state
+	}
+
+	/// Returns the pending gaussian, if any.
+	pub fn next_gaussian(&self) -> Option<f64> {
+		self.next_gaussian
+	}
+
+	/// Encodes the checkpoint in its stable binary form.
+	pub fn to_bytes(&self) -> [u8; 26] {
+		let mut bytes = [0; 26];
+
+		bytes[0] = self.algorithm.tag();
+		bytes[1] = self.next_gaussian.is_some() as u8;
+		bytes[2..10].copy_from_slice(&self.state[0].to_be_bytes());
+		bytes[10..18].copy_from_slice(&self.state[1].to_be_bytes());
+		bytes[18..].copy_from_slice(&self.next_gaussian.unwrap_or(0.0).to_bits().to_be_bytes());
+
+		bytes
+	}
+
+	/// Decodes a checkpoint encoded by `to_bytes`, returning None if the bytes are not a valid checkpoint.
+	pub fn from_bytes(bytes: [u8; 26]) -> Option<Self> {
+		let word = |range: &[u8]| {
+			let mut word = [0; 8];
+			word.copy_from_slice(range);
+
+			u64::from_be_bytes(word)
+		};
+
+		let algorithm = Algorithm::from_tag(bytes[0])?;
+		let state = [word(&bytes[2..10]), word(&bytes[10..18])];
+		let gaussian = f64::from_bits(word(&bytes[18..]));
+
+		let next_gaussian = match bytes[1] {
+			0 if gaussian.to_bits() == 0 => None,
+			1 => Some(gaussian),
+			_ => return None
+		};
+
+		Checkpoint::new(algorithm, state, next_gaussian)
+	}
+
+	/// Encodes the checkpoint in the versioned form: `Checkpoint::VERSION` followed by the bytes of `to_bytes`.
+	pub fn to_versioned_bytes(&self) -> [u8; 27] {
+		let mut bytes = [0; 27];
+
+		bytes[0] = Checkpoint::VERSION;
+		bytes[1..].copy_from_slice(&self.to_bytes());
+
+		bytes
+	}
+
+	/// Decodes a checkpoint in the versioned form, of any version up to `Checkpoint::VERSION`.
+	///
+	/// # Errors
+	/// If the bytes were written in a later version, `Error::UnsupportedVersion` is returned. If they are not a valid
+	/// checkpoint of their version, `Error::InvalidState` is returned.
+	pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		match bytes.split_first() {
+			Some((&1, body)) if body.len() == 26 => {
+				let mut checkpoint = [0; 26];
+				checkpoint.copy_from_slice(body);
+
+				Checkpoint::from_bytes(checkpoint).ok_or(Error::InvalidState)
+			},
+			Some((&version, _)) if version > Checkpoint::VERSION => Err(Error::UnsupportedVersion),
+			_ => Err(Error::InvalidState)
+		}
+	}
+
+	/// Version of the binary form written by `to_versioned_bytes`
+	pub const VERSION: u8 = 1;
+}
+
+impl Random {
+	/// Captures the full state of the generator.
+	pub fn save(&self) -> Checkpoint {
+		Checkpoint {
+			algorithm: Algorithm::JavaRandom,
+			state: [self.state, 0],
+			next_gaussian: self.next_gaussian
+		}
+	}
+
+	/// Returns the generator to the state captured in `checkpoint`.
+	///
+	/// # Errors
+	/// If the checkpoint was taken from a different algorithm, `Error::AlgorithmMismatch` is returned and the
+	/// generator is left untouched.
+	pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		if checkpoint.algorithm != Algorithm::JavaRandom {
+			return Err(Error::AlgorithmMismatch);
+		}
+
+		self.state = checkpoint.state[0] & MASK;
+		self.next_gaussian = checkpoint.next_gaussian;
+
+		Ok(())
+	}
+
+	/// Encodes the full state in the versioned binary form, see `Checkpoint::to_versioned_bytes`.
+	pub fn to_bytes(&self) -> [u8; 27] {
+		self.save().to_versioned_bytes()
+	}
+
+	/// Decodes a generator encoded by `to_bytes`, in this or an earlier version of the crate.
+	///
+	/// # Errors
+	/// Besides the errors of `Checkpoint::from_versioned_bytes`, `Error::AlgorithmMismatch` is returned if the bytes
+	/// hold the state of a different algorithm.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		let mut random = Random::new(0);
+		random.restore(&Checkpoint::from_versioned_bytes(bytes)?)?;
+
+		Ok(random)
+	}
+}
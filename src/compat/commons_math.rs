@@ -0,0 +1,350 @@
+//! The samplers of Apache Commons Math 3's `RandomDataGenerator`, when driven by a `JDKRandomGenerator`, the
+//! `RandomGenerator` that delegates to `java.util.Random`.
+//!
+//! Each method constructs the corresponding distribution in Commons Math and calls `sample()`, so no state is carried
+//! between calls apart from the generator. The algorithms are the same as in Commons Math 3.6.1: Ahrens and Dieter's
+//! SA algorithm for exponentials, Marsaglia and Tsang's method for gamma variates with a shape of at least 1 and
+//! Ahrens and Dieter's GS algorithm below it, and for Poisson variates, multiplication of uniforms below a mean of
+//! 40 and Devroye's rejection method above it.
+//!
+//! Commons Math evaluates `exp`, `log` and `pow` with its own `FastMath` rather than `StrictMath`, and so does this
+//! port, through `compat::fastmath`. `FastMath.sqrt` is `Math.sqrt`, which is correctly rounded like `strictmath`.
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use compat::fastmath;
+use strictmath;
+use Random;
+
+/// Euler's number, `FastMath.E`
+const E: f64 = core::f64::consts::E;
+
+/// Means below this are sampled by multiplying uniforms by `PoissonDistribution`
+const POISSON_PIVOT: f64 = 40.0;
+
+/// Factorials up to 20, the largest that fits in an `i64`, as in `CombinatoricsUtils`
+const FACTORIALS: [i64; 21] = {
+	let mut factorials = [1; 21];
+	let mut n = 1;
+
+	while n < 21 {
+		factorials[n] = factorials[n - 1] * n as i64;
+		n += 1;
+	}
+
+	factorials
+};
+
+/// A `RandomDataGenerator` constructed with a `JDKRandomGenerator`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RandomDataGenerator {
+	random: Random
+}
+
+impl RandomDataGenerator {
+	/// Wraps a generator, like `new RandomDataGenerator(new JDKRandomGenerator())` with the generator's state.
+	pub fn new(random: Random) -> Self {
+		RandomDataGenerator { random }
+	}
+
+	/// Creates the generator with a seed, like `new RandomDataGenerator(new JDKRandomGenerator(seed))`.
+	pub fn from_seed(seed: i64) -> Self {
+		RandomDataGenerator::new(Random::new(seed as u64))
+	}
+
+	/// Reseeds the underlying generator, like `reSeed(seed)`.
+	pub fn re_seed(&mut self, seed: i64) {
+		self.random.set_seed(seed as u64);
+	}
+
+	/// Returns the underlying generator.
+	pub fn random(&mut self) -> &mut Random {
+		&mut self.random
+	}
+
+	/// Returns the underlying generator, consuming the wrapper.
+	pub fn into_inner(self) -> Random {
+		self.random
+	}
+
+	/// Returns a Poisson distributed value with the given mean, like `nextPoisson(mean)`.
+	///
+	/// # Panics
+	/// If `mean` is zero or negative, the function panics.
+	pub fn next_poisson(&mut self, mean: f64) -> i64 {
+		if mean <= 0.0 {
+			panic!("Mean must be > 0")
+		}
+
+		// PoissonDistribution.sample() clamps to the range of an int
+		poisson(&mut self.random, mean).min(i32::MAX as i64)
+	}
+
+	/// Returns an exponentially distributed value with the given mean, like `nextExponential(mean)`.
+	///
+	/// # Panics
+	/// If `mean` is zero or negative, the function panics.
+	pub fn next_exponential(&mut self, mean: f64) -> f64 {
+		if mean <= 0.0 {
+			panic!("Mean must be > 0")
+		}
+
+		mean * exponential(&mut self.random)
+	}
+
+	/// Returns a gamma distributed value with the given shape and scale, like `nextGamma(shape, scale)`.
+	///
+	/// # Panics
+	/// If `shape` or `scale` is zero or negative, the function panics.
+	pub fn next_gamma(&mut self, shape: f64, scale: f64) -> f64 {
+		if shape <= 0.0 {
+			panic!("Shape must be > 0")
+		}
+
+		if scale <= 0.0 {
+			panic!("Scale must be > 0")
+		}
+
+		gamma(&mut self.random, shape, scale)
+	}
+
+	/// Returns a string of `len` random lowercase hexadecimal digits, like `nextHexString(len)`. The digits come from
+	/// `len / 2 + 1` random bytes, each offset by 128 before being formatted.
+	///
+	/// # Panics
+	/// If `len` is 0, the function panics.
+	#[cfg(feature = "std")]
+	pub fn next_hex_string(&mut self, len: usize) -> String {
+		if len == 0 {
+			panic!("Length must be > 0")
+		}
+
+		let mut bytes = vec![0; len / 2 + 1];
+		self.random.next_bytes(&mut bytes);
+
+		let mut hex = String::with_capacity(bytes.len() * 2);
+
+		for byte in bytes {
+			let value = (byte as i8 as i32 + 128) as u8;
+
+			hex.push(char::from_digit((value >> 4) as u32, 16).expect("Digit is below 16"));
+			hex.push(char::from_digit((value & 15) as u32, 16).expect("Digit is below 16"));
+		}
+
+		hex.truncate(len);
+		hex
+	}
+}
+
+/// `ExponentialDistribution.sample()` with a mean of 1.
+///
+/// The table `EXPONENTIAL_SA_QI` holds the partial sums of `ln(2)^i / i!` up to the first that reaches 1. Each
+/// sample reads a prefix of it, so the entries are computed as they are needed, with the same operations as the
+/// static initializer.
+fn exponential(random: &mut Random) -> f64 {
+	let ln2 = fastmath::log(2.0);
+	let mut a = 0.0;
+	let mut u = random.next_f64();
+
+	while u < 0.5 {
+		a += ln2;
+		u *= 2.0;
+	}
+
+	u += u - 1.0;
+
+	if u <= ln2 {
+		return a + u;
+	}
+
+	let mut qi = ln2;
+	let mut i = 1;
+	let mut u2 = random.next_f64();
+	let mut umin = u2;
+
+	loop {
+		i += 1;
+		qi += fastmath::pow_i32(ln2, i as i32) / factorial(i) as f64;
+
+		u2 = random.next_f64();
+
+		if u2 < umin {
+			umin = u2;
+		}
+
+		if u <= qi {
+			break;
+		}
+	}
+
+	a + umin * ln2
+}
+
+/// `GammaDistribution.sample()`
+fn gamma(random: &mut Random, shape: f64, scale: f64) -> f64 {
+	if shape < 1.0 {
+		loop {
+			let u = random.next_f64();
+			let b = 1.0 + shape / E;
+			let p = b * u;
+
+			if p <= 1.0 {
+				let x = fastmath::pow(p, 1.0 / shape);
+
+				if random.next_f64() <= fastmath::exp(-x) {
+					return scale * x;
+				}
+			} else {
+				let x = -fastmath::log((b - p) / shape);
+
+				if random.next_f64() <= fastmath::pow(x, shape - 1.0) {
+					return scale * x;
+				}
+			}
+		}
+	}
+
+	let d = shape - 1.0 / 3.0;
+	let c = 1.0 / (3.0 * strictmath::sqrt(d));
+
+	loop {
+		let x = random.next_gaussian();
+		let v = (1.0 + c * x) * (1.0 + c * x) * (1.0 + c * x);
+
+		if v <= 0.0 {
+			continue;
+		}
+
+		let x2 = x * x;
+		let u = random.next_f64();
+
+		if u < 1.0 - 0.0331 * x2 * x2 {
+			return scale * d * v;
+		}
+
+		if fastmath::log(u) < 0.5 * x2 + d * (1.0 - v + fastmath::log(v)) {
+			return scale * d * v;
+		}
+	}
+}
+
+/// `PoissonDistribution.nextPoisson(mean)`, before clamping.
+fn poisson(random: &mut Random, mean: f64) -> i64 {
+	if mean < POISSON_PIVOT {
+		let p = fastmath::exp(-mean);
+		let mut n = 0;
+		let mut r = 1.0;
+
+		while (n as f64) < 1000.0 * mean {
+			r *= random.next_f64();
+
+			if r >= p {
+				n += 1;
+			} else {
+				return n;
+			}
+		}
+
+		return n;
+	}
+
+	let lambda = floor(mean);
+	let lambda_fractional = mean - lambda;
+	let log_lambda = fastmath::log(lambda);
+	let log_lambda_factorial = factorial_log(lambda as i32);
+	// Compared against Double.MIN_VALUE, the smallest subnormal
+	let y2 = if lambda_fractional < f64::from_bits(1) { 0 } else { poisson(random, lambda_fractional) };
+	let delta = strictmath::sqrt(lambda * fastmath::log(32.0 * lambda / core::f64::consts::PI + 1.0));
+	let half_delta = delta / 2.0;
+	let twolpd = 2.0 * lambda + delta;
+	let a1 = strictmath::sqrt(core::f64::consts::PI * twolpd) * fastmath::exp(1.0 / (8.0 * lambda));
+	let a2 = (twolpd / delta) * fastmath::exp(-delta * (1.0 + delta) / twolpd);
+	let a_sum = a1 + a2 + 1.0;
+	let p1 = a1 / a_sum;
+	let p2 = a2 / a_sum;
+	let c1 = 1.0 / (8.0 * lambda);
+
+	let mut y;
+
+	loop {
+		let x;
+		let v;
+		let u = random.next_f64();
+
+		if u <= p1 {
+			let n = random.next_gaussian();
+			x = n * strictmath::sqrt(lambda + half_delta) - 0.5;
+
+			if x > delta || x < -lambda {
+				continue;
+			}
+
+			y = if x < 0.0 { floor(x) } else { ceil(x) };
+			let e = exponential(random);
+			v = -e - (n * n / 2.0) + c1;
+		} else if u > p1 + p2 {
+			y = lambda;
+			break;
+		} else {
+			x = delta + (twolpd / delta) * exponential(random);
+			y = ceil(x);
+			v = -exponential(random) - delta * (x + 1.0) / twolpd;
+		}
+
+		let a = if x < 0.0 { 1.0 } else { 0.0 };
+		let t = y * (y + 1.0) / (2.0 * lambda);
+
+		if v < -t && a == 0.0 {
+			y += lambda;
+			break;
+		}
+
+		let qr = t * ((2.0 * y + 1.0) / (6.0 * lambda) - 1.0);
+		let qa = qr - (t * t) / (3.0 * (lambda + a * (y + 1.0)));
+
+		if v < qa {
+			y += lambda;
+			break;
+		}
+
+		if v > qr {
+			continue;
+		}
+
+		if v < y * log_lambda - factorial_log((y + lambda) as i32) + log_lambda_factorial {
+			y += lambda;
+			break;
+		}
+	}
+
+	y2 + y as i64
+}
+
+fn factorial(n: usize) -> i64 {
+	FACTORIALS[n]
+}
+
+/// `CombinatoricsUtils.factorialLog(n)`
+fn factorial_log(n: i32) -> f64 {
+	if n < 21 {
+		return fastmath::log(FACTORIALS[n as usize] as f64);
+	}
+
+	(2..=n).fold(0.0, |sum, i| sum + fastmath::log(i as f64))
+}
+
+fn floor(value: f64) -> f64 {
+	// Doubles of magnitude 2^52 and above are already integers
+	if value.is_nan() || value.abs() >= 4503599627370496.0 {
+		return value;
+	}
+
+	let truncated = value as i64 as f64;
+
+	if value < truncated { truncated - 1.0 } else { truncated }
+}
+
+fn ceil(value: f64) -> f64 {
+	-floor(-value)
+}
@@ -0,0 +1,230 @@
+//! The `RandomSource.JDK` provider of Apache Commons RNG, `JDKRandom`, which wraps a `java.util.Random` behind the
+//! `UniformRandomProvider` interface.
+//!
+//! The provider only uses `nextInt()` of the wrapped generator and builds every other draw from it in `IntProvider`.
+//! Most draws still match `java.util.Random`, but `nextLong()` concatenates the two halves instead of adding them,
+//! and `nextBoolean()` hands out the bits of a cached `nextInt()` one at a time.
+//!
+//! With `std`, states saved by `saveState()` in Commons RNG 1.3 and later can be restored here and the other way
+//! around. The state is the byte array of the `RandomProviderDefaultState`: the length of the serialized
+//! `java.util.Random` as a big endian 32-bit integer, the serialized generator itself, and the 32-bit boolean cache.
+//!
+//! The bounded draws are those of `BaseProvider` in Commons RNG 1.3 and later, which replaced the `java.util.Random`
+//! algorithm of `nextInt(n)` with the multiply and reject method of Lemire. Earlier releases are not reproduced, and
+//! neither are the ranged draws added in 1.5.
+
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use float;
+use {Error, Random};
+
+/// Value of the boolean cache when no bits are left, the refill flag of a cached value after 31 shifts
+const EMPTY_BOOLEAN_SOURCE: i32 = 1;
+
+/// A `JDKRandom` provider, as returned by `RandomSource.JDK.create(seed)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JdkRandom {
+	random: Random,
+	boolean_source: i32
+}
+
+impl JdkRandom {
+	/// Creates the provider with a seed, like `RandomSource.JDK.create(seed)` with a `Long` seed.
+	pub fn new(seed: i64) -> Self {
+		JdkRandom::from_random(Random::new(seed as u64))
+	}
+
+	/// Wraps a generator, starting with an empty boolean cache.
+	pub fn from_random(random: Random) -> Self {
+		JdkRandom {
+			random,
+			boolean_source: EMPTY_BOOLEAN_SOURCE
+		}
+	}
+
+	/// Returns the wrapped generator.
+	pub fn random(&mut self) -> &mut Random {
+		&mut self.random
+	}
+
+	/// Returns the wrapped generator. Any cached boolean bits are lost.
+	pub fn into_inner(self) -> Random {
+		self.random
+	}
+
+	/// Same as `nextInt()`, and the same as `java.util.Random.nextInt()`.
+	pub fn next_int(&mut self) -> i32 {
+		self.random.next_i32()
+	}
+
+	/// Same as `nextLong()`: the bits of two draws from `next_int`, the first in the upper half.
+	///
+	/// Unlike `java.util.Random.nextLong()`, the halves are combined with an or, so the result differs whenever the
+	/// second draw is negative.
+	pub fn next_long(&mut self) -> i64 {
+		let high = self.next_int() as i64;
+		let low = self.next_int() as u32 as i64;
+
+		(high << 32) | low
+	}
+
+	/// Same as `nextInt(n)`: the upper half of the product of an unsigned draw from `next_int` and `max`, drawing
+	/// again while the lower half falls below `2^32 % max`.
+	///
+	/// # Panics
+	///
+	/// Panics if `max` is not positive, where `nextInt(n)` throws an `IllegalArgumentException`.
+	pub fn next_int_bound(&mut self, max: i32) -> i32 {
+		match self.try_next_int_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_int_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	pub fn try_next_int_bound(&mut self, max: i32) -> Result<i32, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		let max = max as u64;
+		let mut product = self.next_int() as u32 as u64 * max;
+
+		if (product as u32 as u64) < max {
+			let threshold = (1u64 << 32) % max;
+
+			while (product as u32 as u64) < threshold {
+				product = self.next_int() as u32 as u64 * max;
+			}
+		}
+
+		Ok((product >> 32) as i32)
+	}
+
+	/// Same as `nextLong(n)`: the remainder of the upper 63 bits of `next_long`, drawing again while the draw falls in
+	/// the incomplete last multiple of `max`.
+	///
+	/// # Panics
+	///
+	/// Panics if `max` is not positive, where `nextLong(n)` throws an `IllegalArgumentException`.
+	pub fn next_long_bound(&mut self, max: i64) -> i64 {
+		match self.try_next_long_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_long_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	pub fn try_next_long_bound(&mut self, max: i64) -> Result<i64, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		loop {
+			let bits = (self.next_long() as u64 >> 1) as i64;
+			let value = bits % max;
+
+			if bits.wrapping_sub(value).wrapping_add(max - 1) >= 0 {
+				return Ok(value);
+			}
+		}
+	}
+
+	/// Same as `nextBoolean()`. A draw from `next_int` supplies the next 32 booleans, starting at the lowest bit.
+	pub fn next_boolean(&mut self) -> bool {
+		let bits = self.boolean_source;
+
+		if bits == EMPTY_BOOLEAN_SOURCE {
+			let bits = self.next_int();
+
+			// The sign bit marks the end of the remaining 31 bits after they have been shifted out
+			self.boolean_source = i32::MIN | (bits as u32 >> 1) as i32;
+
+			return bits & 1 == 1;
+		}
+
+		self.boolean_source = (bits as u32 >> 1) as i32;
+
+		bits & 1 == 1
+	}
+
+	/// Same as `nextFloat()`, and the same as `java.util.Random.nextFloat()`.
+	pub fn next_float(&mut self) -> f32 {
+		float::unit_f32(self.next_int() as u32 >> 8)
+	}
+
+	/// Same as `nextDouble()`, and the same as `java.util.Random.nextDouble()`: 26 bits of a first draw from `next_int`
+	/// followed by 27 bits of a second.
+	pub fn next_double(&mut self) -> f64 {
+		let high = (self.next_int() as u32 >> 6) as u64;
+		let low = (self.next_int() as u32 >> 5) as u64;
+
+		float::unit_f64((high << 27) | low)
+	}
+
+	/// Same as `nextBytes(bytes)`, and the same as `java.util.Random.nextBytes(bytes)`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		self.random.next_bytes(bytes);
+	}
+
+	/// Returns the state in the form of `saveState()`.
+	#[cfg(feature = "std")]
+	pub fn save_state(&self) -> Vec<u8> {
+		let mut serialized = Vec::new();
+
+		self.random.write_serialized(&mut serialized).expect("Writing to a Vec cannot fail");
+
+		let mut state = Vec::with_capacity(8 + serialized.len());
+
+		state.extend_from_slice(&(serialized.len() as u32).to_be_bytes());
+		state.extend_from_slice(&serialized);
+		state.extend_from_slice(&self.boolean_source.to_be_bytes());
+
+		state
+	}
+
+	/// Restores a state in the form of `saveState()`, like `restoreState(state)`.
+	///
+	/// The provider is left untouched if the state is malformed.
+	#[cfg(feature = "std")]
+	pub fn restore_state(&mut self, state: &[u8]) -> io::Result<()> {
+		*self = JdkRandom::from_state(state)?;
+
+		Ok(())
+	}
+
+	/// Creates a provider from a state in the form of `saveState()`.
+	#[cfg(feature = "std")]
+	pub fn from_state(state: &[u8]) -> io::Result<Self> {
+		let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+
+		if state.len() < 4 {
+			return Err(invalid("state is too short for the serialized size"));
+		}
+
+		let (size, rest) = state.split_at(4);
+		let size = u32::from_be_bytes([size[0], size[1], size[2], size[3]]) as usize;
+
+		if rest.len() != size + 4 {
+			return Err(invalid("state size does not match the serialized size"));
+		}
+
+		let (serialized, boolean_source) = rest.split_at(size);
+		let mut reader = serialized;
+		let random = Random::read_serialized(&mut reader)?;
+
+		if !reader.is_empty() {
+			return Err(invalid("unexpected data after the serialized generator"));
+		}
+
+		Ok(JdkRandom {
+			random,
+			boolean_source: i32::from_be_bytes([
+				boolean_source[0], boolean_source[1], boolean_source[2], boolean_source[3]
+			])
+		})
+	}
+}
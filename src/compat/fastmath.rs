@@ -0,0 +1,666 @@
+//! `exp`, `log` and `pow` of Commons Math 3's `FastMath`, which its distributions use in place of `StrictMath`.
+//!
+//! Like Commons Math 3.6.1, the functions look up `exp` of the integer part and of the leading 10 bits of the fraction,
+//! and `log` of the leading 10 bits of the mantissa, in tables holding each value as a sum of two doubles. Commons
+//! Math ships the tables as literals printed by `FastMathCalc`; here they are computed by the compiler with the same
+//! operations, in the same order. The results are nearly always correctly rounded, so they differ from `strictmath`
+//! in the last bit now and then, which is enough to change a rejection sampler's draws.
+
+// Constants are kept exactly as written in the Commons Math sources.
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
+const HEX_40000000: f64 = 1073741824.0;
+const TWO_POWER_52: f64 = 4503599627370496.0;
+
+/// High and low parts of `ln(2)`
+const LN_2_A: f64 = 0.693147063255310059;
+const LN_2_B: f64 = 1.17304635250823482e-7;
+
+const EXP_INT_TABLE_MAX_INDEX: usize = 750;
+const EXP_INT_TABLE_LEN: usize = EXP_INT_TABLE_MAX_INDEX * 2;
+const EXP_FRAC_TABLE_LEN: usize = 1025;
+const LN_MANT_LEN: usize = 1024;
+
+/// Coefficients of `log` for inputs between 0.99 and 1.01
+const LN_QUICK_COEF: [[f64; 2]; 9] = [
+	[1.0, 5.669184079525E-24],
+	[-0.25, -0.25],
+	[0.3333333134651184, 1.986821492305628E-8],
+	[-0.25, -6.663542893624021E-14],
+	[0.19999998807907104, 1.1921056801463227E-8],
+	[-0.1666666567325592, -7.800414592973399E-9],
+	[0.1428571343421936, 5.650007086920087E-9],
+	[-0.12502530217170715, -7.44321345601866E-11],
+	[0.11113807559013367, 9.219544613762692E-9]
+];
+
+/// Coefficients of `log(1 + epsilon)` for `epsilon` below 2^-10, when `pow` needs the extra precision
+const LN_HI_PREC_COEF: [[f64; 2]; 6] = [
+	[1.0, -6.032174644509064E-23],
+	[-0.25, -0.25],
+	[0.3333333134651184, 1.9868161777724352E-8],
+	[-0.2499999701976776, -2.957007209750105E-8],
+	[0.19999954104423523, 1.5830993332061267E-10],
+	[-0.16624879837036133, -2.6033824355191673E-8]
+];
+
+/// Coefficients of the series of `log` in `FastMathCalc.slowLog`
+const LN_SPLIT_COEF: [[f64; 2]; 16] = [
+	[2.0, 0.0],
+	[0.6666666269302368, 3.9736429850260626E-8],
+	[0.3999999761581421, 2.3841857910019882E-8],
+	[0.2857142686843872, 1.7029898543501842E-8],
+	[0.2222222089767456, 1.3245471311735498E-8],
+	[0.1818181574344635, 2.4384203044354907E-8],
+	[0.1538461446762085, 9.140260083262505E-9],
+	[0.13333332538604736, 9.220590270857665E-9],
+	[0.11764700710773468, 1.2393345855018391E-8],
+	[0.10526403784751892, 8.251545029714408E-9],
+	[0.0952233225107193, 1.2675934823758863E-8],
+	[0.08713622391223907, 1.1430250008909141E-8],
+	[0.07842259109020233, 2.404307984052299E-9],
+	[0.08371849358081818, 1.176342548272881E-8],
+	[0.030589580535888672, 1.2958646899018938E-9],
+	[0.14982303977012634, 1.225743062930824E-8]
+];
+
+/// Factorials from 0 to 19, for the Taylor series of `FastMathCalc.slowexp`
+const FACT: [f64; 20] = [
+	1.0, 1.0, 2.0, 6.0, 24.0, 120.0, 720.0, 5040.0, 40320.0, 362880.0, 3628800.0, 39916800.0, 479001600.0,
+	6227020800.0, 87178291200.0, 1307674368000.0, 20922789888000.0, 355687428096000.0, 6402373705728000.0,
+	121645100408832000.0
+];
+
+/// The tables of `FastMath`, `ExpIntTable`, `ExpFracTable` and `lnMant`
+struct Tables {
+	exp_int: [[f64; 2]; EXP_INT_TABLE_LEN],
+	exp_frac: [[f64; 2]; EXP_FRAC_TABLE_LEN],
+	ln_mant: [[f64; 2]; LN_MANT_LEN]
+}
+
+static TABLES: Tables = {
+	let mut tables = Tables {
+		exp_int: [[0.0; 2]; EXP_INT_TABLE_LEN],
+		exp_frac: [[0.0; 2]; EXP_FRAC_TABLE_LEN],
+		ln_mant: [[0.0; 2]; LN_MANT_LEN]
+	};
+
+	let mut i = 0;
+
+	while i < EXP_INT_TABLE_MAX_INDEX {
+		let power = expint(i as u32);
+		tables.exp_int[EXP_INT_TABLE_MAX_INDEX + i] = power;
+
+		// Negative powers, the first entry is left at 0 as in Java
+		if i != 0 {
+			tables.exp_int[EXP_INT_TABLE_MAX_INDEX - i] = split_reciprocal(power);
+		}
+
+		i += 1;
+	}
+
+	let factor = 1.0 / (EXP_FRAC_TABLE_LEN - 1) as f64;
+	let mut i = 0;
+
+	while i < EXP_FRAC_TABLE_LEN {
+		tables.exp_frac[i] = slow_exp(i as f64 * factor);
+		i += 1;
+	}
+
+	let mut i = 0;
+
+	while i < LN_MANT_LEN {
+		tables.ln_mant[i] = slow_log(f64::from_bits(((i as u64) << 42) | 0x3FF0000000000000));
+		i += 1;
+	}
+
+	tables
+};
+
+/// `FastMathCalc.split`: splits `d` into a high part of about 30 bits and the rest.
+const fn split(d: f64) -> [f64; 2] {
+	if d < 8e298 && d > -8e298 {
+		let a = d * HEX_40000000;
+		let high = (d + a) - a;
+
+		[high, d - high]
+	} else {
+		let a = d * 9.31322574615478515625E-10;
+		let high = (d + a - d) * HEX_40000000;
+
+		[high, d - high]
+	}
+}
+
+/// `FastMathCalc.resplit`
+const fn resplit(a: [f64; 2]) -> [f64; 2] {
+	let c = a[0] + a[1];
+	let d = -(c - a[0] - a[1]);
+
+	if c < 8e298 && c > -8e298 {
+		let z = c * HEX_40000000;
+		let high = (c + z) - z;
+
+		[high, c - high + d]
+	} else {
+		let z = c * 9.31322574615478515625E-10;
+		let high = (c + z - c) * HEX_40000000;
+
+		[high, c - high + d]
+	}
+}
+
+/// `FastMathCalc.splitMult`
+const fn split_mult(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+	resplit([a[0] * b[0], a[0] * b[1] + a[1] * b[0] + a[1] * b[1]])
+}
+
+/// `FastMathCalc.splitAdd`
+const fn split_add(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+	resplit([a[0] + b[0], a[1] + b[1]])
+}
+
+/// `FastMathCalc.splitReciprocal`
+const fn split_reciprocal(mut input: [f64; 2]) -> [f64; 2] {
+	let b = 1.0 / 4194304.0;
+	let a = 1.0 - b;
+
+	if input[0] == 0.0 {
+		input = [input[1], 0.0];
+	}
+
+	let mut result = [a / input[0], (b * input[0] - a * input[1]) / (input[0] * input[0] + input[0] * input[1])];
+
+	if result[1].is_nan() {
+		result[1] = 0.0;
+	}
+
+	result = resplit(result);
+
+	let mut i = 0;
+
+	while i < 2 {
+		let mut err = 1.0 - result[0] * input[0] - result[0] * input[1] - result[1] * input[0] - result[1] * input[1];
+		err *= result[0] + result[1];
+		result[1] += err;
+
+		i += 1;
+	}
+
+	result
+}
+
+/// Adds both parts of `z` to `result` with the error of each addition kept, as in `FastMathCalc.quadMult`.
+const fn quad_add(mut result: [f64; 2], z: [f64; 2]) -> [f64; 2] {
+	let tmp = result[0] + z[0];
+	result[1] -= tmp - result[0] - z[0];
+	result[0] = tmp;
+
+	let tmp = result[0] + z[1];
+	result[1] -= tmp - result[0] - z[1];
+	result[0] = tmp;
+
+	result
+}
+
+/// `FastMathCalc.quadMult`
+const fn quad_mult(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+	let mut result = split_mult(split(a[0]), split(b[0]));
+
+	result = quad_add(result, split_mult(split(a[0]), split(b[1])));
+	result = quad_add(result, split_mult(split(a[1]), split(b[0])));
+
+	quad_add(result, split_mult(split(a[1]), split(b[1])))
+}
+
+/// `FastMathCalc.expint`: `e^p` by squaring.
+const fn expint(mut p: u32) -> [f64; 2] {
+	let mut xs = [2.718281828459045, 1.4456468917292502E-16];
+	let mut ys = split(1.0);
+
+	while p > 0 {
+		if p & 1 != 0 {
+			ys = quad_mult(ys, xs);
+		}
+
+		xs = quad_mult(xs, xs);
+		p >>= 1;
+	}
+
+	resplit(ys)
+}
+
+/// `FastMathCalc.slowexp`: the Taylor series of `e^x`.
+const fn slow_exp(x: f64) -> [f64; 2] {
+	let xs = split(x);
+	let mut ys = [0.0; 2];
+	let mut i = FACT.len();
+
+	while i > 0 {
+		i -= 1;
+
+		ys = split_mult(xs, ys);
+		ys = split_add(ys, split_reciprocal(split(FACT[i])));
+	}
+
+	ys
+}
+
+/// `FastMathCalc.slowLog`: `2 atanh((x - 1) / (x + 1))` as a series.
+const fn slow_log(xi: f64) -> [f64; 2] {
+	let mut x = split(xi);
+
+	// X = (x - 1) / (x + 1)
+	x[0] += 1.0;
+	x = resplit(x);
+	let a = split_reciprocal(x);
+	x[0] -= 2.0;
+	x = resplit(x);
+	x = split_mult(x, a);
+
+	let x2 = split_mult(x, x);
+	let mut y = LN_SPLIT_COEF[LN_SPLIT_COEF.len() - 1];
+	let mut i = LN_SPLIT_COEF.len() - 1;
+
+	while i > 0 {
+		i -= 1;
+
+		y = split_mult(y, x2);
+		y = split_add(y, LN_SPLIT_COEF[i]);
+	}
+
+	split_mult(y, x)
+}
+
+/// Returns `e^x`, like `FastMath.exp(x)`.
+pub fn exp(x: f64) -> f64 {
+	exp_extra(x, 0.0)
+}
+
+/// `FastMath.exp(x, extra, null)`, which computes `e^x * (1 + extra)` for the low bits that `pow` carries over.
+fn exp_extra(x: f64, extra: f64) -> f64 {
+	let mut int_val = x as i32;
+
+	if x < 0.0 {
+		if x < -746.0 {
+			return 0.0;
+		}
+
+		// Subnormal results
+		if int_val < -709 {
+			return exp_extra(x + 40.19140625, extra) / 285040095144011776.0;
+		}
+
+		if int_val == -709 {
+			return exp_extra(x + 1.494140625, extra) / 4.455505956692756620;
+		}
+
+		int_val -= 1;
+	} else if int_val > 709 {
+		return f64::INFINITY;
+	}
+
+	let [int_part_a, int_part_b] = TABLES.exp_int[(EXP_INT_TABLE_MAX_INDEX as i32 + int_val) as usize];
+
+	let int_frac = ((x - int_val as f64) * 1024.0) as i32;
+	let [frac_part_a, frac_part_b] = TABLES.exp_frac[int_frac as usize];
+
+	// The distance from the multiple of 2^-10 below x, from 0 to 2^-10
+	let epsilon = x - (int_val as f64 + int_frac as f64 / 1024.0);
+
+	// e^epsilon - 1
+	let mut z = 0.04168701738764507;
+	z = z * epsilon + 0.1666666505023083;
+	z = z * epsilon + 0.5000000000042687;
+	z = z * epsilon + 1.0;
+	z = z * epsilon + -3.940510424527919E-20;
+
+	let temp_a = int_part_a * frac_part_a;
+	let temp_b = int_part_a * frac_part_b + int_part_b * frac_part_a + int_part_b * frac_part_b;
+	let temp_c = temp_b + temp_a;
+
+	// Going on would give NaN if z is negative
+	if temp_c == f64::INFINITY {
+		return f64::INFINITY;
+	}
+
+	if extra != 0.0 {
+		temp_c * extra * z + temp_c * extra + temp_c * z + temp_b + temp_a
+	} else {
+		temp_c * z + temp_b + temp_a
+	}
+}
+
+/// Returns the natural logarithm of `x`, like `FastMath.log(x)`.
+pub fn log(x: f64) -> f64 {
+	let [a, b] = log_split(x, false);
+
+	a + b
+}
+
+/// `FastMath.log(x, hiPrec)`, returning the high and low parts. The extra precision is only computed when `pow`
+/// asks for it, and otherwise the low part is 0 in the special cases and near 1.
+fn log_split(x: f64, hi_prec: bool) -> [f64; 2] {
+	if x == 0.0 {
+		return [f64::NEG_INFINITY, 0.0];
+	}
+
+	let mut bits = x.to_bits();
+
+	if bits & 0x8000000000000000 != 0 || x.is_nan() {
+		return [f64::NAN, 0.0];
+	}
+
+	if x == f64::INFINITY {
+		return [f64::INFINITY, 0.0];
+	}
+
+	let mut exp = (bits >> 52) as i32 - 1023;
+
+	if bits & 0x7FF0000000000000 == 0 {
+		// Normalize the subnormal number
+		bits <<= 1;
+
+		while bits & 0x0010000000000000 == 0 {
+			exp -= 1;
+			bits <<= 1;
+		}
+	}
+
+	if (exp == -1 || exp == 0) && x < 1.01 && x > 0.99 && !hi_prec {
+		// The polynomial in x - 1, in higher precision
+		let mut xa = x - 1.0;
+		let tmp = xa * HEX_40000000;
+		let aa = xa + tmp - tmp;
+		let xb = xa - aa;
+		xa = aa;
+
+		let [mut ya, mut yb] = LN_QUICK_COEF[LN_QUICK_COEF.len() - 1];
+		let mut i = LN_QUICK_COEF.len() - 1;
+
+		while i > 0 {
+			i -= 1;
+
+			let [a, b] = split_product(ya, yb, xa, xb);
+			let [a, b] = resplit_sum(a, b);
+			ya = a;
+			yb = b;
+
+			let [a, b] = resplit_sum(ya + LN_QUICK_COEF[i][0], yb + LN_QUICK_COEF[i][1]);
+			ya = a;
+			yb = b;
+		}
+
+		let [a, b] = split_product(ya, yb, xa, xb);
+		let [ya, yb] = resplit_sum(a, b);
+
+		return [ya + yb, 0.0];
+	}
+
+	// The log of the leading 10 bits of the mantissa, between 0 and ln(2)
+	let lnm = TABLES.ln_mant[((bits & 0x000FFC0000000000) >> 42) as usize];
+
+	let epsilon = (bits & 0x3FFFFFFFFFF) as f64 / (TWO_POWER_52 + (bits & 0x000FFC0000000000) as f64);
+
+	let lnza;
+	let mut lnzb = 0.0;
+
+	if hi_prec {
+		let tmp = epsilon * HEX_40000000;
+		let xa = epsilon + tmp - tmp;
+		let mut xb = epsilon - xa;
+
+		// A more accurate epsilon
+		let numer = (bits & 0x3FFFFFFFFFF) as f64;
+		let denom = TWO_POWER_52 + (bits & 0x000FFC0000000000) as f64;
+		let aa = numer - xa * denom - xb * denom;
+		xb += aa / denom;
+
+		let [mut ya, mut yb] = LN_HI_PREC_COEF[LN_HI_PREC_COEF.len() - 1];
+		let mut i = LN_HI_PREC_COEF.len() - 1;
+
+		while i > 0 {
+			i -= 1;
+
+			let [a, b] = split_product(ya, yb, xa, xb);
+			let [a, b] = resplit_sum(a, b);
+			ya = a;
+			yb = b;
+
+			let [a, b] = resplit_sum(ya + LN_HI_PREC_COEF[i][0], yb + LN_HI_PREC_COEF[i][1]);
+			ya = a;
+			yb = b;
+		}
+
+		let [aa, ab] = split_product(ya, yb, xa, xb);
+
+		lnza = aa + ab;
+		lnzb = -(lnza - aa - ab);
+	} else {
+		let mut z = -0.16624882440418567;
+		z = z * epsilon + 0.19999954120254515;
+		z = z * epsilon + -0.2499999997677497;
+		z = z * epsilon + 0.3333333333332802;
+		z = z * epsilon + -0.5;
+		z = z * epsilon + 1.0;
+
+		lnza = z * epsilon;
+	}
+
+	// Sums lnzb + lnm[1] + ln2B*exp + lnza + lnm[0] + ln2A*exp from the largest term, keeping the error of each
+	// addition
+	let mut a = LN_2_A * exp as f64;
+	let mut b = 0.0;
+
+	for &term in &[lnm[0], lnza, LN_2_B * exp as f64, lnm[1], lnzb] {
+		let c = a + term;
+		let d = -(c - a - term);
+		a = c;
+		b += d;
+	}
+
+	[a, b]
+}
+
+/// `(ya + yb) * (xa + xb)` as the product of the high parts and the sum of the other terms.
+fn split_product(ya: f64, yb: f64, xa: f64, xb: f64) -> [f64; 2] {
+	[ya * xa, ya * xb + yb * xa + yb * xb]
+}
+
+/// Moves all but the high 30 or so bits of `aa` into the low part, as the polynomials of `FastMath.log` do after each
+/// step.
+fn resplit_sum(aa: f64, ab: f64) -> [f64; 2] {
+	let tmp = aa * HEX_40000000;
+	let ya = aa + tmp - tmp;
+
+	[ya, aa - ya + ab]
+}
+
+/// Returns `x` raised to the power `y`, like `FastMath.pow(x, y)`. Integral exponents are computed by `pow_i64`.
+pub fn pow(x: f64, y: f64) -> f64 {
+	if y == 0.0 {
+		return 1.0;
+	}
+
+	let y_bits = y.to_bits();
+	let y_raw_exp = ((y_bits & 0x7FF0000000000000) >> 52) as i32;
+	let y_raw_mantissa = y_bits & 0x000FFFFFFFFFFFFF;
+	let x_bits = x.to_bits();
+	let x_raw_exp = ((x_bits & 0x7FF0000000000000) >> 52) as i32;
+	let x_raw_mantissa = x_bits & 0x000FFFFFFFFFFFFF;
+
+	if y_raw_exp > 1085 {
+		// y is infinite, NaN, or so large that it behaves like infinity
+		if (y_raw_exp == 2047 && y_raw_mantissa != 0) || (x_raw_exp == 2047 && x_raw_mantissa != 0) {
+			return f64::NAN;
+		}
+
+		if x_raw_exp == 1023 && x_raw_mantissa == 0 {
+			return if y_raw_exp == 2047 { f64::NAN } else { 1.0 };
+		}
+
+		return if (y > 0.0) ^ (x_raw_exp < 1023) { f64::INFINITY } else { 0.0 };
+	}
+
+	if y_raw_exp >= 1023 {
+		let y_full_mantissa = 0x0010000000000000 | y_raw_mantissa;
+
+		if y_raw_exp < 1075 {
+			// Integral if none of the bits below the point are set
+			let integral_mask = u64::MAX << (1075 - y_raw_exp);
+
+			if y_full_mantissa & integral_mask == y_full_mantissa {
+				let l = (y_full_mantissa >> (1075 - y_raw_exp)) as i64;
+
+				return pow_i64(x, if y < 0.0 { -l } else { l });
+			}
+		} else {
+			let l = (y_full_mantissa << (y_raw_exp - 1075)) as i64;
+
+			return pow_i64(x, if y < 0.0 { -l } else { l });
+		}
+	}
+
+	// y is not integral
+	if x == 0.0 {
+		return if y < 0.0 { f64::INFINITY } else { 0.0 };
+	}
+
+	if x_raw_exp == 2047 {
+		if x_raw_mantissa != 0 {
+			return f64::NAN;
+		}
+
+		return if y < 0.0 { 0.0 } else { f64::INFINITY };
+	}
+
+	if x < 0.0 {
+		return f64::NAN;
+	}
+
+	let tmp = y * HEX_40000000;
+	let ya = (y + tmp) - tmp;
+	let yb = y - ya;
+
+	let [mut lna, mut lnb] = log_split(x, true);
+
+	if lna.is_infinite() {
+		return lna;
+	}
+
+	let tmp1 = lna * HEX_40000000;
+	let tmp2 = (lna + tmp1) - tmp1;
+	lnb += lna - tmp2;
+	lna = tmp2;
+
+	// y * ln(x)
+	let aa = lna * ya;
+	let ab = lna * yb + lnb * ya + lnb * yb;
+
+	lna = aa + ab;
+	lnb = -(lna - aa - ab);
+
+	let mut z = 1.0 / 120.0;
+	z = z * lnb + (1.0 / 24.0);
+	z = z * lnb + (1.0 / 6.0);
+	z = z * lnb + 0.5;
+	z = z * lnb + 1.0;
+	z *= lnb;
+
+	exp_extra(lna, z)
+}
+
+/// Returns `d` raised to the integer power `e`, like `FastMath.pow(d, e)` with an `int` exponent.
+pub fn pow_i32(d: f64, e: i32) -> f64 {
+	pow_i64(d, e as i64)
+}
+
+/// Returns `d` raised to the integer power `e`, like `FastMath.pow(d, e)` with a `long` exponent. The powers are
+/// computed by squaring with Veltkamp's two product algorithm, after an accurate reciprocal for negative exponents.
+pub fn pow_i64(d: f64, e: i64) -> f64 {
+	if e == 0 {
+		1.0
+	} else if e > 0 {
+		Split::new(d).pow(e as u64).full
+	} else {
+		Split::new(d).reciprocal().pow(e.wrapping_neg() as u64).full
+	}
+}
+
+/// A double with its high 26 bits split off, `FastMath.Split`.
+#[derive(Clone, Copy)]
+struct Split {
+	full: f64,
+	high: f64,
+	low: f64
+}
+
+impl Split {
+	fn new(x: f64) -> Self {
+		let high = f64::from_bits(x.to_bits() & (u64::MAX << 27));
+
+		Split { full: x, high, low: x - high }
+	}
+
+	fn from_parts(high: f64, low: f64) -> Self {
+		let full = if high == 0.0 {
+			if low == 0.0 && high.to_bits() == 0x8000000000000000 { -0.0 } else { low }
+		} else {
+			high + low
+		};
+
+		Split { full, high, low }
+	}
+
+	fn multiply(self, b: Split) -> Split {
+		// The expressions rely on the rounding of each operation and must not be simplified
+		let basic = Split::new(self.full * b.full);
+		let error = self.low * b.low - (((basic.full - self.high * b.high) - self.low * b.high) - self.high * b.low);
+
+		Split::from_parts(basic.high, basic.low + error)
+	}
+
+	fn reciprocal(self) -> Split {
+		let inverse = Split::new(1.0 / self.full);
+
+		// If the reciprocal were exact, multiplying it back would give 1
+		let product = self.multiply(inverse);
+		let error = (product.high - 1.0) + product.low;
+
+		if error.is_nan() {
+			inverse
+		} else {
+			Split::from_parts(inverse.high, inverse.low - error / self.full)
+		}
+	}
+
+	fn pow(self, e: u64) -> Split {
+		let mut result = Split::new(1.0);
+		let mut d2p = self;
+		let mut p = e;
+
+		while p != 0 {
+			if p & 1 != 0 {
+				result = result.multiply(d2p);
+			}
+
+			d2p = d2p.multiply(d2p);
+			p >>= 1;
+		}
+
+		if !result.full.is_nan() {
+			return result;
+		}
+
+		// Intermediate values overflowed, and infinity minus infinity gave NaN in the low parts
+		if self.full.is_nan() {
+			Split::from_parts(f64::NAN, 0.0)
+		} else if self.full.abs() < 1.0 {
+			Split::from_parts(0.0f64.copysign(self.full), 0.0)
+		} else if self.full < 0.0 && e & 1 == 1 {
+			Split::from_parts(f64::NEG_INFINITY, 0.0)
+		} else {
+			Split::from_parts(f64::INFINITY, 0.0)
+		}
+	}
+}
@@ -0,0 +1,261 @@
+//! The emulation of `java.util.Random` that GWT compiles into JavaScript, for reproducing clients built with GWT.
+//!
+//! JavaScript has no 64-bit integers, so GWT keeps the 48-bit state as two doubles holding 24 bits each and steps it
+//! with double arithmetic. This port performs the same floating point operations in the same order. Every
+//! intermediate value is an integer below 2^53, so the integer and uniform draws are exact and agree with
+//! `java.util.Random`; `GwtRandom` converts to and from `Random` without losing its place in the sequence.
+//!
+//! The difference lies in `nextGaussian()`, which calls the `Math.log` and `Math.sqrt` of the JavaScript engine
+//! instead of `StrictMath`. `Math.sqrt` is correctly rounded everywhere, and current engines derive `Math.log` from
+//! fdlibm like `StrictMath.log`, which `next_gaussian` assumes. Older engines used the platform's `log`, which can
+//! differ in the last bit; `next_gaussian_with` takes that function as a parameter.
+
+use core::hash::{Hash, Hasher};
+
+use strictmath;
+use {Checkpoint, Error, Random, MASK};
+
+/// The high 24 bits of `0x5DEECE66D`
+const MULTIPLIER_HI: f64 = 0x5DE as f64;
+
+/// The low 24 bits of `0x5DEECE66D`
+const MULTIPLIER_LO: f64 = 0xECE66D as f64;
+
+const TWO_TO_THE_24: f64 = 16777216.0;
+const TWO_TO_THE_31: f64 = 2147483648.0;
+const TWO_TO_THE_32: f64 = 4294967296.0;
+const TWO_TO_THE_MINUS_24: f64 = 1.0 / TWO_TO_THE_24;
+const TWO_TO_THE_MINUS_26: f64 = 1.0 / (1 << 26) as f64;
+const TWO_TO_THE_MINUS_53: f64 = TWO_TO_THE_MINUS_26 * TWO_TO_THE_MINUS_26 / 2.0;
+
+/// `Math.floor` of a nonnegative double below 2^53
+fn floor(value: f64) -> f64 {
+	value as u64 as f64
+}
+
+/// The GWT emulation of `java.util.Random`, with the state split into `seedhi` and `seedlo`.
+#[derive(Debug, Clone)]
+pub struct GwtRandom {
+	seed_hi: f64,
+	seed_lo: f64,
+	next_next_gaussian: Option<f64>
+}
+
+impl PartialEq for GwtRandom {
+	fn eq(&self, other: &GwtRandom) -> bool {
+		self.seed_hi.to_bits() == other.seed_hi.to_bits()
+			&& self.seed_lo.to_bits() == other.seed_lo.to_bits()
+			&& self.next_next_gaussian.map(f64::to_bits) == other.next_next_gaussian.map(f64::to_bits)
+	}
+}
+
+impl Eq for GwtRandom {}
+
+impl Hash for GwtRandom {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.seed_hi.to_bits().hash(state);
+		self.seed_lo.to_bits().hash(state);
+		self.next_next_gaussian.map(f64::to_bits).hash(state);
+	}
+}
+
+impl GwtRandom {
+	/// Creates the generator with a seed, like `new Random(seed)`. As on the JVM, only the low 48 bits of the seed
+	/// are used.
+	pub fn new(seed: i64) -> Self {
+		let mut random = GwtRandom {
+			seed_hi: 0.0,
+			seed_lo: 0.0,
+			next_next_gaussian: None
+		};
+
+		random.set_seed(seed);
+		random
+	}
+
+	/// Creates the generator from the two halves of the state as they are stored in JavaScript.
+	///
+	/// # Panics
+	/// If either half is not an integer in the range [0, 2^24), the function panics.
+	pub fn from_state(seed_hi: f64, seed_lo: f64) -> Self {
+		let valid = |half: f64| (0.0..TWO_TO_THE_24).contains(&half) && floor(half) == half;
+
+		if !valid(seed_hi) || !valid(seed_lo) {
+			panic!("State halves must be integers in the range [0, 2^24)")
+		}
+
+		GwtRandom {
+			seed_hi,
+			seed_lo,
+			next_next_gaussian: None
+		}
+	}
+
+	/// Creates the generator at the same position in the sequence as `random`, including a pending gaussian.
+	pub fn from_random(random: &Random) -> Self {
+		GwtRandom {
+			seed_hi: (random.state >> 24) as f64,
+			seed_lo: (random.state & 0xFFFFFF) as f64,
+			next_next_gaussian: random.next_gaussian
+		}
+	}
+
+	/// Returns a `Random` at the same position in the sequence, including a pending gaussian.
+	pub fn to_random(&self) -> Random {
+		Random {
+			state: ((self.seed_hi as u64) << 24 | self.seed_lo as u64) & MASK,
+			next_gaussian: self.next_next_gaussian
+		}
+	}
+
+	/// Captures the state of the generator. As the generator is `java.util.Random`, the checkpoint is that of the
+	/// equivalent `Random`, so either type can restore it.
+	pub fn save(&self) -> Checkpoint {
+		self.to_random().save()
+	}
+
+	/// Returns the generator to the state captured in `checkpoint`, see `Random::restore`.
+	pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		let mut random = self.to_random();
+		random.restore(checkpoint)?;
+
+		*self = GwtRandom::from_random(&random);
+
+		Ok(())
+	}
+
+	/// Encodes the state in the versioned binary form, the same as that of the equivalent `Random`.
+	pub fn to_bytes(&self) -> [u8; 27] {
+		self.save().to_versioned_bytes()
+	}
+
+	/// Decodes a generator encoded by `to_bytes` or `Random::to_bytes`, see `Random::from_bytes`.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		Random::from_bytes(bytes).map(|random| GwtRandom::from_random(&random))
+	}
+
+	/// Returns the two halves of the state, `seedhi` and `seedlo`.
+	pub fn state(&self) -> (f64, f64) {
+		(self.seed_hi, self.seed_lo)
+	}
+
+	/// Sets the seed, like `setSeed(seed)`, and discards a pending gaussian.
+	pub fn set_seed(&mut self, seed: i64) {
+		self.seed_hi = (((seed >> 24) & 0xFFFFFF) as i32 ^ 0x5DE) as f64;
+		self.seed_lo = ((seed & 0xFFFFFF) as i32 ^ 0xECE66D) as f64;
+		self.next_next_gaussian = None;
+	}
+
+	/// Steps the generator, returning up to 32 bits, like `next(bits)`.
+	///
+	/// # Panics
+	/// If the amount of requested bits is over 32, this function panics.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		if bits > 32 {
+			panic!("Too many bits!")
+		}
+
+		let mut hi = self.seed_hi * MULTIPLIER_LO + self.seed_lo * MULTIPLIER_HI;
+		let mut lo = self.seed_lo * MULTIPLIER_LO + 11.0;
+		let carry = floor(lo * TWO_TO_THE_MINUS_24);
+
+		hi += carry;
+		lo -= carry * TWO_TO_THE_24;
+		hi %= TWO_TO_THE_24;
+
+		self.seed_hi = hi;
+		self.seed_lo = lo;
+
+		// GWT looks the powers of two up in tables, twoToTheXMinus24 and twoToTheXMinus48
+		if bits <= 24 {
+			floor(self.seed_hi * (1.0 / (1u32 << (24 - bits)) as f64)) as i32
+		} else {
+			let h = self.seed_hi * (1u32 << (bits - 24)) as f64;
+			let l = floor(self.seed_lo * (1.0 / (1u64 << (48 - bits)) as f64));
+			let mut value = h + l;
+
+			if value >= TWO_TO_THE_31 {
+				value -= TWO_TO_THE_32;
+			}
+
+			value as i32
+		}
+	}
+
+	/// Returns a uniformly distributed signed 32-bit integer, like `nextInt()`.
+	pub fn next_i32(&mut self) -> i32 {
+		self.next(32)
+	}
+
+	/// Returns a random number in the range [0, max), like `nextInt(max)`.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		if max <= 0 {
+			panic!("Maximum must be > 0")
+		}
+
+		random_next_i32_bound!(self, max).0
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer, like `nextLong()`.
+	pub fn next_i64(&mut self) -> i64 {
+		random_next_i64!(self)
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false, like `nextBoolean()`.
+	pub fn next_bool(&mut self) -> bool {
+		self.next(1) != 0
+	}
+
+	/// Returns a f32 uniformly distributed between 0.0 and 1.0, like `nextFloat()`.
+	pub fn next_f32(&mut self) -> f32 {
+		(self.next(24) as f64 * TWO_TO_THE_MINUS_24) as f32
+	}
+
+	/// Returns a f64 uniformly distributed between 0.0 and 1.0, like `nextDouble()`. The two halves are scaled
+	/// separately and added, rather than joined as integers first.
+	pub fn next_f64(&mut self) -> f64 {
+		self.next(26) as f64 * TWO_TO_THE_MINUS_26 + self.next(27) as f64 * TWO_TO_THE_MINUS_53
+	}
+
+	/// Fills the byte array with random bytes, like `nextBytes(bytes)`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		for chunk in bytes.chunks_mut(4) {
+			let mut value = self.next_i32();
+
+			for byte in chunk {
+				*byte = value as u8;
+				value >>= 8;
+			}
+		}
+	}
+
+	/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0, like `nextGaussian()`
+	/// in an engine whose `Math.log` matches fdlibm.
+	pub fn next_gaussian(&mut self) -> f64 {
+		self.next_gaussian_with(strictmath::log)
+	}
+
+	/// Like `next_gaussian`, but with `log` in place of the engine's `Math.log`.
+	pub fn next_gaussian_with<F: FnMut(f64) -> f64>(&mut self, mut log: F) -> f64 {
+		if let Some(next) = self.next_next_gaussian.take() {
+			return next;
+		}
+
+		loop {
+			let v1 = 2.0 * self.next_f64() - 1.0;
+			let v2 = 2.0 * self.next_f64() - 1.0;
+			let s = v1*v1 + v2*v2;
+
+			if s < 1.0 && s != 0.0 {
+				let multiplier = strictmath::sqrt(-2.0 * log(s) / s);
+
+				self.next_next_gaussian = Some(v2 * multiplier);
+
+				return v1 * multiplier;
+			}
+		}
+	}
+}
@@ -0,0 +1,7 @@
+//! Reproductions of Java libraries that build their randomness on top of `java.util.Random`, so that programs using
+//! them can be rerun with identical draws.
+
+pub mod commons_math;
+pub mod commons_rng;
+pub mod fastmath;
+pub mod gwt;
@@ -0,0 +1,655 @@
+//! Recovery of the internal state of a `Random` from values it produced.
+//!
+//! Observed calls are recorded in order on a `Cracker`, which translates each of them into a range that the state
+//! after the corresponding step must lie in: `nextInt()` reveals the top 32 bits of the state, `nextInt(16)` the top
+//! 4 bits, and so on. Finding the states that satisfy all ranges at once is a closest vector problem on a lattice,
+//! which `solve` handles with LLL reduction followed by an enumeration of the lattice points near the center of the
+//! ranges, the approach of tools such as LattiCG. Every candidate is then checked by replaying all observations.
+//!
+//! The state can be recovered once the observations reveal about 48 bits in total: two `nextInt()` calls, one
+//! `nextLong()` or `nextDouble()`, or around a dozen calls to `nextInt(16)`. Bounds that are not powers of two only
+//! reveal information through their remainders, which the lattice cannot express, so such calls are only used to
+//! check candidates found from other observations. Likewise, at most `MAX_DIMENSION` ranges enter the lattice, which
+//! rules out recovering the state from booleans alone. Calls to `nextInt(bound)` are assumed to draw a single value,
+//! without the rare retries of its rejection loop.
+//!
+//! A `Cracker` can be saved as text, to share a set of observations or to replay it against another solver without
+//! entering them again. The text has a version line followed by one observation per line, in order:
+//!
+//! ```text
+//! # java-rand observations
+//! version 1
+//! nextInt() = -1170105035
+//! nextInt(16) = 0
+//! nextBoolean() = true
+//! nextDouble() = 0.047939305137387644
+//! skip 2
+//! ```
+//!
+//! The methods use their Java names, and floating point values are written with the shortest representation that
+//! parses back to the same value. When reading, comments starting with `#` and blank lines are ignored.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+use std::vec::Vec;
+
+use lcg::affine_power;
+use {Error, JavaLcg, Random, INCREMENT, MASK, MULTIPLIER};
+
+/// Most candidate states `solve` will enumerate, enough for a single `nextInt()` observation
+pub const MAX_CANDIDATES: u64 = 1 << 16;
+
+/// Most ranges that enter the lattice, beyond which the enumeration becomes too slow
+pub const MAX_DIMENSION: usize = 24;
+
+/// Bits revealed by the ranges in the lattice after which further ranges are only used to check candidates. The 8
+/// bits above the size of the state make additional solutions in the lattice unlikely.
+const TARGET_BITS: f64 = 56.0;
+
+/// A call made to the generator whose result was observed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Observation {
+	/// `nextInt()` returned the value
+	NextI32(i32),
+	/// `nextInt(bound)` returned `value`
+	NextI32Bound { bound: i32, value: i32 },
+	/// `nextLong()` returned the value
+	NextI64(i64),
+	/// `nextBoolean()` returned the value
+	NextBool(bool),
+	/// `nextFloat()` returned the value
+	NextF32(f32),
+	/// `nextDouble()` returned the value
+	NextF64(f64),
+	/// The generator was stepped the given number of times without observing the results, for example by calls
+	/// whose results are unknown
+	Skip(u64)
+}
+
+/// Formats the observation as a line of the text form of `Cracker`, such as `nextInt(16) = 3` or `skip 2`.
+impl Display for Observation {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Observation::NextI32(value) => write!(f, "nextInt() = {}", value),
+			Observation::NextI32Bound { bound, value } => write!(f, "nextInt({}) = {}", bound, value),
+			Observation::NextI64(value) => write!(f, "nextLong() = {}", value),
+			Observation::NextBool(value) => write!(f, "nextBoolean() = {}", value),
+			Observation::NextF32(value) => write!(f, "nextFloat() = {:?}", value),
+			Observation::NextF64(value) => write!(f, "nextDouble() = {:?}", value),
+			Observation::Skip(steps) => write!(f, "skip {}", steps)
+		}
+	}
+}
+
+/// Parses a line written by `Display`. Malformed lines give `Error::ParseError`, and bounds that `nextInt(bound)`
+/// rejects give `Error::InvalidBound`.
+impl FromStr for Observation {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		fn parse<T: FromStr>(value: &str) -> Result<T, Error> {
+			value.trim().parse().map_err(|_| Error::ParseError)
+		}
+
+		if let Some(steps) = s.trim().strip_prefix("skip ") {
+			return Ok(Observation::Skip(parse(steps)?));
+		}
+
+		let (call, value) = s.split_once('=').ok_or(Error::ParseError)?;
+
+		match call.trim() {
+			"nextInt()" => Ok(Observation::NextI32(parse(value)?)),
+			"nextLong()" => Ok(Observation::NextI64(parse(value)?)),
+			"nextBoolean()" => Ok(Observation::NextBool(parse(value)?)),
+			"nextFloat()" => Ok(Observation::NextF32(parse(value)?)),
+			"nextDouble()" => Ok(Observation::NextF64(parse(value)?)),
+			call => {
+				let bound = call.strip_prefix("nextInt(").and_then(|call| call.strip_suffix(')'));
+				let bound = parse(bound.ok_or(Error::ParseError)?)?;
+
+				if bound <= 0 {
+					return Err(Error::InvalidBound);
+				}
+
+				Ok(Observation::NextI32Bound { bound, value: parse(value)? })
+			}
+		}
+	}
+}
+
+/// The states after step `step` of the generator must lie in `[min, max]`.
+#[derive(Debug, Clone, Copy)]
+struct Range {
+	step: u64,
+	min: u64,
+	max: u64
+}
+
+impl Range {
+	fn top_bits(step: u64, value: u64, bits: u32) -> Range {
+		let low = 48 - bits;
+
+		Range {
+			step,
+			min: value << low,
+			max: (value << low) | ((1 << low) - 1)
+		}
+	}
+
+	fn width(&self) -> u64 {
+		self.max - self.min + 1
+	}
+
+	/// Number of bits of the state revealed by the range
+	fn bits(&self) -> f64 {
+		48.0 - (self.width() as f64).log2()
+	}
+}
+
+/// A sequence of observed calls, from which `solve` recovers the states the generator could have started in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cracker {
+	observations: Vec<Observation>
+}
+
+impl Cracker {
+	/// Version of the text form written by `Display`.
+	pub const VERSION: u8 = 1;
+
+	pub fn new() -> Self {
+		Cracker { observations: Vec::new() }
+	}
+
+	/// Records the next call made to the generator.
+	pub fn observe(&mut self, observation: Observation) -> &mut Self {
+		self.observations.push(observation);
+		self
+	}
+
+	/// Records that `nextInt()` returned `value`.
+	pub fn next_i32(&mut self, value: i32) -> &mut Self {
+		self.observe(Observation::NextI32(value))
+	}
+
+	/// Records that `nextInt(bound)` returned `value`.
+	pub fn next_i32_bound(&mut self, bound: i32, value: i32) -> &mut Self {
+		self.observe(Observation::NextI32Bound { bound, value })
+	}
+
+	/// Records that `nextLong()` returned `value`.
+	pub fn next_i64(&mut self, value: i64) -> &mut Self {
+		self.observe(Observation::NextI64(value))
+	}
+
+	/// Records that `nextBoolean()` returned `value`.
+	pub fn next_bool(&mut self, value: bool) -> &mut Self {
+		self.observe(Observation::NextBool(value))
+	}
+
+	/// Records that `nextFloat()` returned `value`.
+	pub fn next_f32(&mut self, value: f32) -> &mut Self {
+		self.observe(Observation::NextF32(value))
+	}
+
+	/// Records that `nextDouble()` returned `value`.
+	pub fn next_f64(&mut self, value: f64) -> &mut Self {
+		self.observe(Observation::NextF64(value))
+	}
+
+	/// Records that the generator was stepped `steps` times without observing the results.
+	pub fn skip(&mut self, steps: u64) -> &mut Self {
+		self.observe(Observation::Skip(steps))
+	}
+
+	/// Returns the observations recorded so far, in order.
+	pub fn observations(&self) -> &[Observation] {
+		&self.observations
+	}
+
+	/// Returns every generator that produces the observed values, positioned before the first observed call and
+	/// sorted by internal state. The seed that `Random::new` turns into such a state is the state xor `0x5DEECE66D`.
+	///
+	/// # Errors
+	/// If a bound is less than 1, `Error::InvalidBound` is returned. If the observations leave more than
+	/// `MAX_CANDIDATES` states to enumerate, `Error::TooManyCandidates` is returned.
+	pub fn solve(&self) -> Result<Vec<Random>, Error> {
+		let mut ranges = match self.ranges()? {
+			Some(ranges) => ranges,
+			None => return Ok(Vec::new())
+		};
+
+		ranges.sort_by_key(Range::width);
+
+		// The narrowest ranges enter the lattice until they determine the state
+		let mut bits = 0.0;
+		let mut dimension = 0;
+
+		for range in ranges.iter().take(MAX_DIMENSION) {
+			if bits >= TARGET_BITS {
+				break;
+			}
+
+			bits += range.bits();
+			dimension += 1;
+		}
+
+		if dimension == 0 || 48.0 - bits > (MAX_CANDIDATES as f64).log2() {
+			return Err(Error::TooManyCandidates);
+		}
+
+		let lattice = &mut ranges[..dimension];
+		lattice.sort_by_key(|range| range.step);
+
+		let mut candidates = Vec::new();
+		let first = lattice[0].step;
+
+		for state in enumerate(lattice)? {
+			let mut lcg = JavaLcg::new(state);
+			lcg.jump_back(first);
+
+			let start = Random { state: lcg.state(), next_gaussian: None };
+
+			if self.replay(start.clone()) {
+				candidates.push(start);
+			}
+		}
+
+		candidates.sort_by_key(|random| random.state);
+		candidates.dedup();
+
+		Ok(candidates)
+	}
+
+	/// Returns the ranges of the state implied by the observations, or None if a value is impossible.
+	fn ranges(&self) -> Result<Option<Vec<Range>>, Error> {
+		let mut ranges = Vec::new();
+		let mut step = 0;
+
+		for &observation in &self.observations {
+			match observation {
+				Observation::NextI32(value) => {
+					step += 1;
+					ranges.push(Range::top_bits(step, value as u32 as u64, 32));
+				},
+				Observation::NextI32Bound { bound, value } => {
+					if bound <= 0 {
+						return Err(Error::InvalidBound);
+					}
+
+					if value < 0 || value >= bound {
+						return Ok(None);
+					}
+
+					step += 1;
+
+					// (bound * next(31)) >> 31 keeps the top bits of next(31) for powers of two
+					if bound & (bound - 1) == 0 && bound > 1 {
+						ranges.push(Range::top_bits(step, value as u64, bound.trailing_zeros()));
+					}
+				},
+				Observation::NextI64(value) => {
+					// nextLong() adds the second int as a signed value, borrowing from the first
+					let low = value as i32;
+					let high = (value.wrapping_sub(low as i64) >> 32) as i32;
+
+					ranges.push(Range::top_bits(step + 1, high as u32 as u64, 32));
+					ranges.push(Range::top_bits(step + 2, low as u32 as u64, 32));
+					step += 2;
+				},
+				Observation::NextBool(value) => {
+					step += 1;
+					ranges.push(Range::top_bits(step, value as u64, 1));
+				},
+				Observation::NextF32(value) => {
+					let bits = value * (1u32 << 24) as f32;
+
+					if !(0.0..(1u32 << 24) as f32).contains(&bits) || bits as u32 as f32 != bits {
+						return Ok(None);
+					}
+
+					step += 1;
+					ranges.push(Range::top_bits(step, bits as u64, 24));
+				},
+				Observation::NextF64(value) => {
+					let bits = value * (1u64 << 53) as f64;
+
+					if !(0.0..(1u64 << 53) as f64).contains(&bits) || bits as u64 as f64 != bits {
+						return Ok(None);
+					}
+
+					let bits = bits as u64;
+
+					ranges.push(Range::top_bits(step + 1, bits >> 27, 26));
+					ranges.push(Range::top_bits(step + 2, bits & ((1 << 27) - 1), 27));
+					step += 2;
+				},
+				Observation::Skip(steps) => step += steps
+			}
+		}
+
+		Ok(Some(ranges))
+	}
+
+	/// Returns true if `random` produces the observed values, with every call to `nextInt(bound)` drawing a single
+	/// value as `solve` assumes.
+	pub fn matches(&self, random: &Random) -> bool {
+		self.replay(random.clone())
+	}
+
+	/// Returns true if the generator produces the observed values.
+	fn replay(&self, mut random: Random) -> bool {
+		self.observations.iter().all(|&observation| match observation {
+			Observation::NextI32(value) => random.next_i32() == value,
+			Observation::NextI32Bound { bound, value } => {
+				// Java throws for these bounds, so no generator produces a value
+				if bound <= 0 {
+					return false;
+				}
+
+				// A single draw, as assumed when building the ranges
+				let bits = random.next(31);
+
+				if bound & (bound - 1) == 0 {
+					((bound as i64 * bits as i64) >> 31) as i32 == value
+				} else {
+					bits % bound == value && bits.wrapping_sub(value).wrapping_add(bound - 1) >= 0
+				}
+			},
+			Observation::NextI64(value) => random.next_i64() == value,
+			Observation::NextBool(value) => random.next_bool() == value,
+			Observation::NextF32(value) => random.next_f32().to_bits() == value.to_bits(),
+			Observation::NextF64(value) => random.next_f64().to_bits() == value.to_bits(),
+			Observation::Skip(steps) => {
+				random.skip(steps);
+
+				true
+			}
+		})
+	}
+}
+
+/// Formats the text form described in the module documentation, which `FromStr` parses back into an identical
+/// `Cracker`.
+impl Display for Cracker {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		writeln!(f, "# java-rand observations")?;
+		writeln!(f, "version {}", Cracker::VERSION)?;
+
+		for observation in &self.observations {
+			writeln!(f, "{}", observation)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Parses the text form. Malformed text gives `Error::ParseError`, and text from a later version of the crate gives
+/// `Error::UnsupportedVersion`.
+impl FromStr for Cracker {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		let mut lines = s.lines()
+			.map(|line| line.split('#').next().unwrap_or("").trim())
+			.filter(|line| !line.is_empty());
+
+		let version = lines.next().and_then(|line| line.strip_prefix("version ")).ok_or(Error::ParseError)?;
+
+		match version.trim().parse::<u8>() {
+			Ok(Cracker::VERSION) => (),
+			Ok(version) if version > Cracker::VERSION => return Err(Error::UnsupportedVersion),
+			_ => return Err(Error::ParseError)
+		}
+
+		let observations = lines.map(str::parse).collect::<Result<_, _>>()?;
+
+		Ok(Cracker { observations })
+	}
+}
+
+/// Returns the states at the step of the first range for which the states at the steps of all ranges lie in their
+/// ranges. The ranges must be sorted by step.
+///
+/// The state `u` at the first step determines the states at the later steps as `a_i * u + c_i mod 2^48`, so the
+/// solutions correspond to the points of the lattice spanned by `(1, a_2, ..., a_n)` and `2^48 e_i` that lie in the
+/// box of the ranges shifted by `c_i`. Each coordinate is scaled by the inverse of the width of its range, making the
+/// box close to a cube, whose points all lie in the ball around its center that the enumeration visits.
+fn enumerate(ranges: &[Range]) -> Result<Vec<u64>, Error> {
+	let dimension = ranges.len();
+	let widest = ranges.iter().map(Range::width).max().unwrap_or(1);
+	let first = ranges[0].step;
+
+	let scales: Vec<i128> = ranges.iter().map(|range| (widest / range.width()).max(1) as i128).collect();
+	let offsets: Vec<i128> = ranges.iter()
+		.map(|range| affine_power(MULTIPLIER, INCREMENT, MASK, range.step - first).1 as i128)
+		.collect();
+
+	let mut basis = vec![vec![0i128; dimension]; dimension];
+
+	for (index, range) in ranges.iter().enumerate() {
+		let multiplier = affine_power(MULTIPLIER, INCREMENT, MASK, range.step - first).0 as i128;
+
+		basis[0][index] = multiplier * scales[index];
+
+		if index > 0 {
+			basis[index][index] = (1i128 << 48) * scales[index];
+		}
+	}
+
+	reduce(&mut basis);
+
+	// Bounds of the box, scaled, and its center
+	let min: Vec<i128> = (0..dimension).map(|i| (ranges[i].min as i128 - offsets[i]) * scales[i]).collect();
+	let max: Vec<i128> = (0..dimension).map(|i| (ranges[i].max as i128 - offsets[i]) * scales[i]).collect();
+	let center: Vec<f64> = (0..dimension).map(|i| (min[i] as f64 + max[i] as f64) / 2.0).collect();
+
+	let radius = (0..dimension)
+		.map(|i| {
+			let half = (max[i] - min[i]) as f64 / 2.0;
+			half * half
+		})
+		.sum::<f64>();
+
+	// Generous slack so that rounding never excludes a point on the boundary, which is checked exactly anyway
+	let radius = radius * (1.0 + 1e-6) + 1.0;
+
+	let (mu, lengths) = gram_schmidt(&basis);
+	let orthogonal = orthogonal_basis(&basis, &mu);
+
+	let target: Vec<f64> = (0..dimension)
+		.map(|j| dot(&center, &orthogonal[j]) / lengths[j])
+		.collect();
+
+	let mut search = Search {
+		basis: &basis,
+		mu: &mu,
+		lengths: &lengths,
+		target: &target,
+		min: &min,
+		max: &max,
+		coefficients: vec![0; dimension],
+		solutions: Vec::new()
+	};
+
+	search.visit(dimension, radius)?;
+
+	Ok(search.solutions.into_iter().map(|point| (point / scales[0]) as u64).collect())
+}
+
+/// Depth first enumeration of the lattice points within a radius of the target, from the last basis vector to the
+/// first (Fincke and Pohst).
+struct Search<'a> {
+	basis: &'a [Vec<i128>],
+	mu: &'a [Vec<f64>],
+	lengths: &'a [f64],
+	target: &'a [f64],
+	min: &'a [i128],
+	max: &'a [i128],
+	coefficients: Vec<i128>,
+	solutions: Vec<i128>
+}
+
+impl<'a> Search<'a> {
+	/// Chooses the coefficient of basis vector `level - 1`, with `remaining` left of the squared radius.
+	fn visit(&mut self, level: usize, remaining: f64) -> Result<(), Error> {
+		if level == 0 {
+			return self.check();
+		}
+
+		let j = level - 1;
+		let dimension = self.coefficients.len();
+
+		let mut center = self.target[j];
+
+		for i in level..dimension {
+			center -= self.mu[i][j] * self.coefficients[i] as f64;
+		}
+
+		let half = (remaining / self.lengths[j]).sqrt();
+
+		let mut coefficient = (center - half).ceil() as i128;
+		let last = (center + half).floor() as i128;
+
+		while coefficient <= last {
+			let offset = coefficient as f64 - center;
+			let left = remaining - offset * offset * self.lengths[j];
+
+			if left >= 0.0 {
+				self.coefficients[j] = coefficient;
+				self.visit(j, left)?;
+			}
+
+			coefficient += 1;
+		}
+
+		Ok(())
+	}
+
+	/// Records the current lattice point if it lies in the box.
+	fn check(&mut self) -> Result<(), Error> {
+		let dimension = self.coefficients.len();
+		let mut first = 0;
+
+		for i in 0..dimension {
+			let coordinate: i128 = (0..dimension).map(|j| self.coefficients[j] * self.basis[j][i]).sum();
+
+			if coordinate < self.min[i] || coordinate > self.max[i] {
+				return Ok(());
+			}
+
+			if i == 0 {
+				first = coordinate;
+			}
+		}
+
+		if self.solutions.len() as u64 >= MAX_CANDIDATES {
+			return Err(Error::TooManyCandidates);
+		}
+
+		self.solutions.push(first);
+
+		Ok(())
+	}
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+	a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn to_f64(row: &[i128]) -> Vec<f64> {
+	row.iter().map(|&value| value as f64).collect()
+}
+
+/// Returns the Gram-Schmidt coefficients `mu[i][j]` for `j < i` and the squared lengths of the orthogonalized
+/// vectors.
+fn gram_schmidt(basis: &[Vec<i128>]) -> (Vec<Vec<f64>>, Vec<f64>) {
+	let dimension = basis.len();
+	let mut mu = vec![vec![0.0; dimension]; dimension];
+	let mut lengths = vec![0.0; dimension];
+	let mut orthogonal: Vec<Vec<f64>> = Vec::with_capacity(dimension);
+
+	for i in 0..dimension {
+		let row = to_f64(&basis[i]);
+		let mut projected = row.clone();
+
+		for j in 0..i {
+			mu[i][j] = dot(&row, &orthogonal[j]) / lengths[j];
+
+			for (value, &component) in projected.iter_mut().zip(&orthogonal[j]) {
+				*value -= mu[i][j] * component;
+			}
+		}
+
+		lengths[i] = dot(&projected, &projected);
+		orthogonal.push(projected);
+	}
+
+	(mu, lengths)
+}
+
+/// Returns the orthogonalized vectors for the coefficients returned by `gram_schmidt`.
+fn orthogonal_basis(basis: &[Vec<i128>], mu: &[Vec<f64>]) -> Vec<Vec<f64>> {
+	let mut orthogonal: Vec<Vec<f64>> = Vec::with_capacity(basis.len());
+
+	for (i, row) in basis.iter().enumerate() {
+		let mut projected = to_f64(row);
+
+		for (j, vector) in orthogonal.iter().enumerate() {
+			for (value, &component) in projected.iter_mut().zip(vector) {
+				*value -= mu[i][j] * component;
+			}
+		}
+
+		orthogonal.push(projected);
+	}
+
+	orthogonal
+}
+
+/// LLL reduction with a parameter of 0.99, keeping the basis exact and the Gram-Schmidt data in floating point.
+fn reduce(basis: &mut [Vec<i128>]) {
+	let dimension = basis.len();
+	let (mut mu, mut lengths) = gram_schmidt(basis);
+	let mut k = 1;
+
+	// A reduced basis is reached long before this, even with rounding in the Gram-Schmidt data
+	let mut iterations = 0;
+
+	while k < dimension && iterations < 100_000 {
+		iterations += 1;
+
+		for j in (0..k).rev() {
+			let q = mu[k][j].round();
+
+			if q != 0.0 {
+				let q = q as i128;
+
+				let (lower, upper) = basis.split_at_mut(k);
+
+				for (value, &other) in upper[0].iter_mut().zip(&lower[j]) {
+					*value -= q * other;
+				}
+
+				let (lower, upper) = mu.split_at_mut(k);
+
+				for (value, &other) in upper[0][..j].iter_mut().zip(&lower[j]) {
+					*value -= q as f64 * other;
+				}
+
+				mu[k][j] -= q as f64;
+			}
+		}
+
+		if lengths[k] >= (0.99 - mu[k][k - 1] * mu[k][k - 1]) * lengths[k - 1] {
+			k += 1;
+		} else {
+			basis.swap(k, k - 1);
+
+			let (new_mu, new_lengths) = gram_schmidt(basis);
+			mu = new_mu;
+			lengths = new_lengths;
+
+			k = (k - 1).max(1);
+		}
+	}
+}
@@ -0,0 +1,47 @@
+//! Deterministic child generators, for giving every entity or subsystem its own stream derived from one master
+//! generator.
+//!
+//! The seed of a child is `mix(state ^ salt.wrapping_mul(0x9E3779B97F4A7C15))`, where `state` is the 48-bit internal
+//! state of the parent and `mix` is the 64-bit finalizer of SplitMix64, `mix::mix_stafford13`:
+//!
+//! ```text
+//! z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9
+//! z = (z ^ (z >> 27)) * 0x94D049BB133111EB
+//! z ^ (z >> 31)
+//! ```
+//!
+//! The child is then created with `Random::new(seed)`. String salts are first hashed to a `u64` with 64-bit FNV-1a
+//! over their UTF-8 bytes. These functions are part of the stable behavior of the crate and will not change.
+
+use mix::{mix_stafford13, GOLDEN_RATIO_64};
+use Random;
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+const FNV_PRIME: u64 = 0x100000001B3;
+
+/// 64-bit FNV-1a
+const fn fnv1a(bytes: &[u8]) -> u64 {
+	let mut hash = FNV_OFFSET_BASIS;
+	let mut index = 0;
+
+	while index < bytes.len() {
+		hash = (hash ^ bytes[index] as u64).wrapping_mul(FNV_PRIME);
+		index += 1;
+	}
+
+	hash
+}
+
+impl Random {
+	/// Creates a child generator identified by `salt`, without stepping this generator. Children depend only on the
+	/// current internal state and the salt, so the same parent state and salt always give the same child, and
+	/// different salts give unrelated children. A pending gaussian of the parent is not inherited.
+	pub const fn derive_child(&self, salt: u64) -> Random {
+		Random::new(mix_stafford13(self.state ^ salt.wrapping_mul(GOLDEN_RATIO_64)))
+	}
+
+	/// Like `derive_child`, but identified by a string such as `"terrain"` or `"mob:1234"`.
+	pub const fn derive_child_str(&self, salt: &str) -> Random {
+		self.derive_child(fnv1a(salt.as_bytes()))
+	}
+}
@@ -0,0 +1,168 @@
+//! Human readable checkpoint files, for long running simulations whose state should be inspected or edited by hand.
+//!
+//! The file is a small TOML document:
+//!
+//! ```toml
+//! # java-rand checkpoint
+//! version = 1
+//! algorithm = "Random"
+//! state = ["0x00000005deece66d", "0x0000000000000000"]
+//! next_gaussian = -0.8313453697589337
+//! calls = 1024
+//! ```
+//!
+//! `algorithm` is the name reported by `Algorithm::info`, and the state words are the words of the `Checkpoint`,
+//! written as hexadecimal strings because TOML integers are signed. `next_gaussian` is only present when a gaussian
+//! is pending, and is written with the shortest representation that round-trips exactly. `calls` is a counter kept
+//! by the application, such as the number of calls or steps made since the generator was seeded.
+//!
+//! When reading, comments and blank lines are ignored, the state words may also be bare decimal or hexadecimal
+//! integers, and `calls` defaults to 0. Other keys and TOML features are rejected.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use {Algorithm, Checkpoint, Error};
+
+/// A checkpoint together with a call counter, stored as a TOML document by `save_checkpoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointFile {
+	pub checkpoint: Checkpoint,
+	pub calls: u64
+}
+
+impl CheckpointFile {
+	pub fn new(checkpoint: Checkpoint, calls: u64) -> Self {
+		CheckpointFile { checkpoint, calls }
+	}
+}
+
+/// Formats the TOML document, which `FromStr` parses back into an identical `CheckpointFile`.
+impl Display for CheckpointFile {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let [first, second] = self.checkpoint.state();
+
+		writeln!(f, "# java-rand checkpoint")?;
+		writeln!(f, "version = {}", Checkpoint::VERSION)?;
+		writeln!(f, "algorithm = \"{}\"", self.checkpoint.algorithm().info().name)?;
+		writeln!(f, "state = [\"{:#018x}\", \"{:#018x}\"]", first, second)?;
+
+		if let Some(gaussian) = self.checkpoint.next_gaussian() {
+			writeln!(f, "next_gaussian = {:?}", gaussian)?;
+		}
+
+		writeln!(f, "calls = {}", self.calls)
+	}
+}
+
+/// Parses a TOML document. Malformed documents give `Error::ParseError`, documents from a later version of the crate
+/// give `Error::UnsupportedVersion`, and states the algorithm cannot be in give `Error::InvalidState`.
+impl FromStr for CheckpointFile {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		let mut version = None;
+		let mut algorithm = None;
+		let mut state = None;
+		let mut next_gaussian = None;
+		let mut calls = None;
+
+		for line in s.lines() {
+			// None of the values can contain a #, so everything after one is a comment
+			let line = line.split('#').next().unwrap_or("").trim();
+
+			if line.is_empty() {
+				continue;
+			}
+
+			let (key, value) = line.split_once('=').ok_or(Error::ParseError)?;
+			let value = value.trim();
+
+			let duplicate = match key.trim() {
+				"version" => version.replace(value.parse::<u8>().map_err(|_| Error::ParseError)?).is_some(),
+				"algorithm" => {
+					let name = string(value).ok_or(Error::ParseError)?;
+
+					algorithm.replace(Algorithm::from_name(name).ok_or(Error::ParseError)?).is_some()
+				},
+				"state" => {
+					let words = value.strip_prefix('[').and_then(|value| value.strip_suffix(']'));
+					let mut words = words.ok_or(Error::ParseError)?.split(',').map(str::trim);
+
+					let first = words.next().and_then(word).ok_or(Error::ParseError)?;
+					let second = words.next().and_then(word).ok_or(Error::ParseError)?;
+
+					// A trailing comma is allowed after the last element
+					if !matches!((words.next(), words.next()), (None, None) | (Some(""), None)) {
+						return Err(Error::ParseError);
+					}
+
+					state.replace([first, second]).is_some()
+				},
+				"next_gaussian" => {
+					next_gaussian.replace(value.parse::<f64>().map_err(|_| Error::ParseError)?).is_some()
+				},
+				"calls" => calls.replace(integer(value).ok_or(Error::ParseError)?).is_some(),
+				_ => return Err(Error::ParseError)
+			};
+
+			if duplicate {
+				return Err(Error::ParseError);
+			}
+		}
+
+		match version {
+			Some(1) => (),
+			Some(version) if version > Checkpoint::VERSION => return Err(Error::UnsupportedVersion),
+			_ => return Err(Error::ParseError)
+		}
+
+		let algorithm = algorithm.ok_or(Error::ParseError)?;
+		let state = state.ok_or(Error::ParseError)?;
+		let checkpoint = Checkpoint::new(algorithm, state, next_gaussian).ok_or(Error::InvalidState)?;
+
+		Ok(CheckpointFile::new(checkpoint, calls.unwrap_or(0)))
+	}
+}
+
+/// Returns the contents of a basic TOML string without escapes.
+fn string(value: &str) -> Option<&str> {
+	value.strip_prefix('"').and_then(|value| value.strip_suffix('"')).filter(|value| !value.contains(['"', '\\']))
+}
+
+/// Parses an unsigned decimal or `0x` prefixed hexadecimal integer, allowing TOML's underscores between digits.
+fn integer(value: &str) -> Option<u64> {
+	let (digits, radix) = match value.strip_prefix("0x") {
+		Some(hex) => (hex, 16),
+		None => (value, 10)
+	};
+
+	if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+		return None;
+	}
+
+	u64::from_str_radix(&digits.replace('_', ""), radix).ok()
+}
+
+/// Parses a state word, either a string holding an integer or a bare integer.
+fn word(value: &str) -> Option<u64> {
+	integer(string(value).unwrap_or(value))
+}
+
+/// Writes `checkpoint` and the counter `calls` to the file at `path` as a TOML document, replacing the file if it
+/// exists.
+pub fn save_checkpoint<P: AsRef<Path>>(path: P, checkpoint: &Checkpoint, calls: u64) -> io::Result<()> {
+	fs::write(path, CheckpointFile::new(*checkpoint, calls).to_string())
+}
+
+/// Reads a checkpoint and its counter from a TOML document written by `save_checkpoint` or by hand.
+///
+/// # Errors
+/// Besides errors reading the file, an error of kind `InvalidData` is returned if the document cannot be parsed,
+/// wrapping the `Error` returned by `CheckpointFile::from_str`.
+pub fn load_checkpoint<P: AsRef<Path>>(path: P) -> io::Result<CheckpointFile> {
+	fs::read_to_string(path)?.parse().map_err(|error: Error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
@@ -0,0 +1,106 @@
+//! Draws chosen by type, for code that is generic over the kind of value it takes from the generator.
+//!
+//! Every type has exactly one draw, so generic code makes the same calls as the Java code it was written after.
+//! Draws that take arguments in Java, such as `nextInt(bound)` and `nextGaussian()`, are selected with the newtypes
+//! [`Bounded`] and [`Gaussian`].
+
+use core::array;
+
+use Random;
+
+/// A type with a standard draw, the `java.util.Random` method that produces it.
+///
+/// | Type   | Method      | Java            |
+/// |--------|-------------|-----------------|
+/// | `i32`  | `next_i32`  | `nextInt()`     |
+/// | `u32`  | `next_u32`  | `nextInt()`     |
+/// | `i64`  | `next_i64`  | `nextLong()`    |
+/// | `u64`  | `next_u64`  | `nextLong()`    |
+/// | `bool` | `next_bool` | `nextBoolean()` |
+/// | `f32`  | `next_f32`  | `nextFloat()`   |
+/// | `f64`  | `next_f64`  | `nextDouble()`  |
+///
+/// [`Bounded`] and [`Gaussian`] select `nextInt(bound)` and `nextGaussian()`.
+pub trait JavaDraw: Sized {
+	/// Draws a value with the standard method of the type.
+	fn draw(random: &mut Random) -> Self;
+}
+
+macro_rules! java_draw {
+	($($ty:ty => $method:ident),*) => {
+		$(
+			impl JavaDraw for $ty {
+				#[inline]
+				fn draw(random: &mut Random) -> Self {
+					random.$method()
+				}
+			}
+		)*
+	};
+}
+
+java_draw!(i32 => next_i32, u32 => next_u32, i64 => next_i64, u64 => next_u64, bool => next_bool, f32 => next_f32,
+	f64 => next_f64);
+
+/// An `i32` drawn with `nextInt(MAX)`, through `next_i32_bound_const`. A bound of less than 1 is rejected at compile
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bounded<const MAX: i32>(pub i32);
+
+impl<const MAX: i32> JavaDraw for Bounded<MAX> {
+	#[inline]
+	fn draw(random: &mut Random) -> Self {
+		Bounded(random.next_i32_bound_const::<MAX>())
+	}
+}
+
+/// An `f64` drawn with `nextGaussian()`, which takes the pending gaussian first like any other call.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Gaussian(pub f64);
+
+impl JavaDraw for Gaussian {
+	#[inline]
+	fn draw(random: &mut Random) -> Self {
+		Gaussian(random.next_gaussian())
+	}
+}
+
+impl Random {
+	/// Returns the standard draw of `T`, for code that is generic over the drawn type.
+	///
+	/// ```
+	/// use java_rand::{Bounded, Random};
+	///
+	/// let mut random = Random::new(42);
+	/// let mut expected = random.clone();
+	///
+	/// let Bounded(roll) = random.gen::<Bounded<6>>();
+	/// let chance: f64 = random.gen();
+	///
+	/// assert_eq!(roll, expected.next_i32_bound(6));
+	/// assert_eq!(chance, expected.next_f64());
+	/// ```
+	#[inline]
+	pub fn gen<T: JavaDraw>(&mut self) -> T {
+		T::draw(self)
+	}
+
+	/// Returns an array filled with the standard draw of `T`, the first element being drawn first. This makes the
+	/// same calls as a Java loop filling an array of length `N` from index 0, without allocating.
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// let mut random = Random::new(1234);
+	/// let offsets = random.next_array::<f64, 16>();
+	///
+	/// let mut expected = Random::new(1234);
+	///
+	/// for &offset in &offsets {
+	///     assert_eq!(offset, expected.next_f64());
+	/// }
+	/// ```
+	pub fn next_array<T: JavaDraw, const N: usize>(&mut self) -> [T; N] {
+		array::from_fn(|_| T::draw(self))
+	}
+}
@@ -0,0 +1,93 @@
+use core::fmt::{self, Display, Formatter};
+
+use sweep::ParseShardError;
+use text::ParseRandomError;
+
+/// Error returned by the `try_` variants of the methods that otherwise panic, and by the parsers through `From`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+	/// An integer bound was less than 1, or a floating point bound was not positive and finite.
+	InvalidBound,
+	/// A floating point range had endpoints that were not finite, or an origin that was not less than the bound.
+	InvalidRange,
+	/// More than 48 bits were requested from `next`.
+	TooManyBits,
+	/// A shard would extend past the end of the state space, shard counts and indices were out of range, or a substream
+	/// index did not fit in the period.
+	InvalidShard,
+	/// The textual form of a `Random` or a `Shard` was malformed.
+	ParseError,
+	/// A `RandomBuilder` was built without a seed source and without a clock to fall back on.
+	MissingSeed,
+	/// A `Checkpoint` was restored into a generator of a different algorithm.
+	AlgorithmMismatch,
+	/// An encoded state was written by a later version of the crate, in a binary form this version cannot read.
+	UnsupportedVersion,
+	/// An encoded state was truncated, or was not a state its algorithm can be in.
+	InvalidState,
+	/// Observations passed to the cracker left too many candidate states to enumerate.
+	TooManyCandidates,
+	/// A `RandomSet` already held a generator of the same name, or one in the same state.
+	DuplicateGenerator
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(match *self {
+			Error::InvalidBound => "bound must be positive, and finite for floating point bounds",
+			Error::InvalidRange => "origin must be less than bound, and both must be finite",
+			Error::TooManyBits => "at most 48 bits can be requested from next",
+			Error::InvalidShard => "shard lies outside of the 48-bit state space",
+			Error::ParseError => "malformed textual form",
+			Error::MissingSeed => "a seed source is required without std",
+			Error::AlgorithmMismatch => "checkpoint was taken from a different algorithm",
+			Error::UnsupportedVersion => "state was encoded by a later version of the binary form",
+			Error::InvalidState => "encoded state is malformed or not reachable by its algorithm",
+			Error::TooManyCandidates => "observations leave too many candidate states to enumerate",
+			Error::DuplicateGenerator => "set already holds a generator of that name or in that state"
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Error {}
+
+impl From<ParseRandomError> for Error {
+	fn from(_: ParseRandomError) -> Self {
+		Error::ParseError
+	}
+}
+
+impl From<ParseShardError> for Error {
+	fn from(_: ParseShardError) -> Self {
+		Error::ParseError
+	}
+}
+
+/// Checks an integer bound as accepted by `next_i32_bound`.
+pub const fn check_i32_bound(max: i32) -> Result<(), Error> {
+	if max <= 0 {
+		Err(Error::InvalidBound)
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks a bound as accepted by `next_f64_bound`.
+pub fn check_f64_bound(bound: f64) -> Result<(), Error> {
+	if bound > 0.0 && bound < f64::INFINITY {
+		Ok(())
+	} else {
+		Err(Error::InvalidBound)
+	}
+}
+
+/// Checks a range as accepted by `next_f64_range`.
+pub fn check_f64_range(origin: f64, bound: f64) -> Result<(), Error> {
+	if f64::NEG_INFINITY < origin && origin < bound && bound < f64::INFINITY {
+		Ok(())
+	} else {
+		Err(Error::InvalidRange)
+	}
+}
@@ -0,0 +1,539 @@
+//! A line based call log format shared with Java, so that the calls of a Rust port and of the Java program it was
+//! ported from can be recorded and compared automatically.
+//!
+//! A trace is a JSON Lines file with one object per call:
+//!
+//! ```text
+//! {"index":0,"method":"nextInt","args":[10],"result":7}
+//! {"index":1,"method":"nextDouble","args":[],"result":0.6832234717598454}
+//! {"index":2,"method":"nextBytes","args":[3],"result":[-12,5,99]}
+//! ```
+//!
+//! * `index` counts the calls made by the program, from 0 when the generator was seeded. It is informational, so a
+//!   trace can start in the middle of a run.
+//! * `method` is the Java name of the method, and `args` its arguments, which also select the overload: `nextInt`
+//!   with `[]` or `[bound]`, `nextDouble` with `[]`, `[bound]` or `[origin, bound]`, and `next` with `[bits]`.
+//!   `nextBytes` takes the length of the array.
+//! * `result` is an integer for `next`, `nextInt` and `nextLong`, `true` or `false` for `nextBoolean`, a number for
+//!   `nextFloat`, `nextDouble` and `nextGaussian`, and an array of signed bytes for `nextBytes`.
+//!
+//! Floating point numbers are written so that they parse back to the same value: Rust uses the shortest round trip
+//! representation, and Java `Double.toString`. `nextFloat` results are written as the double with the same value,
+//! `Double.toString((double) value)` in Java, so that no rounding happens when reading them.
+//!
+//! The Java side is `TracingRandom.java` in the repository, a subclass of `java.util.Random` that writes a line for
+//! every call made to it. Its traces are read with `read_trace`, checked against a seed with `validate`, and compared
+//! with the trace of a port with `diff`. `TraceWriter` writes the traces of the Rust side.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+use std::io::{self, BufRead, Write};
+use std::vec::Vec;
+
+use {Error, Method, Random};
+
+/// An argument or result in a trace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+	Int(i64),
+	Double(f64),
+	Bool(bool),
+	Bytes(Vec<i8>)
+}
+
+impl Value {
+	/// Returns whether the values are identical, comparing doubles bit for bit.
+	pub fn same(&self, other: &Value) -> bool {
+		match (self, other) {
+			(&Value::Double(a), &Value::Double(b)) => a.to_bits() == b.to_bits(),
+			_ => self == other
+		}
+	}
+}
+
+/// Formats the value as JSON.
+impl Display for Value {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Value::Int(value) => write!(f, "{}", value),
+			Value::Double(value) => write!(f, "{:?}", value),
+			Value::Bool(value) => write!(f, "{}", value),
+			Value::Bytes(ref bytes) => list(f, bytes)
+		}
+	}
+}
+
+/// One call in a trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+	pub index: u64,
+	pub method: Method,
+	pub args: Vec<Value>,
+	pub result: Value
+}
+
+impl TraceEntry {
+	/// Makes the call of this entry on `random` and returns its result, or None if the arguments do not fit the
+	/// method or would make Java throw.
+	pub fn replay(&self, random: &mut Random) -> Option<Value> {
+		let result = match (self.method, &self.args[..]) {
+			(Method::Next, &[Value::Int(bits)]) if (1..=32).contains(&bits) => {
+				Value::Int(random.next(bits as u8) as i64)
+			},
+			(Method::NextBytes, &[Value::Int(len)]) if (0..=i32::MAX as i64).contains(&len) => {
+				let mut bytes = vec![0; len as usize];
+				random.next_bytes(&mut bytes);
+
+				Value::Bytes(bytes.iter().map(|&byte| byte as i8).collect())
+			},
+			(Method::NextI32, &[]) => Value::Int(random.next_i32() as i64),
+			(Method::NextI32Bound, &[Value::Int(bound)]) => {
+				Value::Int(random.try_next_i32_bound(java_int(bound)?).ok()? as i64)
+			},
+			(Method::NextI64, &[]) => Value::Int(random.next_i64()),
+			(Method::NextBool, &[]) => Value::Bool(random.next_bool()),
+			(Method::NextF32, &[]) => Value::Double(random.next_f32() as f64),
+			(Method::NextF64, &[]) => Value::Double(random.next_f64()),
+			(Method::NextF64Bound, &[Value::Double(bound)]) => Value::Double(random.try_next_f64_bound(bound).ok()?),
+			(Method::NextF64Range, &[Value::Double(origin), Value::Double(bound)]) => {
+				Value::Double(random.try_next_f64_range(origin, bound).ok()?)
+			},
+			(Method::NextGaussian, &[]) => Value::Double(random.next_gaussian()),
+			_ => return None
+		};
+
+		Some(result)
+	}
+}
+
+/// Formats the entry as a line of the trace, without the line terminator.
+impl Display for TraceEntry {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{{\"index\":{},\"method\":\"{}\",\"args\":", self.index, json_name(self.method))?;
+		list(f, &self.args)?;
+		write!(f, ",\"result\":{}}}", self.result)
+	}
+}
+
+/// Parses a line of a trace. Malformed lines, unknown methods and arguments or results of the wrong type for the
+/// method give `Error::ParseError`.
+impl FromStr for TraceEntry {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		let mut parser = Parser { rest: s };
+		let mut index = None;
+		let mut method = None;
+		let mut args = None;
+		let mut result = None;
+
+		parser.expect('{')?;
+
+		loop {
+			let key = parser.string()?;
+			parser.expect(':')?;
+
+			let duplicate = match key {
+				"index" => index.replace(parser.json()?).is_some(),
+				"method" => method.replace(parser.string()?).is_some(),
+				"args" => args.replace(parser.json()?).is_some(),
+				"result" => result.replace(parser.json()?).is_some(),
+				_ => return Err(Error::ParseError)
+			};
+
+			if duplicate {
+				return Err(Error::ParseError);
+			}
+
+			if !parser.accept(',') {
+				break;
+			}
+		}
+
+		parser.expect('}')?;
+
+		if !parser.rest.trim().is_empty() {
+			return Err(Error::ParseError);
+		}
+
+		let index = match index {
+			Some(Json::Number(number)) => number.parse::<u64>().map_err(|_| Error::ParseError)?,
+			_ => return Err(Error::ParseError)
+		};
+
+		let args = match args {
+			Some(Json::Array(args)) => args,
+			_ => return Err(Error::ParseError)
+		};
+
+		let method = method.ok_or(Error::ParseError)?;
+		let result = result.ok_or(Error::ParseError)?;
+
+		let (method, args) = match (method, args.len()) {
+			("next", 1) => (Method::Next, vec![int(&args[0])?]),
+			("nextBytes", 1) => (Method::NextBytes, vec![int(&args[0])?]),
+			("nextInt", 0) => (Method::NextI32, Vec::new()),
+			("nextInt", 1) => (Method::NextI32Bound, vec![int(&args[0])?]),
+			("nextLong", 0) => (Method::NextI64, Vec::new()),
+			("nextBoolean", 0) => (Method::NextBool, Vec::new()),
+			("nextFloat", 0) => (Method::NextF32, Vec::new()),
+			("nextDouble", 0) => (Method::NextF64, Vec::new()),
+			("nextDouble", 1) => (Method::NextF64Bound, vec![double(&args[0])?]),
+			("nextDouble", 2) => (Method::NextF64Range, vec![double(&args[0])?, double(&args[1])?]),
+			("nextGaussian", 0) => (Method::NextGaussian, Vec::new()),
+			_ => return Err(Error::ParseError)
+		};
+
+		let result = match method {
+			Method::Next | Method::NextI32 | Method::NextI32Bound | Method::NextI64 => int(&result)?,
+			Method::NextBool => match result {
+				Json::Bool(value) => Value::Bool(value),
+				_ => return Err(Error::ParseError)
+			},
+			Method::NextBytes => match result {
+				Json::Array(ref bytes) => {
+					let bytes = bytes.iter().map(|byte| match int(byte) {
+						Ok(Value::Int(byte)) if byte as i8 as i64 == byte => Ok(byte as i8),
+						_ => Err(Error::ParseError)
+					});
+
+					Value::Bytes(bytes.collect::<Result<_, _>>()?)
+				},
+				_ => return Err(Error::ParseError)
+			},
+			_ => double(&result)?
+		};
+
+		Ok(TraceEntry { index, method, args, result })
+	}
+}
+
+/// The first entry of a trace whose result differs from the one computed by replaying the trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+	/// Position of the entry in the trace
+	pub position: usize,
+	pub entry: TraceEntry,
+	/// The result computed by replaying the call, or None if its arguments were invalid
+	pub actual: Option<Value>
+}
+
+/// Replays every call of the trace on `random`, returning the first entry whose recorded result is not the one
+/// `random` produces. A trace recorded from `new Random(seed)` validates against `Random::new(seed)`.
+pub fn validate(entries: &[TraceEntry], random: &mut Random) -> Result<(), Divergence> {
+	for (position, entry) in entries.iter().enumerate() {
+		let actual = entry.replay(random);
+
+		if !actual.as_ref().is_some_and(|actual| actual.same(&entry.result)) {
+			return Err(Divergence { position, entry: entry.clone(), actual });
+		}
+	}
+
+	Ok(())
+}
+
+/// Returns the position of the first entry that differs between two traces, in its index, method, arguments or
+/// result, or the length of the shorter trace if one is a prefix of the other. Identical traces give None.
+pub fn diff(a: &[TraceEntry], b: &[TraceEntry]) -> Option<usize> {
+	let same = |a: &TraceEntry, b: &TraceEntry| {
+		let args = a.args.len() == b.args.len() && a.args.iter().zip(&b.args).all(|(a, b)| a.same(b));
+
+		a.index == b.index && a.method == b.method && args && a.result.same(&b.result)
+	};
+
+	match a.iter().zip(b).position(|(a, b)| !same(a, b)) {
+		Some(position) => Some(position),
+		None if a.len() != b.len() => Some(a.len().min(b.len())),
+		None => None
+	}
+}
+
+/// Reads a trace, skipping blank lines.
+///
+/// # Errors
+/// Besides errors reading the trace, an error of kind `InvalidData` is returned for lines that cannot be parsed,
+/// wrapping the `Error` returned by `TraceEntry::from_str`.
+pub fn read_trace<R: BufRead>(reader: R) -> io::Result<Vec<TraceEntry>> {
+	let mut entries = Vec::new();
+
+	for line in reader.lines() {
+		let line = line?;
+
+		if !line.trim().is_empty() {
+			entries.push(line.parse().map_err(|error: Error| io::Error::new(io::ErrorKind::InvalidData, error))?);
+		}
+	}
+
+	Ok(entries)
+}
+
+/// Wrapper around a `Random` writing a trace line for every call made through it.
+///
+/// Errors writing the trace do not interrupt the calls: the first one is kept and returned by `finish`, and nothing
+/// more is written after it.
+#[derive(Debug)]
+pub struct TraceWriter<W: Write> {
+	random: Random,
+	out: W,
+	index: u64,
+	error: Option<io::Error>
+}
+
+impl<W: Write> TraceWriter<W> {
+	/// Wraps a generator, numbering calls from 0.
+	pub fn new(random: Random, out: W) -> Self {
+		TraceWriter::starting_at(random, out, 0)
+	}
+
+	/// Wraps a generator, numbering calls from `index`, for generators that already made calls.
+	pub fn starting_at(random: Random, out: W, index: u64) -> Self {
+		TraceWriter {
+			random,
+			out,
+			index,
+			error: None
+		}
+	}
+
+	/// Returns the wrapped generator.
+	pub fn random(&self) -> &Random {
+		&self.random
+	}
+
+	/// Flushes the trace and returns the generator and the writer, or the first error writing the trace.
+	pub fn finish(mut self) -> io::Result<(Random, W)> {
+		if let Some(error) = self.error {
+			return Err(error);
+		}
+
+		self.out.flush()?;
+
+		Ok((self.random, self.out))
+	}
+
+	fn record(&mut self, method: Method, args: Vec<Value>, result: Value) {
+		let entry = TraceEntry { index: self.index, method, args, result };
+		self.index += 1;
+
+		if self.error.is_none() {
+			if let Err(error) = writeln!(self.out, "{}", entry) {
+				self.error = Some(error);
+			}
+		}
+	}
+
+	/// See `Random::next`.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		let value = self.random.next(bits);
+
+		self.record(Method::Next, vec![Value::Int(bits as i64)], Value::Int(value as i64));
+		value
+	}
+
+	/// See `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		self.random.next_bytes(bytes);
+
+		let result = Value::Bytes(bytes.iter().map(|&byte| byte as i8).collect());
+		self.record(Method::NextBytes, vec![Value::Int(bytes.len() as i64)], result);
+	}
+
+	/// See `Random::next_i32`.
+	pub fn next_i32(&mut self) -> i32 {
+		let value = self.random.next_i32();
+
+		self.record(Method::NextI32, Vec::new(), Value::Int(value as i64));
+		value
+	}
+
+	/// See `Random::next_i32_bound`.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		let value = self.random.next_i32_bound(max);
+
+		self.record(Method::NextI32Bound, vec![Value::Int(max as i64)], Value::Int(value as i64));
+		value
+	}
+
+	/// See `Random::next_i64`.
+	pub fn next_i64(&mut self) -> i64 {
+		let value = self.random.next_i64();
+
+		self.record(Method::NextI64, Vec::new(), Value::Int(value));
+		value
+	}
+
+	/// See `Random::next_bool`.
+	pub fn next_bool(&mut self) -> bool {
+		let value = self.random.next_bool();
+
+		self.record(Method::NextBool, Vec::new(), Value::Bool(value));
+		value
+	}
+
+	/// See `Random::next_f32`.
+	pub fn next_f32(&mut self) -> f32 {
+		let value = self.random.next_f32();
+
+		self.record(Method::NextF32, Vec::new(), Value::Double(value as f64));
+		value
+	}
+
+	/// See `Random::next_f64`.
+	pub fn next_f64(&mut self) -> f64 {
+		let value = self.random.next_f64();
+
+		self.record(Method::NextF64, Vec::new(), Value::Double(value));
+		value
+	}
+
+	/// See `Random::next_f64_bound`.
+	pub fn next_f64_bound(&mut self, bound: f64) -> f64 {
+		let value = self.random.next_f64_bound(bound);
+
+		self.record(Method::NextF64Bound, vec![Value::Double(bound)], Value::Double(value));
+		value
+	}
+
+	/// See `Random::next_f64_range`.
+	pub fn next_f64_range(&mut self, origin: f64, bound: f64) -> f64 {
+		let value = self.random.next_f64_range(origin, bound);
+
+		self.record(Method::NextF64Range, vec![Value::Double(origin), Value::Double(bound)], Value::Double(value));
+		value
+	}
+
+	/// See `Random::next_gaussian`.
+	pub fn next_gaussian(&mut self) -> f64 {
+		let value = self.random.next_gaussian();
+
+		self.record(Method::NextGaussian, Vec::new(), Value::Double(value));
+		value
+	}
+}
+
+/// Returns the name of a method in traces, its Java name without the arguments.
+fn json_name(method: Method) -> &'static str {
+	let name = method.java_name();
+
+	&name[..name.find('(').unwrap_or(name.len())]
+}
+
+/// Writes the items as a JSON array.
+fn list<T: Display>(f: &mut Formatter, items: &[T]) -> fmt::Result {
+	f.write_str("[")?;
+
+	for (index, item) in items.iter().enumerate() {
+		if index > 0 {
+			f.write_str(",")?;
+		}
+
+		write!(f, "{}", item)?;
+	}
+
+	f.write_str("]")
+}
+
+/// Converts a Java `int` from a trace.
+fn java_int(value: i64) -> Option<i32> {
+	if value as i32 as i64 == value { Some(value as i32) } else { None }
+}
+
+fn int(json: &Json) -> Result<Value, Error> {
+	match *json {
+		Json::Number(number) => number.parse::<i64>().map(Value::Int).map_err(|_| Error::ParseError),
+		_ => Err(Error::ParseError)
+	}
+}
+
+fn double(json: &Json) -> Result<Value, Error> {
+	match *json {
+		Json::Number(number) => number.parse::<f64>().map(Value::Double).map_err(|_| Error::ParseError),
+		_ => Err(Error::ParseError)
+	}
+}
+
+/// The JSON values that appear in traces. Numbers are kept as text, since their type depends on the method.
+#[derive(Debug)]
+enum Json<'a> {
+	Number(&'a str),
+	Bool(bool),
+	Array(Vec<Json<'a>>)
+}
+
+/// Parser for the subset of JSON used by traces. Strings cannot contain escapes.
+struct Parser<'a> {
+	rest: &'a str
+}
+
+impl<'a> Parser<'a> {
+	fn skip_whitespace(&mut self) {
+		self.rest = self.rest.trim_start_matches([' ', '\t', '\n', '\r']);
+	}
+
+	fn accept(&mut self, token: char) -> bool {
+		self.skip_whitespace();
+
+		match self.rest.strip_prefix(token) {
+			Some(rest) => {
+				self.rest = rest;
+				true
+			},
+			None => false
+		}
+	}
+
+	fn expect(&mut self, token: char) -> Result<(), Error> {
+		if self.accept(token) { Ok(()) } else { Err(Error::ParseError) }
+	}
+
+	fn string(&mut self) -> Result<&'a str, Error> {
+		self.expect('"')?;
+
+		let end = self.rest.find(['"', '\\']).ok_or(Error::ParseError)?;
+		let (string, rest) = self.rest.split_at(end);
+
+		self.rest = rest.strip_prefix('"').ok_or(Error::ParseError)?;
+		Ok(string)
+	}
+
+	fn json(&mut self) -> Result<Json<'a>, Error> {
+		self.skip_whitespace();
+
+		if self.accept('[') {
+			let mut items = Vec::new();
+
+			if !self.accept(']') {
+				loop {
+					items.push(self.json()?);
+
+					if !self.accept(',') {
+						break;
+					}
+				}
+
+				self.expect(']')?;
+			}
+
+			return Ok(Json::Array(items));
+		}
+
+		for &(word, value) in &[("true", true), ("false", false)] {
+			if let Some(rest) = self.rest.strip_prefix(word) {
+				self.rest = rest;
+				return Ok(Json::Bool(value));
+			}
+		}
+
+		let is_number = |c: char| matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E');
+		let end = self.rest.find(|c: char| !is_number(c)).unwrap_or(self.rest.len());
+
+		if end == 0 {
+			return Err(Error::ParseError);
+		}
+
+		let (number, rest) = self.rest.split_at(end);
+		self.rest = rest;
+
+		Ok(Json::Number(number))
+	}
+}
@@ -0,0 +1,79 @@
+//! Distributions that `java.util.Random` does not have, built only from its `nextDouble` calls. These are **not
+//! compatible with Java** in the sense that Java has no such methods, but each one is written in terms of the calls
+//! it makes, so that it can be ported to Java or any other language with the same results.
+//!
+//! The draw order of every sampler, that is the calls it makes and how their results are combined, is part of its
+//! documentation and will not change in later versions of the crate. Given the same generator and parameters, a
+//! sampler always returns the same value and leaves the generator in the same state. The arithmetic uses `strictmath`,
+//! so the values are also the same on every platform.
+
+use strictmath;
+use Random;
+
+/// Returns a log-normally distributed number, whose logarithm is normally distributed with a mean of `mu` and a
+/// standard deviation of `sigma`.
+///
+/// Draws pairs of `nextDouble()` until the polar method accepts one, exactly like `nextGaussian()`, computes the first
+/// gaussian of the pair as `v1 * sqrt(-2 * log(s) / s)`, and returns `exp(mu + sigma * gaussian)`. Unlike
+/// `nextGaussian()`, the second value of the pair is discarded rather than saved, and a pending gaussian of the
+/// generator is neither used nor changed.
+///
+/// # Panics
+/// If `sigma` is negative or NaN, the function panics.
+pub fn next_log_normal(random: &mut Random, mu: f64, sigma: f64) -> f64 {
+	if sigma < 0.0 || sigma.is_nan() {
+		panic!("Sigma must be >= 0")
+	}
+
+	loop {
+		let v1 = 2.0 * random.next_f64() - 1.0;
+		let v2 = 2.0 * random.next_f64() - 1.0;
+		let s = v1 * v1 + v2 * v2;
+
+		if s < 1.0 && s != 0.0 {
+			let gaussian = v1 * strictmath::sqrt(-2.0 * strictmath::log(s) / s);
+
+			return strictmath::exp(mu + sigma * gaussian);
+		}
+	}
+}
+
+/// Returns a Pareto distributed number of at least `scale`, with a tail index of `shape`.
+///
+/// Draws one `nextDouble()` as `u`, and returns `scale * pow(1 - u, -1 / shape)`.
+///
+/// # Panics
+/// If `scale` or `shape` is not greater than 0, the function panics.
+pub fn next_pareto(random: &mut Random, scale: f64, shape: f64) -> f64 {
+	if scale <= 0.0 || scale.is_nan() {
+		panic!("Scale must be > 0")
+	}
+
+	if shape <= 0.0 || shape.is_nan() {
+		panic!("Shape must be > 0")
+	}
+
+	let u = random.next_f64();
+
+	scale * strictmath::pow(1.0 - u, -1.0 / shape)
+}
+
+/// Returns a geometrically distributed number, the number of failed trials before the first success, where each
+/// trial succeeds with a probability of `p`.
+///
+/// Draws one `nextDouble()` as `u`, and returns `(long) ((0 - log(1 - u)) / (0 - log(1 - p)))`, a cast that rounds
+/// toward 0, saturates, and takes NaN to 0. One value is drawn even if `p` is 1, where the result is always 0. Below
+/// about 2^-53, `1 - p` rounds to 1, and the result is `i64::MAX` unless `u` is 0.
+///
+/// # Panics
+/// If `p` is not greater than 0 and at most 1, the function panics.
+pub fn next_geometric(random: &mut Random, p: f64) -> i64 {
+	if !(p > 0.0 && p <= 1.0) {
+		panic!("Probability must be > 0 and <= 1")
+	}
+
+	let u = random.next_f64();
+
+	// Subtracting from 0 negates the logarithms without turning log(1) into -0, so the quotient is never negative
+	((0.0 - strictmath::log(1.0 - u)) / (0.0 - strictmath::log(1.0 - p))) as i64
+}
@@ -0,0 +1,126 @@
+//! C API over `Random`, enabled by the `ffi` feature. The matching header is `include/java_rand.h`, and the shared
+//! library is built with `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! Generators are handed out as opaque pointers created by `javarand_new` and released by `javarand_free`. Every
+//! other function requires a valid, non-null handle that is not used from multiple threads at once.
+
+use std::slice;
+
+use Random;
+
+/// Creates a generator, equivalent to `new Random(seed)`. The handle must be released with `javarand_free`.
+#[no_mangle]
+pub extern "C" fn javarand_new(seed: i64) -> *mut Random {
+	Box::into_raw(Box::new(Random::new(seed as u64)))
+}
+
+/// Releases a generator created by `javarand_new`. Passing null is a no-op.
+///
+/// # Safety
+/// `random` must be null or a handle from `javarand_new` that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_free(random: *mut Random) {
+	if !random.is_null() {
+		drop(Box::from_raw(random));
+	}
+}
+
+/// Equivalent to `setSeed(seed)`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_set_seed(random: *mut Random, seed: i64) {
+	(*random).set_seed(seed as u64)
+}
+
+/// Equivalent to `next(bits)`. Returns 0 without stepping the generator if `bits` is over 48.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next(random: *mut Random, bits: u8) -> i32 {
+	if bits > 48 {
+		return 0;
+	}
+
+	(*random).next(bits)
+}
+
+/// Equivalent to `nextBytes(bytes)` over a buffer of `len` bytes.
+///
+/// # Safety
+/// `random` must be a valid handle, and `bytes` must point to `len` writable bytes. `bytes` may be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_bytes(random: *mut Random, bytes: *mut u8, len: usize) {
+	if len != 0 {
+		(*random).next_bytes(slice::from_raw_parts_mut(bytes, len))
+	}
+}
+
+/// Equivalent to `nextInt()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_int(random: *mut Random) -> i32 {
+	(*random).next_i32()
+}
+
+/// Equivalent to `nextInt(bound)`. Returns -1 without stepping the generator if `bound` is not positive.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_int_bound(random: *mut Random, bound: i32) -> i32 {
+	if bound <= 0 {
+		return -1;
+	}
+
+	(*random).next_i32_bound(bound)
+}
+
+/// Equivalent to `nextLong()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_long(random: *mut Random) -> i64 {
+	(*random).next_i64()
+}
+
+/// Equivalent to `nextBoolean()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_boolean(random: *mut Random) -> bool {
+	(*random).next_bool()
+}
+
+/// Equivalent to `nextFloat()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_float(random: *mut Random) -> f32 {
+	(*random).next_f32()
+}
+
+/// Equivalent to `nextDouble()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_double(random: *mut Random) -> f64 {
+	(*random).next_f64()
+}
+
+/// Equivalent to `nextGaussian()`.
+///
+/// # Safety
+/// `random` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn javarand_next_gaussian(random: *mut Random) -> f64 {
+	(*random).next_gaussian()
+}
@@ -0,0 +1,110 @@
+//! Conversion of random integers to floats in [0, 1), as done by `nextFloat` and `nextDouble`.
+//!
+//! Java divides by 2^24 or 2^53. With the `fast-float` feature, the division is replaced by a multiplication with
+//! the reciprocal. Because the reciprocal of a power of two is exact and the quotient never underflows, both give
+//! identical results for every input.
+//!
+//! With the `deterministic` feature, which takes precedence, the floats are assembled from their bit patterns instead
+//! of going through an integer to float conversion and a division, so that the result does not depend on the host FPU
+//! at all.
+
+#[cfg(not(any(feature = "deterministic", feature = "fast-float")))]
+const F32_DIV: f32 = (1u32 << 24) as f32;
+#[cfg(not(any(feature = "deterministic", feature = "fast-float")))]
+const F64_DIV: f64 = (1u64 << 53) as f64;
+
+#[cfg(all(feature = "fast-float", not(feature = "deterministic")))]
+const F32_RECIPROCAL: f32 = 1.0 / (1u32 << 24) as f32;
+#[cfg(all(feature = "fast-float", not(feature = "deterministic")))]
+const F64_RECIPROCAL: f64 = 1.0 / (1u64 << 53) as f64;
+
+/// Returns `value / 2^24` for a 24-bit `value`.
+#[cfg(not(any(feature = "deterministic", feature = "fast-float")))]
+#[inline]
+pub const fn unit_f32(value: u32) -> f32 {
+	(value as f32) / F32_DIV
+}
+
+/// Returns `value / 2^53` for a 53-bit `value`.
+#[cfg(not(any(feature = "deterministic", feature = "fast-float")))]
+#[inline]
+pub const fn unit_f64(value: u64) -> f64 {
+	(value as f64) / F64_DIV
+}
+
+/// Returns `value / 2^24` for a 24-bit `value`, computed as `value * 2^-24`.
+#[cfg(all(feature = "fast-float", not(feature = "deterministic")))]
+#[inline]
+pub const fn unit_f32(value: u32) -> f32 {
+	(value as f32) * F32_RECIPROCAL
+}
+
+/// Returns `value / 2^53` for a 53-bit `value`, computed as `value * 2^-53`.
+#[cfg(all(feature = "fast-float", not(feature = "deterministic")))]
+#[inline]
+pub const fn unit_f64(value: u64) -> f64 {
+	(value as f64) * F64_RECIPROCAL
+}
+
+/// Returns `value / 2^24` for a 24-bit `value`. Every such value is exactly representable, so only the exponent
+/// needs to be computed.
+#[cfg(feature = "deterministic")]
+#[inline]
+pub const fn unit_f32(value: u32) -> f32 {
+	if value == 0 {
+		return 0.0;
+	}
+
+	// Position of the highest set bit, which becomes the implicit leading bit of the significand.
+	let msb = 31 - value.leading_zeros();
+	let exponent = 127 + msb - 24;
+	let significand = (value << (23 - msb)) & ((1 << 23) - 1);
+
+	f32::from_bits((exponent << 23) | significand)
+}
+
+/// Returns `value / 2^53` for a 53-bit `value`. Every such value is exactly representable, so only the exponent
+/// needs to be computed.
+#[cfg(feature = "deterministic")]
+#[inline]
+pub const fn unit_f64(value: u64) -> f64 {
+	if value == 0 {
+		return 0.0;
+	}
+
+	let msb = 63 - value.leading_zeros() as u64;
+	let exponent = 1023 + msb - 53;
+	let significand = (value << (52 - msb)) & ((1 << 52) - 1);
+
+	f64::from_bits((exponent << 52) | significand)
+}
+
+/// Scales `r` in [0, 1) to [0, bound), equivalent to `RandomSupport.boundedNextDouble(rng, bound)`. The product can
+/// round up to `bound` itself, in which case the largest value below `bound` is returned instead.
+pub fn bounded_f64(r: f64, bound: f64) -> f64 {
+	let r = r * bound;
+
+	if r >= bound {
+		bound.next_down()
+	} else {
+		r
+	}
+}
+
+/// Scales `r` in [0, 1) to [origin, bound), equivalent to `RandomSupport.boundedNextDouble(rng, origin, bound)`.
+/// Ranges wider than `f64::MAX` are handled as in Java 19 and newer, by computing with halved endpoints.
+pub fn bounded_f64_range(r: f64, origin: f64, bound: f64) -> f64 {
+	let r = if bound - origin < f64::INFINITY {
+		r * (bound - origin) + origin
+	} else {
+		let half_origin = 0.5 * origin;
+
+		(r * (0.5 * bound - half_origin) + half_origin) * 2.0
+	};
+
+	if r >= bound {
+		bound.next_down()
+	} else {
+		r
+	}
+}
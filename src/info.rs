@@ -0,0 +1,121 @@
+//! Machine-readable descriptions of the generators, mirroring the queries of `RandomGeneratorFactory` in Java.
+
+use core::fmt::{self, Display, Formatter};
+
+use Algorithm;
+
+/// Period of a generator, the number of steps after which its state repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+	/// A period of 2^k
+	PowerOfTwo(u32),
+	/// A period of 2^k - 1, typical of linear generators over GF(2), which never visit the all-zero state
+	PowerOfTwoMinusOne(u32)
+}
+
+impl Period {
+	/// Returns the period as an integer, or None if it does not fit in a `u128`.
+	pub fn to_u128(self) -> Option<u128> {
+		match self {
+			Period::PowerOfTwo(exponent) => 1u128.checked_shl(exponent),
+			Period::PowerOfTwoMinusOne(128) => Some(u128::MAX),
+			Period::PowerOfTwoMinusOne(exponent) => 1u128.checked_shl(exponent).map(|period| period - 1)
+		}
+	}
+}
+
+/// Formats the period as `2^48` or `2^128 - 1`.
+impl Display for Period {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Period::PowerOfTwo(exponent) => write!(f, "2^{}", exponent),
+			Period::PowerOfTwoMinusOne(exponent) => write!(f, "2^{} - 1", exponent)
+		}
+	}
+}
+
+/// Description of a generator algorithm, returned by `Algorithm::info`.
+///
+/// The name, group, state size, period and equidistribution match what `RandomGeneratorFactory` reports in Java. The
+/// capabilities describe the implementation in this crate, which may offer less than the JDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AlgorithmInfo {
+	/// Name of the algorithm in `RandomGeneratorFactory.of`
+	pub name: &'static str,
+	/// Group of the algorithm in the JDK, such as `Legacy` or `Xoroshiro`
+	pub group: &'static str,
+	/// Number of bits of state
+	pub state_bits: u32,
+	/// Period of the state
+	pub period: Period,
+	/// Highest number of dimensions in which consecutive 64-bit outputs are equidistributed
+	pub equidistribution: u32,
+	/// Whether the generator can create statistically independent children by splitting
+	pub splittable: bool,
+	/// Whether the generator can jump ahead by a large fixed distance
+	pub jumpable: bool,
+	/// Whether the generator can leap ahead by a distance much larger than a jump
+	pub leapable: bool,
+	/// Whether the generator can jump ahead by an arbitrary distance
+	pub arbitrarily_jumpable: bool
+}
+
+impl Algorithm {
+	/// Every algorithm implemented by the crate.
+	pub const ALL: [Algorithm; 5] = [
+		Algorithm::JavaRandom,
+		Algorithm::Xoroshiro128Plus,
+		Algorithm::Xoroshiro128PlusPlus,
+		Algorithm::Xoroshiro128StarStar,
+		Algorithm::SplitMix64
+	];
+
+	/// Returns the description of the algorithm.
+	pub fn info(self) -> AlgorithmInfo {
+		let xoroshiro = |name| AlgorithmInfo {
+			name,
+			group: "Xoroshiro",
+			state_bits: 128,
+			period: Period::PowerOfTwoMinusOne(128),
+			equidistribution: 1,
+			splittable: false,
+			jumpable: false,
+			leapable: false,
+			arbitrarily_jumpable: false
+		};
+
+		match self {
+			Algorithm::JavaRandom => AlgorithmInfo {
+				name: "Random",
+				group: "Legacy",
+				state_bits: 48,
+				period: Period::PowerOfTwo(48),
+				equidistribution: 0,
+				splittable: false,
+				jumpable: false,
+				leapable: false,
+				arbitrarily_jumpable: false
+			},
+			Algorithm::Xoroshiro128Plus => xoroshiro("Xoroshiro128Plus"),
+			Algorithm::Xoroshiro128PlusPlus => xoroshiro("Xoroshiro128PlusPlus"),
+			Algorithm::Xoroshiro128StarStar => xoroshiro("Xoroshiro128StarStar"),
+			Algorithm::SplitMix64 => AlgorithmInfo {
+				name: "SplittableRandom",
+				group: "Legacy",
+				state_bits: 64,
+				period: Period::PowerOfTwo(64),
+				equidistribution: 1,
+				splittable: true,
+				jumpable: false,
+				leapable: false,
+				arbitrarily_jumpable: false
+			}
+		}
+	}
+
+	/// Returns the algorithm with the given name, like `RandomGeneratorFactory.of`.
+	pub fn from_name(name: &str) -> Option<Self> {
+		Algorithm::ALL.iter().cloned().find(|algorithm| algorithm.info().name == name)
+	}
+}
@@ -0,0 +1,305 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use std::vec::Vec;
+
+use {calls, Checkpoint, Error, Random};
+
+const METHODS: usize = 11;
+
+/// The methods of `Random` that `Instrumented` keeps track of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Method {
+	Next,
+	NextBytes,
+	NextI32,
+	NextI32Bound,
+	NextI64,
+	NextBool,
+	NextF32,
+	NextF64,
+	NextF64Bound,
+	NextF64Range,
+	NextGaussian
+}
+
+impl Method {
+	/// Every method, in declaration order.
+	pub const ALL: [Method; METHODS] = [
+		Method::Next,
+		Method::NextBytes,
+		Method::NextI32,
+		Method::NextI32Bound,
+		Method::NextI64,
+		Method::NextBool,
+		Method::NextF32,
+		Method::NextF64,
+		Method::NextF64Bound,
+		Method::NextF64Range,
+		Method::NextGaussian
+	];
+
+	/// Returns the name of the equivalent `java.util.Random` method, with the arguments of overloads.
+	pub fn java_name(self) -> &'static str {
+		match self {
+			Method::Next => "next(bits)",
+			Method::NextBytes => "nextBytes(bytes)",
+			Method::NextI32 => "nextInt()",
+			Method::NextI32Bound => "nextInt(bound)",
+			Method::NextI64 => "nextLong()",
+			Method::NextBool => "nextBoolean()",
+			Method::NextF32 => "nextFloat()",
+			Method::NextF64 => "nextDouble()",
+			Method::NextF64Bound => "nextDouble(bound)",
+			Method::NextF64Range => "nextDouble(origin, bound)",
+			Method::NextGaussian => "nextGaussian()"
+		}
+	}
+}
+
+impl Display for Method {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(self.java_name())
+	}
+}
+
+/// A single recorded call and the number of steps of the generator it consumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Call {
+	pub method: Method,
+	pub steps: u32
+}
+
+impl Display for Call {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}: {} step{}", self.method, self.steps, if self.steps == 1 { "" } else { "s" })
+	}
+}
+
+/// Wrapper around a `Random` counting the calls made to each method and the steps of the generator they consume,
+/// optionally recording every call in order, or passing it to a hook as soon as it returns.
+///
+/// When a port diverges from a Java reference, comparing the call log against the Java side shows which call
+/// consumed a different number of steps. Unsigned variants of methods are recorded as their signed counterparts.
+///
+/// The hook can forward the calls to a logging or tracing framework, or stop at a given call:
+///
+/// ```
+/// use java_rand::{Instrumented, Random};
+///
+/// let mut calls = Vec::new();
+/// let mut random = Instrumented::with_hook(Random::new(42), |call| calls.push(call.to_string()));
+///
+/// random.next_i32_bound(10);
+/// random.next_gaussian();
+/// drop(random);
+///
+/// // The first pair of the polar method is rejected
+/// assert_eq!(calls, ["nextInt(bound): 1 step", "nextGaussian(): 8 steps"]);
+/// ```
+#[derive(Clone)]
+pub struct Instrumented<H = fn(Call)> {
+	random: Random,
+	counts: [u64; METHODS],
+	steps: u64,
+	log: Option<Vec<Call>>,
+	hook: H
+}
+
+/// The hook of wrappers created without one
+fn ignore(_: Call) {}
+
+impl Instrumented {
+	/// Wraps a generator, counting calls without recording them.
+	pub fn new(random: Random) -> Self {
+		Instrumented::with_hook(random, ignore)
+	}
+
+	/// Wraps a generator, counting calls and recording every call in the log.
+	pub fn with_log(random: Random) -> Self {
+		Instrumented {
+			log: Some(Vec::new()),
+			..Instrumented::new(random)
+		}
+	}
+}
+
+impl<H: FnMut(Call)> Instrumented<H> {
+	/// Wraps a generator, counting calls and passing every call to `hook` after it returns, without recording them.
+	pub fn with_hook(random: Random, hook: H) -> Self {
+		Instrumented {
+			random,
+			counts: [0; METHODS],
+			steps: 0,
+			log: None,
+			hook
+		}
+	}
+
+	/// Returns the wrapped generator.
+	pub fn random(&self) -> &Random {
+		&self.random
+	}
+
+	/// Captures the state of the wrapped generator. The counts and the log are not part of the checkpoint.
+	pub fn save(&self) -> Checkpoint {
+		self.random.save()
+	}
+
+	/// Restores the wrapped generator from `checkpoint`, see `Random::restore`. The counts and the log are kept.
+	pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		self.random.restore(checkpoint)
+	}
+
+	/// Unwraps the generator, discarding the counts, the log and the hook.
+	pub fn into_inner(self) -> Random {
+		self.random
+	}
+
+	/// Returns the number of calls made to `method`.
+	pub fn count(&self, method: Method) -> u64 {
+		self.counts[method as usize]
+	}
+
+	/// Returns the total number of steps of the generator consumed by all calls.
+	pub fn steps(&self) -> u64 {
+		self.steps
+	}
+
+	/// Returns the recorded calls in order, or None if the wrapper was created without a log.
+	pub fn log(&self) -> Option<&[Call]> {
+		self.log.as_ref().map(|log| &log[..])
+	}
+
+	/// Resets the counts and clears the log, if any.
+	pub fn reset(&mut self) {
+		self.counts = [0; METHODS];
+		self.steps = 0;
+
+		if let Some(ref mut log) = self.log {
+			log.clear();
+		}
+	}
+
+	fn record<T>(&mut self, method: Method, (value, steps): (T, u32)) -> T {
+		self.counts[method as usize] += 1;
+		self.steps += steps as u64;
+
+		if let Some(ref mut log) = self.log {
+			log.push(Call { method, steps });
+		}
+
+		(self.hook)(Call { method, steps });
+
+		value
+	}
+
+	/// See `Random::next`.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		let value = self.random.next(bits);
+
+		self.record(Method::Next, (value, 1))
+	}
+
+	/// See `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		self.random.next_bytes(bytes);
+
+		self.record(Method::NextBytes, ((), calls::next_bytes(bytes.len()) as u32))
+	}
+
+	/// See `Random::next_i32`.
+	pub fn next_i32(&mut self) -> i32 {
+		let value = self.random.next_i32();
+
+		self.record(Method::NextI32, (value, calls::NEXT_I32))
+	}
+
+	/// See `Random::next_u32`.
+	pub fn next_u32(&mut self) -> u32 {
+		self.next_i32() as u32
+	}
+
+	/// See `Random::next_i32_bound`.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		let counted = self.random.next_i32_bound_counted(max);
+
+		self.record(Method::NextI32Bound, counted)
+	}
+
+	/// See `Random::next_u32_bound`.
+	pub fn next_u32_bound(&mut self, max: u32) -> u32 {
+		let counted = self.random.next_u32_bound_counted(max);
+
+		self.record(Method::NextI32Bound, counted)
+	}
+
+	/// See `Random::next_i64`.
+	pub fn next_i64(&mut self) -> i64 {
+		let value = self.random.next_i64();
+
+		self.record(Method::NextI64, (value, calls::NEXT_I64))
+	}
+
+	/// See `Random::next_u64`.
+	pub fn next_u64(&mut self) -> u64 {
+		self.next_i64() as u64
+	}
+
+	/// See `Random::next_bool`.
+	pub fn next_bool(&mut self) -> bool {
+		let value = self.random.next_bool();
+
+		self.record(Method::NextBool, (value, calls::NEXT_BOOL))
+	}
+
+	/// See `Random::next_f32`.
+	pub fn next_f32(&mut self) -> f32 {
+		let value = self.random.next_f32();
+
+		self.record(Method::NextF32, (value, calls::NEXT_F32))
+	}
+
+	/// See `Random::next_f64`.
+	pub fn next_f64(&mut self) -> f64 {
+		let value = self.random.next_f64();
+
+		self.record(Method::NextF64, (value, calls::NEXT_F64))
+	}
+
+	/// See `Random::next_f64_bound`.
+	pub fn next_f64_bound(&mut self, bound: f64) -> f64 {
+		let value = self.random.next_f64_bound(bound);
+
+		self.record(Method::NextF64Bound, (value, calls::NEXT_F64))
+	}
+
+	/// See `Random::next_f64_range`.
+	pub fn next_f64_range(&mut self, origin: f64, bound: f64) -> f64 {
+		let value = self.random.next_f64_range(origin, bound);
+
+		self.record(Method::NextF64Range, (value, calls::NEXT_F64))
+	}
+
+	/// See `Random::next_gaussian`.
+	pub fn next_gaussian(&mut self) -> f64 {
+		let counted = self.random.next_gaussian_counted();
+
+		self.record(Method::NextGaussian, counted)
+	}
+}
+
+impl<H> Debug for Instrumented<H> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("Instrumented")
+			.field("random", &self.random)
+			.field("counts", &self.counts)
+			.field("steps", &self.steps)
+			.field("log", &self.log)
+			.finish_non_exhaustive()
+	}
+}
+
+impl From<Random> for Instrumented {
+	fn from(random: Random) -> Self {
+		Instrumented::new(random)
+	}
+}
@@ -0,0 +1,121 @@
+//! Endless iterators over each kind of draw. They borrow the generator mutably and make exactly the same calls, in
+//! the same order, as calling the corresponding method in a loop.
+
+use core::iter::repeat_with;
+
+use {error, Error, Random};
+
+impl Random {
+	/// Returns an endless iterator over `next_i32` values.
+	pub fn iter_i32(&mut self) -> impl Iterator<Item = i32> + '_ {
+		repeat_with(move || self.next_i32())
+	}
+
+	/// Returns an endless iterator over `next_u32` values.
+	pub fn iter_u32(&mut self) -> impl Iterator<Item = u32> + '_ {
+		repeat_with(move || self.next_u32())
+	}
+
+	/// Returns an endless iterator over `next_i32_bound(max)` values.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	pub fn iter_i32_bound(&mut self, max: i32) -> impl Iterator<Item = i32> + '_ {
+		match self.try_iter_i32_bound(max) {
+			Ok(iter) => iter,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `iter_i32_bound`, but returns `Error::InvalidBound` instead of panicking.
+	pub fn try_iter_i32_bound(&mut self, max: i32) -> Result<impl Iterator<Item = i32> + '_, Error> {
+		error::check_i32_bound(max)?;
+
+		Ok(repeat_with(move || self.next_i32_bound(max)))
+	}
+
+	/// Returns an endless iterator over `next_u32_bound(max)` values.
+	///
+	/// # Panics
+	/// If `max` reinterpreted as a signed 32-bit integer is less than 1, the function panics.
+	pub fn iter_u32_bound(&mut self, max: u32) -> impl Iterator<Item = u32> + '_ {
+		match self.try_iter_u32_bound(max) {
+			Ok(iter) => iter,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `iter_u32_bound`, but returns `Error::InvalidBound` instead of panicking.
+	pub fn try_iter_u32_bound(&mut self, max: u32) -> Result<impl Iterator<Item = u32> + '_, Error> {
+		error::check_i32_bound(max as i32)?;
+
+		Ok(repeat_with(move || self.next_u32_bound(max)))
+	}
+
+	/// Returns an endless iterator over `next_i64` values.
+	pub fn iter_i64(&mut self) -> impl Iterator<Item = i64> + '_ {
+		repeat_with(move || self.next_i64())
+	}
+
+	/// Returns an endless iterator over `next_u64` values.
+	pub fn iter_u64(&mut self) -> impl Iterator<Item = u64> + '_ {
+		repeat_with(move || self.next_u64())
+	}
+
+	/// Returns an endless iterator over `next_bool` values.
+	pub fn iter_bool(&mut self) -> impl Iterator<Item = bool> + '_ {
+		repeat_with(move || self.next_bool())
+	}
+
+	/// Returns an endless iterator over `next_f32` values.
+	pub fn iter_f32(&mut self) -> impl Iterator<Item = f32> + '_ {
+		repeat_with(move || self.next_f32())
+	}
+
+	/// Returns an endless iterator over `next_f64` values.
+	pub fn iter_f64(&mut self) -> impl Iterator<Item = f64> + '_ {
+		repeat_with(move || self.next_f64())
+	}
+
+	/// Returns an endless iterator over `next_f64_bound(bound)` values.
+	///
+	/// # Panics
+	/// If `bound` is not positive and finite, the function panics.
+	pub fn iter_f64_bound(&mut self, bound: f64) -> impl Iterator<Item = f64> + '_ {
+		match self.try_iter_f64_bound(bound) {
+			Ok(iter) => iter,
+			Err(_) => panic!("Bound must be positive and finite")
+		}
+	}
+
+	/// Like `iter_f64_bound`, but returns `Error::InvalidBound` instead of panicking.
+	pub fn try_iter_f64_bound(&mut self, bound: f64) -> Result<impl Iterator<Item = f64> + '_, Error> {
+		error::check_f64_bound(bound)?;
+
+		Ok(repeat_with(move || self.next_f64_bound(bound)))
+	}
+
+	/// Returns an endless iterator over `next_f64_range(origin, bound)` values, like `doubles(origin, bound)` in Java.
+	///
+	/// # Panics
+	/// If either endpoint is not finite, or if `origin` is not less than `bound`, the function panics.
+	pub fn iter_f64_range(&mut self, origin: f64, bound: f64) -> impl Iterator<Item = f64> + '_ {
+		match self.try_iter_f64_range(origin, bound) {
+			Ok(iter) => iter,
+			Err(_) => panic!("Origin must be less than bound, and both must be finite")
+		}
+	}
+
+	/// Like `iter_f64_range`, but returns `Error::InvalidRange` instead of panicking.
+	pub fn try_iter_f64_range(&mut self, origin: f64, bound: f64) -> Result<impl Iterator<Item = f64> + '_, Error> {
+		error::check_f64_range(origin, bound)?;
+
+		Ok(repeat_with(move || self.next_f64_range(origin, bound)))
+	}
+
+	/// Returns an endless iterator over `next_gaussian` values. A gaussian left pending by an earlier call is returned
+	/// first, and one left pending by the iterator remains available afterwards.
+	pub fn iter_gaussian(&mut self) -> impl Iterator<Item = f64> + '_ {
+		repeat_with(move || self.next_gaussian())
+	}
+}
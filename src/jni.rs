@@ -0,0 +1,399 @@
+//! JNI exports, enabled by the `jni-export` feature, for calling the generator and the cracker from Java. The
+//! matching classes are `NativeRandom` and `NativeCracker` in `jni/io/github/coderbot16/javarand`, and the shared
+//! library is built with `cargo rustc --release --features jni-export --crate-type cdylib`.
+//!
+//! Apart from the `fill` methods, every native method takes and returns primitives only and never calls back into
+//! the JVM. The `fill` methods pin a caller-provided array with `GetPrimitiveArrayCritical` and fill it in place
+//! with the batched kernels of `Random::fill_i32` and `Random::fill_gaussian`, which pays off for long arrays.
+//! Objects are handed out as `long` handles created by `create` and released by `free`. Every other method requires
+//! a valid handle that is not used from multiple threads at once, and failures are reported through return values
+//! as in the C API rather than by throwing exceptions.
+
+use std::os::raw::c_void;
+use std::vec::Vec;
+use std::{mem, slice};
+
+use crack::Cracker;
+use {Error, JavaLcg, Random};
+
+/// The `JNIEnv` pointer, which is never dereferenced
+pub type JniEnv = *mut c_void;
+
+/// The `jclass` of the class declaring a static native method, which is never dereferenced
+pub type JClass = *mut c_void;
+
+/// `jboolean`, an unsigned byte that is 1 for true and 0 for false
+pub type JBoolean = u8;
+
+/// A `jarray`, such as an `int[]` or a `double[]`
+pub type JArray = *mut c_void;
+
+/// Indices of the functions in the JNI function table that `JNIEnv` points to, fixed by the JNI specification
+const GET_ARRAY_LENGTH: usize = 171;
+const GET_PRIMITIVE_ARRAY_CRITICAL: usize = 222;
+const RELEASE_PRIMITIVE_ARRAY_CRITICAL: usize = 223;
+
+type GetArrayLength = unsafe extern "system" fn(JniEnv, JArray) -> i32;
+type GetPrimitiveArrayCritical = unsafe extern "system" fn(JniEnv, JArray, *mut JBoolean) -> *mut c_void;
+type ReleasePrimitiveArrayCritical = unsafe extern "system" fn(JniEnv, JArray, *mut c_void, i32);
+
+/// Calls `fill` with the elements of a primitive array, pinned with `GetPrimitiveArrayCritical` and written back
+/// with `ReleasePrimitiveArrayCritical`. If the array cannot be pinned, `fill` is not called and the
+/// `OutOfMemoryError` raised by the JVM is thrown once the native method returns.
+///
+/// # Safety
+/// `env` must be the `JNIEnv` of the current thread, and `array` a non-null array with elements of type `T`.
+unsafe fn with_array_elements<T, F: FnOnce(&mut [T])>(env: JniEnv, array: JArray, fill: F) {
+	let functions = *(env as *const *const *const c_void);
+
+	let get_length = mem::transmute::<*const c_void, GetArrayLength>(*functions.add(GET_ARRAY_LENGTH));
+	let get_elements = mem::transmute::<*const c_void, GetPrimitiveArrayCritical>(
+		*functions.add(GET_PRIMITIVE_ARRAY_CRITICAL)
+	);
+	let release_elements = mem::transmute::<*const c_void, ReleasePrimitiveArrayCritical>(
+		*functions.add(RELEASE_PRIMITIVE_ARRAY_CRITICAL)
+	);
+
+	let length = get_length(env, array) as usize;
+	let elements = get_elements(env, array, std::ptr::null_mut());
+
+	if elements.is_null() {
+		return;
+	}
+
+	if length != 0 {
+		fill(slice::from_raw_parts_mut(elements as *mut T, length));
+	}
+
+	// Mode 0 copies the elements back if the JVM handed out a copy
+	release_elements(env, array, elements, 0);
+}
+
+/// A cracker and the candidates found by its last `solve`
+pub struct CrackerHandle {
+	cracker: Cracker,
+	candidates: Vec<Random>
+}
+
+/// `NativeRandom.create(long seed)`: creates a generator, equivalent to `new Random(seed)`.
+#[no_mangle]
+pub extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_create(
+	_env: JniEnv, _class: JClass, seed: i64
+) -> i64 {
+	Box::into_raw(Box::new(Random::new(seed as u64))) as i64
+}
+
+/// `NativeRandom.free(long handle)`: releases a generator. Passing 0 is a no-op.
+///
+/// # Safety
+/// `handle` must be 0 or a handle from `create` that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_free(
+	_env: JniEnv, _class: JClass, handle: i64
+) {
+	if handle != 0 {
+		drop(Box::from_raw(handle as *mut Random));
+	}
+}
+
+/// `NativeRandom.setSeed(long handle, long seed)`: equivalent to `setSeed(seed)`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_setSeed(
+	_env: JniEnv, _class: JClass, handle: i64, seed: i64
+) {
+	(*(handle as *mut Random)).set_seed(seed as u64)
+}
+
+/// `NativeRandom.advance(long handle, long steps)`: steps the generator `steps` times in logarithmic time, or
+/// backwards if `steps` is negative. A pending gaussian is discarded.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_advance(
+	_env: JniEnv, _class: JClass, handle: i64, steps: i64
+) {
+	let random = &mut *(handle as *mut Random);
+	let mut lcg = JavaLcg::new(random.state);
+
+	if steps >= 0 {
+		lcg.jump(steps as u64);
+	} else {
+		lcg.jump_back(steps.unsigned_abs());
+	}
+
+	*random = Random { state: lcg.state(), next_gaussian: None };
+}
+
+/// `NativeRandom.next(long handle, int bits)`: equivalent to `next(bits)`. Returns 0 without stepping the
+/// generator if `bits` is not between 0 and 48.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_next(
+	_env: JniEnv, _class: JClass, handle: i64, bits: i32
+) -> i32 {
+	if !(0..=48).contains(&bits) {
+		return 0;
+	}
+
+	(*(handle as *mut Random)).next(bits as u8)
+}
+
+/// `NativeRandom.nextInt(long handle)`: equivalent to `nextInt()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextInt(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> i32 {
+	(*(handle as *mut Random)).next_i32()
+}
+
+/// `NativeRandom.nextIntBound(long handle, int bound)`: equivalent to `nextInt(bound)`. Returns -1 without
+/// stepping the generator if `bound` is not positive.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextIntBound(
+	_env: JniEnv, _class: JClass, handle: i64, bound: i32
+) -> i32 {
+	if bound <= 0 {
+		return -1;
+	}
+
+	(*(handle as *mut Random)).next_i32_bound(bound)
+}
+
+/// `NativeRandom.nextLong(long handle)`: equivalent to `nextLong()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextLong(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> i64 {
+	(*(handle as *mut Random)).next_i64()
+}
+
+/// `NativeRandom.nextBoolean(long handle)`: equivalent to `nextBoolean()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextBoolean(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> JBoolean {
+	(*(handle as *mut Random)).next_bool() as JBoolean
+}
+
+/// `NativeRandom.nextFloat(long handle)`: equivalent to `nextFloat()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextFloat(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> f32 {
+	(*(handle as *mut Random)).next_f32()
+}
+
+/// `NativeRandom.nextDouble(long handle)`: equivalent to `nextDouble()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextDouble(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> f64 {
+	(*(handle as *mut Random)).next_f64()
+}
+
+/// `NativeRandom.nextGaussian(long handle)`: equivalent to `nextGaussian()`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_nextGaussian(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> f64 {
+	(*(handle as *mut Random)).next_gaussian()
+}
+
+/// `NativeRandom.fillInt(long handle, int[] values)`: fills `values` with the results of calling `nextInt()` for
+/// each element in order.
+///
+/// # Safety
+/// `env` must be the `JNIEnv` of the current thread, `handle` a valid handle, and `values` a non-null `int[]`.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_fillInt(
+	env: JniEnv, _class: JClass, handle: i64, values: JArray
+) {
+	let random = &mut *(handle as *mut Random);
+
+	with_array_elements(env, values, |values| random.fill_i32(values));
+}
+
+/// `NativeRandom.fillGaussian(long handle, double[] values)`: fills `values` with the results of calling
+/// `nextGaussian()` for each element in order, see `Random::fill_gaussian`.
+///
+/// # Safety
+/// `env` must be the `JNIEnv` of the current thread, `handle` a valid handle, and `values` a non-null `double[]`.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeRandom_fillGaussian(
+	env: JniEnv, _class: JClass, handle: i64, values: JArray
+) {
+	let random = &mut *(handle as *mut Random);
+
+	with_array_elements(env, values, |values| random.fill_gaussian(values));
+}
+
+/// `NativeCracker.create()`: creates a cracker without observations.
+#[no_mangle]
+pub extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_create(_env: JniEnv, _class: JClass) -> i64 {
+	Box::into_raw(Box::new(CrackerHandle { cracker: Cracker::new(), candidates: Vec::new() })) as i64
+}
+
+/// `NativeCracker.free(long handle)`: releases a cracker. Passing 0 is a no-op.
+///
+/// # Safety
+/// `handle` must be 0 or a handle from `create` that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_free(
+	_env: JniEnv, _class: JClass, handle: i64
+) {
+	if handle != 0 {
+		drop(Box::from_raw(handle as *mut CrackerHandle));
+	}
+}
+
+/// Returns the cracker behind a handle.
+unsafe fn cracker<'a>(handle: i64) -> &'a mut Cracker {
+	&mut (*(handle as *mut CrackerHandle)).cracker
+}
+
+/// `NativeCracker.nextInt(long handle, int value)`: records that `nextInt()` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextInt(
+	_env: JniEnv, _class: JClass, handle: i64, value: i32
+) {
+	cracker(handle).next_i32(value);
+}
+
+/// `NativeCracker.nextIntBound(long handle, int bound, int value)`: records that `nextInt(bound)` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextIntBound(
+	_env: JniEnv, _class: JClass, handle: i64, bound: i32, value: i32
+) {
+	cracker(handle).next_i32_bound(bound, value);
+}
+
+/// `NativeCracker.nextLong(long handle, long value)`: records that `nextLong()` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextLong(
+	_env: JniEnv, _class: JClass, handle: i64, value: i64
+) {
+	cracker(handle).next_i64(value);
+}
+
+/// `NativeCracker.nextBoolean(long handle, boolean value)`: records that `nextBoolean()` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextBoolean(
+	_env: JniEnv, _class: JClass, handle: i64, value: JBoolean
+) {
+	cracker(handle).next_bool(value != 0);
+}
+
+/// `NativeCracker.nextFloat(long handle, float value)`: records that `nextFloat()` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextFloat(
+	_env: JniEnv, _class: JClass, handle: i64, value: f32
+) {
+	cracker(handle).next_f32(value);
+}
+
+/// `NativeCracker.nextDouble(long handle, double value)`: records that `nextDouble()` returned `value`.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_nextDouble(
+	_env: JniEnv, _class: JClass, handle: i64, value: f64
+) {
+	cracker(handle).next_f64(value);
+}
+
+/// `NativeCracker.skip(long handle, long steps)`: records that the generator was stepped `steps` times without
+/// observing the results. Negative counts are ignored.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_skip(
+	_env: JniEnv, _class: JClass, handle: i64, steps: i64
+) {
+	if steps > 0 {
+		cracker(handle).skip(steps as u64);
+	}
+}
+
+/// `NativeCracker.solve(long handle)`: recovers the generators producing the observations, see `Cracker::solve`,
+/// and returns how many there are. Returns -1 if a bound was not positive, and -2 if there are too many candidates.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_solve(
+	_env: JniEnv, _class: JClass, handle: i64
+) -> i32 {
+	let handle = &mut *(handle as *mut CrackerHandle);
+
+	handle.candidates.clear();
+
+	match handle.cracker.solve() {
+		Ok(candidates) => {
+			handle.candidates = candidates;
+			handle.candidates.len() as i32
+		},
+		Err(Error::TooManyCandidates) => -2,
+		Err(_) => -1
+	}
+}
+
+/// `NativeCracker.candidateSeed(long handle, int index)`: returns the seed, reduced to 48 bits, that makes
+/// `new Random(seed)` start in the candidate at `index` of the last `solve`. Returns -1 if `index` is out of range.
+///
+/// # Safety
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "system" fn Java_io_github_coderbot16_javarand_NativeCracker_candidateSeed(
+	_env: JniEnv, _class: JClass, handle: i64, index: i32
+) -> i64 {
+	let handle = &*(handle as *mut CrackerHandle);
+
+	match handle.candidates.get(index as usize) {
+		Some(random) if index >= 0 => (random.state ^ 0x5DEECE66D) as i64,
+		_ => -1
+	}
+}
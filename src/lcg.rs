@@ -0,0 +1,291 @@
+//! Linear congruential generators with arbitrary constants, `state = (state * A + C) mod 2^BITS`, for exploring
+//! generators related to `java.util.Random`. The Java generator is the instantiation `JavaLcg`.
+//!
+//! The draws use the same algorithms as `Random`, built on the top bits of the state, so they need a state of at least
+//! 32 bits; this is checked when the program is compiled.
+
+use core::hash::{Hash, Hasher};
+
+use {Algorithm, Checkpoint, Error};
+
+/// Returns the multiplier and increment of the affine map that applies `x * multiplier + increment` a total of
+/// `steps` times, modulo `mask + 1`.
+pub const fn affine_power(multiplier: u64, increment: u64, mask: u64, steps: u64) -> (u64, u64) {
+	// The map for the current bit of `steps`, and the accumulated result
+	let mut multiplier = multiplier & mask;
+	let mut increment = increment & mask;
+	let mut result: (u64, u64) = (1 & mask, 0);
+	let mut steps = steps;
+
+	while steps != 0 {
+		if steps & 1 != 0 {
+			result = (
+				result.0.wrapping_mul(multiplier) & mask,
+				result.1.wrapping_mul(multiplier).wrapping_add(increment) & mask
+			);
+		}
+
+		increment = increment.wrapping_mul(multiplier).wrapping_add(increment) & mask;
+		multiplier = multiplier.wrapping_mul(multiplier) & mask;
+		steps >>= 1;
+	}
+
+	result
+}
+
+/// The LCG of `java.util.Random`. Unlike `Random::new`, `JavaLcg::new` takes the raw state, so the equivalent of
+/// `Random::new(seed)` is `JavaLcg::new(seed ^ 0x5DEECE66D)`.
+pub type JavaLcg = Lcg<0x5DEECE66D, 11, 48>;
+
+/// A linear congruential generator with multiplier `A`, increment `C` and a state of `BITS` bits.
+///
+/// Like `Random`, it keeps the second gaussian of each pair drawn by `next_gaussian` pending.
+#[derive(Debug, Clone, Copy)]
+pub struct Lcg<const A: u64, const C: u64, const BITS: u32> {
+	state: u64,
+	next_gaussian: Option<f64>
+}
+
+/// Generators are equal if their states match, and so do their pending gaussians, compared bit for bit.
+impl<const A: u64, const C: u64, const BITS: u32> PartialEq for Lcg<A, C, BITS> {
+	fn eq(&self, other: &Self) -> bool {
+		self.state == other.state && self.next_gaussian.map(f64::to_bits) == other.next_gaussian.map(f64::to_bits)
+	}
+}
+
+impl<const A: u64, const C: u64, const BITS: u32> Eq for Lcg<A, C, BITS> {}
+
+impl<const A: u64, const C: u64, const BITS: u32> Hash for Lcg<A, C, BITS> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.state.hash(state);
+		self.next_gaussian.map(f64::to_bits).hash(state);
+	}
+}
+
+impl<const A: u64, const C: u64, const BITS: u32> Lcg<A, C, BITS> {
+	/// Mask of the bits making up the state
+	pub const MASK: u64 = if BITS >= 64 { !0 } else { (1 << BITS) - 1 };
+
+	/// Creates a generator with the raw state `state`. Bits above the low `BITS` are ignored.
+	#[inline]
+	pub const fn new(state: u64) -> Self {
+		const { assert!(BITS >= 1 && BITS <= 64, "State must have between 1 and 64 bits") };
+
+		Lcg { state: state & Self::MASK, next_gaussian: None }
+	}
+
+	/// Returns the raw state.
+	#[inline]
+	pub const fn state(&self) -> u64 {
+		self.state
+	}
+
+	/// Steps the generator, returning the new state.
+	#[inline]
+	pub const fn next_state(&mut self) -> u64 {
+		self.state = self.state.wrapping_mul(A).wrapping_add(C) & Self::MASK;
+		self.state
+	}
+
+	/// Steps the generator, returning the top `bits` bits of the new state, like `next` in Java.
+	///
+	/// # Panics
+	/// If more bits are requested than the state has, the function panics.
+	#[inline]
+	pub const fn next_bits(&mut self, bits: u32) -> u64 {
+		if bits > BITS {
+			panic!("Too many bits!")
+		}
+
+		let state = self.next_state();
+
+		if bits == 0 { 0 } else { state >> (BITS - bits) }
+	}
+
+	/// Steps the generator with the 32 bit check of the Java draws done at compile time.
+	#[inline]
+	const fn next(&mut self, bits: u32) -> i32 {
+		const { assert!(BITS >= 32, "Draws need a state of at least 32 bits") };
+
+		(self.next_state() >> (BITS - bits)) as i32
+	}
+
+	/// Returns a uniformly distributed signed 32-bit integer, see `Random::next_i32`.
+	#[inline]
+	pub const fn next_i32(&mut self) -> i32 {
+		self.next(32)
+	}
+
+	/// Returns a random number in the range [0, max), see `Random::next_i32_bound`.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	#[inline]
+	pub const fn next_i32_bound(&mut self, max: i32) -> i32 {
+		match self.try_next_i32_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_i32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	#[inline]
+	pub const fn try_next_i32_bound(&mut self, max: i32) -> Result<i32, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		Ok(random_next_i32_bound!(self, max).0)
+	}
+
+	/// Returns a uniformly distributed unsigned 32-bit integer, see `Random::next_u32`.
+	#[inline]
+	pub const fn next_u32(&mut self) -> u32 {
+		self.next(32) as u32
+	}
+
+	/// Returns a random number in the range [0, max), see `Random::next_u32_bound`.
+	///
+	/// # Panics
+	/// If `max` reinterpreted as a signed 32-bit integer is less than 1, the function panics.
+	#[inline]
+	pub const fn next_u32_bound(&mut self, max: u32) -> u32 {
+		self.next_i32_bound(max as i32) as u32
+	}
+
+	/// Like `next_u32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	#[inline]
+	pub const fn try_next_u32_bound(&mut self, max: u32) -> Result<u32, Error> {
+		match self.try_next_i32_bound(max as i32) {
+			Ok(value) => Ok(value as u32),
+			Err(error) => Err(error)
+		}
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer, see `Random::next_i64`.
+	#[inline]
+	pub const fn next_i64(&mut self) -> i64 {
+		random_next_i64!(self)
+	}
+
+	/// Returns a uniformly distributed unsigned 64-bit integer, see `Random::next_u64`.
+	#[inline]
+	pub const fn next_u64(&mut self) -> u64 {
+		self.next_i64() as u64
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false, see `Random::next_bool`.
+	#[inline]
+	pub const fn next_bool(&mut self) -> bool {
+		self.next(1) != 0
+	}
+
+	/// Returns a f32 uniformly distributed between 0.0 and 1.0, see `Random::next_f32`.
+	#[inline]
+	pub const fn next_f32(&mut self) -> f32 {
+		random_next_f32!(self)
+	}
+
+	/// Returns a f64 uniformly distributed between 0.0 and 1.0, see `Random::next_f64`.
+	#[inline]
+	pub const fn next_f64(&mut self) -> f64 {
+		random_next_f64!(self)
+	}
+
+	/// Fills the byte array with random bytes, see `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		for chunk in bytes.chunks_mut(4) {
+			let mut value = self.next_i32();
+
+			for byte in chunk {
+				*byte = value as u8;
+				value >>= 8;
+			}
+		}
+	}
+
+	/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0, see
+	/// `Random::next_gaussian`. The pending gaussian is kept across `jump` and `jump_back`, which only move the state.
+	pub fn next_gaussian(&mut self) -> f64 {
+		if let Some(next) = self.next_gaussian.take() {
+			return next;
+		}
+
+		let ((v0, v1), _) = random_next_gaussian_pair!(self);
+		self.next_gaussian = Some(v1);
+
+		v0
+	}
+
+	/// Advances the generator by `steps` steps in logarithmic time.
+	#[inline]
+	pub const fn jump(&mut self, steps: u64) {
+		let (multiplier, increment) = affine_power(A, C, Self::MASK, steps);
+
+		self.state = self.state.wrapping_mul(multiplier).wrapping_add(increment) & Self::MASK;
+	}
+
+	/// Steps the generator backwards, undoing one call to `next_state`, and returns the previous state.
+	#[inline]
+	pub const fn previous_state(&mut self) -> u64 {
+		self.jump_back(1);
+		self.state
+	}
+
+	/// Moves the generator back by `steps` steps in logarithmic time, undoing `jump(steps)`. This requires an odd
+	/// multiplier, which is checked when the program is compiled.
+	#[inline]
+	pub const fn jump_back(&mut self, steps: u64) {
+		const { assert!(A % 2 == 1, "Only generators with an odd multiplier can be reversed") };
+
+		// Newton's iteration doubles the number of correct low bits of the inverse each time, starting from 3
+		let mut inverse = A;
+		let mut iteration = 0;
+
+		while iteration < 5 {
+			inverse = inverse.wrapping_mul(2u64.wrapping_sub(A.wrapping_mul(inverse)));
+			iteration += 1;
+		}
+
+		// The inverse of x -> x * A + C is x -> x * A^-1 - C * A^-1
+		let increment = inverse.wrapping_mul(C).wrapping_neg();
+		let (multiplier, increment) = affine_power(inverse, increment, Self::MASK, steps);
+
+		self.state = self.state.wrapping_mul(multiplier).wrapping_add(increment) & Self::MASK;
+	}
+}
+
+impl JavaLcg {
+	/// Captures the state of the generator. The checkpoint is that of the `Random` in the same state, so either type
+	/// can restore it. Other instantiations of `Lcg` have no checkpoint, as the binary form has no room for their
+	/// constants.
+	pub fn save(&self) -> Checkpoint {
+		Checkpoint::new(Algorithm::JavaRandom, [self.state, 0], self.next_gaussian)
+			.expect("State fits in 48 bits and gaussians are finite")
+	}
+
+	/// Returns the generator to the state captured in `checkpoint`, see `Random::restore`.
+	pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		if checkpoint.algorithm() != Algorithm::JavaRandom {
+			return Err(Error::AlgorithmMismatch);
+		}
+
+		self.state = checkpoint.state()[0];
+		self.next_gaussian = checkpoint.next_gaussian();
+
+		Ok(())
+	}
+
+	/// Encodes the state in the versioned binary form, the same as that of the equivalent `Random`.
+	pub fn to_bytes(&self) -> [u8; 27] {
+		self.save().to_versioned_bytes()
+	}
+
+	/// Decodes a generator encoded by `to_bytes` or `Random::to_bytes`, see `Random::from_bytes`.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		let mut lcg = JavaLcg::new(0);
+		lcg.restore(&Checkpoint::from_versioned_bytes(bytes)?)?;
+
+		Ok(lcg)
+	}
+}
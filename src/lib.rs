@@ -1,11 +1,160 @@
 //! Implementation of the Java Random Number generator.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled. This removes the `std::io` adapters and Java
+//! serialization interop.
+//!
+//! The constructor and the integer and float methods are `const fn`, so fixed sequences and small lookup tables
+//! can be computed at compile time:
+//!
+//! ```
+//! use java_rand::Random;
+//!
+//! const ROLLS: [i32; 4] = {
+//!     let mut random = Random::new(42);
+//!     let mut rolls = [0; 4];
+//!     let mut index = 0;
+//!
+//!     while index < rolls.len() {
+//!         rolls[index] = random.next_i32_bound(6) + 1;
+//!         index += 1;
+//!     }
+//!
+//!     rolls
+//! };
+//!
+//! assert_eq!(ROLLS, [3, 4, 1, 3]);
+//! ```
+//!
+//! # Determinism
+//!
+//! All integer outputs are computed with wrapping integer arithmetic and are identical on every platform.
+//! `next_f32` and `next_f64` are exact conversions of integer outputs, and `next_gaussian` only uses correctly rounded
+//! IEEE 754 addition, multiplication and division on top of them, together with the ports in `strictmath`. Rust never
+//! fuses these operations, so the results match Java on x86_64, AArch64, wasm and any other target with IEEE 754
+//! binary64 arithmetic.
+//!
+//! The exception is 32-bit x86 without SSE2, where the x87 FPU computes with excess precision. The `deterministic`
+//! feature refuses to build on such targets, and additionally assembles the results of `next_f32` and `next_f64`
+//! from their bit patterns so that they never touch the FPU.
+//!
+//! # Errors
+//!
+//! Methods that panic on invalid arguments, such as `next_i32_bound` with a bound of 0, have `try_` variants that
+//! return an [`Error`] instead and leave the generator untouched. The panicking methods are thin wrappers around them.
+
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(all(feature = "deterministic", target_arch = "x86", not(target_feature = "sse2")))]
+compile_error!("the deterministic feature requires SSE2: x87 arithmetic is not bit-identical to Java");
+
+#[cfg(any(feature = "std", test))]
+extern crate core;
+
+// Declared first, so that the macros can be used by the modules after it
+#[macro_use]
+mod macros;
 
 #[cfg(test)]
 mod test;
-#[cfg(test)]
-mod test_data;
 
-use std::num::Wrapping;
+mod batch;
+#[cfg(feature = "std")]
+mod bigint;
+mod bits;
+mod bools;
+mod builder;
+mod bytes;
+pub mod calls;
+mod checkpoint;
+pub mod compat;
+#[cfg(feature = "std")]
+pub mod crack;
+mod derive;
+mod draw;
+#[cfg(feature = "std")]
+mod document;
+mod error;
+pub mod ext;
+#[cfg(feature = "std")]
+pub mod exchange;
+mod float;
+mod info;
+#[cfg(feature = "std")]
+mod instrumented;
+mod iter;
+mod lcg;
+pub mod mix;
+#[cfg(feature = "std")]
+mod recorder;
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(feature = "std")]
+mod serialization;
+#[cfg(feature = "std")]
+mod set;
+mod skip;
+mod speculative;
+mod splitmix;
+mod state;
+pub mod stats;
+pub mod strictmath;
+mod subclass;
+pub mod sweep;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+#[cfg(all(test, not(feature = "test-vectors")))]
+#[cfg_attr(any(not(feature = "std"), not(feature = "minecraft")), allow(dead_code))]
+mod test_vectors;
+mod text;
+mod trace;
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "std")]
+pub mod verify;
+mod xoroshiro;
+pub mod ziggurat;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "jni-export")]
+pub mod jni;
+#[cfg(feature = "minecraft")]
+pub mod minecraft;
+
+pub use bits::BitReader;
+pub use bools::BufferedBools;
+pub use builder::RandomBuilder;
+pub use bytes::Bytes;
+#[cfg(feature = "std")]
+pub use bytes::RandomReader;
+pub use checkpoint::{Algorithm, Checkpoint};
+#[cfg(feature = "std")]
+pub use document::{load_checkpoint, save_checkpoint, CheckpointFile};
+pub use draw::{Bounded, Gaussian, JavaDraw};
+pub use error::Error;
+pub use info::{AlgorithmInfo, Period};
+#[cfg(feature = "std")]
+pub use instrumented::{Call, Instrumented, Method};
+pub use lcg::{JavaLcg, Lcg};
+#[cfg(feature = "std")]
+pub use recorder::{Histogram, StatsRecorder, Summary};
+#[cfg(feature = "std")]
+pub use set::RandomSet;
+pub use speculative::SpeculativeGuard;
+pub use splitmix::{SplitMix64, Splits};
+pub use state::RandomState;
+pub use subclass::{NextBits, Subclass};
+pub use text::ParseRandomError;
+pub use trace::{Step, Tracer};
+#[cfg(feature = "std")]
+pub use thread::{
+	next_bool, next_bytes, next_f32, next_f64, next_gaussian, next_i32, next_i32_bound, next_i64, set_seed, thread_rng,
+	ThreadRandom
+};
+pub use xoroshiro::{Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar};
+
+use core::hash::{Hash, Hasher};
+use core::num::Wrapping;
 
 /// Modulus
 pub const M: Wrapping<i64> = Wrapping((1 << 48) - 1);
@@ -16,25 +165,46 @@ pub const A: Wrapping<i64> = Wrapping(0x5DEECE66D);
 /// Increment
 pub const C: Wrapping<i64> = Wrapping(11);
 
-const F32_DIV: f32 = (1u32 << 24) as f32;
-const F64_DIV: f64 = (1u64 << 53) as f64;
+// The same constants in the representation used internally
+const MASK: u64 = M.0 as u64;
+const MULTIPLIER: u64 = A.0 as u64;
+const INCREMENT: u64 = C.0 as u64;
 
 #[derive(Debug, Clone)]
 pub struct Random {
-	state: Wrapping<i64>,
+	state: u64,
 	next_gaussian: Option<f64>
 }
 
+/// Generators are equal if they will produce identical outputs: their states match, and so do their pending gaussians,
+/// compared bit for bit.
+impl PartialEq for Random {
+	fn eq(&self, other: &Random) -> bool {
+		self.state == other.state && self.next_gaussian.map(f64::to_bits) == other.next_gaussian.map(f64::to_bits)
+	}
+}
+
+impl Eq for Random {}
+
+impl Hash for Random {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.state.hash(state);
+		self.next_gaussian.map(f64::to_bits).hash(state);
+	}
+}
+
 impl Random {
-	pub fn new(seed: u64) -> Self {
+	#[inline]
+	pub const fn new(seed: u64) -> Self {
 		Random {
-			state: Wrapping((seed as i64) ^ A.0) & M,
+			state: (seed ^ MULTIPLIER) & MASK,
 			next_gaussian: None
 		}
 	}
 
 	/// Sets the seed to `seed`. This is equivalent to `Random::new`
-	pub fn set_seed(&mut self, seed: u64) {
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u64) {
 		*self = Random::new(seed);
 	}
 
@@ -42,35 +212,64 @@ impl Random {
 	///
 	/// # Panics
 	/// If the amount of requested bits is over 48, this function panics. Use next_i64/next_u64 instead, or multiple calls.
-	pub fn next(&mut self, bits: u8) -> i32 {
+	#[inline]
+	pub const fn next(&mut self, bits: u8) -> i32 {
+		match self.try_next(bits) {
+			Ok(value) => value,
+			Err(_) => panic!("Too many bits!")
+		}
+	}
+
+	/// Like `next`, but returns `Error::TooManyBits` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next(&mut self, bits: u8) -> Result<i32, Error> {
 		if bits > 48 {
-			panic!("Too many bits!")
+			return Err(Error::TooManyBits);
 		}
 
-		self.state = (self.state * A + C) & M;
+		self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT) & MASK;
 
-		((self.state.0 as u64) >> (48 - bits)) as i32
+		Ok((self.state >> (48 - bits)) as i32)
 	}
 
 	/// Fills the byte array with random bytes.
+	#[inline]
 	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
-		for chunk in bytes.chunks_mut(4) {
-			let mut block = self.next_u32();
+		// Large enough for the vector kernels of fill_u32, which need at least 16 values, to do most of the work
+		let mut blocks = [0; 64];
 
-			for item in chunk {
-				*item = (block & 0xFF) as u8;
-				block >>= 8;
+		for batch in bytes.chunks_mut(4 * blocks.len()) {
+			let blocks = &mut blocks[..batch.len().div_ceil(4)];
+
+			self.fill_u32(blocks);
+
+			// Each block gives 4 bytes, starting with the lowest, and the last one may be cut short
+			for (chunk, block) in batch.chunks_mut(4).zip(blocks.iter()) {
+				chunk.copy_from_slice(&block.to_le_bytes()[..chunk.len()]);
 			}
 		}
 	}
 
+	/// Returns an endless iterator over random bytes, in the same order as `next_bytes`.
+	pub fn bytes(&mut self) -> Bytes<'_> {
+		Bytes::new(self)
+	}
+
+	/// Returns a reader yielding random bytes endlessly, in the same order as `next_bytes`.
+	#[cfg(feature = "std")]
+	pub fn reader(&mut self) -> RandomReader<'_> {
+		RandomReader::new(self)
+	}
+
 	/// Returns a uniformly distributed signed 32-bit integer.
-	pub fn next_i32(&mut self) -> i32 {
-		self.next(32) as i32
+	#[inline]
+	pub const fn next_i32(&mut self) -> i32 {
+		self.next(32)
 	}
 
 	/// Returns a uniformly distributed unsigned 32-bit integer.
-	pub fn next_u32(&mut self) -> u32 {
+	#[inline]
+	pub const fn next_u32(&mut self) -> u32 {
 		self.next(32) as u32
 	}
 
@@ -80,26 +279,52 @@ impl Random {
 	///
 	/// # Panics
 	/// If `max` is less than 1, the function panics.
-	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
-		if max <= 0 {
-			panic!("Maximum must be > 0")
+	#[inline]
+	pub const fn next_i32_bound(&mut self, max: i32) -> i32 {
+		self.next_i32_bound_counted(max).0
+	}
+
+	/// Like `next_i32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next_i32_bound(&mut self, max: i32) -> Result<i32, Error> {
+		match self.try_next_i32_bound_counted(max) {
+			Ok((value, _)) => Ok(value),
+			Err(error) => Err(error)
 		}
+	}
 
-		if (max as u32).is_power_of_two() {
-			let max = max as i64;
+	/// Like `next_i32_bound`, but also returns the number of calls made to `next`, which depends on how many values
+	/// were rejected to avoid modulo bias.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	#[inline]
+	pub const fn next_i32_bound_counted(&mut self, max: i32) -> (i32, u32) {
+		match self.try_next_i32_bound_counted(max) {
+			Ok(counted) => counted,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
 
-			return ((max.wrapping_mul(self.next(31) as i64)) >> 31) as i32;
+	/// Like `next_i32_bound_counted`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next_i32_bound_counted(&mut self, max: i32) -> Result<(i32, u32), Error> {
+		if let Err(error) = error::check_i32_bound(max) {
+			return Err(error);
 		}
 
-		let mut bits = self.next(31);
-		let mut val = bits % max;
+		Ok(random_next_i32_bound!(self, max))
+	}
 
-		while bits.wrapping_sub(val).wrapping_add(max - 1) < 0 {
-			bits = self.next(31);
-			val = bits % max;
-		}
+	/// Like `next_i32_bound`, but with the bound fixed at compile time, so that choosing between the power of two and
+	/// the rejection strategy costs nothing at runtime.
+	///
+	/// A bound of less than 1 is rejected when the call is compiled, rather than with a panic.
+	#[inline]
+	pub const fn next_i32_bound_const<const MAX: i32>(&mut self) -> i32 {
+		const { assert!(MAX > 0, "Maximum must be > 0") };
 
-		val
+		random_next_i32_bound!(self, MAX).0
 	}
 
 	/// Returns a positive random number in the range [0, max), up to 2^31.
@@ -109,73 +334,199 @@ impl Random {
 	///
 	/// # Panics
 	/// If `max` reinterpreted as a signed 32-bit integer is less than 1, the function panics.
-	pub fn next_u32_bound(&mut self, max: u32) -> u32 {
+	#[inline]
+	pub const fn next_u32_bound(&mut self, max: u32) -> u32 {
 		self.next_i32_bound(max as i32) as u32
 	}
 
+	/// Like `next_u32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next_u32_bound(&mut self, max: u32) -> Result<u32, Error> {
+		match self.try_next_i32_bound(max as i32) {
+			Ok(value) => Ok(value as u32),
+			Err(error) => Err(error)
+		}
+	}
+
+	/// Like `next_u32_bound`, but also returns the number of calls made to `next`.
+	///
+	/// # Panics
+	/// If `max` reinterpreted as a signed 32-bit integer is less than 1, the function panics.
+	#[inline]
+	pub const fn next_u32_bound_counted(&mut self, max: u32) -> (u32, u32) {
+		let (value, calls) = self.next_i32_bound_counted(max as i32);
+
+		(value as u32, calls)
+	}
+
+	/// Returns a random number in the range [0, max) for any `max` above 0, using all 32 bits of `next_u32`. This is
+	/// a Rust extension **not compatible with Java**: it draws different values from `next_u32_bound` even for
+	/// bounds below 2^31, which Java supports.
+	///
+	/// Values of `next_u32` below `2^32 mod max` are rejected and drawn again, so every result is equally likely.
+	///
+	/// # Panics
+	/// If `max` is 0, the function panics.
+	#[inline]
+	pub const fn next_u32_bound_full(&mut self, max: u32) -> u32 {
+		match self.try_next_u32_bound_full(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_u32_bound_full`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next_u32_bound_full(&mut self, max: u32) -> Result<u32, Error> {
+		if max == 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		let threshold = max.wrapping_neg() % max;
+
+		loop {
+			let value = self.next_u32();
+
+			if value >= threshold {
+				return Ok(value % max);
+			}
+		}
+	}
+
+	/// Returns a random number in the range [0, max) for any `max` above 0, using all 64 bits of `next_u64`. Like
+	/// `next_u32_bound_full`, this is a Rust extension **not compatible with Java**, and it rejects values of
+	/// `next_u64` below `2^64 mod max`.
+	///
+	/// # Panics
+	/// If `max` is 0, the function panics.
+	#[inline]
+	pub const fn next_u64_bound_full(&mut self, max: u64) -> u64 {
+		match self.try_next_u64_bound_full(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_u64_bound_full`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	#[inline]
+	pub const fn try_next_u64_bound_full(&mut self, max: u64) -> Result<u64, Error> {
+		if max == 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		let threshold = max.wrapping_neg() % max;
+
+		loop {
+			let value = self.next_u64();
+
+			if value >= threshold {
+				return Ok(value % max);
+			}
+		}
+	}
+
 	/// Returns a uniformly distributed signed 64-bit integer.
-	pub fn next_i64(&mut self) -> i64 {
-		((self.next(32) as i64) << 32).wrapping_add(self.next(32) as i64)
+	#[inline]
+	pub const fn next_i64(&mut self) -> i64 {
+		random_next_i64!(self)
 	}
 
 	/// Returns a uniformly distributed unsigned 64-bit integer.
-	pub fn next_u64(&mut self) -> u64 {
+	#[inline]
+	pub const fn next_u64(&mut self) -> u64 {
 		self.next_i64() as u64
 	}
 
 	/// Returns a boolean value that has an equal chance of being true or false.
-	pub fn next_bool(&mut self) -> bool {
+	#[inline]
+	pub const fn next_bool(&mut self) -> bool {
 		self.next(1) == 1
 	}
 
+	/// Returns a buffered source of booleans that serves 32 of them per step of the generator. This is much faster
+	/// than `next_bool` but **not compatible with Java**, see [`BufferedBools`].
+	pub fn buffered_bools(&mut self) -> BufferedBools<'_> {
+		BufferedBools::new(self)
+	}
+
+	/// Returns a reader serving reads of any width from 1 to 64 bits from whole `nextInt` calls, see [`BitReader`].
+	pub fn bit_reader(&mut self) -> BitReader<'_> {
+		BitReader::new(self)
+	}
+
 	/// Returns a f32 uniformly distributed between 0.0 and 1.0.
-	pub fn next_f32(&mut self) -> f32 {
-		(self.next(24) as f32) / F32_DIV
+	#[inline]
+	pub const fn next_f32(&mut self) -> f32 {
+		random_next_f32!(self)
 	}
 
 	/// Returns a f64 uniformly distributed between 0.0 and 1.0.
-	pub fn next_f64(&mut self) -> f64 {
-		let high = (self.next(26) as i64) << 27;
-		let low = self.next(27) as i64;
-
-		(high.wrapping_add(low) as f64) / F64_DIV
+	#[inline]
+	pub const fn next_f64(&mut self) -> f64 {
+		random_next_f64!(self)
 	}
 
-	/// Returns a pair of gaussian random numbers generated by the Box-Mueller transform.
-	fn next_gaussian_pair(&mut self) -> (f64, f64) {
-		let mut next_candidate = || {
-			let v = (
-				2.0 * self.next_f64() - 1.0,
-				2.0 * self.next_f64() - 1.0
-			);
+	/// Returns a f64 uniformly distributed in the range [0, bound), equivalent to `nextDouble(bound)` in Java 17 and
+	/// newer.
+	///
+	/// # Panics
+	/// If `bound` is not positive and finite, the function panics.
+	pub fn next_f64_bound(&mut self, bound: f64) -> f64 {
+		match self.try_next_f64_bound(bound) {
+			Ok(value) => value,
+			Err(_) => panic!("Bound must be positive and finite")
+		}
+	}
 
-			(v, v.0*v.0 + v.1*v.1)
-		};
+	/// Like `next_f64_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the RNG.
+	pub fn try_next_f64_bound(&mut self, bound: f64) -> Result<f64, Error> {
+		error::check_f64_bound(bound)?;
 
-		let (mut v, mut s) = next_candidate();
+		Ok(float::bounded_f64(self.next_f64(), bound))
+	}
 
-		while s >= 1.0 || s == 0.0 {
-			let (vn, sn) = next_candidate();
-			v = vn;
-			s = sn;
+	/// Returns a f64 uniformly distributed in the range [origin, bound), equivalent to `nextDouble(origin, bound)` in
+	/// Java 17 and newer. Java 17 and 18 reject ranges wider than `f64::MAX`, which are supported here as in later
+	/// versions.
+	///
+	/// # Panics
+	/// If either endpoint is not finite, or if `origin` is not less than `bound`, the function panics.
+	pub fn next_f64_range(&mut self, origin: f64, bound: f64) -> f64 {
+		match self.try_next_f64_range(origin, bound) {
+			Ok(value) => value,
+			Err(_) => panic!("Origin must be less than bound, and both must be finite")
 		}
+	}
+
+	/// Like `next_f64_range`, but returns `Error::InvalidRange` instead of panicking, without stepping the RNG.
+	pub fn try_next_f64_range(&mut self, origin: f64, bound: f64) -> Result<f64, Error> {
+		error::check_f64_range(origin, bound)?;
 
-		// TODO: Use StrictMath (software) equivalent.
-		let multiplier = ((s.log(::std::f64::consts::E) / s) * -2.0).sqrt();
+		Ok(float::bounded_f64_range(self.next_f64(), origin, bound))
+	}
 
-		(v.0 * multiplier, v.1 * multiplier)
+	/// Returns a pair of gaussian random numbers generated by the Box-Mueller transform, and the number of calls made
+	/// to `next`.
+	fn next_gaussian_pair(&mut self) -> ((f64, f64), u32) {
+		random_next_gaussian_pair!(self)
 	}
 
 	/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0.
 	pub fn next_gaussian(&mut self) -> f64 {
+		self.next_gaussian_counted().0
+	}
+
+	/// Like `next_gaussian`, but also returns the number of calls made to `next`. This is 0 when the second value of a
+	/// previously generated pair is returned.
+	pub fn next_gaussian_counted(&mut self) -> (f64, u32) {
 		match self.next_gaussian.take() {
-			Some(next) => next,
+			Some(next) => (next, 0),
 			None => {
-				let (v0, v1) = self.next_gaussian_pair();
+				let ((v0, v1), calls) = self.next_gaussian_pair();
 
 				self.next_gaussian = Some(v1);
 
-				v0
+				(v0, calls)
 			}
 		}
 	}
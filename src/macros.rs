@@ -0,0 +1,133 @@
+/// Computes the values of a draw from a seeded generator at compile time, for embedding expected sequences in tests
+/// without generating them at runtime or checking in tables.
+///
+/// `java_rand_vectors!(seed = SEED, METHOD(ARGS) * COUNT)` evaluates to an array of the first `COUNT` values of
+/// `Random::new(SEED).METHOD(ARGS)`, where the method is any of the `const fn` draws of `Random` and the arguments can
+/// be left out along with their parentheses. In a `const` or `static` item, the whole sequence is computed by the
+/// compiler:
+///
+/// ```
+/// use java_rand::java_rand_vectors;
+///
+/// const ROLLS: [i32; 5] = java_rand_vectors!(seed = 42, next_i32_bound(6) * 5);
+/// const CHANCES: [f64; 100] = java_rand_vectors!(seed = 1234, next_f64 * 100);
+///
+/// assert_eq!(ROLLS, [2, 3, 0, 2, 0]);
+/// assert_eq!(CHANCES[0], java_rand::Random::new(1234).next_f64());
+/// ```
+#[macro_export]
+macro_rules! java_rand_vectors {
+	(seed = $seed:expr, $method:ident $(($($argument:expr),*))? * $count:expr) => {{
+		let mut random = $crate::Random::new($seed);
+
+		// The first value also gives the type of the array
+		let first = random.$method($($($argument),*)?);
+		let mut values = [first; $count];
+		let mut index = 1;
+
+		while index < values.len() {
+			values[index] = random.$method($($($argument),*)?);
+			index += 1;
+		}
+
+		values
+	}};
+}
+
+// The algorithms that `java.util.Random` derives from `next(bits)`, shared by `Random` and every type that reimplements
+// it on top of another `next`. They are macros rather than functions generic over `NextBits` so that the `const fn`
+// draws can use them too. `$random` is evaluated once per call to its `next` method.
+
+/// `nextInt(bound)` of `Random`, for a bound already checked to be positive: the high bits of `next(31)` scaled down
+/// for powers of two, and otherwise `next(31) % bound` with the values that would bias the remainder rejected.
+/// Evaluates to the value and the number of calls made to `next`.
+macro_rules! random_next_i32_bound {
+	($random:expr, $max:expr) => {{
+		let max: i32 = $max;
+
+		if (max as u32).is_power_of_two() {
+			((((max as i64).wrapping_mul($random.next(31) as i64)) >> 31) as i32, 1u32)
+		} else {
+			let mut bits: i32 = $random.next(31);
+			let mut val = bits % max;
+			let mut calls = 1u32;
+
+			while bits.wrapping_sub(val).wrapping_add(max - 1) < 0 {
+				bits = $random.next(31);
+				val = bits % max;
+				calls += 1;
+			}
+
+			(val, calls)
+		}
+	}};
+}
+
+/// `nextLong()` of `Random`: two calls to `next(32)`, the first one giving the high half.
+macro_rules! random_next_i64 {
+	($random:expr) => {
+		((($random.next(32) as i64) << 32).wrapping_add($random.next(32) as i64))
+	};
+}
+
+/// `nextFloat()` of `Random`, from `next(24)`.
+macro_rules! random_next_f32 {
+	($random:expr) => {
+		$crate::float::unit_f32($random.next(24) as u32)
+	};
+}
+
+/// `nextDouble()` of `Random`, from `next(26)` and `next(27)`.
+macro_rules! random_next_f64 {
+	($random:expr) => {{
+		let high = ($random.next(26) as i64) << 27;
+		let low = $random.next(27) as i64;
+
+		$crate::float::unit_f64(high.wrapping_add(low) as u64)
+	}};
+}
+
+/// The polar method of `nextGaussian()` in `Random`, which draws pairs of `nextDouble()` until one falls inside the
+/// unit circle. Evaluates to both gaussians and the number of calls made to `next`.
+macro_rules! random_next_gaussian_pair {
+	($random:expr) => {{
+		let mut calls = 0u32;
+
+		loop {
+			let v0 = 2.0 * random_next_f64!($random) - 1.0;
+			let v1 = 2.0 * random_next_f64!($random) - 1.0;
+			let s = v0*v0 + v1*v1;
+
+			calls += $crate::calls::NEXT_GAUSSIAN_PER_ATTEMPT;
+
+			if s < 1.0 && s != 0.0 {
+				let multiplier = $crate::strictmath::sqrt(-2.0 * $crate::strictmath::log(s) / s);
+
+				break ((v0 * multiplier, v1 * multiplier), calls);
+			}
+		}
+	}};
+}
+
+/// `nextInt(bound)` and `nextLong(bound)` of `java.util.random.RandomGenerator`, for a bound already checked to be
+/// positive: `$next` masked for powers of two, and otherwise `$next >>> 1` with the values that would bias the
+/// remainder rejected. `$signed` and `$unsigned` are the integer types of the draw.
+macro_rules! generator_next_bound {
+	($random:expr, $max:expr, $next:ident, $signed:ty, $unsigned:ty) => {{
+		let max: $signed = $max;
+		let mask = max - 1;
+		let value: $signed = $random.$next();
+
+		if max & mask == 0 {
+			value & mask
+		} else {
+			let mut bits = (value as $unsigned >> 1) as $signed;
+
+			while bits.wrapping_add(mask).wrapping_sub(bits % max) < 0 {
+				bits = ($random.$next() as $unsigned >> 1) as $signed;
+			}
+
+			bits % max
+		}
+	}};
+}
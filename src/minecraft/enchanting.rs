@@ -0,0 +1,157 @@
+//! The random draws of the enchanting table, following `EnchantmentMenu` and `EnchantmentHelper`.
+//!
+//! Each player has an enchantment seed, redrawn with `nextInt()` from the player's generator whenever they enchant
+//! an item. When an item is placed in the table, the menu:
+//!
+//! 1. seeds a generator with the enchantment seed and draws the level cost of each of the three slots with
+//!    `enchantment_costs`,
+//! 2. for each slot with a cost, reseeds the generator with `slot_random` and selects the enchantments with
+//!    `select_enchantments`, removing one at random with `next_i32_bound(len)` if the item is a book with more than
+//!    one enchantment,
+//! 3. picks the enchantment shown as a clue with another `next_i32_bound(len)` from the same generator.
+//!
+//! Which enchantments are available at a level, their weights, and which are compatible come from the game's
+//! registry, so they are supplied by the caller.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use minecraft::mth::floor;
+use Random;
+
+/// Returns the level costs shown for the three slots of the table, like the costs computed in
+/// `EnchantmentMenu.slotsChanged`. Slots that do not meet their minimum cost, and every slot if the item is not
+/// enchantable, cost 0.
+pub const fn enchantment_costs(enchantment_seed: i32, bookshelves: i32, enchantability: i32) -> [i32; 3] {
+	let mut random = Random::new(enchantment_seed as i64 as u64);
+	let mut costs = [0; 3];
+	let mut slot = 0;
+
+	while slot < 3 {
+		let cost = enchantment_cost(&mut random, slot as i32, bookshelves, enchantability);
+		costs[slot] = if cost < slot as i32 + 1 { 0 } else { cost };
+
+		slot += 1;
+	}
+
+	costs
+}
+
+/// Draws the cost of a single slot, like `EnchantmentHelper.getEnchantmentCost`. Bookshelves beyond 15 are ignored,
+/// and the generator is left untouched if the item is not enchantable.
+pub const fn enchantment_cost(random: &mut Random, slot: i32, bookshelves: i32, enchantability: i32) -> i32 {
+	if enchantability <= 0 {
+		return 0;
+	}
+
+	let bookshelves = if bookshelves > 15 { 15 } else { bookshelves };
+	let base = random.next_i32_bound(8) + 1 + (bookshelves >> 1) + random.next_i32_bound(bookshelves + 1);
+
+	match slot {
+		0 => if base / 3 > 1 { base / 3 } else { 1 },
+		1 => base * 2 / 3 + 1,
+		_ => if base > bookshelves * 2 { base } else { bookshelves * 2 }
+	}
+}
+
+/// Returns the generator that selects the enchantments of a slot, seeded with the enchantment seed plus the slot.
+pub const fn slot_random(enchantment_seed: i32, slot: i32) -> Random {
+	Random::new(enchantment_seed.wrapping_add(slot) as i64 as u64)
+}
+
+/// Draws the enchantment level for a cost, the first step of `EnchantmentHelper.selectEnchantment`: the cost is
+/// raised by up to half the enchantability, then varied by up to 15% either way. Returns None without any draws if
+/// the item is not enchantable.
+pub fn modified_level(random: &mut Random, cost: i32, enchantability: i32) -> Option<i32> {
+	if enchantability <= 0 {
+		return None;
+	}
+
+	let level = cost
+		.wrapping_add(1)
+		.wrapping_add(random.next_i32_bound(enchantability / 4 + 1))
+		.wrapping_add(random.next_i32_bound(enchantability / 4 + 1));
+
+	let variation = (random.next_f32() + random.next_f32() - 1.0) * 0.15;
+	let level = round(level as f32 + level as f32 * variation);
+
+	Some(if level < 1 { 1 } else { level })
+}
+
+/// Picks an index with probability proportional to its weight, like `WeightedRandom.getRandomItem`. Returns None
+/// without any draws if the weights sum to 0.
+///
+/// # Panics
+/// If the weights sum to more than `i32::MAX`, the function panics.
+pub fn weighted_index(random: &mut Random, weights: &[i32]) -> Option<usize> {
+	let total = weights.iter().map(|&weight| weight as i64).sum::<i64>();
+
+	if total > i32::MAX as i64 {
+		panic!("Sum of weights must be <= i32::MAX")
+	}
+
+	if total == 0 {
+		return None;
+	}
+
+	let mut remaining = random.next_i32_bound(total as i32);
+
+	weights.iter().position(|&weight| {
+		remaining -= weight;
+		remaining < 0
+	})
+}
+
+/// Selects enchantments like `EnchantmentHelper.selectEnchantment`.
+///
+/// `available` returns the enchantments that can be applied at the level drawn by `modified_level`, with their
+/// weights, in registry order. After each pick, the candidates that are not `compatible` with the enchantment just
+/// picked are removed, so `compatible` must return false for an enchantment and itself. Further picks are taken
+/// while `next_i32_bound(50)` does not exceed the level, which halves after every pick.
+#[cfg(feature = "std")]
+pub fn select_enchantments<T, A, C>(random: &mut Random, cost: i32, enchantability: i32, available: A, compatible: C) -> Vec<T>
+	where A: FnOnce(i32) -> Vec<(T, i32)>, C: Fn(&T, &T) -> bool, T: Clone {
+	let mut selected = Vec::new();
+
+	let mut level = match modified_level(random, cost, enchantability) {
+		Some(level) => level,
+		None => return selected
+	};
+
+	let mut candidates = available(level);
+
+	if candidates.is_empty() {
+		return selected;
+	}
+
+	selected.extend(pick(random, &candidates));
+
+	while random.next_i32_bound(50) <= level {
+		if let Some(last) = selected.last() {
+			candidates.retain(|(candidate, _)| compatible(last, candidate));
+		}
+
+		if candidates.is_empty() {
+			break;
+		}
+
+		selected.extend(pick(random, &candidates));
+		level /= 2;
+	}
+
+	selected
+}
+
+#[cfg(feature = "std")]
+fn pick<T: Clone>(random: &mut Random, candidates: &[(T, i32)]) -> Option<T> {
+	let weights = candidates.iter().map(|&(_, weight)| weight).collect::<Vec<_>>();
+
+	weighted_index(random, &weights).map(|index| candidates[index].0.clone())
+}
+
+/// `Math.round(float)`: rounds to the nearest integer with ties toward positive infinity, saturating at the limits
+/// of `i32`.
+fn round(value: f32) -> i32 {
+	// Every float plus one half is exact as a double, so this never rounds twice
+	floor(value as f64 + 0.5)
+}
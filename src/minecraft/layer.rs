@@ -0,0 +1,105 @@
+//! The generator of the biome layers of 1.7 through 1.17, `GenLayer` before 1.14 and `LazyAreaContext` after it.
+//!
+//! The layers do not use `java.util.Random`. Each layer mixes its salt into the world seed with the multiplier and
+//! increment of Knuth's MMIX LCG, reseeds itself at every position from the coordinates, and draws each `nextInt` from
+//! the upper bits of the seed before stepping it with the world seed of the layer mixed in. The arithmetic is the
+//! same in both versions, only the names changed.
+
+/// Multiplier of `LinearCongruentialGenerator`
+pub const MULTIPLIER: i64 = 6364136223846793005;
+
+/// Increment of `LinearCongruentialGenerator`
+pub const INCREMENT: i64 = 1442695040888963407;
+
+/// Returns `seed * (seed * MULTIPLIER + INCREMENT) + salt` with wrapping arithmetic, the step of every seed of the
+/// layers, as computed by `LinearCongruentialGenerator.next`.
+pub const fn layer_step(seed: i64, salt: i64) -> i64 {
+	seed.wrapping_mul(seed.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT)).wrapping_add(salt)
+}
+
+/// Returns the base seed of a layer with the given salt, the `baseSeed` of `GenLayer`: the salt stepped three times
+/// with itself.
+pub const fn layer_base_seed(salt: i64) -> i64 {
+	layer_step(layer_step(layer_step(salt, salt), salt), salt)
+}
+
+/// Returns the world seed of a layer, as set by `GenLayer.initWorldGenSeed` and computed by
+/// `LazyAreaContext.mixSeed`: the world seed stepped three times with the base seed of the layer.
+pub const fn layer_seed(world_seed: i64, salt: i64) -> i64 {
+	let base = layer_base_seed(salt);
+
+	layer_step(layer_step(layer_step(world_seed, base), base), base)
+}
+
+/// The generator of one biome layer in one world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayerRandom {
+	layer_seed: i64,
+	chunk_seed: i64
+}
+
+impl LayerRandom {
+	/// Creates the generator of the layer with the given salt, such as 1 for the island layer and 2001 for the first
+	/// zoom layer. `set_position` must be called before drawing values, like in Java.
+	pub const fn new(world_seed: i64, salt: i64) -> Self {
+		LayerRandom::from_layer_seed(layer_seed(world_seed, salt))
+	}
+
+	/// Creates the generator of a layer from its world seed as returned by `layer_seed`.
+	pub const fn from_layer_seed(layer_seed: i64) -> Self {
+		LayerRandom { layer_seed, chunk_seed: 0 }
+	}
+
+	/// Returns the world seed of the layer, the `worldGenSeed` of `GenLayer`.
+	pub const fn layer_seed(&self) -> i64 {
+		self.layer_seed
+	}
+
+	/// Returns the current seed, the `chunkSeed` of `GenLayer`.
+	pub const fn chunk_seed(&self) -> i64 {
+		self.chunk_seed
+	}
+
+	/// Reseeds the generator for the position (`x`, `z`) of the layer, like `GenLayer.initChunkSeed` and
+	/// `LazyAreaContext.initRandom`. The coordinates are in the units of the layer, which depend on how many zoom
+	/// layers lie between it and the final biomes.
+	pub const fn set_position(&mut self, x: i64, z: i64) {
+		let mut seed = layer_step(self.layer_seed, x);
+		seed = layer_step(seed, z);
+		seed = layer_step(seed, x);
+
+		self.chunk_seed = layer_step(seed, z);
+	}
+
+	/// Returns a number from 0 to `bound` exclusive, like `GenLayer.nextInt` and `LazyAreaContext.nextRandom`.
+	///
+	/// # Panics
+	/// If `bound` is not greater than 0, the function panics.
+	pub const fn next_i32_bound(&mut self, bound: i32) -> i32 {
+		if bound <= 0 {
+			panic!("Maximum must be > 0")
+		}
+
+		let value = (self.chunk_seed >> 24).rem_euclid(bound as i64) as i32;
+		self.chunk_seed = layer_step(self.chunk_seed, self.layer_seed);
+
+		value
+	}
+
+	/// Returns one of the two values with equal probability, drawing `nextInt(2)`, like `choose` with two arguments
+	/// in `GenLayer.selectRandom` and `Context.random`.
+	pub const fn choose(&mut self, a: i32, b: i32) -> i32 {
+		if self.next_i32_bound(2) == 0 { a } else { b }
+	}
+
+	/// Returns one of the four values with equal probability, drawing `nextInt(4)`, like `GenLayer.selectRandom` with
+	/// four arguments, as used by the zoom layers.
+	pub const fn choose4(&mut self, a: i32, b: i32, c: i32, d: i32) -> i32 {
+		match self.next_i32_bound(4) {
+			0 => a,
+			1 => b,
+			2 => c,
+			_ => d
+		}
+	}
+}
@@ -0,0 +1,33 @@
+//! Helpers reproducing how Minecraft: Java Edition seeds and consumes its generators during world generation.
+//!
+//! The formulas changed between versions, so the helpers take an `McVersion` and pick the right one.
+
+pub mod enchanting;
+mod layer;
+mod md5;
+mod mth;
+pub mod noise;
+mod seed;
+mod sequence;
+mod sha256;
+mod slime;
+mod source;
+mod spike;
+mod structure;
+mod version;
+mod worldgen;
+
+pub use self::layer::{layer_base_seed, layer_seed, layer_step, LayerRandom};
+pub use self::seed::{hashed_seed, next_long_seeds, sister_seeds, structure_seed, text_seed};
+pub use self::sequence::{seed_for_key, RandomSequence};
+#[cfg(feature = "std")]
+pub use self::sequence::RandomSequences;
+pub use self::slime::{is_slime_chunk, is_slime_chunk_bedrock, slime_chunk_seed};
+pub use self::source::{
+	position_seed, LegacyPositionalRandomFactory, LegacyRandomSource, PositionalRandomFactory, RandomSource,
+	XoroshiroPositionalRandomFactory, XoroshiroRandomSource
+};
+pub use self::spike::{end_spike_key, end_spikes, end_spikes_for_key, EndSpike};
+pub use self::structure::{random_spread_chunk, region_random, region_seed, SpreadType, Structure, StructurePlacement};
+pub use self::version::McVersion;
+pub use self::worldgen::WorldgenRandom;
@@ -0,0 +1,30 @@
+//! Ports of the `Mth` helpers that the rest of the module relies on for exact results.
+
+/// `Mth.floor`, saturating like Java's conversion to int
+pub fn floor(value: f64) -> i32 {
+	let truncated = value as i32;
+
+	if value < truncated as f64 { truncated.wrapping_sub(1) } else { truncated }
+}
+
+/// `Mth.lfloor`, saturating like Java's conversion to long
+pub fn lfloor(value: f64) -> i64 {
+	let truncated = value as i64;
+
+	if value < truncated as f64 { truncated.wrapping_sub(1) } else { truncated }
+}
+
+/// `Mth.smoothstep`, the fade curve of Perlin noise
+pub fn smoothstep(x: f64) -> f64 {
+	x * x * x * (x * (x * 6.0 - 15.0) + 10.0)
+}
+
+/// `Mth.lerp`
+pub fn lerp(delta: f64, start: f64, end: f64) -> f64 {
+	start + delta * (end - start)
+}
+
+/// `Mth.lerp2`
+pub fn lerp2(delta_x: f64, delta_y: f64, x0y0: f64, x1y0: f64, x0y1: f64, x1y1: f64) -> f64 {
+	lerp(delta_y, lerp(delta_x, x0y0, x1y0), lerp(delta_x, x0y1, x1y1))
+}
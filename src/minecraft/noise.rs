@@ -0,0 +1,612 @@
+//! Ports of Minecraft's noise generators: `ImprovedNoise`, the octave sums of `PerlinNoise`, and `SimplexNoise`.
+//!
+//! Constructing a single octave takes three doubles for the offsets, then shuffles a 256 entry permutation with
+//! `nextInt(256 - i)` for each entry. Because the bounded draws can reject values, the exact number of steps varies,
+//! but when an octave is not needed the game skips a fixed 262 steps instead, the number of steps taken without any
+//! rejections. Skipping is therefore not equivalent to constructing an octave and discarding it.
+//!
+//! Evaluation follows the game's floating point operations in the same order, so the results are bit-identical.
+//!
+//! Before 1.13, chunk generators constructed all of their noise generators from one `Random` seeded with the world
+//! seed, in a fixed order. The `ConsumptionPlan` constants record that order, so that the generator can be positioned
+//! at the start of any noise generator.
+
+use minecraft::mth::{floor, lerp, lerp2, lfloor, smoothstep};
+use minecraft::{PositionalRandomFactory, RandomSource};
+use strictmath;
+use Random;
+
+/// Number of steps that the game skips in place of constructing an unused octave, with `consumeCount(262)`.
+pub const SKIPPED_OCTAVE_STEPS: u32 = 262;
+
+/// Gradients shared by `ImprovedNoise` and `SimplexNoise`, the first 12 being the edges of a cube
+const GRADIENT: [[i32; 3]; 16] = [
+	[1, 1, 0], [-1, 1, 0], [1, -1, 0], [-1, -1, 0],
+	[1, 0, 1], [-1, 0, 1], [1, 0, -1], [-1, 0, -1],
+	[0, 1, 1], [0, -1, 1], [0, 1, -1], [0, -1, -1],
+	[1, 1, 0], [0, -1, 1], [-1, 1, 0], [0, -1, -1]
+];
+
+/// Period that `PerlinNoise` wraps coordinates to, 2^25
+const WRAP: f64 = 33554432.0;
+
+const SQRT_3: f64 = 1.7320508075688772;
+const F2: f64 = 0.5 * (SQRT_3 - 1.0);
+const G2: f64 = (3.0 - SQRT_3) / 6.0;
+const F3: f64 = 1.0 / 3.0;
+const G3: f64 = 1.0 / 6.0;
+
+/// A single octave of Perlin noise, with its offsets and permutation table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImprovedNoise {
+	/// Offset added to x coordinates, in [0, 256)
+	pub xo: f64,
+	/// Offset added to y coordinates, in [0, 256)
+	pub yo: f64,
+	/// Offset added to z coordinates, in [0, 256)
+	pub zo: f64,
+	/// Permutation of the values 0 through 255
+	pub permutation: [u8; 256]
+}
+
+impl ImprovedNoise {
+	/// Constructs an octave exactly like `new ImprovedNoise(random)`.
+	pub fn new<R: RandomSource>(random: &mut R) -> Self {
+		let ([xo, yo, zo], permutation) = setup(random, R::next_i32_bound);
+
+		ImprovedNoise { xo, yo, zo, permutation }
+	}
+
+	/// Like `new` with a legacy generator, but also returns the number of steps taken, which is at least 262 and
+	/// varies with the number of values rejected by the bounded draws.
+	pub fn new_counted(random: &mut Random) -> (Self, u32) {
+		let mut steps = 6;
+
+		let ([xo, yo, zo], permutation) = setup(random, |random, max| {
+			let (value, calls) = random.next_i32_bound_counted(max);
+			steps += calls;
+
+			value
+		});
+
+		(ImprovedNoise { xo, yo, zo, permutation }, steps)
+	}
+
+	/// Samples the noise at the given position, like `noise(x, y, z)`.
+	pub fn noise(&self, x: f64, y: f64, z: f64) -> f64 {
+		self.noise_y_scaled(x, y, z, 0.0, 0.0)
+	}
+
+	/// Samples the noise with the deprecated y quantization used by `BlendedNoise`: when `y_scale` is not zero, the
+	/// gradient inputs are taken relative to a multiple of `y_scale`, with the fractional y clamped to `y_max` if it
+	/// is not negative.
+	pub fn noise_y_scaled(&self, x: f64, y: f64, z: f64, y_scale: f64, y_max: f64) -> f64 {
+		let x = x + self.xo;
+		let y = y + self.yo;
+		let z = z + self.zo;
+
+		let grid_x = floor(x);
+		let grid_y = floor(y);
+		let grid_z = floor(z);
+
+		let delta_x = x - grid_x as f64;
+		let delta_y = y - grid_y as f64;
+		let delta_z = z - grid_z as f64;
+
+		let y_offset = if y_scale != 0.0 {
+			let clamped = if y_max >= 0.0 && y_max < delta_y { y_max } else { delta_y };
+
+			floor(clamped / y_scale + 1.0E-7f32 as f64) as f64 * y_scale
+		} else {
+			0.0
+		};
+
+		self.sample_and_lerp(grid_x, grid_y, grid_z, delta_x, delta_y - y_offset, delta_z, delta_y)
+	}
+
+	fn p(&self, index: i32) -> i32 {
+		self.permutation[(index & 255) as usize] as i32
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn sample_and_lerp(&self, grid_x: i32, grid_y: i32, grid_z: i32, delta_x: f64, weird_delta_y: f64, delta_z: f64, delta_y: f64) -> f64 {
+		let i = self.p(grid_x);
+		let j = self.p(grid_x.wrapping_add(1));
+		let k = self.p(i.wrapping_add(grid_y));
+		let l = self.p(i.wrapping_add(grid_y).wrapping_add(1));
+		let i1 = self.p(j.wrapping_add(grid_y));
+		let j1 = self.p(j.wrapping_add(grid_y).wrapping_add(1));
+
+		let corner = |hash: i32, z_offset: i32, x: f64, y: f64, z: f64| {
+			dot(GRADIENT[(self.p(hash.wrapping_add(grid_z).wrapping_add(z_offset)) & 15) as usize], x, y, z)
+		};
+
+		let d0 = corner(k, 0, delta_x, weird_delta_y, delta_z);
+		let d1 = corner(i1, 0, delta_x - 1.0, weird_delta_y, delta_z);
+		let d2 = corner(l, 0, delta_x, weird_delta_y - 1.0, delta_z);
+		let d3 = corner(j1, 0, delta_x - 1.0, weird_delta_y - 1.0, delta_z);
+		let d4 = corner(k, 1, delta_x, weird_delta_y, delta_z - 1.0);
+		let d5 = corner(i1, 1, delta_x - 1.0, weird_delta_y, delta_z - 1.0);
+		let d6 = corner(l, 1, delta_x, weird_delta_y - 1.0, delta_z - 1.0);
+		let d7 = corner(j1, 1, delta_x - 1.0, weird_delta_y - 1.0, delta_z - 1.0);
+
+		let fade_x = smoothstep(delta_x);
+		let fade_y = smoothstep(delta_y);
+		let fade_z = smoothstep(delta_z);
+
+		lerp(fade_z, lerp2(fade_x, fade_y, d0, d1, d2, d3), lerp2(fade_x, fade_y, d4, d5, d6, d7))
+	}
+}
+
+/// A sum of `N` octaves of `ImprovedNoise`, like `PerlinNoise`. Octave `i` has a frequency of `2^(first_octave + i)`
+/// and is weighted by `amplitudes[i]`, octaves with an amplitude of zero are left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerlinNoise<const N: usize> {
+	first_octave: i32,
+	amplitudes: [f64; N],
+	octaves: [Option<ImprovedNoise>; N],
+	lowest_freq_input_factor: f64,
+	lowest_freq_value_factor: f64
+}
+
+impl<const N: usize> PerlinNoise<N> {
+	/// Constructs the octaves like `PerlinNoise.create`, from sources created by a factory forked from `random`, each
+	/// named after its octave, such as `octave_-3`.
+	pub fn new<R: RandomSource>(random: &mut R, first_octave: i32, amplitudes: [f64; N]) -> Self {
+		let factory = random.fork_positional();
+		let mut octaves: [Option<ImprovedNoise>; N] = core::array::from_fn(|_| None);
+
+		for (index, octave) in octaves.iter_mut().enumerate() {
+			if amplitudes[index] != 0.0 {
+				let mut name = OctaveName::new();
+				name.push_str("octave_");
+				name.push_i32(first_octave.wrapping_add(index as i32));
+
+				*octave = Some(ImprovedNoise::new(&mut factory.from_hash_of(name.as_str())));
+			}
+		}
+
+		PerlinNoise::from_octaves(first_octave, amplitudes, octaves)
+	}
+
+	/// Constructs the octaves from a single generator, like `PerlinNoise.createLegacyForBlendedNoise` and the noise
+	/// generators of versions before 1.18. Octave 0 is constructed first, then the lower octaves in descending order,
+	/// skipping those with an amplitude of zero with `skip_octave`.
+	///
+	/// # Panics
+	/// If any octave is above octave 0, which the legacy construction does not support, the function panics.
+	pub fn new_legacy<R: RandomSource>(random: &mut R, first_octave: i32, amplitudes: [f64; N]) -> Self {
+		let count = N as i64;
+		let zero = -(first_octave as i64);
+
+		if zero < count - 1 {
+			panic!("Legacy octaves must not be above octave 0")
+		}
+
+		let mut octaves: [Option<ImprovedNoise>; N] = core::array::from_fn(|_| None);
+		let first = ImprovedNoise::new(random);
+
+		if zero < count && amplitudes[zero as usize] != 0.0 {
+			octaves[zero as usize] = Some(first);
+		}
+
+		for index in (0..zero).rev() {
+			if index < count && amplitudes[index as usize] != 0.0 {
+				octaves[index as usize] = Some(ImprovedNoise::new(random));
+			} else {
+				skip_octave(random);
+			}
+		}
+
+		PerlinNoise::from_octaves(first_octave, amplitudes, octaves)
+	}
+
+	fn from_octaves(first_octave: i32, amplitudes: [f64; N], octaves: [Option<ImprovedNoise>; N]) -> Self {
+		let lowest_freq_input_factor = strictmath::pow(2.0, first_octave as f64);
+		let lowest_freq_value_factor = strictmath::pow(2.0, N as f64 - 1.0) / (strictmath::pow(2.0, N as f64) - 1.0);
+
+		PerlinNoise { first_octave, amplitudes, octaves, lowest_freq_input_factor, lowest_freq_value_factor }
+	}
+
+	/// Returns the octave with the lowest frequency.
+	pub fn first_octave(&self) -> i32 {
+		self.first_octave
+	}
+
+	/// Returns the amplitudes of the octaves.
+	pub fn amplitudes(&self) -> &[f64; N] {
+		&self.amplitudes
+	}
+
+	/// Returns octave `index`, counting from the lowest frequency, or None if its amplitude is zero.
+	///
+	/// # Panics
+	/// If `index` is not less than `N`, the function panics.
+	pub fn octave(&self, index: usize) -> Option<&ImprovedNoise> {
+		self.octaves[index].as_ref()
+	}
+
+	/// Samples the sum of the octaves at the given position, like `getValue(x, y, z)`.
+	pub fn value(&self, x: f64, y: f64, z: f64) -> f64 {
+		self.value_y_scaled(x, y, z, 0.0, 0.0, false)
+	}
+
+	/// Samples the octaves with `ImprovedNoise::noise_y_scaled`, scaling `y_scale` and `y_max` with the frequency of
+	/// each octave. With `fixed_y`, each octave is sampled at y level `-yo`, cancelling out its y offset.
+	pub fn value_y_scaled(&self, x: f64, y: f64, z: f64, y_scale: f64, y_max: f64, fixed_y: bool) -> f64 {
+		let mut value = 0.0;
+		let mut input_factor = self.lowest_freq_input_factor;
+		let mut value_factor = self.lowest_freq_value_factor;
+
+		for (octave, &amplitude) in self.octaves.iter().zip(self.amplitudes.iter()) {
+			if let Some(octave) = octave {
+				let noise = octave.noise_y_scaled(
+					wrap(x * input_factor),
+					if fixed_y { -octave.yo } else { wrap(y * input_factor) },
+					wrap(z * input_factor),
+					y_scale * input_factor,
+					y_max * input_factor
+				);
+
+				value += amplitude * noise * value_factor;
+			}
+
+			input_factor *= 2.0;
+			value_factor /= 2.0;
+		}
+
+		value
+	}
+
+	/// Returns the sum of the weights of the octaves multiplied by `bound`, like `edgeValue`. With a bound of 2, this
+	/// is the value that the game treats as the maximum of the noise.
+	pub fn edge_value(&self, bound: f64) -> f64 {
+		let mut value = 0.0;
+		let mut value_factor = self.lowest_freq_value_factor;
+
+		for (octave, &amplitude) in self.octaves.iter().zip(self.amplitudes.iter()) {
+			if octave.is_some() {
+				value += amplitude * bound * value_factor;
+			}
+
+			value_factor /= 2.0;
+		}
+
+		value
+	}
+}
+
+/// Simplex noise in two and three dimensions, like `SimplexNoise`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplexNoise {
+	/// Offset added to x coordinates by `value_3d`, in [0, 256)
+	pub xo: f64,
+	/// Offset added to y coordinates by `value_3d`, in [0, 256)
+	pub yo: f64,
+	/// Offset added to z coordinates by `value_3d`, in [0, 256)
+	pub zo: f64,
+	/// Permutation of the values 0 through 255
+	pub permutation: [u8; 256]
+}
+
+impl SimplexNoise {
+	/// Constructs the noise exactly like `new SimplexNoise(random)`, which takes the same draws as `ImprovedNoise`.
+	pub fn new<R: RandomSource>(random: &mut R) -> Self {
+		let ([xo, yo, zo], permutation) = setup(random, R::next_i32_bound);
+
+		SimplexNoise { xo, yo, zo, permutation }
+	}
+
+	fn p(&self, index: i32) -> i32 {
+		self.permutation[(index & 255) as usize] as i32
+	}
+
+	/// Samples two dimensional noise at the given position, like `getValue(x, y)`. The offsets are not applied.
+	pub fn value_2d(&self, x: f64, y: f64) -> f64 {
+		let skew = (x + y) * F2;
+		let i = floor(x + skew);
+		let j = floor(y + skew);
+
+		let unskew = i.wrapping_add(j) as f64 * G2;
+		let x0 = x - (i as f64 - unskew);
+		let y0 = y - (j as f64 - unskew);
+
+		let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+
+		let x1 = x0 - i1 as f64 + G2;
+		let y1 = y0 - j1 as f64 + G2;
+		let x2 = x0 - 1.0 + 2.0 * G2;
+		let y2 = y0 - 1.0 + 2.0 * G2;
+
+		let ii = i & 255;
+		let jj = j & 255;
+
+		let g0 = self.p(ii + self.p(jj)) % 12;
+		let g1 = self.p(ii + i1 + self.p(jj + j1)) % 12;
+		let g2 = self.p(ii + 1 + self.p(jj + 1)) % 12;
+
+		let n0 = corner(g0, x0, y0, 0.0, 0.5);
+		let n1 = corner(g1, x1, y1, 0.0, 0.5);
+		let n2 = corner(g2, x2, y2, 0.0, 0.5);
+
+		70.0 * (n0 + n1 + n2)
+	}
+
+	/// Samples three dimensional noise at the given position, like `getValue(x, y, z)`. The offsets are not applied.
+	pub fn value_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+		let skew = (x + y + z) * F3;
+		let i = floor(x + skew);
+		let j = floor(y + skew);
+		let k = floor(z + skew);
+
+		let unskew = i.wrapping_add(j).wrapping_add(k) as f64 * G3;
+		let x0 = x - (i as f64 - unskew);
+		let y0 = y - (j as f64 - unskew);
+		let z0 = z - (k as f64 - unskew);
+
+		let (i1, j1, k1, i2, j2, k2) = if x0 >= y0 {
+			if y0 >= z0 {
+				(1, 0, 0, 1, 1, 0)
+			} else if x0 >= z0 {
+				(1, 0, 0, 1, 0, 1)
+			} else {
+				(0, 0, 1, 1, 0, 1)
+			}
+		} else if y0 < z0 {
+			(0, 0, 1, 0, 1, 1)
+		} else if x0 < z0 {
+			(0, 1, 0, 0, 1, 1)
+		} else {
+			(0, 1, 0, 1, 1, 0)
+		};
+
+		let x1 = x0 - i1 as f64 + G3;
+		let y1 = y0 - j1 as f64 + G3;
+		let z1 = z0 - k1 as f64 + G3;
+		let x2 = x0 - i2 as f64 + F3;
+		let y2 = y0 - j2 as f64 + F3;
+		let z2 = z0 - k2 as f64 + F3;
+		let x3 = x0 - 1.0 + 0.5;
+		let y3 = y0 - 1.0 + 0.5;
+		let z3 = z0 - 1.0 + 0.5;
+
+		let ii = i & 255;
+		let jj = j & 255;
+		let kk = k & 255;
+
+		let g0 = self.p(ii + self.p(jj + self.p(kk))) % 12;
+		let g1 = self.p(ii + i1 + self.p(jj + j1 + self.p(kk + k1))) % 12;
+		let g2 = self.p(ii + i2 + self.p(jj + j2 + self.p(kk + k2))) % 12;
+		let g3 = self.p(ii + 1 + self.p(jj + 1 + self.p(kk + 1))) % 12;
+
+		let n0 = corner(g0, x0, y0, z0, 0.6);
+		let n1 = corner(g1, x1, y1, z1, 0.6);
+		let n2 = corner(g2, x2, y2, z2, 0.6);
+		let n3 = corner(g3, x3, y3, z3, 0.6);
+
+		32.0 * (n0 + n1 + n2 + n3)
+	}
+}
+
+/// Kind of the octaves of a noise generator. Both take the same draws when constructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoiseKind {
+	/// Octaves of `ImprovedNoise`, named `NoiseGeneratorImproved` before 1.13
+	Improved,
+	/// Octaves of `SimplexNoise`, named `NoiseGeneratorSimplex` before 1.13
+	Simplex
+}
+
+/// A noise generator constructed by a chunk generator, with the name of its field in MCP mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoiseStage {
+	/// Name of the field holding the noise generator
+	pub name: &'static str,
+	/// Kind of the octaves
+	pub kind: NoiseKind,
+	/// Number of octaves, each taking the draws of one `ImprovedNoise`
+	pub octaves: u32
+}
+
+/// The order in which a chunk generator constructs its noise generators from a generator seeded with the world seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConsumptionPlan {
+	/// Name of the chunk generator in MCP mappings
+	pub name: &'static str,
+	/// The noise generators, in the order they are constructed
+	pub stages: &'static [NoiseStage]
+}
+
+const fn stage(name: &'static str, kind: NoiseKind, octaves: u32) -> NoiseStage {
+	NoiseStage { name, kind, octaves }
+}
+
+/// `ChunkProviderGenerate` of Beta 1.7.3, which starts its noise with the same draws as Alpha.
+pub const BETA_1_7_OVERWORLD: ConsumptionPlan = ConsumptionPlan {
+	name: "ChunkProviderGenerate",
+	stages: &[
+		stage("field_912_k", NoiseKind::Improved, 16),
+		stage("field_911_l", NoiseKind::Improved, 16),
+		stage("field_910_m", NoiseKind::Improved, 8),
+		stage("field_909_n", NoiseKind::Improved, 4),
+		stage("field_908_o", NoiseKind::Improved, 4),
+		stage("field_922_a", NoiseKind::Improved, 10),
+		stage("field_921_b", NoiseKind::Improved, 16),
+		stage("mobSpawnerNoise", NoiseKind::Improved, 8)
+	]
+};
+
+/// `ChunkGeneratorOverworld` of 1.12.
+pub const OVERWORLD_1_12: ConsumptionPlan = ConsumptionPlan {
+	name: "ChunkGeneratorOverworld",
+	stages: &[
+		stage("minLimitPerlinNoise", NoiseKind::Improved, 16),
+		stage("maxLimitPerlinNoise", NoiseKind::Improved, 16),
+		stage("mainPerlinNoise", NoiseKind::Improved, 8),
+		stage("surfaceNoise", NoiseKind::Simplex, 4),
+		stage("scaleNoise", NoiseKind::Improved, 10),
+		stage("depthNoise", NoiseKind::Improved, 16),
+		stage("forestNoise", NoiseKind::Improved, 8)
+	]
+};
+
+/// `ChunkGeneratorHell` of 1.12.
+pub const NETHER_1_12: ConsumptionPlan = ConsumptionPlan {
+	name: "ChunkGeneratorHell",
+	stages: &[
+		stage("lperlinNoise1", NoiseKind::Improved, 16),
+		stage("lperlinNoise2", NoiseKind::Improved, 16),
+		stage("perlinNoise1", NoiseKind::Improved, 8),
+		stage("slowsandGravelNoiseGen", NoiseKind::Improved, 4),
+		stage("netherrackExculsivityNoiseGen", NoiseKind::Improved, 4),
+		stage("scaleNoise", NoiseKind::Improved, 10),
+		stage("depthNoise", NoiseKind::Improved, 16)
+	]
+};
+
+/// `ChunkGeneratorEnd` of 1.12.
+pub const END_1_12: ConsumptionPlan = ConsumptionPlan {
+	name: "ChunkGeneratorEnd",
+	stages: &[
+		stage("lperlinNoise1", NoiseKind::Improved, 16),
+		stage("lperlinNoise2", NoiseKind::Improved, 16),
+		stage("perlinNoise1", NoiseKind::Improved, 8),
+		stage("noiseGen5", NoiseKind::Improved, 10),
+		stage("noiseGen6", NoiseKind::Improved, 16),
+		stage("islandNoise", NoiseKind::Simplex, 1)
+	]
+};
+
+impl ConsumptionPlan {
+	/// Returns the total number of octaves constructed by the chunk generator.
+	pub fn octaves(&self) -> u32 {
+		self.stages.iter().map(|stage| stage.octaves).sum()
+	}
+
+	/// Returns the index of the stage with the given name.
+	pub fn position(&self, name: &str) -> Option<usize> {
+		self.stages.iter().position(|stage| stage.name == name)
+	}
+
+	/// Returns the generator that the chunk generator constructs stage `stage` from, that is, a generator seeded with
+	/// `world_seed` advanced past all of the octaves of the earlier stages. Passing the number of stages returns the
+	/// generator as it is left after construction.
+	///
+	/// Because the bounded draws of each octave can reject values, the octaves have to be constructed rather than
+	/// skipped over with a fixed number of steps.
+	///
+	/// # Panics
+	/// If `stage` is larger than the number of stages, the function panics.
+	pub fn positioned(&self, world_seed: u64, stage: usize) -> Random {
+		let mut random = Random::new(world_seed);
+
+		for stage in &self.stages[..stage] {
+			for _ in 0..stage.octaves {
+				setup(&mut random, Random::next_i32_bound);
+			}
+		}
+
+		random
+	}
+}
+
+/// Skips an unused octave the way the game does, with `consume_count(262)`.
+pub fn skip_octave<R: RandomSource>(random: &mut R) {
+	random.consume_count(SKIPPED_OCTAVE_STEPS)
+}
+
+/// Skips `count` unused octaves, see `skip_octave`.
+pub fn skip_octaves<R: RandomSource>(random: &mut R, count: u64) {
+	for _ in 0..count {
+		skip_octave(random);
+	}
+}
+
+/// Reduces a coordinate to within half a period of zero, like `PerlinNoise.wrap`, keeping the inputs of the octaves
+/// small enough to retain precision.
+pub fn wrap(value: f64) -> f64 {
+	value - lfloor(value / WRAP + 0.5) as f64 * WRAP
+}
+
+/// Draws the offsets and permutation of an octave, taking the bounded draws from `bounded`.
+fn setup<R: RandomSource>(random: &mut R, mut bounded: impl FnMut(&mut R, i32) -> i32) -> ([f64; 3], [u8; 256]) {
+	let xo = random.next_f64() * 256.0;
+	let yo = random.next_f64() * 256.0;
+	let zo = random.next_f64() * 256.0;
+
+	let mut permutation = [0; 256];
+
+	for (index, entry) in permutation.iter_mut().enumerate() {
+		*entry = index as u8;
+	}
+
+	for index in 0..256 {
+		let offset = bounded(random, 256 - index as i32);
+		permutation.swap(index, index + offset as usize);
+	}
+
+	([xo, yo, zo], permutation)
+}
+
+/// Buffer for the name of an octave, long enough for `octave_` followed by any `i32`
+struct OctaveName {
+	bytes: [u8; 18],
+	len: usize
+}
+
+impl OctaveName {
+	fn new() -> Self {
+		OctaveName { bytes: [0; 18], len: 0 }
+	}
+
+	fn push_str(&mut self, text: &str) {
+		self.bytes[self.len..self.len + text.len()].copy_from_slice(text.as_bytes());
+		self.len += text.len();
+	}
+
+	fn push_i32(&mut self, value: i32) {
+		if value < 0 {
+			self.push_str("-");
+		}
+
+		let mut digits = [0; 10];
+		let mut remaining = value.unsigned_abs();
+		let mut count = 0;
+
+		loop {
+			digits[count] = b'0' + (remaining % 10) as u8;
+			remaining /= 10;
+			count += 1;
+
+			if remaining == 0 {
+				break;
+			}
+		}
+
+		for &digit in digits[..count].iter().rev() {
+			self.bytes[self.len] = digit;
+			self.len += 1;
+		}
+	}
+
+	fn as_str(&self) -> &str {
+		core::str::from_utf8(&self.bytes[..self.len]).expect("Octave names are ASCII")
+	}
+}
+
+/// Contribution of a simplex corner with the given gradient, falling off to zero at a squared distance of `radius`.
+fn corner(gradient: i32, x: f64, y: f64, z: f64, radius: f64) -> f64 {
+	let falloff = radius - x * x - y * y - z * z;
+
+	if falloff < 0.0 {
+		0.0
+	} else {
+		let falloff = falloff * falloff;
+
+		falloff * falloff * dot(GRADIENT[gradient as usize], x, y, z)
+	}
+}
+
+fn dot(gradient: [i32; 3], x: f64, y: f64, z: f64) -> f64 {
+	gradient[0] as f64 * x + gradient[1] as f64 * y + gradient[2] as f64 * z
+}
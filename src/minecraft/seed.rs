@@ -0,0 +1,61 @@
+//! World seeds as players enter them, and the structure seeds they share.
+//!
+//! Structure placement only depends on the low 48 bits of the world seed, since it goes through `java.util.Random`,
+//! so the 2^16 world seeds sharing those bits, the sister seeds, have the same structures with different biomes. A
+//! world created without a seed gets `new Random().nextLong()`, which can only produce 2^48 of the 2^64 seeds: about
+//! one sister seed of each structure seed, found by `next_long_seeds`.
+
+use builder;
+use minecraft::sha256;
+use {JavaLcg, MASK};
+
+/// Returns the seed of a world whose seed field contains `text`, following `WorldOptions.parseSeed`. Surrounding
+/// whitespace is trimmed, text that parses as a `long` is used directly, and anything else is hashed with
+/// `String.hashCode()`. Empty text gives None, for which the game picks a random seed.
+pub fn text_seed(text: &str) -> Option<i64> {
+	builder::text_seed(text)
+}
+
+/// Returns the structure seed of a world seed, its low 48 bits.
+pub const fn structure_seed(world_seed: i64) -> i64 {
+	world_seed & MASK as i64
+}
+
+/// Returns the hashed seed of a world, as computed by `BiomeManager.obfuscateSeed` since 1.15: the first 8 bytes of
+/// the SHA-256 digest of the world seed, both in little endian like Guava's `Hashing.sha256().hashLong(seed).asLong()`.
+/// Biome zoom offsets its sample positions with it, and the server sends it to clients, which do not know the seed.
+pub fn hashed_seed(world_seed: i64) -> i64 {
+	let digest = sha256::digest(&world_seed.to_le_bytes());
+	let mut bytes = [0; 8];
+	bytes.copy_from_slice(&digest[..8]);
+
+	i64::from_le_bytes(bytes)
+}
+
+/// Returns the 2^16 world seeds with the given structure seed, ordered by their upper 16 bits. The structure seed
+/// itself comes first. Bits of `structure_seed` above the low 48 are ignored.
+pub fn sister_seeds(structure_seed: i64) -> impl Iterator<Item = i64> {
+	(0..1i64 << 16).map(move |upper| (upper << 48) | (structure_seed & MASK as i64))
+}
+
+/// Returns the world seeds with the given structure seed that `nextLong()` can return, in no particular order.
+/// These are the candidates for a world created without entering a seed; most structure seeds have none, one or two.
+pub fn next_long_seeds(structure_seed: i64) -> impl Iterator<Item = i64> {
+	let structure_seed = structure_seed & MASK as i64;
+
+	// The low int of nextLong() is the second draw, which fixes the top 32 bits of its state
+	let low = structure_seed as i32;
+
+	(0..1u64 << 16).filter_map(move |bits| {
+		let mut lcg = JavaLcg::new(((low as u32 as u64) << 16) | bits);
+		let high = (lcg.previous_state() >> 16) as i32;
+
+		let seed = ((high as i64) << 32).wrapping_add(low as i64);
+
+		if seed & MASK as i64 == structure_seed {
+			Some(seed)
+		} else {
+			None
+		}
+	})
+}
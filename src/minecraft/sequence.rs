@@ -0,0 +1,117 @@
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::string::String;
+
+use minecraft::{md5, XoroshiroRandomSource};
+use mix::{mix_stafford13, GOLDEN_RATIO_64, SILVER_RATIO_64};
+
+/// Returns the unmixed 128-bit seed for a sequence id, `RandomSupport.seedFromHashOf`: the MD5 digest of the id read
+/// as two big endian words.
+pub fn seed_for_key(id: &str) -> (u64, u64) {
+	let digest = md5::digest(id.as_bytes());
+
+	let mut lo = [0; 8];
+	let mut hi = [0; 8];
+
+	lo.copy_from_slice(&digest[..8]);
+	hi.copy_from_slice(&digest[8..]);
+
+	(u64::from_be_bytes(lo), u64::from_be_bytes(hi))
+}
+
+/// A `RandomSequence` from Minecraft 1.20: a xoroshiro source derived from a seed and, optionally, the
+/// resource location naming the sequence, such as `minecraft:chests/simple_dungeon`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RandomSequence {
+	random: XoroshiroRandomSource
+}
+
+impl RandomSequence {
+	/// Creates the sequence for `id` in a world with the given seed. The id must include its namespace.
+	pub fn new(world_seed: i64, id: Option<&str>) -> Self {
+		// The seed is expanded as by RandomSupport.upgradeSeedTo128bitUnmixed, combined with the id, then mixed
+		let lo = world_seed as u64 ^ SILVER_RATIO_64;
+		let hi = lo.wrapping_add(GOLDEN_RATIO_64);
+
+		let (lo, hi) = match id {
+			Some(id) => {
+				let (key_lo, key_hi) = seed_for_key(id);
+
+				(lo ^ key_lo, hi ^ key_hi)
+			},
+			None => (lo, hi)
+		};
+
+		RandomSequence {
+			random: XoroshiroRandomSource::from_state(mix_stafford13(lo), mix_stafford13(hi))
+		}
+	}
+
+	/// Returns the source of the sequence.
+	pub fn random(&mut self) -> &mut XoroshiroRandomSource {
+		&mut self.random
+	}
+}
+
+/// The `RandomSequences` of a world, creating each sequence when it is first requested.
+///
+/// The options correspond to the arguments of `/random reset` added in 1.20.2. By default the world seed and the
+/// sequence id are both included and the salt is 0, which is the behavior of 1.20 and 1.20.1.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct RandomSequences {
+	world_seed: i64,
+	salt: i32,
+	include_world_seed: bool,
+	include_sequence_id: bool,
+	sequences: HashMap<String, RandomSequence>
+}
+
+#[cfg(feature = "std")]
+impl RandomSequences {
+	pub fn new(world_seed: i64) -> Self {
+		RandomSequences {
+			world_seed,
+			salt: 0,
+			include_world_seed: true,
+			include_sequence_id: true,
+			sequences: HashMap::new()
+		}
+	}
+
+	/// Sets the options used to create sequences from now on. Sequences that already exist are not affected.
+	pub fn set_options(&mut self, salt: i32, include_world_seed: bool, include_sequence_id: bool) {
+		self.salt = salt;
+		self.include_world_seed = include_world_seed;
+		self.include_sequence_id = include_sequence_id;
+	}
+
+	/// Creates the sequence for `id` with explicit options, as `/random reset` does.
+	pub fn create(&self, id: &str, salt: i32, include_world_seed: bool, include_sequence_id: bool) -> RandomSequence {
+		let seed = if include_world_seed { self.world_seed } else { 0 } ^ salt as i64;
+
+		RandomSequence::new(seed, if include_sequence_id { Some(id) } else { None })
+	}
+
+	/// Returns the source of the sequence `id`, creating the sequence with the current options if needed.
+	pub fn get(&mut self, id: &str) -> &mut XoroshiroRandomSource {
+		if !self.sequences.contains_key(id) {
+			let sequence = self.create(id, self.salt, self.include_world_seed, self.include_sequence_id);
+			self.sequences.insert(id.into(), sequence);
+		}
+
+		self.sequences.get_mut(id).expect("Sequence was just inserted").random()
+	}
+
+	/// Replaces the sequence `id` with a fresh one created with explicit options, like `/random reset`.
+	pub fn reset(&mut self, id: &str, salt: i32, include_world_seed: bool, include_sequence_id: bool) {
+		let sequence = self.create(id, salt, include_world_seed, include_sequence_id);
+		self.sequences.insert(id.into(), sequence);
+	}
+
+	/// Removes every sequence, so that they are recreated from scratch when next requested.
+	pub fn clear(&mut self) {
+		self.sequences.clear();
+	}
+}
@@ -0,0 +1,57 @@
+//! Slime chunks in both editions of Minecraft.
+//!
+//! Java Edition derives slime chunks from the world seed with `java.util.Random`. Bedrock Edition ignores the world
+//! seed entirely: the chunk coordinates seed a Mersenne Twister (MT19937), and the chunk holds slimes if the first
+//! output is divisible by 10.
+
+use Random;
+
+/// Salt that Java Edition mixes into the seed of each chunk
+const SALT: i64 = 987234911;
+
+/// Returns the seed of the generator deciding whether a Java Edition chunk is a slime chunk, as set by
+/// `WorldgenRandom.seedSlimeChunk`. Some of the products use 32-bit arithmetic, so they wrap for distant chunks.
+pub const fn slime_chunk_seed(world_seed: i64, chunk_x: i32, chunk_z: i32) -> i64 {
+	world_seed
+		.wrapping_add(chunk_x.wrapping_mul(chunk_x).wrapping_mul(4987142) as i64)
+		.wrapping_add(chunk_x.wrapping_mul(5947611) as i64)
+		.wrapping_add((chunk_z.wrapping_mul(chunk_z) as i64).wrapping_mul(4392871))
+		.wrapping_add(chunk_z.wrapping_mul(389711) as i64)
+		^ SALT
+}
+
+/// Returns true if slimes can spawn in the given chunk of a Java Edition world, below y level 40.
+pub const fn is_slime_chunk(world_seed: i64, chunk_x: i32, chunk_z: i32) -> bool {
+	Random::new(slime_chunk_seed(world_seed, chunk_x, chunk_z) as u64).next_i32_bound(10) == 0
+}
+
+/// Returns true if slimes can spawn in the given chunk of a Bedrock Edition world, below y level 40. The result is
+/// the same for every world.
+pub const fn is_slime_chunk_bedrock(chunk_x: i32, chunk_z: i32) -> bool {
+	mt19937_first((chunk_x as u32).wrapping_mul(0x1F1F1F1F) ^ chunk_z as u32).is_multiple_of(10)
+}
+
+/// Returns the first output of an MT19937 generator seeded with `seed`, like `std::mt19937(seed)()`.
+///
+/// The first output only depends on the first 398 words of the state, so the rest are never initialized.
+const fn mt19937_first(seed: u32) -> u32 {
+	let mut state = [0u32; 398];
+	state[0] = seed;
+
+	let mut index = 1;
+
+	while index < state.len() {
+		let previous = state[index - 1];
+		state[index] = 1812433253u32.wrapping_mul(previous ^ (previous >> 30)).wrapping_add(index as u32);
+
+		index += 1;
+	}
+
+	let y = (state[0] & 0x80000000) | (state[1] & 0x7FFFFFFF);
+	let mut y = state[397] ^ (y >> 1) ^ if y & 1 != 0 { 0x9908B0DF } else { 0 };
+
+	y ^= y >> 11;
+	y ^= (y << 7) & 0x9D2C5680;
+	y ^= (y << 15) & 0xEFC60000;
+	y ^ (y >> 18)
+}
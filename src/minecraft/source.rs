@@ -0,0 +1,384 @@
+//! The `RandomSource` interface that Minecraft 1.19 and later uses for all of its randomness, with its legacy and
+//! xoroshiro backends.
+//!
+//! The legacy backend, `LegacyRandomSource`, draws exactly like `java.util.Random`, so it is implemented by `Random`
+//! itself. The xoroshiro backend wraps `Xoroshiro128PlusPlus`, but derives its draws differently from the methods of
+//! `Xoroshiro128PlusPlus`, which follow the JDK: for example, `next_i32` takes the low half of `nextLong()` and
+//! bounded integers use Lemire's method.
+
+use core::hash::{Hash, Hasher};
+
+use builder::java_string_hash;
+use minecraft::seed_for_key;
+use strictmath;
+use {batch, float, Random, MASK};
+use Xoroshiro128PlusPlus;
+
+/// The generator behind Minecraft's `LegacyRandomSource`, which draws exactly like `java.util.Random`.
+pub type LegacyRandomSource = Random;
+
+/// A source of randomness like Minecraft's `RandomSource`, letting emulation code run against either backend.
+pub trait RandomSource {
+	/// The factory returned by `fork_positional`.
+	type Positional: PositionalRandomFactory<Source = Self>;
+
+	/// Creates an independent source seeded from this one, like `fork()`.
+	fn fork(&mut self) -> Self where Self: Sized;
+
+	/// Creates a factory for sources derived from positions and names, seeded from this source, like
+	/// `forkPositional()`.
+	fn fork_positional(&mut self) -> Self::Positional;
+
+	/// Reseeds the source and discards any pending gaussian, like `setSeed(seed)`.
+	fn set_seed(&mut self, seed: i64);
+
+	/// Returns a uniformly distributed signed 32-bit integer, like `nextInt()`.
+	fn next_i32(&mut self) -> i32;
+
+	/// Returns a uniformly distributed integer in [0, max), like `nextInt(max)`.
+	///
+	/// # Panics
+	/// If `max` is not positive, the function panics.
+	fn next_i32_bound(&mut self, max: i32) -> i32;
+
+	/// Returns a uniformly distributed integer in [min, max], like `nextIntBetweenInclusive(min, max)`.
+	///
+	/// # Panics
+	/// If `max` is less than `min`, or the range holds more than `i32::MAX` values, the function panics.
+	fn next_i32_between_inclusive(&mut self, min: i32, max: i32) -> i32 {
+		self.next_i32_bound(max.wrapping_sub(min).wrapping_add(1)).wrapping_add(min)
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer, like `nextLong()`.
+	fn next_i64(&mut self) -> i64;
+
+	/// Returns a boolean, like `nextBoolean()`.
+	fn next_bool(&mut self) -> bool;
+
+	/// Returns a uniformly distributed float in [0, 1), like `nextFloat()`.
+	fn next_f32(&mut self) -> f32;
+
+	/// Returns a uniformly distributed double in [0, 1), like `nextDouble()`.
+	fn next_f64(&mut self) -> f64;
+
+	/// Returns a normally distributed double with mean 0 and standard deviation 1, like `nextGaussian()`. Both
+	/// backends use the Marsaglia polar method, caching the second value of each pair.
+	fn next_gaussian(&mut self) -> f64;
+
+	/// Returns a double with a triangular distribution in [center - width, center + width], like
+	/// `triangle(center, width)`.
+	fn triangle(&mut self, center: f64, width: f64) -> f64 {
+		let a = self.next_f64();
+		let b = self.next_f64();
+
+		center + width * (a - b)
+	}
+
+	/// Discards `count` draws of `next_i32`, like `consumeCount(count)`.
+	fn consume_count(&mut self, count: u32) {
+		for _ in 0..count {
+			self.next_i32();
+		}
+	}
+}
+
+/// A factory creating sources from positions and names, like Minecraft's `PositionalRandomFactory`.
+#[allow(clippy::wrong_self_convention)]
+pub trait PositionalRandomFactory {
+	/// The sources created by the factory.
+	type Source: RandomSource;
+
+	/// Creates the source for a block position, like `at(x, y, z)`.
+	fn at(&self, x: i32, y: i32, z: i32) -> Self::Source;
+
+	/// Creates the source for a name, such as `minecraft:ore_gold`, like `fromHashOf(name)`.
+	fn from_hash_of(&self, name: &str) -> Self::Source;
+
+	/// Creates the source for a seed, like `fromSeed(seed)`.
+	fn from_seed(&self, seed: i64) -> Self::Source;
+}
+
+/// Scrambles a block position into a seed, like `Mth.getSeed(x, y, z)`.
+pub const fn position_seed(x: i32, y: i32, z: i32) -> i64 {
+	let seed = (x.wrapping_mul(3129871) as i64) ^ (z as i64).wrapping_mul(116129781) ^ y as i64;
+	let seed = seed.wrapping_mul(seed).wrapping_mul(42317861).wrapping_add(seed.wrapping_mul(11));
+
+	seed >> 16
+}
+
+/// Draws a gaussian with the Marsaglia polar method like `MarsagliaPolarGaussian`, caching the second value.
+fn marsaglia_polar(next_next_gaussian: &mut Option<f64>, mut next_f64: impl FnMut() -> f64) -> f64 {
+	if let Some(gaussian) = next_next_gaussian.take() {
+		return gaussian;
+	}
+
+	loop {
+		let x = 2.0 * next_f64() - 1.0;
+		let y = 2.0 * next_f64() - 1.0;
+		let s = x * x + y * y;
+
+		if s < 1.0 && s != 0.0 {
+			let multiplier = strictmath::sqrt(-2.0 * strictmath::log(s) / s);
+			*next_next_gaussian = Some(y * multiplier);
+
+			return x * multiplier;
+		}
+	}
+}
+
+impl RandomSource for Random {
+	type Positional = LegacyPositionalRandomFactory;
+
+	fn fork(&mut self) -> Self {
+		Random::new(Random::next_i64(self) as u64)
+	}
+
+	fn fork_positional(&mut self) -> LegacyPositionalRandomFactory {
+		LegacyPositionalRandomFactory { seed: Random::next_i64(self) }
+	}
+
+	fn set_seed(&mut self, seed: i64) {
+		Random::set_seed(self, seed as u64)
+	}
+
+	fn next_i32(&mut self) -> i32 {
+		Random::next_i32(self)
+	}
+
+	fn next_i32_bound(&mut self, max: i32) -> i32 {
+		Random::next_i32_bound(self, max)
+	}
+
+	fn next_i64(&mut self) -> i64 {
+		Random::next_i64(self)
+	}
+
+	fn next_bool(&mut self) -> bool {
+		Random::next_bool(self)
+	}
+
+	fn next_f32(&mut self) -> f32 {
+		Random::next_f32(self)
+	}
+
+	fn next_f64(&mut self) -> f64 {
+		Random::next_f64(self)
+	}
+
+	fn next_gaussian(&mut self) -> f64 {
+		Random::next_gaussian(self)
+	}
+
+	/// Steps the generator `count` times at once. Any pending gaussian is kept.
+	fn consume_count(&mut self, count: u32) {
+		let (multiplier, increment) = batch::affine_steps(count as u64);
+
+		self.state = self.state.wrapping_mul(multiplier).wrapping_add(increment) & MASK;
+	}
+}
+
+/// The factory of `LegacyRandomSource`, like `LegacyRandomSource.LegacyPositionalRandomFactory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LegacyPositionalRandomFactory {
+	seed: i64
+}
+
+impl LegacyPositionalRandomFactory {
+	pub const fn new(seed: i64) -> Self {
+		LegacyPositionalRandomFactory { seed }
+	}
+
+	/// Returns the seed that created sources are derived from.
+	pub const fn seed(&self) -> i64 {
+		self.seed
+	}
+}
+
+impl PositionalRandomFactory for LegacyPositionalRandomFactory {
+	type Source = Random;
+
+	fn at(&self, x: i32, y: i32, z: i32) -> Random {
+		Random::new((position_seed(x, y, z) ^ self.seed) as u64)
+	}
+
+	fn from_hash_of(&self, name: &str) -> Random {
+		Random::new((java_string_hash(name) as i64 ^ self.seed) as u64)
+	}
+
+	fn from_seed(&self, seed: i64) -> Random {
+		Random::new(seed as u64)
+	}
+}
+
+/// Minecraft's `XoroshiroRandomSource`, used for world generation since 1.18.
+#[derive(Debug, Clone)]
+pub struct XoroshiroRandomSource {
+	random: Xoroshiro128PlusPlus,
+	next_next_gaussian: Option<f64>
+}
+
+impl PartialEq for XoroshiroRandomSource {
+	fn eq(&self, other: &XoroshiroRandomSource) -> bool {
+		self.random == other.random && self.next_next_gaussian.map(f64::to_bits) == other.next_next_gaussian.map(f64::to_bits)
+	}
+}
+
+impl Eq for XoroshiroRandomSource {}
+
+impl Hash for XoroshiroRandomSource {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.random.hash(state);
+		self.next_next_gaussian.map(f64::to_bits).hash(state);
+	}
+}
+
+impl XoroshiroRandomSource {
+	/// Creates a source by expanding `seed` to 128 bits, like `new XoroshiroRandomSource(seed)`.
+	pub const fn new(seed: i64) -> Self {
+		XoroshiroRandomSource::from_random(Xoroshiro128PlusPlus::new(seed as u64))
+	}
+
+	/// Creates a source with the raw state `(lo, hi)`, like `new XoroshiroRandomSource(lo, hi)`.
+	pub const fn from_state(lo: u64, hi: u64) -> Self {
+		XoroshiroRandomSource::from_random(Xoroshiro128PlusPlus::from_state(lo, hi))
+	}
+
+	const fn from_random(random: Xoroshiro128PlusPlus) -> Self {
+		XoroshiroRandomSource { random, next_next_gaussian: None }
+	}
+
+	/// Returns the underlying generator.
+	pub fn random(&self) -> &Xoroshiro128PlusPlus {
+		&self.random
+	}
+
+	/// Returns the high `bits` bits of `nextLong()`, like `nextBits(bits)`.
+	///
+	/// # Panics
+	/// If `bits` is 0 or larger than 64, the function panics.
+	pub fn next_bits(&mut self, bits: u8) -> u64 {
+		if bits == 0 || bits > 64 {
+			panic!("Bits must be in the range [1, 64]")
+		}
+
+		self.random.next_u64() >> (64 - bits)
+	}
+}
+
+impl RandomSource for XoroshiroRandomSource {
+	type Positional = XoroshiroPositionalRandomFactory;
+
+	fn fork(&mut self) -> Self {
+		let lo = self.random.next_u64();
+		let hi = self.random.next_u64();
+
+		XoroshiroRandomSource::from_state(lo, hi)
+	}
+
+	fn fork_positional(&mut self) -> XoroshiroPositionalRandomFactory {
+		let lo = self.random.next_u64();
+		let hi = self.random.next_u64();
+
+		XoroshiroPositionalRandomFactory { lo, hi }
+	}
+
+	fn set_seed(&mut self, seed: i64) {
+		*self = XoroshiroRandomSource::new(seed);
+	}
+
+	fn next_i32(&mut self) -> i32 {
+		self.random.next_u64() as i32
+	}
+
+	fn next_i32_bound(&mut self, max: i32) -> i32 {
+		if max <= 0 {
+			panic!("Maximum must be > 0")
+		}
+
+		let max = max as u64;
+		let mut product = self.next_i32() as u32 as u64 * max;
+		let mut low = product & 0xFFFFFFFF;
+
+		if low < max {
+			let threshold = (max as u32).wrapping_neg() % max as u32;
+
+			while low < threshold as u64 {
+				product = self.next_i32() as u32 as u64 * max;
+				low = product & 0xFFFFFFFF;
+			}
+		}
+
+		(product >> 32) as i32
+	}
+
+	fn next_i64(&mut self) -> i64 {
+		self.random.next_i64()
+	}
+
+	fn next_bool(&mut self) -> bool {
+		self.random.next_u64() & 1 != 0
+	}
+
+	fn next_f32(&mut self) -> f32 {
+		float::unit_f32(self.next_bits(24) as u32)
+	}
+
+	fn next_f64(&mut self) -> f64 {
+		next_f64(&mut self.random)
+	}
+
+	/// Like the legacy backend, this uses the Marsaglia polar method on top of `next_f64`, rather than the ziggurat
+	/// method of `Xoroshiro128PlusPlus::next_gaussian`.
+	fn next_gaussian(&mut self) -> f64 {
+		let random = &mut self.random;
+
+		marsaglia_polar(&mut self.next_next_gaussian, || next_f64(random))
+	}
+
+	fn consume_count(&mut self, count: u32) {
+		for _ in 0..count {
+			self.random.next_u64();
+		}
+	}
+}
+
+/// `XoroshiroRandomSource.nextDouble()`: the high 53 bits of `nextLong()`, scaled to [0, 1)
+fn next_f64(random: &mut Xoroshiro128PlusPlus) -> f64 {
+	float::unit_f64(random.next_u64() >> 11)
+}
+
+/// The factory of `XoroshiroRandomSource`, like `XoroshiroRandomSource.XoroshiroPositionalRandomFactory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XoroshiroPositionalRandomFactory {
+	lo: u64,
+	hi: u64
+}
+
+impl XoroshiroPositionalRandomFactory {
+	pub const fn new(lo: u64, hi: u64) -> Self {
+		XoroshiroPositionalRandomFactory { lo, hi }
+	}
+
+	/// Returns the 128-bit seed `(lo, hi)` that created sources are derived from.
+	pub const fn seed(&self) -> (u64, u64) {
+		(self.lo, self.hi)
+	}
+}
+
+impl PositionalRandomFactory for XoroshiroPositionalRandomFactory {
+	type Source = XoroshiroRandomSource;
+
+	fn at(&self, x: i32, y: i32, z: i32) -> XoroshiroRandomSource {
+		XoroshiroRandomSource::from_state(position_seed(x, y, z) as u64 ^ self.lo, self.hi)
+	}
+
+	fn from_hash_of(&self, name: &str) -> XoroshiroRandomSource {
+		let (lo, hi) = seed_for_key(name);
+
+		XoroshiroRandomSource::from_state(lo ^ self.lo, hi ^ self.hi)
+	}
+
+	fn from_seed(&self, seed: i64) -> XoroshiroRandomSource {
+		XoroshiroRandomSource::from_state(seed as u64 ^ self.lo, seed as u64 ^ self.hi)
+	}
+}
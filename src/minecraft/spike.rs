@@ -0,0 +1,63 @@
+//! The obsidian spikes around the exit portal of the End, as laid out by `SpikeFeature`.
+//!
+//! The layout only depends on the low 16 bits of the first `nextLong()` of a generator seeded with the world seed, so
+//! there are only 65536 distinct layouts. The game shuffles the indices 0 to 9 with `Collections.shuffle`, then places
+//! spike `i` at angle `i` around a circle of radius 42, with a size decided by the shuffled index. Flooring matters at
+//! the angles where the sine or cosine is a tiny negative number.
+//!
+//! The game uses `Math.sin` and `Math.cos`, which may differ from `StrictMath` by an ulp, and on HotSpot do for one of
+//! the cosines. The port uses `strictmath` anyway, since the coordinates are floored and no difference that small can
+//! change them: every product other than 42 or -42 lies at least 0.02 from an integer, the two sines near zero are
+//! around 1e-14 and keep their sign, and the cosines at those angles are exactly 1.0 and -1.0 either way.
+
+use core::f64::consts::PI;
+
+use minecraft::mth::floor;
+use strictmath;
+use Random;
+
+/// One obsidian spike, like `SpikeFeature.EndSpike`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EndSpike {
+	/// X coordinate of the center of the spike
+	pub center_x: i32,
+	/// Z coordinate of the center of the spike
+	pub center_z: i32,
+	/// Radius of the spike, from 2 to 5
+	pub radius: i32,
+	/// Y coordinate of the top of the spike, from 76 to 103, where the end crystal sits
+	pub height: i32,
+	/// Whether the end crystal is surrounded by iron bars
+	pub guarded: bool
+}
+
+/// Returns the key that the spike layout of a world is derived from, `new Random(seed).nextLong() & 65535`.
+pub const fn end_spike_key(world_seed: i64) -> u16 {
+	Random::new(world_seed as u64).next_i64() as u16
+}
+
+/// Returns the spikes of a world, in the order the game generates them.
+pub fn end_spikes(world_seed: i64) -> [EndSpike; 10] {
+	end_spikes_for_key(end_spike_key(world_seed))
+}
+
+/// Returns the spike layout for a key returned by `end_spike_key`.
+pub fn end_spikes_for_key(key: u16) -> [EndSpike; 10] {
+	let mut sizes = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+	Random::new(key as u64).shuffle(&mut sizes);
+
+	let mut index = 0;
+
+	sizes.map(|size| {
+		let angle = 2.0 * (-PI + (PI / 10.0) * index as f64);
+		index += 1;
+
+		EndSpike {
+			center_x: floor(42.0 * strictmath::cos(angle)),
+			center_z: floor(42.0 * strictmath::sin(angle)),
+			radius: 2 + size / 3,
+			height: 76 + size * 3,
+			guarded: size == 1 || size == 2
+		}
+	})
+}
@@ -0,0 +1,175 @@
+use minecraft::McVersion;
+use Random;
+
+/// Returns the seed of a structure region, as set by `WorldgenRandom.setLargeFeatureWithSalt`:
+/// `region_x * 341873128712 + region_z * 132897987541 + world_seed + salt`, with wrapping arithmetic.
+pub const fn region_seed(world_seed: i64, region_x: i32, region_z: i32, salt: i32) -> i64 {
+	(region_x as i64).wrapping_mul(341873128712)
+		.wrapping_add((region_z as i64).wrapping_mul(132897987541))
+		.wrapping_add(world_seed)
+		.wrapping_add(salt as i64)
+}
+
+/// Returns the generator seeded with `region_seed`, from which structure placements draw their offsets.
+pub const fn region_random(world_seed: i64, region_x: i32, region_z: i32, salt: i32) -> Random {
+	Random::new(region_seed(world_seed, region_x, region_z, salt) as u64)
+}
+
+/// How a random spread placement draws the offset of a structure within its region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpreadType {
+	/// `nextInt(limit)`
+	Linear,
+	/// `(nextInt(limit) + nextInt(limit)) / 2`, favoring the center of the region
+	Triangular
+}
+
+impl SpreadType {
+	const fn evaluate(self, random: &mut Random, limit: i32) -> i32 {
+		match self {
+			SpreadType::Linear => random.next_i32_bound(limit),
+			SpreadType::Triangular => (random.next_i32_bound(limit) + random.next_i32_bound(limit)) / 2
+		}
+	}
+}
+
+/// Returns the chunk at which a `RandomSpreadStructurePlacement` attempts to place a structure in the region
+/// containing the chunk (`chunk_x`, `chunk_z`). Regions are `spacing` chunks wide, and structures keep at least
+/// `separation` chunks from the region edge on the positive sides.
+///
+/// # Panics
+/// If `spacing` is not greater than `separation`, the function panics.
+pub const fn random_spread_chunk(
+	world_seed: i64, chunk_x: i32, chunk_z: i32, spacing: i32, separation: i32, salt: i32, spread: SpreadType
+) -> (i32, i32) {
+	if spacing <= separation {
+		panic!("Spacing must be greater than separation")
+	}
+
+	let region_x = chunk_x.div_euclid(spacing);
+	let region_z = chunk_z.div_euclid(spacing);
+
+	let mut random = region_random(world_seed, region_x, region_z, salt);
+	let limit = spacing - separation;
+
+	let offset_x = spread.evaluate(&mut random, limit);
+	let offset_z = spread.evaluate(&mut random, limit);
+
+	(region_x * spacing + offset_x, region_z * spacing + offset_z)
+}
+
+/// The parameters of a `RandomSpreadStructurePlacement`, as passed to `random_spread_chunk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StructurePlacement {
+	/// Width of a region in chunks
+	pub spacing: i32,
+	/// Minimum distance in chunks between the structure and the positive edges of its region
+	pub separation: i32,
+	/// Salt added to the region seed
+	pub salt: i32,
+	pub spread: SpreadType
+}
+
+impl StructurePlacement {
+	const fn new(spacing: i32, separation: i32, salt: i32, spread: SpreadType) -> Self {
+		StructurePlacement { spacing, separation, salt, spread }
+	}
+
+	/// Returns the chunk at which the structure is attempted in the region containing the chunk (`chunk_x`,
+	/// `chunk_z`), see `random_spread_chunk`.
+	pub const fn chunk(self, world_seed: i64, chunk_x: i32, chunk_z: i32) -> (i32, i32) {
+		random_spread_chunk(world_seed, chunk_x, chunk_z, self.spacing, self.separation, self.salt, self.spread)
+	}
+}
+
+/// Vanilla structures placed by random spread, the structures whose attempts are found with `random_spread_chunk`.
+///
+/// The placements only give the chunk at which a structure is attempted. Whether it generates there also depends on
+/// the biome, and for some structures on further checks, such as the extra draw deciding pillager outposts or the
+/// choice between a fortress and a bastion remnant, which are not part of the placement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Structure {
+	Village,
+	/// Before 1.13, the desert pyramid, jungle pyramid, igloo and swamp hut are one scattered feature, chosen by
+	/// biome, with the salt of the desert pyramid.
+	DesertPyramid,
+	JunglePyramid,
+	Igloo,
+	SwampHut,
+	/// Since 1.14
+	PillagerOutpost,
+	OceanMonument,
+	/// Since 1.11
+	WoodlandMansion,
+	/// Since 1.9
+	EndCity,
+	/// Since 1.14, see `Structure::placement`
+	Shipwreck,
+	/// Since 1.14, see `Structure::placement`
+	OceanRuin,
+	/// Since 1.16, in the overworld
+	RuinedPortal,
+	/// Nether fortresses since 1.16, and bastion remnants, which share their regions
+	NetherComplex,
+	/// Since 1.16
+	NetherFossil,
+	/// Since 1.19
+	AncientCity,
+	/// Since 1.20
+	TrailRuins,
+	/// Since 1.21
+	TrialChambers
+}
+
+impl Structure {
+	/// Every structure, in declaration order.
+	pub const ALL: [Structure; 17] = [
+		Structure::Village, Structure::DesertPyramid, Structure::JunglePyramid, Structure::Igloo, Structure::SwampHut,
+		Structure::PillagerOutpost, Structure::OceanMonument, Structure::WoodlandMansion, Structure::EndCity,
+		Structure::Shipwreck, Structure::OceanRuin, Structure::RuinedPortal, Structure::NetherComplex,
+		Structure::NetherFossil, Structure::AncientCity, Structure::TrailRuins, Structure::TrialChambers
+	];
+
+	/// Returns the placement of the structure in the given versions, or None if none of them place it by random
+	/// spread.
+	///
+	/// Before 1.13, the values are the constants of the `MapGen` classes of 1.12.2, and the scattered features share
+	/// one salt. The values of 1.13 through 1.17.1 are the defaults of the chunk generator settings, as listed in
+	/// `StructureSettings.DEFAULTS` of 1.16.2. 1.13 used narrower regions for shipwrecks and ocean ruins, which are not
+	/// covered, and structures are returned for the whole range even where they were only added partway through it.
+	/// From 1.18 on, the values are those of the `structure_set` data of the game, where villages are 34 chunks apart
+	/// instead of 32.
+	pub const fn placement(self, version: McVersion) -> Option<StructurePlacement> {
+		use self::SpreadType::{Linear, Triangular};
+
+		let placement = match (self, version) {
+			(Structure::Village, McVersion::From1_18) => (34, 8, 10387312, Linear),
+			(Structure::Village, _) => (32, 8, 10387312, Linear),
+			(Structure::DesertPyramid, _) => (32, 8, 14357617, Linear),
+			(Structure::JunglePyramid, McVersion::UpTo1_12) => (32, 8, 14357617, Linear),
+			(Structure::JunglePyramid, _) => (32, 8, 14357619, Linear),
+			(Structure::Igloo, McVersion::UpTo1_12) => (32, 8, 14357617, Linear),
+			(Structure::Igloo, _) => (32, 8, 14357618, Linear),
+			(Structure::SwampHut, McVersion::UpTo1_12) => (32, 8, 14357617, Linear),
+			(Structure::SwampHut, _) => (32, 8, 14357620, Linear),
+			(Structure::OceanMonument, _) => (32, 5, 10387313, Triangular),
+			(Structure::WoodlandMansion, _) => (80, 20, 10387319, Triangular),
+			(Structure::EndCity, _) => (20, 11, 10387313, Triangular),
+			(_, McVersion::UpTo1_12) => return None,
+			(Structure::PillagerOutpost, _) => (32, 8, 165745296, Linear),
+			(Structure::Shipwreck, _) => (24, 4, 165745295, Linear),
+			(Structure::OceanRuin, _) => (20, 8, 14357621, Linear),
+			(Structure::RuinedPortal, _) => (40, 15, 34222645, Linear),
+			(Structure::NetherComplex, _) => (27, 4, 30084232, Linear),
+			(Structure::NetherFossil, _) => (2, 1, 14357921, Linear),
+			(_, McVersion::From1_13To1_17) => return None,
+			(Structure::AncientCity, _) => (24, 8, 20083232, Linear),
+			(Structure::TrailRuins, _) => (34, 8, 83469867, Linear),
+			(Structure::TrialChambers, _) => (34, 12, 94251327, Linear)
+		};
+
+		let (spacing, separation, salt, spread) = placement;
+
+		Some(StructurePlacement::new(spacing, separation, salt, spread))
+	}
+}
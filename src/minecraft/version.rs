@@ -0,0 +1,61 @@
+use minecraft::WorldgenRandom;
+
+/// Ranges of Minecraft versions that share the same world generation seeding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum McVersion {
+	/// 1.12.2 and earlier: chunks are populated from a seed derived from chunk coordinates with
+	/// `nextLong() / 2 * 2 + 1` multipliers.
+	UpTo1_12,
+	/// 1.13 through 1.17.1: the decoration seed uses block coordinates and `nextLong() | 1` multipliers, still on top
+	/// of `java.util.Random`.
+	From1_13To1_17,
+	/// 1.18 and later: the decoration seed formula of 1.13, drawn from a `WorldgenRandom` backed by xoroshiro128++.
+	From1_18
+}
+
+impl McVersion {
+	/// Returns true if world generation in this version is backed by xoroshiro128++ instead of `java.util.Random`.
+	pub fn uses_xoroshiro(self) -> bool {
+		self >= McVersion::From1_18
+	}
+
+	/// Returns a generator of the kind used for world generation in this version, seeded with `seed`.
+	pub fn worldgen_random(self, seed: i64) -> WorldgenRandom {
+		if self.uses_xoroshiro() {
+			WorldgenRandom::xoroshiro(seed)
+		} else {
+			WorldgenRandom::legacy(seed)
+		}
+	}
+
+	/// Returns the seed used to populate or decorate the chunk at (`chunk_x`, `chunk_z`).
+	///
+	/// Before 1.13 this is the seed of `ChunkGenerator.populate`. From 1.13 on it is the decoration seed returned by
+	/// `WorldgenRandom.setDecorationSeed`, from which the seeds of individual features are derived.
+	pub fn population_seed(self, world_seed: i64, chunk_x: i32, chunk_z: i32) -> i64 {
+		let mut random = self.worldgen_random(world_seed);
+
+		let (x, z, a, b) = match self {
+			McVersion::UpTo1_12 => {
+				let a = random.next_i64() / 2 * 2 + 1;
+				let b = random.next_i64() / 2 * 2 + 1;
+
+				(chunk_x as i64, chunk_z as i64, a, b)
+			},
+			McVersion::From1_13To1_17 | McVersion::From1_18 => {
+				let a = random.next_i64() | 1;
+				let b = random.next_i64() | 1;
+
+				(chunk_x as i64 * 16, chunk_z as i64 * 16, a, b)
+			}
+		};
+
+		x.wrapping_mul(a).wrapping_add(z.wrapping_mul(b)) ^ world_seed
+	}
+
+	/// Returns the generator that populates or decorates the chunk at (`chunk_x`, `chunk_z`), seeded with
+	/// `population_seed`.
+	pub fn population_random(self, world_seed: i64, chunk_x: i32, chunk_z: i32) -> WorldgenRandom {
+		self.worldgen_random(self.population_seed(world_seed, chunk_x, chunk_z))
+	}
+}
@@ -0,0 +1,87 @@
+use {NextBits, Random, Subclass, Xoroshiro128PlusPlus};
+
+#[derive(Debug, Clone)]
+enum Source {
+	Legacy(Random),
+	Xoroshiro(Xoroshiro128PlusPlus)
+}
+
+impl NextBits for Source {
+	fn next(&mut self, bits: u8) -> i32 {
+		match *self {
+			Source::Legacy(ref mut random) => random.next(bits),
+			Source::Xoroshiro(ref mut random) => (random.next_u64() >> (64 - bits)) as i32
+		}
+	}
+}
+
+/// The `WorldgenRandom` of Minecraft: the derived methods of `java.util.Random` on top of either `Random` itself or,
+/// since 1.18, xoroshiro128++. With xoroshiro128++, `next(bits)` takes the top bits of a full 64-bit output, so a
+/// `next_i64` consumes two outputs.
+#[derive(Debug, Clone)]
+pub struct WorldgenRandom {
+	random: Subclass<Source>
+}
+
+impl WorldgenRandom {
+	/// Creates a generator backed by `java.util.Random`, seeded with `seed`.
+	pub fn legacy(seed: i64) -> Self {
+		WorldgenRandom { random: Subclass::new(Source::Legacy(Random::new(seed as u64))) }
+	}
+
+	/// Creates a generator backed by xoroshiro128++, seeded with `seed` like `XoroshiroRandomSource`.
+	pub fn xoroshiro(seed: i64) -> Self {
+		WorldgenRandom { random: Subclass::new(Source::Xoroshiro(Xoroshiro128PlusPlus::new(seed as u64))) }
+	}
+
+	/// Returns true if the generator is backed by xoroshiro128++.
+	pub fn is_xoroshiro(&self) -> bool {
+		match *self.random.core() {
+			Source::Legacy(_) => false,
+			Source::Xoroshiro(_) => true
+		}
+	}
+
+	/// Reseeds the backing generator, keeping its kind.
+	pub fn set_seed(&mut self, seed: i64) {
+		*self = if self.is_xoroshiro() { WorldgenRandom::xoroshiro(seed) } else { WorldgenRandom::legacy(seed) };
+	}
+
+	/// Returns `bits` random bits, between 1 and 32.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		self.random.next(bits)
+	}
+
+	/// Returns a uniformly distributed signed 32-bit integer.
+	pub fn next_i32(&mut self) -> i32 {
+		self.random.next_i32()
+	}
+
+	/// Returns a random number in the range [0, max), using the algorithm of `java.util.Random`.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		self.random.next_i32_bound(max)
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer, built from two calls to `next(32)`.
+	pub fn next_i64(&mut self) -> i64 {
+		self.random.next_i64()
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false.
+	pub fn next_bool(&mut self) -> bool {
+		self.random.next_bool()
+	}
+
+	/// Returns a f32 uniformly distributed between 0.0 and 1.0.
+	pub fn next_f32(&mut self) -> f32 {
+		self.random.next_f32()
+	}
+
+	/// Returns a f64 uniformly distributed between 0.0 and 1.0.
+	pub fn next_f64(&mut self) -> f64 {
+		self.random.next_f64()
+	}
+}
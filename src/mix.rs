@@ -0,0 +1,71 @@
+//! The mixing functions and constants of the JDK's `jdk.internal.util.random.RandomSupport`, which seed the
+//! generators of `java.util.random` and derive new streams from old ones.
+//!
+//! All functions are bijections apart from `mix_gamma`, so distinct inputs always give distinct outputs. They are
+//! written for `u64` and `u32`, and match the Java methods on `long` and `int` when the bits are reinterpreted.
+
+/// The 64-bit golden ratio, `0x9E3779B97F4A7C15`, the default gamma of SplitMix64 and `SplittableRandom`
+pub const GOLDEN_RATIO_64: u64 = 0x9E3779B97F4A7C15;
+
+/// The 64-bit silver ratio, `0x6A09E667F3BCC909`, the fractional bits of the square root of 2
+pub const SILVER_RATIO_64: u64 = 0x6A09E667F3BCC909;
+
+/// The 32-bit golden ratio, `0x9E3779B9`
+pub const GOLDEN_RATIO_32: u32 = 0x9E3779B9;
+
+/// The 32-bit silver ratio, `0x6A09E667`
+pub const SILVER_RATIO_32: u32 = 0x6A09E667;
+
+/// The 64-bit finalizer of MurmurHash3, `mixMurmur64`.
+pub const fn mix_murmur64(mut z: u64) -> u64 {
+	z = (z ^ (z >> 33)).wrapping_mul(0xFF51AFD7ED558CCD);
+	z = (z ^ (z >> 33)).wrapping_mul(0xC4CEB9FE1A85EC53);
+
+	z ^ (z >> 33)
+}
+
+/// Variant 13 of David Stafford's 64-bit mixers, `mixStafford13`. This is the output function of SplitMix64.
+pub const fn mix_stafford13(mut z: u64) -> u64 {
+	z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+	z ^ (z >> 31)
+}
+
+/// Doug Lea's 64-bit mixer, `mixLea64`, the output function of the LXM generators.
+pub const fn mix_lea64(mut z: u64) -> u64 {
+	z = (z ^ (z >> 32)).wrapping_mul(0xDABA0B6EB09322E3);
+	z = (z ^ (z >> 32)).wrapping_mul(0xDABA0B6EB09322E3);
+
+	z ^ (z >> 32)
+}
+
+/// The 32-bit finalizer of MurmurHash3, `mixMurmur32`.
+pub const fn mix_murmur32(mut z: u32) -> u32 {
+	z = (z ^ (z >> 16)).wrapping_mul(0x85EBCA6B);
+	z = (z ^ (z >> 13)).wrapping_mul(0xC2B2AE35);
+
+	z ^ (z >> 16)
+}
+
+/// Doug Lea's 32-bit mixer, `mixLea32`.
+pub const fn mix_lea32(mut z: u32) -> u32 {
+	z = (z ^ (z >> 16)).wrapping_mul(0xD36D884B);
+	z = (z ^ (z >> 16)).wrapping_mul(0xD36D884B);
+
+	z ^ (z >> 16)
+}
+
+/// Turns an arbitrary value into a gamma for SplitMix64, `SplittableRandom.mixGamma`: the MurmurHash3 finalizer forced
+/// to be odd, with alternate bits flipped if it has too few transitions between adjacent bits.
+pub const fn mix_gamma(mut z: u64) -> u64 {
+	z = (z ^ (z >> 33)).wrapping_mul(0xFF51AFD7ED558CCD);
+	z = (z ^ (z >> 33)).wrapping_mul(0xC4CEB9FE1A85EC53);
+	z = (z ^ (z >> 33)) | 1;
+
+	if (z ^ (z >> 1)).count_ones() < 24 {
+		z ^ 0xAAAAAAAAAAAAAAAA
+	} else {
+		z
+	}
+}
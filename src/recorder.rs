@@ -0,0 +1,320 @@
+use std::vec::Vec;
+
+use {Method, NextBits, Random, Subclass};
+
+const METHODS: usize = Method::ALL.len();
+
+/// Running count, mean, variance, minimum and maximum of a sequence of values, updated with Welford's algorithm so
+/// that no value has to be kept.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+	count: u64,
+	mean: f64,
+	/// Sum of the squared differences from the current mean
+	squares: f64,
+	min: f64,
+	max: f64
+}
+
+impl Summary {
+	/// Creates a summary of no values.
+	pub const fn new() -> Self {
+		Summary {
+			count: 0,
+			mean: 0.0,
+			squares: 0.0,
+			min: f64::INFINITY,
+			max: f64::NEG_INFINITY
+		}
+	}
+
+	/// Adds a value to the summary.
+	pub fn push(&mut self, value: f64) {
+		self.count += 1;
+
+		let delta = value - self.mean;
+		self.mean += delta / self.count as f64;
+		self.squares += delta * (value - self.mean);
+
+		self.min = self.min.min(value);
+		self.max = self.max.max(value);
+	}
+
+	/// Returns the number of values.
+	pub fn count(&self) -> u64 {
+		self.count
+	}
+
+	/// Returns the mean of the values, or None if there are none.
+	pub fn mean(&self) -> Option<f64> {
+		if self.count == 0 { None } else { Some(self.mean) }
+	}
+
+	/// Returns the sample variance of the values, or None if there are fewer than 2.
+	pub fn variance(&self) -> Option<f64> {
+		if self.count < 2 { None } else { Some(self.squares / (self.count - 1) as f64) }
+	}
+
+	/// Returns the smallest value, or None if there are none.
+	pub fn min(&self) -> Option<f64> {
+		if self.count == 0 { None } else { Some(self.min) }
+	}
+
+	/// Returns the largest value, or None if there are none.
+	pub fn max(&self) -> Option<f64> {
+		if self.count == 0 { None } else { Some(self.max) }
+	}
+}
+
+impl Default for Summary {
+	fn default() -> Self {
+		Summary::new()
+	}
+}
+
+/// Counts of values in equally wide buckets covering the range [low, high), with separate counts for values below
+/// and above the range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+	low: f64,
+	high: f64,
+	counts: Vec<u64>,
+	below: u64,
+	above: u64
+}
+
+impl Histogram {
+	/// Creates an empty histogram of `buckets` buckets covering [low, high).
+	///
+	/// # Panics
+	/// If `buckets` is 0, or the range is not finite with `low` less than `high`, the function panics.
+	pub fn new(low: f64, high: f64, buckets: usize) -> Self {
+		if buckets == 0 {
+			panic!("At least 1 bucket is required")
+		}
+
+		if !(f64::NEG_INFINITY < low && low < high && high < f64::INFINITY) {
+			panic!("Histogram range must be finite and non-empty")
+		}
+
+		Histogram {
+			low,
+			high,
+			counts: vec![0; buckets],
+			below: 0,
+			above: 0
+		}
+	}
+
+	/// Adds a value to the histogram.
+	pub fn push(&mut self, value: f64) {
+		if value < self.low {
+			self.below += 1;
+		} else if value >= self.high || value.is_nan() {
+			self.above += 1;
+		} else {
+			let scaled = (value - self.low) / (self.high - self.low) * self.counts.len() as f64;
+
+			// Rounding can put values just below high into the bucket past the end
+			let index = (scaled as usize).min(self.counts.len() - 1);
+			self.counts[index] += 1;
+		}
+	}
+
+	/// Returns the counts of the buckets, from the one starting at `low` upwards.
+	pub fn counts(&self) -> &[u64] {
+		&self.counts
+	}
+
+	/// Returns the number of values below `low`.
+	pub fn below(&self) -> u64 {
+		self.below
+	}
+
+	/// Returns the number of values at or above `high`, including NaNs.
+	pub fn above(&self) -> u64 {
+		self.above
+	}
+
+	fn clear(&mut self) {
+		self.counts.iter_mut().for_each(|count| *count = 0);
+		self.below = 0;
+		self.above = 0;
+	}
+}
+
+/// Wrapper around a generator accumulating statistics of the values drawn through each method, optionally with a
+/// histogram for some of them.
+///
+/// The wrapper draws exactly the same values as the wrapped generator would, so it can be dropped into a port to
+/// check that the model draws as much randomness as expected, and from the expected distributions. Any `NextBits`
+/// core can be wrapped, including `Random` itself, with the methods derived from `next` as in `Subclass`.
+///
+/// Values are summarized as f64s, so 64-bit integers lose their low bits, and booleans count as 0 and 1. Unsigned
+/// variants of methods are recorded as their signed counterparts, and `next_bytes` records each byte.
+///
+/// ```
+/// use java_rand::{Method, Random, StatsRecorder};
+///
+/// let mut random = StatsRecorder::new(Random::new(1234)).with_histogram(Method::NextI32Bound, 0.0, 6.0, 6);
+///
+/// for _ in 0..600 {
+///     random.next_i32_bound(6);
+/// }
+///
+/// assert_eq!(random.summary(Method::NextI32Bound).count(), 600);
+/// assert_eq!(random.histogram(Method::NextI32Bound).unwrap().counts().iter().sum::<u64>(), 600);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatsRecorder<G = Random> {
+	random: Subclass<G>,
+	summaries: [Summary; METHODS],
+	histograms: [Option<Histogram>; METHODS]
+}
+
+impl<G: NextBits> StatsRecorder<G> {
+	/// Wraps a generator, summarizing every method without histograms.
+	pub fn new(random: G) -> Self {
+		StatsRecorder {
+			random: Subclass::new(random),
+			summaries: [Summary::new(); METHODS],
+			histograms: Default::default()
+		}
+	}
+
+	/// Also counts the values of `method` in a histogram of `buckets` buckets covering [low, high), replacing any
+	/// histogram it had. See `Histogram::new` for the panics.
+	pub fn with_histogram(mut self, method: Method, low: f64, high: f64, buckets: usize) -> Self {
+		self.histograms[method as usize] = Some(Histogram::new(low, high, buckets));
+		self
+	}
+
+	/// Returns the wrapped generator.
+	pub fn core(&self) -> &G {
+		self.random.core()
+	}
+
+	/// Unwraps the generator, discarding the statistics. A pending gaussian is kept if the generator keeps one
+	/// itself, like `Random`.
+	pub fn into_inner(self) -> G {
+		self.random.into_inner()
+	}
+
+	/// Returns the summary of the values drawn through `method`.
+	pub fn summary(&self, method: Method) -> &Summary {
+		&self.summaries[method as usize]
+	}
+
+	/// Returns the histogram of the values drawn through `method`, or None if it was not given one.
+	pub fn histogram(&self, method: Method) -> Option<&Histogram> {
+		self.histograms[method as usize].as_ref()
+	}
+
+	/// Resets every summary and histogram, keeping the histogram buckets.
+	pub fn reset(&mut self) {
+		self.summaries = [Summary::new(); METHODS];
+		self.histograms.iter_mut().flatten().for_each(Histogram::clear);
+	}
+
+	fn record(&mut self, method: Method, value: f64) {
+		self.summaries[method as usize].push(value);
+
+		if let Some(ref mut histogram) = self.histograms[method as usize] {
+			histogram.push(value);
+		}
+	}
+
+	/// See `Random::next`.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		let value = self.random.next(bits);
+
+		self.record(Method::Next, value as f64);
+		value
+	}
+
+	/// See `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		self.random.next_bytes(bytes);
+
+		for &byte in bytes.iter() {
+			self.record(Method::NextBytes, byte as f64);
+		}
+	}
+
+	/// See `Random::next_i32`.
+	pub fn next_i32(&mut self) -> i32 {
+		let value = self.random.next_i32();
+
+		self.record(Method::NextI32, value as f64);
+		value
+	}
+
+	/// See `Random::next_u32`.
+	pub fn next_u32(&mut self) -> u32 {
+		self.next_i32() as u32
+	}
+
+	/// See `Random::next_i32_bound`.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		let value = self.random.next_i32_bound(max);
+
+		self.record(Method::NextI32Bound, value as f64);
+		value
+	}
+
+	/// See `Random::next_u32_bound`.
+	pub fn next_u32_bound(&mut self, max: u32) -> u32 {
+		self.next_i32_bound(max as i32) as u32
+	}
+
+	/// See `Random::next_i64`.
+	pub fn next_i64(&mut self) -> i64 {
+		let value = self.random.next_i64();
+
+		self.record(Method::NextI64, value as f64);
+		value
+	}
+
+	/// See `Random::next_u64`.
+	pub fn next_u64(&mut self) -> u64 {
+		self.next_i64() as u64
+	}
+
+	/// See `Random::next_bool`.
+	pub fn next_bool(&mut self) -> bool {
+		let value = self.random.next_bool();
+
+		self.record(Method::NextBool, value as u8 as f64);
+		value
+	}
+
+	/// See `Random::next_f32`.
+	pub fn next_f32(&mut self) -> f32 {
+		let value = self.random.next_f32();
+
+		self.record(Method::NextF32, value as f64);
+		value
+	}
+
+	/// See `Random::next_f64`.
+	pub fn next_f64(&mut self) -> f64 {
+		let value = self.random.next_f64();
+
+		self.record(Method::NextF64, value);
+		value
+	}
+
+	/// See `Random::next_gaussian`.
+	pub fn next_gaussian(&mut self) -> f64 {
+		let value = self.random.next_gaussian();
+
+		self.record(Method::NextGaussian, value);
+		value
+	}
+}
+
+impl From<Random> for StatsRecorder {
+	fn from(random: Random) -> Self {
+		StatsRecorder::new(random)
+	}
+}
@@ -0,0 +1,160 @@
+//! A line by line transcription of `java.util.Random` from the OpenJDK sources, as an oracle for the optimized
+//! implementation.
+//!
+//! Nothing here is optimized: the state is a signed `long` updated with the same expressions as Java, through
+//! `Wrapping` to get Java's overflow, and every method is written the way the JDK writes it, down to the loop shapes.
+//! When the fast path of [`Random`](::Random) is suspected of a parity bug, comparing it with this module shows
+//! whether the difference is in the port or in the expectation. The crate's differential tests compare the two for
+//! every method.
+
+use core::cmp;
+use core::num::Wrapping;
+
+use {strictmath, A, C, M};
+
+/// `0x1.0p-53`
+const DOUBLE_UNIT: f64 = 1.0 / (1u64 << 53) as f64;
+
+/// `java.util.Random`, with the fields of the JDK class.
+#[derive(Debug, Clone)]
+pub struct Random {
+	seed: Wrapping<i64>,
+	next_next_gaussian: f64,
+	have_next_next_gaussian: bool
+}
+
+impl Random {
+	/// `public Random(long seed)`
+	pub fn new(seed: i64) -> Self {
+		Random {
+			seed: Random::initial_scramble(seed),
+			next_next_gaussian: 0.0,
+			have_next_next_gaussian: false
+		}
+	}
+
+	/// `private static long initialScramble(long seed)`
+	fn initial_scramble(seed: i64) -> Wrapping<i64> {
+		(Wrapping(seed) ^ A) & M
+	}
+
+	/// `synchronized public void setSeed(long seed)`
+	pub fn set_seed(&mut self, seed: i64) {
+		self.seed = Random::initial_scramble(seed);
+		self.have_next_next_gaussian = false;
+	}
+
+	/// `protected int next(int bits)`
+	pub fn next(&mut self, bits: i32) -> i32 {
+		let oldseed = self.seed;
+		let nextseed = (oldseed * A + C) & M;
+		self.seed = nextseed;
+
+		((nextseed.0 as u64) >> (48 - bits)) as i32
+	}
+
+	/// `public void nextBytes(byte[] bytes)`
+	pub fn next_bytes(&mut self, bytes: &mut [i8]) {
+		let len = bytes.len();
+		let mut i = 0;
+
+		while i < len {
+			let mut rnd = self.next_int();
+			let mut n = cmp::min(len - i, 32 / 8);
+
+			while n > 0 {
+				n -= 1;
+
+				bytes[i] = rnd as i8;
+				i += 1;
+				rnd >>= 8;
+			}
+		}
+	}
+
+	/// `public int nextInt()`
+	pub fn next_int(&mut self) -> i32 {
+		self.next(32)
+	}
+
+	/// `public int nextInt(int bound)`
+	///
+	/// # Panics
+	/// If `bound` is not positive, the function panics, where Java throws `IllegalArgumentException`.
+	pub fn next_int_bound(&mut self, bound: i32) -> i32 {
+		if bound <= 0 {
+			panic!("bound must be positive")
+		}
+
+		let mut r = Wrapping(self.next(31));
+		let m = Wrapping(bound) - Wrapping(1);
+
+		if (Wrapping(bound) & m).0 == 0 {
+			// i.e., bound is a power of 2
+			r = Wrapping(((bound as i64 * r.0 as i64) >> 31) as i32);
+		} else {
+			let mut u = r;
+
+			loop {
+				r = Wrapping(u.0 % bound);
+
+				if (u - r + m).0 >= 0 {
+					break;
+				}
+
+				u = Wrapping(self.next(31));
+			}
+		}
+
+		r.0
+	}
+
+	/// `public long nextLong()`
+	pub fn next_long(&mut self) -> i64 {
+		(Wrapping((self.next(32) as i64) << 32) + Wrapping(self.next(32) as i64)).0
+	}
+
+	/// `public boolean nextBoolean()`
+	pub fn next_boolean(&mut self) -> bool {
+		self.next(1) != 0
+	}
+
+	/// `public float nextFloat()`
+	pub fn next_float(&mut self) -> f32 {
+		self.next(24) as f32 / ((1 << 24) as f32)
+	}
+
+	/// `public double nextDouble()`
+	pub fn next_double(&mut self) -> f64 {
+		(((self.next(26) as i64) << 27) + self.next(27) as i64) as f64 * DOUBLE_UNIT
+	}
+
+	/// `synchronized public double nextGaussian()`
+	pub fn next_gaussian(&mut self) -> f64 {
+		// See Knuth, TAOCP, Vol. 2, 3rd edition, Section 3.4.1 Algorithm C.
+		if self.have_next_next_gaussian {
+			self.have_next_next_gaussian = false;
+			self.next_next_gaussian
+		} else {
+			let mut v1;
+			let mut v2;
+			let mut s;
+
+			loop {
+				v1 = 2.0 * self.next_double() - 1.0; // between -1 and 1
+				v2 = 2.0 * self.next_double() - 1.0; // between -1 and 1
+				s = v1 * v1 + v2 * v2;
+
+				if !(s >= 1.0 || s == 0.0) {
+					break;
+				}
+			}
+
+			let multiplier = strictmath::sqrt(-2.0 * strictmath::log(s) / s);
+			self.next_next_gaussian = v2 * multiplier;
+			self.have_next_next_gaussian = true;
+
+			v1 * multiplier
+		}
+	}
+}
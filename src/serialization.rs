@@ -0,0 +1,174 @@
+//! Interop with the Java object serialization form of `java.util.Random`.
+
+use std::io::{self, Read, Write};
+
+use {Random, MASK};
+
+const STREAM_MAGIC: u16 = 0xACED;
+const STREAM_VERSION: u16 = 5;
+
+const TC_NULL: u8 = 0x70;
+const TC_CLASSDESC: u8 = 0x72;
+const TC_OBJECT: u8 = 0x73;
+const TC_ENDBLOCKDATA: u8 = 0x78;
+
+const SC_WRITE_METHOD: u8 = 0x01;
+const SC_SERIALIZABLE: u8 = 0x02;
+
+const CLASS_NAME: &str = "java.util.Random";
+const SERIAL_VERSION_UID: i64 = 3905348978240129619;
+
+/// Serializable fields of `java.util.Random`, in the order Java writes them (primitives sorted by name).
+const FIELDS: [(u8, &str); 3] = [
+	(b'Z', "haveNextNextGaussian"),
+	(b'D', "nextNextGaussian"),
+	(b'J', "seed")
+];
+
+fn invalid(message: &'static str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+	let mut buf = [0; 1];
+	reader.read_exact(&mut buf)?;
+
+	Ok(buf[0])
+}
+
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
+	let mut buf = [0; 2];
+	reader.read_exact(&mut buf)?;
+
+	Ok(u16::from_be_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+	let mut buf = [0; 8];
+	reader.read_exact(&mut buf)?;
+
+	Ok(u64::from_be_bytes(buf))
+}
+
+fn read_utf<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+	let mut buf = vec![0; read_u16(reader)? as usize];
+	reader.read_exact(&mut buf)?;
+
+	Ok(buf)
+}
+
+fn write_utf<W: Write>(writer: &mut W, value: &str) -> io::Result<()> {
+	writer.write_all(&(value.len() as u16).to_be_bytes())?;
+	writer.write_all(value.as_bytes())
+}
+
+/// Returns the size in bytes of a primitive field value, or None for object fields.
+fn primitive_size(type_code: u8) -> Option<usize> {
+	match type_code {
+		b'B' | b'Z' => Some(1),
+		b'C' | b'S' => Some(2),
+		b'I' | b'F' => Some(4),
+		b'J' | b'D' => Some(8),
+		_ => None
+	}
+}
+
+impl Random {
+	/// Reads a generator from a stream produced by `ObjectOutputStream.writeObject(random)` in Java.
+	/// The state and the pending gaussian (`haveNextNextGaussian`) are both restored.
+	///
+	/// Only direct instances of `java.util.Random` are supported: subclasses have a different class descriptor.
+	pub fn read_serialized<R: Read>(mut reader: R) -> io::Result<Self> {
+		if read_u16(&mut reader)? != STREAM_MAGIC || read_u16(&mut reader)? != STREAM_VERSION {
+			return Err(invalid("not a Java serialization stream"));
+		}
+
+		if read_u8(&mut reader)? != TC_OBJECT || read_u8(&mut reader)? != TC_CLASSDESC {
+			return Err(invalid("expected a new object with a new class descriptor"));
+		}
+
+		if read_utf(&mut reader)? != CLASS_NAME.as_bytes() {
+			return Err(invalid("serialized object is not a java.util.Random"));
+		}
+
+		if read_u64(&mut reader)? as i64 != SERIAL_VERSION_UID {
+			return Err(invalid("serialVersionUID mismatch"));
+		}
+
+		let flags = read_u8(&mut reader)?;
+
+		if flags & SC_SERIALIZABLE == 0 {
+			return Err(invalid("class descriptor is not serializable"));
+		}
+
+		let field_count = read_u16(&mut reader)?;
+		let mut fields = Vec::with_capacity(field_count as usize);
+
+		for _ in 0..field_count {
+			let type_code = read_u8(&mut reader)?;
+			let name = read_utf(&mut reader)?;
+			let size = primitive_size(type_code).ok_or_else(|| invalid("unexpected object field"))?;
+
+			fields.push((type_code, name, size));
+		}
+
+		if read_u8(&mut reader)? != TC_ENDBLOCKDATA || read_u8(&mut reader)? != TC_NULL {
+			return Err(invalid("unexpected class annotation or superclass"));
+		}
+
+		// Defaults used by Random.readObject for missing fields.
+		let mut seed = -1i64;
+		let mut next_next_gaussian = 0.0;
+		let mut have_next_next_gaussian = false;
+
+		for (type_code, name, size) in fields {
+			let mut buf = [0; 8];
+			reader.read_exact(&mut buf[..size])?;
+
+			match (type_code, &name[..]) {
+				(b'J', b"seed") => seed = i64::from_be_bytes(buf),
+				(b'D', b"nextNextGaussian") => next_next_gaussian = f64::from_bits(u64::from_be_bytes(buf)),
+				(b'Z', b"haveNextNextGaussian") => have_next_next_gaussian = buf[0] != 0,
+				_ => ()
+			}
+		}
+
+		if flags & SC_WRITE_METHOD != 0 && read_u8(&mut reader)? != TC_ENDBLOCKDATA {
+			return Err(invalid("unexpected data after serialized fields"));
+		}
+
+		if seed < 0 {
+			return Err(invalid("Random: invalid seed"));
+		}
+
+		Ok(Random {
+			state: seed as u64 & MASK,
+			next_gaussian: if have_next_next_gaussian { Some(next_next_gaussian) } else { None }
+		})
+	}
+
+	/// Writes the generator in the same form as `ObjectOutputStream.writeObject(random)` in Java, so that it can be
+	/// read back with `ObjectInputStream.readObject()`.
+	pub fn write_serialized<W: Write>(&self, mut writer: W) -> io::Result<()> {
+		writer.write_all(&STREAM_MAGIC.to_be_bytes())?;
+		writer.write_all(&STREAM_VERSION.to_be_bytes())?;
+		writer.write_all(&[TC_OBJECT, TC_CLASSDESC])?;
+		write_utf(&mut writer, CLASS_NAME)?;
+		writer.write_all(&SERIAL_VERSION_UID.to_be_bytes())?;
+		writer.write_all(&[SC_WRITE_METHOD | SC_SERIALIZABLE])?;
+		writer.write_all(&(FIELDS.len() as u16).to_be_bytes())?;
+
+		for &(type_code, name) in FIELDS.iter() {
+			writer.write_all(&[type_code])?;
+			write_utf(&mut writer, name)?;
+		}
+
+		writer.write_all(&[TC_ENDBLOCKDATA, TC_NULL])?;
+
+		writer.write_all(&[self.next_gaussian.is_some() as u8])?;
+		writer.write_all(&self.next_gaussian.unwrap_or(0.0).to_bits().to_be_bytes())?;
+		writer.write_all(&self.state.to_be_bytes())?;
+
+		writer.write_all(&[TC_ENDBLOCKDATA])
+	}
+}
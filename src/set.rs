@@ -0,0 +1,210 @@
+//! Named bundles of generators, for programs that give every subsystem its own stream and need to save and restore
+//! all of them together.
+//!
+//! The binary form of a `RandomSet` is the number of generators as a big endian `u32`, followed by every generator in
+//! name order: the length of its name in bytes as a big endian `u16`, the name in UTF-8, and the 27 bytes of
+//! `Random::to_bytes`. Like the form of a single generator, it will keep decoding in later versions of the crate.
+
+use std::collections::btree_map::{self, BTreeMap};
+use std::string::String;
+use std::vec::Vec;
+
+use {Error, Random};
+
+/// Generators identified by name, such as `"terrain"`, `"loot"` and `"ai"`.
+///
+/// The set owns its generators, and lends them out by name, so two systems can only share a stream by asking for the
+/// same name. Inserting a generator in the same internal state as one already in the set, which usually comes from
+/// cloning a generator instead of deriving a new one, is rejected as well, since both would draw the same values.
+///
+/// `to_bytes` and `from_bytes` save and load every generator at once, and `restore` only replaces the generators if
+/// the whole save could be decoded, so a corrupt save never leaves some systems restored and others not.
+///
+/// ```
+/// use java_rand::{Random, RandomSet};
+///
+/// let mut set = RandomSet::derived(&Random::new(1234), &["terrain", "loot", "ai"]).unwrap();
+/// let saved = set.to_bytes();
+///
+/// let before = set.get_mut("loot").unwrap().next_i32();
+/// set.restore(&saved).unwrap();
+///
+/// assert_eq!(set.get_mut("loot").unwrap().next_i32(), before);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RandomSet {
+	generators: BTreeMap<String, Random>
+}
+
+impl RandomSet {
+	/// Creates an empty set.
+	pub fn new() -> Self {
+		RandomSet { generators: BTreeMap::new() }
+	}
+
+	/// Creates a set holding a child of `master` for every name, created with `Random::derive_child_str`.
+	///
+	/// # Errors
+	/// If a name is repeated, `Error::DuplicateGenerator` is returned.
+	pub fn derived(master: &Random, names: &[&str]) -> Result<Self, Error> {
+		let mut set = RandomSet::new();
+
+		for &name in names {
+			set.insert(name, master.derive_child_str(name))?;
+		}
+
+		Ok(set)
+	}
+
+	/// Adds a generator under `name`.
+	///
+	/// # Errors
+	/// If the set already holds a generator named `name`, or a generator whose internal state is the same as that of
+	/// `random`, `Error::DuplicateGenerator` is returned and the set is left untouched.
+	pub fn insert(&mut self, name: &str, random: Random) -> Result<(), Error> {
+		if self.generators.contains_key(name) || self.generators.values().any(|other| other.state == random.state) {
+			return Err(Error::DuplicateGenerator);
+		}
+
+		self.generators.insert(String::from(name), random);
+
+		Ok(())
+	}
+
+	/// Removes the generator named `name` and returns it, or None if there is none.
+	pub fn remove(&mut self, name: &str) -> Option<Random> {
+		self.generators.remove(name)
+	}
+
+	/// Returns the generator named `name`, or None if there is none.
+	pub fn get(&self, name: &str) -> Option<&Random> {
+		self.generators.get(name)
+	}
+
+	/// Returns the generator named `name` for drawing from it, or None if there is none.
+	pub fn get_mut(&mut self, name: &str) -> Option<&mut Random> {
+		self.generators.get_mut(name)
+	}
+
+	/// Returns the number of generators.
+	pub fn len(&self) -> usize {
+		self.generators.len()
+	}
+
+	/// Returns whether the set holds no generators.
+	pub fn is_empty(&self) -> bool {
+		self.generators.is_empty()
+	}
+
+	/// Returns the names and generators, in name order.
+	pub fn iter(&self) -> btree_map::Iter<'_, String, Random> {
+		self.generators.iter()
+	}
+
+	/// Encodes every generator, including pending gaussians, in the binary form described in the module
+	/// documentation.
+	///
+	/// # Panics
+	/// If a name is longer than 65535 bytes, which cannot be encoded, the function panics.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(4 + self.generators.keys().map(|name| 2 + name.len() + 27).sum::<usize>());
+		bytes.extend_from_slice(&(self.generators.len() as u32).to_be_bytes());
+
+		for (name, random) in &self.generators {
+			if name.len() > u16::MAX as usize {
+				panic!("Generator names must be at most 65535 bytes long")
+			}
+
+			bytes.extend_from_slice(&(name.len() as u16).to_be_bytes());
+			bytes.extend_from_slice(name.as_bytes());
+			bytes.extend_from_slice(&random.to_bytes());
+		}
+
+		bytes
+	}
+
+	/// Decodes a set encoded by `to_bytes`.
+	///
+	/// # Errors
+	/// Besides the errors of `Random::from_bytes` for each generator, `Error::InvalidState` is returned if the bytes
+	/// are truncated, have bytes left over, or hold a name that is not UTF-8 or appears twice. As `insert` rejects
+	/// them, so that `to_bytes` never produces them, two generators in the same internal state are rejected with
+	/// `Error::InvalidState` too.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		let mut reader = Reader { bytes };
+		let count = u32::from_be_bytes(reader.take()?);
+		let mut generators = BTreeMap::new();
+
+		for _ in 0..count {
+			let length = u16::from_be_bytes(reader.take()?) as usize;
+			let name = String::from_utf8(reader.slice(length)?.to_vec()).map_err(|_| Error::InvalidState)?;
+			let random = Random::from_bytes(&reader.take::<27>()?)?;
+
+			if generators.values().any(|other: &Random| other.state == random.state) {
+				return Err(Error::InvalidState);
+			}
+
+			if generators.insert(name, random).is_some() {
+				return Err(Error::InvalidState);
+			}
+		}
+
+		if !reader.bytes.is_empty() {
+			return Err(Error::InvalidState);
+		}
+
+		Ok(RandomSet { generators })
+	}
+
+	/// Replaces every generator with the one of the same name decoded from `bytes`, which must hold exactly the
+	/// names of this set.
+	///
+	/// # Errors
+	/// Besides the errors of `from_bytes`, `Error::InvalidState` is returned if the names are not those of this set.
+	/// On error, no generator is changed.
+	pub fn restore(&mut self, bytes: &[u8]) -> Result<(), Error> {
+		let restored = RandomSet::from_bytes(bytes)?;
+
+		if !self.generators.keys().eq(restored.generators.keys()) {
+			return Err(Error::InvalidState);
+		}
+
+		*self = restored;
+
+		Ok(())
+	}
+}
+
+impl<'a> IntoIterator for &'a RandomSet {
+	type Item = (&'a String, &'a Random);
+	type IntoIter = btree_map::Iter<'a, String, Random>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Reads the fields of the binary form from the front of a slice.
+struct Reader<'a> {
+	bytes: &'a [u8]
+}
+
+impl<'a> Reader<'a> {
+	fn slice(&mut self, length: usize) -> Result<&'a [u8], Error> {
+		if self.bytes.len() < length {
+			return Err(Error::InvalidState);
+		}
+
+		let (slice, rest) = self.bytes.split_at(length);
+		self.bytes = rest;
+
+		Ok(slice)
+	}
+
+	fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+		let mut array = [0; N];
+		array.copy_from_slice(self.slice(N)?);
+
+		Ok(array)
+	}
+}
@@ -0,0 +1,130 @@
+//! Skipping draws whose values are not needed, such as the calls an emulated program makes between the ones of
+//! interest. Draws that always take the same number of calls to `next` are skipped with a jump in logarithmic time,
+//! while draws that can reject values are simulated.
+
+use batch;
+use calls;
+use {Error, Random, MASK};
+
+impl Random {
+	/// Steps the generator as `steps` calls to `next` would, in logarithmic time. A pending gaussian is kept, as it is
+	/// by every method other than `next_gaussian` and `set_seed`.
+	#[inline]
+	pub const fn skip(&mut self, steps: u64) {
+		let (multiplier, increment) = batch::affine_steps(steps);
+
+		self.state = self.state.wrapping_mul(multiplier).wrapping_add(increment) & MASK;
+	}
+
+	/// Returns substream `i` of this generator, a copy advanced by `i << stride_log2` steps, for splitting one seed
+	/// into reproducible parallel streams. The period of 2^48 holds `2^(48 - stride_log2)` substreams, which do not
+	/// overlap as long as each one makes fewer than `2^stride_log2` calls to `next`. This generator is not stepped,
+	/// and a pending gaussian is not inherited.
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// let random = Random::new(1234);
+	///
+	/// // 256 substreams of 2^40 steps each
+	/// let streams: Vec<Random> = (0..256).map(|i| random.substream(i, 40)).collect();
+	/// ```
+	///
+	/// # Panics
+	/// If `stride_log2` is greater than 48 or `i` is not less than `2^(48 - stride_log2)`, the function panics.
+	pub const fn substream(&self, i: u64, stride_log2: u32) -> Random {
+		match self.try_substream(i, stride_log2) {
+			Ok(random) => random,
+			Err(_) => panic!("Substream lies outside of the period")
+		}
+	}
+
+	/// Like `substream`, but returns `Error::InvalidShard` instead of panicking.
+	pub const fn try_substream(&self, i: u64, stride_log2: u32) -> Result<Random, Error> {
+		if stride_log2 > 48 || i >= 1 << (48 - stride_log2) {
+			return Err(Error::InvalidShard);
+		}
+
+		let mut random = Random {
+			state: self.state,
+			next_gaussian: None
+		};
+
+		random.skip(i << stride_log2);
+
+		Ok(random)
+	}
+
+	/// Advances the generator past `n` calls to `next_i32`. The period of 2^48 divides 2^64, so counts whose calls
+	/// overflow a `u64` are still skipped exactly.
+	#[inline]
+	pub const fn skip_ints(&mut self, n: u64) {
+		self.skip(n.wrapping_mul(calls::NEXT_I32 as u64))
+	}
+
+	/// Advances the generator past `n` calls to `next_i64`.
+	#[inline]
+	pub const fn skip_longs(&mut self, n: u64) {
+		self.skip(n.wrapping_mul(calls::NEXT_I64 as u64))
+	}
+
+	/// Advances the generator past `n` calls to `next_f64`.
+	#[inline]
+	pub const fn skip_doubles(&mut self, n: u64) {
+		self.skip(n.wrapping_mul(calls::NEXT_F64 as u64))
+	}
+
+	/// Advances the generator past `n` calls to `next_i32_bound(bound)`. Powers of two take one call each and are
+	/// jumped over, while other bounds can reject values and are drawn one by one.
+	///
+	/// # Panics
+	/// If `bound` is less than 1, the function panics.
+	pub const fn skip_bounded_ints(&mut self, bound: i32, n: u64) {
+		match self.try_skip_bounded_ints(bound, n) {
+			Ok(()) => (),
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `skip_bounded_ints`, but returns `Error::InvalidBound` instead of panicking, without stepping the
+	/// generator.
+	pub const fn try_skip_bounded_ints(&mut self, bound: i32, n: u64) -> Result<(), Error> {
+		if bound <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		if (bound as u32).is_power_of_two() {
+			self.skip(n.wrapping_mul(calls::NEXT_I32_BOUND_MIN as u64));
+
+			return Ok(());
+		}
+
+		let mut remaining = n;
+
+		while remaining > 0 {
+			self.next_i32_bound(bound);
+			remaining -= 1;
+		}
+
+		Ok(())
+	}
+
+	/// Advances the generator past `n` calls to `next_gaussian`, leaving the same pending gaussian. Pairs that are
+	/// skipped entirely are drawn with the polar method of `next_gaussian`, but are not kept.
+	pub fn skip_gaussians(&mut self, n: u64) {
+		let mut remaining = n;
+
+		if remaining > 0 && self.next_gaussian.take().is_some() {
+			remaining -= 1;
+		}
+
+		for _ in 0..remaining / 2 {
+			random_next_gaussian_pair!(self);
+		}
+
+		// The first value of the last pair is skipped, and its second value becomes pending
+		if remaining % 2 == 1 {
+			self.next_gaussian();
+		}
+	}
+}
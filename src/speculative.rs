@@ -0,0 +1,83 @@
+use core::ops::{Deref, DerefMut};
+
+use Random;
+
+/// Guard returned by `Random::speculate`, giving access to the generator while remembering its state on entry. Unless
+/// `commit` is called, the generator is rewound to that state when the guard is dropped, as if none of the draws made
+/// through the guard had happened.
+#[derive(Debug)]
+#[must_use = "the draws are rewound as soon as the guard is dropped"]
+pub struct SpeculativeGuard<'a> {
+	random: &'a mut Random,
+	saved: Random,
+	committed: bool
+}
+
+impl<'a> SpeculativeGuard<'a> {
+	/// Keeps the draws made through the guard.
+	pub fn commit(mut self) {
+		self.committed = true;
+	}
+
+	/// Rewinds the generator immediately. This is the same as dropping the guard.
+	pub fn rollback(self) {}
+}
+
+impl<'a> Deref for SpeculativeGuard<'a> {
+	type Target = Random;
+
+	fn deref(&self) -> &Random {
+		self.random
+	}
+}
+
+impl<'a> DerefMut for SpeculativeGuard<'a> {
+	fn deref_mut(&mut self) -> &mut Random {
+		self.random
+	}
+}
+
+impl<'a> Drop for SpeculativeGuard<'a> {
+	fn drop(&mut self) {
+		if !self.committed {
+			*self.random = self.saved.clone();
+		}
+	}
+}
+
+impl Random {
+	/// Starts a speculative sequence of draws, which is rewound unless committed:
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// let mut random = Random::new(7);
+	///
+	/// let peeked = {
+	///     let mut guard = random.speculate();
+	///     guard.next_i32_bound(100)
+	/// };
+	///
+	/// assert_eq!(random.next_i32_bound(100), peeked);
+	/// ```
+	pub fn speculate(&mut self) -> SpeculativeGuard<'_> {
+		SpeculativeGuard {
+			saved: self.clone(),
+			random: self,
+			committed: false
+		}
+	}
+
+	/// Calls `f` on the generator, keeping its draws only if it returns `Some`. Otherwise the generator is rewound to
+	/// its state before the call.
+	pub fn scoped<T, F: FnOnce(&mut Random) -> Option<T>>(&mut self, f: F) -> Option<T> {
+		let mut guard = self.speculate();
+		let result = f(&mut guard);
+
+		if result.is_some() {
+			guard.commit();
+		}
+
+		result
+	}
+}
@@ -0,0 +1,285 @@
+//! SplitMix64 by Steele, Lea and Flood, the algorithm of `java.util.SplittableRandom`.
+//!
+//! The generator adds an odd gamma to its state on every step and returns the state passed through
+//! `mix::mix_stafford13`. `SplitMix64::new(seed)` produces the same sequence as `new SplittableRandom(seed)`, `split`
+//! creates the same child as `SplittableRandom.split()`, and `splits` reproduces the per-half seeding of a parallel
+//! `splits()` stream.
+
+use core::convert::TryFrom;
+
+use {float, ziggurat};
+use mix::{mix_gamma, mix_stafford13, GOLDEN_RATIO_64};
+use {Algorithm, Checkpoint, Error};
+
+/// A SplitMix64 generator with its state and gamma, equivalent to `java.util.SplittableRandom`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitMix64 {
+	seed: u64,
+	gamma: u64
+}
+
+impl SplitMix64 {
+	/// Creates a generator with the default gamma, like `new SplittableRandom(seed)`.
+	#[inline]
+	pub const fn new(seed: u64) -> Self {
+		SplitMix64 { seed, gamma: GOLDEN_RATIO_64 }
+	}
+
+	/// Creates a generator with the raw state `seed` and the given gamma.
+	///
+	/// # Panics
+	/// If `gamma` is even, the function panics: the generator would not go through all 2^64 states.
+	#[inline]
+	pub const fn with_gamma(seed: u64, gamma: u64) -> Self {
+		if gamma & 1 == 0 {
+			panic!("Gamma must be odd")
+		}
+
+		SplitMix64 { seed, gamma }
+	}
+
+	/// Returns the raw state, the value that was last passed to the mixer.
+	#[inline]
+	pub const fn seed(&self) -> u64 {
+		self.seed
+	}
+
+	/// Returns the gamma added to the state on every step.
+	#[inline]
+	pub const fn gamma(&self) -> u64 {
+		self.gamma
+	}
+
+	#[inline]
+	const fn next_seed(&mut self) -> u64 {
+		self.seed = self.seed.wrapping_add(self.gamma);
+		self.seed
+	}
+
+	/// Returns a uniformly distributed unsigned 64-bit integer, stepping the generator once.
+	#[inline]
+	pub const fn next_u64(&mut self) -> u64 {
+		mix_stafford13(self.next_seed())
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer, equivalent to `nextLong()`.
+	#[inline]
+	pub const fn next_i64(&mut self) -> i64 {
+		self.next_u64() as i64
+	}
+
+	/// Returns a uniformly distributed unsigned 32-bit integer, stepping the generator once. The state goes through a
+	/// separate 32-bit mixer rather than taking half of `next_u64`.
+	#[inline]
+	pub const fn next_u32(&mut self) -> u32 {
+		let z = self.next_seed();
+		let z = (z ^ (z >> 33)).wrapping_mul(0x62A9D9ED799705F5);
+
+		((z ^ (z >> 28)).wrapping_mul(0xCB24D0A5C88C35B3) >> 32) as u32
+	}
+
+	/// Returns a uniformly distributed signed 32-bit integer, equivalent to `nextInt()`.
+	#[inline]
+	pub const fn next_i32(&mut self) -> i32 {
+		self.next_u32() as i32
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false, the sign of `next_i32`.
+	#[inline]
+	pub const fn next_bool(&mut self) -> bool {
+		self.next_i32() < 0
+	}
+
+	/// Returns a f32 uniformly distributed between 0.0 and 1.0, from the high 24 bits of `next_u32`.
+	#[inline]
+	pub const fn next_f32(&mut self) -> f32 {
+		float::unit_f32(self.next_u32() >> 8)
+	}
+
+	/// Returns a f64 uniformly distributed between 0.0 and 1.0, from the high 53 bits of `next_u64`.
+	#[inline]
+	pub const fn next_f64(&mut self) -> f64 {
+		float::unit_f64(self.next_u64() >> 11)
+	}
+
+	/// Returns a random number in the range [0, max), equivalent to `nextInt(bound)`.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	#[inline]
+	pub const fn next_i32_bound(&mut self, max: i32) -> i32 {
+		match self.try_next_i32_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_i32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	#[inline]
+	pub const fn try_next_i32_bound(&mut self, max: i32) -> Result<i32, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		Ok(generator_next_bound!(self, max, next_i32, i32, u32))
+	}
+
+	/// Returns a random number in the range [0, max), equivalent to `nextLong(bound)`. Like `next_i32_bound`, it
+	/// masks `next_i64` for powers of two, and otherwise rejects values of `next_u64 >>> 1` that would bias the
+	/// remainder.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	#[inline]
+	pub const fn next_i64_bound(&mut self, max: i64) -> i64 {
+		match self.try_next_i64_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_i64_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the generator.
+	#[inline]
+	pub const fn try_next_i64_bound(&mut self, max: i64) -> Result<i64, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		Ok(generator_next_bound!(self, max, next_i64, i64, u64))
+	}
+
+	/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0, equivalent to
+	/// `nextGaussian()`, which `SplittableRandom` inherits from `RandomGenerator` since Java 17.
+	pub fn next_gaussian(&mut self) -> f64 {
+		ziggurat::next_gaussian(|| self.next_i64())
+	}
+
+	/// Returns an exponentially distributed number with a mean of 1.0, equivalent to `nextExponential()`.
+	pub fn next_exponential(&mut self) -> f64 {
+		ziggurat::next_exponential(|| self.next_i64())
+	}
+
+	/// Fills the byte array with random bytes, equivalent to `nextBytes(bytes)`. Each `next_u64` fills 8 bytes low
+	/// byte first, and a final partial block takes the low bytes of one more value, unlike the 4 byte blocks of
+	/// `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		for chunk in bytes.chunks_mut(8) {
+			chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+		}
+	}
+
+	/// Creates a new generator from two steps of this one, equivalent to `split()`. The child's state is the next
+	/// output, and its gamma is derived from the following state with `mix::mix_gamma`.
+	#[inline]
+	pub const fn split(&mut self) -> SplitMix64 {
+		let seed = self.next_u64();
+
+		SplitMix64 { seed, gamma: mix_gamma(self.next_seed()) }
+	}
+
+	/// Captures the state and gamma of the generator.
+	pub fn save(&self) -> Checkpoint {
+		Checkpoint::new(Algorithm::SplitMix64, [self.seed, self.gamma], None).expect("Gamma is always odd")
+	}
+
+	/// Returns the generator to the state captured in `checkpoint`, see `Random::restore`.
+	pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		if checkpoint.algorithm() != Algorithm::SplitMix64 {
+			return Err(Error::AlgorithmMismatch);
+		}
+
+		let [seed, gamma] = checkpoint.state();
+		*self = SplitMix64 { seed, gamma };
+
+		Ok(())
+	}
+
+	/// Encodes the state in the versioned binary form, see `Checkpoint::to_versioned_bytes`.
+	pub fn to_bytes(&self) -> [u8; 27] {
+		self.save().to_versioned_bytes()
+	}
+
+	/// Decodes a generator encoded by `to_bytes`, see `Random::from_bytes`.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		let mut random = SplitMix64::new(0);
+		random.restore(&Checkpoint::from_versioned_bytes(bytes)?)?;
+
+		Ok(random)
+	}
+
+	/// Returns a stream of `size` new generators, equivalent to `splits(size)`. A size of `i64::MAX as u64` gives the
+	/// stream of `splits()`, which splits at the same indices.
+	///
+	/// This is the only stream of `SplittableRandom` whose halves get their own generators. The spliterator behind
+	/// `ints`, `longs` and `doubles` halves its range on `trySplit` but keeps drawing from the one shared generator, so
+	/// the value at each index of a parallel pipeline depends on how its threads interleave, and only sequential
+	/// streams are reproducible. Those are the endless iterators of the draw methods, limited with `take`.
+	pub fn splits(self, size: u64) -> Splits {
+		Splits { random: self, index: 0, fence: size }
+	}
+}
+
+/// The stream of new generators returned by `SplitMix64::splits`, the `RandomSplitsSpliterator` behind
+/// `SplittableRandom.splits(size)`.
+///
+/// Each item is created from two outputs of the stream's generator, like `split(source)`, which differs from `split`
+/// in mixing the gamma from the second output rather than from the raw state. `try_split` hands the lower half of the
+/// remaining indices to a new stream whose generator is `split` from this one, like `trySplit`, so every index of a
+/// parallel Java pipeline gets the same generator whatever order the parts are consumed in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Splits {
+	random: SplitMix64,
+	index: u64,
+	fence: u64
+}
+
+impl Splits {
+	/// Returns the index of the next generator in the original stream.
+	pub fn index(&self) -> u64 {
+		self.index
+	}
+
+	/// Returns the number of generators left, equivalent to `estimateSize()`, which is exact for this stream.
+	pub fn estimate_size(&self) -> u64 {
+		self.fence - self.index
+	}
+
+	/// Splits off the lower half of the remaining indices, equivalent to `trySplit()`. The returned stream draws from
+	/// `split()` of this stream's generator, and this stream keeps the upper half. Returns `None` without stepping
+	/// the generator when fewer than two generators are left.
+	pub fn try_split(&mut self) -> Option<Splits> {
+		let middle = self.index + (self.fence - self.index) / 2;
+
+		if middle <= self.index {
+			return None;
+		}
+
+		let lower = Splits { random: self.random.split(), index: self.index, fence: middle };
+		self.index = middle;
+
+		Some(lower)
+	}
+}
+
+impl Iterator for Splits {
+	type Item = SplitMix64;
+
+	fn next(&mut self) -> Option<SplitMix64> {
+		if self.index >= self.fence {
+			return None;
+		}
+
+		self.index += 1;
+
+		let seed = self.random.next_u64();
+
+		Some(SplitMix64 { seed, gamma: mix_gamma(self.random.next_u64()) })
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match usize::try_from(self.estimate_size()) {
+			Ok(size) => (size, Some(size)),
+			Err(_) => (usize::MAX, None)
+		}
+	}
+}
@@ -0,0 +1,41 @@
+use {Random, MASK};
+
+/// Plain-old-data snapshot of a `Random`, suitable for copying into shared memory, GPU buffers, or memory-mapped files.
+///
+/// The layout is `#[repr(C)]` with no padding, and every bit pattern is a valid value, so the type meets the
+/// requirements of `bytemuck::Pod`. Bits of `state` above the low 48 are ignored when converting back to a `Random`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RandomState {
+	/// Internal 48-bit LCG state
+	pub state: u64,
+	/// Pending gaussian value, only meaningful if `have_next_gaussian` is non-zero
+	pub next_gaussian: f64,
+	/// Non-zero if `next_gaussian` holds a pending value
+	pub have_next_gaussian: u64
+}
+
+impl<'a> From<&'a Random> for RandomState {
+	fn from(random: &'a Random) -> Self {
+		RandomState {
+			state: random.state,
+			next_gaussian: random.next_gaussian.unwrap_or(0.0),
+			have_next_gaussian: random.next_gaussian.is_some() as u64
+		}
+	}
+}
+
+impl From<Random> for RandomState {
+	fn from(random: Random) -> Self {
+		RandomState::from(&random)
+	}
+}
+
+impl From<RandomState> for Random {
+	fn from(state: RandomState) -> Self {
+		Random {
+			state: state.state & MASK,
+			next_gaussian: if state.have_next_gaussian != 0 { Some(state.next_gaussian) } else { None }
+		}
+	}
+}
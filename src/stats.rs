@@ -0,0 +1,284 @@
+//! Statistical tests for checking that derived streams, such as salted children or split shards, look uniform.
+//!
+//! The tests take plain samples, so they work with any generator through the endless iterators:
+//!
+//! ```
+//! use java_rand::{stats, Random};
+//!
+//! let mut random = Random::new(1234).derive_child_str("loot");
+//! let counts: [u64; 10] = stats::histogram(random.iter_u32_bound(10).take(10_000));
+//!
+//! assert!(stats::chi_square(&counts).p_value > 0.001);
+//! ```
+//!
+//! Each test returns its statistic together with a p-value, the probability of a result at least as extreme from a
+//! truly uniform source. A p-value below a small threshold such as 0.001 is strong evidence against uniformity. The
+//! p-values are computed with the `strictmath` ports, so they are reproducible across platforms.
+
+use core::f64::consts::PI;
+
+use strictmath;
+
+/// Result of a statistical test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outcome {
+	/// Value of the test statistic
+	pub statistic: f64,
+	/// Probability of a statistic at least this extreme under the hypothesis that the samples are uniform
+	pub p_value: f64
+}
+
+/// Counts how many samples fall into each of the `N` buckets.
+///
+/// # Panics
+/// If a sample is not less than `N`, the function panics.
+pub fn histogram<I: IntoIterator<Item = u32>, const N: usize>(samples: I) -> [u64; N] {
+	let mut counts = [0; N];
+
+	for sample in samples {
+		match counts.get_mut(sample as usize) {
+			Some(count) => *count += 1,
+			None => panic!("Sample must be less than the bucket count")
+		}
+	}
+
+	counts
+}
+
+/// Pearson's chi-square test of `counts` against equal expected counts in every bucket.
+///
+/// # Panics
+/// If there are fewer than 2 buckets, or no samples at all, the function panics.
+pub fn chi_square(counts: &[u64]) -> Outcome {
+	if counts.len() < 2 {
+		panic!("At least 2 buckets are required")
+	}
+
+	let total: u64 = counts.iter().sum();
+
+	if total == 0 {
+		panic!("At least 1 sample is required")
+	}
+
+	let expected = total as f64 / counts.len() as f64;
+	let statistic = counts.iter().map(|&count| (count as f64 - expected) * (count as f64 - expected) / expected).sum();
+	let degrees_of_freedom = (counts.len() - 1) as f64;
+
+	Outcome {
+		statistic,
+		p_value: gamma_q(degrees_of_freedom / 2.0, statistic / 2.0)
+	}
+}
+
+/// One-sample Kolmogorov-Smirnov test of `samples` against the uniform distribution on [0, 1). The statistic is the
+/// largest distance between the empirical and the uniform distribution function. The samples are sorted in place.
+///
+/// # Panics
+/// If `samples` is empty, the function panics.
+pub fn kolmogorov_smirnov(samples: &mut [f64]) -> Outcome {
+	if samples.is_empty() {
+		panic!("At least 1 sample is required")
+	}
+
+	samples.sort_unstable_by(f64::total_cmp);
+
+	let n = samples.len() as f64;
+	let mut statistic: f64 = 0.0;
+
+	for (index, &sample) in samples.iter().enumerate() {
+		let below = sample - index as f64 / n;
+		let above = (index + 1) as f64 / n - sample;
+
+		statistic = statistic.max(below).max(above);
+	}
+
+	let root = strictmath::sqrt(n);
+
+	Outcome {
+		statistic,
+		p_value: kolmogorov_q((root + 0.12 + 0.11 / root) * statistic)
+	}
+}
+
+/// Knuth's serial correlation test: the correlation between each sample and the next, wrapping around at the end.
+/// Independent samples give a coefficient close to `-1 / (n - 1)`, and the p-value uses the normal approximation of
+/// its distribution.
+///
+/// # Panics
+/// If there are fewer than 3 samples, or all samples are equal, the function panics.
+pub fn serial_correlation<I: IntoIterator<Item = f64>>(samples: I) -> Outcome {
+	let mut samples = samples.into_iter();
+
+	let first = match samples.next() {
+		Some(first) => first,
+		None => panic!("At least 3 samples are required")
+	};
+
+	let mut count = 1u64;
+	let mut sum = first;
+	let mut squares = first * first;
+	let mut products = 0.0;
+	let mut previous = first;
+
+	for sample in samples {
+		count += 1;
+		sum += sample;
+		squares += sample * sample;
+		products += previous * sample;
+		previous = sample;
+	}
+
+	if count < 3 {
+		panic!("At least 3 samples are required")
+	}
+
+	products += previous * first;
+
+	let n = count as f64;
+	let denominator = n * squares - sum * sum;
+
+	if denominator == 0.0 || denominator.is_nan() {
+		panic!("Samples must not all be equal")
+	}
+
+	let statistic = (n * products - sum * sum) / denominator;
+
+	let mean = -1.0 / (n - 1.0);
+	let deviation = strictmath::sqrt(n * n / ((n - 1.0) * (n - 1.0) * (n - 2.0)));
+	let z = (statistic - mean) / deviation;
+
+	Outcome {
+		statistic,
+		p_value: gamma_q(0.5, z * z / 2.0)
+	}
+}
+
+const EPSILON: f64 = 1e-15;
+const MAX_ITERATIONS: u32 = 1000;
+
+/// Natural logarithm of the gamma function for `x >= 0.5`, using the Lanczos approximation with g = 7.
+fn ln_gamma(x: f64) -> f64 {
+	const COEFFICIENTS: [f64; 8] = [
+		676.5203681218851,
+		-1259.1392167224028,
+		771.3234287776531,
+		-176.6150291621406,
+		12.507343278686905,
+		-0.13857109526572012,
+		9.984369578019572e-6,
+		1.5056327351493116e-7
+	];
+
+	let x = x - 1.0;
+	let mut series = 0.999_999_999_999_809_9;
+
+	for (index, &coefficient) in COEFFICIENTS.iter().enumerate() {
+		series += coefficient / (x + (index + 1) as f64);
+	}
+
+	let t = x + 7.5;
+
+	0.9189385332046728 + (x + 0.5) * strictmath::log(t) - t + strictmath::log(series)
+}
+
+/// Regularized upper incomplete gamma function Q(a, x), the chi-square tail probability for `a = k / 2`.
+fn gamma_q(a: f64, x: f64) -> f64 {
+	if x <= 0.0 {
+		return 1.0;
+	}
+
+	let scale = strictmath::exp(-x + a * strictmath::log(x) - ln_gamma(a));
+
+	if x < a + 1.0 {
+		// Series for the lower function P(a, x)
+		let mut denominator = a;
+		let mut term = 1.0 / a;
+		let mut sum = term;
+
+		for _ in 0..MAX_ITERATIONS {
+			denominator += 1.0;
+			term *= x / denominator;
+			sum += term;
+
+			if term.abs() < sum.abs() * EPSILON {
+				break;
+			}
+		}
+
+		(1.0 - sum * scale).max(0.0)
+	} else {
+		// Continued fraction for Q(a, x), evaluated with the modified Lentz method
+		const TINY: f64 = 1e-300;
+
+		let mut b = x + 1.0 - a;
+		let mut c = 1.0 / TINY;
+		let mut d = 1.0 / b;
+		let mut fraction = d;
+
+		for index in 1..MAX_ITERATIONS {
+			let an = -(index as f64) * (index as f64 - a);
+			b += 2.0;
+
+			d = an * d + b;
+			if d.abs() < TINY {
+				d = TINY;
+			}
+
+			c = b + an / c;
+			if c.abs() < TINY {
+				c = TINY;
+			}
+
+			d = 1.0 / d;
+
+			let delta = d * c;
+			fraction *= delta;
+
+			if (delta - 1.0).abs() < EPSILON {
+				break;
+			}
+		}
+
+		fraction * scale
+	}
+}
+
+/// Tail probability of the Kolmogorov distribution, using the series that converges quickly for each range of `lambda`.
+fn kolmogorov_q(lambda: f64) -> f64 {
+	if lambda <= 0.0 {
+		return 1.0;
+	}
+
+	let mut sum = 0.0;
+
+	if lambda < 1.18 {
+		let factor = -PI * PI / (8.0 * lambda * lambda);
+
+		for j in 0..MAX_ITERATIONS {
+			let odd = (2 * j + 1) as f64;
+			let term = strictmath::exp(factor * odd * odd);
+			sum += term;
+
+			if term < EPSILON * sum {
+				break;
+			}
+		}
+
+		(1.0 - strictmath::sqrt(2.0 * PI) / lambda * sum).max(0.0)
+	} else {
+		let mut sign = 1.0;
+
+		for j in 1..MAX_ITERATIONS {
+			let j = j as f64;
+			let term = strictmath::exp(-2.0 * j * j * lambda * lambda);
+			sum += sign * term;
+			sign = -sign;
+
+			if term < EPSILON * sum.abs() {
+				break;
+			}
+		}
+
+		(2.0 * sum).clamp(0.0, 1.0)
+	}
+}
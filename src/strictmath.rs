@@ -0,0 +1,1376 @@
+//! Ports of the fdlibm routines behind Java's `StrictMath`, producing bit-identical results on every platform.
+//!
+//! Platform libm implementations are free to differ in the last bit, which is enough to make `nextGaussian` diverge
+//! from Java, as well as any code that derives values from `sin`, `exp` and so on. These functions only use basic
+//! IEEE 754 arithmetic, and therefore also work without std.
+
+// Constants are kept exactly as written in the fdlibm sources.
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
+const TWO54: f64 = 1.80143985094819840000e+16;
+
+fn high_word(x: f64) -> i32 {
+	(x.to_bits() >> 32) as i32
+}
+
+fn low_word(x: f64) -> u32 {
+	x.to_bits() as u32
+}
+
+fn from_words(high: i32, low: u32) -> f64 {
+	f64::from_bits(((high as u32 as u64) << 32) | low as u64)
+}
+
+fn with_high_word(x: f64, high: i32) -> f64 {
+	from_words(high, low_word(x))
+}
+
+const LN2_HI: f64 = 6.93147180369123816490e-01; // 0x3FE62E42_FEE00000
+const LN2_LO: f64 = 1.90821492927058770002e-10; // 0x3DEA39EF_35793C76
+const LG1: f64 = 6.666666666666735130e-01; // 0x3FE55555_55555593
+const LG2: f64 = 3.999999999940941908e-01; // 0x3FD99999_9997FA04
+const LG3: f64 = 2.857142874366239149e-01; // 0x3FD24924_94229359
+const LG4: f64 = 2.222219843214978396e-01; // 0x3FCC71C5_1D8E78AF
+const LG5: f64 = 1.818357216161805012e-01; // 0x3FC74664_96CB03DE
+const LG6: f64 = 1.531383769920937332e-01; // 0x3FC39A09_D078C69F
+const LG7: f64 = 1.479819860511658591e-01; // 0x3FC2F112_DF3E5244
+
+/// Returns the natural logarithm of `x`, equivalent to `StrictMath.log` (fdlibm `__ieee754_log`).
+pub fn log(x: f64) -> f64 {
+	let mut x = x;
+	let mut hx = high_word(x);
+	let lx = low_word(x);
+
+	let mut k = 0;
+
+	if hx < 0x00100000 {
+		// x < 2^-1022
+		if ((hx & 0x7fffffff) as u32 | lx) == 0 {
+			return f64::NEG_INFINITY;
+		}
+
+		if hx < 0 {
+			return f64::NAN;
+		}
+
+		// Subnormal, scale up x
+		k -= 54;
+		x *= TWO54;
+		hx = high_word(x);
+	}
+
+	if hx >= 0x7ff00000 {
+		return x + x;
+	}
+
+	k += (hx >> 20) - 1023;
+	hx &= 0x000fffff;
+
+	// Normalize x or x/2
+	let i = (hx + 0x95f64) & 0x100000;
+	x = with_high_word(x, hx | (i ^ 0x3ff00000));
+	k += i >> 20;
+
+	let f = x - 1.0;
+
+	if (0x000fffff & (2 + hx)) < 3 {
+		// |f| < 2^-20
+		if f == 0.0 {
+			if k == 0 {
+				return 0.0;
+			}
+
+			let dk = k as f64;
+			return dk * LN2_HI + dk * LN2_LO;
+		}
+
+		let r = f * f * (0.5 - 0.33333333333333333 * f);
+
+		if k == 0 {
+			return f - r;
+		}
+
+		let dk = k as f64;
+		return dk * LN2_HI - ((r - dk * LN2_LO) - f);
+	}
+
+	let s = f / (2.0 + f);
+	let dk = k as f64;
+	let z = s * s;
+	let w = z * z;
+	let t1 = w * (LG2 + w * (LG4 + w * LG6));
+	let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+	let r = t2 + t1;
+
+	if ((hx - 0x6147a) | (0x6b851 - hx)) > 0 {
+		let hfsq = 0.5 * f * f;
+
+		if k == 0 {
+			f - (hfsq - s * (hfsq + r))
+		} else {
+			dk * LN2_HI - ((hfsq - (s * (hfsq + r) + dk * LN2_LO)) - f)
+		}
+	} else if k == 0 {
+		f - s * (f - r)
+	} else {
+		dk * LN2_HI - ((s * (f - r) - dk * LN2_LO) - f)
+	}
+}
+
+/// Shifts the 64-bit integer formed by the high and low words left by one bit.
+fn shift_left(high: i32, low: u32) -> (i32, u32) {
+	(high + high + (low >> 31) as i32, low.wrapping_add(low))
+}
+
+/// Returns the correctly rounded square root of `x`, equivalent to `StrictMath.sqrt` (fdlibm `__ieee754_sqrt`).
+///
+/// The root is computed bit by bit with integer arithmetic, so the result does not depend on a hardware square root.
+pub fn sqrt(x: f64) -> f64 {
+	const SIGN: u32 = 0x80000000;
+
+	let mut ix0 = high_word(x);
+	let mut ix1 = low_word(x);
+
+	// Inf and NaN
+	if (ix0 & 0x7ff00000) == 0x7ff00000 {
+		return x * x + x;
+	}
+
+	if ix0 <= 0 {
+		// sqrt(+-0) = +-0
+		if ((ix0 & !(SIGN as i32)) as u32 | ix1) == 0 {
+			return x;
+		}
+
+		if ix0 < 0 {
+			return f64::NAN;
+		}
+	}
+
+	// Normalize x
+	let mut m = ix0 >> 20;
+
+	if m == 0 {
+		// Subnormal
+		while ix0 == 0 {
+			m -= 21;
+			ix0 |= (ix1 >> 11) as i32;
+			ix1 <<= 21;
+		}
+
+		let mut i = 0;
+
+		while (ix0 & 0x00100000) == 0 {
+			ix0 <<= 1;
+			i += 1;
+		}
+
+		m -= i - 1;
+		ix0 |= ix1.checked_shr(32 - i as u32).unwrap_or(0) as i32;
+		ix1 <<= i;
+	}
+
+	// Unbias the exponent, and double x if it is odd to make it even
+	m -= 1023;
+	ix0 = (ix0 & 0x000fffff) | 0x00100000;
+
+	if (m & 1) != 0 {
+		(ix0, ix1) = shift_left(ix0, ix1);
+	}
+
+	m >>= 1;
+
+	// Generate sqrt(x) bit by bit into [q, q1]
+	(ix0, ix1) = shift_left(ix0, ix1);
+
+	let mut q: i32 = 0;
+	let mut q1: u32 = 0;
+	let mut s0: i32 = 0;
+	let mut s1: u32 = 0;
+
+	// Moving bit from right to left
+	let mut r: u32 = 0x00200000;
+
+	while r != 0 {
+		let t = s0 + r as i32;
+
+		if t <= ix0 {
+			s0 = t + r as i32;
+			ix0 -= t;
+			q += r as i32;
+		}
+
+		(ix0, ix1) = shift_left(ix0, ix1);
+		r >>= 1;
+	}
+
+	r = SIGN;
+
+	while r != 0 {
+		let t1 = s1.wrapping_add(r);
+		let t = s0;
+
+		if t < ix0 || (t == ix0 && t1 <= ix1) {
+			s1 = t1.wrapping_add(r);
+
+			if (t1 & SIGN) == SIGN && (s1 & SIGN) == 0 {
+				s0 += 1;
+			}
+
+			ix0 -= t;
+
+			if ix1 < t1 {
+				ix0 -= 1;
+			}
+
+			ix1 = ix1.wrapping_sub(t1);
+			q1 = q1.wrapping_add(r);
+		}
+
+		(ix0, ix1) = shift_left(ix0, ix1);
+		r >>= 1;
+	}
+
+	// A non-zero remainder means the result is inexact: round to nearest, ties to even. fdlibm probes the rounding
+	// mode with floating point additions here, but Rust always rounds to nearest.
+	if (ix0 as u32 | ix1) != 0 {
+		if q1 == 0xffffffff {
+			q1 = 0;
+			q += 1;
+		} else {
+			q1 += q1 & 1;
+		}
+	}
+
+	ix0 = (q >> 1) + 0x3fe00000;
+	ix1 = q1 >> 1;
+
+	if (q & 1) == 1 {
+		ix1 |= SIGN;
+	}
+
+	ix0 += m << 20;
+
+	from_words(ix0, ix1)
+}
+
+const HUGE: f64 = 1.0e300;
+const TINY: f64 = 1.0e-300;
+const TWOM54: f64 = 5.55111512312578270212e-17;
+
+/// Returns `x * 2^n`, equivalent to fdlibm `scalbn`.
+fn scalbn(x: f64, n: i32) -> f64 {
+	let mut x = x;
+	let mut hx = high_word(x);
+	let lx = low_word(x);
+
+	let mut k = (hx & 0x7ff00000) >> 20;
+
+	if k == 0 {
+		// 0 or subnormal x
+		if (lx | (hx & 0x7fffffff) as u32) == 0 {
+			return x;
+		}
+
+		x *= TWO54;
+		hx = high_word(x);
+		k = ((hx & 0x7ff00000) >> 20) - 54;
+
+		if n < -50000 {
+			return TINY * x;
+		}
+	}
+
+	if k == 0x7ff {
+		// NaN or Inf
+		return x + x;
+	}
+
+	k += n;
+
+	if k > 0x7fe {
+		return HUGE * HUGE.copysign(x);
+	}
+
+	if k > 0 {
+		return with_high_word(x, (hx & 0x800fffffu32 as i32) | (k << 20));
+	}
+
+	if k <= -54 {
+		return if n > 50000 { HUGE * HUGE.copysign(x) } else { TINY * TINY.copysign(x) };
+	}
+
+	// Subnormal result
+	k += 54;
+
+	with_high_word(x, (hx & 0x800fffffu32 as i32) | (k << 20)) * TWOM54
+}
+
+/// Equivalent to `floor(x)` for non-negative `x` below 2^63, which is all kernel_rem_pio2 needs.
+fn floor_positive(x: f64) -> f64 {
+	x as i64 as f64
+}
+
+const S1: f64 = -1.66666666666666324348e-01; // 0xBFC55555_55555549
+const S2: f64 = 8.33333333332248946124e-03; // 0x3F811111_1110F8A6
+const S3: f64 = -1.98412698298579493134e-04; // 0xBF2A01A0_19C161D5
+const S4: f64 = 2.75573137070700676789e-06; // 0x3EC71DE3_57B1FE7D
+const S5: f64 = -2.50507602534068634195e-08; // 0xBE5AE5E6_8A2B9CEB
+const S6: f64 = 1.58969099521155010221e-10; // 0x3DE5D93A_5ACFD57C
+
+/// fdlibm `__kernel_sin`: sin(x + y) on [-pi/4, pi/4], where y is the tail of x. `iy` indicates whether y is 0.
+fn kernel_sin(x: f64, y: f64, iy: i32) -> f64 {
+	let ix = high_word(x) & 0x7fffffff;
+
+	// |x| < 2^-27
+	if ix < 0x3e400000 && x as i32 == 0 {
+		return x;
+	}
+
+	let z = x * x;
+	let v = z * x;
+	let r = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+
+	if iy == 0 {
+		x + v * (S1 + z * r)
+	} else {
+		x - ((z * (0.5 * y - v * r) - y) - v * S1)
+	}
+}
+
+const C1: f64 = 4.16666666666666019037e-02; // 0x3FA55555_5555554C
+const C2: f64 = -1.38888888888741095749e-03; // 0xBF56C16C_16C15177
+const C3: f64 = 2.48015872894767294178e-05; // 0x3EFA01A0_19CB1590
+const C4: f64 = -2.75573143513906633035e-07; // 0xBE927E4F_809C52AD
+const C5: f64 = 2.08757232129817482790e-09; // 0x3E21EE9E_BDB4B1C4
+const C6: f64 = -1.13596475577881948265e-11; // 0xBDA8FAE9_BE8838D4
+
+/// fdlibm `__kernel_cos`: cos(x + y) on [-pi/4, pi/4], where y is the tail of x.
+fn kernel_cos(x: f64, y: f64) -> f64 {
+	let ix = high_word(x) & 0x7fffffff;
+
+	// |x| < 2^-27
+	if ix < 0x3e400000 && x as i32 == 0 {
+		return 1.0;
+	}
+
+	let z = x * x;
+	let r = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+
+	if ix < 0x3FD33333 {
+		// |x| < 0.3
+		return 1.0 - (0.5 * z - (z * r - x * y));
+	}
+
+	let qx = if ix > 0x3fe90000 {
+		// |x| > 0.78125
+		0.28125
+	} else {
+		// x/4
+		from_words(ix - 0x00200000, 0)
+	};
+
+	let hz = 0.5 * z - qx;
+	let a = 1.0 - qx;
+
+	a - (hz - (z * r - x * y))
+}
+
+/// Table of 2/pi in 24-bit chunks, for large argument reduction.
+const TWO_OVER_PI: [i32; 66] = [
+	0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62,
+	0x95993C, 0x439041, 0xFE5163, 0xABDEBB, 0xC561B7, 0x246E3A,
+	0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
+	0xA73EE8, 0x8235F5, 0x2EBB44, 0x84E99C, 0x7026B4, 0x5F7E41,
+	0x3991D6, 0x398353, 0x39F49C, 0x845F8B, 0xBDF928, 0x3B1FF8,
+	0x97FFDE, 0x05980F, 0xEF2F11, 0x8B5A0A, 0x6D1F6D, 0x367ECF,
+	0x27CB09, 0xB74F46, 0x3F669E, 0x5FEA2D, 0x7527BA, 0xC7EBE5,
+	0xF17B3D, 0x0739F7, 0x8A5292, 0xEA6BFB, 0x5FB11F, 0x8D5D08,
+	0x560330, 0x46FC7B, 0x6BABF0, 0xCFBC20, 0x9AF436, 0x1DA9E3,
+	0x91615E, 0xE61B08, 0x659985, 0x5F14A0, 0x68408D, 0xFFD880,
+	0x4D7327, 0x310606, 0x1556CA, 0x73A8C9, 0x60E27B, 0xC08C6B
+];
+
+/// High words of n * pi/2 for n in 1..=32, used to detect cancellation.
+const NPIO2_HW: [i32; 32] = [
+	0x3FF921FB, 0x400921FB, 0x4012D97C, 0x401921FB, 0x401F6A7A, 0x4022D97C,
+	0x4025FDBB, 0x402921FB, 0x402C463A, 0x402F6A7A, 0x4031475C, 0x4032D97C,
+	0x40346B9C, 0x4035FDBB, 0x40378FDB, 0x403921FB, 0x403AB41B, 0x403C463A,
+	0x403DD85A, 0x403F6A7A, 0x40407E4C, 0x4041475C, 0x4042106C, 0x4042D97C,
+	0x4043A28C, 0x40446B9C, 0x404534AC, 0x4045FDBB, 0x4046C6CB, 0x40478FDB,
+	0x404858EB, 0x404921FB
+];
+
+/// pi/2 split into 24-bit pieces.
+const PIO2: [f64; 8] = [
+	1.57079625129699707031e+00, // 0x3FF921FB_40000000
+	7.54978941586159635335e-08, // 0x3E74442D_00000000
+	5.39030252995776476554e-15, // 0x3CF84698_80000000
+	3.28200341580791294123e-22, // 0x3B78CC51_60000000
+	1.27065575308067607349e-29, // 0x39F01B83_80000000
+	1.22933308981111328932e-36, // 0x387A2520_40000000
+	2.73370053816464559624e-44, // 0x36E38222_80000000
+	2.16741683877804819444e-51  // 0x3569F31D_00000000
+];
+
+const TWO24: f64 = 1.67772160000000000000e+07;
+const TWON24: f64 = 5.96046447753906250000e-08;
+
+/// fdlibm `__kernel_rem_pio2` with `prec = 2` (53-bit result tails): reduces the 24-bit chunks `x` of a large
+/// argument, scaled by 2^e0, modulo pi/2. Returns the octant and writes the remainder to `y` as a head and tail.
+fn kernel_rem_pio2(x: &[f64], y: &mut [f64; 2], e0: i32) -> i32 {
+	// Initial number of terms for double precision
+	let jk = 4;
+	let jp = jk;
+
+	let jx = x.len() - 1;
+	let jv = ((e0 - 3) / 24).max(0);
+	let mut q0 = e0 - 24 * (jv + 1);
+
+	let mut f = [0.0; 20];
+	let mut q = [0.0; 20];
+	let mut fq = [0.0; 20];
+	let mut iq = [0i32; 20];
+
+	// Set up f[0] to f[jx + jk] where f[jx + jk] = TWO_OVER_PI[jv + jk]
+	for (j, item) in ((jv - jx as i32)..).zip(f.iter_mut().take(jx + jk + 1)) {
+		*item = if j < 0 { 0.0 } else { TWO_OVER_PI[j as usize] as f64 };
+	}
+
+	// Compute q[0], q[1], ..., q[jk]
+	for i in 0..=jk {
+		let mut fw = 0.0;
+
+		for j in 0..=jx {
+			fw += x[j] * f[jx + i - j];
+		}
+
+		q[i] = fw;
+	}
+
+	let mut jz = jk;
+
+	loop {
+		// Distill q[] into iq[] in reverse
+		let mut z = q[jz];
+		let mut j = jz;
+
+		for item in iq.iter_mut().take(jz) {
+			let fw = ((TWON24 * z) as i32) as f64;
+			*item = (z - TWO24 * fw) as i32;
+			z = q[j - 1] + fw;
+			j -= 1;
+		}
+
+		// Compute n
+		z = scalbn(z, q0);
+		z -= 8.0 * floor_positive(z * 0.125);
+
+		let mut n = z as i32;
+		z -= n as f64;
+
+		let mut ih = 0;
+
+		if q0 > 0 {
+			// Need iq[jz - 1] to determine n
+			let i = iq[jz - 1] >> (24 - q0);
+			n += i;
+			iq[jz - 1] -= i << (24 - q0);
+			ih = iq[jz - 1] >> (23 - q0);
+		} else if q0 == 0 {
+			ih = iq[jz - 1] >> 23;
+		} else if z >= 0.5 {
+			ih = 2;
+		}
+
+		if ih > 0 {
+			// q > 0.5, compute 1 - q
+			n += 1;
+
+			let mut carry = 0;
+
+			for item in iq.iter_mut().take(jz) {
+				let j = *item;
+
+				if carry == 0 {
+					if j != 0 {
+						carry = 1;
+						*item = 0x1000000 - j;
+					}
+				} else {
+					*item = 0xffffff - j;
+				}
+			}
+
+			if q0 == 1 {
+				iq[jz - 1] &= 0x7fffff;
+			} else if q0 == 2 {
+				iq[jz - 1] &= 0x3fffff;
+			}
+
+			if ih == 2 {
+				z = 1.0 - z;
+
+				if carry != 0 {
+					z -= scalbn(1.0, q0);
+				}
+			}
+		}
+
+		// Check if recomputation is needed
+		if z == 0.0 && iq[jk..jz].iter().fold(0, |acc, &chunk| acc | chunk) == 0 {
+			// k = number of additional terms needed
+			let mut k = 1;
+
+			while iq[jk - k] == 0 {
+				k += 1;
+			}
+
+			// Add q[jz + 1] to q[jz + k]
+			for i in (jz + 1)..=(jz + k) {
+				f[jx + i] = TWO_OVER_PI[jv as usize + i] as f64;
+
+				let mut fw = 0.0;
+
+				for j in 0..=jx {
+					fw += x[j] * f[jx + i - j];
+				}
+
+				q[i] = fw;
+			}
+
+			jz += k;
+			continue;
+		}
+
+		// Chop off zero terms
+		if z == 0.0 {
+			jz -= 1;
+			q0 -= 24;
+
+			while iq[jz] == 0 {
+				jz -= 1;
+				q0 -= 24;
+			}
+		} else {
+			// Break z into 24-bit chunks if necessary
+			z = scalbn(z, -q0);
+
+			if z >= TWO24 {
+				let fw = ((TWON24 * z) as i32) as f64;
+				iq[jz] = (z - TWO24 * fw) as i32;
+				jz += 1;
+				q0 += 24;
+				iq[jz] = fw as i32;
+			} else {
+				iq[jz] = z as i32;
+			}
+		}
+
+		// Convert the integer chunks to floating point values
+		let mut fw = scalbn(1.0, q0);
+
+		for i in (0..=jz).rev() {
+			q[i] = fw * iq[i] as f64;
+			fw *= TWON24;
+		}
+
+		// Compute PIO2[0..=jp] * q[jz..=0]
+		for i in (0..=jz).rev() {
+			let mut fw = 0.0;
+			let mut k = 0;
+
+			while k <= jp && k <= jz - i {
+				fw += PIO2[k] * q[i + k];
+				k += 1;
+			}
+
+			fq[jz - i] = fw;
+		}
+
+		// Compress fq[] into y[]
+		let mut fw = 0.0;
+
+		for i in (0..=jz).rev() {
+			fw += fq[i];
+		}
+
+		y[0] = if ih == 0 { fw } else { -fw };
+
+		fw = fq[0] - fw;
+
+		for item in fq.iter().take(jz + 1).skip(1) {
+			fw += item;
+		}
+
+		y[1] = if ih == 0 { fw } else { -fw };
+
+		return n & 7;
+	}
+}
+
+const INVPIO2: f64 = 6.36619772367581382433e-01; // 0x3FE45F30_6DC9C883
+const PIO2_1: f64 = 1.57079632673412561417e+00; // 0x3FF921FB_54400000
+const PIO2_1T: f64 = 6.07710050650619224932e-11; // 0x3DD0B461_1A626331
+const PIO2_2: f64 = 6.07710050630396597660e-11; // 0x3DD0B461_1A600000
+const PIO2_2T: f64 = 2.02226624879595063154e-21; // 0x3BA3198A_2E037073
+const PIO2_3: f64 = 2.02226624871116645580e-21; // 0x3BA3198A_2E000000
+const PIO2_3T: f64 = 8.47842766036889956997e-32; // 0x397B839A_252049C1
+
+/// fdlibm `__ieee754_rem_pio2`: returns n and writes x - n*pi/2 to `y` as a head and tail.
+fn rem_pio2(x: f64, y: &mut [f64; 2]) -> i32 {
+	let hx = high_word(x);
+	let ix = hx & 0x7fffffff;
+
+	if ix <= 0x3fe921fb {
+		// |x| ~<= pi/4, no need for reduction
+		y[0] = x;
+		y[1] = 0.0;
+		return 0;
+	}
+
+	if ix < 0x4002d97c {
+		// |x| < 3pi/4, special case with n = +-1
+		if hx > 0 {
+			let mut z = x - PIO2_1;
+
+			if ix != 0x3ff921fb {
+				// 33+53 bit pi is good enough
+				y[0] = z - PIO2_1T;
+				y[1] = (z - y[0]) - PIO2_1T;
+			} else {
+				// Near pi/2, use 33+33+53 bit pi
+				z -= PIO2_2;
+				y[0] = z - PIO2_2T;
+				y[1] = (z - y[0]) - PIO2_2T;
+			}
+
+			return 1;
+		} else {
+			let mut z = x + PIO2_1;
+
+			if ix != 0x3ff921fb {
+				y[0] = z + PIO2_1T;
+				y[1] = (z - y[0]) + PIO2_1T;
+			} else {
+				z += PIO2_2;
+				y[0] = z + PIO2_2T;
+				y[1] = (z - y[0]) + PIO2_2T;
+			}
+
+			return -1;
+		}
+	}
+
+	if ix <= 0x413921fb {
+		// |x| ~<= 2^19 * (pi/2), medium size
+		let t = x.abs();
+		let n = (t * INVPIO2 + 0.5) as i32;
+		let fn_ = n as f64;
+		let mut r = t - fn_ * PIO2_1;
+
+		// 1st round, good to 85 bits
+		let mut w = fn_ * PIO2_1T;
+
+		if n < 32 && ix != NPIO2_HW[n as usize - 1] {
+			// Quick check for no cancellation
+			y[0] = r - w;
+		} else {
+			let j = ix >> 20;
+			y[0] = r - w;
+
+			let i = j - ((high_word(y[0]) >> 20) & 0x7ff);
+
+			if i > 16 {
+				// 2nd iteration needed, good to 118 bits
+				let t = r;
+				w = fn_ * PIO2_2;
+				r = t - w;
+				w = fn_ * PIO2_2T - ((t - r) - w);
+				y[0] = r - w;
+
+				let i = j - ((high_word(y[0]) >> 20) & 0x7ff);
+
+				if i > 49 {
+					// 3rd iteration needed, 151 bits, covers all possible cases
+					let t = r;
+					w = fn_ * PIO2_3;
+					r = t - w;
+					w = fn_ * PIO2_3T - ((t - r) - w);
+					y[0] = r - w;
+				}
+			}
+		}
+
+		y[1] = (r - y[0]) - w;
+
+		if hx < 0 {
+			y[0] = -y[0];
+			y[1] = -y[1];
+			return -n;
+		}
+
+		return n;
+	}
+
+	if ix >= 0x7ff00000 {
+		// Inf or NaN
+		y[0] = f64::NAN;
+		y[1] = f64::NAN;
+		return 0;
+	}
+
+	// Set z = scalbn(|x|, ilogb(x) - 23), and split it into 24-bit chunks
+	let e0 = (ix >> 20) - 1046;
+	let mut z = from_words(ix - (e0 << 20), low_word(x));
+
+	let mut tx = [0.0; 3];
+
+	for item in tx.iter_mut().take(2) {
+		*item = (z as i32) as f64;
+		z = (z - *item) * TWO24;
+	}
+
+	tx[2] = z;
+
+	// Skip zero terms
+	let mut nx = 3;
+
+	while tx[nx - 1] == 0.0 {
+		nx -= 1;
+	}
+
+	let n = kernel_rem_pio2(&tx[..nx], y, e0);
+
+	if hx < 0 {
+		y[0] = -y[0];
+		y[1] = -y[1];
+		return -n;
+	}
+
+	n
+}
+
+/// Returns the sine of `x`, equivalent to `StrictMath.sin` (fdlibm `sin`).
+pub fn sin(x: f64) -> f64 {
+	let ix = high_word(x) & 0x7fffffff;
+
+	if ix <= 0x3fe921fb {
+		return kernel_sin(x, 0.0, 0);
+	}
+
+	if ix >= 0x7ff00000 {
+		return f64::NAN;
+	}
+
+	let mut y = [0.0; 2];
+
+	match rem_pio2(x, &mut y) & 3 {
+		0 => kernel_sin(y[0], y[1], 1),
+		1 => kernel_cos(y[0], y[1]),
+		2 => -kernel_sin(y[0], y[1], 1),
+		_ => -kernel_cos(y[0], y[1])
+	}
+}
+
+/// Returns the cosine of `x`, equivalent to `StrictMath.cos` (fdlibm `cos`).
+pub fn cos(x: f64) -> f64 {
+	let ix = high_word(x) & 0x7fffffff;
+
+	if ix <= 0x3fe921fb {
+		return kernel_cos(x, 0.0);
+	}
+
+	if ix >= 0x7ff00000 {
+		return f64::NAN;
+	}
+
+	let mut y = [0.0; 2];
+
+	match rem_pio2(x, &mut y) & 3 {
+		0 => kernel_cos(y[0], y[1]),
+		1 => -kernel_sin(y[0], y[1], 1),
+		2 => -kernel_cos(y[0], y[1]),
+		_ => kernel_sin(y[0], y[1], 1)
+	}
+}
+
+const O_THRESHOLD: f64 = 7.09782712893383973096e+02; // 0x40862E42_FEFA39EF
+const U_THRESHOLD: f64 = -7.45133219101941108420e+02; // 0xC0874910_D52D3051
+const TWOM1000: f64 = 9.33263618503218878990e-302; // 2^-1000
+const INVLN2: f64 = 1.44269504088896338700e+00; // 0x3FF71547_652B82FE
+const P1: f64 = 1.66666666666666019037e-01; // 0x3FC55555_5555553E
+const P2: f64 = -2.77777777770155933842e-03; // 0xBF66C16C_16BEBD93
+const P3: f64 = 6.61375632143793436117e-05; // 0x3F11566A_AF25DE2C
+const P4: f64 = -1.65339022054652515390e-06; // 0xBEBBBD41_C5D26BF1
+const P5: f64 = 4.13813679705723846039e-08; // 0x3E663769_72BEA4D0
+
+/// Returns e raised to the power of `x`, equivalent to `StrictMath.exp` (fdlibm `__ieee754_exp`).
+pub fn exp(x: f64) -> f64 {
+	let mut x = x;
+	let mut hx = high_word(x) as u32;
+	let xsb = ((hx >> 31) & 1) as i32;
+
+	// High word of |x|
+	hx &= 0x7fffffff;
+
+	// Filter out non-finite arguments
+	if hx >= 0x40862E42 {
+		// |x| >= 709.78...
+		if hx >= 0x7ff00000 {
+			if ((hx & 0xfffff) | low_word(x)) != 0 {
+				// NaN
+				return x + x;
+			}
+
+			// exp(+-inf) = {inf, 0}
+			return if xsb == 0 { x } else { 0.0 };
+		}
+
+		if x > O_THRESHOLD {
+			return f64::INFINITY;
+		}
+
+		if x < U_THRESHOLD {
+			return 0.0;
+		}
+	}
+
+	// Argument reduction
+	let mut hi = 0.0;
+	let mut lo = 0.0;
+	let mut k = 0;
+
+	if hx > 0x3fd62e42 {
+		// |x| > 0.5 ln2
+		if hx < 0x3FF0A2B2 {
+			// and |x| < 1.5 ln2
+			if xsb == 0 {
+				hi = x - LN2_HI;
+				lo = LN2_LO;
+			} else {
+				hi = x + LN2_HI;
+				lo = -LN2_LO;
+			}
+
+			k = 1 - xsb - xsb;
+		} else {
+			k = (INVLN2 * x + if xsb == 0 { 0.5 } else { -0.5 }) as i32;
+
+			let t = k as f64;
+
+			// t * LN2_HI is exact here
+			hi = x - t * LN2_HI;
+			lo = t * LN2_LO;
+		}
+
+		x = hi - lo;
+	} else if hx < 0x3e300000 {
+		// |x| < 2^-28
+		return 1.0 + x;
+	}
+
+	// x is now in the primary range
+	let t = x * x;
+	let c = x - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+
+	if k == 0 {
+		return 1.0 - ((x * c) / (c - 2.0) - x);
+	}
+
+	let y = 1.0 - ((lo - (x * c) / (2.0 - c)) - hi);
+
+	if k >= -1021 {
+		// Add k to the exponent of y
+		with_high_word(y, high_word(y) + (k << 20))
+	} else {
+		with_high_word(y, high_word(y) + ((k + 1000) << 20)) * TWOM1000
+	}
+}
+
+const BP: [f64; 2] = [1.0, 1.5];
+const DP_H: [f64; 2] = [0.0, 5.84962487220764160156e-01]; // 0x3FE2B803_40000000
+const DP_L: [f64; 2] = [0.0, 1.35003920212974897128e-08]; // 0x3E4CFDEB_43CFD006
+const TWO53: f64 = 9007199254740992.0;
+
+// Polynomial coefficients for (3/2) * (log(x) - 2s - 2/3 * s^3)
+const L1: f64 = 5.99999999999994648725e-01; // 0x3FE33333_33333303
+const L2: f64 = 4.28571428578550184252e-01; // 0x3FDB6DB6_DB6FABFF
+const L3: f64 = 3.33333329818377432918e-01; // 0x3FD55555_518F264D
+const L4: f64 = 2.72728123808534006489e-01; // 0x3FD17460_A91D4101
+const L5: f64 = 2.30660745775561754067e-01; // 0x3FCD864A_93C9DB65
+const L6: f64 = 2.06975017800338417784e-01; // 0x3FCA7E28_4A454EEF
+
+const LN2: f64 = 6.93147180559945286227e-01; // 0x3FE62E42_FEFA39EF
+const LN2_H: f64 = 6.93147182464599609375e-01; // 0x3FE62E43_00000000
+const LN2_L: f64 = -1.90465429995776804525e-09; // 0xBE205C61_0CA86C39
+const OVT: f64 = 8.0085662595372944372e-0017; // -(1024 - log2(ovfl + .5ulp))
+const CP: f64 = 9.61796693925975554329e-01; // 0x3FEEC709_DC3A03FD = 2/(3 ln2)
+const CP_H: f64 = 9.61796700954437255859e-01; // 0x3FEEC709_E0000000 = (float) cp
+const CP_L: f64 = -7.02846165095275826516e-09; // 0xBE3E2FE0_145B01F5 = tail of cp_h
+const IVLN2: f64 = 1.44269504088896338700e+00; // 0x3FF71547_652B82FE = 1/ln2
+const IVLN2_H: f64 = 1.44269502162933349609e+00; // 0x3FF71547_60000000 = 24 bits of 1/ln2
+const IVLN2_L: f64 = 1.92596299112661746887e-08; // 0x3E54AE0B_F85DDF44 = tail of 1/ln2
+
+/// Clears the low word of `x`, keeping only the high 21 bits of the significand.
+fn clear_low_word(x: f64) -> f64 {
+	from_words(high_word(x), 0)
+}
+
+/// Returns `x` raised to the power of `y`, equivalent to `StrictMath.pow` (fdlibm `__ieee754_pow`).
+pub fn pow(x: f64, y: f64) -> f64 {
+	let hx = high_word(x);
+	let lx = low_word(x);
+	let hy = high_word(y);
+	let ly = low_word(y);
+
+	let mut ix = hx & 0x7fffffff;
+	let iy = hy & 0x7fffffff;
+
+	// x^0 = 1
+	if (iy as u32 | ly) == 0 {
+		return 1.0;
+	}
+
+	// Propagate NaNs
+	if ix > 0x7ff00000 || (ix == 0x7ff00000 && lx != 0) || iy > 0x7ff00000 || (iy == 0x7ff00000 && ly != 0) {
+		return x + y;
+	}
+
+	// When x < 0, determine if y is an odd integer (1), an even integer (2), or not an integer (0)
+	let mut yisint = 0;
+
+	if hx < 0 {
+		if iy >= 0x43400000 {
+			yisint = 2;
+		} else if iy >= 0x3ff00000 {
+			let k = (iy >> 20) - 0x3ff;
+
+			if k > 20 {
+				let j = ly >> (52 - k);
+
+				if (j << (52 - k)) == ly {
+					yisint = 2 - (j & 1) as i32;
+				}
+			} else if ly == 0 {
+				let j = iy >> (20 - k);
+
+				if (j << (20 - k)) == iy {
+					yisint = 2 - (j & 1);
+				}
+			}
+		}
+	}
+
+	// Special values of y
+	if ly == 0 {
+		if iy == 0x7ff00000 {
+			// y is +-inf
+			return if ((ix - 0x3ff00000) as u32 | lx) == 0 {
+				// inf^+-1 is NaN
+				f64::NAN
+			} else if ix >= 0x3ff00000 {
+				// (|x| > 1)^+-inf = inf, 0
+				if hy >= 0 { y } else { 0.0 }
+			} else {
+				// (|x| < 1)^-,+inf = inf, 0
+				if hy < 0 { -y } else { 0.0 }
+			};
+		}
+
+		if iy == 0x3ff00000 {
+			// y is +-1
+			return if hy < 0 { 1.0 / x } else { x };
+		}
+
+		if hy == 0x40000000 {
+			// y is 2
+			return x * x;
+		}
+
+		if hy == 0x3fe00000 && hx >= 0 {
+			// y is 0.5 and x >= +0
+			return sqrt(x);
+		}
+	}
+
+	let mut ax = x.abs();
+
+	// Special values of x
+	if lx == 0 && (ix == 0x7ff00000 || ix == 0 || ix == 0x3ff00000) {
+		// x is +-0, +-inf, +-1
+		let mut z = ax;
+
+		if hy < 0 {
+			z = 1.0 / z;
+		}
+
+		if hx < 0 {
+			if ((ix - 0x3ff00000) | yisint) == 0 {
+				// (-1)^non-int is NaN
+				z = f64::NAN;
+			} else if yisint == 1 {
+				// (x < 0)^odd = -(|x|^odd)
+				z = -z;
+			}
+		}
+
+		return z;
+	}
+
+	let mut n = (hx >> 31) + 1;
+
+	// (x < 0)^(non-int) is NaN
+	if (n | yisint) == 0 {
+		return f64::NAN;
+	}
+
+	// Sign of the result, negative for (-ve)^(odd int)
+	let s = if (n | (yisint - 1)) == 0 { -1.0 } else { 1.0 };
+
+	let t1;
+	let t2;
+
+	if iy > 0x41e00000 {
+		// |y| > 2^31
+		if iy > 0x43f00000 {
+			// |y| > 2^64, must overflow or underflow
+			if ix <= 0x3fefffff {
+				return if hy < 0 { HUGE * HUGE } else { TINY * TINY };
+			}
+
+			if ix >= 0x3ff00000 {
+				return if hy > 0 { HUGE * HUGE } else { TINY * TINY };
+			}
+		}
+
+		// Overflow or underflow if x is not close to one
+		if ix < 0x3fefffff {
+			return if hy < 0 { s * HUGE * HUGE } else { s * TINY * TINY };
+		}
+
+		if ix > 0x3ff00000 {
+			return if hy > 0 { s * HUGE * HUGE } else { s * TINY * TINY };
+		}
+
+		// Now |1 - x| is tiny <= 2^-20, so log(x) can be computed by x - x^2/2 + x^3/3 - x^4/4
+		let t = ax - 1.0;
+		let w = (t * t) * (0.5 - t * (0.3333333333333333333333 - t * 0.25));
+		let u = IVLN2_H * t;
+		let v = t * IVLN2_L - w * IVLN2;
+
+		t1 = clear_low_word(u + v);
+		t2 = v - (t1 - u);
+	} else {
+		n = 0;
+
+		// Take care of subnormal numbers
+		if ix < 0x00100000 {
+			ax *= TWO53;
+			n -= 53;
+			ix = high_word(ax);
+		}
+
+		n += (ix >> 20) - 0x3ff;
+
+		let j = ix & 0x000fffff;
+
+		// Determine the interval
+		ix = j | 0x3ff00000;
+
+		let k = if j <= 0x3988E {
+			// |x| < sqrt(3/2)
+			0
+		} else if j < 0xBB67A {
+			// |x| < sqrt(3)
+			1
+		} else {
+			n += 1;
+			ix -= 0x00100000;
+
+			0
+		};
+
+		ax = with_high_word(ax, ix);
+
+		// Compute ss = s_h + s_l = (x - 1)/(x + 1) or (x - 1.5)/(x + 1.5)
+		let u = ax - BP[k];
+		let v = 1.0 / (ax + BP[k]);
+		let ss = u * v;
+		let s_h = clear_low_word(ss);
+
+		// t_h = ax + BP[k], high part
+		let t_h = from_words(((ix >> 1) | 0x20000000) + 0x00080000 + ((k as i32) << 18), 0);
+		let t_l = ax - (t_h - BP[k]);
+		let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+
+		// Compute log(ax)
+		let s2 = ss * ss;
+		let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+		r += s_l * (s_h + ss);
+
+		let s2 = s_h * s_h;
+		let t_h = clear_low_word(3.0 + s2 + r);
+		let t_l = r - ((t_h - 3.0) - s2);
+
+		// u + v = ss * (1 + ...)
+		let u = s_h * t_h;
+		let v = s_l * t_h + t_l * ss;
+
+		// 2/(3 log2) * (ss + ...)
+		let p_h = clear_low_word(u + v);
+		let p_l = v - (p_h - u);
+
+		// CP_H + CP_L = 2/(3 log2)
+		let z_h = CP_H * p_h;
+		let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+
+		// log2(ax) = (ss + ...) * 2/(3 log2) = n + DP_H + z_h + z_l
+		let t = n as f64;
+
+		t1 = clear_low_word(((z_h + z_l) + DP_H[k]) + t);
+		t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+	}
+
+	// Split y into y1 + y2 and compute (y1 + y2) * (t1 + t2)
+	let y1 = clear_low_word(y);
+	let p_l = (y - y1) * t1 + y * t2;
+	let mut p_h = y1 * t1;
+	let z = p_l + p_h;
+
+	let j = high_word(z);
+	let i = low_word(z);
+
+	if j >= 0x40900000 {
+		// z >= 1024
+		if ((j - 0x40900000) as u32 | i) != 0 || p_l + OVT > z - p_h {
+			return s * HUGE * HUGE;
+		}
+	} else if (j & 0x7fffffff) >= 0x4090cc00 {
+		// z <= -1075
+		if ((j as u32).wrapping_sub(0xc090cc00) | i) != 0 || p_l <= z - p_h {
+			return s * TINY * TINY;
+		}
+	}
+
+	// Compute 2^(p_h + p_l)
+	let i = j & 0x7fffffff;
+	let mut k = (i >> 20) - 0x3ff;
+	let mut n = 0;
+
+	if i > 0x3fe00000 {
+		// |z| > 0.5, set n = [z + 0.5]
+		n = j + (0x00100000 >> (k + 1));
+
+		// New k for n
+		k = ((n & 0x7fffffff) >> 20) - 0x3ff;
+
+		let t = from_words(n & !(0x000fffff >> k), 0);
+		n = ((n & 0x000fffff) | 0x00100000) >> (20 - k);
+
+		if j < 0 {
+			n = -n;
+		}
+
+		p_h -= t;
+	}
+
+	let t = clear_low_word(p_l + p_h);
+	let u = t * LN2_H;
+	let v = (p_l - (t - p_h)) * LN2 + t * LN2_L;
+	let z = u + v;
+	let w = v - (z - u);
+	let t = z * z;
+	let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+	let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+	let z = 1.0 - (r - z);
+
+	let j = high_word(z) + (n << 20);
+
+	if (j >> 20) <= 0 {
+		// Subnormal output
+		s * scalbn(z, n)
+	} else {
+		s * with_high_word(z, j)
+	}
+}
+
+const ATANHI: [f64; 4] = [
+	4.63647609000806093515e-01, // atan(0.5) hi 0x3FDDAC67_0561BB4F
+	7.85398163397448278999e-01, // atan(1.0) hi 0x3FE921FB_54442D18
+	9.82793723247329054082e-01, // atan(1.5) hi 0x3FEF730B_D281F69B
+	1.57079632679489655800e+00  // atan(inf) hi 0x3FF921FB_54442D18
+];
+
+const ATANLO: [f64; 4] = [
+	2.26987774529616870924e-17, // atan(0.5) lo 0x3C7A2B7F_222F65E2
+	3.06161699786838301793e-17, // atan(1.0) lo 0x3C81A626_33145C07
+	1.39033110312309984516e-17, // atan(1.5) lo 0x3C700788_7AF0CBBD
+	6.12323399573676603587e-17  // atan(inf) lo 0x3C91A626_33145C07
+];
+
+const AT: [f64; 11] = [
+	3.33333333333329318027e-01,  // 0x3FD55555_5555550D
+	-1.99999999998764832476e-01, // 0xBFC99999_9998EBC4
+	1.42857142725034663711e-01,  // 0x3FC24924_920083FF
+	-1.11111104054623557880e-01, // 0xBFBC71C6_FE231671
+	9.09088713343650656196e-02,  // 0x3FB745CD_C54C206E
+	-7.69187620504482999495e-02, // 0xBFB3B0F2_AF749A6D
+	6.66107313738753120669e-02,  // 0x3FB10D66_A0D03D51
+	-5.83357013379057348645e-02, // 0xBFADDE2D_52DEFD9A
+	4.97687799461593236017e-02,  // 0x3FA97B4B_24760DEB
+	-3.65315727442169155270e-02, // 0xBFA2B444_2C6A6C2F
+	1.62858201153657823623e-02   // 0x3F90AD3A_E322DA11
+];
+
+/// Returns the arc tangent of `x`, equivalent to `StrictMath.atan` (fdlibm `atan`).
+pub fn atan(x: f64) -> f64 {
+	let mut x = x;
+	let hx = high_word(x);
+	let ix = hx & 0x7fffffff;
+
+	if ix >= 0x44100000 {
+		// |x| >= 2^66
+		if ix > 0x7ff00000 || (ix == 0x7ff00000 && low_word(x) != 0) {
+			// NaN
+			return x + x;
+		}
+
+		return if hx > 0 { ATANHI[3] + ATANLO[3] } else { -ATANHI[3] - ATANLO[3] };
+	}
+
+	let id = if ix < 0x3fdc0000 {
+		// |x| < 0.4375
+		if ix < 0x3e200000 {
+			// |x| < 2^-29
+			return x;
+		}
+
+		None
+	} else {
+		x = x.abs();
+
+		if ix < 0x3ff30000 {
+			// |x| < 1.1875
+			if ix < 0x3fe60000 {
+				// 7/16 <= |x| < 11/16
+				x = (2.0 * x - 1.0) / (2.0 + x);
+				Some(0)
+			} else {
+				// 11/16 <= |x| < 19/16
+				x = (x - 1.0) / (x + 1.0);
+				Some(1)
+			}
+		} else if ix < 0x40038000 {
+			// |x| < 2.4375
+			x = (x - 1.5) / (1.0 + 1.5 * x);
+			Some(2)
+		} else {
+			// 2.4375 <= |x| < 2^66
+			x = -1.0 / x;
+			Some(3)
+		}
+	};
+
+	let z = x * x;
+	let w = z * z;
+
+	// Break the sum of AT[i] * z^(i + 1) into odd and even polynomials
+	let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+	let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+
+	match id {
+		None => x - x * (s1 + s2),
+		Some(id) => {
+			let z = ATANHI[id] - ((x * (s1 + s2) - ATANLO[id]) - x);
+
+			if hx < 0 { -z } else { z }
+		}
+	}
+}
+
+const PI_O_4: f64 = 7.8539816339744827900e-01; // 0x3FE921FB_54442D18
+const PI_O_2: f64 = 1.5707963267948965580e+00; // 0x3FF921FB_54442D18
+const PI: f64 = 3.1415926535897931160e+00; // 0x400921FB_54442D18
+const PI_LO: f64 = 1.2246467991473531772e-16; // 0x3CA1A626_33145C07
+
+/// Returns the angle of the point `(x, y)` in polar coordinates, equivalent to `StrictMath.atan2`
+/// (fdlibm `__ieee754_atan2`).
+pub fn atan2(y: f64, x: f64) -> f64 {
+	let hx = high_word(x);
+	let ix = hx & 0x7fffffff;
+	let lx = low_word(x);
+	let hy = high_word(y);
+	let iy = hy & 0x7fffffff;
+	let ly = low_word(y);
+
+	if x.is_nan() || y.is_nan() {
+		return x + y;
+	}
+
+	if (hx.wrapping_sub(0x3ff00000) as u32 | lx) == 0 {
+		// x = 1.0
+		return atan(y);
+	}
+
+	// 2 * sign(x) + sign(y)
+	let m = ((hy >> 31) & 1) | ((hx >> 30) & 2);
+
+	// When y = 0
+	if (iy as u32 | ly) == 0 {
+		return match m {
+			0 | 1 => y,
+			2 => PI + TINY,
+			_ => -PI - TINY
+		};
+	}
+
+	// When x = 0
+	if (ix as u32 | lx) == 0 {
+		return if hy < 0 { -PI_O_2 - TINY } else { PI_O_2 + TINY };
+	}
+
+	// When x is infinite
+	if ix == 0x7ff00000 {
+		if iy == 0x7ff00000 {
+			return match m {
+				0 => PI_O_4 + TINY,
+				1 => -PI_O_4 - TINY,
+				2 => 3.0 * PI_O_4 + TINY,
+				_ => -3.0 * PI_O_4 - TINY
+			};
+		} else {
+			return match m {
+				0 => 0.0,
+				1 => -0.0,
+				2 => PI + TINY,
+				_ => -PI - TINY
+			};
+		}
+	}
+
+	// When y is infinite
+	if iy == 0x7ff00000 {
+		return if hy < 0 { -PI_O_2 - TINY } else { PI_O_2 + TINY };
+	}
+
+	// Compute y/x
+	let k = (iy - ix) >> 20;
+
+	let z = if k > 60 {
+		// |y/x| > 2^60
+		PI_O_2 + 0.5 * PI_LO
+	} else if hx < 0 && k < -60 {
+		// |y|/x < -2^60
+		0.0
+	} else {
+		atan((y / x).abs())
+	};
+
+	match m {
+		0 => z,
+		1 => -z,
+		2 => PI - (z - PI_LO),
+		_ => (z - PI_LO) - PI
+	}
+}
@@ -0,0 +1,226 @@
+//! Emulation of Java subclasses of `Random` that override `next(int)`, such as deterministic mocks.
+//!
+//! Implement `NextBits` for the custom core and wrap it in a `Subclass` to get the derived methods exactly as
+//! `java.util.Random` implements them on top of `next`:
+//!
+//! ```
+//! use java_rand::{NextBits, Subclass};
+//!
+//! /// Like `new Random() { protected int next(int bits) { return 0; } }`
+//! struct Zero;
+//!
+//! impl NextBits for Zero {
+//!     fn next(&mut self, _bits: u8) -> i32 {
+//!         0
+//!     }
+//! }
+//!
+//! let mut random = Subclass::new(Zero);
+//!
+//! assert_eq!(random.next_i32_bound(10), 0);
+//! assert_eq!(random.next_f64(), 0.0);
+//! ```
+
+use {Error, Random};
+
+/// Source of random bits, the equivalent of overriding `protected int next(int bits)` in Java.
+pub trait NextBits {
+	/// Returns an integer whose low `bits` bits are random and whose other bits are zero, where `bits` is between 1
+	/// and 32. As in Java, the result for 32 bits uses the sign bit.
+	fn next(&mut self, bits: u8) -> i32;
+
+	/// Returns the pending gaussian of cores that keep one themselves, like `Random`, so that a `Subclass` wrapping
+	/// the core uses it instead of its own. The default returns None, leaving the pending gaussian to the `Subclass`.
+	fn next_gaussian_slot(&mut self) -> Option<&mut Option<f64>> {
+		None
+	}
+}
+
+impl NextBits for Random {
+	fn next(&mut self, bits: u8) -> i32 {
+		Random::next(self, bits)
+	}
+
+	fn next_gaussian_slot(&mut self) -> Option<&mut Option<f64>> {
+		Some(&mut self.next_gaussian)
+	}
+}
+
+impl<N: NextBits + ?Sized> NextBits for &mut N {
+	fn next(&mut self, bits: u8) -> i32 {
+		(**self).next(bits)
+	}
+
+	fn next_gaussian_slot(&mut self) -> Option<&mut Option<f64>> {
+		(**self).next_gaussian_slot()
+	}
+}
+
+/// A `Random` whose `next` has been replaced by `N`, providing the methods that Java derives from `next`. Like a Java
+/// subclass, it carries the pending gaussian inherited from `Random`, unless the core keeps one itself as reported by
+/// `NextBits::next_gaussian_slot`, in which case the core's is used so that it survives wrapping and unwrapping.
+#[derive(Debug, Clone)]
+pub struct Subclass<N> {
+	core: N,
+	next_gaussian: Option<f64>
+}
+
+impl<N: NextBits> Subclass<N> {
+	pub fn new(core: N) -> Self {
+		Subclass {
+			core,
+			next_gaussian: None
+		}
+	}
+
+	/// Returns the custom core.
+	pub fn core(&self) -> &N {
+		&self.core
+	}
+
+	/// Returns the custom core mutably.
+	pub fn core_mut(&mut self) -> &mut N {
+		&mut self.core
+	}
+
+	/// Unwraps the custom core, discarding any pending gaussian not kept by the core.
+	pub fn into_inner(self) -> N {
+		self.core
+	}
+
+	/// Calls the custom `next`.
+	pub fn next(&mut self, bits: u8) -> i32 {
+		self.core.next(bits)
+	}
+
+	/// Fills the byte array with random bytes, using one call to `next_i32` per 4 bytes, see `Random::next_bytes`.
+	pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+		for chunk in bytes.chunks_mut(4) {
+			let mut block = self.next_i32();
+
+			for item in chunk {
+				*item = block as u8;
+				block >>= 8;
+			}
+		}
+	}
+
+	/// Returns a uniformly distributed signed 32-bit integer.
+	pub fn next_i32(&mut self) -> i32 {
+		self.next(32)
+	}
+
+	/// Returns a uniformly distributed unsigned 32-bit integer.
+	pub fn next_u32(&mut self) -> u32 {
+		self.next_i32() as u32
+	}
+
+	/// Returns a positive random number in the range [0, max), see `Random::next_i32_bound`.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	pub fn next_i32_bound(&mut self, max: i32) -> i32 {
+		match self.try_next_i32_bound(max) {
+			Ok(value) => value,
+			Err(_) => panic!("Maximum must be > 0")
+		}
+	}
+
+	/// Like `next_i32_bound`, but returns `Error::InvalidBound` instead of panicking, without calling `next`.
+	pub fn try_next_i32_bound(&mut self, max: i32) -> Result<i32, Error> {
+		if max <= 0 {
+			return Err(Error::InvalidBound);
+		}
+
+		Ok(random_next_i32_bound!(self, max).0)
+	}
+
+	/// Returns a positive random number in the range [0, max), see `Random::next_u32_bound`.
+	///
+	/// # Panics
+	/// If `max` reinterpreted as a signed 32-bit integer is less than 1, the function panics.
+	pub fn next_u32_bound(&mut self, max: u32) -> u32 {
+		self.next_i32_bound(max as i32) as u32
+	}
+
+	/// Returns a uniformly distributed signed 64-bit integer.
+	pub fn next_i64(&mut self) -> i64 {
+		random_next_i64!(self)
+	}
+
+	/// Returns a uniformly distributed unsigned 64-bit integer.
+	pub fn next_u64(&mut self) -> u64 {
+		self.next_i64() as u64
+	}
+
+	/// Returns a boolean value that has an equal chance of being true or false.
+	pub fn next_bool(&mut self) -> bool {
+		self.next(1) != 0
+	}
+
+	/// Returns a f32 uniformly distributed between 0.0 and 1.0.
+	pub fn next_f32(&mut self) -> f32 {
+		random_next_f32!(self)
+	}
+
+	/// Returns a f64 uniformly distributed between 0.0 and 1.0.
+	pub fn next_f64(&mut self) -> f64 {
+		random_next_f64!(self)
+	}
+
+	/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0, see
+	/// `Random::next_gaussian`.
+	pub fn next_gaussian(&mut self) -> f64 {
+		if let Some(next) = self.next_gaussian_slot().take() {
+			return next;
+		}
+
+		let ((v0, v1), _) = random_next_gaussian_pair!(self);
+		*self.next_gaussian_slot() = Some(v1);
+
+		v0
+	}
+
+	fn next_gaussian_slot(&mut self) -> &mut Option<f64> {
+		match self.core.next_gaussian_slot() {
+			Some(slot) => slot,
+			None => &mut self.next_gaussian
+		}
+	}
+
+	/// Shuffles `items` like `Collections.shuffle(list, random)`, see `Random::shuffle`.
+	///
+	/// # Panics
+	/// If `items` has more than `i32::MAX` items, the function panics.
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		shuffle(items, |bound| self.next_i32_bound(bound))
+	}
+}
+
+impl<N: NextBits> From<N> for Subclass<N> {
+	fn from(core: N) -> Self {
+		Subclass::new(core)
+	}
+}
+
+impl Random {
+	/// Shuffles `items` exactly like `Collections.shuffle(list, random)` in Java: from the last index down to 1, each
+	/// item is swapped with the item at `next_i32_bound(index + 1)`.
+	///
+	/// # Panics
+	/// If `items` has more than `i32::MAX` items, the function panics.
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		shuffle(items, |bound| self.next_i32_bound(bound))
+	}
+}
+
+/// `Collections.shuffle` on top of `nextInt(bound)`.
+fn shuffle<T, F: FnMut(i32) -> i32>(items: &mut [T], mut next_i32_bound: F) {
+	if items.len() > i32::MAX as usize {
+		panic!("Too many items to shuffle")
+	}
+
+	for index in (1..items.len()).rev() {
+		items.swap(index, next_i32_bound(index as i32 + 1) as usize);
+	}
+}
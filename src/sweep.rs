@@ -0,0 +1,305 @@
+//! Bookkeeping for exhaustive sweeps over all 2^48 internal states, split into shards that can be handed out to
+//! different threads or machines.
+//!
+//! Shards cover internal states rather than seeds: `Random::new` scrambles the seed with the multiplier, so the
+//! generator with internal state `s` is the one created from seed `s ^ 0x5DEECE66D`. Shards have a textual form,
+//! such as `shard:0x0+0x1000000000`, and a 16 byte big endian form, for sending them to workers.
+
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use {Error, Random, MULTIPLIER};
+
+/// Number of distinct internal states
+pub const STATE_SPACE: u64 = 1 << 48;
+
+/// A contiguous range of internal states, starting at `start` and containing `len` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shard {
+	start: u64,
+	len: u64
+}
+
+impl Shard {
+	/// Creates a shard of `len` states starting at `start`.
+	///
+	/// # Panics
+	/// If the shard would extend past the end of the state space, the function panics.
+	pub fn new(start: u64, len: u64) -> Self {
+		match Shard::try_new(start, len) {
+			Ok(shard) => shard,
+			Err(_) => panic!("Shard extends past the end of the state space")
+		}
+	}
+
+	/// Like `new`, but returns `Error::InvalidShard` instead of panicking.
+	pub fn try_new(start: u64, len: u64) -> Result<Self, Error> {
+		Shard::checked(start, len).ok_or(Error::InvalidShard)
+	}
+
+	/// Returns the shard covering the entire state space.
+	pub fn full() -> Self {
+		Shard::new(0, STATE_SPACE)
+	}
+
+	/// Returns shard `index` out of `count` shards of nearly equal size that together cover the state space.
+	///
+	/// # Panics
+	/// If `count` is 0 or larger than the state space, or if `index` is not less than `count`, the function panics.
+	pub fn nth_of(index: u64, count: u64) -> Self {
+		if count == 0 || count > STATE_SPACE {
+			panic!("Shard count must be in the range [1, 2^48]")
+		}
+
+		match Shard::try_nth_of(index, count) {
+			Ok(shard) => shard,
+			Err(_) => panic!("Shard index must be less than the shard count")
+		}
+	}
+
+	/// Like `nth_of`, but returns `Error::InvalidShard` instead of panicking.
+	pub fn try_nth_of(index: u64, count: u64) -> Result<Self, Error> {
+		if count == 0 || count > STATE_SPACE || index >= count {
+			return Err(Error::InvalidShard);
+		}
+
+		let boundary = |index: u64| ((index as u128 * STATE_SPACE as u128) / count as u128) as u64;
+		let start = boundary(index);
+
+		Shard::try_new(start, boundary(index + 1) - start)
+	}
+
+	/// Returns an iterator over `count` shards of nearly equal size that together cover the state space, in order.
+	///
+	/// # Panics
+	/// If `count` is 0 or larger than the state space, the function panics.
+	pub fn split(count: u64) -> impl Iterator<Item = Shard> {
+		match Shard::try_split(count) {
+			Ok(shards) => shards,
+			Err(_) => panic!("Shard count must be in the range [1, 2^48]")
+		}
+	}
+
+	/// Like `split`, but returns `Error::InvalidShard` instead of panicking.
+	pub fn try_split(count: u64) -> Result<impl Iterator<Item = Shard>, Error> {
+		if count == 0 || count > STATE_SPACE {
+			return Err(Error::InvalidShard);
+		}
+
+		Ok((0..count).map(move |index| Shard::nth_of(index, count)))
+	}
+
+	/// Returns the first state of the shard.
+	pub fn start(&self) -> u64 {
+		self.start
+	}
+
+	/// Returns the number of states in the shard.
+	pub fn len(&self) -> u64 {
+		self.len
+	}
+
+	/// Returns true if the shard contains no states.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the state after the last state of the shard.
+	pub fn end(&self) -> u64 {
+		self.start + self.len
+	}
+
+	/// Returns the range of internal states covered by the shard.
+	pub fn states(&self) -> Range<u64> {
+		self.start..self.end()
+	}
+
+	/// Returns the seeds that `Random::new` turns into the states of this shard, in the same order.
+	pub fn seeds(&self) -> impl Iterator<Item = u64> {
+		self.states().map(|state| state ^ MULTIPLIER)
+	}
+
+	/// Returns an iterator over generators positioned at each state of the shard.
+	pub fn randoms(&self) -> Randoms {
+		Randoms { states: self.states() }
+	}
+
+	/// Encodes the shard as 16 bytes: the start followed by the length, both big endian.
+	pub fn to_bytes(&self) -> [u8; 16] {
+		let mut bytes = [0; 16];
+
+		bytes[..8].copy_from_slice(&self.start.to_be_bytes());
+		bytes[8..].copy_from_slice(&self.len.to_be_bytes());
+
+		bytes
+	}
+
+	/// Decodes a shard encoded by `to_bytes`, returning None if it extends past the end of the state space.
+	pub fn from_bytes(bytes: [u8; 16]) -> Option<Self> {
+		let mut start = [0; 8];
+		let mut len = [0; 8];
+
+		start.copy_from_slice(&bytes[..8]);
+		len.copy_from_slice(&bytes[8..]);
+
+		Shard::checked(u64::from_be_bytes(start), u64::from_be_bytes(len))
+	}
+
+	fn checked(start: u64, len: u64) -> Option<Self> {
+		if start <= STATE_SPACE && len <= STATE_SPACE - start {
+			Some(Shard { start, len })
+		} else {
+			None
+		}
+	}
+}
+
+/// Iterator over generators positioned at each state of a shard, created by `Shard::randoms`.
+#[derive(Debug, Clone)]
+pub struct Randoms {
+	states: Range<u64>
+}
+
+impl Iterator for Randoms {
+	type Item = Random;
+
+	fn next(&mut self) -> Option<Random> {
+		self.states.next().map(|state| Random {
+			state,
+			next_gaussian: None
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.states.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for Randoms {
+	fn next_back(&mut self) -> Option<Random> {
+		self.states.next_back().map(|state| Random {
+			state,
+			next_gaussian: None
+		})
+	}
+}
+
+impl FusedIterator for Randoms {}
+
+/// Error returned when parsing the textual form of a `Shard` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseShardError(());
+
+impl Display for ParseShardError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("invalid shard, expected the form shard:0x<start>+0x<len> within the 48-bit state space")
+	}
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ParseShardError {}
+
+impl Display for Shard {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "shard:{:#x}+{:#x}", self.start, self.len)
+	}
+}
+
+impl FromStr for Shard {
+	type Err = ParseShardError;
+
+	fn from_str(s: &str) -> Result<Self, ParseShardError> {
+		let parse_hex = |hex: &str| hex.strip_prefix("0x").and_then(|hex| u64::from_str_radix(hex, 16).ok());
+
+		let mut parts = s.strip_prefix("shard:").ok_or(ParseShardError(()))?.split('+');
+
+		let start = parts.next().and_then(parse_hex);
+		let len = parts.next().and_then(parse_hex);
+
+		match (start, len, parts.next()) {
+			(Some(start), Some(len), None) => Shard::checked(start, len).ok_or(ParseShardError(())),
+			_ => Err(ParseShardError(()))
+		}
+	}
+}
+
+/// Tracks which parts of the state space have been swept, merging completed shards as results come in.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+	/// Disjoint, non-adjacent, non-empty shards sorted by start
+	done: Vec<Shard>
+}
+
+#[cfg(feature = "std")]
+impl Coverage {
+	pub fn new() -> Self {
+		Coverage { done: Vec::new() }
+	}
+
+	/// Marks the states of `shard` as swept. Overlapping or adjacent shards are merged.
+	pub fn insert(&mut self, shard: Shard) {
+		if shard.is_empty() {
+			return;
+		}
+
+		let mut start = shard.start();
+		let mut end = shard.end();
+
+		// Shards that overlap or touch the new shard are absorbed into it
+		let first = self.done.partition_point(|done| done.end() < start);
+		let last = self.done.partition_point(|done| done.start() <= end);
+
+		if first < last {
+			start = start.min(self.done[first].start());
+			end = end.max(self.done[last - 1].end());
+		}
+
+		self.done.splice(first..last, Some(Shard::new(start, end - start)));
+	}
+
+	/// Returns the number of states swept so far.
+	pub fn swept(&self) -> u64 {
+		self.done.iter().map(Shard::len).sum()
+	}
+
+	/// Returns true if every state of `shard` has been swept.
+	pub fn contains(&self, shard: Shard) -> bool {
+		shard.is_empty() || self.done.iter().any(|done| done.start() <= shard.start() && shard.end() <= done.end())
+	}
+
+	/// Returns true if the entire state space has been swept.
+	pub fn is_complete(&self) -> bool {
+		self.swept() == STATE_SPACE
+	}
+
+	/// Returns the swept parts of the state space, as sorted disjoint shards.
+	pub fn done(&self) -> &[Shard] {
+		&self.done
+	}
+
+	/// Returns the parts of the state space that still need to be swept, as sorted disjoint shards.
+	pub fn missing(&self) -> Vec<Shard> {
+		let mut missing = Vec::new();
+		let mut position = 0;
+
+		for done in &self.done {
+			if done.start() > position {
+				missing.push(Shard::new(position, done.start() - position));
+			}
+
+			position = done.end();
+		}
+
+		if position < STATE_SPACE {
+			missing.push(Shard::new(position, STATE_SPACE - position));
+		}
+
+		missing
+	}
+}
@@ -1,5 +1,8 @@
+use mix;
+use strictmath;
 use Random;
-use test_data::*;
+use test_vectors::*;
+use ziggurat;
 
 #[test]
 fn test_nextbytes() {
@@ -11,6 +14,40 @@ fn test_nextbytes() {
 	assert_eq!(&bytes as &[u8], &RAND_NEXTBYTES as &[u8]);
 }
 
+#[test]
+fn test_bytes() {
+	let mut random = Random::new(RAND_NEXTBYTES_SEED);
+
+	for (index, (gen, &elem)) in random.bytes().zip(RAND_NEXTBYTES.iter()).enumerate() {
+		if gen != elem {
+			panic!("mismatch at index {}: expected {}, got {}", index, elem, gen);
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reader() {
+	use std::io::Read;
+
+	let mut random = Random::new(RAND_NEXTBYTES_SEED);
+	let mut reader = random.reader();
+
+	let mut bytes = [0; 128];
+	let mut offset = 0;
+
+	for len in [1, 3, 4, 7, 13].iter().cycle() {
+		let end = (offset + len).min(bytes.len());
+		offset += reader.read(&mut bytes[offset..end]).unwrap();
+
+		if offset == bytes.len() {
+			break;
+		}
+	}
+
+	assert_eq!(&bytes as &[u8], &RAND_NEXTBYTES as &[u8]);
+}
+
 #[test]
 fn test_next32() {
 	let mut random = Random::new(RAND_NEXT32_SEED);
@@ -113,4 +150,3230 @@ fn test_nextgaussian() {
 			panic!("mismatch at index {}: expected {}, got {}", index, elem, gen);
 		}
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialized_write() {
+	let mut random = Random::new(RAND_SERIALIZED_SEED);
+	random.next_gaussian();
+
+	let mut bytes = Vec::new();
+	random.write_serialized(&mut bytes).unwrap();
+
+	assert_eq!(&bytes as &[u8], &RAND_SERIALIZED as &[u8]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialized_read() {
+	let mut expected = Random::new(RAND_SERIALIZED_SEED);
+	expected.next_gaussian();
+
+	let mut random = Random::read_serialized(&RAND_SERIALIZED as &[u8]).unwrap();
+
+	for _ in 0..16 {
+		assert_eq!(random.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialized_read_invalid() {
+	assert!(Random::read_serialized(&RAND_SERIALIZED[..40]).is_err());
+
+	let mut bytes = RAND_SERIALIZED;
+	bytes[10] = b'X';
+
+	assert!(Random::read_serialized(&bytes as &[u8]).is_err());
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi() {
+	use ffi::*;
+
+	unsafe {
+		let random = javarand_new(RAND_NEXT32_SEED as i64);
+
+		for (index, &elem) in RAND_NEXT32.iter().enumerate() {
+			let gen = javarand_next_int(random) as u32;
+
+			if gen != elem {
+				panic!("mismatch at index {}: expected {}, got {}", index, elem, gen);
+			}
+		}
+
+		assert_eq!(javarand_next_int_bound(random, 0), -1);
+
+		javarand_set_seed(random, RAND_NEXTBYTES_SEED as i64);
+
+		let mut bytes = [0; 128];
+		javarand_next_bytes(random, bytes.as_mut_ptr(), bytes.len());
+
+		assert_eq!(&bytes as &[u8], &RAND_NEXTBYTES as &[u8]);
+
+		javarand_free(random);
+		javarand_free(::std::ptr::null_mut());
+	}
+}
+
+#[cfg(feature = "jni-export")]
+#[test]
+fn test_jni() {
+	use jni::*;
+	use std::ptr::null_mut;
+
+	let (env, class) = (null_mut(), null_mut());
+
+	unsafe {
+		let random = Java_io_github_coderbot16_javarand_NativeRandom_create(env, class, RAND_NEXT32_SEED as i64);
+
+		for (index, &elem) in RAND_NEXT32.iter().enumerate() {
+			let gen = Java_io_github_coderbot16_javarand_NativeRandom_nextInt(env, class, random) as u32;
+
+			if gen != elem {
+				panic!("mismatch at index {}: expected {}, got {}", index, elem, gen);
+			}
+		}
+
+		// Stepping back over the whole sequence returns to the start
+		Java_io_github_coderbot16_javarand_NativeRandom_advance(env, class, random, -(RAND_NEXT32.len() as i64));
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeRandom_nextInt(env, class, random) as u32, RAND_NEXT32[0]);
+
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeRandom_nextIntBound(env, class, random, 0), -1);
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeRandom_next(env, class, random, 49), 0);
+
+		let cracker = Java_io_github_coderbot16_javarand_NativeCracker_create(env, class);
+
+		Java_io_github_coderbot16_javarand_NativeRandom_setSeed(env, class, random, RAND_NEXT64_SEED as i64);
+
+		for _ in 0..2 {
+			let value = Java_io_github_coderbot16_javarand_NativeRandom_nextInt(env, class, random);
+			Java_io_github_coderbot16_javarand_NativeCracker_nextInt(env, class, cracker, value);
+		}
+
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeCracker_solve(env, class, cracker), 1);
+		assert_eq!(
+			Java_io_github_coderbot16_javarand_NativeCracker_candidateSeed(env, class, cracker, 0),
+			(RAND_NEXT64_SEED & ((1 << 48) - 1)) as i64
+		);
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeCracker_candidateSeed(env, class, cracker, 1), -1);
+
+		Java_io_github_coderbot16_javarand_NativeCracker_nextIntBound(env, class, cracker, 0, 0);
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeCracker_solve(env, class, cracker), -1);
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeCracker_candidateSeed(env, class, cracker, 0), -1);
+
+		Java_io_github_coderbot16_javarand_NativeCracker_free(env, class, cracker);
+		Java_io_github_coderbot16_javarand_NativeCracker_free(env, class, 0);
+		Java_io_github_coderbot16_javarand_NativeRandom_free(env, class, random);
+		Java_io_github_coderbot16_javarand_NativeRandom_free(env, class, 0);
+	}
+}
+
+#[cfg(feature = "jni-export")]
+#[test]
+fn test_jni_fill() {
+	use jni::*;
+	use std::os::raw::c_void;
+	use std::ptr::null_mut;
+
+	// A stand-in for the JVM: arrays are pointers to their elements and length, and the function table only has the
+	// functions used by the fill methods
+	type FakeArray = (*mut c_void, usize);
+
+	unsafe extern "system" fn get_length(_env: JniEnv, array: JArray) -> i32 {
+		(*(array as *mut FakeArray)).1 as i32
+	}
+
+	unsafe extern "system" fn get_elements(_env: JniEnv, array: JArray, _is_copy: *mut JBoolean) -> *mut c_void {
+		(*(array as *mut FakeArray)).0
+	}
+
+	unsafe extern "system" fn release_elements(_env: JniEnv, _array: JArray, _elements: *mut c_void, _mode: i32) {}
+
+	let mut functions = [null_mut::<c_void>(); 229];
+
+	functions[171] = get_length as *mut c_void;
+	functions[222] = get_elements as *mut c_void;
+	functions[223] = release_elements as *mut c_void;
+
+	let mut table = functions.as_ptr();
+	let (env, class) = (&mut table as *mut _ as JniEnv, null_mut());
+
+	unsafe {
+		let handle = Java_io_github_coderbot16_javarand_NativeRandom_create(env, class, RAND_NEXT32_SEED as i64);
+		let mut reference = Random::new(RAND_NEXT32_SEED);
+
+		let mut ints = [0i32; 100];
+		let mut array: FakeArray = (ints.as_mut_ptr() as *mut c_void, ints.len());
+
+		Java_io_github_coderbot16_javarand_NativeRandom_fillInt(env, class, handle, &mut array as *mut _ as JArray);
+
+		for (index, &value) in ints.iter().enumerate() {
+			assert_eq!(value, reference.next_i32(), "mismatch at index {}", index);
+		}
+
+		let mut gaussians = [0.0f64; 37];
+		let mut array: FakeArray = (gaussians.as_mut_ptr() as *mut c_void, gaussians.len());
+
+		Java_io_github_coderbot16_javarand_NativeRandom_fillGaussian(env, class, handle, &mut array as *mut _ as JArray);
+
+		for (index, &value) in gaussians.iter().enumerate() {
+			assert_eq!(value.to_bits(), reference.next_gaussian().to_bits(), "mismatch at index {}", index);
+		}
+
+		// The second value of the last pair is left pending
+		assert_eq!(
+			Java_io_github_coderbot16_javarand_NativeRandom_nextGaussian(env, class, handle).to_bits(),
+			reference.next_gaussian().to_bits()
+		);
+
+		let mut array: FakeArray = (gaussians.as_mut_ptr() as *mut c_void, 0);
+
+		Java_io_github_coderbot16_javarand_NativeRandom_fillGaussian(env, class, handle, &mut array as *mut _ as JArray);
+		assert_eq!(Java_io_github_coderbot16_javarand_NativeRandom_nextInt(env, class, handle), reference.next_i32());
+
+		Java_io_github_coderbot16_javarand_NativeRandom_free(env, class, handle);
+	}
+}
+
+#[test]
+fn test_text_round_trip() {
+	let mut random = Random::new(RAND_NEXT64_SEED);
+
+	for _ in 0..16 {
+		let text = random.to_string();
+		let mut parsed: Random = text.parse().unwrap();
+
+		assert_eq!(parsed.to_string(), text);
+		assert_eq!(parsed.next_i64(), random.next_i64());
+	}
+
+	for &text in ["state:0x2a7f8e1c4b30;gauss:-0.8313453697589337", "state:0x0;gauss:1e-300"].iter() {
+		let parsed: Random = text.parse().unwrap();
+
+		assert_eq!(parsed.to_string().parse::<Random>().unwrap().to_string(), parsed.to_string());
+	}
+}
+
+#[test]
+fn test_text_gaussian() {
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	random.next_gaussian();
+
+	let mut parsed: Random = random.to_string().parse().unwrap();
+
+	assert_eq!(parsed.next_gaussian().to_bits(), random.next_gaussian().to_bits());
+	assert_eq!(parsed.next_gaussian().to_bits(), random.next_gaussian().to_bits());
+}
+
+#[test]
+fn test_text_invalid() {
+	assert_eq!(Random::new(0).to_string(), "state:0x5deece66d;gauss:none");
+
+	for text in ["", "state:0x5deece66d", "state:5deece66d;gauss:none", "state:0x1000000000000;gauss:none",
+		"state:0x5deece66d;gauss:", "state:0x5deece66d;gauss:NaN", "state:0x5deece66d;gauss:none;"].iter() {
+		assert!(text.parse::<Random>().is_err(), "{} should not parse", text);
+	}
+}
+
+#[test]
+fn test_random_state() {
+	use std::mem;
+	use RandomState;
+
+	assert_eq!(mem::size_of::<RandomState>(), 24);
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	random.next_i64();
+
+	let state = RandomState::from(&random);
+	assert_eq!(state.have_next_gaussian, 0);
+
+	let mut restored = Random::from(state);
+	assert_eq!(restored.next_i64(), random.next_i64());
+
+	let pending: Random = "state:0x2a7f8e1c4b30;gauss:-0.8313453697589337".parse().unwrap();
+	let state = RandomState::from(&pending);
+
+	assert_eq!(state.state, 0x2a7f8e1c4b30);
+	assert_eq!(state.have_next_gaussian, 1);
+	assert_eq!(Random::from(state).to_string(), pending.to_string());
+
+	let unmasked = RandomState { state: !0, ..RandomState::default() };
+	assert_eq!(Random::from(unmasked).to_string(), "state:0xffffffffffff;gauss:none");
+}
+
+#[test]
+fn test_eq_hash() {
+	use std::collections::HashSet;
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	assert_eq!(random, Random::new(RAND_NEXT32_SEED));
+
+	random.next_i32();
+	assert_ne!(random, Random::new(RAND_NEXT32_SEED));
+
+	let positive: Random = "state:0x0;gauss:0.0".parse().unwrap();
+	let negative: Random = "state:0x0;gauss:-0.0".parse().unwrap();
+	let none: Random = "state:0x0;gauss:none".parse().unwrap();
+
+	assert_ne!(positive, negative);
+	assert_ne!(positive, none);
+
+	let set: HashSet<Random> = vec![positive.clone(), negative, none, positive].into_iter().collect();
+	assert_eq!(set.len(), 3);
+}
+
+fn check_strictmath(function: fn(f64) -> f64, vectors: &[(u64, u64)]) {
+	for (index, &(input, elem)) in vectors.iter().enumerate() {
+		let gen = function(f64::from_bits(input));
+
+		if gen.to_bits() != elem && !(gen.is_nan() && f64::from_bits(elem).is_nan()) {
+			panic!("mismatch at index {} for input {:#x}: expected {:#x}, got {:#x}", index, input, elem, gen.to_bits());
+		}
+	}
+}
+
+#[test]
+fn test_strictmath_log() {
+	check_strictmath(strictmath::log, &STRICTMATH_LOG);
+
+	assert_eq!(strictmath::log(0.0), f64::NEG_INFINITY);
+	assert_eq!(strictmath::log(-0.0), f64::NEG_INFINITY);
+	assert_eq!(strictmath::log(1.0).to_bits(), 0);
+	assert_eq!(strictmath::log(f64::INFINITY), f64::INFINITY);
+	assert!(strictmath::log(-1.0).is_nan());
+	assert!(strictmath::log(f64::NAN).is_nan());
+
+	// Smallest subnormal: -1074 * ln(2)
+	assert_eq!(strictmath::log(f64::from_bits(1)), -744.4400719213812);
+}
+
+#[test]
+fn test_strictmath_sqrt() {
+	check_strictmath(strictmath::sqrt, &STRICTMATH_SQRT);
+
+	assert_eq!(strictmath::sqrt(0.0).to_bits(), 0.0f64.to_bits());
+	assert_eq!(strictmath::sqrt(-0.0).to_bits(), (-0.0f64).to_bits());
+	assert_eq!(strictmath::sqrt(f64::INFINITY), f64::INFINITY);
+	assert!(strictmath::sqrt(-1.0).is_nan());
+
+	// The hardware square root is also correctly rounded, so the two must agree everywhere.
+	let mut random = Random::new(RAND_NEXT64_SEED);
+
+	for bits in (0..4096).map(|_| random.next_u64() >> 1).chain(1..4096).chain((1..4096).map(|bits| bits << 40)) {
+		let x = f64::from_bits(bits);
+
+		assert_eq!(strictmath::sqrt(x).to_bits(), x.sqrt().to_bits(), "sqrt({:#x})", bits);
+	}
+}
+
+fn check_strictmath2(function: fn(f64, f64) -> f64, vectors: &[(u64, u64, u64)]) {
+	for (index, &(x, y, elem)) in vectors.iter().enumerate() {
+		let gen = function(f64::from_bits(x), f64::from_bits(y));
+
+		if gen.to_bits() != elem && !(gen.is_nan() && f64::from_bits(elem).is_nan()) {
+			panic!("mismatch at index {} for input ({:#x}, {:#x}): expected {:#x}, got {:#x}", index, x, y, elem, gen.to_bits());
+		}
+	}
+}
+
+#[test]
+fn test_strictmath_sin() {
+	check_strictmath(strictmath::sin, &STRICTMATH_SIN);
+
+	assert_eq!(strictmath::sin(-0.0).to_bits(), (-0.0f64).to_bits());
+	assert!(strictmath::sin(f64::INFINITY).is_nan());
+	assert!(strictmath::sin(f64::NAN).is_nan());
+
+	// Large arguments go through the multi-word reduction
+	assert_eq!(strictmath::sin(1e22), -0.8522008497671888);
+}
+
+#[test]
+fn test_strictmath_cos() {
+	check_strictmath(strictmath::cos, &STRICTMATH_COS);
+
+	assert_eq!(strictmath::cos(0.0), 1.0);
+	assert!(strictmath::cos(f64::NEG_INFINITY).is_nan());
+	assert_eq!(strictmath::cos(1e22), 0.523214785395139);
+}
+
+#[test]
+fn test_strictmath_exp() {
+	check_strictmath(strictmath::exp, &STRICTMATH_EXP);
+
+	assert_eq!(strictmath::exp(0.0), 1.0);
+	assert_eq!(strictmath::exp(f64::NEG_INFINITY).to_bits(), 0);
+	assert_eq!(strictmath::exp(f64::INFINITY), f64::INFINITY);
+	assert_eq!(strictmath::exp(710.0), f64::INFINITY);
+	assert_eq!(strictmath::exp(-745.0).to_bits(), 1);
+	assert!(strictmath::exp(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_strictmath_atan() {
+	check_strictmath(strictmath::atan, &STRICTMATH_ATAN);
+
+	assert_eq!(strictmath::atan(f64::INFINITY), core::f64::consts::FRAC_PI_2);
+	assert_eq!(strictmath::atan(-0.0).to_bits(), (-0.0f64).to_bits());
+}
+
+#[test]
+fn test_strictmath_atan2() {
+	check_strictmath2(strictmath::atan2, &STRICTMATH_ATAN2);
+
+	assert_eq!(strictmath::atan2(0.0, -1.0), core::f64::consts::PI);
+	assert_eq!(strictmath::atan2(-0.0, -1.0), -core::f64::consts::PI);
+	assert_eq!(strictmath::atan2(1.0, 0.0), core::f64::consts::FRAC_PI_2);
+	assert_eq!(strictmath::atan2(f64::INFINITY, f64::NEG_INFINITY), 3.0 * core::f64::consts::FRAC_PI_4);
+	assert!(strictmath::atan2(f64::NAN, 1.0).is_nan());
+}
+
+#[test]
+fn test_strictmath_pow() {
+	check_strictmath2(strictmath::pow, &STRICTMATH_POW);
+	check_strictmath2(strictmath::pow, &STRICTMATH_POW_NEGATIVE);
+
+	assert_eq!(strictmath::pow(f64::NAN, 0.0), 1.0);
+	assert_eq!(strictmath::pow(-2.0, 3.0), -8.0);
+	assert_eq!(strictmath::pow(2.0, -1074.0).to_bits(), 1);
+	assert_eq!(strictmath::pow(2.0, 1024.0), f64::INFINITY);
+	assert_eq!(strictmath::pow(-0.0, -1.0), f64::NEG_INFINITY);
+	assert!(strictmath::pow(-2.0, 0.5).is_nan());
+	assert!(strictmath::pow(-1.0, f64::INFINITY).is_nan());
+}
+
+/// Checks the float conversion selected by the `fast-float` and `deterministic` features against Java's division.
+#[test]
+fn test_unit_floats() {
+	use float;
+
+	for value in 0..(1u32 << 24) {
+		assert_eq!(float::unit_f32(value).to_bits(), (value as f32 / 16777216.0).to_bits(), "{}", value);
+	}
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+
+	for value in (0..4096).chain((0..65536).map(|_| random.next_u64() >> 11)).chain(((1u64 << 53) - 4096)..(1 << 53)) {
+		assert_eq!(float::unit_f64(value).to_bits(), (value as f64 / 9007199254740992.0).to_bits(), "{}", value);
+	}
+}
+
+/// Steps `random` by `calls` calls to `next`.
+fn skip_calls(random: &mut Random, calls: u32) {
+	for _ in 0..calls {
+		random.next(1);
+	}
+}
+
+#[test]
+fn test_counted() {
+	use calls;
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut model = random.clone();
+
+	let mut rejected = false;
+
+	for index in 0..4096 {
+		// Bounds just above a power of two reject the most
+		let bound = (1 << (index % 31)) + 1;
+		let (value, calls) = random.next_i32_bound_counted(bound);
+
+		rejected |= calls > 1;
+
+		assert!(value >= 0 && value < bound);
+		skip_calls(&mut model, calls);
+		assert_eq!(model, random);
+	}
+
+	assert!(rejected);
+
+	let (_, calls) = random.next_u32_bound_counted(1 << 20);
+	assert_eq!(calls, calls::NEXT_I32_BOUND_MIN);
+	skip_calls(&mut model, calls);
+
+	let mut pending = 0;
+
+	for _ in 0..1024 {
+		let (_, calls) = random.next_gaussian_counted();
+
+		if calls == 0 {
+			pending -= 1;
+		} else {
+			assert_eq!(calls % calls::NEXT_GAUSSIAN_PER_ATTEMPT, 0);
+			pending += 1;
+		}
+
+		skip_calls(&mut model, calls);
+		assert_eq!(model.state, random.state);
+	}
+
+	assert_eq!(pending, 0);
+
+	let mut expected = Random::new(1);
+	let mut actual = Random::new(1);
+	actual.next_bytes(&mut [0; 13]);
+	skip_calls(&mut expected, calls::next_bytes(13) as u32);
+	assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_ziggurat_gaussian() {
+	let mut random = Random::new(ZIGGURAT_GAUSSIAN_SEED);
+
+	for (index, &elem) in ZIGGURAT_GAUSSIAN.iter().enumerate() {
+		let gen = ziggurat::next_gaussian(|| random.next_i64());
+
+		if gen.to_bits() != elem {
+			panic!("mismatch at index {}: expected {}, got {}", index, f64::from_bits(elem), gen);
+		}
+	}
+}
+
+#[test]
+fn test_ziggurat_exponential() {
+	let mut random = Random::new(ZIGGURAT_EXPONENTIAL_SEED);
+
+	for (index, &elem) in ZIGGURAT_EXPONENTIAL.iter().enumerate() {
+		let gen = ziggurat::next_exponential(|| random.next_i64());
+
+		if gen.to_bits() != elem {
+			panic!("mismatch at index {}: expected {}, got {}", index, f64::from_bits(elem), gen);
+		}
+	}
+}
+
+#[test]
+fn test_f64_range() {
+	let mut random = Random::new(RAND_RANGE_F64_SEED);
+
+	for (index, &(origin, bound, elem)) in RAND_RANGE_F64.iter().enumerate() {
+		let gen = random.next_f64_range(f64::from_bits(origin), f64::from_bits(bound));
+
+		if gen.to_bits() != elem {
+			panic!("mismatch at index {}: expected {}, got {}", index, f64::from_bits(elem), gen);
+		}
+	}
+
+	let mut random = Random::new(RAND_BOUND_F64_SEED);
+
+	for (index, &(bound, elem)) in RAND_BOUND_F64.iter().enumerate() {
+		let gen = random.next_f64_bound(f64::from_bits(bound));
+
+		if gen.to_bits() != elem {
+			panic!("mismatch at index {}: expected {}, got {}", index, f64::from_bits(elem), gen);
+		}
+	}
+}
+
+#[test]
+fn test_f64_range_edges() {
+	use float;
+
+	// Largest output of next_f64
+	let max = 1.0 - 1.0 / 9007199254740992.0;
+
+	// Results that round up to the bound are clamped below it
+	assert_eq!(float::bounded_f64_range(max, 1.0, 1.0f64.next_up()), 1.0);
+	assert_eq!(float::bounded_f64_range(max, 1e15, 1e15 + 1.0), (1e15 + 1.0f64).next_down());
+	assert_eq!(float::bounded_f64(max, f64::from_bits(1)), 0.0);
+	assert_eq!(float::bounded_f64(max, f64::MAX), f64::MAX.next_down());
+
+	// Ranges wider than f64::MAX
+	assert_eq!(float::bounded_f64_range(0.0, f64::MIN, f64::MAX), f64::MIN);
+	assert_eq!(float::bounded_f64_range(0.5, f64::MIN, f64::MAX), 0.0);
+	assert!(float::bounded_f64_range(max, f64::MIN, f64::MAX) < f64::MAX);
+
+	let mut random = Random::new(0);
+
+	for _ in 0..1024 {
+		let value = random.next_f64_range(f64::MIN, f64::MAX);
+
+		assert!((f64::MIN..f64::MAX).contains(&value));
+	}
+}
+
+#[test]
+#[should_panic]
+fn test_f64_range_empty() {
+	Random::new(0).next_f64_range(1.0, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_f64_bound_infinite() {
+	Random::new(0).next_f64_bound(f64::INFINITY);
+}
+
+#[test]
+fn test_shards() {
+	use sweep::{Shard, STATE_SPACE};
+
+	let shards: Vec<Shard> = Shard::split(7).collect();
+
+	assert_eq!(shards[0].start(), 0);
+	assert_eq!(shards[6].end(), STATE_SPACE);
+
+	for pair in shards.windows(2) {
+		assert_eq!(pair[0].end(), pair[1].start());
+		assert!(pair[0].len() == pair[1].len() || pair[0].len() + 1 == pair[1].len() || pair[0].len() == pair[1].len() + 1);
+	}
+
+	assert_eq!(Shard::split(1).collect::<Vec<_>>(), vec![Shard::full()]);
+	assert_eq!(Shard::nth_of(STATE_SPACE - 1, STATE_SPACE), Shard::new(STATE_SPACE - 1, 1));
+
+	let shard = Shard::nth_of(3, 7);
+
+	assert_eq!(shard.to_string().parse::<Shard>(), Ok(shard));
+	assert_eq!(Shard::from_bytes(shard.to_bytes()), Some(shard));
+	assert_eq!("shard:0x10+0x20".parse::<Shard>(), Ok(Shard::new(0x10, 0x20)));
+
+	assert!("shard:0x1000000000000+0x1".parse::<Shard>().is_err());
+	assert!("shard:0x0+0x1000000000001".parse::<Shard>().is_err());
+	assert!("shard:0x0".parse::<Shard>().is_err());
+	assert!("shard:0x0+0x1+0x2".parse::<Shard>().is_err());
+	assert!(Shard::from_bytes([0xFF; 16]).is_none());
+
+	// Generators in a seed shard match the ones created from the shard's seeds
+	let shard = Shard::new(0x5DEECE66D ^ 1000, 16);
+
+	for (mut random, seed) in shard.randoms().zip(shard.seeds()) {
+		assert_eq!(random.next_i32(), Random::new(seed).next_i32());
+	}
+
+	assert_eq!(shard.seeds().next(), Some(1000));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_coverage() {
+	use sweep::{Coverage, Shard, STATE_SPACE};
+
+	let mut coverage = Coverage::new();
+	let shards: Vec<Shard> = Shard::split(5).collect();
+
+	coverage.insert(shards[3]);
+	coverage.insert(shards[1]);
+
+	assert_eq!(coverage.done(), &[shards[1], shards[3]]);
+	assert_eq!(coverage.missing(), vec![shards[0], shards[2], shards[4]]);
+	assert!(coverage.contains(Shard::new(shards[1].start() + 1, 10)));
+	assert!(!coverage.contains(Shard::new(shards[1].end() - 1, 2)));
+
+	// Filling the gap merges both neighbours
+	coverage.insert(shards[2]);
+	assert_eq!(coverage.done(), &[Shard::new(shards[1].start(), shards[3].end() - shards[1].start())]);
+
+	// Overlapping shards are merged too
+	coverage.insert(Shard::new(0, shards[1].start() + 1));
+	coverage.insert(Shard::new(shards[4].start() - 1, shards[4].len() + 1));
+
+	assert!(coverage.is_complete());
+	assert_eq!(coverage.swept(), STATE_SPACE);
+	assert_eq!(coverage.done(), &[Shard::full()]);
+	assert!(coverage.missing().is_empty());
+}
+
+#[test]
+fn test_const() {
+	const NEXT32: [u32; 8] = {
+		let mut random = Random::new(RAND_NEXT32_SEED);
+		let mut values = [0; 8];
+		let mut index = 0;
+
+		while index < values.len() {
+			values[index] = random.next_u32();
+			index += 1;
+		}
+
+		values
+	};
+
+	const NEXT64: u64 = Random::new(RAND_NEXT64_SEED).next_u64();
+
+	assert_eq!(NEXT32, RAND_NEXT32[..8]);
+	assert_eq!(NEXT64, RAND_NEXT64[0]);
+}
+
+#[test]
+fn test_bound_const() {
+	fn check<F: FnMut(&mut Random) -> i32>(max: i32, mut bound_const: F) {
+		let mut expected = Random::new(RAND_NEXT32_SEED);
+		let mut actual = expected.clone();
+
+		for _ in 0..1024 {
+			assert_eq!(bound_const(&mut actual), expected.next_i32_bound(max), "bound {}", max);
+		}
+
+		assert_eq!(actual, expected);
+	}
+
+	check(1, Random::next_i32_bound_const::<1>);
+	check(8, Random::next_i32_bound_const::<8>);
+	check(16, Random::next_i32_bound_const::<16>);
+	check(24, Random::next_i32_bound_const::<24>);
+	check(256, Random::next_i32_bound_const::<256>);
+	check(0x40000001, Random::next_i32_bound_const::<0x40000001>);
+	check(i32::MAX, Random::next_i32_bound_const::<{ i32::MAX }>);
+}
+
+#[test]
+fn test_buffered_bools() {
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	let bools: Vec<bool> = random.buffered_bools().take(RAND_NEXT32.len() * 32).collect();
+
+	for (index, &elem) in RAND_NEXT32.iter().enumerate() {
+		for bit in 0..32 {
+			assert_eq!(bools[index * 32 + bit], elem & (0x80000000 >> bit) != 0, "mismatch at index {}", index);
+		}
+	}
+
+	// Leftover bits are discarded with the buffer
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	random.buffered_bools().next_bool();
+
+	assert_eq!(random.next_u32(), RAND_NEXT32[1]);
+}
+
+#[test]
+fn test_fill() {
+	for len in 0..41 {
+		let mut expected = Random::new(RAND_NEXT32_SEED);
+		let mut actual = expected.clone();
+
+		let mut values = vec![0; len];
+		actual.fill_u32(&mut values);
+
+		for (index, &value) in values.iter().enumerate() {
+			assert_eq!(value, expected.next_u32(), "mismatch at index {} of {}", index, len);
+		}
+
+		let mut values = vec![0; len];
+		actual.fill_i32(&mut values);
+
+		for (index, &value) in values.iter().enumerate() {
+			assert_eq!(value, expected.next_i32(), "mismatch at index {} of {}", index, len);
+		}
+
+		assert_eq!(actual, expected);
+	}
+
+	// Beyond 256 bytes, next_bytes fills several blocks, each long enough for the vector kernels
+	for len in (0..100).chain([255, 256, 257, 1000, 1027].iter().cloned()) {
+		let mut expected = Random::new(RAND_NEXTBYTES_SEED);
+		let mut actual = expected.clone();
+
+		let mut bytes = vec![0; len];
+		actual.next_bytes(&mut bytes);
+
+		assert_eq!(bytes, expected.bytes().take(len).collect::<Vec<u8>>());
+		assert_eq!(actual, expected);
+	}
+}
+
+#[test]
+fn test_iterators() {
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	assert!(random.iter_u32().take(RAND_NEXT32.len()).eq(RAND_NEXT32.iter().cloned()));
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	assert!(random.iter_u64().take(RAND_NEXT64.len()).eq(RAND_NEXT64.iter().cloned()));
+
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	let gaussians = random.iter_gaussian().take(RAND_NEXTGAUSSIAN.len()).map(f64::to_bits);
+	assert!(gaussians.eq(RAND_NEXTGAUSSIAN.iter().cloned()));
+
+	// Interleaving iterators with direct calls keeps the exact call order
+	let mut expected = Random::new(0);
+	let mut actual = expected.clone();
+
+	for _ in 0..16 {
+		assert!(actual.iter_i32().take(3).eq((0..3).map(|_| expected.next_i32())));
+		assert!(actual.iter_i32_bound(7).take(3).eq((0..3).map(|_| expected.next_i32_bound(7))));
+		assert!(actual.iter_u32_bound(1 << 30).take(3).eq((0..3).map(|_| expected.next_u32_bound(1 << 30))));
+		assert!(actual.iter_i64().take(3).eq((0..3).map(|_| expected.next_i64())));
+		assert!(actual.iter_bool().take(3).eq((0..3).map(|_| expected.next_bool())));
+		assert!(actual.iter_f32().take(3).map(f32::to_bits).eq((0..3).map(|_| expected.next_f32().to_bits())));
+		assert!(actual.iter_f64().take(3).map(f64::to_bits).eq((0..3).map(|_| expected.next_f64().to_bits())));
+
+		let bounded = actual.iter_f64_bound(2.5).take(3).map(f64::to_bits);
+		assert!(bounded.eq((0..3).map(|_| expected.next_f64_bound(2.5).to_bits())));
+
+		let ranged = actual.iter_f64_range(-1.0, 1.0).take(3).map(f64::to_bits);
+		assert!(ranged.eq((0..3).map(|_| expected.next_f64_range(-1.0, 1.0).to_bits())));
+
+		let gaussians = actual.iter_gaussian().take(3).map(f64::to_bits);
+		assert!(gaussians.eq((0..3).map(|_| expected.next_gaussian().to_bits())));
+
+		assert_eq!(actual, expected);
+	}
+}
+
+#[test]
+#[should_panic]
+fn test_iter_bound_invalid() {
+	let _ = Random::new(0).iter_i32_bound(0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_instrumented() {
+	use {Call, Instrumented, Method};
+
+	let mut expected = Random::new(RAND_NEXT32_SEED);
+	let mut instrumented = Instrumented::with_log(expected.clone());
+
+	assert_eq!(instrumented.next_i32(), expected.next_i32());
+	assert_eq!(instrumented.next_u32_bound(1 << 4), expected.next_u32_bound(1 << 4));
+	assert_eq!(instrumented.next_i64(), expected.next_i64());
+	assert_eq!(instrumented.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+	assert_eq!(instrumented.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+
+	let mut bytes = [0; 5];
+	instrumented.next_bytes(&mut bytes);
+	expected.next_bytes(&mut [0; 5]);
+
+	assert_eq!(instrumented.random(), &expected);
+
+	let log = instrumented.log().unwrap().to_vec();
+
+	assert_eq!(log[0], Call { method: Method::NextI32, steps: 1 });
+	assert_eq!(log[1], Call { method: Method::NextI32Bound, steps: 1 });
+	assert_eq!(log[2], Call { method: Method::NextI64, steps: 2 });
+	assert_eq!(log[3].method, Method::NextGaussian);
+	assert_eq!(log[4], Call { method: Method::NextGaussian, steps: 0 });
+	assert_eq!(log[5], Call { method: Method::NextBytes, steps: 2 });
+	assert_eq!(log[5].to_string(), "nextBytes(bytes): 2 steps");
+
+	assert_eq!(instrumented.count(Method::NextGaussian), 2);
+	assert_eq!(instrumented.count(Method::NextF64), 0);
+	assert_eq!(instrumented.steps(), log.iter().map(|call| call.steps as u64).sum::<u64>());
+
+	// The step count must match how far the generator actually moved
+	let mut model = Random::new(RAND_NEXT32_SEED);
+	skip_calls(&mut model, instrumented.steps() as u32);
+	assert_eq!(model.next_i32(), instrumented.into_inner().next_i32());
+
+	let mut unlogged = Instrumented::new(Random::new(0));
+	unlogged.next_f64_range(0.0, 2.0);
+
+	assert!(unlogged.log().is_none());
+	assert_eq!(unlogged.count(Method::NextF64Range), 1);
+
+	unlogged.reset();
+	assert_eq!(unlogged.steps(), 0);
+
+	// The hook sees the calls of the log, as they happen
+	let mut hooked_calls = Vec::new();
+	let mut hooked = Instrumented::with_hook(Random::new(RAND_NEXT32_SEED), |call| hooked_calls.push(call));
+
+	hooked.next_i32();
+	hooked.next_u32_bound(1 << 4);
+	hooked.next_i64();
+	hooked.next_gaussian();
+	hooked.next_gaussian();
+	hooked.next_bytes(&mut [0; 5]);
+
+	assert_eq!(hooked.count(Method::NextGaussian), 2);
+	drop(hooked);
+
+	assert_eq!(hooked_calls, log);
+}
+
+#[test]
+fn test_builder() {
+	assert_eq!(Random::builder().seed(RAND_NEXT32_SEED).build(), Random::new(RAND_NEXT32_SEED));
+	assert_eq!(Random::builder().seed_i64(-1).build(), Random::new(!0));
+	assert_eq!(Random::builder().seed_text("-42").build(), Random::new(-42i64 as u64));
+
+	// "glacier".hashCode() in Java, and a number too large for a long, which is hashed as text
+	assert_eq!(Random::builder().seed_text("glacier").build(), Random::new(108_181_935));
+	assert_eq!(Random::builder().seed_text("99999999999999999999").build(), Random::new(1_260_560_192));
+	assert_eq!(Random::builder().seed_text("").build(), Random::new(0));
+
+	// Surrounding whitespace is trimmed, as in the seed field
+	assert_eq!(Random::builder().seed_text(" 123\t").build(), Random::new(123));
+	assert_eq!(Random::builder().seed_text(" glacier ").build(), Random::new(108_181_935));
+	assert_eq!(Random::builder().seed_text("  ").build(), Random::new(0));
+
+	let pending: Random = "state:0x2a7f8e1c4b30;gauss:none".parse().unwrap();
+	assert_eq!(Random::builder().state(0xffff_2a7f8e1c4b30).build(), pending);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_builder_time_seeded() {
+	// Generators created back to back still differ thanks to the uniquifier
+	let first = Random::builder().time_seeded().build();
+	let second = Random::builder().build();
+
+	assert_ne!(first, second);
+}
+
+#[test]
+fn test_try() {
+	use sweep::{Shard, STATE_SPACE};
+	use Error;
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let untouched = random.clone();
+
+	assert_eq!(random.try_next(49), Err(Error::TooManyBits));
+	assert_eq!(random.try_next_i32_bound(0), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_i32_bound_counted(-5), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_u32_bound(1 << 31), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_f64_bound(f64::NAN), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_f64_range(1.0, 1.0), Err(Error::InvalidRange));
+	assert!(random.try_iter_i32_bound(0).is_err());
+	assert!(random.try_iter_f64_range(f64::NEG_INFINITY, 0.0).is_err());
+
+	// Rejected calls leave the generator untouched
+	assert_eq!(random, untouched);
+
+	let mut expected = random.clone();
+
+	assert_eq!(random.try_next(48), Ok(expected.next(48)));
+	assert_eq!(random.try_next_i32_bound(7), Ok(expected.next_i32_bound(7)));
+	assert_eq!(random.try_next_u32_bound(100), Ok(expected.next_u32_bound(100)));
+	assert_eq!(random.try_next_f64_bound(3.5), Ok(expected.next_f64_bound(3.5)));
+	assert_eq!(random.try_next_f64_range(-2.0, 2.0), Ok(expected.next_f64_range(-2.0, 2.0)));
+
+	assert_eq!(Shard::try_new(STATE_SPACE, 1), Err(Error::InvalidShard));
+	assert_eq!(Shard::try_nth_of(3, 3), Err(Error::InvalidShard));
+	assert!(Shard::try_split(0).is_err());
+	assert_eq!(Shard::try_nth_of(0, 1), Ok(Shard::full()));
+
+	assert_eq!("state:0x".parse::<Random>().map_err(Error::from), Err(Error::ParseError));
+	assert_eq!("shard:0x0".parse::<Shard>().map_err(Error::from), Err(Error::ParseError));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_thread_rng() {
+	use std::thread;
+
+	::set_seed(RAND_NEXT32_SEED);
+
+	let mut expected = Random::new(RAND_NEXT32_SEED);
+
+	assert_eq!(::next_i32(), expected.next_i32());
+	assert_eq!(::thread_rng().next_i32_bound(50), expected.next_i32_bound(50));
+	assert_eq!(::next_f64(), expected.next_f64());
+	assert_eq!(::thread_rng().with(|random| random.clone()), expected);
+
+	// Other threads are seeded independently and unaffected by set_seed here
+	let other = thread::spawn(|| ::thread_rng().with(|random| random.clone())).join().unwrap();
+
+	assert_ne!(other, expected);
+}
+
+#[test]
+fn test_checkpoint() {
+	use {Algorithm, Checkpoint};
+
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	random.next_gaussian();
+
+	let checkpoint = random.save();
+	let expected: Vec<f64> = random.iter_gaussian().take(8).collect();
+
+	random.restore(&checkpoint).unwrap();
+
+	assert_eq!(random.save(), checkpoint);
+	assert_eq!(random.iter_gaussian().take(8).collect::<Vec<f64>>(), expected);
+
+	assert_eq!(checkpoint.algorithm(), Algorithm::JavaRandom);
+	assert_eq!(Checkpoint::from_bytes(checkpoint.to_bytes()), Some(checkpoint));
+
+	// The binary form is stable: tag, pending flag, two state words and the gaussian bits
+	let bytes = Random::new(0).save().to_bytes();
+
+	assert_eq!(bytes[..2], [1, 0]);
+	assert_eq!(bytes[2..10], 0x5DEECE66Du64.to_be_bytes());
+	assert_eq!(bytes[10..], [0; 16]);
+
+	let mut invalid = bytes;
+	invalid[0] = 0;
+	assert_eq!(Checkpoint::from_bytes(invalid), None);
+
+	let mut invalid = bytes;
+	invalid[2] = 1;
+	assert_eq!(Checkpoint::from_bytes(invalid), None);
+
+	let mut invalid = bytes;
+	invalid[1] = 2;
+	assert_eq!(Checkpoint::from_bytes(invalid), None);
+}
+
+#[test]
+fn test_derive_child() {
+	let parent = Random::new(42);
+	let untouched = parent.clone();
+
+	// Reference values computed in Java from the documented mixing function
+	let mut child = parent.derive_child(7);
+	assert_eq!([child.next_i32(), child.next_i32()], [-1174272153, -372920165]);
+
+	let mut child = parent.derive_child_str("terrain");
+	assert_eq!([child.next_i32(), child.next_i32()], [1262634109, 1016659102]);
+
+	assert_eq!(parent, untouched);
+	assert_eq!(parent.derive_child(7), parent.derive_child(7));
+	assert_ne!(parent.derive_child(7), parent.derive_child(8));
+	assert_ne!(parent.derive_child(7), Random::new(43).derive_child(7));
+}
+
+#[test]
+fn test_speculate() {
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	let start = random.clone();
+
+	{
+		let mut guard = random.speculate();
+		guard.next_gaussian();
+		guard.next_i64();
+	}
+
+	assert_eq!(random, start);
+
+	let mut guard = random.speculate();
+	let gaussian = guard.next_gaussian();
+	guard.commit();
+
+	let mut expected = start.clone();
+	assert_eq!(expected.next_gaussian(), gaussian);
+	assert_eq!(random, expected);
+
+	// Only outcomes that succeed are kept
+	let before = random.clone();
+	assert_eq!(random.scoped(|random| Some(random.next_i32_bound(10)).filter(|&roll| roll > 10)), None);
+	assert_eq!(random, before);
+
+	let roll = random.scoped(|random| Some(random.next_i32_bound(10)));
+	assert_eq!(roll, Some(expected.next_i32_bound(10)));
+	assert_eq!(random, expected);
+}
+
+#[test]
+fn test_stats() {
+	use stats;
+
+	let close = |actual: f64, expected: f64| (actual - expected).abs() < 1e-9 * expected.max(1e-3);
+
+	// Reference tail probabilities from closed forms of the chi-square and Kolmogorov distributions
+	assert!(close(stats::chi_square(&[60, 40]).p_value, 0.045500263896358396));
+
+	let outcome = stats::chi_square(&[30, 20, 20, 10, 20]);
+	assert_eq!(outcome.statistic, 10.0);
+	assert!(close(outcome.p_value, 0.0404276819945128));
+
+	let outcome = stats::kolmogorov_smirnov(&mut [0.5]);
+	assert_eq!(outcome.statistic, 0.5);
+	assert!(close(outcome.p_value, 0.8438198245415606));
+
+	let outcome = stats::kolmogorov_smirnov(&mut [0.95, 0.75, 0.9, 0.8]);
+	assert_eq!(outcome.statistic, 0.75);
+	assert!(close(outcome.p_value, 0.00976641264623291));
+
+	// Java Random passes, and obviously non-uniform sources fail
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	assert!(stats::chi_square(&stats::histogram::<_, 16>(random.iter_u32_bound(16).take(16_000))).p_value > 0.001);
+	assert!(stats::kolmogorov_smirnov(&mut random.iter_f64().take(4096).collect::<Vec<f64>>()).p_value > 0.001);
+	assert!(stats::serial_correlation(random.iter_f64().take(4096)).p_value > 0.001);
+
+	assert!(stats::chi_square(&stats::histogram::<_, 16>(random.iter_u32_bound(16).take(16_000).map(|x| x / 2))).p_value < 1e-9);
+	assert!(stats::kolmogorov_smirnov(&mut random.iter_f64().take(4096).map(|x| x * x).collect::<Vec<f64>>()).p_value < 1e-9);
+	assert!(stats::serial_correlation((0..4096).map(|index| (index % 2) as f64 + random.next_f64() * 0.5)).p_value < 1e-9);
+}
+
+macro_rules! check_xoroshiro {
+	($generator:ty, $seed:expr, $expected:expr, $raw:expr) => {{
+		let mut random = <$generator>::new($seed);
+
+		for (index, (iteration, expected)) in (0..256).zip($expected.chunks_exact(8)).enumerate() {
+			let bound = if iteration % 2 == 0 { 1 << (iteration % 31) } else { iteration * 104729 + 1 };
+
+			let gen = [
+				random.next_i64() as u64,
+				random.next_i32() as i64 as u64,
+				random.next_i32_bound(bound) as i64 as u64,
+				random.next_bool() as u64,
+				random.next_f32().to_bits() as u64,
+				random.next_f64().to_bits(),
+				random.next_gaussian().to_bits(),
+				random.next_exponential().to_bits()
+			];
+
+			if gen != expected {
+				panic!("mismatch at index {}: expected {:x?}, got {:x?}", index, expected, gen);
+			}
+		}
+
+		let mut raw = <$generator>::from_state(1, 2);
+
+		for (index, &elem) in $raw.iter().enumerate() {
+			let gen = raw.next_u64();
+
+			if gen != elem {
+				panic!("mismatch at index {}: expected {}, got {}", index, elem, gen);
+			}
+		}
+
+		for (index, &elem) in $raw.iter().enumerate().rev() {
+			assert_eq!(raw.previous(), elem, "mismatch stepping back at index {}", index);
+		}
+
+		assert_eq!(raw.state(), (1, 2));
+
+		let checkpoint = random.save();
+		let next = random.next_u64();
+
+		random.restore(&checkpoint).unwrap();
+		assert_eq!(random.next_u64(), next);
+		assert!(Random::new(0).restore(&checkpoint).is_err());
+	}}
+}
+
+#[test]
+fn test_xoroshiro128() {
+	use {Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar};
+
+	check_xoroshiro!(Xoroshiro128Plus, XOROSHIRO128PLUS_SEED, XOROSHIRO128PLUS, XOROSHIRO128PLUS_RAW);
+	check_xoroshiro!(Xoroshiro128PlusPlus, XOROSHIRO128PLUSPLUS_SEED, XOROSHIRO128PLUSPLUS, XOROSHIRO128PLUSPLUS_RAW);
+	check_xoroshiro!(Xoroshiro128StarStar, XOROSHIRO128STARSTAR_SEED, XOROSHIRO128STARSTAR, XOROSHIRO128STARSTAR_RAW);
+
+	assert_eq!(Xoroshiro128PlusPlus::from_state(0, 0).state(), (0x9E3779B97F4A7C15, 0x6A09E667F3BCC909));
+	assert_eq!(Xoroshiro128Plus::new(0).try_next_i32_bound(0), Err(::Error::InvalidBound));
+}
+
+#[test]
+fn test_lcg() {
+	use {JavaLcg, Lcg};
+
+	// The Java instantiation reproduces Random
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let mut lcg = JavaLcg::new(RAND_NEXT32_SEED ^ 0x5DEECE66D);
+
+	for _ in 0..64 {
+		assert_eq!(lcg.next_i32(), random.next_i32());
+		assert_eq!(lcg.next_i32_bound(1000), random.next_i32_bound(1000));
+		assert_eq!(lcg.next_i32_bound(64), random.next_i32_bound(64));
+		assert_eq!(lcg.next_i64(), random.next_i64());
+		assert_eq!(lcg.next_bool(), random.next_bool());
+		assert_eq!(lcg.next_f32().to_bits(), random.next_f32().to_bits());
+		assert_eq!(lcg.next_f64().to_bits(), random.next_f64().to_bits());
+		assert_eq!(lcg.next_u32(), random.next_u32());
+		assert_eq!(lcg.next_u32_bound(100), random.next_u32_bound(100));
+		assert_eq!(lcg.next_u64(), random.next_u64());
+		assert_eq!(lcg.next_gaussian().to_bits(), random.next_gaussian().to_bits());
+
+		let mut bytes = [0; 7];
+		let mut expected = [0; 7];
+
+		lcg.next_bytes(&mut bytes);
+		random.next_bytes(&mut expected);
+		assert_eq!(bytes, expected);
+	}
+
+	assert_eq!(lcg.try_next_u32_bound(1 << 31), Err(::Error::InvalidBound));
+
+	// Jumps agree with stepping, and reverse it, for the Java generator and a 64-bit one
+	let start = JavaLcg::new(0x1234_5678_9abc);
+	let mut stepped = start;
+
+	for _ in 0..1000 {
+		stepped.next_state();
+	}
+
+	let mut jumped = start;
+	jumped.jump(1000);
+	assert_eq!(jumped, stepped);
+
+	jumped.jump_back(1000);
+	assert_eq!(jumped, start);
+
+	type Mmix = Lcg<6364136223846793005, 1442695040888963407, 64>;
+
+	let mut mmix = Mmix::new(42);
+	assert_eq!(mmix.next_state(), 42u64.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407));
+	assert_eq!(mmix.previous_state(), 42);
+
+	mmix.jump_back(1 << 40);
+	mmix.jump(1 << 40);
+	assert_eq!(mmix.state(), 42);
+
+	// Small states are masked, and next_bits returns the top bits
+	let mut small = Lcg::<5, 3, 8>::new(0x1ff);
+	assert_eq!(small.state(), 0xff);
+	assert_eq!(small.next_bits(4), ((0xff * 5 + 3) & 0xff) >> 4);
+	assert_eq!(small.previous_state(), 0xff);
+	assert_eq!(Lcg::<5, 3, 8>::MASK, 0xff);
+}
+
+#[test]
+fn test_algorithm_info() {
+	use {Algorithm, Period};
+
+	// Values reported by RandomGeneratorFactory in Java 17
+	let random = Algorithm::JavaRandom.info();
+
+	assert_eq!((random.name, random.group, random.state_bits, random.equidistribution), ("Random", "Legacy", 48, 0));
+	assert_eq!(random.period.to_u128(), Some(281474976710656));
+
+	let xoroshiro = Algorithm::Xoroshiro128PlusPlus.info();
+
+	assert_eq!((xoroshiro.group, xoroshiro.state_bits, xoroshiro.equidistribution), ("Xoroshiro", 128, 1));
+	assert_eq!(xoroshiro.period.to_u128(), Some(340282366920938463463374607431768211455));
+	assert_eq!(xoroshiro.period.to_string(), "2^128 - 1");
+
+	assert_eq!(Period::PowerOfTwo(128).to_u128(), None);
+
+	for &algorithm in Algorithm::ALL.iter() {
+		assert_eq!(Algorithm::from_name(algorithm.info().name), Some(algorithm));
+		assert_eq!(Algorithm::from_tag(algorithm.tag()), Some(algorithm));
+	}
+
+	let splittable = Algorithm::SplitMix64.info();
+
+	assert_eq!((splittable.group, splittable.state_bits, splittable.equidistribution), ("Legacy", 64, 1));
+	assert_eq!(splittable.period.to_string(), "2^64");
+	assert!(splittable.splittable);
+
+	assert_eq!(Algorithm::from_name("SplittableRandom"), Some(Algorithm::SplitMix64));
+	assert_eq!(Algorithm::from_name("L64X128MixRandom"), None);
+}
+
+#[test]
+fn test_subclass() {
+	use {NextBits, Subclass};
+
+	struct Weyl(i32);
+
+	impl NextBits for Weyl {
+		fn next(&mut self, bits: u8) -> i32 {
+			self.0 = self.0.wrapping_add(0x9E3779B9u32 as i32);
+			((self.0 as u32) >> (32 - bits)) as i32
+		}
+	}
+
+	let mut random = Subclass::new(Weyl(0));
+
+	for (index, (iteration, expected)) in (0..128).zip(SUBCLASS_WEYL.chunks_exact(8)).enumerate() {
+		let bound = if iteration % 2 == 0 { 1 << (iteration % 31) } else { iteration * 104729 + 1 };
+		let mut bytes = [0; 3];
+		random.next_bytes(&mut bytes);
+
+		let gen = [
+			random.next_i32() as i64 as u64,
+			random.next_i32_bound(bound) as i64 as u64,
+			random.next_i64() as u64,
+			random.next_bool() as u64,
+			random.next_f32().to_bits() as u64,
+			random.next_f64().to_bits(),
+			random.next_gaussian().to_bits(),
+			bytes[0] as u64 | (bytes[1] as u64) << 8 | (bytes[2] as u64) << 16
+		];
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:x?}, got {:x?}", index, expected, gen);
+		}
+	}
+
+	let mut items: Vec<u8> = (0..52).collect();
+	random.shuffle(&mut items);
+	assert_eq!(items[..], SUBCLASS_WEYL_SHUFFLE[..]);
+
+	// Wrapping Random itself changes nothing
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	let mut subclass = Subclass::new(random.clone());
+
+	for _ in 0..64 {
+		assert_eq!(subclass.next_i32_bound(1000), random.next_i32_bound(1000));
+		assert_eq!(subclass.next_gaussian().to_bits(), random.next_gaussian().to_bits());
+		assert_eq!(subclass.next_f32().to_bits(), random.next_f32().to_bits());
+	}
+}
+
+#[test]
+fn test_shuffle() {
+	let mut random = Random::new(RAND_SHUFFLE_SEED);
+	let mut items: Vec<u8> = (0..52).collect();
+
+	random.shuffle(&mut items);
+	assert_eq!(items[..], RAND_SHUFFLE[..]);
+
+	// Fewer than 2 items consume no draws
+	let before = random.clone();
+	random.shuffle(&mut [0u8; 1]);
+	random.shuffle::<u8>(&mut []);
+	assert_eq!(random, before);
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_mc_population_seed() {
+	use minecraft::McVersion;
+
+	for (index, &(world_seed, chunk_x, chunk_z, populate, decorate, xoroshiro)) in MC_POPULATION_SEEDS.iter().enumerate() {
+		let gen = [
+			McVersion::UpTo1_12.population_seed(world_seed, chunk_x, chunk_z),
+			McVersion::From1_13To1_17.population_seed(world_seed, chunk_x, chunk_z),
+			McVersion::From1_18.population_seed(world_seed, chunk_x, chunk_z)
+		];
+
+		if gen != [populate, decorate, xoroshiro] {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, [populate, decorate, xoroshiro], gen);
+		}
+	}
+
+	assert!(!McVersion::From1_13To1_17.population_random(0, 0, 0).is_xoroshiro());
+	assert!(McVersion::From1_18.population_random(0, 0, 0).is_xoroshiro());
+}
+
+#[test]
+#[cfg(all(feature = "minecraft", feature = "std"))]
+fn test_mc_random_sequences() {
+	use minecraft::{seed_for_key, RandomSequence, RandomSequences, RandomSource};
+
+	// MD5 of the empty string
+	assert_eq!(seed_for_key(""), (0xd41d8cd98f00b204, 0xe9800998ecf8427e));
+
+	for (index, &(world_seed, id, keyed, unkeyed)) in MC_RANDOM_SEQUENCES.iter().enumerate() {
+		let mut sequence = RandomSequence::new(world_seed, Some(id));
+		let gen = [0; 4].map(|_: i64| sequence.random().next_i64());
+
+		if gen != keyed {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, keyed, gen);
+		}
+
+		let mut sequence = RandomSequence::new(world_seed, None);
+		let gen = [0; 4].map(|_: i64| sequence.random().next_i64());
+
+		if gen != unkeyed {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, unkeyed, gen);
+		}
+	}
+
+	let (world_seed, id, keyed, _) = MC_RANDOM_SEQUENCES[13];
+	let mut sequences = RandomSequences::new(world_seed);
+
+	assert_eq!(sequences.get(id).next_i64(), keyed[0]);
+	assert_eq!(sequences.get(id).next_i64(), keyed[1]);
+
+	// Without the world seed, a salted sequence matches one created from the salt alone
+	sequences.reset(id, 5, false, true);
+	assert_eq!(sequences.get(id), RandomSequence::new(5, Some(id)).random());
+
+	sequences.set_options(0, true, false);
+	sequences.clear();
+	assert_eq!(sequences.get(id), RandomSequence::new(world_seed, None).random());
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_mc_random_spread() {
+	use minecraft::{random_spread_chunk, region_random, region_seed, SpreadType};
+
+	assert_eq!(region_seed(0, 1, 1, 0), 341873128712 + 132897987541);
+	assert_eq!(region_seed(i64::MAX, 0, 0, 1), i64::MIN);
+	assert_eq!(region_random(7, -3, 5, 10387312), Random::new(region_seed(7, -3, 5, 10387312) as u64));
+
+	// Spacing, separation, salt and spread of the placements in the test data
+	let placements = [
+		(34, 8, 10387312, SpreadType::Linear),
+		(24, 4, 165745295, SpreadType::Linear),
+		(80, 20, 10387313, SpreadType::Triangular)
+	];
+
+	for (index, &(world_seed, chunk_x, chunk_z, placement, x, z)) in MC_RANDOM_SPREAD.iter().enumerate() {
+		let (spacing, separation, salt, spread) = placements[placement];
+		let gen = random_spread_chunk(world_seed, chunk_x, chunk_z, spacing, separation, salt, spread);
+
+		if gen != (x, z) {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, (x, z), gen);
+		}
+	}
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_improved_noise() {
+	use minecraft::noise::{self, ImprovedNoise};
+
+	let mut rand = Random::new(MC_IMPROVED_NOISE_SEED);
+	let (octave, steps) = ImprovedNoise::new_counted(&mut rand);
+
+	assert_eq!(octave.xo.to_bits(), MC_IMPROVED_NOISE_OFFSETS[0]);
+	assert_eq!(octave.yo.to_bits(), MC_IMPROVED_NOISE_OFFSETS[1]);
+	assert_eq!(octave.zo.to_bits(), MC_IMPROVED_NOISE_OFFSETS[2]);
+	assert_eq!(&octave.permutation[..], &MC_IMPROVED_NOISE_PERMUTATION[..]);
+	assert!(steps >= noise::SKIPPED_OCTAVE_STEPS);
+	assert_eq!(rand.next_i64(), MC_IMPROVED_NOISE_NEXT);
+
+	noise::skip_octave(&mut rand);
+	noise::skip_octaves(&mut rand, 4);
+	assert_eq!(rand.next_i64(), MC_IMPROVED_NOISE_SKIPPED);
+
+	let mut rand = Random::new(MC_IMPROVED_NOISE_SEED);
+	let mut counted = rand.clone();
+
+	ImprovedNoise::new(&mut rand);
+
+	for _ in 0..steps {
+		counted.next(32);
+	}
+
+	assert_eq!(rand, counted);
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_noise() {
+	use minecraft::noise::{ImprovedNoise, PerlinNoise, SimplexNoise};
+
+	let mut rand = Random::new(MC_NOISE_SEED);
+	let improved = ImprovedNoise::new(&mut rand);
+	let perlin = PerlinNoise::new_legacy(&mut rand, -3, [1.0, 0.0, 2.0, 0.5]);
+	let skipped = PerlinNoise::new_legacy(&mut rand, -7, [1.0, 1.0, 0.0, 1.0]);
+	let simplex = SimplexNoise::new(&mut rand);
+
+	assert_eq!(rand.next_i64(), MC_NOISE_NEXT);
+	assert_eq!(perlin.edge_value(2.0).to_bits(), MC_NOISE_EDGE_VALUES[0]);
+	assert_eq!(skipped.edge_value(2.0).to_bits(), MC_NOISE_EDGE_VALUES[1]);
+	assert!(perlin.octave(1).is_none() && skipped.octave(2).is_none());
+
+	for (index, &(position, expected)) in MC_NOISE.iter().enumerate() {
+		let [x, y, z] = position.map(f64::from_bits);
+
+		let values = [
+			improved.noise(x, y, z),
+			improved.noise_y_scaled(x, y, z, 0.25, 0.5),
+			perlin.value(x, y, z),
+			skipped.value(x, y, z),
+			perlin.value_y_scaled(x, y, z, 0.5, 0.125, true),
+			simplex.value_2d(x, z),
+			simplex.value_3d(x, y, z)
+		];
+
+		if values.map(f64::to_bits) != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected.map(f64::from_bits), values);
+		}
+	}
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+#[should_panic]
+fn test_mc_noise_positive_octaves() {
+	use minecraft::noise::PerlinNoise;
+
+	PerlinNoise::new_legacy(&mut Random::new(0), -1, [1.0, 1.0, 1.0]);
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_legacy_noise_plan() {
+	use minecraft::noise::{self, ImprovedNoise};
+
+	let plan = noise::OVERWORLD_1_12;
+	let scale = plan.position("scaleNoise").unwrap();
+
+	assert_eq!(plan.octaves(), 78);
+	assert_eq!(plan.position("missing"), None);
+
+	let mut rand = plan.positioned(MC_LEGACY_NOISE_SEED, scale);
+
+	for _ in 0..3 {
+		ImprovedNoise::new(&mut rand);
+	}
+
+	let octave = ImprovedNoise::new(&mut rand);
+
+	assert_eq!([octave.xo, octave.yo, octave.zo].map(f64::to_bits), MC_LEGACY_NOISE_SCALE_OFFSETS);
+	assert_eq!(plan.positioned(MC_LEGACY_NOISE_SEED, plan.stages.len()).next_i64(), MC_LEGACY_NOISE_NEXT);
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_random_source() {
+	use minecraft::noise::PerlinNoise;
+	use minecraft::{position_seed, PositionalRandomFactory, RandomSource, XoroshiroRandomSource};
+
+	fn draws<R: RandomSource>(source: &mut R) -> [i64; 24] {
+		let mut draws = [0; 24];
+		let mut values = draws.iter_mut();
+		let mut push = |value: i64| *values.next().unwrap() = value;
+
+		push(source.next_i32() as i64);
+		push(source.next_i32_bound(10) as i64);
+		push(source.next_i32_bound(1 << 20) as i64);
+		push(source.next_i32_bound(2000000001) as i64);
+		push(source.next_i32_bound(2000000001) as i64);
+		push(source.next_i32_bound(2000000001) as i64);
+		push(source.next_i32_between_inclusive(-5, 5) as i64);
+		push(source.next_i64());
+		push(source.next_bool() as i64);
+		push(source.next_f32().to_bits() as i32 as i64);
+		push(source.next_f64().to_bits() as i64);
+		push(source.next_gaussian().to_bits() as i64);
+		push(source.next_gaussian().to_bits() as i64);
+		push(source.next_gaussian().to_bits() as i64);
+		push(source.triangle(4.0, 2.5).to_bits() as i64);
+		source.consume_count(3);
+		push(source.next_i64());
+		push(source.fork().next_i64());
+
+		let factory = source.fork_positional();
+
+		push(factory.at(1, -2, 3).next_i64());
+		push(factory.at(-30000000, 320, 29999999).next_i64());
+		push(factory.from_hash_of("minecraft:ore_gold").next_i64());
+		push(factory.from_seed(7).next_i64());
+		push(position_seed(-30000000, 320, 29999999));
+
+		let noise = PerlinNoise::new(source, -3, [1.0, 1.0, 0.0, 1.0]);
+
+		push(noise.value(1.5, 2.25, -3.75).to_bits() as i64);
+		push(source.next_i64());
+
+		draws
+	}
+
+	for (index, &(seed, expected)) in MC_RANDOM_SOURCE_LEGACY.iter().enumerate() {
+		let gen = draws(&mut Random::new(seed as u64));
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	for (index, &(seed, expected)) in MC_RANDOM_SOURCE_XOROSHIRO.iter().enumerate() {
+		let gen = draws(&mut XoroshiroRandomSource::new(seed));
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	// Reseeding discards the pending gaussian
+	let mut source = XoroshiroRandomSource::new(1);
+	source.next_gaussian();
+	RandomSource::set_seed(&mut source, 0);
+	assert_eq!(source, XoroshiroRandomSource::new(0));
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_slime_chunks() {
+	use minecraft::{is_slime_chunk, is_slime_chunk_bedrock};
+
+	let mask = |is_slime: &dyn Fn(i32, i32) -> bool| {
+		let mut rows = [0u32; 32];
+
+		for (row, z) in rows.iter_mut().zip(-16..16) {
+			for x in -16..16 {
+				*row |= (is_slime(x, z) as u32) << (x + 16);
+			}
+		}
+
+		rows
+	};
+
+	for (index, &(world_seed, expected)) in MC_SLIME_CHUNKS.iter().enumerate() {
+		let gen = mask(&|x, z| is_slime_chunk(world_seed, x, z));
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	assert_eq!(mask(&is_slime_chunk_bedrock), MC_SLIME_CHUNKS_BEDROCK);
+
+	for (index, &(x, z, expected)) in MC_SLIME_CHUNKS_DISTANT.iter().enumerate() {
+		let gen = [
+			is_slime_chunk(MC_SLIME_CHUNKS[0].0, x, z),
+			is_slime_chunk(MC_SLIME_CHUNKS[1].0, x, z),
+			is_slime_chunk(MC_SLIME_CHUNKS[2].0, x, z),
+			is_slime_chunk_bedrock(x, z)
+		];
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_end_spikes() {
+	use minecraft::{end_spike_key, end_spikes, end_spikes_for_key};
+
+	for (index, &(world_seed, key, ref expected)) in MC_END_SPIKES.iter().enumerate() {
+		assert_eq!(end_spike_key(world_seed), key);
+
+		let gen = end_spikes(world_seed).map(|spike| [spike.center_x, spike.center_z, spike.radius, spike.height, spike.guarded as i32]);
+
+		if &gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+
+		assert_eq!(end_spikes_for_key(key), end_spikes(world_seed));
+	}
+}
+
+#[cfg(all(feature = "minecraft", feature = "std"))]
+#[test]
+fn test_mc_enchanting() {
+	use minecraft::enchanting::{enchantment_costs, select_enchantments, slot_random};
+
+	// The stand-in registry of the test data: enchantment i is available above level 4i, and 1 and 2 are mutually
+	// exclusive
+	let weights = [10, 5, 2, 1, 5, 2, 1];
+	let available = |level: i32| (0..7).filter(|&id| level > 4 * id).map(|id| (id, weights[id as usize])).collect();
+	let compatible = |&a: &i32, &b: &i32| a != b && !(a + b == 3 && (a == 1 || a == 2));
+
+	for (index, (&([seed, bookshelves, enchantability, book], costs), slots)) in MC_ENCHANTING.iter().zip(MC_ENCHANTING_OFFERS.iter()).enumerate() {
+		let gen = enchantment_costs(seed, bookshelves, enchantability);
+
+		if gen != costs {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, costs, gen);
+		}
+
+		for (slot, &expected) in slots.iter().enumerate() {
+			let mut gen = [-1; 9];
+
+			if costs[slot] > 0 {
+				let mut random = slot_random(seed, slot as i32);
+				let mut selected = select_enchantments(&mut random, costs[slot], enchantability, available, compatible);
+
+				if book == 1 && selected.len() > 1 {
+					selected.remove(random.next_i32_bound(selected.len() as i32) as usize);
+				}
+
+				if !selected.is_empty() {
+					gen[8] = selected[random.next_i32_bound(selected.len() as i32) as usize];
+				}
+
+				gen[..selected.len()].copy_from_slice(&selected);
+			}
+
+			if gen != expected {
+				panic!("mismatch at index {}, slot {}: expected {:?}, got {:?}", index, slot, expected, gen);
+			}
+		}
+	}
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn test_mc_xoroshiro_gaussian() {
+	use minecraft::{RandomSource, XoroshiroRandomSource};
+
+	// The vectors come from a transcription of the game's code in GenerateTestData, not from the game itself
+	for (index, &(seed, expected, thousandth)) in MC_XOROSHIRO_GAUSSIANS.iter().enumerate() {
+		let mut source = XoroshiroRandomSource::new(seed);
+		let mut gen = [0; 48];
+
+		// A cached gaussian survives the draws in between
+		for (index, value) in gen.iter_mut().enumerate() {
+			*value = if index % 3 == 2 { source.next_i32() as u64 } else { source.next_gaussian().to_bits() };
+		}
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+
+		let mut source = XoroshiroRandomSource::new(seed);
+
+		for _ in 0..999 {
+			source.next_gaussian();
+		}
+
+		assert_eq!(source.next_gaussian().to_bits(), thousandth);
+	}
+
+	// Forks take two raw words of output, and start without a cached gaussian
+	let mut source = XoroshiroRandomSource::new(3);
+	source.next_gaussian();
+
+	let mut raw = source.random().clone();
+	let (lo, hi) = (raw.next_u64(), raw.next_u64());
+
+	assert_eq!(source.fork(), XoroshiroRandomSource::from_state(lo, hi));
+}
+
+#[test]
+fn test_commons_math() {
+	use compat::commons_math::RandomDataGenerator;
+
+	let mut data = RandomDataGenerator::from_seed(COMMONS_MATH_SEED as i64);
+
+	for (index, &(mean, expected)) in COMMONS_MATH_POISSON.iter().enumerate() {
+		let mut gen = [0; 6];
+
+		for value in gen.iter_mut().take(5) {
+			*value = data.next_poisson(f64::from_bits(mean));
+		}
+
+		gen[5] = data.random().next_i64();
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	for (index, &(mean, expected)) in COMMONS_MATH_EXPONENTIAL.iter().enumerate() {
+		let mut gen = [0; 6];
+
+		for value in gen.iter_mut().take(5) {
+			*value = data.next_exponential(f64::from_bits(mean)).to_bits();
+		}
+
+		gen[5] = data.random().next_i64() as u64;
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	for (index, &([shape, scale], expected)) in COMMONS_MATH_GAMMA.iter().enumerate() {
+		let mut gen = [0; 6];
+
+		for value in gen.iter_mut().take(5) {
+			*value = data.next_gamma(f64::from_bits(shape), f64::from_bits(scale)).to_bits();
+		}
+
+		gen[5] = data.random().next_i64() as u64;
+
+		if gen != expected {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, expected, gen);
+		}
+	}
+
+	#[cfg(feature = "std")]
+	{
+		let hex = [data.next_hex_string(1), data.next_hex_string(2), data.next_hex_string(7), data.next_hex_string(32)];
+
+		assert_eq!(hex, COMMONS_MATH_HEX);
+		assert_eq!(data.random().next_i64(), COMMONS_MATH_NEXT);
+	}
+}
+
+#[test]
+fn test_fastmath() {
+	use compat::fastmath;
+
+	// NaN results are compared by bits, which Java and Rust both give as the canonical quiet NaN
+	for (index, &(x, exp, log)) in FASTMATH_EXP_LOG.iter().enumerate() {
+		let x = f64::from_bits(x);
+
+		assert_eq!(fastmath::exp(x).to_bits(), exp, "mismatch at index {}", index);
+		assert_eq!(fastmath::log(x).to_bits(), log, "mismatch at index {}", index);
+	}
+
+	for (index, &(x, y, pow)) in FASTMATH_POW.iter().enumerate() {
+		assert_eq!(fastmath::pow(f64::from_bits(x), f64::from_bits(y)).to_bits(), pow, "mismatch at index {}", index);
+	}
+
+	for (index, &(x, e, pow)) in FASTMATH_POW_INT.iter().enumerate() {
+		assert_eq!(fastmath::pow_i32(f64::from_bits(x), e).to_bits(), pow, "mismatch at index {}", index);
+	}
+}
+
+#[test]
+#[should_panic(expected = "Mean must be > 0")]
+fn test_commons_math_poisson_mean() {
+	use compat::commons_math::RandomDataGenerator;
+
+	RandomDataGenerator::from_seed(0).next_poisson(0.0);
+}
+
+#[test]
+fn test_commons_rng() {
+	use compat::commons_rng::JdkRandom;
+
+	let mut provider = JdkRandom::new(COMMONS_RNG_SEED);
+	let mut gen = [0; 24];
+
+	for (index, value) in gen.iter_mut().enumerate() {
+		*value = match index % 4 {
+			0 => provider.next_int() as u32 as u64,
+			1 => provider.next_long() as u64,
+			2 => provider.next_float().to_bits() as u64,
+			_ => provider.next_double().to_bits()
+		};
+	}
+
+	assert_eq!(gen, COMMONS_RNG_DRAWS);
+
+	let mut booleans = [false; 40];
+
+	for value in booleans.iter_mut() {
+		*value = provider.next_boolean();
+	}
+
+	assert_eq!(booleans, COMMONS_RNG_BOOLEANS);
+
+	#[cfg(feature = "std")]
+	{
+		assert_eq!(provider.save_state(), &COMMONS_RNG_STATE[..]);
+
+		// The restored provider picks up in the middle of the cached booleans
+		let mut restored = JdkRandom::new(0);
+		restored.restore_state(&COMMONS_RNG_STATE).unwrap();
+
+		assert_eq!(restored, provider);
+
+		let mut booleans = [false; 32];
+
+		for value in booleans.iter_mut() {
+			*value = restored.next_boolean();
+		}
+
+		assert_eq!(booleans, COMMONS_RNG_AFTER_STATE);
+
+		assert!(JdkRandom::from_state(&COMMONS_RNG_STATE[..111]).is_err());
+		assert!(JdkRandom::from_state(&COMMONS_RNG_STATE[4..]).is_err());
+
+		// A malformed state leaves the provider untouched
+		let mut state = COMMONS_RNG_STATE;
+		state[3] -= 1;
+
+		let before = restored.clone();
+
+		assert!(restored.restore_state(&state).is_err());
+		assert_eq!(restored, before);
+	}
+}
+
+#[test]
+fn test_commons_rng_bounded() {
+	use compat::commons_rng::JdkRandom;
+	use Error;
+
+	let mut provider = JdkRandom::new(COMMONS_RNG_SEED);
+
+	for (index, &(int_bound, int_value, long_bound, long_value)) in COMMONS_RNG_BOUNDED.iter().enumerate() {
+		assert_eq!(provider.next_int_bound(int_bound), int_value, "mismatch at index {}", index);
+		assert_eq!(provider.next_long_bound(long_bound), long_value, "mismatch at index {}", index);
+	}
+
+	let before = provider.clone();
+
+	assert_eq!(provider.try_next_int_bound(0), Err(Error::InvalidBound));
+	assert_eq!(provider.try_next_long_bound(-1), Err(Error::InvalidBound));
+	assert_eq!(provider, before);
+}
+
+#[test]
+fn test_mix() {
+	use mix::{mix_gamma, mix_lea32, mix_lea64, mix_murmur32, mix_murmur64, mix_stafford13};
+
+	for (index, &[input, murmur, stafford, lea]) in MIX_64.iter().enumerate() {
+		let gen = [input, mix_murmur64(input), mix_stafford13(input), mix_lea64(input)];
+
+		if gen != [input, murmur, stafford, lea] {
+			panic!("mismatch at index {}: expected {:?}, got {:?}", index, MIX_64[index], gen);
+		}
+	}
+
+	for (index, &[input, murmur, lea]) in MIX_32.iter().enumerate() {
+		assert_eq!([mix_murmur32(input), mix_lea32(input)], [murmur, lea], "mismatch at index {}", index);
+	}
+
+	for (index, &[input, gamma]) in MIX_GAMMA.iter().enumerate() {
+		assert_eq!(mix_gamma(input), gamma, "mismatch at index {}", index);
+		assert_eq!(gamma & 1, 1);
+	}
+}
+
+#[test]
+fn test_splitmix() {
+	use {Error, SplitMix64};
+
+	let mut random = SplitMix64::new(SPLITMIX_SEED as u64);
+	let mut gen = [0; 20];
+
+	for (index, value) in gen.iter_mut().enumerate() {
+		*value = match index % 4 {
+			0 => random.next_u64(),
+			1 => random.next_u32() as u64,
+			2 => random.next_f64().to_bits(),
+			_ => random.next_f32().to_bits() as u64
+		};
+	}
+
+	assert_eq!(gen, SPLITMIX_DRAWS);
+
+	let mut child = random.split();
+	assert_ne!(child.gamma(), random.gamma());
+
+	for (index, &[expected_child, expected_parent]) in SPLITMIX_SPLIT.iter().enumerate() {
+		assert_eq!([child.next_i64(), random.next_i64()], [expected_child, expected_parent], "mismatch at index {}", index);
+	}
+
+	let mut random = SplitMix64::new(SPLITMIX_DERIVED_SEED as u64);
+
+	for (index, &(bound, value, bound_i64, value_i64, gaussian, exponential)) in SPLITMIX_DERIVED.iter().enumerate() {
+		assert_eq!(random.next_i32_bound(bound), value, "mismatch at index {}", index);
+		assert_eq!(random.next_i64_bound(bound_i64), value_i64, "mismatch at index {}", index);
+		assert_eq!(random.next_gaussian().to_bits(), gaussian, "mismatch at index {}", index);
+		assert_eq!(random.next_exponential().to_bits(), exponential, "mismatch at index {}", index);
+	}
+
+	assert_eq!(random.try_next_i32_bound(0), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_i64_bound(-1), Err(Error::InvalidBound));
+
+	let mut random = SplitMix64::with_gamma(5, mix::GOLDEN_RATIO_64);
+	assert_eq!(random, SplitMix64::new(5));
+	assert_eq!(random.next_u64(), mix::mix_stafford13(5u64.wrapping_add(mix::GOLDEN_RATIO_64)));
+	assert_eq!(random.seed(), 5u64.wrapping_add(mix::GOLDEN_RATIO_64));
+}
+
+#[test]
+fn test_splitmix_splits() {
+	use SplitMix64;
+
+	let random = SplitMix64::new(SPLITMIX_SPLITS_SEED as u64);
+	let mut root = random.clone().splits(20);
+	let mut first = root.try_split().unwrap();
+	let lower = first.try_split().unwrap();
+	let third = root.try_split().unwrap();
+
+	assert_eq!([lower.index(), first.index(), third.index(), root.index()], [0, 5, 10, 15]);
+	assert_eq!(root.estimate_size(), 5);
+
+	// The parts are independent, so consuming them out of order gives the same generators
+	let mut generators: Vec<(u64, SplitMix64)> = Vec::new();
+
+	for part in [root, lower, third, first].iter().cloned() {
+		let start = part.index();
+		generators.extend(part.enumerate().map(|(offset, generator)| (start + offset as u64, generator)));
+	}
+
+	generators.sort_by_key(|&(index, _)| index);
+	assert_eq!(generators.len(), SPLITMIX_SPLITS.len());
+
+	for (&(index, ref generator), &(long, int)) in generators.iter().zip(SPLITMIX_SPLITS.iter()) {
+		let mut generator = generator.clone();
+		assert_eq!((generator.next_i64(), generator.next_i32()), (long, int), "mismatch at index {}", index);
+	}
+
+	// A single generator can not be split
+	let mut splits = random.splits(3);
+	assert_eq!(splits.size_hint(), (3, Some(3)));
+
+	splits.nth(1);
+	assert!(splits.try_split().is_none());
+	assert_eq!(splits.count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Gamma must be odd")]
+fn test_splitmix_even_gamma() {
+	use SplitMix64;
+
+	SplitMix64::with_gamma(0, 2);
+}
+
+#[test]
+fn test_gwt() {
+	use compat::gwt::GwtRandom;
+
+	// Double arithmetic on 24-bit halves is exact, so GWT follows the JVM sequence
+	for &seed in &[0, 1, -1, RAND_NEXTGAUSSIAN_SEED as i64, i64::MIN, 0x7FFF_FFFF_FFFF] {
+		let mut gwt = GwtRandom::new(seed);
+		let mut random = Random::new(seed as u64);
+
+		for index in 0..1000 {
+			match index % 8 {
+				0 => assert_eq!(gwt.next_i32(), random.next_i32()),
+				1 => assert_eq!(gwt.next_i32_bound(index + 1), random.next_i32_bound(index + 1)),
+				2 => assert_eq!(gwt.next_i32_bound(64), random.next_i32_bound(64)),
+				3 => assert_eq!(gwt.next_i64(), random.next_i64()),
+				4 => assert_eq!(gwt.next_f32().to_bits(), random.next_f32().to_bits()),
+				5 => assert_eq!(gwt.next_f64().to_bits(), random.next_f64().to_bits()),
+				6 => assert_eq!(gwt.next_gaussian().to_bits(), random.next_gaussian().to_bits()),
+				_ => assert_eq!(gwt.next_bool(), random.next_bool())
+			}
+		}
+
+		for bits in 0..33 {
+			assert_eq!(gwt.next(bits), random.next(bits));
+		}
+
+		let (mut gwt_bytes, mut bytes) = ([0; 23], [0; 23]);
+		gwt.next_bytes(&mut gwt_bytes);
+		random.next_bytes(&mut bytes);
+
+		assert_eq!(gwt_bytes, bytes);
+		assert_eq!(gwt.to_random(), random);
+		assert_eq!(GwtRandom::from_random(&random), gwt);
+	}
+
+	let gwt = GwtRandom::new(42);
+	let (hi, lo) = gwt.state();
+
+	assert_eq!(GwtRandom::from_state(hi, lo), gwt);
+	assert_eq!(GwtRandom::from_state(hi, lo).to_random(), Random::new(42));
+
+	// An engine with a different Math.log changes the gaussians, but not the state
+	let mut exact = GwtRandom::new(7);
+	let mut off = GwtRandom::new(7);
+
+	assert_ne!(exact.next_gaussian(), off.next_gaussian_with(|x| f64::from_bits(strictmath::log(x).to_bits() + 1)));
+	assert_eq!(exact.state(), off.state());
+}
+
+#[test]
+#[should_panic(expected = "State halves must be integers in the range [0, 2^24)")]
+fn test_gwt_invalid_state() {
+	use compat::gwt::GwtRandom;
+
+	GwtRandom::from_state(16777216.0, 0.0);
+}
+
+#[test]
+fn test_versioned_bytes() {
+	use {Checkpoint, Error, SplitMix64, Xoroshiro128PlusPlus, Xoroshiro128StarStar};
+
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	random.next_gaussian();
+
+	// Version 1 is the version byte followed by the checkpoint
+	let bytes = random.to_bytes();
+
+	assert_eq!(bytes[0], 1);
+	assert_eq!(bytes[1..], random.save().to_bytes());
+	assert_eq!(Random::from_bytes(&bytes), Ok(random.clone()));
+
+	assert_eq!(Random::new(0).to_bytes()[..11], [1, 1, 0, 0, 0, 0, 5, 0xDE, 0xEC, 0xE6, 0x6D]);
+
+	let mut xoroshiro = Xoroshiro128PlusPlus::new(9);
+	xoroshiro.next_u64();
+
+	assert_eq!(Xoroshiro128PlusPlus::from_bytes(&xoroshiro.to_bytes()), Ok(xoroshiro.clone()));
+	assert_eq!(Xoroshiro128StarStar::from_bytes(&xoroshiro.to_bytes()), Err(Error::AlgorithmMismatch));
+	assert_eq!(Random::from_bytes(&xoroshiro.to_bytes()), Err(Error::AlgorithmMismatch));
+
+	// GwtRandom and JavaLcg share the checkpoints of Random, including the pending gaussian
+	let gwt = ::compat::gwt::GwtRandom::from_random(&random);
+	let mut lcg = ::JavaLcg::new(0);
+
+	assert_eq!(gwt.to_bytes(), bytes);
+	assert_eq!(::compat::gwt::GwtRandom::from_bytes(&bytes), Ok(gwt.clone()));
+	assert_eq!(::compat::gwt::GwtRandom::from_bytes(&xoroshiro.to_bytes()), Err(Error::AlgorithmMismatch));
+
+	lcg.restore(&random.save()).unwrap();
+	assert_eq!(lcg.to_bytes(), bytes);
+	assert_eq!(::JavaLcg::from_bytes(&bytes), Ok(lcg));
+	assert_eq!(lcg.next_gaussian().to_bits(), random.clone().next_gaussian().to_bits());
+	assert_eq!(::JavaLcg::from_bytes(&xoroshiro.to_bytes()), Err(Error::AlgorithmMismatch));
+
+	let mut splitmix = SplitMix64::new(3);
+	let child = splitmix.split();
+
+	assert_eq!(SplitMix64::from_bytes(&child.to_bytes()), Ok(child.clone()));
+	assert_eq!(SplitMix64::from_bytes(&splitmix.to_bytes()), Ok(splitmix));
+
+	// Later versions are reported separately from malformed input
+	let mut later = bytes;
+	later[0] = Checkpoint::VERSION + 1;
+
+	assert_eq!(Random::from_bytes(&later), Err(Error::UnsupportedVersion));
+	assert_eq!(Random::from_bytes(&bytes[..26]), Err(Error::InvalidState));
+	assert_eq!(Random::from_bytes(&[]), Err(Error::InvalidState));
+
+	let mut invalid = bytes;
+	invalid[0] = 0;
+	assert_eq!(Random::from_bytes(&invalid), Err(Error::InvalidState));
+
+	// An even gamma is not a state SplitMix64 can be in
+	let mut invalid = child.to_bytes();
+	invalid[18] &= !1;
+	assert_eq!(SplitMix64::from_bytes(&invalid), Err(Error::InvalidState));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_checkpoint_file() {
+	use {load_checkpoint, save_checkpoint, Checkpoint, CheckpointFile, Error, Xoroshiro128StarStar};
+
+	let mut random = Random::new(0);
+	random.next_gaussian();
+
+	let file = CheckpointFile::new(random.save(), 2);
+	let document = file.to_string();
+
+	assert_eq!(document, format!(
+		"# java-rand checkpoint\nversion = 1\nalgorithm = \"Random\"\nstate = [\"{:#018x}\", \"0x0000000000000000\"]\n\
+		 next_gaussian = {:?}\ncalls = 2\n",
+		random.save().state()[0], random.save().next_gaussian().unwrap()
+	));
+	assert_eq!(document.parse(), Ok(file));
+
+	let xoroshiro = CheckpointFile::new(Xoroshiro128StarStar::new(5).save(), u64::MAX);
+	assert_eq!(xoroshiro.to_string().parse(), Ok(xoroshiro));
+
+	// Hand edited documents may use comments, other orders and bare integers
+	let edited = "version = 1 # format\n\n  calls = 1_000\nstate = [0x5deece66d, 0, ]\nalgorithm = \"Random\"\n";
+	assert_eq!(edited.parse(), Ok(CheckpointFile::new(Random::new(0).save(), 1000)));
+
+	let header = "version = 1\nalgorithm = \"Random\"\n";
+
+	assert_eq!(format!("{}state = [\"0x1\", \"0x0\"]\nseed = 1\n", header).parse::<CheckpointFile>(), Err(Error::ParseError));
+	assert_eq!(format!("{}state = [\"0x1\"]\n", header).parse::<CheckpointFile>(), Err(Error::ParseError));
+	assert_eq!(format!("{}algorithm = \"Random\"\n", header).parse::<CheckpointFile>(), Err(Error::ParseError));
+	assert_eq!(format!("{}state = [\"0x1\", \"0x1\"]\n", header).parse::<CheckpointFile>(), Err(Error::InvalidState));
+	assert_eq!(
+		"version = 2\nalgorithm = \"Random\"\nstate = [1, 0]\n".parse::<CheckpointFile>(),
+		Err(Error::UnsupportedVersion)
+	);
+
+	let path = ::std::env::temp_dir().join(format!("java-rand-checkpoint-{}.toml", ::std::process::id()));
+
+	save_checkpoint(&path, &random.save(), 7).unwrap();
+	let loaded = load_checkpoint(&path).unwrap();
+
+	assert_eq!(loaded, CheckpointFile::new(random.save(), 7));
+
+	::std::fs::write(&path, "version = 1\n").unwrap();
+	assert_eq!(load_checkpoint(&path).unwrap_err().kind(), ::std::io::ErrorKind::InvalidData);
+
+	::std::fs::remove_file(&path).unwrap();
+
+	assert_eq!(Checkpoint::from_versioned_bytes(&loaded.checkpoint.to_versioned_bytes()), Ok(loaded.checkpoint));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_crack() {
+	use crack::{Cracker, Observation};
+	use Error;
+
+	let start = Random::new(0x2A_1234_5678);
+
+	// Two nextInt() calls determine the state
+	let mut random = start.clone();
+	let mut cracker = Cracker::new();
+	cracker.next_i32(random.next_i32()).next_i32(random.next_i32());
+
+	assert_eq!(cracker.solve(), Ok(vec![start.clone()]));
+
+	// So do a dozen small powers of two, a few floats, a long or a double
+	let mut random = start.clone();
+	let mut cracker = Cracker::new();
+
+	for _ in 0..14 {
+		cracker.next_i32_bound(16, random.next_i32_bound(16));
+	}
+
+	assert_eq!(cracker.solve(), Ok(vec![start.clone()]));
+
+	let mut random = start.clone();
+	let mut cracker = Cracker::new();
+	cracker.next_f32(random.next_f32()).next_f32(random.next_f32()).next_f32(random.next_f32());
+
+	assert_eq!(cracker.solve(), Ok(vec![start.clone()]));
+
+	let mut random = start.clone();
+	assert_eq!(Cracker::new().next_i64(random.next_i64()).solve(), Ok(vec![start.clone()]));
+
+	let mut random = start.clone();
+	assert_eq!(Cracker::new().next_f64(random.next_f64()).solve(), Ok(vec![start.clone()]));
+
+	// Unobserved steps, bounds that only check candidates, and booleans can be mixed in
+	let mut random = start.clone();
+	let mut cracker = Cracker::new();
+
+	cracker.next_i32_bound(100, random.next_i32_bound(100));
+	random.next_i64();
+	cracker.skip(2);
+	cracker.next_bool(random.next_bool());
+	cracker.next_i32(random.next_i32());
+	cracker.observe(Observation::NextF32(random.next_f32()));
+
+	assert_eq!(cracker.observations().len(), 5);
+	assert_eq!(cracker.solve(), Ok(vec![start.clone()]));
+
+	// A single nextInt() leaves the low 16 bits free
+	let mut random = start.clone();
+	let candidates = Cracker::new().next_i32(random.next_i32()).solve().unwrap();
+
+	assert_eq!(candidates.len(), 1 << 16);
+	assert!(candidates.contains(&start));
+
+	let mut booleans = Cracker::new();
+
+	for _ in 0..32 {
+		booleans.next_bool(true);
+	}
+
+	assert_eq!(booleans.solve(), Err(Error::TooManyCandidates));
+	assert_eq!(Cracker::new().solve(), Err(Error::TooManyCandidates));
+	assert_eq!(Cracker::new().next_i32_bound(0, 0).solve(), Err(Error::InvalidBound));
+
+	// Values the generator cannot produce have no candidates
+	assert_eq!(Cracker::new().next_i32(0).next_f32(0.1).solve(), Ok(vec![]));
+	assert_eq!(Cracker::new().next_i32(0).next_i32_bound(10, 10).solve(), Ok(vec![]));
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_minecraft_seeds() {
+	use minecraft::{next_long_seeds, sister_seeds, structure_seed, text_seed};
+
+	assert_eq!(text_seed("glacier"), Some(108_181_935));
+	assert_eq!(text_seed("  -42\t"), Some(-42));
+	assert_eq!(text_seed("99999999999999999999"), Some(1_260_560_192));
+	assert_eq!(text_seed(" \n"), None);
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	for _ in 0..16 {
+		let world_seed = random.next_i64();
+		let structure = structure_seed(world_seed);
+
+		assert_eq!(structure_seed(structure), structure);
+		assert!(next_long_seeds(structure).any(|seed| seed == world_seed));
+		assert!(next_long_seeds(world_seed).all(|seed| structure_seed(seed) == structure));
+
+		let mut sisters = sister_seeds(world_seed);
+
+		assert_eq!(sisters.next(), Some(structure));
+		assert_eq!(sisters.count(), (1 << 16) - 1);
+	}
+
+	assert!(sister_seeds(-1).any(|seed| seed == -1));
+}
+
+#[test]
+fn test_next_bytes_64() {
+	use {SplitMix64, Xoroshiro128PlusPlus};
+
+	let mut splitmix = SplitMix64::new(NEXT_BYTES_SEED);
+	let mut xoroshiro = Xoroshiro128PlusPlus::new(NEXT_BYTES_SEED);
+	let mut offset = 0;
+
+	// A call of each length from 0 to 17, covering empty, partial and multiple blocks
+	for len in 0..18 {
+		let mut bytes = [0; 17];
+
+		splitmix.next_bytes(&mut bytes[..len]);
+		assert_eq!(&bytes[..len], &SPLITMIX_NEXT_BYTES[offset..offset + len], "mismatch at length {}", len);
+
+		xoroshiro.next_bytes(&mut bytes[..len]);
+		assert_eq!(&bytes[..len], &XOROSHIRO128PLUSPLUS_NEXT_BYTES[offset..offset + len], "mismatch at length {}", len);
+
+		offset += len;
+	}
+}
+
+#[test]
+fn test_bound_full() {
+	use Error;
+
+	// Powers of two never reject, and keep the low bits of the full value
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let mut reference = random.clone();
+
+	for shift in 0..32 {
+		assert_eq!(random.next_u32_bound_full(1 << shift), reference.next_u32() & ((1u64 << shift) - 1) as u32);
+	}
+
+	for shift in 0..64 {
+		assert_eq!(random.next_u64_bound_full(1 << shift), reference.next_u64() & ((1u128 << shift) - 1) as u64);
+	}
+
+	// Bounds beyond Java's 2^31, with 2^32 mod max = 2^30 and 2^64 mod max = 2^62 rejected
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut reference = random.clone();
+
+	for _ in 0..256 {
+		let max = 3 << 30;
+		let mut expected = reference.next_u32();
+
+		while expected < 1 << 30 {
+			expected = reference.next_u32();
+		}
+
+		assert_eq!(random.next_u32_bound_full(max), expected % max);
+
+		let max = 3 << 62;
+		let mut expected = reference.next_u64();
+
+		while expected < 1 << 62 {
+			expected = reference.next_u64();
+		}
+
+		assert_eq!(random.next_u64_bound_full(max), expected % max);
+	}
+
+	assert!((0..1000).all(|_| random.next_u32_bound_full(u32::MAX) < u32::MAX));
+	assert!((0..1000).all(|_| random.next_u64_bound_full(u64::MAX) < u64::MAX));
+
+	let before = random.clone();
+
+	assert_eq!(random.try_next_u32_bound_full(0), Err(Error::InvalidBound));
+	assert_eq!(random.try_next_u64_bound_full(0), Err(Error::InvalidBound));
+	assert_eq!(random, before);
+}
+
+#[test]
+#[should_panic(expected = "Maximum must be > 0")]
+fn test_bound_full_zero() {
+	Random::new(0).next_u64_bound_full(0);
+}
+
+#[test]
+fn test_bit_reader() {
+	// Bit `index` of the stream of nextInt() calls, most significant first
+	let bit = |index: usize| (RAND_NEXT32[index / 32] >> (31 - index % 32)) & 1 != 0;
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let mut reader = random.bit_reader();
+	let mut position = 0;
+
+	for (read, &width) in [5u8, 17, 10, 64, 1, 3, 32, 63, 7, 48, 2, 31, 64].iter().cycle().take(64).enumerate() {
+		let expected = (position..position + width as usize).fold(0u64, |value, index| value << 1 | bit(index) as u64);
+
+		assert_eq!(reader.read(width), expected, "mismatch at read {}", read);
+		position += width as usize;
+
+		assert_eq!(reader.buffered() as usize, (32 - position % 32) % 32);
+	}
+
+	// 5 + 17 + 10 bits use exactly one call, and aligning discards the rest of a call
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	{
+		let mut reader = random.bit_reader();
+
+		reader.read(5);
+		reader.read(17);
+		reader.read(10);
+		assert_eq!(reader.buffered(), 0);
+
+		assert_eq!(reader.read_bool(), bit(32));
+		reader.align();
+	}
+
+	assert_eq!(random.next_u32(), RAND_NEXT32[2]);
+}
+
+#[test]
+#[should_panic(expected = "Bits must be between 1 and 64")]
+fn test_bit_reader_zero() {
+	Random::new(0).bit_reader().read(0);
+}
+
+#[test]
+fn test_skip() {
+	use Error;
+
+	type Skip = fn(&mut Random, u64);
+	type Draw = fn(&mut Random);
+
+	let cases: [(Skip, Draw); 6] = [
+		(Random::skip, |random| { random.next(1); }),
+		(Random::skip_ints, |random| { random.next_i32(); }),
+		(Random::skip_longs, |random| { random.next_i64(); }),
+		(Random::skip_doubles, |random| { random.next_f64(); }),
+		(|random, n| random.skip_bounded_ints(100, n), |random| { random.next_i32_bound(100); }),
+		(Random::skip_gaussians, |random| { random.next_gaussian(); })
+	];
+
+	for (case, &(skip, draw)) in cases.iter().enumerate() {
+		for n in 0..12 {
+			// Start with and without a pending gaussian
+			for &pending in &[false, true] {
+				let mut skipped = Random::new(RAND_NEXT64_SEED);
+
+				if pending {
+					skipped.next_gaussian();
+				}
+
+				let mut drawn = skipped.clone();
+
+				skip(&mut skipped, n);
+				(0..n).for_each(|_| draw(&mut drawn));
+
+				assert_eq!(skipped, drawn, "mismatch in case {} after {} calls", case, n);
+			}
+		}
+	}
+
+	let mut skipped = Random::new(RAND_NEXT32_SEED);
+	let mut drawn = skipped.clone();
+
+	skipped.skip_bounded_ints(64, 1000);
+	(0..1000).for_each(|_| { drawn.next_i32_bound(64); });
+
+	assert_eq!(skipped, drawn);
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	assert_eq!(random.try_skip_bounded_ints(0, 5), Err(Error::InvalidBound));
+	assert_eq!(random, Random::new(RAND_NEXT32_SEED));
+	assert_eq!(random.try_skip_bounded_ints(100, 5), Ok(()));
+
+	// A full period returns to the same state, even when counted in longs that overflow
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	random.skip(1 << 48);
+	random.skip_longs(1 << 63);
+	assert_eq!(random, Random::new(RAND_NEXT32_SEED));
+}
+
+#[test]
+fn test_next_array() {
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let ints = random.next_array::<u32, 16>();
+
+	for (i, &value) in ints.iter().enumerate() {
+		assert_eq!(value, RAND_NEXT32[i], "mismatch at index {}", i);
+	}
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut expected = random.clone();
+
+	let longs: [i64; 3] = random.next_array();
+	let doubles: [f64; 5] = random.next_array();
+	let bools: [bool; 7] = random.next_array();
+
+	assert_eq!(longs, [expected.next_i64(), expected.next_i64(), expected.next_i64()]);
+
+	for &value in &doubles {
+		assert_eq!(value.to_bits(), expected.next_f64().to_bits());
+	}
+
+	for &value in &bools {
+		assert_eq!(value, expected.next_bool());
+	}
+
+	let empty: [f32; 0] = random.next_array();
+
+	assert_eq!(empty.len(), 0);
+	assert_eq!(random, expected);
+}
+
+#[test]
+fn test_next_bytes_endian() {
+	let mut little = [0; 128];
+
+	Random::new(RAND_NEXTBYTES_SEED).next_bytes_le(&mut little);
+	assert_eq!(&little[..], &RAND_NEXTBYTES[..]);
+
+	// Lengths around a whole batch and with every partial tail
+	for len in 0..72 {
+		let mut bytes = [0; 72];
+
+		Random::new(RAND_NEXT32_SEED).next_bytes_be(&mut bytes[..len]);
+
+		for (i, &byte) in bytes[..len].iter().enumerate() {
+			assert_eq!(byte, RAND_NEXT32[i / 4].to_be_bytes()[i % 4], "mismatch at index {} of {}", i, len);
+		}
+	}
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+
+	for (i, &value) in RAND_NEXT32.iter().enumerate().step_by(2) {
+		assert_eq!(random.next_i32_be_bytes(), value.to_be_bytes(), "mismatch at index {}", i);
+		assert_eq!(random.next_i32_le_bytes(), RAND_NEXT32[i + 1].to_le_bytes(), "mismatch at index {}", i + 1);
+	}
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+
+	for (i, &value) in RAND_NEXT64.iter().enumerate().step_by(2) {
+		assert_eq!(random.next_i64_be_bytes(), value.to_be_bytes(), "mismatch at index {}", i);
+		assert_eq!(random.next_i64_le_bytes(), RAND_NEXT64[i + 1].to_le_bytes(), "mismatch at index {}", i + 1);
+	}
+}
+
+#[test]
+fn test_substream() {
+	use Error;
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	random.next_gaussian();
+
+	let before = random.clone();
+
+	for &(i, stride_log2) in &[(0, 0), (1, 0), (5, 3), (3, 40), ((1 << 8) - 1, 40), (0, 48)] {
+		// Taking the pending gaussian leaves the same state without one
+		let mut expected = before.clone();
+		expected.next_gaussian();
+		expected.skip(i << stride_log2);
+
+		assert_eq!(random.substream(i, stride_log2), expected, "mismatch for substream {} << {}", i, stride_log2);
+	}
+
+	assert_eq!(random, before);
+
+	assert_eq!(random.try_substream(1 << 8, 40), Err(Error::InvalidShard));
+	assert_eq!(random.try_substream(1, 48), Err(Error::InvalidShard));
+	assert_eq!(random.try_substream(0, 49), Err(Error::InvalidShard));
+}
+
+#[test]
+#[should_panic(expected = "Substream lies outside of the period")]
+fn test_substream_outside() {
+	Random::new(0).substream(1 << 16, 32);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_stats_recorder_pending_gaussian() {
+	use StatsRecorder;
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let mut expected = random.clone();
+
+	// Wrapping picks up the gaussian left pending by the generator
+	assert_eq!(random.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+
+	let mut recorder = StatsRecorder::new(random);
+
+	for _ in 0..3 {
+		assert_eq!(recorder.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+	}
+
+	// Unwrapping hands back the gaussian left pending by the recorder
+	let mut random = recorder.into_inner();
+
+	assert_eq!(random, expected);
+	assert_eq!(random.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+	assert_eq!(random.next_i32(), expected.next_i32());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_stats_recorder() {
+	use {Method, StatsRecorder};
+
+	let mut recorder = StatsRecorder::new(Random::new(RAND_NEXT32_SEED))
+		.with_histogram(Method::NextI32Bound, 0.0, 10.0, 5)
+		.with_histogram(Method::NextF64, 0.25, 0.75, 2);
+
+	let mut random = Random::new(RAND_NEXT32_SEED);
+	let mut bounded = [0.0; 1000];
+
+	for value in bounded.iter_mut() {
+		let drawn = recorder.next_i32_bound(10);
+
+		assert_eq!(drawn, random.next_i32_bound(10));
+		*value = drawn as f64;
+	}
+
+	let mut doubles = [0.0; 1000];
+
+	for value in doubles.iter_mut() {
+		*value = recorder.next_f64();
+
+		assert_eq!(value.to_bits(), random.next_f64().to_bits());
+	}
+
+	// The pending gaussian is kept by the core, so the comparison below covers it
+	for _ in 0..5 {
+		assert_eq!(recorder.next_gaussian().to_bits(), random.next_gaussian().to_bits());
+	}
+
+	let mut bytes = [0; 7];
+	let mut expected = [0; 7];
+
+	recorder.next_bytes(&mut bytes);
+	random.next_bytes(&mut expected);
+	assert_eq!(bytes, expected);
+
+	assert_eq!(recorder.next_u32(), random.next_u32());
+	assert_eq!(*recorder.core(), random);
+
+	let summary = recorder.summary(Method::NextI32Bound);
+	let mean = bounded.iter().sum::<f64>() / 1000.0;
+	let variance = bounded.iter().map(|value| (value - mean) * (value - mean)).sum::<f64>() / 999.0;
+
+	assert_eq!(summary.count(), 1000);
+	assert!((summary.mean().unwrap() - mean).abs() < 1e-12);
+	assert!((summary.variance().unwrap() - variance).abs() < 1e-12);
+	assert_eq!((summary.min(), summary.max()), (Some(0.0), Some(9.0)));
+
+	let histogram = recorder.histogram(Method::NextI32Bound).unwrap();
+
+	for (bucket, &count) in histogram.counts().iter().enumerate() {
+		let expected = bounded.iter().filter(|&&value| value as usize / 2 == bucket).count();
+
+		assert_eq!(count, expected as u64, "mismatch at bucket {}", bucket);
+	}
+
+	let histogram = recorder.histogram(Method::NextF64).unwrap();
+
+	assert_eq!(histogram.below(), doubles.iter().filter(|&&value| value < 0.25).count() as u64);
+	assert_eq!(histogram.above(), doubles.iter().filter(|&&value| value >= 0.75).count() as u64);
+	assert_eq!(histogram.counts()[0], doubles.iter().filter(|value| (0.25..0.5).contains(*value)).count() as u64);
+
+	assert_eq!(recorder.summary(Method::NextGaussian).count(), 5);
+	assert_eq!(recorder.summary(Method::NextBytes).count(), 7);
+	assert_eq!(recorder.summary(Method::NextI32).count(), 1);
+	assert_eq!(recorder.summary(Method::NextI64).mean(), None);
+	assert!(recorder.histogram(Method::NextGaussian).is_none());
+
+	recorder.reset();
+
+	assert_eq!(recorder.summary(Method::NextI32Bound).count(), 0);
+	assert_eq!(recorder.histogram(Method::NextI32Bound).unwrap().counts(), &[0; 5]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_traced() {
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut expected = random.clone();
+	let mut steps = Vec::new();
+
+	{
+		let mut traced = random.traced(|step| steps.push(step));
+
+		assert_eq!(traced.next_i64(), expected.next_i64());
+		assert_eq!(traced.next_i32_bound(1 << 20), expected.next_i32_bound(1 << 20));
+		assert_eq!(traced.next_f64().to_bits(), expected.next_f64().to_bits());
+		assert_eq!(traced.next_bool(), expected.next_bool());
+	}
+
+	assert_eq!(random, expected);
+	assert_eq!(steps.iter().map(|step| step.bits).collect::<Vec<_>>(), [32, 32, 31, 26, 27, 1]);
+
+	let mut replay = Random::new(RAND_NEXT64_SEED);
+
+	for (i, step) in steps.iter().enumerate() {
+		let old_state = replay.state;
+
+		assert_eq!(step.old_state, old_state, "mismatch at index {}", i);
+		assert_eq!(step.output, replay.next(step.bits), "mismatch at index {}", i);
+		assert_eq!(step.new_state, replay.state, "mismatch at index {}", i);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_traced_gaussian() {
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut expected = random.clone();
+	let mut steps = 0;
+
+	// Alternating between the view and the generator hands the pending gaussian back and forth
+	for round in 0..4 {
+		assert_eq!(random.next_gaussian().to_bits(), expected.next_gaussian().to_bits(), "mismatch at round {}", round);
+
+		{
+			let mut traced = random.traced(|_| steps += 1);
+
+			let gaussian = traced.next_gaussian();
+
+			assert_eq!(gaussian.to_bits(), expected.next_gaussian().to_bits(), "mismatch at round {}", round);
+		}
+
+		assert_eq!(random, expected, "mismatch at round {}", round);
+	}
+
+	// Each view returned the second value of the pair drawn by the generator, without stepping
+	assert_eq!(steps, 0);
+
+	{
+		let mut traced = random.traced(|_| steps += 1);
+
+		for _ in 0..3 {
+			assert_eq!(traced.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+		}
+	}
+
+	// The view drew two pairs and left the second value of the last one to the generator
+	assert!(steps >= 8);
+	assert_eq!(random.next_gaussian().to_bits(), expected.next_gaussian().to_bits());
+	assert_eq!(random, expected);
+}
+
+#[test]
+fn test_next_bits_and_small_bounds() {
+	let mut random = Random::new(RAND_NEXT_BITS_SEED);
+
+	for (i, &(bits, value)) in RAND_NEXT_BITS.iter().enumerate() {
+		assert_eq!(random.next(bits), value, "mismatch at index {}", i);
+	}
+
+	let mut random = Random::new(RAND_NEXT32_BOUND_SMALL_SEED);
+
+	for (i, &(bound, value)) in RAND_NEXT32_BOUND_SMALL.iter().enumerate() {
+		assert_eq!(random.next_i32_bound(bound), value, "mismatch at index {}", i);
+	}
+}
+
+#[test]
+#[cfg(feature = "reference")]
+fn test_reference() {
+	use reference;
+
+	for seed in (0..64).map(|i: u64| i.wrapping_mul(0x9E3779B97F4A7C15)) {
+		let mut fast = Random::new(seed);
+		let mut oracle = reference::Random::new(seed as i64);
+
+		for i in 0..256 {
+			let bound = [1, 2, 7, 10, 64, 100, 1 << 30, (1 << 30) + 1, i32::MAX][i % 9];
+
+			assert_eq!(fast.next((i % 32 + 1) as u8), oracle.next((i % 32 + 1) as i32), "mismatch at index {}", i);
+			assert_eq!(fast.next_i32(), oracle.next_int(), "mismatch at index {}", i);
+			assert_eq!(fast.next_i32_bound(bound), oracle.next_int_bound(bound), "mismatch at index {}", i);
+			assert_eq!(fast.next_i64(), oracle.next_long(), "mismatch at index {}", i);
+			assert_eq!(fast.next_bool(), oracle.next_boolean(), "mismatch at index {}", i);
+			assert_eq!(fast.next_f32().to_bits(), oracle.next_float().to_bits(), "mismatch at index {}", i);
+			assert_eq!(fast.next_f64().to_bits(), oracle.next_double().to_bits(), "mismatch at index {}", i);
+			assert_eq!(fast.next_gaussian().to_bits(), oracle.next_gaussian().to_bits(), "mismatch at index {}", i);
+
+			let mut bytes = [0; 11];
+			let mut signed = [0; 11];
+
+			fast.next_bytes(&mut bytes[..i % 12]);
+			oracle.next_bytes(&mut signed[..i % 12]);
+
+			assert_eq!(bytes.map(|byte| byte as i8), signed, "mismatch at index {}", i);
+		}
+
+		fast.set_seed(seed ^ 1);
+		oracle.set_seed((seed ^ 1) as i64);
+
+		assert_eq!(fast.next_gaussian().to_bits(), oracle.next_gaussian().to_bits());
+	}
+}
+
+#[test]
+#[cfg(feature = "reference")]
+fn test_reference_golden() {
+	use reference;
+
+	let mut oracle = reference::Random::new(RAND_NEXT32_SEED as i64);
+
+	for (i, &value) in RAND_NEXT32.iter().enumerate() {
+		assert_eq!(oracle.next_int() as u32, value, "mismatch at index {}", i);
+	}
+
+	let mut oracle = reference::Random::new(RAND_NEXTGAUSSIAN_SEED as i64);
+
+	for (i, &value) in RAND_NEXTGAUSSIAN.iter().enumerate() {
+		assert_eq!(oracle.next_gaussian().to_bits(), value, "mismatch at index {}", i);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_big_uint_bytes() {
+	let mut random = Random::new(BIG_INTEGER_RANDOM_SEED);
+
+	for (i, &(bits, hex)) in BIG_INTEGER_RANDOM.iter().enumerate() {
+		let bytes = random.next_big_uint_bytes(bits);
+
+		assert_eq!(bytes.len(), (bits as usize).div_ceil(8), "mismatch at index {}", i);
+
+		let digits: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+		let digits = digits.trim_start_matches('0');
+
+		assert_eq!(if digits.is_empty() { "0" } else { digits }, hex, "mismatch at index {}", i);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_probable_prime_bytes() {
+	let mut random = Random::new(BIG_INTEGER_PRIMES_SEED);
+
+	for (i, &(bits, certainty, hex)) in BIG_INTEGER_PRIMES.iter().enumerate() {
+		let bytes = random.next_probable_prime_bytes(bits, certainty);
+		let digits: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+		assert_eq!(digits.trim_start_matches('0'), hex, "mismatch at index {}", i);
+	}
+
+	assert_eq!(random.next_i32(), BIG_INTEGER_PRIMES_NEXT);
+}
+
+#[test]
+#[should_panic(expected = "Bit length must be at least 2")]
+#[cfg(feature = "std")]
+fn test_probable_prime_bytes_short() {
+	Random::new(0).next_probable_prime_bytes(1, 100);
+}
+
+#[test]
+fn test_gen() {
+	use {Bounded, Gaussian};
+
+	let mut random = Random::new(RAND_NEXT32_BOUND_999999999_SEED);
+
+	for (i, &value) in RAND_NEXT32_BOUND_999999999.iter().enumerate() {
+		assert_eq!(random.gen::<Bounded<999999999>>(), Bounded(value as i32), "mismatch at index {}", i);
+	}
+
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+
+	for (i, &value) in RAND_NEXTGAUSSIAN.iter().enumerate() {
+		let Gaussian(gaussian) = random.gen();
+
+		assert_eq!(gaussian.to_bits(), value, "mismatch at index {}", i);
+	}
+
+	let mut random = Random::new(RAND_NEXT64_SEED);
+	let mut expected = random.clone();
+
+	assert_eq!(random.gen::<i64>(), expected.next_i64());
+	assert_eq!(random.gen::<u32>(), expected.next_u32());
+	assert_eq!(random.gen::<bool>(), expected.next_bool());
+	assert_eq!(random.gen::<f32>().to_bits(), expected.next_f32().to_bits());
+	assert_eq!(random.gen::<Bounded<64>>(), Bounded(expected.next_i32_bound(64)));
+	assert_eq!(random, expected);
+}
+
+#[test]
+fn test_java_rand_vectors() {
+	const INTS: [u32; 128] = java_rand_vectors!(seed = RAND_NEXT32_SEED, next_u32 * 128);
+	const BOUNDED: [i32; 128] = java_rand_vectors!(seed = RAND_NEXT32_BOUND_65536_SEED, next_i32_bound(65536) * 128);
+	const FLOATS: [f32; 128] = java_rand_vectors!(seed = RAND_NEXTF32_SEED, next_f32 * 128);
+	const BITS: [i32; 1] = java_rand_vectors!(seed = RAND_NEXT_BITS_SEED, next(1) * 1);
+	const EMPTY: [i64; 0] = java_rand_vectors!(seed = 0, next_i64 * 0);
+
+	assert_eq!(INTS, RAND_NEXT32);
+
+	for i in 0..128 {
+		assert_eq!(BOUNDED[i] as u32, RAND_NEXT32_BOUND_65536[i], "mismatch at index {}", i);
+		assert_eq!(FLOATS[i].to_bits(), RAND_NEXTF32[i], "mismatch at index {}", i);
+	}
+
+	assert_eq!(BITS[0], RAND_NEXT_BITS[0].1);
+	assert_eq!(EMPTY.len(), 0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_exchange_java_trace() {
+	use exchange::{self, TraceEntry, Value};
+	use Method;
+
+	// Written by `java TracingRandom.java 42`
+	const TRACE: &str = r#"{"index":0,"method":"next","args":[17],"result":95363}
+{"index":1,"method":"nextBytes","args":[7],"result":[-9,-118,-2,13,-31,-69,-25]}
+{"index":2,"method":"nextInt","args":[],"result":205897768}
+{"index":3,"method":"nextInt","args":[10],"result":0}
+{"index":4,"method":"nextInt","args":[1048576],"result":987835}
+{"index":5,"method":"nextLong","args":[],"result":5111195811822994797}
+{"index":6,"method":"nextBoolean","args":[],"result":true}
+{"index":7,"method":"nextFloat","args":[],"result":0.0913245677947998}
+{"index":8,"method":"nextDouble","args":[],"result":0.9033722646721782}
+{"index":9,"method":"nextDouble","args":[1.0E-5],"result":3.687829134113057E-6}
+{"index":10,"method":"nextDouble","args":[-100.0,100.0],"result":-44.850386111659525}
+{"index":11,"method":"nextGaussian","args":[],"result":-0.19094451307087512}
+{"index":12,"method":"nextGaussian","args":[],"result":1.4862133923906502}
+"#;
+
+	let entries = exchange::read_trace(TRACE.as_bytes()).unwrap();
+
+	assert_eq!(entries.len(), 13);
+	assert_eq!(entries[9].method, Method::NextF64Bound);
+	assert_eq!(entries[9].args, [Value::Double(1e-5)]);
+	assert_eq!(exchange::validate(&entries, &mut Random::new(42)), Ok(()));
+
+	let divergence = exchange::validate(&entries, &mut Random::new(43)).unwrap_err();
+	assert_eq!(divergence.position, 0);
+
+	// A port that forgot a call diverges at the next one
+	let mut skipped = entries.clone();
+	skipped.remove(6);
+	let divergence = exchange::validate(&skipped, &mut Random::new(42)).unwrap_err();
+	assert_eq!(divergence.position, 6);
+	assert_eq!(divergence.entry.index, 7);
+	assert!(divergence.actual.is_some());
+
+	for line in TRACE.lines() {
+		assert_eq!(line.parse::<TraceEntry>().unwrap().to_string().parse::<TraceEntry>(), line.parse());
+	}
+
+	for line in &[
+		"",
+		"{}",
+		r#"{"index":0,"method":"nextInt","args":[],"result":1.5}"#,
+		r#"{"index":0,"method":"nextInt","args":[1,2],"result":1}"#,
+		r#"{"index":0,"method":"nextLong","args":[],"result":1} trailing"#,
+		r#"{"index":0,"method":"nextBytes","args":[1],"result":[128]}"#,
+		r#"{"index":0,"method":"nextInt","args":[],"result":1,"result":1}"#
+	] {
+		assert!(line.parse::<TraceEntry>().is_err(), "parsed {}", line);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_exchange_trace_writer() {
+	use exchange::{self, TraceWriter};
+
+	let mut writer = TraceWriter::new(Random::new(1234), Vec::new());
+	let mut bytes = [0; 5];
+
+	writer.next_i32_bound(100);
+	writer.next_bytes(&mut bytes);
+	writer.next_f32();
+	writer.next_f64_range(-1.0, 1.0);
+	writer.next_gaussian();
+	writer.next_gaussian();
+	writer.next_i64();
+
+	let (random, trace) = writer.finish().unwrap();
+	let entries = exchange::read_trace(&trace[..]).unwrap();
+
+	assert_eq!(entries.len(), 7);
+	assert_eq!(entries[6].index, 6);
+	assert_eq!(exchange::validate(&entries, &mut Random::new(1234)), Ok(()));
+
+	let mut expected = Random::new(1234);
+
+	for entry in &entries {
+		entry.replay(&mut expected);
+	}
+
+	assert_eq!(random, expected);
+
+	// Java array lengths are ints, so longer arrays cannot be replayed
+	let mut oversized = entries[1].clone();
+	oversized.args = vec![exchange::Value::Int(i32::MAX as i64 + 1)];
+
+	assert_eq!(oversized.replay(&mut expected), None);
+	assert_eq!(random, expected);
+
+	oversized.args = vec![exchange::Value::Int(-1)];
+	assert_eq!(oversized.replay(&mut expected), None);
+
+	let mut other = entries.clone();
+	assert_eq!(exchange::diff(&entries, &other), None);
+
+	other.truncate(4);
+	assert_eq!(exchange::diff(&entries, &other), Some(4));
+
+	other[2].result = exchange::Value::Double(0.5);
+	assert_eq!(exchange::diff(&entries, &other), Some(2));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_random_set() {
+	use Error;
+	use RandomSet;
+
+	let master = Random::new(RAND_NEXT32_SEED);
+	let mut set = RandomSet::derived(&master, &["terrain", "loot", "ai"]).unwrap();
+
+	assert_eq!(set.len(), 3);
+	assert_eq!(set.get("loot"), Some(&master.derive_child_str("loot")));
+	assert_eq!(set.iter().map(|(name, _)| &name[..]).collect::<Vec<_>>(), ["ai", "loot", "terrain"]);
+	assert_eq!(RandomSet::derived(&master, &["ai", "ai"]), Err(Error::DuplicateGenerator));
+
+	// Sharing a stream by name or by cloning a generator is rejected
+	let terrain = set.get("terrain").unwrap().clone();
+	assert_eq!(set.insert("ai", Random::new(0)), Err(Error::DuplicateGenerator));
+	assert_eq!(set.insert("weather", terrain), Err(Error::DuplicateGenerator));
+	assert_eq!(set.len(), 3);
+
+	set.get_mut("ai").unwrap().next_gaussian();
+	let saved = set.to_bytes();
+	assert_eq!(saved.len(), 4 + 3 * (2 + 27) + "ai".len() + "loot".len() + "terrain".len());
+	assert_eq!(RandomSet::from_bytes(&saved).as_ref(), Ok(&set));
+
+	let names = ["ai", "loot", "terrain"];
+	let expected: Vec<i32> = names.iter().map(|&name| set.get_mut(name).unwrap().next_i32()).collect();
+	let drawn = set.clone();
+
+	// Failed restores leave every generator untouched
+	assert_eq!(set.restore(&saved[..saved.len() - 1]), Err(Error::InvalidState));
+	assert_eq!(set.restore(&[&saved[..], &[0]].concat()), Err(Error::InvalidState));
+	assert_eq!(set.restore(&RandomSet::new().to_bytes()), Err(Error::InvalidState));
+	assert_eq!(set, drawn);
+
+	set.restore(&saved).unwrap();
+	assert!(set.get("ai").unwrap().save().next_gaussian().is_some());
+
+	for (i, &name) in names.iter().enumerate() {
+		assert_eq!(set.get_mut(name).unwrap().next_i32(), expected[i], "mismatch at index {}", i);
+	}
+
+	assert_eq!(set.remove("loot"), drawn.get("loot").cloned());
+	assert_eq!(set.remove("loot"), None);
+
+	// A save holding one generator twice under different names is rejected like the insert would be
+	let state = master.to_bytes();
+	let cloned = [&[0, 0, 0, 2, 0, 1, b'a'][..], &state, &[0, 1, b'b'], &state].concat();
+
+	assert_eq!(RandomSet::from_bytes(&cloned), Err(Error::InvalidState));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_fill_kernels() {
+	use batch::{fill_with, Kernel};
+
+	let mut kernels = vec![Kernel::Scalar];
+
+	#[cfg(target_arch = "x86_64")]
+	{
+		if is_x86_feature_detected!("avx2") {
+			kernels.push(Kernel::Avx2);
+		}
+
+		if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512dq") {
+			kernels.push(Kernel::Avx512);
+		}
+	}
+
+	for &kernel in &kernels {
+		for &len in &[0, 1, 15, 16, 17, 63, 64, 65, 200, 1000] {
+			let mut expected = Random::new(RAND_NEXT32_SEED);
+			let mut actual = expected.clone();
+			let mut values = vec![0; len];
+
+			let rest = fill_with(kernel, &mut actual.state, &mut values, |value| value).len();
+
+			for (index, &value) in values[..len - rest].iter().enumerate() {
+				assert_eq!(value, expected.next_u32(), "mismatch at index {} of {} with {:?}", index, len, kernel);
+			}
+
+			assert_eq!(actual, expected);
+		}
+	}
+}
+
+#[test]
+fn test_fill_gaussian() {
+	for len in (0..20).chain([63, 64, 65, 127, 1000]) {
+		for pending in 0..2 {
+			let mut expected = Random::new(RAND_NEXTGAUSSIAN_SEED);
+
+			for _ in 0..pending {
+				expected.next_gaussian();
+			}
+
+			let mut actual = expected.clone();
+			let mut values = vec![0.0; len];
+			actual.fill_gaussian(&mut values);
+
+			for (index, &value) in values.iter().enumerate() {
+				assert_eq!(value.to_bits(), expected.next_gaussian().to_bits(), "mismatch at index {} of {}", index, len);
+			}
+
+			assert_eq!(actual, expected);
+
+			#[cfg(feature = "std")]
+			{
+				let mut fast = Random::new(RAND_NEXTGAUSSIAN_SEED);
+				let mut expected = fast.clone();
+
+				for _ in 0..pending {
+					fast.next_gaussian();
+					expected.next_gaussian();
+				}
+
+				fast.fill_gaussian_fast(&mut values);
+				expected.fill_gaussian(&mut vec![0.0; len]);
+
+				assert_eq!(fast.save().state(), expected.save().state());
+
+				for &value in &values {
+					assert!(value.is_finite());
+				}
+			}
+		}
+	}
+
+	let mut random = Random::new(RAND_NEXTGAUSSIAN_SEED);
+	let mut values = [0.0; 128];
+	random.fill_gaussian(&mut values);
+
+	for (i, &value) in RAND_NEXTGAUSSIAN.iter().enumerate() {
+		assert_eq!(values[i].to_bits(), value, "mismatch at index {}", i);
+	}
+}
+
+
+#[test]
+#[cfg(feature = "std")]
+fn test_cracker_text() {
+	use crack::{Cracker, Observation};
+	use Error;
+
+	let text = "# java-rand observations\nversion 1\nnextInt() = -1170105035\nnextInt(16) = 0\nnextBoolean() = true\n\
+		nextDouble() = 0.047939305137387644\nskip 2\n";
+
+	let cracker: Cracker = text.parse().unwrap();
+
+	assert_eq!(cracker.to_string(), text);
+	assert_eq!(cracker.observations()[1], Observation::NextI32Bound { bound: 16, value: 0 });
+	assert_eq!(cracker.solve().unwrap(), [Random::new(42)]);
+
+	let mut cracker = Cracker::new();
+	cracker.next_i64(i64::MIN).next_f32(0.1).next_f64(1e-300).next_i32_bound(1 << 30, 7).skip(0);
+
+	assert_eq!(cracker.to_string().parse::<Cracker>(), Ok(cracker.clone()));
+	assert_eq!("  version 1  # no observations".parse::<Cracker>(), Ok(Cracker::new()));
+	assert_eq!("version 2\n".parse::<Cracker>(), Err(Error::UnsupportedVersion));
+
+	for text in &["", "nextInt() = 1", "version 1\nnextInt() = 1.5", "version 1\nnextInt = 1", "version 1\nskip -1"] {
+		assert_eq!(text.parse::<Cracker>(), Err(Error::ParseError), "parsed {:?}", text);
+	}
+
+	// Bounds that nextInt(bound) rejects are refused when parsing, and never match when recorded directly
+	assert_eq!("nextInt(-2147483648) = 0".parse::<Observation>(), Err(Error::InvalidBound));
+	assert_eq!("version 1\nnextInt(0) = 0".parse::<Cracker>(), Err(Error::InvalidBound));
+
+	let mut cracker = Cracker::new();
+	cracker.next_i32_bound(i32::MIN, 0);
+
+	assert!(!cracker.matches(&Random::new(0)));
+	assert!(!Cracker::new().next_i32_bound(0, 0).matches(&Random::new(0)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_verifier() {
+	use crack::Cracker;
+	use verify::{self, Verifier};
+
+	let check = |mut random: Random| random.next_i32_bound(1000) == 7;
+	let expected: Vec<u64> = (0..50_000).filter(|&seed| check(Random::new(seed))).collect();
+
+	for &threads in &[1, 3, 8] {
+		for &batch_size in &[1, 7, 4096] {
+			let verifier = Verifier::new(check).threads(threads).batch_size(batch_size);
+
+			assert_eq!(verifier.verify(0..50_000), expected, "{} threads, batches of {}", threads, batch_size);
+			assert_eq!(verifier.clone().limit(5).verify(0..50_000), &expected[..5]);
+			assert_eq!(verifier.clone().limit(0).verify(0..50_000), []);
+		}
+	}
+
+	let mut cracker = Cracker::new();
+	cracker.next_i32_bound(1000, 7);
+	assert_eq!(verify::matching(&cracker).verify(0..50_000), expected);
+
+	let mut target = Random::new(123456789);
+	let mut cracker = Cracker::new();
+	cracker.next_i32(target.next_i32()).next_i64(target.next_i64());
+
+	let candidates = (123_000_000..124_000_000).rev();
+	assert_eq!(verify::matching(&cracker).limit(1).verify(candidates), [123456789]);
+}
+
+#[test]
+fn test_ext_samplers() {
+	use ext;
+
+	let mut random = Random::new(EXT_SAMPLES_SEED as u64);
+
+	for (i, &(log_normal, pareto, geometric)) in EXT_SAMPLES.iter().enumerate() {
+		assert_eq!(ext::next_log_normal(&mut random, 0.25, 0.75).to_bits(), log_normal, "mismatch at index {}", i);
+		assert_eq!(ext::next_pareto(&mut random, 1.5, 2.5).to_bits(), pareto, "mismatch at index {}", i);
+		assert_eq!(ext::next_geometric(&mut random, 0.05), geometric, "mismatch at index {}", i);
+	}
+
+	assert_eq!(random.next_i64(), EXT_SAMPLES_END);
+
+	// A pending gaussian is left alone
+	let mut random = Random::new(EXT_SAMPLES_SEED as u64);
+	random.next_gaussian();
+	let pending = random.clone().next_gaussian();
+
+	ext::next_log_normal(&mut random, 0.0, 1.0);
+	assert_eq!(random.next_gaussian(), pending);
+
+	// Exactly one nextDouble, even when the result does not depend on it
+	let mut random = Random::new(0);
+	assert_eq!(ext::next_geometric(&mut random, 1.0), 0);
+	let mut after = Random::new(0);
+	after.next_f64();
+	assert_eq!(random.next_i64(), after.next_i64());
+
+	assert_eq!(ext::next_geometric(&mut Random::new(1), 1e-300), i64::MAX);
+	assert!(ext::next_pareto(&mut Random::new(2), 1.5, 2.5) >= 1.5);
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_mc_structure_placements() {
+	use minecraft::{McVersion, SpreadType, Structure, StructurePlacement};
+
+	let village = Structure::Village.placement(McVersion::From1_18).unwrap();
+	let shipwreck = Structure::Shipwreck.placement(McVersion::From1_18).unwrap();
+
+	assert_eq!(village, StructurePlacement { spacing: 34, separation: 8, salt: 10387312, spread: SpreadType::Linear });
+	assert_eq!(Structure::Shipwreck.placement(McVersion::From1_13To1_17), Some(shipwreck));
+
+	// The first two placements of the test data are those of villages and shipwrecks
+	for (index, &(world_seed, chunk_x, chunk_z, placement, x, z)) in MC_RANDOM_SPREAD.iter().enumerate() {
+		let structure = match placement {
+			0 => village,
+			1 => shipwreck,
+			_ => continue
+		};
+
+		assert_eq!(structure.chunk(world_seed, chunk_x, chunk_z), (x, z), "mismatch at index {}", index);
+	}
+
+	// Before 1.13, the scattered features share a salt
+	let temple = Structure::DesertPyramid.placement(McVersion::UpTo1_12);
+	assert_eq!(Structure::SwampHut.placement(McVersion::UpTo1_12), temple);
+	assert_ne!(Structure::SwampHut.placement(McVersion::From1_13To1_17), temple);
+
+	assert_eq!(Structure::Shipwreck.placement(McVersion::UpTo1_12), None);
+	assert_eq!(Structure::AncientCity.placement(McVersion::From1_13To1_17), None);
+
+	for &version in &[McVersion::UpTo1_12, McVersion::From1_13To1_17, McVersion::From1_18] {
+		for structure in Structure::ALL.iter().filter_map(|structure| structure.placement(version)) {
+			assert!(structure.spacing > structure.separation);
+		}
+	}
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_mc_layer_random() {
+	use minecraft::{layer_seed, LayerRandom};
+
+	for (index, &(world_seed, salt, x, z, ref values)) in MC_LAYER.iter().enumerate() {
+		let mut random = LayerRandom::new(world_seed, salt);
+		assert_eq!(random, LayerRandom::from_layer_seed(layer_seed(world_seed, salt)));
+
+		random.set_position(x, z);
+
+		for (&bound, &value) in MC_LAYER_BOUNDS.iter().zip(values.iter()) {
+			assert_eq!(random.next_i32_bound(bound), value, "mismatch at index {}", index);
+		}
+
+		// Reseeding at the same position repeats the draws
+		random.set_position(x, z);
+		assert_eq!(random.choose(-1, -2), if values[0] == 0 { -1 } else { -2 });
+
+		random.set_position(x, z);
+		random.next_i32_bound(2);
+		random.next_i32_bound(3);
+		assert_eq!(random.choose4(10, 11, 12, 13), 10 + values[2]);
+	}
+}
+
+#[test]
+#[cfg(feature = "minecraft")]
+fn test_mc_hashed_seed() {
+	use minecraft::{hashed_seed, text_seed};
+
+	for (index, &(world_seed, hashed)) in MC_HASHED_SEEDS.iter().enumerate() {
+		assert_eq!(hashed_seed(world_seed), hashed, "mismatch at index {}", index);
+	}
+
+	assert_eq!(hashed_seed(text_seed("glacier").unwrap()), MC_HASHED_SEEDS[2].1);
+}
@@ -0,0 +1,6439 @@
+//! Golden values computed by the Java implementations, generated by GenerateTestData.java.
+//!
+//! Every table of draws from a seeded generator comes with a `_SEED` constant holding the seed, unless the seed is
+//! part of the rows. Floating point values are stored as their IEEE 754 bit patterns, as returned by `f32::to_bits`
+//! and `f64::to_bits`, so that they can be compared exactly. Tables are only added to and never change, except
+//! to fix values that did not match Java.
+
+pub const RAND_NEXTBYTES_SEED: u64 = 0;
+pub const RAND_NEXTBYTES: [u8; 128] = [
+	0x60, 0xB4, 0x20, 0xBB, 0x38, 0x51, 0xD9, 0xD4,
+	0x7A, 0xCB, 0x93, 0x3D, 0xBE, 0x70, 0x39, 0x9B,
+	0xF6, 0xC9, 0x2D, 0xA3, 0x3A, 0xF0, 0x1D, 0x4F,
+	0xB7, 0x70, 0xE9, 0x8C, 0x03, 0x25, 0xF4, 0x1D,
+	0x3E, 0xBA, 0xF8, 0x98, 0x6D, 0xA7, 0x12, 0xC8,
+	0x2B, 0xCD, 0x4D, 0x55, 0x4B, 0xF0, 0xB5, 0x40,
+	0x23, 0xC2, 0x9B, 0x62, 0x4D, 0xE9, 0xEF, 0x9C,
+	0x2F, 0x93, 0x1E, 0xFC, 0x58, 0x0F, 0x9A, 0xFB,
+	0x08, 0x1B, 0x12, 0xE1, 0x07, 0xB1, 0xE8, 0x05,
+	0xF2, 0xB4, 0xF5, 0xF0, 0xF1, 0xD0, 0x0C, 0x2D,
+	0x0F, 0x62, 0x63, 0x46, 0x70, 0x92, 0x1C, 0x50,
+	0x58, 0x67, 0xFF, 0x20, 0xF6, 0xA8, 0x33, 0x5E,
+	0x98, 0xAF, 0x87, 0x25, 0x38, 0x55, 0x86, 0xB4,
+	0x1F, 0xEF, 0xF2, 0x05, 0xB4, 0xE0, 0x5A, 0x00,
+	0x08, 0x23, 0xF7, 0x8B, 0x5F, 0x8F, 0x5C, 0x02,
+	0x43, 0x9C, 0xE8, 0xF6, 0x7A, 0x78, 0x1D, 0x90,
+];
+pub const RAND_NEXT32_SEED: u64 = 123;
+pub const RAND_NEXT32: [u32; 128] = [
+	0xB921F1DD, 0x3CBC0495, 0xFDAB8CD1, 0x4D33F0AA, 0x40D7D116, 0x92FA2632, 0x9BDA5745, 0x42460F3A,
+	0xCE4D76C3, 0x9F539C8A, 0xE01B0D5F, 0x8C9ED4FC, 0xB74EF490, 0x2986F7DA, 0x1269277B, 0xAE8B47EB,
+	0xCBD7C258, 0x4484F122, 0x9426CB04, 0x3E1AD100, 0xE87AEBB4, 0x9A2ECA2B, 0x261F4429, 0x3774C5D4,
+	0xF9A802D5, 0x754C29CB, 0x10CAE6D0, 0x16CA6888, 0x11CBEC93, 0x1F4BF868, 0x173B8ACB, 0x568E81E8,
+	0x6CA9D9C5, 0xEE9800BA, 0xDFD81749, 0xAD89F9CE, 0x933A01D3, 0x007B58D4, 0x6580D18E, 0x7FF711C4,
+	0x4220F288, 0xFB17D764, 0x271D62E6, 0xCD4325ED, 0x42AB892C, 0xC8E71939, 0x7630C9D6, 0x03B11824,
+	0x7068E8D1, 0x3C07DB05, 0x361EAA95, 0x7A14C965, 0x9D3D01ED, 0x2460AB64, 0x0890B2BA, 0x7082456B,
+	0xB05B7F29, 0x1CAD391C, 0xAF220436, 0xD951849B, 0x13442B77, 0x281BC1A6, 0xF261DB83, 0x6681E820,
+	0x6FD7256A, 0xF07463ED, 0xB19DE51D, 0x6A96D456, 0xAD2873C1, 0xD41C5B54, 0xA0F7F631, 0xA9B90D6B,
+	0x931FCF44, 0x07E366FE, 0x63521C94, 0xC67EBA43, 0x306AB5D3, 0x6AA9E148, 0x06D739F6, 0x2F76319C,
+	0x08141ABB, 0x9CD24B9A, 0xD4959E56, 0x65ADF573, 0xBD76484B, 0xD359525F, 0xF10455B8, 0xD33680A5,
+	0xD370C6A7, 0x529B8D51, 0x83A31D16, 0xC940CAD8, 0x98155BDB, 0x9F807FF9, 0x3A703293, 0x5A46A69B,
+	0x4D9C7932, 0x2B369E76, 0xB1EE74F6, 0xBBD85DCB, 0x6DA2A9C8, 0x6E245A7C, 0xF7298655, 0x6F7D3038,
+	0xB9B5D65E, 0x3E6E7C9F, 0x55CCE610, 0x48CA9889, 0xF552726D, 0x5DB44DD1, 0x7C3E3601, 0x4AB07B5C,
+	0x3EDDE67D, 0x34A65529, 0xB1FA1EF1, 0x7536A4B1, 0x382CCBB6, 0xF224ADE6, 0x1C76884D, 0xC9CA3788,
+	0x90F8CCB7, 0x82DE39B2, 0x83C41718, 0xBCFFF3C7, 0x27933FA6, 0x563A332A, 0x8D542823, 0xDB9A7661,
+];
+
+pub const RAND_NEXT64_SEED: u64 = 246;
+pub const RAND_NEXT64: [u64; 128] = [
+	0xBC2EC2F174B9BA7A, 0x7105C369C188DAB7, 0x675B3ED6DBC9DF76, 0x90C83A7E3100BDC5, 0x915F0D0009C13A6D, 0x613F705DDAC80A80, 0xC60228F178DAE48D, 0x0DC22D3BB4876431,
+	0xC02820BB2E5E2B17, 0x2207E420DD6975B2, 0xA71F07A0AA6B372D, 0x1284A032F71A0F67, 0x5D186EFD2B49DEF0, 0xCB40023A4CC4B06D, 0x5CBE8962A0ABF3CA, 0xE2E0125F9FD5EF14,
+	0x8E55DC05F095A207, 0x2BE8EA386686748B, 0xE8FE2259FFB3B5AA, 0x0EBE7B01756D7FD9, 0x98AB47FAA310D87E, 0xDC05F4298EC42414, 0xD7999CCBE6981D6A, 0x64D88E18B6E1B5CC,
+	0x308EA7632BE46D09, 0xE511BD26B90D1BC7, 0x986A39A3A4C1A77A, 0x381F10EF3145F6F3, 0xBFA1ABAABDBD8F74, 0x7A5843C82263D598, 0x517960EEAC73D29C, 0x744A3E8F7B18E5CC,
+	0x1D6EFBA29421C31A, 0x52C814A88348DB2C, 0x9188E25F76C39669, 0x5A413C4684FBFBC8, 0x9065FBFF6AC63D6E, 0x160C86EBDC41885F, 0xC632C5CA9A7629CC, 0x614B9E2B1ED3F3CB,
+	0xCA5727D84C049476, 0xF70308B522849DBE, 0xDB02ABAD4C821985, 0xA63EFB93279C04AE, 0xF9F2F9285EC6BD4A, 0x05DD7BAF5DB6A30D, 0xF12ABDA1DE2CAEA7, 0x861A9397DD75EBBD,
+	0xF90D61514D845A9A, 0xFC83A1853AE019C3, 0xB1B62EED91FDA51A, 0x7DC3019A2AC0C73A, 0x15A7D196B92B5B26, 0x48059869B4077F89, 0x565B4CF64E03321A, 0x2CD001BBDC40C0D8,
+	0xF203D3A0EC0DE843, 0xDA3167922F1920BF, 0x274F1FC347205AB6, 0x659E456663A18840, 0x7E4031FBAA2FA45D, 0xDE9D9BB66206BAF7, 0xD6BC9A85BE5F9A52, 0x891757C328DD9D8F,
+	0x1C42B0978D8C396F, 0xC6BAF3945D48EF78, 0xD4C15C0E4E83D925, 0x08E1924174546BD6, 0x94F05548D631378C, 0x40BF1C6D592C85BC, 0x7111FF531551B2BC, 0xCEEA2112AA993399,
+	0xA3F64046F745435A, 0x957A7E8645957DF2, 0x61A7FBEF394B0C75, 0x757B17B1EA44C551, 0x85A514AE73569492, 0x996B19A6A721F37D, 0x101B169FE9D6FC05, 0xAF4F95581B540BEA,
+	0x20A0F0FECB8AC482, 0xD59E719EFFD6CF72, 0xE18861C7FD0FFDF3, 0xFB57F98326DD6745, 0x1B67F79E5E89EC8B, 0xCD348ABCDCC04B1E, 0x8728CDECBBB27C1A, 0xC42028785D26D6B7,
+	0x6BF16751153F14A2, 0x4C95F658A530427F, 0x47F94A3940E5A42B, 0x5709DFBE0766F389, 0xB5E743C6A4C7F1D3, 0x7FAEEF49738456C6, 0x1E1674FA10D88E28, 0xB3CD1A9EC1BABB76,
+	0x1A3C8E0EFE2DF4B9, 0x24C1866B929DE0DB, 0x74ADDC232E6DB2EA, 0x10F086A9811A2B30, 0xF1220D1D3DC2A808, 0x91A0DF22B675B3D8, 0x4DA6CDCA056EB29D, 0x162C08341855A8DA,
+	0x459BA6480D395F04, 0x69677BD1A975AF8D, 0x9864B8A7F4026B3F, 0x39E74ED1A6663E8F, 0xD03845CEF4D83407, 0xD0FA9A63F38222FB, 0x8D451C9C1B5F5F24, 0x634679DF979AA4DA,
+	0x9CAE574E895A56FF, 0xA8ECA0C4F2DCFED9, 0xF3BB0B2864F46B74, 0xEE76CCFCCA6B1D3C, 0x7C5ECE4DBD5DABED, 0xD3909964FA4CD813, 0x532A37F25681CEAC, 0x752D748CF7FE1CAA,
+	0xE600BEB3DE75B967, 0xD650BFBB4B31BA45, 0x30E3994564DC7F19, 0x498A5A35B7A7C60C, 0xE8F7854CEF3DE716, 0x359A1CC02D71CA44, 0xA7E5988DF255D160, 0x65D1096353EE34BF,
+];
+pub const RAND_NEXT32_BOUND_65536_SEED: u64 = 369;
+pub const RAND_NEXT32_BOUND_65536: [u32; 128] = [
+	0x0000BF24, 0x0000C034, 0x000091FD, 0x00006A2F, 0x0000D5DA, 0x00000D3C, 0x0000A6C4, 0x00004A98,
+	0x0000CF89, 0x00002BB0, 0x00009370, 0x0000C280, 0x0000251B, 0x0000F7D7, 0x0000DB0C, 0x00000379,
+	0x0000FA1D, 0x0000A564, 0x0000ABA4, 0x0000BB4B, 0x0000E2EA, 0x0000221B, 0x0000DEC8, 0x0000E11A,
+	0x00005787, 0x0000890A, 0x0000748D, 0x0000F689, 0x0000EE94, 0x000040CD, 0x0000E7E9, 0x0000FE02,
+	0x0000E8BD, 0x00001AEF, 0x00009855, 0x000006B3, 0x00001D61, 0x00006E95, 0x00002C28, 0x0000071D,
+	0x0000C056, 0x0000574F, 0x0000D68E, 0x0000DCBC, 0x00007586, 0x00008081, 0x00007C71, 0x00003A56,
+	0x0000A5A1, 0x0000E091, 0x0000CA6D, 0x00006B8A, 0x0000658A, 0x00008B23, 0x0000FC61, 0x000072FA,
+	0x000094B3, 0x0000016B, 0x0000C255, 0x0000C5A7, 0x000023F2, 0x0000B953, 0x0000CFA4, 0x0000E1D5,
+	0x0000F9C6, 0x0000B1EC, 0x00000632, 0x00001E1F, 0x0000522B, 0x0000F3CD, 0x00003850, 0x00003701,
+	0x000029AA, 0x000090F1, 0x00005F4F, 0x000081B9, 0x00009511, 0x0000BF32, 0x00002A97, 0x0000F3BF,
+	0x0000641D, 0x0000CD86, 0x000091AA, 0x0000D1FA, 0x0000148A, 0x00002430, 0x0000CB70, 0x000052F8,
+	0x0000A043, 0x00000E30, 0x0000BBFE, 0x000087B1, 0x0000BB0F, 0x00006BCD, 0x0000A8FF, 0x0000B72A,
+	0x00007E9D, 0x00007E2F, 0x00004101, 0x000006ED, 0x00009D1F, 0x0000F821, 0x0000BCF1, 0x0000D4D6,
+	0x0000FF32, 0x00004B29, 0x0000F183, 0x0000DA99, 0x000027F6, 0x000087EF, 0x0000720E, 0x0000E0C6,
+	0x000005CA, 0x000007CE, 0x0000AC81, 0x00000230, 0x000095FC, 0x0000C1F9, 0x0000F7A4, 0x0000E1BF,
+	0x00004972, 0x00009A6D, 0x00008F46, 0x00009839, 0x0000AED3, 0x0000FAF7, 0x000026AA, 0x0000E55E,
+];
+pub const RAND_NEXT32_BOUND_999999999_SEED: u64 = 369;
+pub const RAND_NEXT32_BOUND_999999999: [u32; 128] = [
+	0x23F74229, 0x247F8570, 0x0D63B8EC, 0x3517F409, 0x2F529259, 0x069E5DCA, 0x17C74738, 0x254C77DD,
+	0x2C29C6CF, 0x15D82E1D, 0x0E1D503E, 0x25A56591, 0x128DE1D7, 0x31EBB28A, 0x01BCFF53, 0x1717A06F,
+	0x1A37860D, 0x220B25C6, 0x35DA72AB, 0x110D9E68, 0x33C947EA, 0x34F284FE, 0x2BC3B329, 0x08EA3CD7,
+	0x3A4682DC, 0x2066B070, 0x3859F848, 0x38C3CC91, 0x0D77DCD5, 0x109005DC, 0x03599497, 0x0EB0F2C1,
+	0x374AD7BC, 0x16143DDF, 0x038EFB16, 0x24903953, 0x2BA7AD43, 0x2FACB421, 0x32C36E9B, 0x3AC326D6,
+	0x04A5E964, 0x029E0976, 0x1D2B141B, 0x17362E4C, 0x34AE2E86, 0x299BC7EF, 0x35C53C94, 0x32C5445A,
+	0x09F6FF2E, 0x397D1303, 0x0EBF0E2D, 0x00B59ED5, 0x258FBDF1, 0x2739088E, 0x11F923E6, 0x210F1FD6,
+	0x2C37A9E3, 0x35500EC0, 0x1D5B7E7E, 0x0319387C, 0x0F0FEFF0, 0x2915DACD, 0x1C283E34, 0x1B809DC2,
+	0x14D50638, 0x0CDDCC1D, 0x2FA7E2D2, 0x0541CDC8, 0x0EEE0E5C, 0x23FE8CDD, 0x154BF7FC, 0x320E804B,
+	0x2B284EE0, 0x0D3A965D, 0x2D626301, 0x0A456B16, 0x121831AB, 0x2A1D4B71, 0x297C5A6F, 0x1486BD9A,
+	0x071812C8, 0x2264B5EE, 0x083E02AB, 0x21ECE400, 0x35E6F4B3, 0x18E4E2D2, 0x1FFA76C9, 0x03B3FAD7,
+	0x037D34EC, 0x20808904, 0x0376B038, 0x12F513D0, 0x22DE23AB, 0x2ED03E47, 0x2594E1AB, 0x31B1CDBD,
+	0x13FB163C, 0x085CF2B1, 0x39074010, 0x34C8AAA6, 0x02E525F6, 0x03E76101, 0x1AA6328B, 0x01183C2D,
+	0x0F63AD34, 0x2561F7A6, 0x3544E4A5, 0x24B91906, 0x119BDAB6, 0x0C085489, 0x1081FFA2, 0x1BCEE81E,
+	0x13552807, 0x37145844, 0x03D33ADE, 0x10A934A3, 0x0E95B7A6, 0x02A29794, 0x03F5FF1C, 0x2827362A,
+	0x0ED7F1B4, 0x2027E505, 0x1FCFF777, 0x196B5AF6, 0x023CC975, 0x2C8DA51D, 0x2D6BB04D, 0x08BDDC24,
+];
+
+pub const RAND_NEXTBOOL_SEED: u64 = 492;
+pub const RAND_NEXTBOOL: [bool; 128] = [
+	true, true, false, true, false, true, true, true,
+	false, false, true, true, true, true, false, false,
+	false, true, false, false, true, false, true, true,
+	false, false, true, true, false, true, false, true,
+	true, false, true, true, false, true, false, true,
+	true, false, true, false, false, false, true, false,
+	false, true, false, false, true, false, true, false,
+	true, true, false, true, false, true, false, true,
+	false, false, false, false, false, false, false, true,
+	false, false, true, false, true, true, true, false,
+	true, false, false, false, false, true, false, false,
+	true, false, true, true, true, false, true, true,
+	true, false, false, true, false, false, false, false,
+	true, false, true, false, false, true, true, false,
+	true, true, false, true, true, false, true, true,
+	false, false, true, true, true, false, false, true,
+];
+
+pub const RAND_NEXTF32_SEED: u64 = 615;
+// f32 bit representation
+pub const RAND_NEXTF32: [u32; 128] = [
+	0x3F2D1DA4, 0x3E572B3C, 0x3F55089A, 0x3D99E008, 0x3DB69008, 0x3F1E74FB, 0x3F0606C0, 0x3E468138,
+	0x3F4BD621, 0x3F069A1D, 0x3EF2E17E, 0x3E036F00, 0x3F5BB556, 0x3F0CE5B3, 0x3F012184, 0x3C95BB40,
+	0x3EDE9866, 0x3F02C529, 0x3ECA5640, 0x3E877166, 0x3F739BD0, 0x3F0A55E4, 0x3F34CD84, 0x3F642915,
+	0x3E77A4EC, 0x3E9BA0C4, 0x3E928D52, 0x3EAE9974, 0x3EB074D2, 0x3F5C4927, 0x3EEBBF2E, 0x3CF4E480,
+	0x3EE90666, 0x3F15E88E, 0x3EDDBA0C, 0x3F7B379B, 0x3EFDD474, 0x3E111AAC, 0x3F58317D, 0x3EE35290,
+	0x3E8B6D94, 0x3E8551A2, 0x3F483A30, 0x3F2E508F, 0x3F5CF600, 0x3EB364FC, 0x3ED35E1E, 0x3F1666F8,
+	0x3CBEDB20, 0x3F72F3AE, 0x3D581B80, 0x3F172969, 0x3D4A1F40, 0x3EADB5BA, 0x3E03B8E4, 0x3ED72508,
+	0x3F67AB1C, 0x3EA66260, 0x3F08BBEC, 0x3B254300, 0x3F71E7F2, 0x3CB573A0, 0x3E5F6F0C, 0x3EDFB4AA,
+	0x3EB7F08A, 0x3EDB0812, 0x3D074CD0, 0x3C612F40, 0x3EC643E0, 0x3F14B99C, 0x3EE48DD2, 0x3F0F28B1,
+	0x3ECC16A8, 0x3F75C7DC, 0x3ED6AD94, 0x3EA0139E, 0x3ECE3980, 0x3F419848, 0x3F3F55CD, 0x3F26E413,
+	0x3EA0049E, 0x3E6DA9FC, 0x3EB4D6B2, 0x3F0D152C, 0x3D74D2C0, 0x3E46ACC0, 0x3E70B2AC, 0x3F53B218,
+	0x3E6730D4, 0x3F5B725C, 0x3D975AB8, 0x3E98BE5A, 0x3EA442B6, 0x3CDCB5A0, 0x3EBAA3C8, 0x3F207EF0,
+	0x3F6B9A58, 0x3F0543DF, 0x3F13C93A, 0x3E16B960, 0x3D6A8860, 0x3EB45524, 0x3ED731B8, 0x3F24CB6F,
+	0x3E3AF3C8, 0x3E13DFBC, 0x3DF2F8B0, 0x3E14B5A8, 0x3F100AFE, 0x3D13DF60, 0x3F109DA1, 0x3DF41CF8,
+	0x3F31051B, 0x3F0E557B, 0x3F3CEA6A, 0x3EB685FA, 0x3EF9190C, 0x3EA4F606, 0x3ECC36D2, 0x3F19DFEC,
+	0x3E001808, 0x3EA78034, 0x3ED97F96, 0x3CD19680, 0x3DC897B0, 0x3D4BFDC0, 0x3EB54FB0, 0x3F4812DA,
+];
+
+pub const RAND_NEXTF64_SEED: u64 = 738;
+// f64 bit representation
+pub const RAND_NEXTF64: [u64; 128] = [
+	0x3FE6054EC36E442F, 0x3FD218B458790EBE, 0x3FCEAAB7DCE89694, 0x3FDEBD290205AFCC, 0x3FE1DCF6EF883DD8, 0x3FEF52A4437827B1, 0x3FED4D1156E1CD01, 0x3FDF1EA280AA9F6A,
+	0x3FD8E72476C9E63A, 0x3FEE61873F183AC4, 0x3FE647FD8CD4928F, 0x3FE4265C1D1E72F8, 0x3FE42B35001E70B9, 0x3F8DDE231A8E05C0, 0x3FE465A0D2ED4916, 0x3FD06107B50EE91C,
+	0x3FE2C5E6ACBF3181, 0x3FE75DBB2DA1A0AE, 0x3FEA95CB591BF843, 0x3FE02DE4D338FDB8, 0x3FE38823FF550E91, 0x3FDF48B056FD18D8, 0x3FDC7904F7763824, 0x3FD615B4F499795A,
+	0x3FE8C392FF168207, 0x3FE78E9956B10DE2, 0x3F9F3CB0D73BD980, 0x3FD41F25F2C5635A, 0x3FEEDE292FA20C32, 0x3FD4FC8B149B7948, 0x3FC80E94314075D0, 0x3FE73884C4238A98,
+	0x3FA320368898B470, 0x3FE533DF98E1B620, 0x3FD1E097A3760D7E, 0x3FC5C817ED4D73F0, 0x3FD8D460658AAB2E, 0x3FBE1134D9732770, 0x3FEF9C9A078B2487, 0x3FB9CA3225907580,
+	0x3FB2455C3AA73208, 0x3FDF3630F49EBD4A, 0x3FC66CC7F55A7EF0, 0x3FEE2CEA2940BCE1, 0x3FD8139A0E79D8CC, 0x3FE2A4B6C706A830, 0x3FE566D7AA2C8EDE, 0x3FE51F88A91D71AE,
+	0x3FE2E1680D3C8CDE, 0x3FEBCBCCED25B0A3, 0x3FD4AF02D7E03DD6, 0x3FEE464AF3007834, 0x3FE155DDC4FDA66D, 0x3FB097C8641A9468, 0x3FEE227B1FCC66D7, 0x3FD04BDB5B013E4E,
+	0x3FD90AC2345BD0E8, 0x3FECA43668A92BCB, 0x3FDAEBB69A776AFE, 0x3FE5E864C99DB9E6, 0x3FAA9ACEBD88C270, 0x3FE8F329FD5C9BE0, 0x3FE9078A2BA72A1B, 0x3FD59AC1F1556014,
+	0x3FE2766C05828041, 0x3FBC3387C30DC620, 0x3FE3381AA86D6735, 0x3FC1BFDCCE29AE04, 0x3FEA36933FF4D736, 0x3FEC863190508A3E, 0x3FE631B57829301B, 0x3FD14754AC99EA3A,
+	0x3FE5D4ED7F4B47EC, 0x3FEEB62D3D0EDBB2, 0x3FEB3356425AC0D3, 0x3FD3662D53281F56, 0x3FD48833858D21D2, 0x3FE60995707AF478, 0x3FE9809A7A20B092, 0x3FEAD27F82C0A184,
+	0x3FE7023B5D0C8E09, 0x3FB43711E86C3580, 0x3FE23662A1772EAC, 0x3FEEA07EBCC43CA3, 0x3FC6F26F23A742D8, 0x3FD973D56966492E, 0x3FEE0C85567E6A08, 0x3FB4B8B2AC60A9D0,
+	0x3FD8BEFDCEA51528, 0x3FB3D3FD1EBC62B0, 0x3FE2D4D42B92F318, 0x3FEC5763FF54B6C3, 0x3FEC2668EF4A9ABF, 0x3FDAAB90072E34BC, 0x3FD09F660719D096, 0x3FE8C23699CFC796,
+	0x3FEE891697868D74, 0x3FE7709F9244B234, 0x3FCADA03CD12F3FC, 0x3F9AF8C09BE55FA0, 0x3FD052CE1802BC9E, 0x3F804DDEBB1B7300, 0x3FED241350866AF6, 0x3FC58331170B4720,
+	0x3FE22E78276CCCF8, 0x3FE80638C3356356, 0x3FB65C17272EF270, 0x3FD0D682EFE112BE, 0x3FE3C134494C145C, 0x3FE769A8E2FED198, 0x3FEBB58AC0716BED, 0x3FEAACF92467195A,
+	0x3FEFA692ADB2D45C, 0x3FE045D06A4F02BE, 0x3F909734A1244FC0, 0x3FEAE661FD27FF90, 0x3FE53BC9230BFE05, 0x3FC4298DC290D6CC, 0x3F911695E578BBC0, 0x3FE667A7369D1AAF,
+	0x3F882C1D3DA490C0, 0x3FDB905898E3BBDA, 0x3FEE30CCAC544512, 0x3FE3E2744E099350, 0x3FE1249A39300313, 0x3FE47177EA8E89CA, 0x3FEFB625218C7ABF, 0x3FE4BA9A6ABF5387,
+];
+
+pub const RAND_NEXTGAUSSIAN_SEED: u64 = 861;
+// f64 bit representation
+pub const RAND_NEXTGAUSSIAN: [u64; 128] = [
+	0xC0039AB75CA861B2, 0x3FF0A128EB5ABF0A, 0x3FDB13FD76232BDB, 0x3FC0F1668AFC83CF, 0x3FECA9A2117D247C, 0x3FE7BB91086162E2, 0xBFF08B2A1E9D184B, 0x400057C72BB2A478,
+	0xBFD559278401E43C, 0xBFA83C05420E2012, 0x3FD22049D0BDA595, 0xBFCAC4AD09AF7851, 0x3FD01BC8CD912CDD, 0xBF82D60DB212A32E, 0xBFCA7F5616D8F4B1, 0xBFCC70BD6918CB63,
+	0xBFEF57F2A5C38006, 0xBFE8330CAEE3F9A5, 0x3FDC58E149F2D6A8, 0xBFE1477F382795D6, 0x3FC04B37EEDD3AE8, 0x3FE6C9217AE0E6A2, 0xBFFA75BF52613713, 0xBFDE7A95BA9B0FB3,
+	0x3FEA82574088267E, 0x3FF8064554433287, 0xBFEB06A2199AB889, 0xBFF7563591723D2E, 0xBFD331E6DE36D26E, 0x3FF661857F043EAC, 0xBFE50249E5AAA9FA, 0x3FD5979996A9ABEF,
+	0xBFCB93D734706355, 0xBFA15B3B3DFA8599, 0x3FDF06401F924518, 0xBFC7746BB1E6120A, 0x3FE6B80188ACF15A, 0x4003F5E0C2DB1E25, 0xBFB8DF755D5E9FD0, 0x3FA24A847AE6263C,
+	0xBFF34397035CB2CA, 0xBFF0551025253591, 0xBFCF894C6BF1B1D8, 0xBFA38957B844E1C3, 0xBFD542846C9CC4AF, 0x3FDF5009C8B2CF1A, 0xBFF1F9FE38B009B7, 0xC0016C9604181944,
+	0xBFD2907224F4A142, 0x3FE8192836FD40EA, 0x3FDD69099C292493, 0x3FCD8242AC805431, 0x400B6F5455241087, 0x3FE0732A017A2EDB, 0xBFF2EEEDA49CA725, 0xBFD6D34872798EC6,
+	0xC0067E29ADA96928, 0x4005DBE8D72083B1, 0xBFF3AFE4E16AC100, 0x3FD91ECB81A4CF26, 0xBFF3DB57E6C18616, 0xBFEA9CC2FEEAFB8D, 0xBFB85F8E90BFF4DC, 0x3FF25A02E52F9867,
+	0xBFD326948A8EA34B, 0x3FE4BD4C6103B37E, 0x3FE7C45763ADB6CA, 0x3FE31393F685B17F, 0xBFECDAE974A98088, 0xBFEFA907A71A47D8, 0x3FF0B20967257753, 0x3FD3BD0FDFB50CCD,
+	0xBFD4E4DBD53ABF02, 0x4005BF1CB921B694, 0x40084156310CF7C1, 0xBFCDCA54C44D4FE3, 0x3FE086E476E14943, 0x3FE410D4894ED3E2, 0xBFC0620D5F25F298, 0xBFF9983586BAEBB5,
+	0x3FE488C783BFD611, 0xBFF3D3A4FD82A440, 0xBFED7048B6BDB7F2, 0xBFD0CB45AB863C6B, 0x3FB93CD335776F76, 0xBFC8DDC4F4A28B8D, 0x3FEDA439CD081EB6, 0x3FD776A9F1D5FB3D,
+	0x3FFAC1F6546CFB81, 0x3FDFC8272B75AA9C, 0xBFE7F793B41A0735, 0x3FF091C07EB78B95, 0x3FD0E6ADCBF5697F, 0x3FAB3DABE4976628, 0xBFE05F96E5E11B65, 0xBFFAF95F266D66AC,
+	0x3FE9B5626AE73177, 0x3FD1678E21787EE7, 0x3FF234892168A787, 0xBFA243250FF101B4, 0xBFF5BE67F23E019F, 0xBFF27B194FB7D624, 0x3FFB18F6857DD1BF, 0x4000A06227269A57,
+	0xC006C374435EDB2F, 0x3FDC18141F32D0B8, 0x400030989211C758, 0xBFF0DF31952AF1DC, 0x3FE3C860B41A9FB1, 0xBFE261F329BA44EA, 0x3FFB4A04EA10A188, 0x3FEF97EB7F12DBA0,
+	0xBFE055212704FF5B, 0xBFB58236FB615FDA, 0xBFC3FAA9121B000A, 0x3FD78041D8808A6E, 0x3FF0274A396438F8, 0x3FFA1DA31B7D7EC9, 0x3FE74FAD0F07E910, 0x3FF2AAD0CC1CA255,
+	0xBFE89A03F957D34B, 0x3FE6C67B4CF2CA53, 0xBFD9B13BAD9F01BA, 0x3FEA77CAFBEF90AF, 0xBFF4E27A16E01558, 0x3FB4155DA4F72F44, 0xBFC326668AC17059, 0x3FCE12CEAEF10428,
+];
+pub const RAND_SERIALIZED_SEED: u64 = 1107;
+// State after a single nextGaussian call, with the second gaussian pending
+pub const RAND_SERIALIZED: [u8; 104] = [
+	0xAC, 0xED, 0x00, 0x05, 0x73, 0x72, 0x00, 0x10,
+	0x6A, 0x61, 0x76, 0x61, 0x2E, 0x75, 0x74, 0x69,
+	0x6C, 0x2E, 0x52, 0x61, 0x6E, 0x64, 0x6F, 0x6D,
+	0x36, 0x32, 0x96, 0x34, 0x4B, 0xF0, 0x0A, 0x53,
+	0x03, 0x00, 0x03, 0x5A, 0x00, 0x14, 0x68, 0x61,
+	0x76, 0x65, 0x4E, 0x65, 0x78, 0x74, 0x4E, 0x65,
+	0x78, 0x74, 0x47, 0x61, 0x75, 0x73, 0x73, 0x69,
+	0x61, 0x6E, 0x44, 0x00, 0x10, 0x6E, 0x65, 0x78,
+	0x74, 0x4E, 0x65, 0x78, 0x74, 0x47, 0x61, 0x75,
+	0x73, 0x73, 0x69, 0x61, 0x6E, 0x4A, 0x00, 0x04,
+	0x73, 0x65, 0x65, 0x64, 0x78, 0x70, 0x01, 0x3F,
+	0xF1, 0xA6, 0x63, 0x05, 0x28, 0x2C, 0x9C, 0x00,
+	0x00, 0x94, 0x21, 0xDE, 0x1C, 0xD5, 0x22, 0x78,
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_LOG: [(u64, u64); 256] = [
+	(0xA4E206BDCAEDDD51, 0xFFF8000000000000), (0xB2D780ACAB07E29E, 0xFFF8000000000000),
+	(0xD36F3FA3ADA67323, 0xFFF8000000000000), (0x5CB0E823B8C92076, 0x4073EE71FDD1C68D),
+	(0xB3AC6EA71C421C5F, 0xFFF8000000000000), (0x0B87CB0B36C8E906, 0xC082293ABA70064A),
+	(0x8B9BB98AC25215CF, 0xFFF8000000000000), (0x5BA4B637191897C8, 0x4073352833908ACE),
+	(0xD9E8AE0741731D98, 0xFFF8000000000000), (0xE63378FBF407124F, 0xFFF8000000000000),
+	(0xD42622ED4F1CA789, 0xFFF8000000000000), (0x30EFE4F1FDA886BE, 0xC064CB79E883392C),
+	(0x2DA6878D9E3E82B7, 0xC06957FF12B906B2), (0xC57845FC2F771D7E, 0xFFF8000000000000),
+	(0xB27F455B249AC449, 0xFFF8000000000000), (0xD500D6865AE0922B, 0xFFF8000000000000),
+	(0xD82E17CEFC530B8F, 0xFFF8000000000000), (0x4713B40CC85FE7CE, 0x4053CE86CA9883AC),
+	(0xCB617E8BC9FD7359, 0xFFF8000000000000), (0x52BD17E47701AC51, 0x406A1158AC5E1B01),
+	(0x16D3ADC3D006C486, 0xC07C7E246D2E75AD), (0xF13BD833ED247F2F, 0xFFF8000000000000),
+	(0xC439A0DA266CBDDB, 0xFFF8000000000000), (0x044A75435A2ECF6A, 0xC084AB9EE512863D),
+	(0xB43E334873348515, 0xFFF8000000000000), (0xE09536F9570E04C2, 0xFFF8000000000000),
+	(0xE79B63C1D9AE9540, 0xFFF8000000000000), (0xC3361EA01FE28823, 0xFFF8000000000000),
+	(0xFFE2259C15DABE49, 0xFFF8000000000000), (0x4757B0EA9F7F5F5E, 0x40548BC4A4D810DC),
+	(0x6CA36468235409D2, 0x407EFCAE2654D852), (0x33ABDC38FC9B501C, 0xC060FFDA2C7F8254),
+	(0x7FB31DE2BD559945, 0x408619813A84520C), (0x6E747F19A3FCBE0E, 0x40801F97E4E132EF),
+	(0x1B68B61024E6D12B, 0xC07950E726BB42D8), (0xD6AF531099C8B869, 0xFFF8000000000000),
+	(0x564DE9A52C4E65BE, 0x406F02891C3B2B6E), (0x71CF3F9B71B0EA91, 0x408148DC89AB7D34),
+	(0x6AE002A5A0D11CBC, 0x407DC3158A898AA7), (0x5B89596FE1436414, 0x407322358A7BFDB9),
+	(0x9E1D334FF6BA587D, 0xFFF8000000000000), (0x40DC1207DC5A6BD9, 0x40248849DF56D0B2),
+	(0x9ED23B3714F9C047, 0xFFF8000000000000), (0xBCDB5B6070D9D298, 0xFFF8000000000000),
+	(0xC83ABAB2568299E1, 0xFFF8000000000000), (0x8534C3FCB7CA788C, 0xFFF8000000000000),
+	(0xDEC8D876354EA866, 0xFFF8000000000000), (0x120ACF30B5BBFA67, 0xC07FCF26FA7883ED),
+	(0x640F1D7F8EAFA45A, 0x407909C6C9465683), (0xF0F70D9BA65FB986, 0xFFF8000000000000),
+	(0xB911AB3857B7A102, 0xFFF8000000000000), (0xA1F6B9F51ECFB366, 0xFFF8000000000000),
+	(0x92F22FD2CC31F2EF, 0xFFF8000000000000), (0x1EFDDE099226DC55, 0xC076D5B8764DD178),
+	(0x073C3CF8582B6BDC, 0xC083A679F795531F), (0xB0294404EC37F16C, 0xFFF8000000000000),
+	(0xD14DE4A67CA1FFB9, 0xFFF8000000000000), (0x9F47B7F0318AF5BC, 0xFFF8000000000000),
+	(0xB3B2C053D8E12707, 0xFFF8000000000000), (0xB1F75D79E198CEC6, 0xFFF8000000000000),
+	(0xAD05C65904919266, 0xFFF8000000000000), (0x021C1DFA6BBFB430, 0xC0856D37218CC718),
+	(0x7250918D87D8036B, 0x408175B13F12E347), (0x154072B387E266B6, 0xC07D96453F397470),
+	(0x7724FF3098485EB7, 0x40832290E1EF4414), (0xB4C20F5B0C4F625E, 0xFFF8000000000000),
+	(0x1818470C402F4B44, 0xC07B9CF99AD10BF3), (0x86C030D6BAAC7FEF, 0xFFF8000000000000),
+	(0xFD14F1D4BB05F302, 0xFFF8000000000000), (0x4AD99447F876B9A3, 0x405E44EA4F41A4AB),
+	(0xE06B51D87308B8A2, 0xFFF8000000000000), (0xB2245A48F46D071B, 0xFFF8000000000000),
+	(0xDBDE61D29222F6B0, 0xFFF8000000000000), (0x5FAA5A7804388492, 0x4075FECB47A885D8),
+	(0x98652E2040EF1BE7, 0xFFF8000000000000), (0xCA58AB362C135955, 0xFFF8000000000000),
+	(0x0E372C18CAABB8C0, 0xC0813AFF914DC9DA), (0x92F961AA98531172, 0xFFF8000000000000),
+	(0x5B3C23B711FB524C, 0x4072EC6D9873CA53), (0xC4BACE0C1DD25EAF, 0xFFF8000000000000),
+	(0xEC5E8F7C494E0E43, 0xFFF8000000000000), (0x4537B81F958174BE, 0x404D4F1C9C6949BD),
+	(0x44C9969248318C5B, 0x404AEBC2897CF2BA), (0x16285FFE882374AE, 0xC07CF4B640D41B03),
+	(0xF30A05343DB480DE, 0xFFF8000000000000), (0x9F1C471D44B29BA3, 0xFFF8000000000000),
+	(0x419BE74CDCD0E476, 0x403293F732BE7EFF), (0xED1905863D4C28C9, 0xFFF8000000000000),
+	(0x4CE4A95E7BA8CD4B, 0x4061F796C4F2DD44), (0x20FAAF69AF202E85, 0xC07574A1B6C352FD),
+	(0xEE33E3A4568F454F, 0xFFF8000000000000), (0x58368EDBE8F0C2ED, 0x4070D48DC22E7822),
+	(0x486333792D97B1D8, 0x40577076BA35D6A6), (0x6E6935D66C6F437A, 0x40801BB44368BC48),
+	(0x494FC6F98BFBA06E, 0x4059FDC40FCCD7CE), (0x85252C80107D9FFC, 0xFFF8000000000000),
+	(0x99EE7EE90D500CE6, 0xFFF8000000000000), (0x4B07BFB0D044E0CD, 0x405EC53F634DC685),
+	(0xD765CAD5128074AF, 0xFFF8000000000000), (0x476DB3B524715C49, 0x4054C699D6B38458),
+	(0x88A6A37851174B6B, 0xFFF8000000000000), (0xC579DF5063F84F7D, 0xFFF8000000000000),
+	(0xB7B0475F0FC0A4C1, 0xFFF8000000000000), (0x63BBF3036FD1CE1D, 0x4078D09B9B11932F),
+	(0xEA409DD0646D2771, 0xFFF8000000000000), (0x5DE5F08CC44D3DBB, 0x4074C554CFFF340E),
+	(0x156D63E760489DA8, 0xC07D76CD70160922), (0xAFE316B45255D036, 0xFFF8000000000000),
+	(0x20C088025518B694, 0xC0759D907EC31656), (0x8D8B45454E5FB170, 0xFFF8000000000000),
+	(0x5234575DABCDCD3B, 0x4069547303F83907), (0xF45A1D58790DDC43, 0xFFF8000000000000),
+	(0x231262019658C888, 0xC0740185CF9D0057), (0x754161D7822BCAB4, 0x40827AB3002D593A),
+	(0x408238DB0C7668A6, 0x40197936267FDF1A), (0xA0225C40B8957569, 0xFFF8000000000000),
+	(0xF7C36A285D519591, 0xFFF8000000000000), (0xA0F249AAA45B5658, 0xFFF8000000000000),
+	(0xC7E0601763E382EA, 0xFFF8000000000000), (0xCC4A1407C31762C1, 0xFFF8000000000000),
+	(0xEF5E05B06311BD0A, 0xFFF8000000000000), (0xBE61DBA8F443305D, 0xFFF8000000000000),
+	(0x564655F255F77358, 0x406EF930451D4B92), (0xC07D150633AEB9D7, 0xFFF8000000000000),
+	(0x5A2A393B49C893DF, 0x40722EC3892458DE), (0xDDD43EBDBE3A767B, 0xFFF8000000000000),
+	(0x949954EC26928C1A, 0xFFF8000000000000), (0x265573DC4FEEB92D, 0xC071BE5A78C02CCA),
+	(0x3FDAD52C53C07C30, 0xBFEBD0FD81864E7D), (0x3FD4D9579ADB58A8, 0xBFF1F1FB2DE7E6D6),
+	(0x3FEDCD6B33260105, 0xBFB236A69D1BC66E), (0x3FD7579376F9B72A, 0xBFF02353B43DB989),
+	(0x3FDE6F96B9E2A362, 0xBFE7C8CA28A81F53), (0x3FE3046ED82E772A, 0xBFE0A700708008DF),
+	(0x3FE3EB786B186EE2, 0xBFDE565DC56574DE), (0x3FEDBF53B98EEA32, 0xBFB2AFCF2468CBDE),
+	(0x3FEA9ED17699D2CF, 0xBFC78F3C1871D2F1), (0x3FE347D656913DC4, 0xBFE0365C2E897428),
+	(0x3FC15DC27F40ED18, 0xBFFFF5C44ABD705B), (0x3FEDB4440433C963, 0xBFB30F11FB16ED30),
+	(0x3FEE6437513F8D54, 0xBFAA67E6CEF4D8FA), (0x3FE87BFAA5AA530E, 0xBFD122104AE0B216),
+	(0x3FB456E3BDC41918, 0xC00442CCAAF9E6EB), (0x3FD0C991D18D7D06, 0xBFF5697EB2B860BF),
+	(0x3FEA59D92F7D5C93, 0xBFC8DC8E1AA863CF), (0x3FE40EFF25A7C565, 0xBFDDE49EC9D5B23E),
+	(0x3FE970D94F38B549, 0xBFCD5C5C14C7DCB3), (0x3FBDF140F6795EB0, 0xC0012ACEA9D82265),
+	(0x3FA3F9B5E032CC20, 0xC009F358B451DA37), (0x3FDC26D6F480FE5A, 0xBFEA47E1EBD527FC),
+	(0x3FCDF5D3D43ADC4C, 0xBFF73C0A5A281BE8), (0x3FE3EE2139E8A664, 0xBFDE4DD2FC46BA89),
+	(0x3FC0E9DEF084DBC4, 0xC00030FA326E578D), (0x3FC65C9D7E9D9790, 0xBFFBEA328745BCBC),
+	(0x3FEE2E7BE8BD6BA3, 0xBFADF441C8E5F809), (0x3FE5441241E07246, 0xBFDA27022609518C),
+	(0x3FE19A1C7E3B477D, 0xBFE3208D167EB8F7), (0x3FDBD0AFB0278F66, 0xBFEAAA67271CD924),
+	(0x3FD3E3F2E692DB1A, 0xBFF2B2C4B2B55D67), (0x3F9783CC5BD6AF80, 0xC00E30D32454CAC6),
+	(0x3FE76D5D522ADF03, 0xBFD3F5271E31FF54), (0x3FDFC4BE6194CF10, 0xBFE669BBBEBE589A),
+	(0x3FE2B6A979E7CB13, 0xBFE12AEBF0D0DF9C), (0x3FE1B29635EBB143, 0xBFE2F42CFDA57568),
+	(0x3FE22DB681A0FDD5, 0xBFE2188420AC540A), (0x3FCD94F8A6B1DE90, 0xBFF770184E5021A0),
+	(0x3FE1B29CCCEC94D5, 0xBFE2F421134AD970), (0x3FECC5E2A5DB51E8, 0xBFBB3666329B11DE),
+	(0x3FE89221E1E269EC, 0xBFD0E84244D9AFB6), (0x3FEF2197D7ABD2B2, 0xBF9C2F6A68FB0564),
+	(0x3FC8D13C8CC43C10, 0xBFFA3F7067D5771F), (0x3FD4EA626B46CA36, 0xBFF1E4EC6381158B),
+	(0x3FDEC2FC15867EC0, 0xBFE77192EDD962FF), (0x3FDFD72DC23B86BA, 0xBFE6572F5C7873D2),
+	(0x3FA9A6F1B5355190, 0xC007F31808AD7376), (0x3FE39222CB5AC6C2, 0xBFDF77EE68B67F62),
+	(0x3FEAE4C730909B59, 0xBFC6408EF8456778), (0x3FC33BD5EAFEA978, 0xBFFE5369D6371D60),
+	(0x3FE1D698609B4008, 0xBFE2B35302C954AA), (0x3FE223BC0E99D451, 0xBFE22A1997249491),
+	(0x3FEF61627DA13182, 0xBF940578785BCB03), (0x3FEA2666855AD317, 0xBFC9D76BE5DEBAD4),
+	(0x3FB139FE07D2F790, 0xC00596FE0177CD5E), (0x3FE6E0D821AE769C, 0xBFD5799AACC5FAAF),
+	(0x3FDD9775811F3800, 0xBFE8AF3CD1723229), (0x3FEC00A16E4EC6A1, 0xBFC114AC92338FED),
+	(0x3FEAFD111486D25D, 0xBFC5CD29174C7F03), (0x3FE9F05D13101227, 0xBFCAE0FF2180AC8D),
+	(0x3FEFAA6FF3AB35E5, 0xBF8580CF6F932CE8), (0x3FDE4A9F21C6DACA, 0xBFE7EFBFC47C20D6),
+	(0x3FEC47FF6CFAD9E3, 0xBFBFA021D0868459), (0x3FDA4AE51F735C04, 0xBFEC7794643805E1),
+	(0x3FE7EA57E0F65023, 0xBFD2A33C821B70C6), (0x3FE0141BF31E4F2A, 0xBFE6062449B4F510),
+	(0x3F9608E6A59C8D40, 0xC00EB5F7145570D6), (0x3FDCEBF45F15422C, 0xBFE96AD4EFCA50FC),
+	(0x3FE62B9174129100, 0xBFD77CB8AE256E31), (0x3FD8C0494DE08362, 0xBFEE668068B983EA),
+	(0x3FD90F441A3BE5AE, 0xBFEE0105B0091750), (0x3FEAF18A5A8815FC, 0xBFC603DF588FA63F),
+	(0x3FE4D7E9A0C64E0C, 0xBFDB6FCA4FF23806), (0x3FD9A41CCFD5AB84, 0xBFED451F9F0EBB86),
+	(0x3F90DDA3E4C3A820, 0xC0106CBBC06F9F7C), (0x3FDA0E5561A76AA2, 0xBFECC19EFFC3A77A),
+	(0x3FC06B4AA53EC248, 0xC0006DBDB5ED696D), (0x3FE1C2FA4646D2C3, 0xBFE2D6978D1D03FB),
+	(0x3FEEB480E5F10AA6, 0xBFA5263E7CFA9DF2), (0x3FC1C04A3D971A28, 0xBFFF9BFABF91E547),
+	(0x3FD53659D708E920, 0xBFF1AB3809656EFC), (0x3FCCAA74477C4330, 0xBFF7F0F1A6A296F3),
+	(0x3FCC5DEDE598AC74, 0xBFF81BE18BBFB44A), (0x3FE29DA999131746, 0xBFE155C8580074BE),
+	(0x3F93E443D8E244C0, 0xC00F8784CA00D7AE), (0x3FE2E2B91ADD013B, 0xBFE0DFEC0283A44C),
+	(0x3FD44878E485091C, 0xBFF262B1C5E845B2), (0x3FD822150C41F24C, 0xBFEF35A2C7BB79FF),
+	(0x3FDC14A4BE48431C, 0xBFEA5C9798CCFCA4), (0x3FE5AA396A2E35C6, 0xBFD8F66D31FA0DA9),
+	(0x3FD02CA65227C3C6, 0xBFF601DA869E3275), (0x3FDB148EA9474C3A, 0xBFEB85BF2128DF20),
+	(0x3FE96E931B74C98F, 0xBFCD67CDD16CE0F7), (0x3FC9E8BF05D0D848, 0xBFF98F166F78C4EE),
+	(0x3FDD683D1ADE6978, 0xBFE8E275F6A09F8D), (0x3FEB85B0391D5B46, 0xBFC34B855E3AC207),
+	(0x3FDA5F00BA5A10E0, 0xBFEC5F24B66A4FCA), (0x3FE7C6F3D8F9F3C6, 0xBFD3023866C889FB),
+	(0x3FB202FB2D2FCD20, 0xC0053BB7AF155BE2), (0x3FB82ADF0A73D1B8, 0xC002E1A0DCE70AA2),
+	(0x3FEF811FA886A76C, 0xBF8FF79FA39B9A34), (0x3FE74FA502542D1D, 0xBFD4468BAE8A43B9),
+	(0x3FEAA5FC3DE86EC3, 0xBFC76CCA7B7CD29D), (0x3FD85A6465C201AE, 0xBFEEEB4EEDFB2942),
+	(0x3FB68EA9981D4538, 0xC0036ED65CB2AD41), (0x3FE3264DCFAC0DFD, 0xBFE06E3486283808),
+	(0x3FEB323797AC6D17, 0xBFC4D20BB60BEAB9), (0x3FABFB8C493A7B00, 0xC0074101A2F20A9C),
+	(0x3FDBEFD56999FF46, 0xBFEA86A5C77458B6), (0x3FAA2E97A4D5F8A0, 0xC007C938908BE444),
+	(0x3FD2F32FBEB56844, 0xBFF3792AE7E93B17), (0x3FD823C2F585ED88, 0xBFEF3368CE11CC5C),
+	(0x3FC90BA33CF190C8, 0xBFFA19F59174FD5A), (0x3FE5FB8BBEEB9638, 0xBFD807F0A639A784),
+	(0x3FCD2F063AB9E084, 0xBFF7A79C0EF9B52C), (0x3FB4A0D87D4506F0, 0xC00425EA556025DE),
+	(0x3FA5901D7EA0FE80, 0xC00956BA94313139), (0x3FE043E9FB2B0E61, 0xBFE5A78C27E1500A),
+	(0x3FE3E2831DDD02C1, 0xBFDE732C5C130B1E), (0x3FC25B8E918DD5B4, 0xBFFF125E06414017),
+	(0x3FE190CF03E13616, 0xBFE3317B081667DF), (0x3FE59E872E55C75A, 0xBFD91903BF148B66),
+	(0x3FE9C05344853650, 0xBFCBCEE9511D98A8), (0x3F8E4754750E3440, 0xC010DB51247AF785),
+	(0x3FED3F9F72280636, 0xBFB704201E50383B), (0x3FE471C13EBCED05, 0xBFDCAC823FC48130),
+	(0x3FE0FA80DD90C373, 0xBFE447FA58D44979), (0x3FDFECC97F2B6662, 0xBFE6417F46A9CA69),
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_SQRT: [(u64, u64); 256] = [
+	(0xA7D75C1F1668C1B6, 0xFFF8000000000000), (0xD3CEC31A53AEEFFA, 0xFFF8000000000000),
+	(0x41EEB97DDF194F41, 0x40EF5B15FCE51701), (0x72ACD013D261526B, 0x594E5D573AE4EA3C),
+	(0xF1D683433E313E2C, 0xFFF8000000000000), (0x3DB88F271E813DA7, 0x3ED3D2A90E3D2692),
+	(0xEAB55449414ECB46, 0xFFF8000000000000), (0x28EF820D680B323D, 0x346FC0C842172A1E),
+	(0x13DE170BB879C75E, 0x29E5F1167B17A1FE), (0x6FD034F2D1E97C27, 0x57E01A63A5D0FD20),
+	(0x0FF1949FC6CCAD2D, 0x27F0C58C58F7651D), (0xFD336890E7A91553, 0xFFF8000000000000),
+	(0x28157EE3FBFEB173, 0x34028B9D3665B105), (0x6EC54979D93BAC5E, 0x575A197994971EAB),
+	(0x445569F6C4BC315F, 0x422282940B67933A), (0xDA0A48B3B90CD3A2, 0xFFF8000000000000),
+	(0x329568E926AD2333, 0x3942821F82D5C80A), (0xB380698B688C9C72, 0xFFF8000000000000),
+	(0xFFC541B538DF6D28, 0xFFF8000000000000), (0x702718A108B222A4, 0x580B2F9DBCA04E85),
+	(0x3E7E6C6E8445468E, 0x3F361022459D527F), (0xEBC4E04A3B1CCC4F, 0xFFF8000000000000),
+	(0x622651B8C0560737, 0x510AB98CD4A70ACE), (0x1BE38E15DDA341D5, 0x2DE903E4BB1CF455),
+	(0x29517C7B27E20915, 0x34A0BA04426C489A), (0xC5F09DAE098B6223, 0xFFF8000000000000),
+	(0xB4BBB2D1C0108022, 0xFFF8000000000000), (0x8778FCE36196B737, 0xFFF8000000000000),
+	(0xD4F42A4859886C7F, 0xFFF8000000000000), (0x8F547D0442C32EE2, 0xFFF8000000000000),
+	(0x3F1C4B4630340AE0, 0x3F8546E0168ACB19), (0x8F06856F63581BCE, 0xFFF8000000000000),
+	(0x5C0AB7C5DB206726, 0x4DFD3D69ED315C6C), (0x21DEDB6A3ED3C2C3, 0x30E6383BDB41D4CB),
+	(0xDC0B894BA1F0F54D, 0xFFF8000000000000), (0xB4BE93334BCDF825, 0xFFF8000000000000),
+	(0xEF91FA1652795487, 0xFFF8000000000000), (0xBB127E55172891C1, 0xFFF8000000000000),
+	(0x39BEED91C58DA0A9, 0x3CD63EC432C532DC), (0x24D5AB3BB62EB0C8, 0x32629EB43BD68082),
+	(0x37E30C0F783BF5C6, 0x3BE8B02E51A49008), (0xDB83CA0A8BD0281A, 0xFFF8000000000000),
+	(0xD85A65B6ECA80A19, 0xFFF8000000000000), (0xE20C8AAC9C3682C8, 0xFFF8000000000000),
+	(0x6E8AD0BC05EC0227, 0x573D4B0F6D0313E7), (0x3B564829E1C56ED4, 0x3DA2E1A890DA752F),
+	(0xA8AD76D1C2EFE325, 0xFFF8000000000000), (0x34EF953A8F70903A, 0x3A6FCA7074B7A4AB),
+	(0xE99F45DBBF5B4796, 0xFFF8000000000000), (0x35747E1F726D0033, 0x3AB21B8726268478),
+	(0xA47B37E8BDDB3A38, 0xFFF8000000000000), (0xE12593AEC8E4FCB8, 0xFFF8000000000000),
+	(0x7C7CA6615E305B20, 0x5E35690617B36A0F), (0xC87C13AFB8B88C44, 0xFFF8000000000000),
+	(0xD8D71C7A9217B324, 0xFFF8000000000000), (0xF567C267F0BE19DE, 0xFFF8000000000000),
+	(0xE5145CF19862D978, 0xFFF8000000000000), (0x7198497304A24D56, 0x58C3B67392914BAB),
+	(0x22608EF753BA4F9B, 0x312704D810B459B8), (0x43FD2FD65FB6A3CE, 0x41F59C258772CE88),
+	(0x7837C667F4CF3773, 0x5C1380FCACCF2605), (0xB2C4BF53A1F28C7C, 0xFFF8000000000000),
+	(0x4A675B41C46FC7B6, 0x452B56B82763BA5F), (0xB2D36B0044630DAD, 0xFFF8000000000000),
+	(0x6288C453A344228C, 0x513C26F025A4DEF1), (0x0B328B132B81360C, 0x2591398988EB06AD),
+	(0x4B42E934B92F7272, 0x4598998D453BD276), (0x9B8E81FE86A7DE23, 0xFFF8000000000000),
+	(0x1EFA1F7817AB7161, 0x2F7471B98134AA36), (0x85C99EC2F8657E22, 0xFFF8000000000000),
+	(0x4166471D6F32BE2F, 0x40AAB3326DF931F3), (0x652A50817BEF1BDF, 0x528D04B0FDB795A5),
+	(0x3709BA49BA30A434, 0x3B7CB165E113A487), (0xC7007F4D0CC5951E, 0xFFF8000000000000),
+	(0xF5FB043A1E33392F, 0xFFF8000000000000), (0x5A071E477EEECA11, 0x4CFB32F0D4E42739),
+	(0xFC1E93739886F56B, 0xFFF8000000000000), (0xB0844AEABC386C21, 0xFFF8000000000000),
+	(0xFF0CDB4014312A1D, 0xFFF8000000000000), (0x7E7B8DA7461274EF, 0x5F34FF1B2203501A),
+	(0xACB49855A6B0FA10, 0xFFF8000000000000), (0x466B0FAD37EB8995, 0x432D6D5C664AF5C5),
+	(0x28902CD82D44B883, 0x3440165C7674517E), (0xD545DA0A63D9CE30, 0xFFF8000000000000),
+	(0x63404782C7EBD251, 0x5196D2F73CB4FB26), (0x7EEA71F696FEEDA2, 0x5F6D171DBBAAC674),
+	(0x38CD4A1F883BA552, 0x3C5E9D62E8CB885C), (0x1076666EB4BE5994, 0x2832EE775D679586),
+	(0x3FF4233E8A9FEA01, 0x3FF1F333B0E026AE), (0x0ACCA30083C906B9, 0x255E458D83C3B726),
+	(0x29FA8F26EF05935E, 0x34F49D3F03C179ED), (0x26AD9BE69E72295A, 0x334EC80266A1A5AE),
+	(0x79D1F4083C794915, 0x5CE0F2D196D67B67), (0xF21DA7559CF4B60D, 0xFFF8000000000000),
+	(0xA20F4D718830DFE0, 0xFFF8000000000000), (0xA5C71816EC89AAC7, 0xFFF8000000000000),
+	(0x66B1DB09BE097429, 0x5350E70144B4BBDD), (0x15CAED54C5AB6415, 0x2ADD5AA9E053B809),
+	(0xC919867A0CFA0AF2, 0xFFF8000000000000), (0xE54C2BB8C6E282A6, 0xFFF8000000000000),
+	(0x152934A94794DCF9, 0x2A8C66808A5A95E6), (0x442F33B3143D250C, 0x420F99346EB7FE15),
+	(0x9DA0762BEDAFB016, 0xFFF8000000000000), (0x6C47A919B65979C3, 0x561B842144984919),
+	(0xC67B4AFB22EDE2E8, 0xFFF8000000000000), (0x93C4BFF7CAF8ED71, 0xFFF8000000000000),
+	(0x8DDDED66846D0EB0, 0xFFF8000000000000), (0xF755029DECA1796F, 0xFFF8000000000000),
+	(0x71FDD84B387F637C, 0x58F5DA295BD49BE3), (0x2477E4FCCB26D688, 0x32338D83D8E26DCB),
+	(0x6BD87C80B46D2287, 0x55E3CB20CBC65E31), (0x8D04B9380C4D97FF, 0xFFF8000000000000),
+	(0x7E9B59982FE8DADF, 0x5F44EB3BE6329EE6), (0x3D53D9743E5672D6, 0x3EA1D232469494D1),
+	(0xC9A86DC31A784C48, 0xFFF8000000000000), (0xF7F01AF27E60354D, 0xFFF8000000000000),
+	(0x0A5D3112CECE1460, 0x25259C9AAB061639), (0xF983DE5FC67332A8, 0xFFF8000000000000),
+	(0x6A718FE8412E8A03, 0x5530C34C3703475E), (0xCF16516AFAF12735, 0xFFF8000000000000),
+	(0x6B2798A532BC6AF0, 0x558B7A8E1F2A69F7), (0x5CE2B5986150D88D, 0x4E6877E4C8E4AACA),
+	(0xBBDBD0A222303FAC, 0xFFF8000000000000), (0xEE5CC0544ABCF619, 0xFFF8000000000000),
+	(0x0F1164667D4799A6, 0x2780AE7BD9E2F014), (0xB592684950A1FDD8, 0xFFF8000000000000),
+	(0x3B14A7149B678F9B, 0x3D822D968A426CDC), (0xE24CB3F26FAF295E, 0xFFF8000000000000),
+	(0x3FE0C032A45E00E1, 0x3FE726F717B34EAA), (0x3FE4AACBAE1C5538, 0x3FE9B77AA9CCD4A0),
+	(0x3FCFD1A72CBE8860, 0x3FDFE8CB2C3B740F), (0x3FD4DA17E52B9A3E, 0x3FE243FC03238628),
+	(0x3FD25F2EB5E251F8, 0x3FE1251AAA88145D), (0x3FA55380D9A07450, 0x3FCA1F9E8705D97B),
+	(0x3FECB339FC53E215, 0x3FEE4E1F9ABCF899), (0x3FEDD6258E6C5885, 0x3FEEE63A380F3CBD),
+	(0x3FE95984EF19A011, 0x3FEC7B3CC20A9CB5), (0x3FD1B30DD606D9B4, 0x3FE0D409E7C196E0),
+	(0x3FE6FD9BD22714F6, 0x3FEB1FB207E90F0D), (0x3FE4472ADB37B2F9, 0x3FE9793314DC69F3),
+	(0x3FDABA3C2F9B7648, 0x3FE4ADF08042B652), (0x3FA67DD3119F9020, 0x3FCAD3E74A6DB9C5),
+	(0x3FD2BF1FFC7A728A, 0x3FE151A558529606), (0x3FD176B7C611D4FA, 0x3FE0B7426330B320),
+	(0x3FD0B530B9977CA4, 0x3FE0599D663CD768), (0x3FEDCD7AD66301B1, 0x3FEEE1BD04416E8D),
+	(0x3FC74BC3EAAE1338, 0x3FDB4DA5A27F3D92), (0x3FEE2DB8B6458082, 0x3FEF137202BBE624),
+	(0x3FAC9508A037E4C0, 0x3FCE3E2A96E9D311), (0x3FE08E2F854A26EC, 0x3FE7044D2D676B58),
+	(0x3FDF80F8CB5834D6, 0x3FE6738834ACE869), (0x3FD4C5E6A0CD3E90, 0x3FE23B21D16277F9),
+	(0x3FBDF0C67CC97320, 0x3FD5E31DF1DC6B51), (0x3FE3C9998AD3FA68, 0x3FE929D8D7F3F5E3),
+	(0x3FEB7AB610F13446, 0x3FEDA7558A99548B), (0x3FD4639714782F84, 0x3FE20FCA7148276F),
+	(0x3FEE2F011ACA6B0B, 0x3FEF141B15153C51), (0x3FE3DB6EA87877B0, 0x3FE9352CFCEDB143),
+	(0x3FEAD3E5AA71D299, 0x3FED4CC99A68F7E5), (0x3FC3B452F8CEDFF4, 0x3FD91C4E09EB02C4),
+	(0x3FEFA9D97E1090D8, 0x3FEFD4CF99DA6C12), (0x3FE14E0ED31EDCCB, 0x3FE788343C5EEAAE),
+	(0x3FE1586DC5ABFEF8, 0x3FE78F4057B97340), (0x3FEDBAA59CA78A01, 0x3FEED7F992E1C84E),
+	(0x3FC56AB9A4B5C02C, 0x3FDA2DD3B396397E), (0x3FE25EA100D6735B, 0x3FE83EC3EDF32C17),
+	(0x3FEA9EBD39CAB410, 0x3FED2FB3ECDC48F7), (0x3FCF29C8B7DE2CDC, 0x3FDF942EB97F0663),
+	(0x3FEAA77F1C616C91, 0x3FED3480892BC3AE), (0x3FD9F3F42193B490, 0x3FE460AB3B0F6507),
+	(0x3FE2061A9ACF79EF, 0x3FE804116448A2AA), (0x3FC6243D58D5B12C, 0x3FDA9E440EE3A3A6),
+	(0x3FD81CDB59B83510, 0x3FE3A4526B265A02), (0x3FDF831B0A18BFB8, 0x3FE6744AD5AEAB62),
+	(0x3FC4962527471B14, 0x3FD9AA9E5D2EEB17), (0x3FD621C1B4D5D322, 0x3FE2D15BAE10797E),
+	(0x3FA5BA9AB8A67440, 0x3FCA5E7881C8B725), (0x3FEB4FA85442FC6F, 0x3FED90117F4F3EE2),
+	(0x3FD2281225306854, 0x3FE10B5011A92536), (0x3FD28A62540A97D6, 0x3FE1393765EB93D1),
+	(0x3FD168A8A56EBDA0, 0x3FE0B08688A2C5B7), (0x3FE49A35D1CC7D85, 0x3FE9AD26F879D892),
+	(0x3FDD36DE368331BE, 0x3FE59EBFAC319B4B), (0x3FE12A732FB2A229, 0x3FE76FF1CA8763B0),
+	(0x3FE65B9EB2869DF8, 0x3FEABF793BFF704E), (0x3FEDFB4E5DEE7174, 0x3FEEF97213BE2520),
+	(0x3FD49E331ACC2E2E, 0x3FE229AD929D4037), (0x3FC8DBB7AFFD0BC4, 0x3FDC343844FC82E6),
+	(0x3FEC7F62482180C4, 0x3FEE32B43C3ABD6E), (0x3FE60ED044AE6388, 0x3FEA915FE67063DE),
+	(0x3FE367BCB70A2F36, 0x3FE8EB5180C4DE85), (0x3FEA7F1299442720, 0x3FED1E52AF1DE224),
+	(0x3FB60B98BC10EC30, 0x3FD2C7ED9218935D), (0x3FEA3C428160F8A9, 0x3FECF9851272926D),
+	(0x3FEF2D8A5CEFE718, 0x3FEF9615E6FD9CCB), (0x3FE0C8BC5BF8ACAE, 0x3FE72CDCD822E65A),
+	(0x3FDD35D7E83238AA, 0x3FE59E5E9BFDB384), (0x3FE66C41C69A7C7B, 0x3FEAC96B11553C2A),
+	(0x3FE6A1F31F4415D4, 0x3FEAE96A2E88AE14), (0x3FEEB899EC3C5215, 0x3FEF5AA1AB2DC368),
+	(0x3FEC1EB5091D7B19, 0x3FEDFF4F7A4CA794), (0x3FC042DB7839BC18, 0x3FD6CFB3E0CD427A),
+	(0x3FE10241AD57E401, 0x3FE7547149102E30), (0x3FC1D40655BBE8D0, 0x3FD7E29CE5F12DC1),
+	(0x3FEA088407274E08, 0x3FECDCE4247AF43B), (0x3F83C5FC8368B580, 0x3FB9278C90E012A0),
+	(0x3FEFA45035089077, 0x3FEFD20714B13E78), (0x3FCD05BBBBC122B8, 0x3FDE798FF7B780CF),
+	(0x3FE46D9651C88CAE, 0x3FE99149665C8150), (0x3FE3C3F312B47BA2, 0x3FE92640DC413976),
+	(0x3FDD2D2F3D53132E, 0x3FE59B2A17614770), (0x3FE1BDEAADA0A6D7, 0x3FE7D3C90EC74E79),
+	(0x3FE6CC659D28FB72, 0x3FEB029AE7A019C5), (0x3FC7FC0C6DF375C4, 0x3FDBB432C87D40BB),
+	(0x3FD2A9B1AD6DD61A, 0x3FE147BC42CE06E0), (0x3FDD3B3921010222, 0x3FE5A05C2909ADCB),
+	(0x3FE0A095F93C4195, 0x3FE711141360BEF9), (0x3FBBF874164AE1C8, 0x3FD527A546D1A9D5),
+	(0x3FE3CAA02B487012, 0x3FE92A7FD30A26F3), (0x3FACFC70BCC4CB00, 0x3FCE74AE8C91E569),
+	(0x3FDE10BC9BF67776, 0x3FE5EEC97CDF4ECB), (0x3FE69CD02C213C08, 0x3FEAE65C3974A076),
+	(0x3FEDC4E2A42EEA35, 0x3FEEDD48C37635F6), (0x3FE03E6D1535E664, 0x3FE6CC9800461B93),
+	(0x3FD4E155143735DA, 0x3FE24727664BB223), (0x3FEA7C2A2F36D1A5, 0x3FED1CB9997EBEFE),
+	(0x3FADBF541D84C9B0, 0x3FCEDA6732396EB1), (0x3FDEDBC432BBB22C, 0x3FE6385C3E2A7355),
+	(0x3FE8A5381412E01E, 0x3FEC153C9014163E), (0x3FB617158B9E9DC0, 0x3FD2CCD19C5D0682),
+	(0x3F98E3CA110B7880, 0x3FC3F4B409463A0F), (0x3FEC7083BE711D52, 0x3FEE2AD25E4E2F98),
+	(0x3FD413D7D7878856, 0x3FE1EC552F6E7AAD), (0x3FE37C2AB5A92C67, 0x3FE8F86C1B105081),
+	(0x3FEEF300BB2611CE, 0x3FEF7860F922EE29), (0x3FECC9DD89D1E125, 0x3FEE5A1116397687),
+	(0x3FE42AA33A071C66, 0x3FE967415009537A), (0x3FE2382EEDA1DB80, 0x3FE8255791768FDC),
+	(0x3FE41C215ACFC73D, 0x3FE95E1C871C85C2), (0x3FD808EDFDE7D10C, 0x3FE39C32FDDDDB23),
+	(0x3FD86D7AD4A69726, 0x3FE3C50D765B41C4), (0x3FE41EF27BBA791C, 0x3FE95FE34CB10367),
+	(0x3FEB96C6469A3039, 0x3FEDB676039514F6), (0x3FE5E58B24171639, 0x3FEA7879952DB8E1),
+	(0x3FE965A092174F72, 0x3FEC82093AE804FD), (0x3FE455B3CF40E8C2, 0x3FE9825299288272),
+	(0x3FCF55C0A540F958, 0x3FDFAA6DE946A8A6), (0x3FE751EA7E4CAD2F, 0x3FEB51401D2AFF40),
+	(0x3FE1A977C0F07ABD, 0x3FE7C609D8694B52), (0x3FE2BE1B6D41CD09, 0x3FE87D750B72D571),
+	(0x3FC29CB646EFDB68, 0x3FD86799E8D5D13C), (0x3FE71F46D135DA71, 0x3FEB3387051A02A8),
+	(0x3FB3EB5A659F5D88, 0x3FD1DA396D26FD62), (0x3FECBE74D08B4BC1, 0x3FEE540CD3D44B21),
+	(0x3FE18F0824F24D7A, 0x3FE7B43875D43FB5), (0x3FEC3529002C177C, 0x3FEE0B46F28CD24F),
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_SIN: [(u64, u64); 256] = [
+	(0xAAE42D324E66779B, 0xAAE42D324E66779B), (0x4728F9B1C803DA07, 0x3FE9F25C454C168F),
+	(0x6872273E27E90885, 0x3FDE6A47A90336B4), (0x679AB34BC11C00F7, 0x3FEFDAA2F1D56C66),
+	(0xB4E81980A89EDC0F, 0xB4E81980A89EDC0F), (0xBEDCF2266CAA732B, 0xBEDCF2266CA97689),
+	(0xF96888A990A2B7F9, 0xBFE22ACC9514BB02), (0x244887CE6E074E82, 0x244887CE6E074E82),
+	(0x082E756EA2530153, 0x082E756EA2530153), (0xFDB14E10713820D9, 0x3FC3DE0C7AE4F3B0),
+	(0xCE95B08BD7091A2F, 0x3FC265ED7A2BD209), (0xE998C49AA74E5EE6, 0x3FEF32D4F8C6F70F),
+	(0x8B85EB0AB1FC6697, 0x8B85EB0AB1FC6697), (0x293A64E40F35F444, 0x293A64E40F35F444),
+	(0x8F4806C6461C2CC1, 0x8F4806C6461C2CC1), (0xA5AED04A025440CE, 0xA5AED04A025440CE),
+	(0x54416B2A28AAC481, 0x3FA2E669677AEFE5), (0xFF913C7A2189172F, 0xBFB06BDCDB55B903),
+	(0x5589623D3817C9FE, 0x3FB70DE913406E09), (0x1964C213FE2890B9, 0x1964C213FE2890B9),
+	(0x9508C1E12C3E47A8, 0x9508C1E12C3E47A8), (0xA0AD718DFC9DCA76, 0xA0AD718DFC9DCA76),
+	(0xF7146558DE070B69, 0x3FB5365245CA4087), (0x0A8B525790D3DF7C, 0x0A8B525790D3DF7C),
+	(0xE9773B0C17BE9B1A, 0x3FE19A3521D07AD1), (0x74E3B0414883B485, 0xBFED302266E777D3),
+	(0xAFE8EA8940717C38, 0xAFE8EA8940717C38), (0xB7075B18225A68BE, 0xB7075B18225A68BE),
+	(0xE43A56CAFA98C2D7, 0x3FED80CC47BAA73D), (0x5A8ABC958BD57FDF, 0xBFCB44F8C397A290),
+	(0x7D5180BCB48C1BD6, 0xBFEB0DEE7703D52E), (0x10EEE87B77EF197B, 0x10EEE87B77EF197B),
+	(0x09A28E007ECDC653, 0x09A28E007ECDC653), (0xC3090AF65785C998, 0x3FEE63B65A75F86A),
+	(0xC06BF7EA44983061, 0x3FE4796F6A041996), (0x6E07D9492710E682, 0xBFE91F63856C8C18),
+	(0xECD826D1B55C2AF8, 0x3FE9F7D87FAA1710), (0x6DCCE8AC2CEB5FDC, 0x3FEF0D788238727F),
+	(0xCF86FD89F559E92C, 0xBFEFE332856ECA0D), (0x7F4A0F71A58C72F7, 0x3FDC097D39123188),
+	(0xFA26192C276E3EA2, 0xBFC04BC502FE77AE), (0xFDF1346A48A6D066, 0xBFED9226453EB223),
+	(0xF5E6C91965D0D13F, 0x3FDACB883AD9B27C), (0x97473086F09C06D1, 0x97473086F09C06D1),
+	(0x950D033E12173221, 0x950D033E12173221), (0xBD90A6C3763B4709, 0xBD90A6C3763B4709),
+	(0x01C2D899019C11D2, 0x01C2D899019C11D2), (0x8099F63F129FD55D, 0x8099F63F129FD55D),
+	(0x95102DFAE1A903BA, 0x95102DFAE1A903BA), (0x8009AAADF174BC2B, 0x8009AAADF174BC2B),
+	(0xE88EBD0EE1B484D6, 0x3FE021F8DE38997F), (0x67BF0EF3842893B9, 0xBFEF8C9879E4AFFB),
+	(0xD86EA199D8ED39A7, 0x3FD90BB4FC2E59CB), (0xBAB4C60923F57345, 0xBAB4C60923F57345),
+	(0x39DFF7038266976C, 0x39DFF7038266976C), (0xA5F98E22824E5F5A, 0xA5F98E22824E5F5A),
+	(0x983A4A164FCA6C92, 0x983A4A164FCA6C92), (0x99CF9213BE84C964, 0x99CF9213BE84C964),
+	(0x1182E303A8B5FC6B, 0x1182E303A8B5FC6B), (0x8D24ECEFF98DF487, 0x8D24ECEFF98DF487),
+	(0x657F2BAE1C20A21E, 0x3FEE3DB02DD59A9D), (0x0D9E43F246F953AB, 0x0D9E43F246F953AB),
+	(0xF990B6222C952EDE, 0xBFE70B8C374A4281), (0xAE969A9E91A634DB, 0xAE969A9E91A634DB),
+	(0x3B6CA76486E6775E, 0x3B6CA76486E6775E), (0x8A05C52BB4D84DCF, 0x8A05C52BB4D84DCF),
+	(0xB5988A72E09AC484, 0xB5988A72E09AC484), (0x79711D1BBC2FFDC7, 0x3FE1034A01B36172),
+	(0xDEB2CF82A6D11B5C, 0x3FEF7858C6DAC60F), (0x79205C2D20045899, 0x3FEF8BAEA64CA4E3),
+	(0x402D7B8E7B1E904F, 0x3FEA55DFD359DBE4), (0x770AC17464EA4F0D, 0x3FDBC0338E9A15C0),
+	(0xD685CC1219111994, 0x3FEABF965CE3F3E4), (0x2F8EE4C5D6158868, 0x2F8EE4C5D6158868),
+	(0x5C6BD31237E894E5, 0x3FDCF34CCDFA0BF5), (0xDE49DC5A07F4C146, 0xBFEF5430922D6A0E),
+	(0xA7F94F61D7EDF476, 0xA7F94F61D7EDF476), (0x878898AC6434C3AC, 0x878898AC6434C3AC),
+	(0xFD47451FE4DBC722, 0xBFD1291645503C3E), (0xB1189AA37F724C5D, 0xB1189AA37F724C5D),
+	(0xA0A61A855E4A8FE7, 0xA0A61A855E4A8FE7), (0x25EB67F54494716C, 0x25EB67F54494716C),
+	(0x6CB43CDD2F46A094, 0x3F874B4B9785E690), (0x195261C9CF4E5E12, 0x195261C9CF4E5E12),
+	(0x69CB91D35E5C6BC0, 0xBFAC451DDA01E1C1), (0xD2CEE19EE7E077C1, 0x3FEFFDAD298B2959),
+	(0x8CFE38F252C381FE, 0x8CFE38F252C381FE), (0x44CCC06C931E4079, 0xBFBBEDCC93DBB617),
+	(0xD15F6168111FAE4E, 0xBFD9F52A6F4D79DC), (0x3D5BAC09C43F8A59, 0x3D5BAC09C43F8A59),
+	(0xC6DD380BBDD2E5D5, 0xBFEFFFD47F9299ED), (0x925DEECE7758D078, 0x925DEECE7758D078),
+	(0xB2BD31A08B51FED0, 0xB2BD31A08B51FED0), (0xF8E98D77ECBFC8F5, 0xBFCD213C6FB0E6C2),
+	(0x371C355BDB9962C6, 0x371C355BDB9962C6), (0x7C02DF53545E844C, 0xBFED4455C2B71D53),
+	(0xACE86BA293FE2FFD, 0xACE86BA293FE2FFD), (0x7FA604947548ADEB, 0xBFE73A790889BA3D),
+	(0x76E2B516F56B7E2E, 0xBF92143FDF5225E8), (0x4C86DF06170BC204, 0xBFEFFFFFB407EE9E),
+	(0x60920DD42FE2BA7B, 0xBFE0D763E117212D), (0x8D4570E7906C4CA4, 0x8D4570E7906C4CA4),
+	(0xE9F360F70A114EA0, 0x3FED8E6E1058F741), (0x594EC6149FCF8604, 0xBFEF9EAC1A8657AD),
+	(0x44549060743D0769, 0xBFEC87AD37265BBD), (0x3280CB64E5B1E01F, 0x3280CB64E5B1E01F),
+	(0xD671B4B78BEBEE63, 0xBFEE61DBD8E1C859), (0x2B29B85326805984, 0x2B29B85326805984),
+	(0xB9D7D9B53AC38AD6, 0xB9D7D9B53AC38AD6), (0x9861EEDF1833A96D, 0x9861EEDF1833A96D),
+	(0xAA00BA9C3266BDF1, 0xAA00BA9C3266BDF1), (0xBB4975B57E69990F, 0xBB4975B57E69990F),
+	(0xF2CF4306FFD8AE47, 0xBFE83A8134BCA34A), (0x88A0708ED6AA1E33, 0x88A0708ED6AA1E33),
+	(0xB84CD7E86E5E767A, 0xB84CD7E86E5E767A), (0xABC43AD6CB4B0B05, 0xABC43AD6CB4B0B05),
+	(0x6103ACCC8B408B35, 0x3FE30585FB12E0C2), (0x7691088F173E672B, 0x3FEC33E27B8462A2),
+	(0x6F52A9649C680C5F, 0x3FEE8D1474CE3D0D), (0x2D423174D544C619, 0x2D423174D544C619),
+	(0xE0DBA34202E3758C, 0x3FD0778C7CD8832F), (0xF2698A6152BE2EA3, 0x3FECF361873D6019),
+	(0x75F4EFDEC32161B7, 0x3FB9AAB52013C400), (0x15B870F22EE967D3, 0x15B870F22EE967D3),
+	(0x8A1392E4AE67562E, 0x8A1392E4AE67562E), (0x26DB6D68AC0ABEFC, 0x26DB6D68AC0ABEFC),
+	(0x69B38CA886F5C8CD, 0xBFE49331FE59EBFA), (0x86538ED3CDF09B0E, 0x86538ED3CDF09B0E),
+	(0xC1CA59FCCFE562AA, 0x3FDB092DEA1F9E19), (0x404C775C4F8958E0, 0x3FD7F77E946C8CE4),
+	(0xBFEF0C409CEF0468, 0xBFEA6699E5FB6DC7), (0xC0726AA9A4F77500, 0x3FE331D4EDD62000),
+	(0x3FF92613049F6056, 0x3FEFFFFEF404C13E), (0xBFCDD002633F4AF8, 0xBFCD8B3191B153A1),
+	(0xC12FD71AD97CD514, 0x3FD5A5843DBA62B7), (0x413E5069B5479F4A, 0xBFEBD16916DB2820),
+	(0x402274F638834250, 0x3FC8F8509BCE8F90), (0xC1DE91621BC2D18C, 0x3FD77A7EE89D1FB9),
+	(0xC033A2804E25C458, 0xBFE69F8DD03F1E3D), (0xC03ABE0DE40EDFEC, 0xBFEFF9D0970F9BDD),
+	(0x42170AF110EA79FC, 0x3FEDDB4298153CB4), (0xBFCD061457E059A0, 0xBFCCC692E2160CE8),
+	(0xC22EFC5034BCFEF4, 0xBFEFB4920268D0BB), (0xC0A047920A84696A, 0x3FE940212EBCFDFD),
+	(0xC06B3E38888DE880, 0x3FED851EC4284964), (0x40D14D0B69FD750A, 0xBFE583D72517C61D),
+	(0x42189930E86EB65C, 0x3FEA8B9A30A6DBE4), (0x40ACF4CAC7387C00, 0xBFE433F0303F9FA0),
+	(0xC05308BACC4CA9BC, 0xBFE588BB853E8B8F), (0xC1D2F2D9D59CC04C, 0xBFAE695BB3526433),
+	(0xC0AD21E07AD34280, 0xBFC0DA36C8F08511), (0xC1BC7D5E3F463620, 0xBFEF3B5875841350),
+	(0x413AAEDD1CD5C79E, 0x3FDCAC3160C79FEB), (0xC1B994DC4F2EF948, 0x3FE99DCB49ED80DA),
+	(0x3FF52F2A0D274D18, 0x3FEF07CFEF97808B), (0xC11295CF488174E8, 0x3FEA15165B1FC206),
+	(0x400B126C09AB78FC, 0xBFCEB97C290586F3), (0xC0F79E6F9A66BB88, 0xBFE64CBDCF4B2153),
+	(0x41D187B64C7FCED4, 0xBFEF8FAA6AA65468), (0xC20E5CE2B29280F2, 0x3FDD11FD2EF610CF),
+	(0x418D9C6867D47ED4, 0x3FEF724C891093F0), (0x3FB7A769EF23C500, 0x3FB79ECD1AE022D3),
+	(0x41C9CE604B05F734, 0x3FDAF1DA3899C67E), (0xC0016E5E30DE636A, 0xBFEA437CE33DCBE2),
+	(0xC1C14AAC43EBEBA8, 0x3FE2B537095A082B), (0x412A285F7356DF00, 0x3FE4C53C62DA7DA0),
+	(0x400EF005110F8F88, 0xBFE53C1C79CC0341), (0x41B83FD713FD7700, 0x3FAD105D1E279060),
+	(0xC20F8A57C2E77AC4, 0x3FEACB44C48BF6CD), (0xC17BB4159A20B0E0, 0xBFEF242367A4303D),
+	(0xC24868CC2D641548, 0xBFEFB8188D549F50), (0x404AFD2EFA2AE3C6, 0xBFE14B0418F40978),
+	(0xC00E4F46FF4C9774, 0x3FE34ADD6399E265), (0x41A505568EFDDA20, 0x3FEDDD75665CA98C),
+	(0xC1A48397C21E9DB0, 0x3FD274530558DB7D), (0xC1E1AAB7BB185E9E, 0xBFE9DDB807354AD1),
+	(0x41C75E470F36296A, 0x3FC604599195A487), (0xC00E8A58EF9C5B3C, 0x3FE4054B7B6095F2),
+	(0xC0D7651C1F9A6FAA, 0x3FEF3227883335F4), (0x423CC3AAA60A915A, 0xBFEA683452505691),
+	(0xC06065A82EE01A84, 0x3FE64764F2B2DFA1), (0xC2190C9F0A602CA8, 0xBFEA04F92D80447B),
+	(0x40C462746F7F9744, 0x3FE06BC57014EBCD), (0xC1651EDB9852E524, 0x3FE89C32416B6C08),
+	(0x40BE0DF12819DFE2, 0xBFC71E524FBF5CDD), (0xC14B39FC2D9CC3FC, 0xBFC05E9AD52682D0),
+	(0xC043D4B8372E8DB0, 0xBFED92B9822B86FB), (0x42407D603EF4AFA2, 0xBFE5DD328DD1B02C),
+	(0x3FEB4099A5C7AA0A, 0x3FE8135322062554), (0xC1980DBE80E5F7E0, 0xBFEC28F9B81742E2),
+	(0x4135B0A78E65C1E0, 0x3F8B487B0F4BD7B1), (0x402BEFCA1E6A0A14, 0x3FEF8B8776EE88B2),
+	(0xC028390A7359DF8E, 0x3FDC1F9A39D91D80), (0x41AED21658BD3370, 0xBFEB96CCB26A6790),
+	(0xC1239373B494A14C, 0xBFCED54B20D32A67), (0x41DA22D35531E940, 0xBFD36633FA7C4BEF),
+	(0x4177EC8ECAB8F114, 0xBFDB2E369817C324), (0x4243A69BEB97F3CA, 0xBFEA3FE449756C80),
+	(0x420546DCB30FE1A0, 0x3FEFFE4076ABD895), (0x42175769F1CA68EA, 0xBFB1CC5A37AB6F3B),
+	(0xC072938087DD2CA4, 0xBFEE2F7A88BE448C), (0xC0DD0BE6D118DC80, 0x3FEACCFA94AE887A),
+	(0xC1D675F58183A1A4, 0x3FEEF3DE3904782A), (0xC1B719F9250601B8, 0xBFEFFFA3E980AF45),
+	(0xBFF4C6D74ED90670, 0xBFEED245B3EEBBF7), (0xC073978605C95BB8, 0x3FE45879F98ABD3D),
+	(0x420C2B156EDAC860, 0x3FD4354D8B808C39), (0xC17E3EACC37D9320, 0xBFEFE3567007C706),
+	(0x41FAE3E068FEE654, 0xBFE983B0F7F7637B), (0xBFF77826FD4122FC, 0xBFEFD3C508A0BCED),
+	(0xC160073F46E358F8, 0x3FE8C1B14EDB7F5E), (0x40267E03A3D00448, 0xBFEF003863FD34EA),
+	(0xC19D6C321C77F90E, 0x3FE855BE4BC76160), (0xBFD3C1FFBA568770, 0xBFD37209D959F823),
+	(0xC0C53CA70AC6A8B0, 0x3FD00292009D86EA), (0xC058F1DAD31992D8, 0x3FE5DBDD86ACA0AA),
+	(0x419E6A702462562C, 0xBFEFBC256F081F1C), (0x412037801C2B69B4, 0xBFEC4267CFBB5599),
+	(0xC0353C4AC6761090, 0xBFE5F1A8600875A3), (0x3FA6BD3E86CFC720, 0x3FA6BB54AA36AE53),
+	(0xC02757A29ECBC8DC, 0x3FE8F889EA24EE25), (0x404C0966D3CF418E, 0xBFDD4832A9CAC1A4),
+	(0x407AEC28B62D274A, 0xBFD6A66C134C5683), (0x41E86988E8DFA200, 0x3FE42BB319A48038),
+	(0xC135F1380A088624, 0xBFE0BE995B894D88), (0xC0BD2BC689C82ED0, 0x3FCAA87A6157F1C8),
+	(0xC1588AA879F9813A, 0xBFDEFA1D5FBBB45C), (0xC165246FDFBDDB5C, 0xBFED08374C544133),
+	(0xC1C4FF53F4548EDC, 0xBFB20C2D45CA2310), (0x4082852DE8841992, 0x3FECB69CEB2A76E9),
+	(0x4118B5442785EC3C, 0xBFE7B361E5022876), (0x40518AC2E73AA710, 0x3FEBCE90420B806D),
+	(0xC1E0C17339D8F4A8, 0x3FEF20BD8F115B68), (0xC1F4487887954230, 0xBFDC3B707EB8F9EE),
+	(0xC06CE7AC35426A3C, 0x3FEE3EC0B0226CA2), (0xBFD5BE8DC4ABAE3E, 0xBFD5541352407A25),
+	(0xC0475A395AB8A1B8, 0xBFDA0A002DD40A63), (0x40565E80EC2680FC, 0x3FEFF1DB209AB2DF),
+	(0xC0C2AE6AC931E1F8, 0xBFEEF634C35FCC1D), (0x422CE19C5A730F6C, 0xBFEE2E45747D671B),
+	(0xC19E666A0B0E6CC0, 0xBFEFB118143B6B14), (0x4030104F209711B4, 0xBFD64A59B509D319),
+	(0xC0C97C87AB1F3EDA, 0x3FECBF30F78F85AA), (0x3FEDF72E19354384, 0x3FE9C63164288B9A),
+	(0x41BCB16ADF71E584, 0x3FE69CDF61CFD0FF), (0xC1A828974EDDA60A, 0xBFEF577DF027253E),
+	(0xC207F4C00399CE28, 0x3FE86A9E5B82A6F0), (0x40EFB1628569FC30, 0x3FEF5684C73ECAAD),
+	(0xC18B6CFC6CF47D9A, 0x3FE215B29BD4398C), (0x4168641F16648EB8, 0x3FEABD122B09F6F9),
+	(0x419E0AA58BD0738C, 0xBFE59CC10BE9F4A0), (0x4222564EBB04C0A6, 0xBFEFE24D36A54C90),
+	(0x403E794DEEED3D2C, 0xBFE9E18E7FC8C2C2), (0xC09B2DB66E477A80, 0x3FEB2B6067CE1313),
+	(0x41F5FCBC7F71168A, 0x3FECA69D95D8717E), (0x3FFB58C7C484E798, 0x3FEFB1B13ACE59D5),
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_COS: [(u64, u64); 256] = [
+	(0x9748A3FD542C0AD9, 0x3FF0000000000000), (0x3D099ABAB798D4D6, 0x3FF0000000000000),
+	(0x0325411CE61F6185, 0x3FF0000000000000), (0x410F23290B907A50, 0x3FCCDF74F31EE318),
+	(0x3C9B9CD12102C080, 0x3FF0000000000000), (0x12F30807E3EAAD6C, 0x3FF0000000000000),
+	(0x9AE88116DBD0589B, 0x3FF0000000000000), (0xAFE7ABF5D9D72B0E, 0x3FF0000000000000),
+	(0x2EBC16ABC3031F0A, 0x3FF0000000000000), (0x6D9C880E7119FE18, 0x3FC88CE08EA28213),
+	(0x4E2D8F54B7CDCB22, 0xBFEF4EB1CC74ADFE), (0xD556505945033CC7, 0xBFC7914F6FF11F59),
+	(0x9E8EA17B449D6C62, 0x3FF0000000000000), (0x56006B83FD5AB85C, 0xBFEFA0AF0D5B6D23),
+	(0x3FC2A8FD7420DCB7, 0x3FEFA91AA3F48BCB), (0xD1B1B1D3746482FE, 0xBFD3AFA36DAD889A),
+	(0x32A681411BDE92FB, 0x3FF0000000000000), (0x8449A754EA0D6FDE, 0x3FF0000000000000),
+	(0x77536C8BF3F699BF, 0xBFEF7F5C302A2D7A), (0xD96AA558AF9CF19C, 0xBFD0B0ACB4938E43),
+	(0xB1D89C92AB46AE49, 0x3FF0000000000000), (0x8368A720453BFAE9, 0x3FF0000000000000),
+	(0x39AC3051D602CBDF, 0x3FF0000000000000), (0x7A0BADD33576C56C, 0x3FE48E8F2C7768CD),
+	(0x3ACDBB1AED34A746, 0x3FF0000000000000), (0xA27FEFB6FCD9A2E9, 0x3FF0000000000000),
+	(0x3CA1A8F12BB8DE85, 0x3FF0000000000000), (0x60BD469E4B273CA0, 0x3FE916C6BB50EB7C),
+	(0xCB2E14158178E0EE, 0xBFEB6EB647777CCE), (0x8952919FDAA8E4F3, 0x3FF0000000000000),
+	(0xC8D6DFE1F40CD35E, 0x3FE809D3A3A93504), (0x6018FE0ACEFFDF4A, 0xBFDB8CCCCE69209E),
+	(0xD2402CD4F4E0F4C3, 0xBFEE507196B2F35A), (0x001E6B9701E8C23A, 0x3FF0000000000000),
+	(0x28DB79EF9CAE46DE, 0x3FF0000000000000), (0xA2555FDE37D057E4, 0x3FF0000000000000),
+	(0x6C15073F82CE7F49, 0xBFDAE774D9E781C9), (0x5E8C3D09EA2BA09B, 0xBFDA61174BC8919E),
+	(0x7A5C4DBA78EF84BB, 0x3FD6B06C34A43F24), (0xCC14AFCF107ADBC9, 0x3FD5C71DDD13A245),
+	(0xAB9BA04DC12AFC8B, 0x3FF0000000000000), (0x209F889B1CF9BB12, 0x3FF0000000000000),
+	(0x13604A3511CAAC72, 0x3FF0000000000000), (0x5360740BAD7D23B9, 0xBFE6026B134BEA50),
+	(0x0B35BE8D9F4AB494, 0x3FF0000000000000), (0x53D046A24E212243, 0x3FD27C8FC7E177D9),
+	(0x32CBAD286EB6E5C1, 0x3FF0000000000000), (0x759BCFB5106FF459, 0x3FD87E1FB5821840),
+	(0xB4A810A0E090B7F7, 0x3FF0000000000000), (0xE9B867A8C0D54CF2, 0xBFEE583FD5276008),
+	(0xA0A389AEFB3FA52B, 0x3FF0000000000000), (0x0676AD5EABBF36B1, 0x3FF0000000000000),
+	(0xD27F9BBCC3E5EC48, 0xBFD734EEAF597D6B), (0x80FF26EE4E00388B, 0x3FF0000000000000),
+	(0x37CB9EB979900078, 0x3FF0000000000000), (0xC92BB991FF1CA0AC, 0x3FDBBECA6EF472BB),
+	(0x86467A2BD95528A1, 0x3FF0000000000000), (0xE06C2CE3CA67199B, 0xBFDF6CA8A6866B4B),
+	(0x10387D8986641331, 0x3FF0000000000000), (0x24932D4AD36EED9B, 0x3FF0000000000000),
+	(0x7021E9CB1F5E621E, 0xBFE7D92D3C74145C), (0x334C81B1FD849C54, 0x3FF0000000000000),
+	(0x25D000398F127328, 0x3FF0000000000000), (0xC9D4687B333FA6B3, 0x3FEF1EC2DE0D7583),
+	(0xEDB3AA8C61D2E861, 0x3FEB0F799CA9C931), (0x11934FB1D43AB50D, 0x3FF0000000000000),
+	(0xE5721032BE9FB4EE, 0xBFDC8814F291A08D), (0x54C05C851AE97B8A, 0xBFE54A18DFBEF5CB),
+	(0x5758ACF47C4CB20F, 0xBFEA82A21F336938), (0x816075F2FCBE00C0, 0x3FF0000000000000),
+	(0xFEE3BCB6530E005C, 0x3FEFFC109DB07579), (0x041EC6C442092A9E, 0x3FF0000000000000),
+	(0x8C1F109C9292B950, 0x3FF0000000000000), (0x6AD6EAB7A35CB58B, 0x3FE10C7C20B4528C),
+	(0x3796A157005AB50B, 0x3FF0000000000000), (0x67783B034DF2F9CE, 0xBFEEC0E0AE922495),
+	(0x0E0D72B4897C6853, 0x3FF0000000000000), (0x6A6CEDF69A03232F, 0x3FEFB5C345EE6EBC),
+	(0xBF869C7C82DC1EDB, 0x3FEFFF802F117881), (0x4726FE03332EBEDE, 0x3FBCEDDC76E29FFA),
+	(0xC9958C7E0B2615C8, 0xBFE1410D94969A49), (0x89200BDACE9FC394, 0x3FF0000000000000),
+	(0xB6A7D5E346F13A70, 0x3FF0000000000000), (0x1AB8AFF78A3C77F8, 0x3FF0000000000000),
+	(0x644C21CC29889164, 0x3FEBEC564255F0CF), (0x2536EF4296C8DB43, 0x3FF0000000000000),
+	(0x269F151A183589B0, 0x3FF0000000000000), (0x18EAC70C22237426, 0x3FF0000000000000),
+	(0x98403F8A42C5C060, 0x3FF0000000000000), (0x417B04410145A9EA, 0xBFEC70AD142FBD67),
+	(0xB1BE68051EAAF846, 0x3FF0000000000000), (0x4BCBD9DB298E0BD9, 0xBFE163620AE844D7),
+	(0xB28BCA3522BC49FB, 0x3FF0000000000000), (0x8413EA9B105D2ADC, 0x3FF0000000000000),
+	(0x9D80195B0C8BD022, 0x3FF0000000000000), (0x1ACBB9F5DD73E965, 0x3FF0000000000000),
+	(0xC4F75C5F7DB453F3, 0x3FED20D5E0A40004), (0x696EB84D7A0C658E, 0x3FC27A74DF505C78),
+	(0xABF9F72843B6BDF7, 0x3FF0000000000000), (0x6BC45E5FCA94E182, 0xBFE5C085F4AA66AE),
+	(0x7BCB752CC8374F10, 0x3FEFC2C1EDC4F0DF), (0x92DB0C044D864E1C, 0x3FF0000000000000),
+	(0x6D02E94BE5E6E5BC, 0xBFE9A80D2C5DBC2D), (0xD3459E1455465BCD, 0xBFD4019C7EE14E10),
+	(0xD228F6DA2064D39A, 0xBFEE2685201CBDDA), (0xA3C7EFA4A79F35C2, 0x3FF0000000000000),
+	(0x4D04D7FA372A072C, 0xBFE40F5041E85886), (0x4BADBA7C69554B45, 0x3FEFFFBEE435D3AB),
+	(0x1082F53275568DDD, 0x3FF0000000000000), (0xD3CA8AC47205CB62, 0x3FEFE01001B1B4C5),
+	(0xACB4E23B7646B246, 0x3FF0000000000000), (0x91D8B9FAE9FEB6CE, 0x3FF0000000000000),
+	(0x9A13D41759653AAC, 0x3FF0000000000000), (0x36D8A52D036BAC04, 0x3FF0000000000000),
+	(0xBA2BE46953048BCD, 0x3FF0000000000000), (0x95709264B840D1B1, 0x3FF0000000000000),
+	(0x0F67B5FFF460B3DC, 0x3FF0000000000000), (0xC060F95F58FF8352, 0xBFE8624782414F82),
+	(0x99194EB47688A1C8, 0x3FF0000000000000), (0x62282381EC06A41A, 0x3FD9181E3CC11BFF),
+	(0x35563A755B0D0CC4, 0x3FF0000000000000), (0x312B570B8BDABC18, 0x3FF0000000000000),
+	(0xA50A4BA02637D003, 0x3FF0000000000000), (0x866B018F2AF04399, 0x3FF0000000000000),
+	(0xE1058C942976BEC3, 0xBFDB808F015ECCCE), (0x732A959B1744C0CA, 0x3FE8A5CAC0F42C5F),
+	(0x3DFF3684E5D1348D, 0x3FF0000000000000), (0x434C0FBBDDB99BA2, 0x3FEDE67016492838),
+	(0x4188B43069DEFE5A, 0xBFE21B4C7121A26A), (0xC1B123418CDA7800, 0xBFEC1C773BF7B8F1),
+	(0x42302F3AA6381C60, 0x3FE438ECC8378D91), (0x411AC88CA7E0A618, 0x3FB1933ADD511821),
+	(0x40B0F5974ACF31E0, 0x3FEFDECC92D419D9), (0xC18BC58622445BB8, 0x3FE9E967A462B772),
+	(0x414A0EC62363FC3E, 0xBFED451DA59AA9E2), (0x4047DC414484847A, 0xBFEA77AD52425E73),
+	(0xBFDB4D0A50F294A0, 0x3FED21E48CDE52EC), (0xC1169E53206AD660, 0x3FB53B9FAFF50E4A),
+	(0xC04E3A8FCCA340FA, 0xBFE708C47978FB11), (0x416F3B3D363CD84C, 0x3FC6B1DE5B4084F5),
+	(0x406270443191E170, 0xBFEFA83624EA471F), (0x4231075002BEA3F0, 0x3FE9E6B8289B7B0F),
+	(0x40CA25FA004810E0, 0x3FACAD78F4CCE633), (0xC2119F4F3C60DA3E, 0xBFC43BAA5D62B9F6),
+	(0xC18D7EC9791E6140, 0xBFE0BB62C96FC924), (0xC1910C81E9557B48, 0x3FEE93D02A0EF6D2),
+	(0xC0102D19959D87F0, 0xBFE3D47D81ED88F2), (0xC141D7765BEE2742, 0xBFD963B1F92840FC),
+	(0xBFF1F574A9D0D560, 0x3FDBBE6D7B60B1CF), (0xC0E2120731692CD8, 0x3FEEE5A0506C17BB),
+	(0x417EDCC6FAAC1208, 0x3FEFF85525F91F3E), (0xC10EB86B167A672C, 0x3FE2398EB123C3FA),
+	(0x3FFB4128BBFAE0C0, 0xBFC0ECB19C1FA9E2), (0x404B837C5A2BB284, 0x3FA943378283A2C9),
+	(0xC11485BE90CD493C, 0x3FD3659020C36DBB), (0xC1AC59299D083D92, 0xBFEB6F96749509DE),
+	(0xC06BEF3C61CBAF00, 0xBFED2DD3BC007E7E), (0x40F10068A2E15044, 0xBFDA75848487FFB5),
+	(0xC0E0039D8C16B6D8, 0x3FD0E0439AF52019), (0xC134758BB309DF02, 0x3FD77397D37A2274),
+	(0xC1C5C4DF1328BAD6, 0x3FE8888D2F1C0E5A), (0x402A7CE6F84DEAB0, 0x3FE8EE53D9AB2532),
+	(0x421A4972E533E7F6, 0xBFDC734598312553), (0x3FE376C27DCB7F7E, 0x3FEA42C5D45E7720),
+	(0xC2489C31D7B3E588, 0xBFE943F8AE050F25), (0x419529BA98DE9CDE, 0xBFEEC829F3D05329),
+	(0x41938E19EEC928E0, 0xBFE2118D354004E1), (0x40B1B4D02CABFAE8, 0xBFEC015C269F64EB),
+	(0xC0020D5FF279B000, 0xBFE44383BA1926C2), (0xC0EBF0BF05063162, 0x3FE144D776E43537),
+	(0x4206EC3E91F28BF4, 0x3FE68AEBA653895A), (0xC014F1A2C9B96C68, 0x3FDFFFC8C382163B),
+	(0x3FC8E3A15D61F8B0, 0x3FEF659E982E9765), (0xC145F4F10E5159AA, 0xBFEF6F847FC6C71B),
+	(0x407D5ECE62B1987E, 0x3FD04902C34817EB), (0x400A27B5B38C82AA, 0xBFEFBD31E8091BD9),
+	(0xC15E663A47D07F80, 0x3FEC0CDD808C4083), (0x41CDE035AFCD032C, 0x3FEF29D193ECB660),
+	(0xC1156877EC4B5A1A, 0xBFEAAA535B5EF5A8), (0x414E59D3E4F76FCC, 0xBFEFB4F76BCF986E),
+	(0x418FAC5530A67E1C, 0xBFDDF3F219EC34EC), (0x41E1AADCC95CDD38, 0xBFEDCFB4781969AC),
+	(0x4146BCCE2847C854, 0x3FE856B80548156D), (0x411BB64F6348E5A0, 0x3FEE78CA68F546BA),
+	(0x4058D3B3FAF02A9E, 0x3FD5CE7E6D9D0FF0), (0xC06F5F30E281AC18, 0x3FEE07BE482DFE97),
+	(0xC13161459D1BED80, 0xBFE35083E3623E71), (0xC08E8DB8E5F353F8, 0xBFE8E20B97D0125E),
+	(0x42250AB6D1180BB4, 0x3FE998EAC3484CEB), (0xC1F23200DA7C1704, 0x3FECE611B1E94423),
+	(0x40A6212FE25E5516, 0x3FDBB536F9E6065A), (0xBFFDD4F2C0A04F0C, 0xBFD286FD19767091),
+	(0xC1BA439113213E28, 0xBFEF904F30F60430), (0xC0A21E4A9C1BA148, 0x3FE977D027041266),
+	(0xC1821F1E91B50F50, 0xBFE0067674795326), (0xC08AC90CD0EBC7E8, 0xBFEBB6BC21B05E05),
+	(0x417FEBB9252C569C, 0xBFD866AD4FEFE061), (0xC0110BF97431FC80, 0xBFDBE0BAD759814D),
+	(0xC1E61631EF8772B0, 0x3FEED37A46B0159C), (0xC1FE2031ECA87530, 0x3FE9ECB7499EB6F0),
+	(0xC2004382D8632D00, 0x3FEF29D3D9C10F8A), (0xC0483D0605E52170, 0xBFCBAD0D68643439),
+	(0xC0C35688CE872730, 0x3FD550FA4466B78C), (0x4175DC80A27B6A00, 0xBFE91DCCB7DBD20D),
+	(0x411FAE3DA40B7D72, 0x3FB941E2AE415B64), (0x3FF615888057EE54, 0x3FC83DE0B67096DD),
+	(0xC1872989B18C4C44, 0xBFEC470F3EC76AD0), (0xC053B1870B3CB8BE, 0xBFEF20A4D6E13C2A),
+	(0x3FC9E75D1D4934D0, 0x3FEF58D20B3C8F74), (0x41A2A2BF1E5C7164, 0x3FD48875FC0CC3BC),
+	(0x410038162DAFC56A, 0xBFEA4E40A784AA5A), (0x3FC754E81AF169F0, 0x3FEF7848E3C294CF),
+	(0x40E83274BADA6760, 0x3FEF9058BAC824B7), (0x4155F525325F908E, 0xBF9833FD986D70EA),
+	(0xC1E9F85F5DF315A0, 0x3FEFFEEE68E2A384), (0x403740BF90FF0AD6, 0xBFD376D0DF109990),
+	(0x417DBFE0796A4C46, 0x3FD980D867FD33EE), (0xBFD0FE200921BC44, 0x3FEEE0F0F7ECD0BC),
+	(0xC1CB5A2E149ED964, 0xBFE9FF246E22CC3A), (0xC09DED1ADB50701E, 0x3FDD4C636A63066F),
+	(0xC026994C9A0C7820, 0x3FD325D839BEE0EE), (0xC21EA59AF9F78B34, 0x3FEB5EE867971814),
+	(0xC0942EEFB4188CBC, 0xBFEB746F2A7ACC60), (0xC181E8B6CEB20AD8, 0x3FDD28382AFDD695),
+	(0x422CA497F29B5200, 0xBFD85733BB079E6C), (0x411208F90ED82630, 0x3FE9EF3C4C5CD171),
+	(0x40E229FCE025E5DC, 0xBFEE88FB43DCAE1D), (0x41D5990D66AA9A40, 0xBFE60A54921C0220),
+	(0xBFF0B783939B81FC, 0x3FE010F98D192463), (0x41F3C219ED874740, 0xBFEF94D4C7CA3D8B),
+	(0x423EE3DF11E7073C, 0x3FC763042317E941), (0x40A21C735FBE546E, 0x3FEED72085218EAA),
+	(0x40919920E88EA034, 0xBF95AD63C2B9A1AB), (0xC10AA27278C1AF16, 0x3FED455F547A6BDE),
+	(0xC19A7520C1A497B6, 0x3FD24433472A1825), (0x3F9F6539CB9E5040, 0x3FEFFC2667BE0652),
+	(0xC0E6C04A84D575E0, 0xBFC9C589C75D69FB), (0x41AF70D90A2DA116, 0x3FEFBF163FFE5111),
+	(0x41A7C53A8AC30868, 0xBFB03396601CBD70), (0xC24F6CF3CD88628A, 0x3FD3B3BBC0352263),
+	(0x40A198DE91998EC8, 0xBFEFE0DAB9C40CE0), (0x40A70F7D8F24EAD8, 0x3FCBCCE0933AB088),
+	(0x402BCA801D077020, 0x3FCEA1CF398B5B23), (0xC19256974467D1FC, 0x3FCF60D4D7FD5589),
+	(0x4065AC8A468E9A80, 0xBFEA55463904EFF4), (0x3FC4512E30FE2388, 0x3FEF99052DE3A68B),
+	(0x40E3C367A074C428, 0x3FE028FA2CC743A0), (0xC197A628070891CC, 0xBFE2CCD25A0EE357),
+	(0x3FE8331B94C29292, 0x3FE7470AB1D33CF2), (0xC011AE39CEBCDE00, 0xBFD2704CEF0414B7),
+	(0x423F47977039D8F8, 0xBFE95EFD55B6122C), (0x41FA4BE34BB4E5BC, 0xBFE898C640E67522),
+	(0xC0020E6E366383BE, 0xBFE446C83E092C36), (0xC118180A0A09DC62, 0x3FE5ADA6D1D3413E),
+	(0x41BE82269BF3EDC0, 0x3FEFFEAA54EB789A), (0xC06AE50B434E95B0, 0x3FA53A3F495DBAB4),
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_EXP: [(u64, u64); 256] = [
+	(0x9A55750F8C29C0BE, 0x3FF0000000000000), (0xB063D1532BEDBEE3, 0x3FF0000000000000),
+	(0x29A8AEDE2EEF1ACA, 0x3FF0000000000000), (0x35FD0661FA4B28DC, 0x3FF0000000000000),
+	(0xFFAD330D8B705E63, 0x0000000000000000), (0x94176B073213E2F0, 0x3FF0000000000000),
+	(0xA99BB5782B24454F, 0x3FF0000000000000), (0xAB40B1B6DFD34754, 0x3FF0000000000000),
+	(0x230C750DACDC58FE, 0x3FF0000000000000), (0xFB7DA12B1068A2CA, 0x0000000000000000),
+	(0x0CD1AB40C80A3824, 0x3FF0000000000000), (0xC1BBAC6304A8865A, 0x0000000000000000),
+	(0x01FF0DA2FA9B2187, 0x3FF0000000000000), (0x107586EE33550041, 0x3FF0000000000000),
+	(0x8AB545CCF580D819, 0x3FF0000000000000), (0x9D563967BDABF02A, 0x3FF0000000000000),
+	(0x545283821DDC3449, 0x7FF0000000000000), (0xD05A7A43A309EA9B, 0x0000000000000000),
+	(0xCD178D13F32EF695, 0x0000000000000000), (0x82A84ECAA5135FB1, 0x3FF0000000000000),
+	(0x0862F206533FAF63, 0x3FF0000000000000), (0x3851386406BCF910, 0x3FF0000000000000),
+	(0xCE9A43F1F3B3D011, 0x0000000000000000), (0x68B37214E8A76314, 0x7FF0000000000000),
+	(0xFAF379ACDD11394A, 0x0000000000000000), (0x517302493BD1F54C, 0x7FF0000000000000),
+	(0x37CEE0A7AC19DA9B, 0x3FF0000000000000), (0x904BA4D30BEAEE27, 0x3FF0000000000000),
+	(0xDA74409822893745, 0x0000000000000000), (0x5488D13323BB35EF, 0x7FF0000000000000),
+	(0x070C15597864E454, 0x3FF0000000000000), (0xE2016115E396DCF7, 0x0000000000000000),
+	(0x7FD8030E988E53F0, 0x7FF0000000000000), (0xA1489B231A9AC910, 0x3FF0000000000000),
+	(0x0D3BE88E3F5581F2, 0x3FF0000000000000), (0x5B9EA5F413134641, 0x7FF0000000000000),
+	(0x695B33FAE5B155BA, 0x7FF0000000000000), (0x1146A761FFEE6EB6, 0x3FF0000000000000),
+	(0x10245DB2A8BBCD3F, 0x3FF0000000000000), (0x26891404FFD89DF8, 0x3FF0000000000000),
+	(0x6DDEAD6B705D4567, 0x7FF0000000000000), (0x430CF2F9D9D0635E, 0x7FF0000000000000),
+	(0x30ECAD968AF37398, 0x3FF0000000000000), (0x089B19E601E2A7C3, 0x3FF0000000000000),
+	(0x31B7F10FAB75E48E, 0x3FF0000000000000), (0xD60AA53AE296FA78, 0x0000000000000000),
+	(0x8BE10EEFAD63146E, 0x3FF0000000000000), (0xC14630B9939F397C, 0x0000000000000000),
+	(0x6018F8C002DE741B, 0x7FF0000000000000), (0x344D94383FDD08EA, 0x3FF0000000000000),
+	(0xE4B70ED51F18EFC8, 0x0000000000000000), (0x8D1028A46702CDB2, 0x3FF0000000000000),
+	(0x2E7196F63EA2CACF, 0x3FF0000000000000), (0x7337D946B93D1F8B, 0x7FF0000000000000),
+	(0x98D4794269DEE4C0, 0x3FF0000000000000), (0x79BD854D90ACE628, 0x7FF0000000000000),
+	(0x396C674F90BCBBBB, 0x3FF0000000000000), (0x08A37584844995A9, 0x3FF0000000000000),
+	(0xFF5AD196DB5FC002, 0x0000000000000000), (0x6DBAEA656D463E54, 0x7FF0000000000000),
+	(0x5D694F1046AFCCC9, 0x7FF0000000000000), (0x8E26064FA28B6384, 0x3FF0000000000000),
+	(0xD4F95B18F737DA50, 0x0000000000000000), (0xC59798198082CDE1, 0x0000000000000000),
+	(0xC6978D9C45753D33, 0x0000000000000000), (0x906689CB5D91CCD0, 0x3FF0000000000000),
+	(0x4FC7B16FE60B0700, 0x7FF0000000000000), (0x32A2F7A250719B2D, 0x3FF0000000000000),
+	(0x17115CFF0B725C0A, 0x3FF0000000000000), (0x74B7335D245CDB37, 0x7FF0000000000000),
+	(0xFDAAF1285EF9D27C, 0x0000000000000000), (0x15FF37B72B045DCB, 0x3FF0000000000000),
+	(0x2B9B2264F1732EB0, 0x3FF0000000000000), (0xD36550326CACA8D5, 0x0000000000000000),
+	(0x9E0770301A1010C1, 0x3FF0000000000000), (0xEBBAF915D6F8F103, 0x0000000000000000),
+	(0xB9E82EA1C8E3675E, 0x3FF0000000000000), (0x41713BB941FF7ABA, 0x7FF0000000000000),
+	(0xBDC1065D5386BBE1, 0x3FEFFFFFFFFBBE69), (0x79C40AFF6C8E964D, 0x7FF0000000000000),
+	(0xBD870EABC2BFAB9F, 0x3FEFFFFFFFFFA3C5), (0x68A06423DB48AB6C, 0x7FF0000000000000),
+	(0xFACBE5E848F32281, 0x0000000000000000), (0x5EC537B7F5B107DA, 0x7FF0000000000000),
+	(0x6AD76C1CBFF92AD3, 0x7FF0000000000000), (0x791B5EEAE7AA6562, 0x7FF0000000000000),
+	(0x7AD003EBE2BD665B, 0x7FF0000000000000), (0x4D41210A00835B0B, 0x7FF0000000000000),
+	(0x29AB7DB1C94584AD, 0x3FF0000000000000), (0x740A0D4941BC2D8A, 0x7FF0000000000000),
+	(0x4EA110E8ED784ABD, 0x7FF0000000000000), (0xB77C2CC20274B2F7, 0x3FF0000000000000),
+	(0xEB7707CE7194FFB5, 0x0000000000000000), (0x8ADFD0BD60283DC4, 0x3FF0000000000000),
+	(0x328D01445FF45309, 0x3FF0000000000000), (0xF10781324548C2EA, 0x0000000000000000),
+	(0x0B2DECF853A90FC7, 0x3FF0000000000000), (0xD349CF8B29A9AF64, 0x0000000000000000),
+	(0x59C325C42C283133, 0x7FF0000000000000), (0xD2FF11AD1ABE20E0, 0x0000000000000000),
+	(0xC7344E57B0852C93, 0x0000000000000000), (0xDBF14938C9B575B4, 0x0000000000000000),
+	(0xB955D41702488446, 0x3FF0000000000000), (0xC04CBB0E3EBC680E, 0x3AC1284EA551BA8C),
+	(0x50023C3E71B3F81B, 0x7FF0000000000000), (0x4283FB11C258286F, 0x7FF0000000000000),
+	(0x95989F4B3EA8E6DF, 0x3FF0000000000000), (0x7F827031A3342B5A, 0x7FF0000000000000),
+	(0x585CF69C779AB537, 0x7FF0000000000000), (0x47B494A5BF129E48, 0x7FF0000000000000),
+	(0xEADD2054F4404DB0, 0x0000000000000000), (0xC01D76795C1AB7DE, 0x3F44BA80163412B7),
+	(0x0E47BD8729550E14, 0x3FF0000000000000), (0x82253C479BBF5761, 0x3FF0000000000000),
+	(0xA8D04E8DA6EAB5FF, 0x3FF0000000000000), (0x4944B249052BA769, 0x7FF0000000000000),
+	(0x660E31B8B0D32AB1, 0x7FF0000000000000), (0x3D6E238EA9CAB7D4, 0x3FF0000000000F12),
+	(0x9DFA682FD1C22424, 0x3FF0000000000000), (0xC054038AC65A42FD, 0x38B6B2EB32EE6F75),
+	(0xAB0A45122B341760, 0x3FF0000000000000), (0xC6B22BD57D48122E, 0x0000000000000000),
+	(0x5F236ADCC728F20E, 0x7FF0000000000000), (0xADC6B22C0F1CB552, 0x3FF0000000000000),
+	(0x5C07BB135A967B4B, 0x7FF0000000000000), (0xE4739ABB72AD81EE, 0x0000000000000000),
+	(0x6C9E1C18D15F6DBF, 0x7FF0000000000000), (0xE752EA9D3BFB0D52, 0x0000000000000000),
+	(0xC039318D76133090, 0x3DA92A56FAB69EE6), (0x4016A04B0AB77438, 0x4071E27DF967DF70),
+	(0x3FD3921299D0C108, 0x3FF5B921772C58D6), (0x405C04615DFC2C64, 0x4A09A5089E3279DD),
+	(0x3FC074D562BE6A38, 0x3FF231F44E3BA2A8), (0x3FFFA4EF0122D464, 0x401CE806ABB734AE),
+	(0x3FC1093AE7C09AEC, 0x3FF24718BE1DE470), (0x4033D00D3EA23958, 0x41B7FA921934E1C3),
+	(0x402996DC8F2E0328, 0x4115FD752E8531F3), (0xC021961EC4F28DE0, 0x3F23E4413F916503),
+	(0xC08499E7A63E870A, 0x047E4882B016B307), (0x3FC6FC874A9D55DC, 0x3FF325C033C9964F),
+	(0xBFF6C59D77473DD0, 0x3FCED6D153DB352E), (0xBFFA923D3502C300, 0x3FC85218A1EAF9AB),
+	(0xC00E764FAF4CCBBE, 0x3F96BAFA982E4FC4), (0x4000B84B53E942B0, 0x40202B6CAD15974E),
+	(0xC00ABB7A43667240, 0x3FA21DA31B2FF92F), (0x4023416C65D53D74, 0x40CDA6598B4F9B7C),
+	(0x3FF408803E58FEEA, 0x400BFB12CA600C0F), (0xC05230D533D33C60, 0x39604810BFEE225F),
+	(0x400FB47FFA58F89A, 0x404A4F9E748482A5), (0x3FFBDFCF9CA81CB0, 0x4016D695FC0EDC64),
+	(0x3FFB579A5097BE60, 0x4016175C63E8887A), (0x405B19D21A7D0DF0, 0x49B502FBC79FB59C),
+	(0xC04CA4DA738AD4D8, 0x3AC4683EB103B44A), (0xC021955596EF6A6A, 0x3F23EC13AE1E572F),
+	(0x3FEB576668D1920E, 0x4002CCD1431DC815), (0x404BF5FE1A5A682C, 0x44F999DB1630D90C),
+	(0x406EF834246836B8, 0x5645A8A4E734B594), (0xC07E2593D6607B28, 0x14715D55FF33A398),
+	(0x403007EDB282C220, 0x41617B673AF6A23E), (0x407E9F5B54DB2FA0, 0x6C1D17A7856EF5EE),
+	(0x40165201C9ACB60C, 0x4070918187594D08), (0x3FE67082E5E00580, 0x40002142552EE543),
+	(0xBFF6B37E92A7688C, 0x3FCEF9D2596B9BC8), (0x3FD89ADB65400258, 0x3FF7803DA403617E),
+	(0x4068E243ADDCFD12, 0x51E25BDAE90E51E8), (0xC00E684EDAD1EE10, 0x3F96E2E7113B37A2),
+	(0xC024E71CC068D528, 0x3EFE4FF086712175), (0xBFFA4A6570B441C0, 0x3FC8C04385AE3F91),
+	(0x4046ABA48EB57396, 0x44054E4BC38DE58F), (0x4027AF1F4ABDC40C, 0x4100F6E8BD490DBF),
+	(0x400E0F2DD90CE274, 0x40456B30153B1C29), (0xC06605600CACA9E4, 0x300CB5056E9AC5D2),
+	(0x3FE9C861C9FF65C8, 0x4001E802D6ECFA28), (0xC0666F567C3FC524, 0x2FC0C00338F076B7),
+	(0xBFBFEA66E50FF140, 0x3FEC3FCC2C0B0AF8), (0xBFE1A3B0A6E828EA, 0x3FE2708E13266587),
+	(0xC060F16349F1D94C, 0x33B5E40AA83900D0), (0xC060C889D868CCDE, 0x33D39D8ACB97CB57),
+	(0xC075B79716C3BE2A, 0x2099FFEEC1FEA68B), (0x3FE2B624585EBD48, 0x3FFCB6541D07F4A2),
+	(0xC05FD81E009DFC64, 0x3472D1872346BD1B), (0xC06B33269AB872DE, 0x2C50D0F0916ABE40),
+	(0x405C0F764CF5710C, 0x4A0E7E2343245B70), (0xC05C90009F8D67AA, 0x35A206BFEF3CC412),
+	(0xC066B7260747EC62, 0x2F8C6A26E40AD7EA), (0x40492F7FDC77F990, 0x44797594EA16B7FE),
+	(0xBFFEC27DE604A198, 0x3FC2B81A088CFF7E), (0x40601B354E83A8D4, 0x4B8DAF2CB91D9750),
+	(0x406E30B4C7C2CFC0, 0x55B5BF0534E62C28), (0xBFF3824535353698, 0x3FD2E86ADDA3DC16),
+	(0x407E983728819EE6, 0x6C129E49B18BA1C4), (0xC081545F698A808C, 0x0DEF1716AD87EBE6),
+	(0xC00A3C282121B47C, 0x3FA3471A27778735), (0xC076899A41C59618, 0x1F6B2B7DFA89A268),
+	(0xBFF1B50D65E08A7A, 0x3FD52954671A7571), (0xC02DCB4A122B6BE8, 0x3E96C13C3307BF06),
+	(0x4064A787D2F02F3C, 0x4ED4E1CDDF7557E5), (0xC010D096C70C00D8, 0x3F8E98F6C7FCA0B5),
+	(0xC0726B18F151DAAE, 0x255CC7CEF1A4556D), (0x407E2D84FA227F18, 0x6B7837EE98EA437C),
+	(0xC00BECFF29719842, 0x3F9F35DB7F253D40), (0xBFE6BF9A65AA7498, 0x3FDF6FF0B8506984),
+	(0xC040E9B4A93C5798, 0x3CE25FEC5AC0EB68), (0x402ED40675460700, 0x4152DE2F42139A0C),
+	(0xC00E2BA809566360, 0x3F97930258A4F532), (0x407076F52232C71C, 0x57B0A28D788E5BAA),
+	(0xBFB8AA86FF6A4DD8, 0x3FED0F848B01693C), (0xC05C72267A63411E, 0x35ACBD549C644FDB),
+	(0x404AB7D8CC8F4894, 0x44C10E963CFE7C96), (0xC0593DE514BDB700, 0x36D42F84499DB634),
+	(0xBFC8285CA0B66FC8, 0x3FEA7F0BE118AB78), (0x40214A1851017CB4, 0x40B6300EEA78C410),
+	(0xBFDA7DA537A5FA2A, 0x3FE5275C0D80E602), (0xBFD05C169A385AD8, 0x3FE8C82D6922B587),
+	(0xC07D7456AD694FDA, 0x15712441E3D860F7), (0xC0096C543F8BC240, 0x3FA5563351E94705),
+	(0xBF5BAA62C2E6E000, 0x3FEFF22DCB928BDF), (0x405C384EE2F521FA, 0x4A1CDCED3D6C854B),
+	(0xBFCBEF7B662C378C, 0x3FE9B9C6A172C95D), (0x3FF5E226B2C756D0, 0x400F692F39339DD2),
+	(0x4073037CC0FE9598, 0x5B5DBA4E2A64AC2B), (0xC0111CA335BFBECE, 0x3F8C6850E2E63A38),
+	(0xC05CB6A35CACA7B8, 0x3593B6A3033C98D5), (0x4044AB71F413A64A, 0x43A8EFAA21950D14),
+	(0x3FD72FE3A4964004, 0x3FF6FC6C79AF4E03), (0xC023F981DB584620, 0x3F081B3D5D42D846),
+	(0xC000EC6C73EA5D60, 0x3FBEDE55F257E383), (0xBFD130FCA6D1BEC0, 0x3FE87645C4D62064),
+	(0xC0343A26CC9BD954, 0x3E1C370294BF9998), (0x402EFADA369B5530, 0x41545AB9995AB205),
+	(0xC066B199BABBFCF8, 0x2F90E59FBADF504D), (0xC012B34EC44E6A98, 0x3F8318BF2E4D6655),
+	(0x3FFBC79885ED410E, 0x4016B41F93B5AD97), (0xC06F61CA5D3CA864, 0x294BE9839FB8D46B),
+	(0xC0393B6748AD7C4C, 0x3DA83725D653F53C), (0xC082CAC9C7C1FEB0, 0x09B5AAE4C8930566),
+	(0xC0836B587BA1D2FA, 0x07E65CE9A2F724A2), (0xBFE9C12DA94DF0C8, 0x3FDC9E52F9645C83),
+	(0x3FE6F4B38F50A010, 0x4000646E6C97AE71), (0xC063E95345134EA0, 0x31924405816D47CC),
+	(0xC001B0AA241876D8, 0x3FBC0C463B659AE1), (0xC00EB3DE028018C0, 0x3F960EAEAE3CDB70),
+	(0xC03B20B87B4D6538, 0x3D7D190662992A60), (0xBFFC6F67606BA4A0, 0x3FC5A57068DDCB7D),
+	(0xC007150143F05BF0, 0x3FAC971F691E5A8F), (0x3FA0FF57EFE86040, 0x3FF08A430575FAED),
+	(0xBFFB3CC421364690, 0x3FC754359D537E8E), (0x3F9F7988A4BF8180, 0x3FF07FDA94365598),
+	(0xC03DEFC9D935A1B0, 0x3D3C0FB087CEC962), (0xC086BD7ABBD16F6A, 0x00000000012086E6),
+	(0xBFD728C17A8545C8, 0x3FE648BAB39E6CCF), (0xC02E222D63A78108, 0x3E9334016AA991BD),
+	(0xC04AB40E0297267C, 0x3B1EEB6CDBE6813A), (0xBFC6E50B4A990ECC, 0x3FEAC24E1D79AC4E),
+	(0x4074ACC2EAFD0B12, 0x5DC2E579EA1CC07C), (0xBFFA4B948C112780, 0x3FC8BE6EB3B450FC),
+];
+// (input, output) f64 bit representations
+pub const STRICTMATH_ATAN: [(u64, u64); 256] = [
+	(0x9D4ACA70D7A4A523, 0x9D4ACA70D7A4A523), (0xD15B13C0D494CC3F, 0xBFF921FB54442D18),
+	(0x982828B86061F6E7, 0x982828B86061F6E7), (0x4BF8EE5213E35AD1, 0x3FF921FB54442D18),
+	(0x3DD747A9AD5F8030, 0x3DD747A9AD5F8030), (0xC6482F2319CC3791, 0xBFF921FB54442D18),
+	(0x08B55035AA20FAC5, 0x08B55035AA20FAC5), (0x788B7D8D2EC5E1C9, 0x3FF921FB54442D18),
+	(0x5D01DE1323E302C5, 0x3FF921FB54442D18), (0x851A5D21EE4B0CA3, 0x851A5D21EE4B0CA3),
+	(0x489D1CF43FBA3DC7, 0x3FF921FB54442D18), (0x8DFF3000EEA914EE, 0x8DFF3000EEA914EE),
+	(0xFC6E04F9585B5043, 0xBFF921FB54442D18), (0xB9C28A6BDD198F22, 0xB9C28A6BDD198F22),
+	(0x1C8B6A6795A2452F, 0x1C8B6A6795A2452F), (0xA25FAB971BD831A1, 0xA25FAB971BD831A1),
+	(0xAEB9D49C48364BED, 0xAEB9D49C48364BED), (0x3CC72FC243369F3F, 0x3CC72FC243369F3F),
+	(0x017B503D6210F064, 0x017B503D6210F064), (0xA0124F8936C3D604, 0xA0124F8936C3D604),
+	(0x300DB0B1077E316B, 0x300DB0B1077E316B), (0x32DA407A54B54630, 0x32DA407A54B54630),
+	(0x6C86F4D08D9D196E, 0x3FF921FB54442D18), (0x804C8AE86C1BD57E, 0x804C8AE86C1BD57E),
+	(0x7006C2DE91BEBD4B, 0x3FF921FB54442D18), (0x36CE68FDEE4F52AD, 0x36CE68FDEE4F52AD),
+	(0x04EF2FB7927BC57D, 0x04EF2FB7927BC57D), (0x548E83164D9F1D3B, 0x3FF921FB54442D18),
+	(0xAF8645456636E57B, 0xAF8645456636E57B), (0x9C859D4AC6FF0573, 0x9C859D4AC6FF0573),
+	(0xD984FC368544E562, 0xBFF921FB54442D18), (0x3D5C0A4C4A53A8AA, 0x3D5C0A4C4A53A8AA),
+	(0x5C2F9CF2B65921D1, 0x3FF921FB54442D18), (0x54B2F772B571CDC4, 0x3FF921FB54442D18),
+	(0xCDDEBBC8BC5FA613, 0xBFF921FB54442D18), (0x39ADE614C51885FD, 0x39ADE614C51885FD),
+	(0x029F446C0BDC4483, 0x029F446C0BDC4483), (0x5A89E61AA56615E6, 0x3FF921FB54442D18),
+	(0xDF03489DCD78512C, 0xBFF921FB54442D18), (0xEFD565D0D4C3EAAC, 0xBFF921FB54442D18),
+	(0x07A48629F1DEE2AF, 0x07A48629F1DEE2AF), (0xDDB4AAFC89461FA0, 0xBFF921FB54442D18),
+	(0x6A74D81762A1BD69, 0x3FF921FB54442D18), (0x2DCC49332D3F57F2, 0x2DCC49332D3F57F2),
+	(0xD808071A5ADF4CD4, 0xBFF921FB54442D18), (0x8C2C29680C91F0C0, 0x8C2C29680C91F0C0),
+	(0x55C5AD4C3B044F2E, 0x3FF921FB54442D18), (0xE42AF6C46D53CF4F, 0xBFF921FB54442D18),
+	(0xE5A9211C338A1757, 0xBFF921FB54442D18), (0x78CB04BB0BEA4F97, 0x3FF921FB54442D18),
+	(0xD0209B85853C88FF, 0xBFF921FB54442D18), (0xCC3F025E11181704, 0xBFF921FB54442D18),
+	(0x17FC0D83D0A13300, 0x17FC0D83D0A13300), (0x1CB60EECDFCECF7A, 0x1CB60EECDFCECF7A),
+	(0x6A6F58C4A3CB2C07, 0x3FF921FB54442D18), (0xBEFC03B095330E9A, 0xBEFC03B095166DF6),
+	(0x4D32DF9BAC7D957A, 0x3FF921FB54442D18), (0xDAF407085760ED43, 0xBFF921FB54442D18),
+	(0x6E08A03A5638E895, 0x3FF921FB54442D18), (0xFFC0BCC0EB64135B, 0xBFF921FB54442D18),
+	(0x289B4F2036ED71D6, 0x289B4F2036ED71D6), (0x3ECEA7A8D8BE3BCF, 0x3ECEA7A8D8BDA5C5),
+	(0xAD1024CE33CF9E9B, 0xAD1024CE33CF9E9B), (0x632A90663D0374D7, 0x3FF921FB54442D18),
+	(0xB1FB52C050710108, 0xB1FB52C050710108), (0xE6D705827CC3A07E, 0xBFF921FB54442D18),
+	(0x82F2539A5F0B2E2E, 0x82F2539A5F0B2E2E), (0x477148CA1C6CF961, 0x3FF921FB54442D18),
+	(0x38F68AA16817DA69, 0x38F68AA16817DA69), (0xAFA73DD9244B9FB6, 0xAFA73DD9244B9FB6),
+	(0x5EA5E66D5B23D809, 0x3FF921FB54442D18), (0xC9052DEEB2867290, 0xBFF921FB54442D18),
+	(0x86C67ADC1980DC33, 0x86C67ADC1980DC33), (0x3ABB75067539B960, 0x3ABB75067539B960),
+	(0xFB9D4649F7542E09, 0xBFF921FB54442D18), (0x7B696C2729D461BF, 0x3FF921FB54442D18),
+	(0xA7CF95FD96BEA408, 0xA7CF95FD96BEA408), (0x5EFC24F965E4D569, 0x3FF921FB54442D18),
+	(0x6191BDE655BC93B2, 0x3FF921FB54442D18), (0x3384CA9994CEAC8C, 0x3384CA9994CEAC8C),
+	(0x7DDD17872022976C, 0x3FF921FB54442D18), (0x69D3BBB17DB2C043, 0x3FF921FB54442D18),
+	(0xDE927C2E2E064EA8, 0xBFF921FB54442D18), (0x1DE2B1C2D167615C, 0x1DE2B1C2D167615C),
+	(0xDB0DAE6B4A307C46, 0xBFF921FB54442D18), (0x58E989C639F6B761, 0x3FF921FB54442D18),
+	(0x720166BE8E282737, 0x3FF921FB54442D18), (0x709E81F377F58BD6, 0x3FF921FB54442D18),
+	(0x1CBAF793D83CA163, 0x1CBAF793D83CA163), (0x5DDC00E0166505BE, 0x3FF921FB54442D18),
+	(0x8A67BC6C85EE98CD, 0x8A67BC6C85EE98CD), (0x85F339CCB7F61964, 0x85F339CCB7F61964),
+	(0x1CE5C85C807696F3, 0x1CE5C85C807696F3), (0x0E94423C90ADB057, 0x0E94423C90ADB057),
+	(0x8B4C87BD5C29927A, 0x8B4C87BD5C29927A), (0x11A96CC82154CDB5, 0x11A96CC82154CDB5),
+	(0xD7F149180462770A, 0xBFF921FB54442D18), (0x9E0CFD2F1F1032AC, 0x9E0CFD2F1F1032AC),
+	(0x4B76E16A26A1C777, 0x3FF921FB54442D18), (0x70DD89B0BD2F473E, 0x3FF921FB54442D18),
+	(0x53B6DF89A702BE20, 0x3FF921FB54442D18), (0x5AA69D9C79FA4B43, 0x3FF921FB54442D18),
+	(0x9F4602E2E0378F9C, 0x9F4602E2E0378F9C), (0xC8D87125854413B4, 0xBFF921FB54442D18),
+	(0x2C3CE96A3034B392, 0x2C3CE96A3034B392), (0x7862CA7BC903D825, 0x3FF921FB54442D18),
+	(0x0E0928CB62CD57E7, 0x0E0928CB62CD57E7), (0xC6F45C1B3D7FD493, 0xBFF921FB54442D18),
+	(0xA99A46E55B01621F, 0xA99A46E55B01621F), (0xDEA1345F3BD9C360, 0xBFF921FB54442D18),
+	(0x04814577FCDFA2FC, 0x04814577FCDFA2FC), (0x58C81258EF5A739A, 0x3FF921FB54442D18),
+	(0x69D0B51CC2E5206B, 0x3FF921FB54442D18), (0x4A37B3E557E9FF83, 0x3FF921FB54442D18),
+	(0x31F68375B4EC99A0, 0x31F68375B4EC99A0), (0xA11270F9CAF6674D, 0xA11270F9CAF6674D),
+	(0x78A7F8A5224FA97F, 0x3FF921FB54442D18), (0x95FFB842CBE29425, 0x95FFB842CBE29425),
+	(0x408B9800AF0D2B3D, 0x3FF91D57D098E8D0), (0xC32040EEFE340772, 0xBFF921FB54442D16),
+	(0x26D3D806FADEC547, 0x26D3D806FADEC547), (0x653305C3EA55BA5E, 0x3FF921FB54442D18),
+	(0xC4B8E58D9361BE62, 0xBFF921FB54442D18), (0xDBA65D7A262AF194, 0xBFF921FB54442D18),
+	(0x10EEE63D8E158113, 0x10EEE63D8E158113), (0xBC31C4460515094B, 0xBC31C4460515094B),
+	(0x13196E4246347140, 0x13196E4246347140), (0xA34A2AB45BBB7D83, 0xA34A2AB45BBB7D83),
+	(0x3FAC283A31C8FC00, 0x3FAC20F941E639C6), (0x4040C56CAA6F3966, 0x3FF8A7E75B45EB51),
+	(0x3FF114970802A41C, 0x3FEA2D716A39DB51), (0xBFFFF1509D985360, 0xBFF1B3F09E0ABFD0),
+	(0x4049C9EDD3D61B82, 0x3FF8D293CC6E6475), (0xC052842BC0227820, 0xBFF8EAAEB62BD59C),
+	(0xBFE8B002A676A8BC, 0xBFE5070D0ADDF86B), (0x3FD7AAD0DAF201C4, 0x3FD6AB4A0FD1AEA9),
+	(0xC06542C43B4CA2CC, 0xBFF909E670F86E53), (0x3FF2B22030F112D4, 0x3FEB9D35ADFB9245),
+	(0xC083ECC1A1BA2F16, 0xBFF91B8EBFD38574), (0xBFF41D5CABA42E70, 0xBFECC3528DA070ED),
+	(0x3FDAC2C156840044, 0x3FD958C5AB98112D), (0xC06196793A3C86EA, 0xBFF904DF02B8C017),
+	(0xC06980C799397C40, 0xBFF90DE7E7DDDDC4), (0x4005A3661F23ABBE, 0x3FF37777BEF40642),
+	(0xBFEE9E71E7A6B6A8, 0xBFE86D55AB3BD945), (0xC08CFAA5AE6F4434, 0xBFF91D9095522F9D),
+	(0xBFB0E069877DB0F0, 0xBFB0DA2B6DDEDD6B), (0x3FFFECE71604BC50, 0x3FF1B30DF6E645F8),
+	(0x3FD785868A9C2B20, 0x3FD68A75F809DA38), (0xC062F038BE734A74, 0xBFF906F273CEA1A2),
+	(0x404E2EF99540A8E6, 0x3FF8DE22E5328635), (0xC04B17EC535CB4D0, 0xBFF8D6666D788A16),
+	(0x4018078295537A00, 0x3FF67E57FD524DFC), (0xBFF84577D2795A34, 0xBFEF9D769DAACF93),
+	(0xBFFD3EF50DEBF6AC, 0xBFF11F83D3E57CF1), (0xC03011ECCEB966B6, 0xBFF8236CE85941EE),
+	(0x405F61A3E856E384, 0x3FF9015A06485982), (0xBFD50520D9B38B8C, 0xBFD44F455822FCC2),
+	(0x3FAACEAEE2D91420, 0x3FAAC86C218947B0), (0x400292E8B09A8EA8, 0x3FF2A0240CDA04C2),
+	(0xC02CC039405FB21E, 0xBFF8058271B2819B), (0x405B033173F9715C, 0x3FF8FC130D6E27E2),
+	(0xC06D16D5027CC512, 0xBFF910617D83540B), (0xC006024F804C2E5C, 0xBFF38DF4893F5658),
+	(0xC04C6ABB5B291E28, 0xBFF8D9EB61698AD9), (0xC03778B742EC898C, 0xBFF87393FBC63CB4),
+	(0xC069F8D243D453C0, 0xBFF90E44B1C497D7), (0x40289F4AAA769968, 0x3FF7D600CF08D4B2),
+	(0x4080558937A20954, 0x3FF91A25398A6D7B), (0xC073F25F5364BAA0, 0xBFF91525CB823AE8),
+	(0xC05CF647CF78AD48, 0xBFF8FEA04178308E), (0x405E590B18676966, 0x3FF9003D8AA5D60B),
+	(0x403B64F6902EF670, 0x3FF88C874787ECBA), (0x3FF838B18E17CAAE, 0x3FEF95B67E6E2349),
+	(0x40601AED62B2776C, 0x3FF90230FED43FFD), (0xBFFCA62D41284880, 0xBFF0FBC2ABBC8CB7),
+	(0x40285394AED5B5D0, 0x3FF7D1FC32F8FCCA), (0xC01FABBFB02B2296, 0xBFF71F62CEF37F24),
+	(0x40492416A272CB9C, 0x3FF8D088263E504D), (0x3FE73100FA4CC200, 0x3FE4117810548B36),
+	(0xC08DF87F626EF0C0, 0xBFF91DB5FEAE05DB), (0xC00EDF1575CF3E2A, 0xBFF5136494509D93),
+	(0xBFD8C598E7DA71CE, 0xBFD7A2A4E2A1A95B), (0x404B15E6600571C0, 0x3FF8D660C8780E70),
+	(0x4025A360F57BE634, 0x3FF7A876F2002507), (0x3FFE2E618C1047BE, 0x3FF15556F6125C28),
+	(0xC034BBDAA610C480, 0xBFF85C953BECC0A5), (0x4078CE9ADE2C3EF4, 0x3FF917A981534D37),
+	(0xC044CD6267A827C8, 0xBFF8BF8CCB0F6C93), (0xC08A3D109A6850FE, 0xBFF91D1A7AC21B8B),
+	(0xBFC22881224552E0, 0xBFC209B14C9A6BD0), (0xC08B56B3AF2E221E, 0xBFF91D4CBC1EB649),
+	(0xC00FB9D3C7448F82, 0xBFF52E39F8661265), (0x401B5D54BE0834DA, 0x3FF6CF76420C6745),
+	(0x401C6FFC28F8CD18, 0x3FF6E59859273446), (0x4024EEF3012EC3DC, 0x3FF79BD45F2A0ABA),
+	(0x408696081EB7F1A0, 0x3FF91C508617AC6F), (0x4024189870160AF2, 0x3FF78BAD8A016319),
+	(0xC061BF5F15579DDA, 0xBFF905221774AA56), (0xC0151C17C569AE12, 0xBFF622F0E2976A46),
+	(0xC02F0F1C3DFABD78, 0xBFF81A96EC113C64), (0x404F222A7D368130, 0x3FF8E034C29255F7),
+	(0xC0410489A0DA7412, 0xBFF8A9ABD766877B), (0xC00F93C3539CCAE0, 0xBFF529A7B3ABB0B9),
+	(0xBFE94CB100BDF608, 0xBFE5685DF985B03A), (0xC0082ABFE38DD308, 0xBFF4049692C7327A),
+	(0x401F8F1E851214C0, 0x3FF71D94E7222A3F), (0xC02B093A20B7B600, 0xBFF7F3879D16AD3A),
+	(0x406B1A7BC0CC5B30, 0x3FF90F175FC405E7), (0xC0778F1E5A489374, 0xBFF9171D8F7CECFC),
+	(0xC068BDC4296A58B2, 0xBFF90D49AB0CA4C6), (0xC08A56BBDAD82982, 0xBFF91D1F3BD887A0),
+	(0xC0195496A8E2E61C, 0xBFF6A077CE282327), (0xC03751E878A8BBEC, 0xBFF872721AC84758),
+	(0xBFFB2BEB3721D4FC, 0xBFF09E2AB00BCFDE), (0xBFEE960F018975A0, 0xBFE868F4519DDF01),
+	(0x400DD0EE3C22A798, 0x3FF4F044BD90A666), (0x40529952711CA30A, 0x3FF8EAED977A6479),
+	(0x4079F62C58FCF4AE, 0x3FF9181EFDE50446), (0xC02EFAC3107EB248, 0xBFF819EA6565C0C5),
+	(0x3FF82B146B299E4A, 0x3FEF8D6DBB9D7781), (0xBFDA1085C98F25EC, 0xBFD8C07A8B2C100C),
+	(0x4014304E223D9CF6, 0x3FF600D07582332E), (0xBFE668FB16518250, 0xBFE38CCC86B916B2),
+	(0xC0305592E6B97A82, 0xBFF82788830C28C7), (0xBFD51236D7DFAE48, 0xBFD45B1470155047),
+	(0x4002639E1C136C34, 0x3FF291370C052405), (0xBFC16478F68FAD70, 0xBFC1495EBEB80234),
+	(0xBFE2125331964536, 0xBFE07366CFD12DF2), (0xC0405CB025441DB8, 0xBFF8A4DA64E38E8A),
+	(0x3F79B39CDE682900, 0x3F79B386C34722B1), (0xC0638ED9131331B0, 0xBFF907CDB66D1540),
+	(0x3FDD797A34D1B2B8, 0x3FDB9F11D144C380), (0x40041D630E8B0A18, 0x3FF3137E945D378A),
+	(0x3FE5DF427D4D95A0, 0x3FE32FAA1068CF72), (0x406160E1E8B484B2, 0x3FF904853E4B0036),
+	(0x401CDB2A0D25195E, 0x3FF6EDCB5CD32E3B), (0x4081E5E11A2CBB7A, 0x3FF91AD4822ACF4A),
+	(0xBFE7FCCA842E4680, 0xBFE49581C9F5521E), (0x3FD9015A83874938, 0x3FD7D68CED0564FA),
+	(0xC076358D305CD3EC, 0xBFF916747C607754), (0xC05741D9F823337C, 0xBFF8F5F4423756F1),
+	(0x40718C00B950E212, 0x3FF913646FEF8B4B), (0xC00DFC22B550038C, 0xBFF4F60A7EE383A3),
+	(0x403F96DD8D07F5DC, 0x3FF8A05C67B36F24), (0x3FE9EC851B310064, 0x3FE5C9CA28DFD083),
+	(0xBFEA4472B9D10F4E, 0xBFE5FE9951B418C2), (0x3FF7C40C7E13FDCC, 0x3FEF4DE7083814A9),
+	(0x4023AECFF321619A, 0x3FF78334A7593707), (0x3FD19534EEFDEA98, 0x3FD128D3CE8873E2),
+	(0x3FFF2B600F857090, 0x3FF18B74C34EF23F), (0xBFCAADFDFD20C7B8, 0xBFCA4D954C3A6A00),
+	(0x4018C4D8FF59B1D0, 0x3FF6922B8AFCF6CE), (0xBFD46583FEE0DF90, 0xBFD3BEC92BAFC2B2),
+	(0xC00BBD39BA5EA310, 0xBFF4A3E64ED93642), (0xC032CE8189EDBD74, 0xBFF848643B3BAE1C),
+];
+// (x, y, output) f64 bit representations
+pub const STRICTMATH_ATAN2: [(u64, u64, u64); 256] = [
+	(0xA0579B840FA25B09, 0x44B54A5948E9B64C, 0x9B91BDC59C5D9674),
+	(0xBEAB9678A931B02C, 0x40E6D18A029E095D, 0xBDB3581975893DF9),
+	(0x00E8DDE817CD1E13, 0x476C922267F56D15, 0x0000000000000000),
+	(0x17688496F974E779, 0x73E4834E34C1FE0E, 0x0000000000000000),
+	(0x51523C760DBC3CBA, 0x12FB763E8D99B155, 0x3FF921FB54442D18),
+	(0x074138E04FF6AACA, 0x7A648C0BAE4E5E81, 0x0000000000000000),
+	(0x5FDE71210E590567, 0x7437A5D61313D707, 0x2B9498CC58371953),
+	(0x677E073817024092, 0x6E04332C651F9ECD, 0x3967C8EAEF71539A),
+	(0xD065D6DD4A33ED3A, 0x88D802B0FC3319FC, 0xBFF921FB54442D19),
+	(0x573F70C461494D3A, 0x494FF8FC2C3A441A, 0x3FF921FB54442D18),
+	(0x86980622AF773286, 0xE7C2D1BF16364457, 0xC00921FB54442D18),
+	(0x01750871AB4E1DA0, 0x6EF44F2A1F4C7326, 0x0000000000000000),
+	(0x302C8170819B4F4F, 0xE5C17B912D47A50F, 0x400921FB54442D18),
+	(0x001C676F12DCC193, 0x841CE14B0E62CEC3, 0x400921FB54442D18),
+	(0xBECC71C807473BD3, 0x67BBDCDD10115670, 0x97005583DCCB1DCF),
+	(0x17BA31AE099CF658, 0xBF446D585EEAA656, 0x400921FB54442D18),
+	(0x09C7732C5A0680FE, 0xF5DD26FECE23D49A, 0x400921FB54442D18),
+	(0xB23F2A685F06E128, 0xF2F72C2BA05B745A, 0xC00921FB54442D18),
+	(0xFFE571286EBF1AF4, 0x0D445072BB28E401, 0xBFF921FB54442D18),
+	(0x74CB5895FD4499AF, 0x4A49CA06C421ACDB, 0x3FF921FB54442D18),
+	(0xC9E79346A1112B8B, 0x0022155C461CC7EB, 0xBFF921FB54442D18),
+	(0x88013B78DBCA848F, 0xE306EF0D81A4DBFC, 0xC00921FB54442D18),
+	(0xFE8A399C670A7CCE, 0x0E668800A107C8F5, 0xBFF921FB54442D18),
+	(0xAEDB0F1379B07DDB, 0x2FD557C7F0831471, 0xBEF448FACA2CA474),
+	(0x911A093B55D7D37B, 0xC36031498AF20B8F, 0xC00921FB54442D18),
+	(0x143420AAA915D39C, 0x52D87DA1CC5BAE05, 0x014A4C8E30B65963),
+	(0x73EE08BD4B5E1186, 0x0EEEC1474B0BB67B, 0x3FF921FB54442D18),
+	(0xCB78334C941A1050, 0x6F8DCF6C26C35416, 0x9BD9FA62535BAF8B),
+	(0x0058CCC063E52894, 0x032B4FA911436952, 0x3D1D0EB728AFD9DC),
+	(0x5D2AF447AB349565, 0x48E879DA853B6414, 0x3FF921FB54442D18),
+	(0x15E2B4655E3EDC81, 0x99A82C477DC502FF, 0x400921FB54442D18),
+	(0x5C397FAD9BF505C3, 0x5EEDC0048A469C06, 0x3D3B6D5A0CA99790),
+	(0x8ADF35D1341355DA, 0x65AA3F9B061AB841, 0x8000000000000000),
+	(0xED47F4D68676803F, 0x2553E3E751F51904, 0xBFF921FB54442D18),
+	(0xF8AF3AABF73D8464, 0xA2FDFB424BEA7A2D, 0xBFF921FB54442D19),
+	(0x5D6D1ADE670FAA29, 0xDAE59EE19B81A5BE, 0x3FF921FB544438FB),
+	(0x26428CA578615193, 0xA349DA803E89ACAB, 0x3FF921FB54442D2F),
+	(0x620E1522110989BF, 0xFFAC2A39B2DA58F3, 0x400921FB54442D18),
+	(0x53AA51EAD625A314, 0x360072BA0DE12CF4, 0x3FF921FB54442D18),
+	(0x5FCC27C7266730B7, 0x6621D795CE2E83FB, 0x39993F9A6EA3C976),
+	(0x71CE99B4D7BC2D43, 0x495413F88A5BA81A, 0x3FF921FB54442D18),
+	(0x22B68C33300836B9, 0x61EF39833CDBF13E, 0x00B71B879EC5F2D7),
+	(0xE198F8BBE0A115B5, 0xACCDF96D8AD84180, 0xBFF921FB54442D19),
+	(0xC632559158B003E3, 0xA4F4DBF1565572BB, 0xBFF921FB54442D19),
+	(0xAE2635BC5EBC65B0, 0x906B09E856DB895E, 0xBFF921FB54442D19),
+	(0x274A655154BBEB44, 0xF1A38CB390DCC082, 0x400921FB54442D18),
+	(0x55D105F6CF4F4CAE, 0x1560285EE078C33E, 0x3FF921FB54442D18),
+	(0x20596FA5AF921561, 0xE7E534038929A73A, 0x400921FB54442D18),
+	(0x1E27D9B0DA5732DE, 0x07E8146CCEAD7C82, 0x3FF921FB54442D18),
+	(0xF94010070F133AB2, 0xD8183CFD0D58869C, 0xBFF921FB54442D19),
+	(0x9F1FB8B48F509BA3, 0xA3BCDACFF62972DC, 0xC00921FB54442D18),
+	(0xEB98EDADFC992E26, 0xB5DF8E206EBA1FF5, 0xBFF921FB54442D19),
+	(0xAA162ECD8183D813, 0x171ED5E9E3BCCAD3, 0xBFF921FB54442D18),
+	(0x569CF01C6A4C379B, 0xFAC911D1775EB4A8, 0x400921FB54442D18),
+	(0xF174484F5D458979, 0x528B3E4088E69645, 0xBFF921FB54442D18),
+	(0x42A983937AD93E67, 0x870CCED7617674AA, 0x3FF921FB54442D19),
+	(0xDE049E8B92D4F3D3, 0x95844AFEF03DAADF, 0xBFF921FB54442D19),
+	(0x209AED9A08D2C3D2, 0x54E690DE17E13D05, 0x0BA317CDA81B25B4),
+	(0xCF4E745EDEC22054, 0x130CE2721CADC8A7, 0xBFF921FB54442D18),
+	(0x456CB17D0A46AD99, 0x214C20F7D887A655, 0x3FF921FB54442D18),
+	(0x9C87E2A3CB05CFE3, 0xFAB9DA8CDBC31073, 0xC00921FB54442D18),
+	(0x7ED204CB3452E06D, 0x03020E180A57634E, 0x3FF921FB54442D18),
+	(0x8BF114BBBF353D9B, 0x2D7AC966607DCA6F, 0x9E6467C353BC82BC),
+	(0x41B853D631C2AA72, 0x47510595B9FCEF34, 0x3A56DDFB24043FD7),
+	(0xBFD8555AB26844EC, 0x3FD9DF196F1687C8, 0xBFE82717829ECD3E),
+	(0xBFC00D14F5202048, 0x3FD4776EEB9B48C6, 0xBFD7EAC39A115D8C),
+	(0x3FDFD62A69543E1E, 0x3FC9633BBA3D4A28, 0x3FF30FF4F73A2286),
+	(0x3FD9898CDFCDB7AE, 0x3FC5B2B21A399258, 0x3FF2B4825BCFE0AA),
+	(0xBFD9768FFC844D7C, 0xBFDDE22A02BF0326, 0xC0037CB4ABAF77A6),
+	(0x3FD02DCDA930A4B6, 0xBFBE9F45CDD33C70, 0x40001A212D1836EC),
+	(0xBFBB0A14FA99DFB8, 0xBFD7D03F452BC27E, 0xC006EB863D4D6282),
+	(0xBFDE061ADF5BF33E, 0xBFC4983316AAEA4C, 0xBFFE6B4AA6E982CB),
+	(0xBFD648A48F72E9FA, 0x3FB3FBB68F6CC3C0, 0xBFF59AA21AEE53E6),
+	(0xBFBCE640E4AA3DF8, 0xBFD4D43601D8CFF8, 0xC0067631EEA6C499),
+	(0x3FBE2C59F60994A8, 0x3FB99A249DD4DBC8, 0x3FEBBFDE42AEF442),
+	(0x3FD1273255FEC02A, 0x3FDDB722B45A8062, 0x3FE0C0AFE0055C15),
+	(0x3FCE509CDCB62E0C, 0x3FD8E6CC776CA49C, 0x3FE17F49E6688F2C),
+	(0x3FD743573C058D22, 0xBFDDE6736CF92F46, 0x4003D7DA260BFC9E),
+	(0xBFC735989963EBF0, 0xBFC80F5C2A4AAE88, 0xC002FE565AAB461A),
+	(0xBFDDB3D06260375E, 0x3FD0236C3EDC2DB2, 0xBFF12B5A4498E2A8),
+	(0xBFBDCF21D11E9570, 0x3FDAA9CCEB2C553C, 0xBFD171507A9AAFD3),
+	(0xBFD5A107993D5096, 0x3FD9FF09072B9398, 0xBFE634DFFA2679FA),
+	(0x3FC00205BF9CC310, 0xBFDE0F59B9579276, 0x40070D08B1D160AA),
+	(0x3FD1AE237494825A, 0x3FD6FACB3A423B56, 0x3FE4FC41BC8ABDC0),
+	(0x3FD9A131A20F4EB6, 0x3FA527E15F4CD030, 0x3FF77CD898F0F8A1),
+	(0xBFD7215B387253DC, 0x3FDED11DCF7A9C54, 0xBFE49A903E5CBF8E),
+	(0xBFB7AAB7BAB7F918, 0x3FD3B1862094A05C, 0xBFD2ADDA56A747D2),
+	(0xBFC0D4794D2C1818, 0x3F9FE752224C3660, 0xBFF568FD746CBA5C),
+	(0x3FCEAD4F38EB7D88, 0xBFDE70223519BF00, 0x4005660291CD2F22),
+	(0x3FB50FBB27F97438, 0xBFA5D03988230A70, 0x4000639423A53EA6),
+	(0xBFC6839C39153E44, 0xBFCA8CABFCEF4CF8, 0xC003818D705556C4),
+	(0xBFDCB6E82803AC7A, 0xBFB7AABA543C85A8, 0xBFFC625415B4A657),
+	(0x3FD601784ECFA908, 0xBFDAB8CFF88E13F6, 0x40039F16F1CC1ADC),
+	(0x3FD09F0E071EC5DA, 0x3FA840DAED685C70, 0x3FF63F025E69460F),
+	(0xBFD86E674A14B596, 0xBFCEF8727B2CA8AC, 0xC00115F422710B29),
+	(0xBFBEE67E0F38D188, 0x3FC6B6F9386AE24C, 0xBFE31D18A68E3122),
+	(0x3FB3846B3D454DD0, 0xBFC45EA0ABD2A458, 0x40058EFF5039A7DA),
+	(0x3FBB090CA365E4A8, 0x3F9B25EB0BD7DD60, 0x3FF53287B638720A),
+	(0x3FD925071A4C940A, 0xBFD1412DECA11F3A, 0x400160AFA0E4BA6D),
+	(0x3FDFFFF7FCF0803A, 0xBFDDE532D442C810, 0x400293E06E0E703A),
+	(0x3FB4D708A2C282B0, 0xBFD52823E7D9191C, 0x4007337CEAC2CE95),
+	(0x3FDE163BD9CB3D9A, 0x3FD8D4F767FA678A, 0x3FEC2F7624CEA22E),
+	(0xBFC577A8CD81DAC4, 0xBFC3C173E34A11B0, 0xC002847E7F656BA4),
+	(0xBFCB4D8FF6C05864, 0x3FBBB15BDD185580, 0xBFF19F8E17BE8399),
+	(0x3FC64F02DEAB4A14, 0xBFD61C44D5839D70, 0x40056519CD0D5CC8),
+	(0x3FB53DDB831FCAD8, 0x3FDB43799843FEC2, 0x3FC89FA23E04072D),
+	(0xBFD9E7791CBC30CC, 0x3FD4096549C35E76, 0xBFED329D9F44255C),
+	(0x3FBC91758213B100, 0xBFC37A2122B4C314, 0x4004120CAAF2A6D4),
+	(0xBFD9419028258468, 0x3FCB0B3AAC71680C, 0xBFF1448E2A6113FB),
+	(0x3FC2895036C56C04, 0xBFC82845E2532AB4, 0x4003E5941A2C01BD),
+	(0x3FD6820FB57C7C14, 0x3FBD51FC8FFBD288, 0x3FF4186D927DB17D),
+	(0xBFCA3D27F76B1028, 0xBF78539EF5460F00, 0xBFF9989EC16F079C),
+	(0x3FD32BCCA12A5B16, 0x3FB70386E95B4D20, 0x3FF477C280CD4F82),
+	(0xBFD2D9E3A136B810, 0xBF9897F02C7CF8E0, 0xBFFA6F39FE8A322B),
+	(0x3FC7C1AD9D705374, 0xBFC7319956837A0C, 0x4002C0EFFA364792),
+	(0x3F8FC25A60765440, 0xBFD7567E02F26D8E, 0x4008CAF0AF2638B1),
+	(0xBFB56F739DC05418, 0xBFCC288C4E440BB0, 0xC006392533905AF8),
+	(0x3FCEAC3B148561D4, 0x3FD7EF30519DE07E, 0x3FE23C48AA0F9850),
+	(0x3FD58FAAE5173BD4, 0xBFD71349AB3304E6, 0x40031EEE59876A48),
+	(0x3FCBAD1F45F5554C, 0xBFDF836D2045E6C4, 0x4005D2956726E554),
+	(0xBFC5EB22BBFA4F28, 0x3FB123B75A170548, 0xBFF32B5DB0F9603A),
+	(0x3FB4895AEA849940, 0xBFB12369F1B3F358, 0x40022142426B641D),
+	(0xBFDFB042EF6961BE, 0x3FBD8C5404F2CFE0, 0xBFF577E64EE43E4A),
+	(0xBFCA90D894CC74A8, 0x3FDF4EE7C1CB5D74, 0xBFD9AE029571A043),
+	(0x3FD7AC3E9304AB48, 0x3FCF35411F36FA84, 0x3FEF9DCEDA3BA1F7),
+	(0xBFD861C7FE08D090, 0x3FD1000E80B845F2, 0xBFEEC80EC959CA57),
+	(0x3FC7CA8865A47438, 0x3FA613C795C736D0, 0x3FF57C441E8467A2),
+	(0x3FB8849BC3021788, 0x3FDECCDA2B8173FC, 0x3FC9250286BC9F32),
+	(0xBFAD892B6D192710, 0xBFD111ECEB0724BA, 0xC0076DC036E34B8C),
+	(0x3FDBDE5A7AE00A90, 0x3FDD1010D966E862, 0x3FE8762C02BB629A),
+	(0xBFCDB5BD4F62AAA8, 0xBFDD8293EA2BD3A8, 0xC00566E4205DFDDF),
+	(0x3FA45464D69C7740, 0xBFD8A763457A4DB4, 0x40084FA046B7F218),
+	(0xBFC46533D9F7B798, 0x3FBF81A5DF6EFA20, 0xBFED38405523F630),
+	(0xBFCE56375AA95C2C, 0x3FDE8ECDA1096920, 0xBFDD7CED825A9BBF),
+	(0x3FD6B383839A3270, 0x3FD71A1D251EB964, 0x3FE8DA4D8F6685CA),
+	(0xBFC431B222502A64, 0xBF98C401E3404900, 0xBFFB910998F37C9F),
+	(0xBFBDDFED2DC38E00, 0xBFC0C72401214608, 0xC003503482009B61),
+	(0xBFA78898B220FAA0, 0xBFD9E49A56068D22, 0xC0083A4D5A3C861E),
+	(0xBFDD4C74096B0FE6, 0xBFC0B6B94C53C780, 0xBFFD94111CF07967),
+	(0xBFD3D3A4DE1670E2, 0x3FD291B598614D06, 0xBFEA2E317DB47E6C),
+	(0x3FC9C7FC6ED01160, 0x3FC5A2AB4DAD38E4, 0x3FEBEC6A26DF1360),
+	(0xBFC2DA3F452C1014, 0x3FD06B7CF8B30966, 0xBFE0AD2DA691D2E9),
+	(0xBFD8580E2DCFAC6E, 0x3FD03A424BFE3984, 0xBFEF73736875FAD4),
+	(0x3FD71875B75D21D4, 0x3FD093556CF78C46, 0x3FEE586CA9A4F248),
+	(0xBFCBC63B91B01DBC, 0xBFCE3AF812F0A0F0, 0xC00330265872CED0),
+	(0x3FCE3FC8657147BC, 0x3FD6333F7E2CF69A, 0x3FE3233EC6A1BCEF),
+	(0xBFB4ED661C28B440, 0xBFB849464531DBA0, 0xC003715A203CB78B),
+	(0x3FB5EA379373F970, 0x3FDF2B6067FE92F4, 0x3FC6457816424464),
+	(0xBFD859535EA10914, 0xBFD56FB0F0370D4A, 0xC0025760B557881C),
+	(0xBFD0F1C512D19F94, 0xBFC3D336E5B31134, 0xC000CD09526D5824),
+	(0x3FD1F17914A4DF86, 0x3FB11142661A4CF0, 0x3FF565B9A53E1681),
+	(0x3FC876D4B7A2F538, 0x3FC8D80A5434F8D8, 0x3FE8E2E55DD08229),
+	(0xBFDC9F16BCE41728, 0x3FB1670E147C2508, 0xBFF6B81808B42E6E),
+	(0xBFBBBD60BB7C0580, 0x3FC9C466047451A8, 0xBFDF9A63D92B5CFB),
+	(0xBFC08489B98FCA5C, 0x3FC1191467615B08, 0xBFE89497F268436B),
+	(0x3FD3676471646BEC, 0x3FC37AC7F18E6540, 0x3FF1B07DBC60384F),
+	(0x3FC5B5B0370630BC, 0xBFA944BA0C1E2DB0, 0x3FFDA9D049EA28F1),
+	(0x3FD116D3B4C4C968, 0x3F74215EA18D3E80, 0x3FF8D699D13359FE),
+	(0xBFC8F637B8BC68E8, 0x3FCE99F293E3EFAC, 0xBFE5E5583C0A3524),
+	(0x3FC2F299C4DFD828, 0xBFDD82ED8106C4EE, 0x4006A5CFBD5FF5AB),
+	(0x3FD5E89115C46EB4, 0x3FBD3C1130043700, 0x3FF3FB1BF69A7D84),
+	(0xBFCEDE9FD1622AA8, 0xBFCED8644E8C7FEC, 0xC002D8ADAC67A251),
+	(0x3FD404359E8F92A0, 0x3F4978D7D132D800, 0x3FF917CD23D87C85),
+	(0x3FCE4C9EA950BCAC, 0xBFD6795FD4743C24, 0x400463424AEAA8B7),
+	(0x3FD0C92850C0C1B8, 0xBFD53FA161E1D69A, 0x4003C8B231464456),
+	(0xBFDDAD245684EFBC, 0xBFC627CC2750D578, 0xBFFED9581BE2E929),
+	(0x3FDAA15F1B85602A, 0xBFDC4B495E4EC22C, 0x40031780FCCA3F25),
+	(0xBFA0A94C2D725CE0, 0xBFD61AE634B40F58, 0xC008619816979248),
+	(0x3FB07747BAB9A420, 0x3FCB560D5BCEDBB0, 0x3FD2B8E344E59752),
+	(0x3FD05C0636C450FE, 0xBFC0F5D2BA03D804, 0x4000646752853AB0),
+	(0x3FD5A222369FA87C, 0x3FD1C9D3BEA0D1F4, 0x3FEC3E7574E8B3C3),
+	(0xBFB19E3FB8C03268, 0xBFC224E45F1D3094, 0xC0058450B6A2B33C),
+	(0x3FD8F205FB0D12EC, 0x3FCD9A2BA8D0A700, 0x3FF0908A2D3B7576),
+	(0x3FDDBB5492A1519C, 0x3FD4DD14FF845CA0, 0x3FEEAF679E455DEF),
+	(0x3FBE53DAFD336478, 0xBFCE7F682C30FDFC, 0x40057101986E949F),
+	(0x3FDD24B11E62F8F8, 0x3FC00520DAA83DB0, 0x3FF4D7541BC2121E),
+	(0x3FDF14A4C938D968, 0x3FDB1AF423C987A0, 0x3FEB50CDA6B63A9E),
+	(0x3FD6D7C4A113A9D0, 0xBFCA15E5D28684AC, 0x4000B781C258B828),
+	(0x3FC4555CD89C1B98, 0x3F9A7E7F9ECF1860, 0x3FF68CA954ED0016),
+	(0xBFD506E6A4A27FD8, 0x3FDC40BEFC2F687A, 0xBFE47947ADABF2A6),
+	(0xBFD3FDE54E1368D4, 0x3F9301C4917C94E0, 0xBFF82EE1121FB285),
+	(0x3FD2589145DA8948, 0x3FD489A2D5AC60AE, 0x3FE754BA5105054F),
+	(0xBFD53EE6EAB0FA90, 0xBF81D7559947B3C0, 0xBFF98D720ABF2056),
+	(0xBFD6FC0820D89988, 0xBFCB57769C7B7ED4, 0xC000DBE5F11D825D),
+	(0x3FD89F840A037CA4, 0x3FD278DF517C0670, 0x3FEDAB5FF9D4B6EB),
+	(0xBFD3116966676362, 0xBFAB56B237BE4580, 0xBFFBF858FC4EE2ED),
+	(0x3FCDBD514CCAAE5C, 0xBFD5F3E6005DB838, 0x40045EACAF770962),
+	(0x3FD4CA4EA75B30E4, 0xBFCE914326C64C8C, 0x4001A3431783A738),
+	(0xBFC7F25D7E6C3628, 0xBFCB55ADFC968E74, 0xC0036097B9ED6EA8),
+	(0x3FDA3EB5983D3264, 0x3FB96AA2871CA130, 0x3FF55506B3F520D2),
+	(0xBF920EAEA3918A20, 0xBFDA6187B92977C2, 0xC008CA6BD4BB6CB2),
+	(0x3FDBBAB6DB4ED0D2, 0xBFD56621D91495F6, 0x4001D309E3D8A2A6),
+	(0x3FD1A3EAD82D6AD2, 0x3FABA21D150416F0, 0x3FF609F679ED02E2),
+	(0x3FD52D386000A020, 0xBFD9C621C9668392, 0x4003A162A152CE5D),
+	(0x3FB44401A01E7220, 0xBFD9EAECAB648662, 0x4007969F4FD306C3),
+	(0x3FDC01448657A924, 0xBFA272805B8E3DF0, 0x3FFA727C0AA80085),
+	(0xBFD71D2AA2E3C224, 0xBFC8ACC7ECABE5C0, 0xC0007D1D8C930B62),
+	(0x3FC3946C9887CE94, 0x3FD78C4486158B98, 0x3FD9375D0B579657),
+	(0x3FDF849AA32E546C, 0xBFD0B37D11F681E6, 0x400076EDB3D8BB8C),
+	(0x3FD21C6B1F19D32E, 0x3FD5345D1B4EE8A8, 0x3FE69EB8A87746BA),
+	(0x3FC95DAAD4C76E84, 0xBFC30AF4B035BA80, 0x4001B7D4A036539F),
+	(0xBFDCE108845C65B0, 0x3FC7962A62D35380, 0xBFF2EDF69A0D087C),
+	(0x3FD2CB4604F118FA, 0x3FA09C37D2610980, 0x3FF75F4D5ABB375B),
+	(0xBFDA8D1F5ACD5C4A, 0xBFD1C66F68FCF5E0, 0xC001492EBF70238C),
+	(0x3FD10293A45E2EF0, 0x3FCD49FCDD5A1DA0, 0x3FEB850982AB94D8),
+	(0xBFC1C74D76E3FCD8, 0xBF88364E93958200, 0xBFFA7DC895A62B1F),
+	(0xBFB05F14EE5DAA90, 0x3FDE464C77CF14BC, 0xBFC1333DF0911E6F),
+	(0x3FD62A82A28E6FF8, 0x3FC38D35DED7C63C, 0x3FF27CA4949BCB37),
+	(0x3FB325037F004218, 0x3FD6DF72DC94392E, 0x3FCA672ED0947B93),
+	(0x3FDAEA320FAC806C, 0xBFD88B4DB46462B8, 0x40027B340E42053C),
+	(0x3FCF6456371595D4, 0x3FD242B08AA46DE6, 0x3FE6B875B20D0C61),
+	(0x3FDE803981B992A2, 0x3FC185EC5346FD4C, 0x3FF4A83AB9E29BEB),
+	(0xBFC35E8C4C579F4C, 0x3FC4230412EA0720, 0xBFE882DCCF0DC055),
+	(0xBFB8A12AF23F1788, 0xBF6D960B33920500, 0xBFF9BBAB36701EA9),
+	(0x3FB7979DA2F418C8, 0x3FDFF58534F4222C, 0x3FC75C10F4E264D8),
+	(0xBFD61F2341834F26, 0xBF9EA360D1906A80, 0xBFFA83A837F3534D),
+	(0x3FC11956F8EEC0E4, 0x3FDB45C1AC73E67C, 0x3FD37126A936B424),
+	(0x3FD92CD77011F6EC, 0x3FC02E244F6B2C80, 0x3FF428698FBEB884),
+	(0xBF9D5FDF332EC0A0, 0x3FCF81A9BC8988C8, 0xBFBDB35C636100FB),
+	(0x3FD40316BA143F34, 0x3FD32C6F868E0E60, 0x3FE9D13F0AB22645),
+	(0xBFD0954963DCBA72, 0xBFA35BF029E98D60, 0xBFFB7380115AD12B),
+	(0x3FA8E5917AAA4810, 0x3FDA38A0A99CAF84, 0x3FBE3DFFF5458532),
+	(0xBFA5E46492F24E90, 0x3FD97B26726D81B2, 0xBFBB6351DB901C46),
+	(0xBFCB7B6D9F597284, 0xBFD4D60A5B001B7C, 0xC00477F85E330892),
+	(0xBFBA21AEB69E4F98, 0x3FC88F2363EB7088, 0xBFDF4A9D216935AE),
+	(0xBFDCE717F0A10E76, 0x3FC3EF14CC55AA58, 0xBFF3D1CD64E7A51A),
+	(0xBFCE8983AC1B4DF8, 0x3FDBC7C195015E92, 0xBFE014E54AF0B19D),
+	(0xBFBA163CC3FA5830, 0x3F8FF494AEA36380, 0xBFF6B3A2AC5938E1),
+	(0x3FDD1539BAB79D3C, 0x3FD3770244B88F52, 0x3FEF6430DF2D2DD9),
+	(0xBFD239F73C117A66, 0x3FD447B608852BBA, 0xBFE76D7C8D0C81DE),
+	(0xBFC195DA4353F058, 0xBFD5F80D95FC4C2E, 0xC006164B878BCB58),
+	(0xBFDD5AF6BB3BD632, 0x3FC95D04C0AE2E28, 0xBFF29BACC1B24DC1),
+	(0x3FCF03F79A34E798, 0x3FC2BDD6E96C92B0, 0x3FF06F9AB1E6A7D3),
+	(0xBF92F0AE2B4ACBE0, 0xBFCC9E2C8F958874, 0xC00878F0327A78AF),
+	(0xBFB1242FC0197730, 0x3FC24A9B316BD72C, 0xBFDC0B2FAC707047),
+	(0x3F7DBC5838DAAC00, 0x3FC7E3ACA8191FD4, 0x3FA3E7D51BA0A3EE),
+	(0xBFB03D30F4133AA0, 0x3FD8AE2FE8814404, 0xBFC4DE4462058642),
+	(0xBFD91ABD27CB7980, 0xBFCF7461B7CBC210, 0xC0010B2C4A2A5D8D),
+	(0x3FA10ED42A6A8740, 0x3FABA7A30A73F290, 0x3FE1AFA747F16E4B),
+	(0x3FC07E9DE27272EC, 0x3FDA92AD8A035CB4, 0x3FD3429171D1CE10),
+	(0x3F8E3C5D4F7A6400, 0xBFD3F1BF8FB31E2E, 0x4008C10794FBC515),
+	(0xBFC7F6BE03B6E364, 0x3FC2C7EF5FFDDA20, 0xBFECFE71365D9638),
+	(0x3FD31B5918F93AEA, 0x3FC7E7B124D25FAC, 0x3FF0304D2333DE4E),
+	(0xBFDFB88D00632F92, 0x3FD6A45E75C78118, 0xBFEE6D91916B9AE2),
+	(0xBFD5922982A398EA, 0xBFB3707CBEDE6390, 0xBFFCADA3E2F05DF9),
+	(0x3FCCFE76A26F8320, 0xBFC1063FEEB32E48, 0x4000D0572D20843C),
+	(0x3FB6A1021DA5E9A8, 0xBFBB8FFD83FDCF10, 0x4003A225F3644907),
+	(0x3FD5F3BD6418DC5E, 0x3FDCEA0D6BF77A52, 0x3FE4C7A77FD810AB),
+	(0xBFD1903419CF5F1C, 0xBFD6B714D494E8F8, 0xC003DE060FB5791A),
+	(0x3FD4CD1EF3064428, 0xBFDFD80B29E412BC, 0x400480EDBCB3202F),
+	(0xBFC68F78697D5C6C, 0xBFD06D34A63F5B08, 0xC004519A7C9FAB5E),
+	(0xBFD21A7ECAC2FE82, 0xBFB96B403468CF30, 0xBFFE88BB70399725),
+	(0x3FA810A491257720, 0x3FD63F141D819094, 0x3FC134213A45AAE5),
+	(0xBFCEEEC42C7DA838, 0xBFC284D40FCCCB78, 0xC000E1C900113113),
+	(0xBF91D644EFA5F620, 0xBFDB0FCBA17CF22E, 0xC008CDA9645E874B),
+	(0x3FD05DE883DAA074, 0xBFB7CBBA3520C780, 0x3FFEB5F7E026FA16),
+];
+// (x, y, output) f64 bit representations
+pub const STRICTMATH_POW: [(u64, u64, u64); 256] = [
+	(0xE933257C5880D6EA, 0xA03754FC3513ABF4, 0xFFF8000000000000),
+	(0xE8EF0DEC650ED141, 0xF328B08884DD1341, 0x0000000000000000),
+	(0xD82ACD8F02399A09, 0x7AC446373BAB2625, 0x7FF0000000000000),
+	(0x25B56D869C0C0929, 0x5787B4325600FDBE, 0x0000000000000000),
+	(0x3E05FD974B840EFD, 0x501573A527A34557, 0x0000000000000000),
+	(0xBBF758ADD9925F5A, 0xEB66416B84A3870E, 0x7FF0000000000000),
+	(0xE8A31EC4133B48CE, 0x2EDBDE0E14C11EB7, 0xFFF8000000000000),
+	(0xF811F7E92B573C2C, 0x9CAB58E9922BF700, 0xFFF8000000000000),
+	(0x4D44749B5155C846, 0xA3C45AAF76D374D5, 0x3FF0000000000000),
+	(0xE4791F57714E0DFC, 0x9ABE2310B92E3505, 0xFFF8000000000000),
+	(0x33C99371DCD3F075, 0xA2AA2DB9490D8025, 0x3FF0000000000000),
+	(0xD981AD2785B985AC, 0xA837B27BB8D998B4, 0xFFF8000000000000),
+	(0x1A3126F560588B2E, 0xD89274CF05C24780, 0x7FF0000000000000),
+	(0xDFA938235A77E321, 0xA71A967984414A47, 0xFFF8000000000000),
+	(0xD31D08A4429D5D88, 0x48BA618C3C0288A0, 0x7FF0000000000000),
+	(0x98B50E20B1DC51C4, 0xDC7A3D8E4120331B, 0x7FF0000000000000),
+	(0xBF52A651FC35CC5D, 0x564243FB06DF30A7, 0x0000000000000000),
+	(0xD542828FD1A75502, 0x5BBC27EE13FA7E34, 0x7FF0000000000000),
+	(0x4771B8A7505190CE, 0x433F651CA0526499, 0x7FF0000000000000),
+	(0x55A28CF10EF044CC, 0x24DCEA0651BEA8B6, 0x3FF0000000000000),
+	(0xE85B499F22717CBD, 0xBD3EB166C81119DD, 0xFFF8000000000000),
+	(0x761AB7595A11DA18, 0xC462FEF1D148226D, 0x0000000000000000),
+	(0x3A030B0A2F404F51, 0x46823340BCC73EC0, 0x0000000000000000),
+	(0xAFE55CF401EBCB5E, 0xAEE96B0741657E46, 0xFFF8000000000000),
+	(0xD5A4FD09C3019976, 0x2A835D88148072F1, 0xFFF8000000000000),
+	(0xE98B387DBE0B791B, 0x256C2E499A4432D4, 0xFFF8000000000000),
+	(0xE5996496A448B25A, 0x3384260BD73F5006, 0xFFF8000000000000),
+	(0x8A7042C7AF8DAA51, 0x2AC086F6FB63EACC, 0xFFF8000000000000),
+	(0x764111FDCDE9BBF1, 0x8638F012DD0541F6, 0x3FF0000000000000),
+	(0x26ADE13970CB5905, 0xF5E303FB89736589, 0x7FF0000000000000),
+	(0x54C3F75E3F56B773, 0xDFD546D396CCEF9E, 0x0000000000000000),
+	(0x6194644A7C238EC0, 0x5805677B29B8E789, 0x7FF0000000000000),
+	(0x659C0F2A1EDAA9D9, 0xF20567FD1839333C, 0x0000000000000000),
+	(0x566DD6092A74510F, 0xC086594C4AE83AED, 0x0000000000000000),
+	(0xE22692A9A564D063, 0xBF9E9E2E6DFAA2F7, 0xFFF8000000000000),
+	(0x705C18974A149E03, 0xE43CE97845883FFD, 0x0000000000000000),
+	(0xD9B58078CFD5E514, 0xAC106B7C0B2DA955, 0xFFF8000000000000),
+	(0x1B6C54A9D76178B2, 0x90C1E2BF404A0DA2, 0x3FF0000000000000),
+	(0xA7DB73079E017334, 0x66E083EF5E332DC2, 0x0000000000000000),
+	(0x2239B80BB03005B4, 0xDFA0ED12EEF3A1EB, 0x7FF0000000000000),
+	(0xCEEBC719BBD53BCD, 0xFBBB98046BC5CD10, 0x0000000000000000),
+	(0xDCB9841A33B6B7A6, 0xD09B801AF4C02289, 0x0000000000000000),
+	(0xCAE91245CD58AA2F, 0x16E4EF2AA54DA1F2, 0xFFF8000000000000),
+	(0x8E0A6C44A0BF8BAA, 0x4D67A5B12D57AD52, 0x0000000000000000),
+	(0xFE5BE9793A76586D, 0xFD0ED2490DB49D7F, 0x0000000000000000),
+	(0x70614493AC3555E5, 0xBC948C63A1C0B8C1, 0x3FEFFFFFFFFFFEA7),
+	(0xCFCBF77143C1A3DE, 0xCCE5C638263D6FB5, 0x0000000000000000),
+	(0xF76D00213A5E1E00, 0x947EE9F778ED0474, 0xFFF8000000000000),
+	(0x6BD563435DAA519C, 0x5FD896430B1EFFF4, 0x7FF0000000000000),
+	(0xC2CBFF975D548BA8, 0xD27F2DDFC5C419AD, 0x0000000000000000),
+	(0x651486D54BD04308, 0x86E72EAA9DE6757F, 0x3FF0000000000000),
+	(0x936CAFBABB92630F, 0xD5BB83C9AECD5BD2, 0x7FF0000000000000),
+	(0x9861F5643A693A44, 0x6F82472399E1CFFF, 0x0000000000000000),
+	(0x57F377E0499E1168, 0xE047A6130DDBA8EE, 0x0000000000000000),
+	(0x5118D2001FEAAEB7, 0xB3C5DD57F5150FFF, 0x3FF0000000000000),
+	(0x0D7FF870D22D496E, 0x6C7C804F505C8A2D, 0x0000000000000000),
+	(0xA661760B0569B18D, 0xF8987F62FE7BEF71, 0x7FF0000000000000),
+	(0xC36B996B204EAFDE, 0x97B1A1C3132CF463, 0xFFF8000000000000),
+	(0x6FBA67BE7062E235, 0x03796655409A2A27, 0x3FF0000000000000),
+	(0x89DB68DEBF6C97F8, 0x1EC27FE9AF159886, 0xFFF8000000000000),
+	(0xFC39A0A40CDC72E1, 0xE10760B34D9C565B, 0x0000000000000000),
+	(0x743F49752D3AD002, 0xCC0988F3AD50C430, 0x0000000000000000),
+	(0xED40241CE3143D09, 0x0C8A8CF7408B4D27, 0xFFF8000000000000),
+	(0x221870E409E17DC5, 0xC1BB0641E5BBD21E, 0x7FF0000000000000),
+	(0x3FC94CF2FB057CBC, 0x40555A6EDB8C5F28, 0x3372BB3BD2368D68),
+	(0x3FF2BDC2CBEC9CEE, 0xC0508CB7E7830094, 0x3EFDCBD23EBDF3C5),
+	(0x3F9EEF64F6FA6B06, 0x404522995091BABD, 0x3297FE7D658653AC),
+	(0x4017FDFA8ADA99DD, 0xC047E583C952E366, 0x38364E5342B6FBF0),
+	(0x3FBCAE15078225D3, 0xBFE4F07954957478, 0x4010C121730863A5),
+	(0x401D518786178465, 0xC04784F9CCD6EDD9, 0x377C4C21596C7971),
+	(0x401D9940BF8944FA, 0xC0465B5837707062, 0x37DDAD06D08632EE),
+	(0x3FE69861991924FA, 0x403FEE805C3E384F, 0x3EEF4FBBE9671AAE),
+	(0x3FB2F638358F5494, 0xC058D02CDDB89387, 0x5739D7BBA85878AD),
+	(0x3FB8ED4FADB2CE37, 0x4053E412E2BA0791, 0x2F38E2CE6124604B),
+	(0x3FE65FA41123F546, 0x404F97E3110E6D58, 0x3DE4CC33F86F6EF2),
+	(0x400BECA9026EC6C5, 0x4057092CA2AC4698, 0x4A521550C87122F6),
+	(0x3FA9AC6173C4B6C4, 0xC03030A67DBDE6E6, 0x444DF7CF77D51203),
+	(0x3FAECE5919C11534, 0x4053FDC34025D28D, 0x2BAAF75B8BDF53FE),
+	(0x3FFAFD2962D90BE4, 0xC056FDCEAB4D6AB8, 0x3B98C5EF05B612BC),
+	(0x3F65A683B04EF580, 0x40232A55F7A377D4, 0x3ACEA1DD1A56B5C0),
+	(0x3F94584991D89B42, 0xC04F961A485A9A1E, 0x5641A402DCE8114E),
+	(0x3F931CB28A882F04, 0xC0573FD4682A53B3, 0x61519BB937CC4C59),
+	(0x3FAEA70FAD724FE4, 0xC056C0AD8A4BE339, 0x5709D3977069EBC9),
+	(0x4013E4513794327C, 0xC04FFA8EB01610B5, 0x36AFE6EA1A6B0B51),
+	(0x3FCE9CF7C0018D32, 0x403E0DF1A9601D3E, 0x3C0F5747A2AB2DCD),
+	(0x3FD5A77BD05B44F0, 0xC050738138BB666E, 0x465D75AA6E7B5D15),
+	(0x3FBE7A5AFE70E09E, 0x404CD96A9E189E02, 0x34DCCB85270427F7),
+	(0x3FBB15044D46914C, 0xC052463850DF77A6, 0x4EBD9E56E0400F19),
+	(0x3FA93BB052B787A8, 0xC05859B3EA58A22F, 0x5A5FCCA69C8FBC7C),
+	(0x3FFE7079FA05C514, 0x403C03B0D1C07AAA, 0x418FDBE88C97CA5A),
+	(0x3FA3075CCF319DD6, 0xC040DEACC3DB6614, 0x49F32799C8F50F2F),
+	(0x3FB0AA0C9001DB86, 0xC053DF18AC0CD542, 0x538369933AEACDCB),
+	(0x3F89D5B2BD6341E8, 0x4030D819AFEAB742, 0x394A9E6CD458215C),
+	(0x3FF874FF526F1D80, 0xC0480D34CA194E0A, 0x3E1776B07B6050C8),
+	(0x3FA2545354080EE0, 0x4055C392FC62E64E, 0x25CBB0C72D6BF7A1),
+	(0x3FFD096524F5A31E, 0x4053A7C5E41A61E6, 0x44283CC73DE58DAB),
+	(0x3FB2A220122BEEED, 0x4049AAB503649CDC, 0x33CEE429E7F121B6),
+	(0x40060AEC4CFBD024, 0x403E46CDBD5D43BE, 0x42B34F30E5A91761),
+	(0x3F731225AB77A600, 0x40521F3811F0D7D0, 0x1CD5FD7A954F385E),
+	(0x400599502DD2244C, 0x404DCEADB7B69A59, 0x45456BC34EE0148E),
+	(0x3FCF5ABA680258C4, 0x4058716AEA85680D, 0x3387EE759F43CED4),
+	(0x3EF6DBABC2847000, 0x40512960E3B7FFC7, 0x00000000000007E8),
+	(0x3F81DD2131685E40, 0x4027369AAD40CC10, 0x3AF83A048564F230),
+	(0x3FDBEA19A67C009D, 0xC02648EC34D91714, 0x40C439E43E7E86BD),
+	(0x3FE6B0CFC1B5B8CA, 0x40170B970A4A7199, 0x3FC1A95E0E88244E),
+	(0x3F9B128A818584B0, 0x4050A2AF2A0AFD93, 0x2A22DBF8DF384EC2),
+	(0x3FCF5C35D4C2D23C, 0xC055F9D6601C0651, 0x4B14A8B262734AFE),
+	(0x3FD3A4A778644AA4, 0x4058C25B6CE1B301, 0x3562DB1DD8F23501),
+	(0x3FD4153002BB1A68, 0x4055993F2D30147C, 0x36E73D94B19133B4),
+	(0x400F8896F7366E36, 0xC0403A2621C48219, 0x3BEB7432943F4B16),
+	(0x3FC1C1AFFC5CA964, 0xC03AD804A8B93D3D, 0x44B695C91520D8FA),
+	(0x40020BCFB57E04B6, 0xC05888E46F5CDFF2, 0x38BC4AE19C53BD1F),
+	(0x3FD563FB68027D58, 0xC0553BAD9F019464, 0x485384031FEC9C64),
+	(0x3FC047CCE41117B4, 0xC043E271EA84517F, 0x4753D6CF6101B471),
+	(0x3FBF48EEA973C7D6, 0x4056C53F4FDEBA07, 0x2EAB8E070E48E8CC),
+	(0x3FF3DC4AA0132612, 0xC055F7AEA35200EB, 0x3E383DC7457BFE0C),
+	(0x3FE66A5429679EB0, 0x404A3A39A5DFF708, 0x3E40ADCD5723E628),
+	(0x401D6A409F420801, 0x4039CB94EE3FEB93, 0x44930AE1289FC7B9),
+	(0x3FE60F16C36F17E0, 0xC03EEDAA3F6B389A, 0x40F83E4F176E9E67),
+	(0x3FACF68144F301F0, 0xC04B0B4935641844, 0x4DF18EFBACAF3797),
+	(0x3FB3C54FE5EBF78B, 0x4054F92D771FB86B, 0x2C906F59CD619410),
+	(0x3FF11022E29DAEDE, 0x4052A2EB674E9EDA, 0x405E3B553B7BD11D),
+	(0x4013FB75AF39951F, 0x4051B673B72C5AA6, 0x4A35676D8660C5E2),
+	(0x3F881B535EA50634, 0x404B151369722ABC, 0x2A3D6A3EF2C0366D),
+	(0x3FAD64FB8B3B6764, 0xC051695EC954C3A4, 0x51E160EBBDA60CD5),
+	(0x40067DEAE88B41BC, 0xC0517EB7E12AD2B9, 0x3968E02F6CF5492D),
+	(0x3FB8027F3BA4EE8A, 0x40234260726F2BFB, 0x3DE163707A9C0373),
+	(0x4015A39DABC311A2, 0xC0571A4181EBB3DC, 0x31DE731741A2E449),
+	(0x3FE7687783FF3D62, 0xC04C56141E7E9D02, 0x4187A33DBF099A5C),
+	(0x3FF2393AB6C2C12E, 0xC0553472321F0382, 0x3EF0DF7E512542B0),
+	(0x3FD2D8B46EEC63D1, 0x4041E69AC4127FBC, 0x3BFCEBB8DF84A04E),
+	(0x3FF616560A5F5C02, 0xC053854C90FF237E, 0x3DA9A442E0C032BE),
+	(0x3FBB63AE74351100, 0xC0561EA30A2361EB, 0x51C3A375179DE597),
+	(0x400C14483B763099, 0xC051140BBA285B7A, 0x383318EC6B8E669C),
+	(0x3FB33884555DC355, 0x404BB802C2DD4946, 0x32FE3C0947D931E3),
+	(0x400A258B2628F1D9, 0xC0292A329079A01A, 0x3E96A9AE60726C84),
+	(0x3FC3879105252688, 0x4057C1C8FC67E9CC, 0x2FD2F69A014455A6),
+	(0x3FC6A6A0B84C6225, 0xC04118F5EA01CEA6, 0x4545A6A8299EE414),
+	(0x4006276FAA3FE809, 0x40463227763F3526, 0x4402D057A85E59AB),
+	(0x3FDB8E6CC540C4EA, 0xC047B7B1F6D19889, 0x4389643359E909C0),
+	(0x3FB3A758B53B1F52, 0xC017879CF4F2A320, 0x414B8E1F1C027F36),
+	(0x3FC2B9313B304728, 0x40352F93EC94B6FA, 0x3C42FC2756FF9930),
+	(0x3FDF273C1DD61DF4, 0x403BBB7CFDDE249A, 0x3E224FCBBA89BEFB),
+	(0x3FD038D21BD446A3, 0x40587AC3AA623DEF, 0x33D143FBEA733984),
+	(0x3FFC00B8547CD399, 0xC048013E6A5AB723, 0x3D82CB22385C7147),
+	(0x3F438CCE5EBDC900, 0xC0224EEB73EF6D51, 0x46108DC7F32D0D0E),
+	(0x3FBE031CB6F14FC0, 0x405177998AF4BD34, 0x326E777A30502CC3),
+	(0x400A60C34FF5F2C2, 0xC04E7A114F40BC0E, 0x3960F1E64D42C9D0),
+	(0x3FE7490BEBE096A9, 0x40581EC20843F310, 0x3D2AE4A85E824A57),
+	(0x3FC862A997A65655, 0xC055D80EE6E229B4, 0x4D001689375012BF),
+	(0x3FD7EF5448C8C378, 0x4019B9BE522399B9, 0x3F5D5393F153BF63),
+	(0x400642F7972D9772, 0x4026D533E0689CE8, 0x40FCF69F4C8C46AC),
+	(0x3FF4C0B5D3A3537A, 0x40371CC43507C1D8, 0x4079805F7F5C0F6C),
+	(0x3FD53075EBF7887E, 0xC0324D0FBC5DC3E6, 0x41C2315D3D3D1CA4),
+	(0x3FC805FBF89C0108, 0xC03974B7887D6D7C, 0x43C5B9835BABDD73),
+	(0x3F671FED421E4DF0, 0x40433A582EA2C6A5, 0x2B9422B6C9496398),
+	(0x3FCA8CC58F1D971A, 0x40140D8C6A7434C1, 0x3F38A4C60EBAB32B),
+	(0x3FD2A82804981E98, 0x40498D21A95A2389, 0x3A4166127DB0BAD4),
+	(0x3F9C84859F143918, 0x4041109EF22C52EE, 0x34E998701F315102),
+	(0x3F7149D40680B818, 0xC04045C7814FBBA4, 0x4FFA71B8D45A324A),
+	(0x3FF3D608DA1EEA82, 0xC04C34EEA3797F48, 0x3ED6C3C7182F2C09),
+	(0x3FEE41BEFD7F0BF4, 0x4051966E3A3FC211, 0x3F93E7049AB15E52),
+	(0x400FDC73C33290C4, 0x40541BE3DDF9DDE5, 0x49F4A22BDF829AD4),
+	(0x4000F3E22EF8D54E, 0x4016D0A354999BE6, 0x40521F3D085A6713),
+	(0x3F7CF0D069A8AD80, 0xC0535355B6C2627A, 0x6273FE6024FE3D2E),
+	(0x3FDBC507D0F1481C, 0x40540EADCD9FB194, 0x39E48426F2348494),
+	(0x4006AA27FAD43782, 0xC051CC2E8DA73954, 0x3940834F1EE38AAE),
+	(0x3F935767E23AF338, 0x4020F6DC2EB78493, 0x3CE586AE49135902),
+	(0x3FD36EAAF7B45FFC, 0xC04E5B67595A83B9, 0x46752F1184294028),
+	(0x3FB6AD2E82827641, 0x40561F5B555467C9, 0x2C979DF1FD2507CF),
+	(0x3FFA02CA4B138750, 0xBFE688EE05B704C0, 0x3FE6BA069F3231D1),
+	(0x3F9ACAEDD8615858, 0xC00B464C538F3DE6, 0x410E4735CE54DE6B),
+	(0x3FC5C49FB4678D0C, 0xC05633CC0B3A58D3, 0x4E1FA64ECAD76007),
+	(0x3FCBBA98EC78CAFC, 0xC04E13FBF69789E6, 0x483AD2D16B2CD269),
+	(0x3FBE3EC7EBF6D5E0, 0xC03A27B55EAF493E, 0x44F8241A797ABA20),
+	(0x3FF6D85B10391EAA, 0xC0521F484DF7F380, 0x3D9AFC7559D93C49),
+	(0x3F83DE75CA920118, 0xC05153A14D5EC2E5, 0x5CE6823EC0DDBDFB),
+	(0x3FB34DF2BDA770D9, 0x4025195A08328DD2, 0x3D794571381A8594),
+	(0x3FE3150FD9FF71E2, 0xC040C198A367BF78, 0x417FE31C10917547),
+	(0x3FE07EADF8D91611, 0xC047B28EF32A637C, 0x42C3DDF7BA0C8DA2),
+	(0x3FE6D8FB13987326, 0x40535CFF5197430A, 0x3D94785406786CA6),
+	(0x3FEB93B89AF737F4, 0x40034127BC492270, 0x3FE65EB1ADAC9370),
+	(0x3FB2318C7BDA4C1D, 0xC058C29957966316, 0x578BF310A6A45ADC),
+	(0x3FA1B7DAAFD90CB9, 0x4054AEF89215FB46, 0x26D6B654CB91933E),
+	(0x400ACAD08AD6DE44, 0xC0515CB71928B5B0, 0x385DD7CE68062227),
+	(0x3FF481564C393226, 0x40556D0F8F5240F3, 0x41D98C6564FE82B2),
+	(0x3FED11986454C28C, 0x405577E0C12B513C, 0x3F311EE1E7FAF543),
+	(0x40027D3D0F1FF35A, 0xC057F523523209FC, 0x38B21B16F143069B),
+	(0x3FD1212F4A24ACC6, 0xC04B6E6A98AA0925, 0x46740CF2AFEDAD0C),
+	(0x3FDF363F5656F996, 0x3FF3C23F22318F80, 0x3FDA5DE5456647E1),
+	(0x3F908CE707C93824, 0x40243991F19C0420, 0x3C2C38FC4CF4934A),
+	(0x3FA2803466216E50, 0x404ECF1BD1CAF6A6, 0x2D7C3DEC5039344A),
+	(0x3FCDF6B114C06D89, 0xC03EB31B3C760D6B, 0x43F3DACB82654B15),
+	(0x3FA3B31D79B651CF, 0x40425C211FE5D698, 0x35256B191C577CB3),
+	(0x400EE733663A749D, 0x4042683C5BCF935E, 0x446B66F95FC3AB34),
+	(0x3FC5418486C093D6, 0xC056917E1982B171, 0x4E8C60096D5473B9),
+	(0x3F9862D676762B80, 0x4051DCC17477D877, 0x27DACDB4B2104940),
+	(0x3FEE498A0C4C302A, 0xC046A198ED8C26EC, 0x40281E4FA5B67491),
+	(0x3FC36B69EC5726D4, 0xC034243BEEC9C167, 0x435BC912C501A534),
+	(0x3FF95F87437F95F1, 0x404E66848A97D741, 0x4275CE63AECCBB3C),
+	(0x3FE7B1A9D45D38A6, 0x40575FE9DC31C58B, 0x3D66106F06C107D9),
+	(0x3FA0AE13BE04CFE5, 0x4054A8E6B35B28C1, 0x266B393168A51139),
+	(0x3FAD09A07C35D666, 0xC0541A58C5F5F7DA, 0x54BE3260A2D16A8B),
+	(0x3FF7BF48EB13CA04, 0xC02E138C4519E9D1, 0x3F65999B4CB0D0FB),
+	(0x3FA2609DF35D1A8C, 0x4035F85E2E36CDFE, 0x395743B89F6563D7),
+	(0x3F75EB0F729D5900, 0x404804D2436E2F28, 0x2946CB17160D1847),
+	(0x400BE758D66E621C, 0xC055DCF818CFEBDF, 0x3614C62A69451984),
+	(0x40038C60FDB4DF48, 0x40560891AACCCDC6, 0x47084ABF922375A3),
+	(0x3FBF4ADF139A58AB, 0x4038CE1615048DA8, 0x3B3B90D202AE41D9),
+	(0x3FCBA7D469C08902, 0xC05216DB1DBF9A31, 0x49EED04BF2007065),
+	(0x3FC481CE70F6A460, 0x4035F654DF0F5447, 0x3C4F7D949CD1ED20),
+	(0x3FEEADFA15B32E27, 0x405168D53EDD04A3, 0x3FAB380E6C10C342),
+	(0x3FC9D92BAF161C3F, 0x4058BB5BA08FB8BF, 0x31A98CF1B903F299),
+	(0x3F870C720D9F1B54, 0x4058359BEBDD965E, 0x18C188738AE4031B),
+	(0x3FCA84913F0547E0, 0x403ECD5DE79855C4, 0x3B908144EBD46613),
+	(0x40144E6B51139E4C, 0x40566147CD3577EF, 0x4D0C49BC1C575449),
+	(0x400A7E347CF20A0A, 0x40445E39C8E96D67, 0x4454B96F0B5297D3),
+	(0x4010F8C8CE2FC1EF, 0x404903EE0DEB2FAB, 0x4673F02AFF1CBCD2),
+	(0x400AA5934F80E629, 0xC036A1A93671441E, 0x3D7A3FFCD42E39B9),
+	(0x3FCC5E146AA018C3, 0x403CEAB353CB0374, 0x3C01A07F29124215),
+	(0x4014E72F9870FE66, 0xC050D5AB6A2B97F8, 0x35E473BA8C05CA55),
+	(0x3FF44B29CCAF4096, 0xC027C52CE5B9B382, 0x3FAE5B6AD45557C7),
+	(0x40177888E37FE1AB, 0xC013DFDD6DB3FC48, 0x3F23EB718C9BB07E),
+	(0x3FF51D384F653F58, 0x404BDD144AB45718, 0x4153ADAC0DAF049D),
+	(0x3FD6767B64B84690, 0xC04C5A7D96F24ADC, 0x45493DCDAA93275B),
+	(0x3FAFC7D0D50A794C, 0x40371CE699D04229, 0x3A23F56BBFB5C464),
+	(0x3FA7D2A384D10BF0, 0x404B087BF188B6DE, 0x30FA4E505E36D31B),
+	(0x3FAA0F5E73305C88, 0x4056EDB51E789DE6, 0x274F709E1A4B9FFB),
+	(0x3F89B3FAE91C0674, 0xC020EA0AC44FD278, 0x434559EB92F78018),
+	(0x3FF920028E387776, 0xC03FC9EBD2EC12BD, 0x3EA3C016F93EDB8C),
+	(0x3FECEA036CCB8C08, 0x404726B6321A0647, 0x3F82B6CA0BB36B63),
+	(0x3FEE46FCC7B23F80, 0x405249712897E5CC, 0x3F91E11D49CA97D5),
+	(0x3FD2984E0D760E5A, 0x4031AF600ED2092C, 0x3DF614854AD54050),
+	(0x3FAD9DD1F0CC31AE, 0x400F45BF2415AC78, 0x3EEE6D20906CFA7A),
+	(0x3FE04545CCA4B79C, 0xC041909C6F2733D6, 0x42136CA8553CE808),
+	(0x3FBD1FBB00E59EBC, 0xC022E6900368A6BC, 0x41C8D88583457EFD),
+	(0x3FC5771CD6B4E81D, 0x40452D753810195B, 0x391DA8619511D6F5),
+	(0x3FB1ADB0FB76F8BC, 0x403F1AC71F65F665, 0x3870A8C1B8CABFCA),
+	(0x3FCB45F415496F00, 0x4057AE70D8A6FA12, 0x32BA1BA0E6F58470),
+	(0x3FEAE065B491F962, 0xC04A3E54EEDB423D, 0x40C289182E59ECFE),
+	(0x40080F5014B48BE0, 0x4049617805BD788A, 0x44F8E31BBDB5AFEA),
+	(0x3FFCCF70EF74A1C7, 0xC0500671B35EFFDB, 0x3C886A381F201784),
+	(0x3FD22055FAE71FEC, 0x4032793D1C27D99B, 0x3DD4CC160A3B3DFC),
+	(0x3FEAAAD3681509CA, 0xC02CF9E25CAFD395, 0x402C0EFEA0D6B86A),
+	(0x3FA3EB457B0F1CCC, 0x3FF291513CF4AF34, 0x3F97A9171AD76494),
+	(0x3FE749E48DC7E291, 0xC049EF7A64A087C9, 0x416B79450E1D552A),
+	(0x400C077F23CE8C4F, 0x4051A427D07160E6, 0x47E8F90D74247F6E),
+	(0x3FE05F9FB644DC6B, 0x40496EEA52DB9A19, 0x3CDC6247B6392B6A),
+	(0x4006E3625797DF88, 0xC0369B03D1E0912F, 0x3DCA5158EDFD6953),
+	(0x3FEE58004AA6BC07, 0x403CAD1E79B4B553, 0x3FCBE21BD4010529),
+	(0x3FE402714BA8D2E1, 0x40470B2AA4680AFE, 0x3DFB7F777664DB66),
+	(0x3FEE0A6C300A7C80, 0xC051A7CAF8FD69B7, 0x4055AA8BD1C73E86),
+	(0x3FA978B687D64D00, 0x404AF7121A605FB7, 0x315709CC4F905D06),
+	(0x3F81D8861D4C58B8, 0x4052C8B32BF00EA9, 0x1FCD8362FB049636),
+	(0x3FEFCE3675EB9730, 0xC05098A628D21982, 0x3FF7FB3D09FD19C8),
+	(0x3FDA182A61A66AEC, 0x4033A04C3E0081AA, 0x3E583479065F5E16),
+];
+// (x, y, output) f64 bit representations
+pub const STRICTMATH_POW_NEGATIVE: [(u64, u64, u64); 256] = [
+	(0xEC3FF68F907E8CCF, 0x13918B93A9689601, 0xFFF8000000000000),
+	(0x0F727BACADDE8A85, 0xE81693C17397C1CC, 0x7FF0000000000000),
+	(0x9B3C63CC6CA737EC, 0xFBE8A93589D45BA9, 0x7FF0000000000000),
+	(0x3468A1E7EB5FF5DD, 0x52E0B9F35BFD1A03, 0x0000000000000000),
+	(0x32565BF9355D48F2, 0xDDF68CC1C6F1EA09, 0x7FF0000000000000),
+	(0x7A9B7499E9CECC5C, 0xD7CB9D774448D0A1, 0x0000000000000000),
+	(0x4C138AEBC938FDF3, 0xE950F9784ABB669D, 0x0000000000000000),
+	(0x430494B8ACB7378E, 0x684FE07EDB73642C, 0x7FF0000000000000),
+	(0x6EF076DB53536994, 0xEFD52D9E2FCFEF92, 0x0000000000000000),
+	(0x3A3D3FDF70866AD3, 0x43FBCC82AEA4A31B, 0x0000000000000000),
+	(0x8A53E8E384CCF190, 0x5792BEFD0A8E7E4C, 0x0000000000000000),
+	(0x6E6FC0C8A36A89DE, 0x96DF76BE6C0A365C, 0x3FF0000000000000),
+	(0xDA56E58750351D33, 0x8785876244BA99E2, 0xFFF8000000000000),
+	(0xDAD66FD9DAD8DF37, 0xD6A8F4AF4504FBCF, 0x0000000000000000),
+	(0xE2633525E3ADB3DF, 0x13F0A11D8514D99D, 0xFFF8000000000000),
+	(0xD6EA4399363462BA, 0x5E62A09A55B730C8, 0x7FF0000000000000),
+	(0x6CEA7C8B9FE32B8A, 0xF76C73871F91377D, 0x0000000000000000),
+	(0xB9A2F12E744E9016, 0x15056E04EF3D6C91, 0xFFF8000000000000),
+	(0x44B7E562B334673E, 0xF5F9CF74B61532B4, 0x0000000000000000),
+	(0xEB6A9CE93EBC8D4F, 0x7F514E3B411C6AE5, 0x7FF0000000000000),
+	(0xAA9E56BBD1A3C598, 0xDFAC1BC484E7C228, 0x7FF0000000000000),
+	(0x93A71ABBD33AA13D, 0x799DA4CC25ADA677, 0x0000000000000000),
+	(0x60853D8C3B6B7F4A, 0xC8BC91DA513D16F5, 0x0000000000000000),
+	(0x08FABEBC4097FA0B, 0xFA93CC0AC494C369, 0x7FF0000000000000),
+	(0x8115E528E54F559A, 0x75188A1693882EE9, 0x0000000000000000),
+	(0x2D9EBDA2E1E4C3B8, 0xAC05A98F5587C9D5, 0x3FF0000000000000),
+	(0x418B5FD11F0590E0, 0x25BCD866427C3707, 0x3FF0000000000000),
+	(0xEB791D509FDC8E99, 0xDB5252B18CF43048, 0x0000000000000000),
+	(0x2966FF2285514F0C, 0xAE7038B396E7BE05, 0x3FF0000000000000),
+	(0x15D03545C5C705D5, 0x3F0AC115234AB641, 0x3FEF3F044F4953C7),
+	(0x420B5CA366A8221D, 0x3AAECB723BD3B6CD, 0x3FF0000000000000),
+	(0x10BDBF29E448F5E8, 0x53C8971A76FC0EB7, 0x0000000000000000),
+	(0x3E7FF23A027CFEAB, 0x70D8A215A1904B00, 0x0000000000000000),
+	(0xC0C3774651DFA321, 0x9E68F46880705A90, 0xFFF8000000000000),
+	(0xA1DF42B5348A7A5E, 0xB2F55B9795997D6E, 0xFFF8000000000000),
+	(0x6F234D0856007023, 0xF61D5A6C2E83732B, 0x0000000000000000),
+	(0x793192412EB65A74, 0x149ED0F4D47D9C9F, 0x3FF0000000000000),
+	(0x81DD2382F116D468, 0x1504A0D1C95004D7, 0xFFF8000000000000),
+	(0x53B62EF4DD68723F, 0x3DE4D1B0062F854D, 0x3FF0000008EB7385),
+	(0x207421EB80DAA2B9, 0x123DFA1028537959, 0x3FF0000000000000),
+	(0xC2DD4949736ED1A5, 0xDB3BF04C786EB4E8, 0x0000000000000000),
+	(0x20DD941E35B89FB7, 0x14A807DB6034B26B, 0x3FF0000000000000),
+	(0xD1745C9763C9439E, 0x6AC95ED2F62F2C11, 0x7FF0000000000000),
+	(0xE23B5B176B476856, 0x81BDFFAF0BB79437, 0xFFF8000000000000),
+	(0x8FC727A0C0F61CBA, 0x2F9DDB524E2B211F, 0xFFF8000000000000),
+	(0x0D43ED797B02A85C, 0x2844DF4B7AA75FE0, 0x3FF0000000000000),
+	(0x08B73509929A5999, 0xD3B1AC5A7608829D, 0x7FF0000000000000),
+	(0x8C79E8098DC6A0E7, 0x6ABAB132E0C1DDF9, 0x0000000000000000),
+	(0xB20BF3DB339F0D70, 0xC9B3AD80BABC49C9, 0x7FF0000000000000),
+	(0x70952E3445C5FEE4, 0x39B9E12D15ED590B, 0x3FF0000000000000),
+	(0xB07D6000341E208B, 0xCFFD6BDF1A159D17, 0x7FF0000000000000),
+	(0xDFBF9A85D7F40199, 0xC2C2A0C398436814, 0xFFF8000000000000),
+	(0x163B3AC88BB85EC5, 0x0E3E5291B49AC2AD, 0x3FF0000000000000),
+	(0xA0873F31511CF11B, 0x141C5BC847BA8903, 0xFFF8000000000000),
+	(0x98F2D36A222ED611, 0x27AFE73A4221E2E4, 0xFFF8000000000000),
+	(0x4BA8368B5026E4D9, 0x9AC13CCBC2788B3D, 0x3FF0000000000000),
+	(0x1A955F7AD55984F5, 0x43E5167C96CF9ACD, 0x0000000000000000),
+	(0xB21003907434DA0F, 0x4B85C1A76017CA1B, 0x0000000000000000),
+	(0xC660E3782CD5590A, 0x8086908391655EA9, 0xFFF8000000000000),
+	(0x8EBC825C1AA61A54, 0x7CEE5FF38969376A, 0x0000000000000000),
+	(0x71EDAB40DD037D7D, 0x768E357C3F09AC70, 0x7FF0000000000000),
+	(0x2E5868B1CE2BF20D, 0xF3653991917D35E9, 0x7FF0000000000000),
+	(0x6842529C1433F991, 0x7DD392169BF40E4C, 0x7FF0000000000000),
+	(0x50B75677F56FB6F7, 0x65C1E12443FD43CE, 0x7FF0000000000000),
+	(0xC008660BB270ACF8, 0xC044000000000000, 0x3BE91F5716EF6B34),
+	(0xBFDBA6ED0AA4DD88, 0xC046000000000000, 0x43434B80D2666B23),
+	(0xC0098AA5D0A103AA, 0x4051C00000000000, 0xC75E0BF8203AC710),
+	(0xBFD2C549330717A0, 0xC034000000000000, 0x4224FE5403B253D2),
+	(0xBFD82EAD9D653938, 0xC032000000000000, 0x418359249439AEFE),
+	(0xBFEB625F6ED19790, 0xC047800000000000, 0xC0979F35BDE8771E),
+	(0xC00F9550D8758DEC, 0xC049000000000000, 0x39BED0BDEFA38497),
+	(0xC00494785693A6FF, 0xC043800000000000, 0xBC9C91F1E07EF886),
+	(0xBFEB5AEF969DDD40, 0xC038000000000000, 0x40458ECDECD1A764),
+	(0xC00ACAD419E505A6, 0xC044000000000000, 0x3B93077809FD8A09),
+	(0xBFD34CA695DD0F90, 0xC04E800000000000, 0xC686A232D7C42A72),
+	(0xC00EFA0ADBDFCF97, 0xC037000000000000, 0xBD20E4C2522BC2D0),
+	(0xBFEF93460D5F31C0, 0x4046800000000000, 0xBFE18A3EF79D195C),
+	(0xBFE2765F0CFC2714, 0x402A000000000000, 0xBF49B7E392A0A364),
+	(0xC00F795D705F0080, 0xC051400000000000, 0xB76919A42ABC598B),
+	(0xBFF43239FBAFB95C, 0xC035000000000000, 0xBF7EC6C28ED5F93E),
+	(0xBFF045C594142A88, 0x4046800000000000, 0xC0011B8AA752BB5F),
+	(0xC001E6712288F5B9, 0x402E000000000000, 0xC1058895BE88D24B),
+	(0xBFA06FD03D9B6B00, 0x4055C00000000000, 0xA4F4D3BA38BFCF70),
+	(0xBF9F5E17961C2B80, 0x4031000000000000, 0xBA96CA957402B665),
+	(0xC00D401C545D5E5A, 0x4049000000000000, 0x45C6EEA3C946B34A),
+	(0xC00FDD44A9C32A5F, 0xC04B800000000000, 0xB914363BC191E984),
+	(0xBFE506F9ECEBA5C4, 0xC052C00000000000, 0xC2C5A4C80433AC5A),
+	(0xBFC34B7B44DE0B60, 0x4051400000000000, 0xB428EE9A3AEF92C2),
+	(0xC001A415758FB918, 0xC047800000000000, 0xBC94D157FEC3832C),
+	(0xBFF33DC80DA61EE8, 0xC050400000000000, 0xBEDA030D55EB775B),
+	(0xBFE156926A00E598, 0xC014000000000000, 0xC0356A6EF9605458),
+	(0xC00FD8CA5BC79CA0, 0x4049800000000000, 0xC6490DE9F607E6FB),
+	(0xBFE648D807FBEA80, 0xC000000000000000, 0x40007F0310D13E1F),
+	(0xC00509D6F1F74233, 0x4054800000000000, 0x4714E9D489AB5F9A),
+	(0xC000AC118C901B86, 0xC045000000000000, 0x3D26BB0405FDE716),
+	(0xC00AFAA84A285C4B, 0xC04E800000000000, 0xB94038EC5E7C7203),
+	(0xC0045C3A65BF6B73, 0x4053800000000000, 0x46816163CA50B960),
+	(0xBF9B92B7A8AFB900, 0xC04B800000000000, 0xD1DC23BDC84EF1B6),
+	(0xC0057A7E1DEFF3DE, 0x4056000000000000, 0x47C4E1D6617028B3),
+	(0xC00F20451C75A975, 0xC038000000000000, 0x3CFF1941CC3423BF),
+	(0xC00E37DDC9ACAAA0, 0xC022000000000000, 0xBEDACB7142161F08),
+	(0xBFFCFFB424B8634E, 0x4036000000000000, 0x411D54880E26EC2E),
+	(0xC00B20ED2F962105, 0x404F800000000000, 0xC6DFC696F4BD6289),
+	(0xBFE21D33E096B168, 0x4045800000000000, 0xBDB9F7E254ACD47E),
+	(0xBFFD8AFB82F722FE, 0xC036000000000000, 0x3EB731DE9F782986),
+	(0xC00A3A5D4A389D27, 0x4052400000000000, 0xC7C0950B79F051E0),
+	(0xC00D581176B25D41, 0xC04B800000000000, 0xB97D59AFE1F3A9A7),
+	(0xBFFAC3EA01CE99B6, 0x4051400000000000, 0xC3229C7AD25A81E2),
+	(0xBFF2499EAC209D70, 0xC047800000000000, 0xBF5EAB9C5A6EEB93),
+	(0xC00608AF781A9712, 0xC053000000000000, 0x38FE273618127E73),
+	(0xC0029A348195AC31, 0xC026000000000000, 0xBF1864D353496706),
+	(0xBFF197DB996CB274, 0x4014000000000000, 0xBFF9B7E7B5AEB841),
+	(0xBFE2861E6630E61C, 0xC053000000000000, 0x43AEB22C7BD18A4C),
+	(0xBFF6B3E5B3D78A60, 0x403B000000000000, 0xC0C8C04BF77A30EA),
+	(0xBFBFB6B30089DCC0, 0xC000000000000000, 0x40504A4BE56D72F7),
+	(0xC00FB26BE0F35DC4, 0xC000000000000000, 0x3FB04EB1D7277B29),
+	(0xC0021421E9E50822, 0x404F000000000000, 0x447E629AB4653608),
+	(0xC000CE9BA4F51747, 0x404F000000000000, 0x44152332E1EC3762),
+	(0xBFF0EFF9DE722486, 0x4020000000000000, 0x3FF93B265E830C06),
+	(0xC005CFE1F5E4FAD4, 0x4055000000000000, 0x478777107164B7F0),
+	(0xC00D12B3E1FD1E0E, 0xC056C00000000000, 0xB5582413F2379DE8),
+	(0xBFFE4E0D13DB7838, 0x4043000000000000, 0x42202E35FC819330),
+	(0xC000C1495C670588, 0xC04A000000000000, 0x3C7747021AA2DD1A),
+	(0xC001512F5CFD087E, 0x4053000000000000, 0x453985D90E4EA819),
+	(0xC0098309E39C9154, 0xC022000000000000, 0xBEFEBDE63A4235DB),
+	(0xC00A701CE26E46A6, 0xC037000000000000, 0xBD7430DA2236B32A),
+	(0xC00A1CC5DB3F40E8, 0x4054000000000000, 0x487726C0D4FB76C2),
+	(0xBFF54D5F095172A8, 0x4014000000000000, 0xC010BBC392A05D97),
+	(0xC00C120CD8AE4F6F, 0x4056000000000000, 0x49E4A0C29EDD8455),
+	(0xC002E915D11CA9BA, 0x403B000000000000, 0xC206C94F48A2089A),
+	(0xBFF3C9B840EBBC0C, 0xC054800000000000, 0x3E5D18B370B51BCD),
+	(0xBFF39F7A3DD45F36, 0xC054800000000000, 0x3E6CE87A08A8DFA0),
+	(0xBFF41976C5FCE9AA, 0xC020000000000000, 0x3FC4A3A3C3328D97),
+	(0xBFF19098C3E5C53E, 0x4058800000000000, 0x40C248D0440D570A),
+	(0xBFC31C243DD9E3E0, 0x402E000000000000, 0xBD5CB7296E0DBED4),
+	(0xC00102E48E6D9A50, 0xC045800000000000, 0xBD025A25754A6E22),
+	(0xC0041CC7158073F7, 0xC057400000000000, 0xB833D12D6458A184),
+	(0xC0023B895728BCE2, 0xC026000000000000, 0xBF1E6C54DA37AC32),
+	(0xBFFC0F90FF7DE934, 0x404C000000000000, 0x42C4ECB93CA28827),
+	(0xBFDAC14BEC6F8B00, 0xC047000000000000, 0x438D71F0C47CBD60),
+	(0xC00A062FFBD7129F, 0xC053C00000000000, 0xB78793EFC67FE59A),
+	(0xC005EA091C4B0BC8, 0x4000000000000000, 0x401E03B734C5C20C),
+	(0xBFF8F7E93D6E869A, 0x4056800000000000, 0x438B87421D4E7BDF),
+	(0xC009F56AB87FCA76, 0x404F000000000000, 0x46837E5B6E2D6C3C),
+	(0xBFEEEFFF193E3A0C, 0x4042000000000000, 0x3FD2FA44DB15AC2A),
+	(0xC00783EC9B0A07C6, 0xC053C00000000000, 0xB8414E3687F68EE7),
+	(0xC008F00109BA1699, 0x4047800000000000, 0xC4C10C5CAEB6EF40),
+	(0xC00F9717EDAD81B2, 0xC053400000000000, 0xB665950C0DF54EBE),
+	(0xC00B808D947663F7, 0xC048000000000000, 0x3A9675CA9639ED79),
+	(0xBFB6759738E60BE0, 0x4056400000000000, 0xAC674E86BC4190AB),
+	(0xBF996E24D5008280, 0x4056000000000000, 0x229C5BA97B48352D),
+	(0xC0011114CEA913BB, 0xC051C00000000000, 0xBB14F33E086B100D),
+	(0xBFA45F371E4EE5C0, 0x4055400000000000, 0xA738A6156F237B77),
+	(0xBFFF752041964972, 0xC050800000000000, 0x3BE8BA16083E8C50),
+	(0xC00CC04EF43376AF, 0x4053000000000000, 0x48B32F207170E757),
+	(0xBFE3D334FF1E21F4, 0xC04E800000000000, 0xC29193F1F55ED45F),
+	(0xBF9077DE1436E580, 0x4044800000000000, 0xB0AA1A78CFF2716A),
+	(0xBFE9242A423D5D80, 0xC058C00000000000, 0xC215E898ED3ED188),
+	(0xBFE745D5832E7EE8, 0xC04B000000000000, 0x417C08AD21F16CAA),
+	(0xC00BD225DAB1B000, 0xC034000000000000, 0x3DB06D83A9CB6284),
+	(0xBFA70BD2BD554080, 0x4048800000000000, 0xB23BC9809B844E6A),
+	(0xBFFCCEAAACA0A6FE, 0x404A800000000000, 0xC2BF31A4C950803E),
+	(0xBFECE10AFDE64E5C, 0x4053800000000000, 0x3F35E43B6A80AF4F),
+	(0xC00FF11C408834A5, 0xC03F000000000000, 0xBC10EDA32B22D7D4),
+	(0xC00C8452BCFB518E, 0x4055C00000000000, 0xC9E73215B1E1F65F),
+	(0xBFEEA5E21CB5F024, 0x4054C00000000000, 0xBF9C754CC83E1B30),
+	(0xBFED892A3F2F2480, 0xC02C000000000000, 0x400890803A63B199),
+	(0xBFE3464BD65EA114, 0xC04A000000000000, 0x425059DEC7F575EE),
+	(0xBFF65E6E2623D7E8, 0x404B000000000000, 0x4191344EAA8A8F4D),
+	(0xC00B1C6450D4BF15, 0xC047800000000000, 0xBAC2ED2D92D95C43),
+	(0xBFF0452DDEAF82B4, 0x4057000000000000, 0x4012AC9E66FF97F8),
+	(0xC00D9DEC13563370, 0xC055800000000000, 0x35C84952C0A59E94),
+	(0xBF97307688764A80, 0xC053C00000000000, 0xDAEA12171AFE4F68),
+	(0xC00E7FA206DDA9EA, 0x4058800000000000, 0x4BC27368067DCC68),
+	(0xC0014F5856890CC5, 0xC042000000000000, 0x3D6E205D3F35A690),
+	(0xBFD79DF3A98F8288, 0x4041000000000000, 0x3CE124DA27B396ED),
+	(0xBFD6F14D156C4CD0, 0x403A000000000000, 0x3D86ECD02DCEE5CE),
+	(0xBFFA41181F533802, 0xC024000000000000, 0x3F7CF1AE832040F4),
+	(0xBFC9EC4AA60C50D0, 0x4050400000000000, 0xB69308AFB57E1208),
+	(0xBFE170628A9AF1C8, 0x4008000000000000, 0xBFC4B7879929F0C8),
+	(0xBFDA4F0E731FAF78, 0x403F000000000000, 0xBD72EB100AC7F282),
+	(0xC00330E4F3608971, 0xC058800000000000, 0x38338C582E4B6777),
+	(0xC00FC6832E016F11, 0xC02E000000000000, 0xBE11C85B1931231E),
+	(0xBFE1A9B5A659BB7C, 0xC053C00000000000, 0xC42A8BDFC4397C7A),
+	(0xC006E959D954A63E, 0x401C000000000000, 0xC098B154169FEEF7),
+	(0xC004630C1C54CF1A, 0xC051000000000000, 0x3A32C13AA635F31C),
+	(0xC0067A8A8F46F308, 0x4036000000000000, 0x41FBAD99E287E0AC),
+	(0xC007266D18C8E1F7, 0xC03C000000000000, 0x3D40E207DEA76037),
+	(0xC00AC98B30528AC4, 0x4050800000000000, 0x4720C9F025B11E52),
+	(0xC00419F89CFF0CF3, 0xC03E000000000000, 0x3D716D0F09F858EA),
+	(0xC001CB5EFBE0F2BD, 0xC044000000000000, 0x3D0D28CA4F67E092),
+	(0xBFE026DCF3F08930, 0x403D000000000000, 0xBE250A58F643BBC1),
+	(0xBFF5F9C4B3E28DBE, 0xC054400000000000, 0xBD9E2D7D1F4B65BD),
+	(0xC0083A88C47ECCD9, 0x403C000000000000, 0x42BB220E4263B465),
+	(0xC001582545F93FA7, 0xC028000000000000, 0x3F184E9CDF57B8C9),
+	(0xBFFBBCF08B34ABEC, 0x4057400000000000, 0xC48C4D777EFF8F0A),
+	(0xC005BFF48C6A1933, 0x4056000000000000, 0x47DF84119ED46855),
+	(0xC00ACE9EAD1FEE3D, 0x4052C00000000000, 0xC81CA801693F8A25),
+	(0xC00C1708D7CA8CD4, 0xC051400000000000, 0xB81F68D630176331),
+	(0xC00517AEC6F491BE, 0xC057000000000000, 0x37E40526D60539C4),
+	(0xC00CDD3194C5B150, 0x404C000000000000, 0x466968B5747C9B42),
+	(0xBFF9B1D17E62C23E, 0xC056000000000000, 0x3C2D117E50CF79D6),
+	(0xBFBCBF44FED65000, 0x404F800000000000, 0xB3831E2E728EB03F),
+	(0xBFD1B7D890CE9B98, 0xC04A800000000000, 0xC6126377E26AA683),
+	(0xC00C0D6BF7DA99A9, 0x403D000000000000, 0xC3367ED3F654EE16),
+	(0xC0081A1DFE41AC9B, 0xC018000000000000, 0x3F55E8EB37C27270),
+	(0xBFBDDA53C23E6580, 0xC047800000000000, 0xC90A2A45F334BFEC),
+	(0xC00E668608B9BAD5, 0xBFF0000000000000, 0xBFD0D782AF5BEA6F),
+	(0xBFDB1EC149A93688, 0x4049000000000000, 0x3C10BBA87B2EF79C),
+	(0xBFEF177552EC3E1C, 0xC056C00000000000, 0xC02B7C7A1FECE80A),
+	(0xBFCF47408C3AC270, 0xC058800000000000, 0x4C62B36A19902C6A),
+	(0xC00CFFB1020D5C73, 0x403B000000000000, 0xC311ECB1A1AAB618),
+	(0xC00ECA6D84A106C2, 0x4053000000000000, 0x492B671B9215DF0E),
+	(0xBFF4CC8F6ABB76CE, 0xC04B800000000000, 0xBEA231CD8BBE37B1),
+	(0xBFF045017FED6AC0, 0x404B800000000000, 0xC0040D3DEA6497DE),
+	(0xC00130EBA3EFABD6, 0x0000000000000000, 0x3FF0000000000000),
+	(0xBFE8DD63C19E54B8, 0xC058C00000000000, 0xC2305DF60E751814),
+	(0xC00219D4471CBC2D, 0x4045800000000000, 0xC3192B133F786814),
+	(0xBFEEFB479299B988, 0x4055800000000000, 0x3FAFB6DC38123649),
+	(0xC002BE327DF47C65, 0xC03D000000000000, 0xBDB4D23391927496),
+	(0xBFE875F1638C0E80, 0xC052C00000000000, 0xC1C0CCD6428CA785),
+	(0xBFF82D8C39572226, 0x4052C00000000000, 0xC2B97B3B8FAE4978),
+	(0xC0036AD9D98B1054, 0x4047000000000000, 0x439CC66C0002DCDD),
+	(0xBFD6C30FFCD7D2A0, 0x4042000000000000, 0x3C93CE7DFE7DC70D),
+	(0xBFEAC2ECC47CB964, 0x404E000000000000, 0x3EF705CD65DE5B7E),
+	(0xC00C9B1388394CFD, 0x4052C00000000000, 0xC88D3172CC2E9983),
+	(0xBFE50E9E03295BAC, 0x403B000000000000, 0xBEE9F6501323C84E),
+	(0xC008A7B15C40BF0D, 0x4043800000000000, 0xC3E417DFED87A113),
+	(0xBFE9CC78207E832C, 0xC03B000000000000, 0xC074FA9F270325B5),
+	(0xC0004E758E6C46B8, 0x4057C00000000000, 0xC608427D9C9C74F9),
+	(0xC0076948914AEC26, 0xC040000000000000, 0x3CD585E749E2D90E),
+	(0xC0024D50E82F4660, 0x4052000000000000, 0x454F309D192D5A4F),
+	(0xC00E299EE220D687, 0xC056C00000000000, 0xB50B28E1F4942E19),
+	(0xBFFC8D7F4621E6FE, 0xC04D000000000000, 0x3CE73A177C829F3E),
+	(0xBFF023A049467952, 0xC052000000000000, 0x3FE1274CFB6B63C8),
+	(0xBFF86E9EA0A117E2, 0x4050000000000000, 0x4260F93725602F29),
+	(0xBFF0AD6ED1865F18, 0xC047000000000000, 0x3FC2FFD58E76F174),
+	(0xBFF8F90ED3B2F03E, 0xC034000000000000, 0x3F21CE04B4AB6687),
+	(0xC008914EFD0174B2, 0x4008000000000000, 0xC03CF61B3B003CEA),
+	(0xBFF521225EF5F462, 0x404B800000000000, 0xC150BCCAD59DABDE),
+	(0xC004B592C35A58CB, 0xC04C800000000000, 0xBB0B8E7AE1075C66),
+	(0xBFF26F163C479E40, 0x4041800000000000, 0xC061C17DA21391AE),
+	(0xC00D769338E45F6C, 0xC043000000000000, 0x3B77123E92A382FB),
+	(0xBFFF899CEFD52C90, 0xC03B000000000000, 0xBE47B41F46E29256),
+	(0xC002730C395613C8, 0xC045800000000000, 0xBCB1EB31F13C8AA4),
+	(0xC003F72F0C84144D, 0xC056C00000000000, 0xB86E80578CE3B747),
+	(0xBFFA991935EB791C, 0x4018000000000000, 0x40351AC9DD7E5034),
+	(0xBFDB318D3EBBB2A8, 0xC051C00000000000, 0xC5697AD49FEBEA8A),
+	(0xBFE445B299ED48D0, 0xC042000000000000, 0x416A2495F1518C9A),
+	(0xC00443D0CEA99801, 0x4040000000000000, 0x429E0C220B56D37B),
+	(0xC00E81BB2C985A2A, 0x4044800000000000, 0xC4E20AEEDE034660),
+	(0xC00DA75B6CFA115F, 0xC03B000000000000, 0xBCBF4219C6BE192C),
+	(0xBFF9123FC5D108BC, 0x4051800000000000, 0x42C47FC1910103BA),
+	(0xC000D198AF61CE93, 0xC049000000000000, 0x3C951D31CFDAE965),
+	(0xC003B215CD70DA7F, 0x4050000000000000, 0x4522387EE3F09140),
+	(0xC007F936FB66DA56, 0xC051000000000000, 0x3934201FE27ABC45),
+];
+pub const RAND_RANGE_F64_SEED: u64 = 2583;
+// (origin, bound, output) f64 bit representations
+pub const RAND_RANGE_F64: [(u64, u64, u64); 384] = [
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FEBD4D278CDE5D2),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FCDC3A5AD7E6208),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFCC74CEF197C6E),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x00143B56941378E2),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7F9CA712B6ED4A50),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2CDC88D25F6CCB),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA043D2),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE79F749B7CBF3C),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFA89B83E760FEA0),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF6500BBFD3DD54),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x001E62F2DE69FE44),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340006),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFD6A9CA19FF324C),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE009D075106B620),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FCC06),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD8399B5CA353F6),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFDB47DFE2AF3900),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF74301C9C126C1),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x001331DBF01BB4B1),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340004),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FCFBBC5BAEDD0DE),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2632031B00967A),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0AFB4),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD69FC0B09B480C),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FE8ADB332E8FE8A),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFEFDCA49C14F8A),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001083529B402E36),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FC1C020B086733A),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2388C8EB88EE51),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0238D),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FEA35DB09B98349),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFC2261BD4808F70),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF10859CF89DD34),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x0011C087F305048C),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340007),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD797E52E20477D),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFDF8847DFBD159C0),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA07D37),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3F949E008174F1C0),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFC420D0CC99D848),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF085C8CC89653A),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x0018E1D407414033),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340003),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDF08BF7F5D1395),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE00CF493F9CB200),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA00BE0),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FC4B92085E4C3A0),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFE80D27B8C5E328),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFB22430BACBE95),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x00184BE9ABC6EDBD),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FC00A2326A8DB22),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE34FC36BE40B574),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FD98B),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD0AD9E44F9D542),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FEC3901B48456BA),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFAD8FC13C23B7E),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x001D0CE71D0C796D),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFAA40683C208730),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE00E712E30944B8),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FBEDF),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FEBA29A2D201AA3),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FEA06A986483CE8),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF5E108BEF04379),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x00195123C6B8690A),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDF88E4F47B00AD),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE300A54851A9BC8),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA07B57),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE116A81213656A),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFC210D3668E00D0),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF383FD94CDA597),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x0012BB9AF2EB0BF4),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD7814BDC3E4773),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE21ED2F0DD11DF1),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FD2A6),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE6FCBC06A7F669),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FEA55EC3D6A1382),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFB95326222FAFE),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x001FA1D382D2B97E),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340004),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFD154E4850023E4),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE33636EBEB6DC33),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FF1AF),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FADD11B87B1CFA0),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FE4DEE00EF55968),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF4B48A893571B0),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x0018FF24AA28357C),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FCE6070C7815556),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE36F7D88DE70236),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFE),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0A593),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD7935850511B94),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFE02C20424BC546),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF37BA2A3B4578E),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x00154737ABA1D321),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340003),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFD5ADF35D4CA49A),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE179FCA9D8CAC5C),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA060D7),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD87DFB7732F184),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FDBB0EBFE492860),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFA2842D3FBBD9E),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001213A67C3CEE14),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7F841A0C73A49220),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE03BA33C4403AB8),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA05CC9),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FEF05AADE88A377),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FD7B17E35F4A510),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF54AF65E578B96),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x0017FC97A26E51C1),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340004),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDD2A073734E55D),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE1D0FDDDDA5AD10),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FC29C),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FEAA9265095B813),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FCA39F2688280C0),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFE74A8084B5B9E),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001CC036BCDB26F2),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDDA5B0BC0CE9B5),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE10E9C8CEFE4E44),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFE),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FF164),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE83EE7DBC3F8C5),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFEFD382AE527C36),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF9D223A79C7654),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x001430EA662CFFA8),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340007),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD7F9AD5F97B717),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE1462FBD0522E2C),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA09770),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FC987F1B20A1E94),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FDC07B260969FA8),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFF185C7CF44716),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x00103626C8E4992C),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD1BAD7915C426D),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2901E00145F12C),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA06DD8),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE06EB0B55E7329),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FC31A04C0FD0CF0),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF91858CA6AA087),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x00137FBD7EBFEC66),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD0A3506D71EEE9),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFDE7A416E6931D00),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA05C07),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FD2642B38816E1A),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FCB6A8C3562EBC0),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF1C5A22D202954),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x00115F8A1F592475),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340003),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FC68B0127C16BAE),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE1C2754AB260F2C),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA04E5D),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FC5A47A367186F4),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFECFD9EB347AB7E),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFA6E1B3A4A493C),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x001F915C0D151386),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFD06CB2C77A288C),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2125C5BEF0F571),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA04CD6),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FCA4C684CCC3914),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0x3FEA6B5EF48261B8),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF95619D3AAEE52),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001C8CFD3A48BDE6),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD12100A2A174CD),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE0117EE3220CBF8),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA028DD),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FAC3442817E2BA0),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFE3F6601CCBF2DA),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFEDFA6AD678288),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x001E5235C6AE935A),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340006),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDB5B67A4357293),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFDE51951FE03EC60),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA08D61),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE04532BA5B4328),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFE80EC416897038),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFCD7C1D38B6972),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001272ACC2D4EEB6),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FDC38A1F7E204F3),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE35C2C58A3B2B78),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA08C3C),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE10FDC0C4B68FC),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFDF9B31642050D8),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF2788C043B5E4A),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x00107185EC2073B0),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340002),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFDDC4D8B00FBB91),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2F14075355DD72),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA08536),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE22AF71B780EF2),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFE274B1A74C00E6),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF723354CB80A3B),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001B649C684DE2FC),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340007),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFA88BFF6A06A280),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE317C48F98D6D2E),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0C4EA),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3F849D90AA586040),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFB765D4A3084F40),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF4C8905EC72AE5),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x001F173A670FFC9C),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFCD559AB069432C),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE30F44A1EF7C81F),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1A9FE9D6),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE94BDB6DBC63C4),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFCCB4CD27F913B8),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF96B2B11F3A122),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x0017C22DF3770876),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFDBF09C3AF1E6D3),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE30D80BA0E8AD3D),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0084A),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE28926A2D8F8E9),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFCFD05F4D35E858),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFD19CBF84DC00C),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000001),
+	(0x0010000000000000, 0x0020000000000000, 0x0019C11A99409D2E),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340006),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0x7FD951ABBE73AA35),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE2BF4D0E99CD37D),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA07D1F),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE1DD1E7BB7C21E),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFB9CDA5A265E450),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FFF3534A8EE3444),
+	(0x8000000000000001, 0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x001880E837FF3D3B),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340000),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFC1D8C1BEA45954),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE331A3F78F6AC7C),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0A751),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3FE92CB293B000F7),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFC3F49B6EE8F788),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF2C8095B76DB76),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000002),
+	(0x0010000000000000, 0x0020000000000000, 0x0019372E933A623A),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340001),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFCFA4E844AB1680),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE305A5873DC1276),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFF),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA0355F),
+	(0x0000000000000000, 0x3FF0000000000000, 0x3F789BEA03D73180),
+	(0xBFF0000000000000, 0x3FF0000000000000, 0xBFC4D81F9A5C4248),
+	(0x3FF0000000000000, 0x3FF0000000000001, 0x3FF0000000000000),
+	(0x3FF0000000000000, 0x4000000000000000, 0x3FF62BBD4B25E424),
+	(0x8000000000000001, 0x0000000000000001, 0x8000000000000001),
+	(0x0000000000000000, 0x0000000000000003, 0x0000000000000000),
+	(0x0010000000000000, 0x0020000000000000, 0x001EC67E27388E88),
+	(0x430C6BF526340000, 0x430C6BF526340008, 0x430C6BF526340005),
+	(0xFFDFFFFFFFFFFFFF, 0x7FDFFFFFFFFFFFFF, 0xFFD893163C851F1D),
+	(0xFE37E43C8800759C, 0x01A56E1FC2F8F359, 0xFE233EBBA2C3AE16),
+	(0x4202A05F1FFFFFFE, 0x4202A05F20000000, 0x4202A05F1FFFFFFE),
+	(0x405EDD2F1A9FBE77, 0x405EDD2F1AA0D158, 0x405EDD2F1AA04B02),
+];
+pub const RAND_BOUND_F64_SEED: u64 = 2706;
+// (bound, output) f64 bit representations
+pub const RAND_BOUND_F64: [(u64, u64); 256] = [
+	(0x3FF0000000000000, 0x3FEC366C9A8DD381), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000C162B1ED46728),
+	(0x01A56E1FC2F8F359, 0x01A35B7D444ED45E), (0x3FB999999999999A, 0x3FAA7E7506A7F77A),
+	(0x7E37E43C8800759C, 0x7E1EC37B5C0A5C93), (0x7FEFFFFFFFFFFFFF, 0x7FE44F96A915BE4B),
+	(0x3FF0000000000000, 0x3FDEDE88CA0C6844), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000689764D2D2340),
+	(0x01A56E1FC2F8F359, 0x0175B67ED6F6BCC0), (0x3FB999999999999A, 0x3F94EC1754A8EDBD),
+	(0x7E37E43C8800759C, 0x7E04545016A2FC6E), (0x7FEFFFFFFFFFFFFF, 0x7FC852A1A7C7D843),
+	(0x3FF0000000000000, 0x3FED629C53A6E492), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x00073E703772D95A),
+	(0x01A56E1FC2F8F359, 0x0194BDDB5ABC212E), (0x3FB999999999999A, 0x3FAFBB3968352602),
+	(0x7E37E43C8800759C, 0x7E268CDE457CD1EA), (0x7FEFFFFFFFFFFFFF, 0x7FEF690E77D4164F),
+	(0x3FF0000000000000, 0x3FC08615F7423A2C), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000A3BE7ACC43587),
+	(0x01A56E1FC2F8F359, 0x019FA2D0DE0C2A55), (0x3FB999999999999A, 0x3F8A81676843566D),
+	(0x7E37E43C8800759C, 0x7E2526F9504ED973), (0x7FEFFFFFFFFFFFFF, 0x7FA9704A9E595ECF),
+	(0x3FF0000000000000, 0x3FDB43D867B1BE62), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x00099F4C0DA21FEA),
+	(0x01A56E1FC2F8F359, 0x018D63BA17A14475), (0x3FB999999999999A, 0x3FADE37169D0EF8A),
+	(0x7E37E43C8800759C, 0x7E2B016C9CD89046), (0x7FEFFFFFFFFFFFFF, 0x7FD2944235FB1A4B),
+	(0x3FF0000000000000, 0x3FDE97F434B46B28), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000000), (0x0010000000000000, 0x000C55D636DAD9EE),
+	(0x01A56E1FC2F8F359, 0x01A0E9199FA84AAB), (0x3FB999999999999A, 0x3F847528E59B84FA),
+	(0x7E37E43C8800759C, 0x7E30FE07F6AEDA65), (0x7FEFFFFFFFFFFFFF, 0x7FE539E5B513C012),
+	(0x3FF0000000000000, 0x3FDF47F01597E254), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000971F18276EB90),
+	(0x01A56E1FC2F8F359, 0x01A280F85ED902CD), (0x3FB999999999999A, 0x3FA86490B87A861C),
+	(0x7E37E43C8800759C, 0x7E27582029B9F578), (0x7FEFFFFFFFFFFFFF, 0x7FE49CD2798B4927),
+	(0x3FF0000000000000, 0x3FE230B03CCB6058), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000FACE4E2BBE097),
+	(0x01A56E1FC2F8F359, 0x01A44B628C5B464D), (0x3FB999999999999A, 0x3FB3DF452FAA628E),
+	(0x7E37E43C8800759C, 0x7E258A31C0B8B752), (0x7FEFFFFFFFFFFFFF, 0x7F907EF26F9C75DF),
+	(0x3FF0000000000000, 0x3FE1BE50157D4EE9), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0004A4BBB058D5D7),
+	(0x01A56E1FC2F8F359, 0x019F73A9EDBC203B), (0x3FB999999999999A, 0x3FB6151911A330C7),
+	(0x7E37E43C8800759C, 0x7E2A34190EEE190E), (0x7FEFFFFFFFFFFFFF, 0x7FD67E0DF651AC9B),
+	(0x3FF0000000000000, 0x3FE91DCA5C703493), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0003666EE9D0D672),
+	(0x01A56E1FC2F8F359, 0x01A561551605B001), (0x3FB999999999999A, 0x3FB7A2DDF798B2C4),
+	(0x7E37E43C8800759C, 0x7E28724857F96719), (0x7FEFFFFFFFFFFFFF, 0x7FE8E8E6E3B87D86),
+	(0x3FF0000000000000, 0x3FE280B90F670378), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0005D44DFB370916),
+	(0x01A56E1FC2F8F359, 0x018887A19C4D1B00), (0x3FB999999999999A, 0x3F9B8362A8EA4BC7),
+	(0x7E37E43C8800759C, 0x7E047BCC122B5EC9), (0x7FEFFFFFFFFFFFFF, 0x7FEC3CC366777632),
+	(0x3FF0000000000000, 0x3FDB237EF0E92D40), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000878A7D250AD40),
+	(0x01A56E1FC2F8F359, 0x019CC31CAA4877C4), (0x3FB999999999999A, 0x3FB8040722A4D653),
+	(0x7E37E43C8800759C, 0x7E25F8CE0BFCB98F), (0x7FEFFFFFFFFFFFFF, 0x7FEAD3A0B8AC6806),
+	(0x3FF0000000000000, 0x3FC1A5B0EF3F0208), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000C54B3F030E876),
+	(0x01A56E1FC2F8F359, 0x01A402BC4BDA1E79), (0x3FB999999999999A, 0x3FB70BAEFCCBAB48),
+	(0x7E37E43C8800759C, 0x7E350909E7E8B220), (0x7FEFFFFFFFFFFFFF, 0x7FBF5368D9CB807F),
+	(0x3FF0000000000000, 0x3FDEFF36498FF2F6), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000000), (0x0010000000000000, 0x000E3BBB56626390),
+	(0x01A56E1FC2F8F359, 0x01A17577D9FC6289), (0x3FB999999999999A, 0x3FB695B8AADEEB98),
+	(0x7E37E43C8800759C, 0x7E354ED517E4C7D3), (0x7FEFFFFFFFFFFFFF, 0x7FD9A1257A58566B),
+	(0x3FF0000000000000, 0x3F972AEC6855CA80), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000000), (0x0010000000000000, 0x000F4A817AB2B7F0),
+	(0x01A56E1FC2F8F359, 0x0181A2F558426AA3), (0x3FB999999999999A, 0x3F873521EAF759A0),
+	(0x7E37E43C8800759C, 0x7E22D25AB3D5EF37), (0x7FEFFFFFFFFFFFFF, 0x7FDC3E13D8CAC179),
+	(0x3FF0000000000000, 0x3FC6CCF554A4BAF8), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x000E0AED69B3ACC6),
+	(0x01A56E1FC2F8F359, 0x0191C48683CF960B), (0x3FB999999999999A, 0x3FAA7D62D0D1F47C),
+	(0x7E37E43C8800759C, 0x7E0E69BEB1EFFE34), (0x7FEFFFFFFFFFFFFF, 0x7FC35FABE60132FB),
+	(0x3FF0000000000000, 0x3FE9685CB92F3BD7), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0003D3F2AD06444C),
+	(0x01A56E1FC2F8F359, 0x01A2D87642F43A29), (0x3FB999999999999A, 0x3F8CAEA24FA99E94),
+	(0x7E37E43C8800759C, 0x7E03371B445EE81A), (0x7FEFFFFFFFFFFFFF, 0x7FB47401F10C559F),
+	(0x3FF0000000000000, 0x3FE5559A8A5A36A5), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000DF5BA5783990F),
+	(0x01A56E1FC2F8F359, 0x01A4A80D163821BD), (0x3FB999999999999A, 0x3FB5A4DD632599A4),
+	(0x7E37E43C8800759C, 0x7DB0ED8D2AF87963), (0x7FEFFFFFFFFFFFFF, 0x7FEFB7D816D59FEA),
+	(0x3FF0000000000000, 0x3FEA399305B0EE98), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000E420BEE578B24),
+	(0x01A56E1FC2F8F359, 0x019E7041D52ECEAE), (0x3FB999999999999A, 0x3FAD114370C02BEC),
+	(0x7E37E43C8800759C, 0x7E2F7212EE4A8CF3), (0x7FEFFFFFFFFFFFFF, 0x7FD687ADC74E4C1B),
+	(0x3FF0000000000000, 0x3FE090FBC57C100B), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000280F68F213D6E),
+	(0x01A56E1FC2F8F359, 0x019D1FF651700B1C), (0x3FB999999999999A, 0x3FB0B255D3ECFDA6),
+	(0x7E37E43C8800759C, 0x7E2C9B561AA6498D), (0x7FEFFFFFFFFFFFFF, 0x7FEA9EC91C9495A1),
+	(0x3FF0000000000000, 0x3FD1DF0B1EFE6584), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000661B9CC2D62DE),
+	(0x01A56E1FC2F8F359, 0x01863CCFC47A2D29), (0x3FB999999999999A, 0x3F7D84F953A1221A),
+	(0x7E37E43C8800759C, 0x7E34B40A281F022C), (0x7FEFFFFFFFFFFFFF, 0x7FC4EDD4C436C20F),
+	(0x3FF0000000000000, 0x3FD8AA7D0B2510D8), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000777EED0DE5BB2),
+	(0x01A56E1FC2F8F359, 0x01669015B164F0D4), (0x3FB999999999999A, 0x3FAC67F5A42A6D45),
+	(0x7E37E43C8800759C, 0x7E287DD5BF09464E), (0x7FEFFFFFFFFFFFFF, 0x7FEEC5ADA3A08C6D),
+	(0x3FF0000000000000, 0x3FE4A0740A052B20), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x0006D4F1315622B5),
+	(0x01A56E1FC2F8F359, 0x018B6590238281BD), (0x3FB999999999999A, 0x3FB7269AF0CBA55A),
+	(0x7E37E43C8800759C, 0x7E324C0CBB4477CE), (0x7FEFFFFFFFFFFFFF, 0x7FD63AC609FC7AE1),
+	(0x3FF0000000000000, 0x3FDA7829A66017C4), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x0007CD881D760A7E),
+	(0x01A56E1FC2F8F359, 0x018BF805C9FCBD19), (0x3FB999999999999A, 0x3F75E7316E73E440),
+	(0x7E37E43C8800759C, 0x7DF8EAEAC9C7BBB9), (0x7FEFFFFFFFFFFFFF, 0x7FDE746BA6FE40F1),
+	(0x3FF0000000000000, 0x3FC0AA4B0E860474), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000F7BCF34484AB0),
+	(0x01A56E1FC2F8F359, 0x0192BF0C8A6D9CA1), (0x3FB999999999999A, 0x3FB02F8790438923),
+	(0x7E37E43C8800759C, 0x7E22CF431F9E7837), (0x7FEFFFFFFFFFFFFF, 0x7FD42F116C9D8B97),
+	(0x3FF0000000000000, 0x3FEB8D452E497528), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000C8D5FA9E75D40),
+	(0x01A56E1FC2F8F359, 0x017517A0CE16701A), (0x3FB999999999999A, 0x3F81CC959E6CEF47),
+	(0x7E37E43C8800759C, 0x7E2D0B9A5CAB93F6), (0x7FEFFFFFFFFFFFFF, 0x7FD192248ACEF715),
+	(0x3FF0000000000000, 0x3FE3C84A780E34C4), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0002EDB932F72BAA),
+	(0x01A56E1FC2F8F359, 0x0157705895D6143E), (0x3FB999999999999A, 0x3FB04193467559E7),
+	(0x7E37E43C8800759C, 0x7E30CE7594C4E03E), (0x7FEFFFFFFFFFFFFF, 0x7FE0D7EF2185782E),
+	(0x3FF0000000000000, 0x3FDCC5D51463BB36), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x000ADCEAFDC40C3A),
+	(0x01A56E1FC2F8F359, 0x017F7A4BB84D4C53), (0x3FB999999999999A, 0x3FAE4AB5D6795D42),
+	(0x7E37E43C8800759C, 0x7E13DFAB78459C37), (0x7FEFFFFFFFFFFFFF, 0x7FC020D004E69437),
+	(0x3FF0000000000000, 0x3FE1C4786AF06CC1), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000002), (0x0010000000000000, 0x0007027CC61B8F49),
+	(0x01A56E1FC2F8F359, 0x019AC4504C8B8094), (0x3FB999999999999A, 0x3FB5CF82CA850A2C),
+	(0x7E37E43C8800759C, 0x7E0B441747DE1BBE), (0x7FEFFFFFFFFFFFFF, 0x7FD14DFF348811A7),
+	(0x3FF0000000000000, 0x3FEDEDB47BBE6D35), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000000), (0x0010000000000000, 0x00090C97C9F31C7C),
+	(0x01A56E1FC2F8F359, 0x0185EA518E7126E4), (0x3FB999999999999A, 0x3FACF12778A8E597),
+	(0x7E37E43C8800759C, 0x7E17622B2BCE03AD), (0x7FEFFFFFFFFFFFFF, 0x7FD3021950BBA415),
+	(0x3FF0000000000000, 0x3FD49D5CAD208CBE), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000000), (0x0010000000000000, 0x00006B6FCDF06007),
+	(0x01A56E1FC2F8F359, 0x019B15A446E909F0), (0x3FB999999999999A, 0x3F9D3BEC252FBD67),
+	(0x7E37E43C8800759C, 0x7E22ADDFCB778156), (0x7FEFFFFFFFFFFFFF, 0x7FD1438809360327),
+	(0x3FF0000000000000, 0x3FD9B24DEB245524), (0x0000000000000001, 0x0000000000000000),
+	(0x0000000000000003, 0x0000000000000001), (0x0010000000000000, 0x0002B8EC11E787E8),
+	(0x01A56E1FC2F8F359, 0x01A37C0DF3A96275), (0x3FB999999999999A, 0x3F8B61932379E3C7),
+	(0x7E37E43C8800759C, 0x7E23D3CCBEE4498F), (0x7FEFFFFFFFFFFFFF, 0x7FCFEB398A2ED4AB),
+];
+pub const ZIGGURAT_GAUSSIAN_SEED: u64 = 2337;
+pub const ZIGGURAT_GAUSSIAN: [u64; 2048] = [
+	0xBFD1CFB638DE78B2, 0x3FE750C7DC115775, 0x3FF45D4321548D55, 0xBF90ACCD6AD65C32,
+	0xBFD601C5A79B6730, 0x3FE63331246C2F8E, 0xBFF91F85CB398493, 0x3FD9742DE3D8315B,
+	0x3FEEDF19243433E4, 0x3FE485CA7BA0AFBF, 0xC000E96FC8D1EF58, 0xBFF52BAB94E21AFC,
+	0xBFDBC24A61572F81, 0x3FF1AE4AD8949F5F, 0xBFEC9237697D9582, 0x3FF8589C93DD6DAB,
+	0x3FF21325BC53950B, 0x3FF41C56D1BEE7A6, 0xBFEAAAE70697C202, 0x3FF106AF7D3877EE,
+	0x3FC97B61B8EA4BE3, 0xBFE541E18A36DE6D, 0x3FF54467704E85CB, 0x3FFB0B5E1C0A5E16,
+	0xBFFB6310513D9639, 0xBFE4072824B12DFD, 0xBFF73ED8D92BD2B0, 0x3FFB250CAEE5E787,
+	0xBFE63E4F3BA5C6CB, 0xBFEB6FA483F272D0, 0x3FEEA4234F7187FF, 0xBFF708EF66DFDD33,
+	0x3FFFF54201976233, 0xBFC88FD63F224C98, 0xBFE8DF5243BA29C3, 0x3FD8B7ABE1152884,
+	0xBFF1CC0B3BF4B4C3, 0x3FE89509B842AA90, 0x3FDB776353875B45, 0x3FF9B0FB600690A7,
+	0xBFF12506FE2B80EF, 0xBFEEEF707792D642, 0x3FB353CC57EAC408, 0x400031AEAC1D4342,
+	0xBFD6D1E53B855B34, 0x3FDE3C25FA5B9985, 0x3FA8324E94347467, 0xBFE84EC1D0C8BD6C,
+	0x3FBF7B08E6F95C80, 0xBFD7B85D5F251E3B, 0x3FEC4BEFA1319B7B, 0xBFC86D085EB542E4,
+	0xBFDAE050910E7C5F, 0x3FD272D984BB4D0C, 0x3FDEEFA242A18951, 0xBFF50119DEC2777A,
+	0xBFFA0CAAC13B886E, 0xBFE6B3BCC4B56EE7, 0x3FCD8D8D51112813, 0xBFD9E3F6465BBBB3,
+	0xBFD8D1C382DF8011, 0xBFC1D63401EE1536, 0x3FE0009D601338F3, 0xBFE15EC493A4E821,
+	0xBFC6BFB798AAB767, 0xBFE42B47E16BE6A4, 0xBFE0BAF45426CD72, 0xBFB7D52A4479248C,
+	0xBFDCD4851E9370B3, 0xBFDB4F2FCD9FFE09, 0xBFC3AFF687F24764, 0x400162D3516DE20C,
+	0xBFD39967D00726A5, 0xBFEFF98CFA741F9B, 0x3FD6ECF4F2387E25, 0x3FCAAC8E45783AB5,
+	0xBFE5D0CA77DFE9E8, 0xBFE1B123CBEEBA8F, 0xBFF9550C1D7DB299, 0xBFD37E5C1925D6CC,
+	0x3F9E97D1BDEA42A9, 0xBFCD1CADF9340F53, 0x3FF59041A4B27120, 0x3FF8EE9BCE0AA7AF,
+	0xBFE4C389A8A72B13, 0xC002C5A3A2F7F0A4, 0xBFF99FB9461B1CF7, 0x3FD78FB68877F6A9,
+	0x3FEE6BC5CCCF55CE, 0xBFC1B1747A8C348C, 0x3FDA73D66B5F8A29, 0x3FF7C295708212B6,
+	0xBFD846F1108B23B2, 0xBFEEA33CFAFBEE9C, 0x3FF2C116A0F57EB3, 0xC001A7FF69777624,
+	0x3FFBDF6B7A6CAC46, 0xBFE10B3C7A0224A3, 0x3FF31CB5F4D14DFC, 0x3FE8EA2DC8ADF893,
+	0xBFD2C53DFF5B933B, 0xBFDDD9F65DE15D1D, 0xBFCBC7E40B5204C7, 0x3FED78BC47E58DF4,
+	0x3FDCF86EFEBA52FA, 0x3FF77B77AE9D0B04, 0xBFB267E4BDC97DE9, 0xBFC31346D5C19E55,
+	0x3FFD37253431A8E3, 0x3FC3F50001C5257D, 0x3FFC31790FAC3CD8, 0x3FAE196C88A3687A,
+	0x3FE1B8ED7A3F1E12, 0xBFEC9CEDF94DEAD3, 0x3FE4727FC3EC5F26, 0x3FEAA20B58C662A7,
+	0xBFCF82E91A690187, 0x3FCBAAC9BAD71B46, 0xBFF7943013921506, 0x3FE290BD0296C152,
+	0x3FDA541AED1003DD, 0x3FEAF1A123D9CE40, 0xBFBBDBA15FCA57EB, 0x3FEEA7877F3ED62F,
+	0xBFE0CE794264C152, 0x3FF33B8F1BFDA20B, 0x4008D6EDB58877FF, 0xBFE8468187BBD442,
+	0xBFE6D2BCAE0CBA82, 0x3FED42627C7B3A9D, 0x3FF8EB07C7E74704, 0x3FE19495D0DEA56D,
+	0x3FE109B56935CF9F, 0xBFF41882ADE7BA20, 0xBFD85848AAD0B936, 0xBFE849A473415CAB,
+	0xBFBB1F9EEF8E66D2, 0x3FCD0EE9C5EBB66E, 0xBFDDE57B3D331ED3, 0xBFE6E7D11334F5EF,
+	0xBFEDD9BE636F2598, 0x3FDB0CA5AE4D4D9F, 0x3FF14F10130DAB52, 0x3FB0F83A7C2AEC4C,
+	0xBFAE48745E352471, 0x3FF3BDE1047C780F, 0x3FD6E287BB4EBFB5, 0x3FE83425768D6B21,
+	0x3FE5EE51FF244A89, 0xBFF8ABFAD5746846, 0xBF7CD357ADE1E280, 0x3FC6D6F93CAF7D6F,
+	0x3FEAB6180297E6F9, 0xBFF2858CB69D6D6E, 0x3FF89C2534C3B576, 0x3FE2B558A452AEDE,
+	0x3FF2ADDA6E6A3DFF, 0xBFD75999E5A8B0D7, 0x3FD4AF814E6DA150, 0x3FE325BDF0E73404,
+	0x3FD1350827C9D827, 0x3FF3203E1D6446FD, 0xBFE8254AC1BF0D21, 0xBFD8EA8624CF9D86,
+	0xBFF6207B8F9D6418, 0xBFE888CAAB8B48B3, 0x3FE2E421C315EF38, 0xBFF1365DE80914BD,
+	0x3FE38907D3403D7D, 0x3F8067477DF8E80E, 0xBFD74DD41FF84AE1, 0xBFF8E8B293C06C0C,
+	0xBFCACFCD11218D17, 0x4002708E613E9EDE, 0x3FBAA35853FF127C, 0x3FF8DF18F70C0233,
+	0x3FEBC0FB15A5AD53, 0xBFEEA435BF84052A, 0xBFFFA9686D4CEA57, 0xBFE31D3B15860D88,
+	0xBFF0B7B93D1E10C9, 0x3FF3D4D71FEC516B, 0x3FE2E1E2490600C6, 0xBFC4158AFE43A5FD,
+	0x3F465B43D769FA58, 0x3FE0B83B2C85F97C, 0x3FF1074D65570737, 0x3FFDF3295F7B5129,
+	0x3FE081906C11FBED, 0x3FE4F00874BB5402, 0xBFE451330F2DCF5F, 0xBFD564195EE49D63,
+	0xBFE59E02D13062D1, 0xBFF183AAD09D9805, 0x3FEF49CAAB5EEFD7, 0x3FF04491AE37984F,
+	0xBFD5106201B2328A, 0xBFF1E7E0900C4C6B, 0x3FD2B45C7CCE975C, 0xBFF689426F434385,
+	0xBFC2133E35EB058C, 0xBFF789C0F1B5EC1A, 0x3FBAA54B60569D20, 0xBFEC8931AB3DAE4E,
+	0xBFF7B8CEC482629D, 0xBFF2B9A88741B252, 0x3FF0D5B157F2170F, 0xBFF5269A839DEF56,
+	0xBFD52FAA11264850, 0x3FC39F8ADD308D7C, 0x3FE14E5C40CA0FAB, 0x3FE17C6C505E0990,
+	0xBFB2A53F6AC18A35, 0x3FE35E3B7E403B20, 0x3FF4B0DE1EEFAF99, 0x3FF4B5F83045F920,
+	0xBFE04326836028B7, 0x3FC2B133E1597C73, 0x3FE2EEF10F4D3C87, 0xBFE6F292EE1A98BB,
+	0xBFF0864854D66AD3, 0x3FE7302BF845AD45, 0xBFEF7D5C00F1D8F8, 0xBFF070879C6B7BCA,
+	0x40021153F44F0DAD, 0x3FA75C3A7C864BC6, 0xC0004D321ED915AC, 0xBFF72FDE1C52C19A,
+	0xBFE514791DC75166, 0x3FF2554CD4A239B3, 0x3FF2A3FC38274168, 0x3FF1827D1B222EFC,
+	0xBFF53BD078B4BF6F, 0xC002B0DBA1E495D1, 0x3FEB4E0701BF3B15, 0x3FF051BF35C35E6A,
+	0x3FE614CCB199DFAA, 0xBFF05929D11B6ADE, 0x3FF2CE589F9CAA81, 0xBFFC0DEBA02FFC3C,
+	0x3FEF430B8D0F70DF, 0xBF6A5B5C9E350E20, 0xBFE64754A5185291, 0x3FFEF8F382570667,
+	0x3FD782A5AAC5232E, 0xBFBE5BCDA2C3206E, 0xBFD16925E5476569, 0x3FE717060484EEB6,
+	0x3FD815DCE7A3C60A, 0x3FEC26ED1D6AE8C3, 0x3FF6591EEB5E64FB, 0xBFCBCE42942AAC87,
+	0x3FEB63043B7DBDFD, 0x3FD21654900360A1, 0xBFA244917509169A, 0xBFFF81F445FEEC88,
+	0xBFC9B1711EFC168C, 0xBFFC6A7CF83D4467, 0x3FD120E0A512D253, 0x3FD5EF2E9BAC3FBE,
+	0xBFD9C4642FDACBEE, 0xBFF3191C7A5AD4BF, 0x3FD41FF374B9C467, 0x3FE446BA3D8B0454,
+	0x3FDA56C1E460FEA6, 0xC00159776FC340C4, 0x3FCF61D3A03BB7BC, 0xBFF07F785A2FEFCD,
+	0x3FFC7DA2B650EDCC, 0x3FF5AFF3FA7DB419, 0xBFF456B58E32004B, 0xBFE289B747E7CB43,
+	0xBFF3664F8547FC7C, 0xBFEA6EF8E942F86E, 0x3FFF77C11683BD3A, 0xBFFDC9ED776EC4FF,
+	0x3FFA09225A309F10, 0x3FF1179431E2DFA1, 0x3FE7F002C0BA6251, 0x40071EE959588758,
+	0x3FFB3E8CF9FD4DBE, 0x3FF67D041F19127C, 0xBFF0E0A34B7E21DE, 0x3FE4232CEE30A477,
+	0x3FBF87916898561B, 0x3FF6D9164FBC5192, 0xBF56DCB4A78E2D99, 0xBFC180EC57B51D62,
+	0x3FEB4BF0146C0C54, 0x3FF820B50B50FC0D, 0x3FEF4A9E2A759F2C, 0xBFF5671060603A66,
+	0x3FED020F4A80A080, 0xBFDCB74C6C094678, 0x3FF0B7DCCB699E7F, 0xBFDCC1990C4A5F82,
+	0x3FC660E6C20F86AE, 0x3FE32BD4125172A8, 0x3FB5B7EED833D5FD, 0x3FF37BAABD38E5D6,
+	0x3FF21A47277AD360, 0x3FE0E9227F9F39D5, 0x3FF95F3010E7DDD2, 0x3FE43C17B18A35A2,
+	0x3FE56D0BB24E77D4, 0x3FCE979129120DB9, 0x3FF47AEBD97E124A, 0xBFF32C9D7CB14A8F,
+	0xBFF1F09C8BBCE8DB, 0xBFF0D6F00EBEA86D, 0x3FD3A3F4C6D5AD70, 0x4000F203FC68E353,
+	0xBFDD30EB1C03265C, 0x3FB4519B2355E6F9, 0x3FF3344FBF37D41B, 0x3FFC022062D191F9,
+	0xBFE2638974047546, 0xBFCBCAD52B147DE4, 0x3FFD8CCF50B69A63, 0x40087EB92D47361D,
+	0x3FF8CB4712AB9ACF, 0xC003B4A55AA1548D, 0xBFF42BB10AE4CE65, 0x3FBF6F54F1CCC1D4,
+	0x40002C01615B87EF, 0x3FE299FD9A526D2B, 0x3FA6B44115DF041E, 0xBFCD7BA8932FF596,
+	0x3FE9C463A4BE289A, 0x3FD86F1880E6C9E1, 0x3FB9287C8C7B2647, 0xBFE2A8E100FDEE38,
+	0x3FCE193DF183C3C2, 0x3F6F1495DB533BD0, 0x3FEF8BD72366F1CD, 0xBFEA9B56418ABFEE,
+	0xBFBF24EF749E85E9, 0x3FE1902A2245B20E, 0xBFE57675213B92A0, 0x3FE3565F5A32FDCE,
+	0xBFF207F310B2C71B, 0x3FE4A72E95CAF784, 0xBFD849A42F13AAE9, 0xBFB393BC175AF126,
+	0x3FF0BB5860C5514A, 0x3FF330D42FF9F74F, 0xBFD84E3D661D9319, 0xBFF38499CFC367EB,
+	0xBFEBFC45E65408FB, 0xBFC978512475428B, 0xBF93A967785DA108, 0xBFE826312374C2F7,
+	0x3FCC38D25552499F, 0x3FF124E5859B4E60, 0x3FF25D428316088A, 0xBFF889B868FA956F,
+	0xBFE1CA0DF6F6AE84, 0x3FDF17E05102B60C, 0x40008D208C20E5D5, 0x3FF07D0FD9BB3E06,
+	0x3FBFDC66A3F0BCC1, 0xBFBCBB7A1E3DB627, 0xC00182A68F77F218, 0xC00140890177A90E,
+	0xBFD2BD2165C64D11, 0xBFD8846A6A2BAA1B, 0xBFB2C4BE88FBFDC1, 0x3FDBC9E1C7D5956E,
+	0xBFB536597FAA610D, 0xBFE33480507F66C7, 0xBFE8F04FD84631C0, 0xBFC6DEE4D9621861,
+	0x3FF00FE5F985E8DF, 0x3FF6987C6CD3C92E, 0xBFD89CCBB1DD6DAF, 0xBFEF8D42A4D8703E,
+	0xBFF3B79A430F84EF, 0x3FE0E55E31EB66E1, 0xBFEB8634B0A270EA, 0xBFE40A8BF0F4E381,
+	0xBFDBA2B25978DD5C, 0x3FC3D6E558D51489, 0x3FE92D18C04E2394, 0xBFF67C66A79D5810,
+	0x3FECBC14741B3692, 0x3FB249B10B0B080D, 0x3FD6FA1E912EB4BD, 0xBFEB7FE968E73CD1,
+	0xBFE502321F1B204F, 0xBFE950E01C51D8B6, 0x3FD54FCB937D2741, 0x3FE422FD748A9309,
+	0xBFBBD5672582B6C6, 0x3FB25FC77F087C0A, 0x3FE37678AA6837D2, 0x3F7192EB9B32294C,
+	0x3FE100B7E8D7C017, 0xC0014C96F508BB2A, 0x3FD4924CEF17B711, 0x3FF26E956FB4ADF8,
+	0xBFF917BE64E6E237, 0x3FE521C1A4B0386F, 0xBFF8BAD47A266453, 0x3FE56C3EF1C52C4C,
+	0x3FE4DA0E75C42FE8, 0x3FD76E41FFD82C97, 0xBFE1239149E0A861, 0xBFBD3A9BB95E2DEB,
+	0xBFE943DB013B064B, 0xBFEFC45B088016C6, 0x3FD2051335580D09, 0xBFDC868B45B654AD,
+	0xBFD2CB0F5A4FF4CC, 0xBFB003E4D81513D6, 0xBFF9B1A6689BC23D, 0x3FF302B75A665379,
+	0xBFF45DDE1EE0DD3F, 0xBFECFCAC907A2770, 0x3FF2B6909D615087, 0x4003945ADE375FA9,
+	0x3FE8D3B5D50125CF, 0xBFD452956766C1FB, 0xBFEA8F5C30ADD22E, 0xBFECABD921763F09,
+	0xBFE7CA0DCB76C8FF, 0xBFFB2090A559F66E, 0x3FCF300FE4C22A3F, 0x3FE15767C0B608FE,
+	0xBFE976E68070A870, 0x3FEA52A2E1690263, 0x3FFDAC76C2EDA83D, 0xBFF57D3603767C34,
+	0xBFBD74199D8C5EF6, 0x3FE8D6BB5C4AB19B, 0x3FF70B3FD81DA49F, 0xBFD90C413BA5C98B,
+	0x3FD3BAC80E14950B, 0x3FF5AC2B6FB92C25, 0x3FE24490F82E7A6E, 0xBFF0175E1DD42245,
+	0xC00038AA81C14194, 0x4005154AC30748EE, 0x3FCD4DA24FB00F9B, 0xBFE80AFA8CD14028,
+	0x3FEA41200AF5698E, 0xBFE443034CD3A512, 0x3FE80117A55731E1, 0x3FB894357BF73E0C,
+	0xBFBACEEA9EBA4891, 0x3FF14F0349A6F122, 0xBFF270A236531DCB, 0xBFEB3266C24F0D6F,
+	0x3FE32B35FD44AEE3, 0xBFB02EDCB951EDBB, 0x3FE5064BF774120A, 0x3FEBC155B47272A2,
+	0xBFC795AE758B7E10, 0x3FE95D38F0353142, 0xBFFA5B8A2084D4A2, 0xBFE75B8922E6B42A,
+	0xBF8230BD6FC64A3B, 0xBFEA79B37F86A5EE, 0x4001499E6A433E64, 0xC0051ECB5DB678D1,
+	0xBFF3DCBB1C98BBC8, 0x3FE4EA7C5BDBBC6C, 0xBFE2F25B35FA5278, 0xBFED5452D9939EEA,
+	0xBFE3357354A93C62, 0x3FE1C76E4378FADB, 0x3FF47D0ECB038004, 0x3FEC6EACA88CDFFE,
+	0xBFB6953FDD6BD074, 0xBFEBD70DE30F2A7F, 0x3FDA4A5172168679, 0xBFF08D4925BFA220,
+	0xBFF0AFC101457B5D, 0x3FD30EA0FAFE72C5, 0x3FEB53D8CC415FD3, 0x3FD5D5B4AFA26E1F,
+	0x3FA8F2F60B4C5D57, 0xBFEE0B0CB54804E0, 0xBFC729BE99E19EFF, 0xBFF1E13288CEB67C,
+	0x3FF19E8B70DB7075, 0x3F5EF7ACC61694A6, 0x3FE6E7A432AECA73, 0x3FE63005011ED32D,
+	0xBFF01808174676EE, 0x3FC9577A482ED2FB, 0xBFEB7CE4B204AF03, 0xBFF5F0203080D02B,
+	0x3FD5CC98813F5FF9, 0xBFF6BE86A407E3C2, 0xBFEF2BCE15F8884B, 0xBFD890FC11BFA367,
+	0x3FD7D538AD5AA7B1, 0x4001480A182F2218, 0xBFE27BA6A4E05869, 0x3FE15A5CCFD9B083,
+	0x3FC159446F15D77B, 0x3FF54760690EDAD8, 0xBFF1C91B909579D3, 0x3FC03226E9B3BECD,
+	0x3FFB55892EF652C7, 0x3FF12CE74B71C334, 0x3FE786FAC7B9BC80, 0xBFFD971C93F871CE,
+	0xBFDA0F67407FCCFC, 0xBFE27D13C1562E9A, 0x3FF6470A6E32853F, 0x3FDB61FE62B23C90,
+	0x3FDB7D597B8133E3, 0x3FE78E837B5EFAA1, 0x3FD4B8D628365725, 0x3FF85DAAC8F79997,
+	0xBFE9B791DB2E84CE, 0xBFF2B13F660F4BD2, 0xBFC75BBB0CC725B7, 0xBFD5D38E67C0A23B,
+	0xBFF065D61AB1ED6F, 0x3FF035E9E3AF7ECD, 0x3FC84403596D568D, 0xBFCB2965573131C4,
+	0x3FB580EFCD83C7CA, 0xBFDF9492BE41A5C3, 0x3FD27A50BB13C35C, 0x400068F3C275CB9B,
+	0x3FEBCD118E6FDC33, 0x3FF7FB57B258CC72, 0xBF9A7534995868F6, 0xBFE3E957A1585F57,
+	0x3FE889D219D7B1E0, 0xBFD5A5E43DD7C7C0, 0x3FD0157A921F1D53, 0x3FDAE18F4A9D769D,
+	0x3FE161AFF91DB9E2, 0x3FFE61024208CCD2, 0xBFD6CCEDE934A246, 0xBFD40DD953DDF797,
+	0x3FA3012A4E21BE2B, 0x4000ECC9045B342C, 0x3FA32BA9D8CA262D, 0xBFF1F90838075A91,
+	0x3FCC7A453A4F5E27, 0x3FEC6CF13065155E, 0xBFEAF15BFB0ABAD5, 0xBFECF6DD399112ED,
+	0xBFF832A91DD945D5, 0xBFEC3923F06CBB42, 0xBFE2C26D23234D71, 0xC001458D4DF3983E,
+	0x3FE37AAF533E089E, 0x3FD1A02C30B57E42, 0x3FDB469C6B2B911B, 0x3FDD5A4F0D0C5F1D,
+	0xBFF583B63346D519, 0x3FE5B5E771A87A6D, 0xBFE90C8FAC3A8458, 0xBFFE9BCCDB104799,
+	0xBFB020F415FF2D03, 0xBFEDEC5F0EA501BC, 0xBFF14DF6F6DEBA78, 0x3FF07B267580D39D,
+	0xBFF917BCD57A602A, 0x3FE7153EB5A21CD4, 0xBFEC3B5BDB4DF05F, 0xBFE0A1BADBAC9C51,
+	0x3FE96868EC7ED76F, 0x3FE0B29D02BCABF0, 0x3FFE67008DF32F80, 0x3FC401B95F9E5E02,
+	0xBFF944073227D075, 0x3FFBF00B387A442B, 0xBFEC4E42F28F002D, 0xBFF64B6AF3594495,
+	0x3FF007C8DCD67168, 0x3FDC52E95286AADB, 0x3FF0C6C7858762CC, 0x3FDA10555EFE4E1E,
+	0x3FF431C342561162, 0x3FBBC24B29F42270, 0xBFD0EB0A9FEEE6AC, 0x3FF8F235BAF435A0,
+	0xBFD2661B5F0B3FAA, 0xBFE54699FEB16CA7, 0x3FC910AE6EB84550, 0x3FF13FEE2437575C,
+	0xBFF2D14FC0CB8878, 0x3FDDCE829E0CD67A, 0x3FC19AC12765FB52, 0x3FD6089FF325E719,
+	0x3FF571B257003F45, 0xBFD6AF22C0F9C00C, 0x3FF55F1764B322F4, 0x3FACC4C33BE8C447,
+	0x3FC55C0B2B596414, 0xC002DE0F63143B0C, 0xBFE8CE22CBD46D9A, 0x3FF8410123C77EFD,
+	0x3FED9DEA49A15F9F, 0x3FDE0F6482846DF4, 0xBFEC0368A04C63F5, 0xBFCFEE97358ABEB6,
+	0x3FE768AFB03FC691, 0xBFE7BAA7926DD0E3, 0x3FCE0A4ACB59FB23, 0xBFF66991C9A7A8F4,
+	0xBFC1DC0CB4C3D789, 0x3FDAE642D631A816, 0xBFBA95EE6C920F8B, 0x3FF1C1F54AEB6CD6,
+	0xBFD8AC03950D37D4, 0xBFF781F168661323, 0xBFEFA8353172A02B, 0x3FDA2F346DAA782C,
+	0x3FF9CBBE9F49B229, 0xBFE0A62D11DA2E42, 0x3FA1E5A8E6F244A5, 0xBFF0A57259C1B461,
+	0x3FD805CD9EF32CEE, 0xBFAFB4AC1C5F14DE, 0xBF6CB9A9989762F4, 0x3FF28AB1C6F28220,
+	0x3FD088ED4BE87809, 0x3FE5B735F039D61F, 0xBFDDEB83494ED6B4, 0x3FD64FCB382791BD,
+	0x3FFF00E8C90F9D1C, 0x3FDC31AD708CA10F, 0xBFFCBA59F3A1B2A2, 0xBFC552A8D4F51AAE,
+	0x3FD8E057E833410C, 0xBFF679F0C74A4A49, 0xBFEA38C733326572, 0xBFF0F1C42F40D71D,
+	0xBFC2295B0FFDA203, 0xBFB15BA0714CEFDA, 0xBFDAA5805E6A8A88, 0xBFE010564613BDCA,
+	0xBFE959DA09844836, 0xBFB1EC4062517D29, 0x3FD24FAB98F25404, 0x3FE5C2425BFDEA03,
+	0xBFF0566847E0D65C, 0xBFBB9372E4261CBF, 0x3FC80AB835DCE17C, 0x3FF355DF0F5FCC33,
+	0x3FFD04D9FAA94724, 0xBFEC65EF0C6DFBB9, 0x3FF094B9E26EDE1D, 0x3FF41FF9C7B88E2F,
+	0xBFDC511F26AB697D, 0x3FD5A708FFCE53B8, 0x3FD0928157B87BF1, 0xBFE878627E11FC9C,
+	0xBFD327E19BDC6DD3, 0x3FD8AF8E0A30352A, 0x3FD9BC8782769AE6, 0xBFE510DA4A414711,
+	0x3FDEA2F14E95776A, 0xBFD408B729E5492B, 0xBF8A69AA3A6ABF55, 0xBFE675CAEC418D90,
+	0xBFEA7979C01F4F25, 0xBFEF7EE206B201DD, 0xC0000D3F9A0A48A9, 0x3FE693E4847ADFA5,
+	0xBFED0A8824F7C626, 0x3FE5D6B28463D66B, 0x3FDFD81C197F839A, 0x3FEAB43F6C2AF342,
+	0x3FF6E24F2422793B, 0xBFD12631E2346E53, 0xBFDCCE412D41CEBB, 0xBFE33FE141687A6F,
+	0xBFC59581F7264CE0, 0xBFF3B96AF5BCF1BA, 0xBFD4548596061996, 0xBFF26AF690362AFB,
+	0xBFC2D01A56CFDD12, 0xBFC29B4F1391B29D, 0xBFDFF315BB01FA4A, 0xBFE3439F6906106C,
+	0x3FFC4DA6420CE958, 0xBFF335283D668074, 0x3FF2A73060DCE5F6, 0xBFA008680AF0D2FA,
+	0xBFE07698AFC142E9, 0x3FD6ABCB4AEA7577, 0x3FD88F04E75194BF, 0x3FF22178C6E22DBC,
+	0x3FFC30F72ACCF461, 0xBFE1CE4BDBFACB25, 0x40008ABB18095A8E, 0x3FF7DAC6CD9D272E,
+	0x3FE871F6CDC9E0B6, 0x3FCFDCBCC00A5E0F, 0xBFFEE249BDC5EC74, 0xBFE96374727E923A,
+	0x3FED35478F479E1F, 0xBFE501A6133CD0F1, 0xBFEB805991B97140, 0x3FE2C1983125BAED,
+	0x3FD861BFD2C6EE70, 0xBFCD2F425C9A4192, 0x3FE3F9818C416F1C, 0xBFF5E8E2DE50C984,
+	0x400173D4BB70146A, 0xBFEEA4EDF224BDB5, 0x3FD22083AEA62495, 0xBFD105B6794D4E3B,
+	0xBFEFFF24F5C7981E, 0xBFF61B3AB52994C7, 0x3FC327325BEC10B6, 0xBFEDB36222E0F63C,
+	0x3FC2E208EE8022E8, 0xBFE0406CF050F502, 0xBFF450061899DBF4, 0xBFF63A6110104058,
+	0xBFF2A917CD3DBC69, 0xBFD33DBAA419B2AB, 0x3FD03469B86C2E91, 0x3FD9A88EB066C4AB,
+	0xBFBDDFEEE7B4F4EE, 0xBFF1EA145CA401CE, 0xBFF5FC9DB7CBF86F, 0x3FAFF3205538E2F6,
+	0xBFDA994F516E8146, 0x3FD5B97B36F7C08B, 0x3FCE218AC46148E8, 0x3FDE54937A6FE8D3,
+	0x3FFDBF6441BA7E1E, 0xBFEC39275388CF08, 0x3FF35DAC2C5C8307, 0x3FF4D231C049F947,
+	0x3FC5B79ED0FBF53B, 0x3FF7370A65E1B069, 0xBFF7D94C028D1017, 0xBFB8615A36AB22A6,
+	0x3FA812554B03E198, 0xBFDFE366FB9ECA08, 0xC00942C18983B245, 0xBFBA03501E9EC42D,
+	0x3FD91486637FB406, 0xBFD7D0330E4D2AAA, 0xBFFCEEBEAC24FECF, 0x3FB29F473C1758CB,
+	0xBFF5FD44E44AB4F2, 0x3FF7EBF7A77E82A1, 0xBFB66FDFA6D7EECF, 0x3FF2255F4E2EF6D5,
+	0xBFEF8F4F1E8A48A0, 0xBFF122F679072164, 0xBFC54C94921BFD69, 0x3FE5224F121FEB29,
+	0xBFF018CAC1408ACF, 0xC000B9620959AE01, 0xBFE92EC2BD94F972, 0x4000ACB26D559202,
+	0xBFE3DE058CEC949B, 0x3FE99BB148C483E7, 0x3FDE3F8EB4463499, 0xBFF477283278383B,
+	0x3FE7CF88EA882204, 0xBFE1C2EAC118A6F2, 0x3FC4654AEF10A7F5, 0xBFD2DDAF9CD8F1E3,
+	0x40022D6314E4FF37, 0x3FE468ECFC9CC886, 0x3FF26B15954485FC, 0xBFF061304C966A99,
+	0x3FCF16107093E32D, 0xBFCA4FFD55CE9058, 0xBFF00BA312B8545D, 0x3FE9A3D88C57031B,
+	0xBFFFEFB20900EC52, 0x3FCAEAE31218EB3C, 0xBFEBE769520AB9F9, 0x3FF98B3011E22676,
+	0xBFD3036FE7E186C2, 0x3FE7353D8891BE69, 0xBFDD4C3C99E0219B, 0xBFB20405AB3802BD,
+	0x400289352F5F666F, 0xBFF5CCD31D5CCCE8, 0x3FAA7217859FAB00, 0xBFF5F9119662524D,
+	0x3FE40F90C4035636, 0x3FAAE04EFC95E1B1, 0xBFC806ED84F02E2F, 0xBFD00487BEF11B5A,
+	0xBFFC378B1032F62C, 0xBFEBCEB4EB54F8F3, 0x3FF15EC8A7262E7A, 0x3FB7230DE32133D1,
+	0x3FD513305C1B07F9, 0x3FED3FFF07899F15, 0xBFE66A660C5CFA77, 0x3FF4C40C30B76246,
+	0xBFF0C2C6ABC7333C, 0xBFD85F065AD8DA29, 0x3FE1D8968175C31B, 0xBFF554C99AD95795,
+	0x3FF6069A9A111DA7, 0x3FDE363BA9770D4D, 0x3FF1C324D1CCA8FC, 0x3FDC06CE52A47A4B,
+	0x3FE24B2018B44AC1, 0x3FFD4CDB6B1424AF, 0xBFA497B3D7D1A3F0, 0xBFE33E19AEDA7BE2,
+	0x3FF3F1828CD50AE6, 0xBFBC28F65D282739, 0xBFDDCB356ED2F3F3, 0xBFF12F7A4DFBD07D,
+	0x3FE01E469C986CB6, 0xBFD90063C27C2257, 0xC0077014A5D43C9D, 0xBFF77DA4F1265AE7,
+	0x3FF7462292FCB222, 0x3FEC17E6F16601E3, 0xBFF7AE6C6029246E, 0xBFE92846B82B4431,
+	0x3FF00E7D9FC5EAC4, 0x3FE2F18B9E064441, 0x3FD517D7862C49EC, 0xBFF2B85F6684C2B7,
+	0xBFF442C93FD35654, 0x3FE38EA28C6DEBA2, 0xC00129D757C6E4B3, 0xBFF711E1E31B6B45,
+	0x4003486CD4003CA8, 0x3FE92BF856311A41, 0x3FAC5E6B824DD644, 0x3FE736AF93ACB0E3,
+	0x400194D48A70F520, 0xBFF48FE64293D0D9, 0xBFDAFE3C711AE424, 0x3FB13EEF6FDCAE22,
+	0x3FDCF6B21E0276B5, 0x3FD0F4381D4F0998, 0xBFD8A270F4C7F161, 0x3FD84EDB8D0DD4A0,
+	0xBFF53BB758895677, 0x3FCFA111D118DA2B, 0xBFDB6F8F973ED9C2, 0x3FF254AC6F4488E3,
+	0x3FD637B791BF8F0E, 0x3FF57670FFFF8BC5, 0xBFC263535F81C204, 0xBFD462C5B04204CD,
+	0xBFF384FCD856EF1B, 0x400495902FE73E28, 0xBFD44D4147285F0D, 0xBFB4244D5CF5F0F6,
+	0x3FF6069CFDBFE731, 0x3FF80270B07FA22F, 0x3FB25F13C382CB00, 0x3FD3F67A3116D584,
+	0xBFE7800FAC07A582, 0x3FF2558E8E56EED5, 0xBFC38458A0CD1973, 0x3FECA4154D00A222,
+	0xBFF0ECB8A23DA307, 0xBFC544AF675B56B4, 0x3FE1DE5B01D9CC18, 0xBFCCE43A1494B02F,
+	0xBFE5852FBFFD823C, 0x3FB88002357494E2, 0xBFF76946F5F5CA87, 0xBFCB8418F4BBFEF7,
+	0xBFC9884903DBD328, 0xBFD80B8E27A1CB19, 0xBFF20E7B8EA243E6, 0x3FC47ECC34FEFB7F,
+	0xBFFF20415730E20E, 0x3FD543400430C7DE, 0xBFED93EF8513B4E7, 0xBFE1FA5E0DFA29CD,
+	0xBFE2C04CE9DDB242, 0x3FE62A7EA951F1D4, 0x3FE6DF59F2A1C4BC, 0x3FD083C6DFDB7C87,
+	0x3FF2D2BF80AB957F, 0xBFF48D2662F2A7A5, 0x3F86D8593598680F, 0x3FC71DB36E639F6D,
+	0x3FE57AFA52C27867, 0x3FA76CCF34001C04, 0x3FE86605932E0CBA, 0x3FE216B66F5E4EE6,
+	0x3FF7DC34949B9C64, 0x3FE8A5712FFD1A83, 0xBFF6959741AD0898, 0x3FF541C2E44BD3FF,
+	0x3FCC8509BC0EE0B1, 0x3FEB16E12F7C968F, 0xBFB5F50D864BFEAD, 0x3FF119EBA8FAE5EB,
+	0xBFCEA3F8649D7A0D, 0xBFD0888C36F4349C, 0xBFEAD4AEAE663FC8, 0xBFC138A929EE9589,
+	0x3FB1F1573BB8F374, 0xBFBA87670AD5A8A9, 0x3FE392615B083688, 0xBFC57F9A64DF44F2,
+	0x3FCC18101A4CE5DE, 0x3FE02B35F70F57E2, 0x3FDBF333DF1EC22D, 0x3FF8AD45B8C0BF81,
+	0xBFDEE72B5DB97812, 0xBFE207E649456633, 0xBFE5705AF992614D, 0xBFEE39222F30E7C6,
+	0x3FE6E49AF2328B50, 0xBFF72B41F5ED30AB, 0xBFDAD9496B24DC4B, 0x400241F2D8ADDF3E,
+	0xBFE9092FF7809EBE, 0xBFED1EBFF1BBFE14, 0x3FDD771D03CC51CC, 0x3FE343CBA5C19057,
+	0xBFE9B84B28E5C5DF, 0x3FE54D7A2DF2F376, 0xBFCB4CC43FBCFD02, 0x400249050BBD592B,
+	0xBFD0A2F65C8EA106, 0x3F8E9F8B96688728, 0xBFE3482D264EFB14, 0xBFED39AB2F8F0268,
+	0x3FB54968185C7B47, 0xBFE33EF76E1112A3, 0xBFFD48F7CFDFAEE1, 0x3FEED1262FDB0A92,
+	0xBFF2D3DC5E3A6F51, 0xBFC08B9988FC9786, 0x3FE54D24B5D35471, 0xBFF165ABE47988BD,
+	0x3FF1D926B22E2DE0, 0xBFF1EEC59BC6C5C2, 0xBFE2D98AC20DFE32, 0x40098346AD0D1702,
+	0xBFE0ECB470EEEE7D, 0xBFF8B7DBEC015DE5, 0x3FD7CDEEC4D0EA56, 0xC002376F0E4B1453,
+	0xBFEE9D04D80EFC03, 0xBFF58A9F2E8821AC, 0xBFD399632E65C56A, 0x3FCBDB6BF3A985F2,
+	0x3F95E60FC0AFC6E9, 0xBFCEE7DE7C8FC740, 0x3FEF6C9C9182E94D, 0xBFD4BB9FCD8D6003,
+	0xBFCADAE48B26A52E, 0x3FEEF1C5A732D1F4, 0x3FE3ED7F2169CEBB, 0x3FC5817A8219D4C7,
+	0xBF9FC51EB03A63FA, 0xBFEF42DF9ED3A7AB, 0xBFFB81494187CAC6, 0xBFEC1146DBF950C4,
+	0x3FE21E4698BC3C00, 0xBFFDA0D1C4C0AF10, 0xBFE5E2F683A4D2D3, 0x3FE0BD2FDC05D1EC,
+	0xC000CF3150C4E2FD, 0x3FEE8399198AB15D, 0xBFE13BF05E6DCFE6, 0x3FF8233EE3215E64,
+	0x3FD42B979AD94D54, 0x3FDD61BBE9AB63A2, 0xBFBBDAD1C1B87753, 0xBFE989E40651D1F3,
+	0x3FD321451321F640, 0x3FE1B84B7A4ABE7F, 0xBFF272394E015D81, 0x3FE89E5712053D83,
+	0xBFEA8168DDD189C7, 0x3FE8ADDAFBB90462, 0xBFE87FF6C068CF08, 0xBFE471E7A31FE1E5,
+	0x3FADC56E674B50CA, 0x3FDC57CFE3CF3896, 0x3FF055B1FE8983E1, 0xBF80AE5ED0A7368C,
+	0x3FDDA42BF9A7000F, 0xBFDC8052C0858718, 0x3FED1D80796C14C9, 0x3FBB13CAF342D628,
+	0xBFFB57DA52650322, 0xBFC5052F0F85C944, 0xBFF7C0A86CCD10BF, 0x3FCAA794560D8694,
+	0xBFF090BA9A9769BB, 0xBFFDB09FD31D94F4, 0x3FF647E16BDEACBB, 0xBFE3029AD91C424D,
+	0xBFF4A435FD1FA10B, 0xBFDADABDE71FD500, 0x3FC0B00AD1BF1840, 0xBFFA1CC4C467257B,
+	0xBFEF132CE9367C69, 0xBFE0145D0DFFF6B1, 0x3FDB3A99A46B527C, 0x3FCEC8D85D8E6BFD,
+	0xBFD8A628385D1615, 0xBFF88687F2340277, 0x3FD7927F2E2B281A, 0x3FE1B6199D2651FD,
+	0xBFEF1853E2911832, 0x3FF3B2D475BAC85E, 0xBFAB213AE3C212D3, 0x3FD76B813940143C,
+	0x3FB7D935BA07F86B, 0xBFE8B1A13DD9ECA3, 0xBFE3BBB4B0CFD499, 0x3FF165DA66E3DF73,
+	0xBFE3999578492DB0, 0x3FBBAFB92D4DD36F, 0xBFE42BC1C47C244F, 0xBFF56A91EEE4C6AB,
+	0x3FE14CE59BF34B29, 0xBFD58B4557149C74, 0x3FF441241B338963, 0x3FF835F2B795F827,
+	0xBFDA184142FCBD94, 0x3FF0D1711F8FB5F4, 0xBFBD7E38E17B3E56, 0x3FF46A78AA8D4EF6,
+	0x3FE1964C4B5DE2A6, 0x3FF5989DD449D5F3, 0xBFF68C95B09077FE, 0xBFF4402F4CC44217,
+	0xBFAE024F253D858A, 0x3FE4370D28012227, 0x3FC7F4866E3740E6, 0x3FB596BDCB94EBF0,
+	0xC001DEB26C9A8748, 0x3FE1EF9F70132015, 0xBFFA54221DBBA7C0, 0xBFC2DA3078AE1432,
+	0x3FE35F096AA94E7A, 0xBFCBA57E9ED2D39A, 0xBFD9569F608C3B91, 0xBFB8EE56170EABF2,
+	0xBFDDDD18049F5C54, 0xBFE65AA75672F53D, 0x3FE4124F8A859193, 0xBFF7693FAA84931F,
+	0x3FF81DF25459CA06, 0xBFDE54765838E53D, 0x3FF9EE22D606976E, 0xBFF62E61864A3AC6,
+	0xBFF359EA9DBC0351, 0xBFF0369397F384FA, 0x3FE5B24EE3324E95, 0xBFE8E00A840D8217,
+	0xBFC4B7651694A998, 0x3FF630F6FA9FC7DF, 0xBFA7FBBFCEEA8700, 0x3FC186CDE5E7AF57,
+	0xBFF6C29FEB118389, 0x3FA0A6F3BFE9A8B9, 0x3FCBC23C544C526A, 0x3FE30D7C5F5D7ED3,
+	0xBFEFA95BB441563D, 0xBFEB08180DE45D3A, 0xBFED9DF73286EB59, 0xBFC8459DEE8766DE,
+	0x3FDD0DE59E9CDAC4, 0xBFCB87934A88D4BF, 0xBFD6B11E8B043F4E, 0xBFE54DA9BBE9C8CF,
+	0x3FFF5551A28F1174, 0x3FF14CB1FDD89CCA, 0xBFE65F781C3F1CE1, 0x3FD03033213AD625,
+	0xBF96154A3302D3CB, 0x400160F973C16370, 0xBF7B0528460ED836, 0xBFF30A39BC55B010,
+	0xBFC42FDD3CDADF9D, 0xBFBF60B1698B8DDC, 0xBFF2A5BA4C20FBBE, 0xBFE673D277568E13,
+	0x3FF8EFBD94EC7883, 0xBFDD54412CE89898, 0x3FEC1CEB7BBC7644, 0x3FD9F0DF8C362083,
+	0x3FC1B6BD599E2FFC, 0x3FE22B7FFF81B420, 0x3FC47461464F1524, 0xBFF24F44408F7892,
+	0x3FEC0FB06E034457, 0xBFE0D9A280111B09, 0xBFC6349710855DFC, 0x3FF0065B87830D0B,
+	0xBF882E149F6A217F, 0xBFB2C00922317EB0, 0xBFE8F155859ECBA2, 0xBFF0CAB491330F9F,
+	0xBFF0A501FD408104, 0x3FF304B4FB858B3D, 0xBFF54E5B1EA7EC77, 0x3FAB58FCE63DE8BE,
+	0xBFE0A6A7197AF1C7, 0x3FC0F7150BBF7BB7, 0xBFA5D91B185506E8, 0x3FFDFAC512633E10,
+	0x4002E766A7532D2C, 0xBFD49DC0B4E11529, 0xBFF832D19B4C0F30, 0x3FFE96CCE0226596,
+	0x3FF48CE1D2B53BE5, 0x3FDFC28B0F35C646, 0xBFD3B20F72423F28, 0x3FDFF2471579E9A5,
+	0x3FF21CB26C0A56A9, 0x3FE46F0C39EC52AC, 0x3FEFAB9D710834E5, 0x3FD6801D37E06E27,
+	0xBFF0F9D3757485A6, 0x3FF889E1F463CE16, 0x3FFA0B586132B148, 0xBFECFBE1A79A9504,
+	0xBFD135C7D43482CD, 0x3FF24EE8C47344CB, 0xBFF93652DB9EC8D6, 0x3FCF2D6DF016E441,
+	0x3FEDEF2A593BBF24, 0xBFAF664D8FE166F9, 0xBFE827C719CB4201, 0xBFD83265287216C8,
+	0x3FAA58697B88D757, 0x3FE796BBA3EDE98C, 0xBF8EBCBF9D7A3EA3, 0xBFF51C3E3B69943B,
+	0x3FD8E1E87C7FCA6F, 0xBFE5BE0AC764162F, 0x3FD355D451EDFCDA, 0x3FFC7D0B51FDFB77,
+	0xBFDA82B34C8241E1, 0x3FD362BF48CE788B, 0xBFDCDFC0B73213C6, 0xBFBA0DE6DA597131,
+	0x3FF89214DC943C90, 0x3FF12C86CE7ABDFA, 0x3FCCF71B103A4812, 0xBFE596BA5AE8FE2C,
+	0xBFF074059A149CA3, 0x3FDE4E458C5BF602, 0xBFF16DB7A31C457C, 0xBFF1FCD42DEB6BE7,
+	0x3FAB060E214C4F27, 0xBFEE3EEFD1195A9D, 0x3FB9AF4C50B0ECB6, 0x3FE9C433B04A47E3,
+	0xBFEB5C6564AA8CF4, 0xBFDA675F0C981A80, 0xBFB86667586FA32D, 0x3FF192A0CE7FA50D,
+	0x3FC2141B229BE9B2, 0xBF7E718631C20FAE, 0xBFDE1FFD004FB3D8, 0xBFFA35F6170CDF6E,
+	0xBFDEA897F2D61BF4, 0x4001C6A3664E4DAA, 0x3FF4110E560E27DF, 0x3FDA588125FED687,
+	0x3FC885144BA39242, 0x3FF691CB87239131, 0x3FE73C41E390E4FD, 0x3FE7A8DDBAD49884,
+	0xBFECCFCD578F3386, 0x3FD1C5113D178CD1, 0xBFC2BDD0EF9C669E, 0xBFF03354AD843E2E,
+	0x3FE7B6A237C165F1, 0xBFB4C007920FE30C, 0x3FE8EF6DF2FD8B74, 0x3FF2349C5E42B01B,
+	0xBFE4DC75365479B5, 0x3FCAD90E69F05174, 0x3FE9548AB84E70C6, 0x3FAC24360663C6E5,
+	0xBFE6D9232EC41D80, 0x3FD9F0FA11C90552, 0x3FBB2F65FE126019, 0x3FCB7E55CA7E6622,
+	0x3FEF5F7C88B2336E, 0x3FBC7CE8BDF53547, 0x3FAA8EA59FD6AE1D, 0xBFEC266597778239,
+	0x3FE914B41D4626A4, 0x3FF66BB0D1DDFCBD, 0xBFE2249C2206E65F, 0xBFF0458EFD81BC89,
+	0xBFD4F30977936B8B, 0x3FEB27B8E1B83F45, 0x3FCF991A64A5548D, 0x3FF493C9693C087C,
+	0x3FE581A248D0D5AE, 0xBFE44E68D0B49DC3, 0x3FE3B13A0ED52CC6, 0x3FF8632E6BF73551,
+	0x3FEF3C6CD84AF8CA, 0x3FED298F3D893A2C, 0x3FE485F475F745AC, 0x40008F0209877187,
+	0x3FF10085FD0CB677, 0xBFD562FED306FD64, 0x3FFBDC121EC44A2A, 0x3F906FCDB31605A5,
+	0xBFE15871DF1C8073, 0x3FBEA258A4A70CCC, 0x3FB2B094CB65E209, 0x3FFA33AE05CBED91,
+	0x3FD15441DB12F00C, 0xBFD0A5BF045E5A67, 0xBFEED226B404B364, 0x3FEC9C02EF4BDEE1,
+	0x3FFBF564F0160133, 0xBFBC3E6FA3D3DCAA, 0xBFE0D2D17714086C, 0x3FD0E37069550D36,
+	0x3FFEC5E22362FED1, 0xBFE26145AD44D9D8, 0x3FF0DFF2FD7B4934, 0x3FC4A2960396DCCD,
+	0x3FA6C8703B46A412, 0xBFE28F11C75C5E7A, 0xBFD87E4F1A182B19, 0x40019590049FE19B,
+	0x3FDF75F5B01986EC, 0x3FEC31BA89FE1C98, 0xBFCC58243CCB6348, 0xBFFC16B5F0BD7F80,
+	0x4003CC36DF613E8F, 0x3FDFA6F82EEBAD90, 0x3FD3BA3CE76BEF6C, 0xBFA4949D71EE551F,
+	0xBFD1112DBFEECE6D, 0x3FC8DB5F0248AA20, 0xBFF5FAF789F82E95, 0xC0019D540140E457,
+	0xBFEB0CF2876B54D6, 0xBFDB687341FA4993, 0xBFA0723821010CF9, 0x3FF62650AFBDA643,
+	0xBFF13BE5912707FD, 0x3FC404C8B839F568, 0xBFAA18EDCD4E7765, 0xBFF5323A2871892E,
+	0xBFBCE50093987CBC, 0xBFECE16D24147D90, 0xBFFCB4837E2B5F8A, 0x3FD0F3DBAA2E5A87,
+	0xBF9B27F3A93460AA, 0xBFE90A88BB96DBC4, 0x3FB0AB828143C8B5, 0xBFFCF146866820E3,
+	0x3FF13CAA67F446AF, 0x3FDB06CBD2D98CC3, 0xBFD932F311DBEFD4, 0x3FF00D89B621C7DA,
+	0xBFF17FC09593C6D4, 0xBFCC5587540FE8F5, 0x3FD3396AB0E9984F, 0xBFF35F6E4F01DF10,
+	0x3FDBAA548237DC37, 0xBFDD008E740C11E3, 0x3FF45F3EE682E58B, 0x3FE9E84D626DDE04,
+	0xBFD6E549A8476977, 0x3FB7A25540C542E7, 0xBFEA6B20A25C9D0D, 0x3FD33C9148E4756D,
+	0xBFBB2F0CFF607FA2, 0xBFF09ED0438E29CC, 0x3F93167B2E3F2962, 0x3FF3B84180ED2F93,
+	0xBFC0DF1FEF9C5237, 0xBFB0666F03D8520E, 0xBFE8FA0D1935533A, 0xBFF3F9F952246F3C,
+	0x3FB769F315FA2079, 0xBFF2FAD3EB01406D, 0xBFEA3AD66575CECB, 0x3FD1BEA64F3863CD,
+	0x3FC52CFB282CEC3F, 0x3FE7A88F2A56D88C, 0x3FE0EA7A970E8754, 0x3FF642435DE43B49,
+	0xBFEB7DA15A24A8F9, 0xBFE6585D9A2EB166, 0x3FEFD6DA537C519F, 0x4004145B1BE25D62,
+	0x3FE6BED1BE988F88, 0xBFCD3678AAC6E7F7, 0xBFE36789FC4F8BC2, 0x3FE8D9C1250D8F09,
+	0xBFE8EB901022813C, 0x3FD08A35DCC1A8D5, 0x3FF3D3F7D3816C2D, 0x3FC84C752E7AB04C,
+	0xBFF2BCD8003C7461, 0x3FE3C7865794A9E9, 0xBFF321F28FB7AF39, 0xBFBAE2ADF7C2ABDA,
+	0xBFFDDF3143A26434, 0x3FE39CC61740C0DE, 0xBFEDF9FB98C315D4, 0xC0037F1FAC8A4596,
+	0x3FC96D4328F580C7, 0x3FFAF96E91889A41, 0x3FE7574EFA29658F, 0xBFE33FFB10AAA23F,
+	0x3FD11A2683B06EFC, 0x3FF83997EE7EF06B, 0xBFE9F060A8DCFF21, 0xBFD22EA8CE25C52D,
+	0xBFD2B3A826235780, 0x3FF28B1BD81DAA4F, 0x3FE8982A897A3C5B, 0xBFEBE70272471B18,
+	0x3FBFF44E080A3F0C, 0x3FF2804D373FFCBD, 0x3FF6D4EB8EC24238, 0x3FDB9C2963CB9E4D,
+	0xBFE2038A2C84C15E, 0xBFFCAFFA600786D3, 0x3FE8B616AE3FB04E, 0xBFE4A60A07FC1FB6,
+	0xBFE9E97D91005ED5, 0x3FDE9907A0758BDE, 0xBFF3246B3DBFE05E, 0xBFF366C7C8895186,
+	0x40030CD332EC64E3, 0xBFE67504ECD89E97, 0x3FE07B3EEF5FC540, 0xBFD0C421CE30BF98,
+	0xBFDED611A61F70F2, 0x3FC7EBB4ED0AF294, 0x3FFE30C85A132B95, 0x3FCC7999116CF207,
+	0xBFC9D2D0F1BED1CC, 0x3FE2742C021059C7, 0x3FB168E601F859D3, 0x3FF6CBCDD07995A1,
+	0x3FE5387B54B57649, 0xBFF042EA862117DC, 0x3F58A5DF7E7F49DA, 0x3FEB1BADFB937314,
+	0xBFFC4D136B176D87, 0xBFE169415D6311A9, 0xBFF7043A82EB6D3A, 0x3FAE46F323B07F3C,
+	0x3FF01208B393E58E, 0x3FE9A53D924C80EA, 0xBFB8C952D9C7F4FE, 0x3FE0C062EBB24EAC,
+	0x3F944EB559F20123, 0xBF937F84DD30FDB5, 0xC00513F0D4A59766, 0x3FD248C103744D30,
+	0x3FB066279891BA08, 0xBFFB70F65C83D136, 0xBFF39089DC385CB2, 0xBFF7EA5662F47DFE,
+	0xBFD3772591867335, 0xBFE630A7C2012E84, 0x3FE6B84A723E19B5, 0xBFC87789C1B292BF,
+	0xBFE6284547C537DD, 0xBFED5AB911BF0333, 0xBFB2E25E1C1CF5AA, 0xBFB05130DB9D17E9,
+	0xBFE2521F9BD614B3, 0xBFD71077F614FB8C, 0x3F6A72B53D6BDE5F, 0x3FD3FCB9A0B57823,
+	0x4000091F6D430DF9, 0x3FCAFE542F47F920, 0xBFE8694F09D3ADD5, 0x3FD80C30ADFA7E20,
+	0x3FB76DA53E1135F7, 0xBFE8B7C20DC2BC1D, 0x3FC543B06AFA76E4, 0xBFF7D1E1AA5BDAD0,
+	0xBFEE964C70C35618, 0xBFCD709D9DB97F8C, 0xBFD46F1A7A3E9340, 0xBFC29568C37B7298,
+	0x3FC30D5FC70B9FC1, 0xBFEC3096DB5DA30F, 0xBFE81DE1BB25A16F, 0xBF9624648C419667,
+	0x3FDBC07C771DF4F8, 0x3FCCE1525B4F9BD5, 0xBFF11C0619E0B295, 0x3FA518B46342950C,
+	0x3FD778A93EE66D33, 0xBFDEA844FE9317B0, 0x3FCB9AA042B6C07D, 0x3FD386F9A06B6443,
+	0xBFF5B7E97351BABE, 0xBFBC93A8916D391F, 0x4005FA774273028A, 0x3FD27A1E8367314B,
+	0x3FD7E155453463AC, 0xBFE37E986A68AEDB, 0xBFECAAF7A59036C0, 0xBFEE04C0E93001E3,
+	0x3FE71D0FCD90FF4C, 0xBFD9F9D4A5F8F669, 0x3FD4A1CB3BB4D59E, 0xBFBB82220CA74553,
+	0x3FC9CED0F0453428, 0x3F9C78A927CB9758, 0xBFFD763E5ED44D0E, 0x3FEEC008508771EC,
+	0x3FFD2E0F7951FF6A, 0xBFD1024E0C883238, 0x3FFD35D9CBD98794, 0x3FE47D82D3746101,
+	0xBFF301601CD8678B, 0xBFCC92990AC841A9, 0x3FE8926426A0F1F3, 0x3FE192B43A114297,
+	0x3FEADCF48DC169DE, 0xBFA5A37C0E1FA439, 0x3FEECA4E4908BF10, 0x3FF0385B7FF58DA8,
+	0x3FE0B71906FC8366, 0x3FF6620067948151, 0xBFE5B9A9279ACB5F, 0xBFEA60CDD8DDBDC4,
+	0xC007694D2FCD0A40, 0xBFB7B18C0CF28648, 0xBFF595CA9BAD7074, 0xBFD2A18E916B6E4D,
+	0x3FE16FCCCE97D9A8, 0x400B22A12CBD742F, 0x3FF9C4D0E82E2ABD, 0x3FA09B890C9C2E78,
+	0xBFD7FB2AAD7235A5, 0x3FF02556BA143668, 0x3FE79607AE6AAFB0, 0x3FD58BFFEEB53923,
+	0xBFEDF91D5CCDDF0B, 0xBFD6FF1CF252C5DB, 0xBFEB0AEE962CD940, 0x3FF8316AA9415BEB,
+	0x3FE05CC081D7EB8E, 0x3FE9CB626E61AD9C, 0x3FF2EF9150FC1E93, 0xBFD56F706A9F05FB,
+	0x3FCF609351E2DAA7, 0x3FE9366A201C5E32, 0xBFD3C7F2FFFF3645, 0x3FFD237C08E604E7,
+	0xBFE2ACB8027FF77F, 0xBFB0168B04359CFD, 0x3FC7B1F410304150, 0x3FBB20BC0C7FFA26,
+	0xBFF08CF8E9100F9E, 0x3FE8BA49682A1FE1, 0x3FC9A0A701EDEC1E, 0x3FEEFB0304106C3D,
+	0xBFDB2B811CA1C196, 0x3FF61DD9D27ACEED, 0xBFDB57DCEC04A7D1, 0xBFC42F004BB16A39,
+	0xBF9F6D2814F6E9F1, 0xBFE7EB9E5F0CD034, 0x3FE744309E6372FE, 0xBFE224F0D5B0BF27,
+	0x3FD2343B9F9BF350, 0xBFCB01E3B99809E3, 0xBFE54F87811DA96D, 0xBFA0B76280AD1F3A,
+	0xBFFCADB7F9EF538E, 0xBF933DDF7571A4CA, 0x4005678C9BB33FEC, 0xBFE8B95CBE16974F,
+	0x3FF6968AD6A31ED5, 0x3FCF8060201FA004, 0xBFFCC0B8B0CA995C, 0x3FF5C71481D62E9F,
+	0x3FA4CDE656489095, 0x3FD2F8B4940D3DB6, 0xBFE643E163AA1589, 0xBFE31950168C6F1D,
+	0x3FFDD41B79C1C25B, 0x3FFF409E44653AA9, 0xC0000AC4516A87F8, 0xBFECA4A9BA2C2F61,
+	0x3FE2A4D24D95242E, 0xBFCA267E57D64EAA, 0xBFF102DD580BC4A5, 0x3FF272B65949E987,
+	0xBFBA5F89D1D9722A, 0xBFCCF07A4232403A, 0xBFD93C54521E07CD, 0x3FEB6EF5A557AEAD,
+	0xBFEC2D64697FDCEC, 0x3FEE69B015BD83B9, 0x3FE71FB0AEFA4948, 0x3FA410F48B50B04A,
+	0xBFE2445F2C3BC05B, 0xBFB5361025FD0D66, 0x3FE6923E5A075C66, 0xBFFD111D832FAADD,
+	0x4003A48BD25F556D, 0xBF9E9BE0162ECF59, 0xBFE41C131DEDA91F, 0xBFDC13BC289E1A7A,
+	0x3FEAB90B398C67C8, 0xBFF1DE38F38E232C, 0xBFF9475118F73867, 0x3FF7D802BA48FAC6,
+	0xBFE1A2621461B6BF, 0xBFEE6909CA0E78A4, 0xBFF3A66975B0DE25, 0x3FD087C150E3ADC7,
+	0x3FB5197BA6F4CDC7, 0x3FFD94AEB79D5944, 0xBFE235036C507857, 0xBFFAFCC616917F34,
+	0x3FF719A54F644888, 0x3FEF00981AAFA800, 0xBFCDF778EE06C91F, 0xBFB5F0DF89D14C1B,
+	0xBFE7A6A3DF562374, 0x3FF6E3A799C49F60, 0xBFE37711F6B2ADA8, 0xBFE070ACA114F081,
+	0xBFD462D1F999E499, 0x3FF2627143F52C1E, 0xBFDB232616369724, 0xBFD277FF702301E5,
+	0x3FDE7634DE3BC0F3, 0x3FF69622E8146779, 0x3FF0B8C35A94E06A, 0xBFEE545EBC616D85,
+	0xBFF6FA46632D364E, 0xBFB82C4847B5835E, 0xBFECC677CC44DA8C, 0xBFE73EE1CA26F86E,
+	0x3FB6124143FCA4D7, 0xBFD4649E6E5B0587, 0x3FD81E2953399720, 0x3FE3552938B38C33,
+	0xBF880E1454F5373F, 0xBFE3EBDB691675E9, 0xBFECA94BC16D9192, 0x3FFF5621990582D0,
+	0xBFE2F26D69A991D7, 0xBFEA9CD380EA57B4, 0xBFE285382A6DA759, 0xBFEE3605DFA9541B,
+	0x3FE1145F7510A6EF, 0x3FCB1759AFC5DB5A, 0x3FD6EB89AC3FD1A4, 0x3FF4354921C0B922,
+	0x3FC1750EAED3CF0D, 0x3FDCFC96ED538FD0, 0x3FEF2F28C02D55B9, 0x3FD2F7A0A95C9D46,
+	0xBFC0B1567204B77D, 0x3FF55ECFD0893083, 0x3FED000DA6326DEA, 0x3FC10683A50FA5F0,
+	0xBFE877236EF468D2, 0x3FE6B8C4A618F5AD, 0xBFB99090462B6869, 0xBFD14E46D69E6C09,
+	0x3FDFAC72191E4F96, 0x3FA57399083C31BC, 0xBFA7D37E74D50BC6, 0xBFD2E1156D8DF7BE,
+	0x3FF03F04508C5ECA, 0x3FD401F62419B0AE, 0xBFAF54F369CE7336, 0x3FC24E33D81E11B2,
+	0xBFF43292BF9C443A, 0xBFE0E1CBE5A23E0F, 0xBFE1F66077EEC769, 0xBFDEFE7CEBCCEA87,
+	0xBFE5C449FD84202E, 0xBFE27A6C7295A975, 0x3FDB5DB05396EC9F, 0x3FF5B05A9BB0FF62,
+	0x3FE3A786D6BC4DCB, 0x3FF8B374C59E0EDE, 0x3FE2819306464ACF, 0x3FC60D8DC1104B35,
+	0x3FCAF10DEBAD27C2, 0x3FE29474BD516930, 0xBFCB791A8B7664B6, 0xBFF18102C7291842,
+	0x3FC0E2B7BC857B12, 0x3FD401E6624EA91D, 0x3FDF37E37BA048D1, 0xBF97ABA2F17EB96C,
+	0x3FE21CFF3FAD3554, 0x3FCFF82DE18B4C2A, 0xBFBF22A322BA54C4, 0xBFDFC5DBC0ABB5F7,
+	0xBFB745CCC33EF820, 0x3FE36F3E8385F5D6, 0x3FF4BC4611B06522, 0x3F8772D732838A3C,
+	0x3FFE294E24C7A1E7, 0x3FDA648258F4DFC6, 0x3FFBB68773E8D8C2, 0xBFF6CB68603A4652,
+	0x3FFDCA6F77B32FCE, 0xBFCA9DFE0F4AAC5A, 0xBFEBC1DF0BE5C689, 0x3FE6B004F3A06560,
+	0x3FBFDABFF6D41F77, 0x3FC1EEEF54A5AAE0, 0x3FE2441A71C3EDA8, 0x3FD8D7CC1750A5CB,
+	0xBFCDD4B758FCCF71, 0x3FEED4D91671B9EF, 0x3FE802E611431D9D, 0x3FF98AD21F0F5270,
+	0xBFF12EFE728F358B, 0xBFD73CFAEE94BFDF, 0xBFD91F936FD11F8F, 0xBFF11EF47BCEB509,
+	0xBFF3B447EFE74BEA, 0xBFFAFDF5B87166AC, 0x4005D93D07A2FBED, 0xBFE0661394DB2198,
+	0xBFC469067476BA08, 0xBFE21CA4BA0C3BA2, 0x3FF1D8B68BF4C9BA, 0x3FF92B0DF3465BA7,
+	0x400D062C59BF1A93, 0xBFF25D098AB32492, 0x3FB7317690137C1A, 0xBFD3646212C83546,
+	0xBFF26C872AD7E5EE, 0x3FD0D358816F557B, 0x3FDC7CA847CB7E82, 0x3FF111E771DD01B8,
+	0xBFD3EB0C30D44673, 0xBFEAA7BB24BD15C6, 0xBFE0D02E5634E084, 0x3FE60EA5272A3FCB,
+	0x3FFC35939073CE28, 0x3FDAF07B9FB8E769, 0x3FD4D16CED5BD7CD, 0xBFD178CFC70DA358,
+	0xBFC9084C9BF85FAF, 0x3FDA87F7AB5AAEFE, 0x3FEDD85E8C161B54, 0xBFED2AE654B5172B,
+	0xBFD320F33613D85F, 0x3FF5C43FF3ED4100, 0x3FF221789DDAF767, 0x400252B981B9DB1B,
+	0x3FF167EB7D88DC2E, 0x3FF39143D3DAEB10, 0x3FD40CACEAC2C188, 0x3FCA2AFF222FF0CB,
+	0x3FB29B864B344F0C, 0x4001773B59508070, 0x3F9CD93F427E2274, 0x3FEF6F7960CF0207,
+	0xBFD2875F91C7753D, 0x3F985D9EA5180AD3, 0xBFDEB46705BEE42C, 0xBFEE4BEAA5953BDC,
+	0xBFE4F42D150AD19D, 0x3FF070793F5A6E26, 0xBFE37BB15A17141E, 0xBFE6FE941FC98B48,
+	0x3FF1D3908B3D0C27, 0x3FD57D9F31432672, 0xBFB932F09DDF5991, 0xBFE7E7E76CFAD3D0,
+	0xC00095DFCECCBE18, 0x400169231A7DA727, 0xBFD6DEF5185ECBBA, 0xBFAC0156E3367CAF,
+	0x3FF022ECC997FC2D, 0xBFC1383172748851, 0x3FF56356D9D58B5B, 0xBFD809E93BC6D4F8,
+	0x3FD9E79FD8ECF947, 0xBFF4F3C2B901B51B, 0x3FC0227837416FD1, 0x3FC4292E16B18A3A,
+	0x3FD1EFC14C27704F, 0x3FDCE57A6518EB73, 0xBFA3E9D554982B2E, 0xBFF78A0E60B36BB3,
+	0x3FFBDDFA95AF3FE8, 0x3FF7DBF4FDFF0A1B, 0xBFF4213EF2A81B3A, 0xBFF09B1F50E65236,
+	0xBFF736745CE53D2A, 0x3FE53BAA9E5B4E5E, 0x3FFD600210920215, 0xBFC4928CC33BA35B,
+	0xBFE124F78EA63320, 0x3FF634834F32B2F0, 0x3FB4206D011F4971, 0xC0001C00C0AEC4F4,
+	0x3FFBE100F9910B10, 0x3FFE2C47EEDD3FE6, 0xBFF20A5CF916CA0A, 0xBFF07FDA0FF9AD55,
+	0x3FF02482B32DEA35, 0xBFF29A500D99999F, 0x3FD086D84941A561, 0xBFE4EDED8CA6FDC0,
+	0x3FD095AEDAD8F210, 0xBFF3E91FD0962591, 0xBFF8E55AA302B294, 0x3FF9252C63A3EC56,
+	0xBFE097A0FC1E6FDD, 0xBFFADF727BE7F8CC, 0xBFF8156A670C079D, 0x3FCB0A28962F5ABA,
+	0xBFEDEC38E83D113D, 0x3FE10D6951CCB350, 0x3FE306988BFA2ECC, 0xBFB7353FB980E578,
+	0x3FEA2F7125C85ACA, 0x3FFC39E37866CF16, 0x3FF1A46AF64634BC, 0xBFC4A22F628B35EA,
+	0x3FDDCF27FCD5D6C7, 0x3FC6908F34F811D1, 0x3FDDA5C80AC89160, 0x3FC097FC95C9999B,
+	0x3FE7FFB08780903A, 0x3FDDA494AABA77E7, 0xBFDA4F2DDBF8E7AC, 0x3FBA3C5C9F0C2958,
+	0x3FC0F4637F923E71, 0x3FEC120BCFF7BA2A, 0x3F9CCD362A541BD1, 0xBFEA9943FB240B86,
+	0xBFEBD120FAA3BC55, 0xBFF581E1750C8DA7, 0x3FF4D32BE2ECE181, 0xBFB053C63D34D3E3,
+	0x3FD20C55405FA266, 0xBFC62C746759D5C5, 0xBFD433E3D88EF32A, 0x3FE51CDBA660BA43,
+	0xBFEF42C6BBFE86A1, 0xBFF12B2F1D975F2A, 0x3FEBB2CC98BD1393, 0xBFD167AAE30F5987,
+	0x4006A04EA2FEEBBA, 0xBFDC69A8EC68C942, 0xBFCE3911B743F4E1, 0x3FE0B49EAC1E3FAE,
+	0x3FFC247667A44CA9, 0xBFB8AE88B9A59544, 0xBFCDB31C8DCC5598, 0x3FC70168A4287C0D,
+	0xBFF4C8E47EB2EC37, 0xBFEE8BB2C196DEFE, 0x3FF33F101CB20C87, 0xC001B7E6C534D8B5,
+	0xBFD208CAFC91B0CB, 0xBFFCB976FCDED98C, 0xBFD72AE7463E020C, 0xBFF62E489B7FBE78,
+	0xBFEEEFD02C97399A, 0x3F7FAE01CF653786, 0x3FFBC2CB00D1E096, 0x3FE760434C2EA8E4,
+	0xBFF02DC57AF767D2, 0xBFE75AC3D1D1D139, 0xBFEDA773A0097798, 0xBFAA8191EF4EE31C,
+	0x3FEAEB328D4CD749, 0xBFCF95F7EFBD090F, 0x3FF30587312BF762, 0xBFE209EA255B5225,
+	0xBFE6F6F81FEB5992, 0x3FD89CCCD0A49BF5, 0xBFA883F5EA5C52EC, 0xBFCE301CD680E666,
+	0xC0003FCC4803EFCE, 0xBF86C2903806F36E, 0x3FCCF40CD9418D47, 0xC0034A6104F6A43F,
+	0xBFF06C25D23D3FD8, 0xBFF26E5DE30DD853, 0xBFFA90BC1A1CDE7A, 0x3FE77D0D20451044,
+	0x3FEDBF24379CC71D, 0x3FD0FC233699F251, 0xBFD2FDC81E7B466F, 0xBFD0D2721C1D8BC1,
+	0x3FF07531C7D32E1B, 0xC00130D841C477A3, 0xBFCE3A3A02E92156, 0x3FC85816618337E5,
+	0xBFF486B1B81CA723, 0xBF9EBFF7CB650019, 0x3FBFF4C2D66F5E1C, 0xBFF40061041DF032,
+	0x40012664377ADE3A, 0x3FB288B863037712, 0xBFF08150550E78EA, 0xBFDF91D492812119,
+	0xBFD7208F779142C1, 0xBFC2819E5E17E017, 0xBFBF76F2E2A4FCA4, 0xBFF82CDBADAC11C5,
+	0xBFE8387F7E086FAD, 0xBFE9DC58F5FB1767, 0xBFEB5920424A5979, 0x3FE64A9FA1B8C025,
+	0x3FE6F783D5F9DC89, 0x3FE1974CDC5BC809, 0xBFC18A36AF35F003, 0x3FCF3382321F0242,
+	0x3FED57AB2087FC3A, 0x3FF405C7902D7685, 0x3FD6D3D6BD225F7E, 0x3FE0C24AEEC8C9AC,
+	0x3FF809ED3F2CF476, 0x3FC0A0FB93FD74EE, 0xBFF60A261B9793BF, 0x3FF493788A96E806,
+	0x3FCD5DDB83AAEC9F, 0xBFED79FDEDC78595, 0x3FEB55E959931628, 0xBFB1760A42DB191B,
+	0xBFD288173B591893, 0xBFD687186FF3D221, 0x3FD5C7E9324ABC37, 0x3FBB667CA702A585,
+	0xBFE4715B6C8C23C0, 0xBFB018D7396FC8F2, 0xBFF34B43AAC17399, 0x3FD2DD39629AAD19,
+	0x3FE5E561991B9C62, 0x3FEB93892B16D3E0, 0xBFE2F8963CEF6B2A, 0xBFDC9C3E3F892326,
+	0xBFFD02E69EC0B16F, 0xBFF7A44EFBF773C2, 0x3FE5B8234CD9A121, 0xBFD7B9EC8E9F4F6A,
+	0x3FF3354EC9631AD9, 0xBFF29B530D86DD4B, 0xBFEFA33E51C538F4, 0xBFC7F69EA81107E9,
+	0xBF6B24F4A76E3428, 0x3FFB1982C7C963B2, 0x3FF9201E76F4451F, 0xBFEAC3E3D6645297,
+	0xBFF6033932520251, 0xBFBE3613F9A8A5FA, 0x3FD5E27CF4E2EB46, 0xBFE3161172F3CB31,
+	0xBFA39C7AB263C5CB, 0xBF7D80EF225140A9, 0xBFF1AE48E2B565B9, 0x3FE4100A8096E700,
+	0x3FFF4D2FDDFDA538, 0x3FE6961CB464821D, 0xBFF707283AC56811, 0xBFF277B31EE80611,
+	0x3FD21324045BF5E3, 0xBFEB4EDC3F152DB0, 0xBFE0F457E2B39719, 0x3FF581C478079126,
+	0x3FD7414E618A7BD0, 0xBFE7DF80C2EDC69E, 0xBFEBCF9F86EE5B58, 0xBFE463FDD6E3F6E2,
+	0x3FDA0B86FAB83FE1, 0xBFCF7C7906547D6E, 0xBFF0389537995B08, 0x3FE0BBE40FB4439A,
+	0x3FDE5262383C474C, 0x3FE37B745FEB472E, 0x3FF4A44B99F4A1B2, 0xBFCC913737A6ED82,
+	0x3FE38CB429D897BB, 0x3FF2EA3A01293162, 0xBFD32A7547FDB4B0, 0x3FD85C4B1C7DF102,
+	0xBFD62423B769D66B, 0xC00187FD9270FDB2, 0xBFDA8765763B1593, 0xBFE42D0F0F8BB07F,
+	0xBFE8E09C9BD8D5B5, 0xBFE2945B96534D34, 0x3FA92F9E0CD85C7E, 0x3FE6A2914979F059,
+	0xBFEC295A1815115E, 0xBFED24F1DD9897A7, 0xBFCC0C2F12360067, 0xBFDE43111061EF16,
+	0xBFEB4A83F122667F, 0xBFF5E258427E3947, 0x3FD315862F8D1923, 0xBFF15BCBCD023137,
+	0x40021650C349BEF3, 0x3FF726E986AAC38E, 0x3FE5E5D6A50C3508, 0x3FDBEDF271833B1D,
+	0xC00017A2BEC2679D, 0x3FF3E1A33B4132EA, 0x3FF0B2DE0F68C534, 0xBFC69B4FA15B7A60,
+	0xBFF2BBA5CC44D041, 0xBFFB5234A4794E2A, 0xBFCC1A2D0982E9BC, 0xBFCEB51F09D054F3,
+	0xBFF0B8BD5F99BE81, 0xBFDAAA344B3F59E3, 0xBF96D0A99E3AE23A, 0xBFE5C101C5941185,
+	0xBFECFCF248DABD5A, 0x3FE20AFD0AF7CCD4, 0x3FE5FA1F31DC66B5, 0xBFFCA53A59D57C74,
+	0x3FDD253FF3F44779, 0xBFF556A2F806AF62, 0xBFE07A4611B12604, 0x3FBB703EEBBAA623,
+	0x3FF26CB74942A597, 0xBFE0A22AFB41C272, 0xBFF70E60491B9150, 0xBFD54B0A7FE37679,
+	0x3FE09B4808BE4CE6, 0x3FDD72155F9E97BA, 0x3FCA830A994F12F2, 0xBFF32D59B4E361DD,
+	0xBFDAE924930A1C10, 0xBFF9A070A6F87F27, 0xBFF0F48155F1C9C6, 0x3FEB2169B0D02A3D,
+	0xBF8C5336504ECAF3, 0xC000D0E64A520AEA, 0x3FFCC0C8A890BDBA, 0xBFED287F6AE96F1E,
+	0x3FFDAA4910B458B5, 0xBFF1E6D07FB14085, 0xBFE576861D3EA6B4, 0xBFEF91F5729DCDBB,
+	0x3FDC14255A564220, 0xBFF27C57F3F01E95, 0x3FE9C98693EEC21F, 0xBFAE6FB7742B2A06,
+	0x3FF155C0B0E18162, 0x3FCF301D36A0DA7F, 0x3FEDC2F370D3A50C, 0x3FF9466AFA691C63,
+	0x3FDE8B21ECE9FE31, 0x3FD6F285BDAACF11, 0xBFD78B99EF56772E, 0x3FD2BDFBD1E63C93,
+	0x3FE1E6100E6CF5B5, 0x3FDD967083AF766A, 0x3FEC8539EDDF619F, 0x40084EC8A5E3EC4B,
+	0x4003FBC368085FD0, 0xBFF62B9664333AF9, 0x3FC9C1FAA1F3B83A, 0x4000D35C432DEACD,
+	0x3FE00307FD809B50, 0xBFBE971CA45EB20F, 0x3FF69788FF2AD46A, 0x3FED0A552F0980B2,
+	0xBFD94AC62A12092B, 0xBFE89965DC37D032, 0xBFF76EE15A1225E8, 0x3FD278292D250154,
+	0x3FD8A6530B25AB54, 0x3FFEDB7D4C45CBD3, 0x3FE368E8679CC823, 0xBFDE5DEBF860A3DC,
+	0x3FE5E78DDB3538B5, 0x3FFC271D1FEBD5F4, 0x3FC64E1DB212BC1F, 0x3FED5F553F71CB99,
+	0x3FE53DA5ACBF8E5C, 0x3FDB41C18AEBFA3B, 0x3FDF275CD6960443, 0x3FF71D07D38A5538,
+	0x3FB2D2097C340FFA, 0x400255CCCE294493, 0xBFBB0AAA1F232D52, 0xBFCC37963BE1BC6C,
+	0x3F9D3FEC0D7CAA9E, 0x400123E2E38006CE, 0x3FEC33C21AC19C08, 0x3FED2C6F47602646,
+	0xBFF72B7C5CA6E6D6, 0xBFF53D761FAE35DE, 0x3FD7CE841326B7A3, 0x3FF59FBB007FF016,
+	0x3FE83DF91BA5C34F, 0x3FF2423452B7C4E2, 0x3FEA800359F2A343, 0xBFDF604B31DC4D86,
+	0xBF92C9F431FE7BEA, 0x3FFEB86912167957, 0x3FF38F78405524F5, 0xBFC829079456EEC6,
+	0x3FF9ED8507CE7B0A, 0x3FF44AC5DC9E6744, 0xBFDE82EC2AD2A9FB, 0x3FFFB3BC3AF7EC6F,
+	0xBFD7EBF83EF3185B, 0xBFD88A02EC678CA4, 0x3FE2B5E96CC156FD, 0xBFD974F253D75CE9,
+	0xBFE3B8A9FC0A9AF0, 0xBFDC87707DEAD072, 0x3FDAB0ACEE0CD2D4, 0xBFF0AFBE91EC5956,
+	0x3FDACE67BF724A8C, 0x4005FB136BC718EF, 0x3FF46614037BA9CE, 0xBFD145224753EF6F,
+	0x3FAA6097805327D8, 0x3FEB3AB878BE3C3A, 0x3FEDCB4232B71BC3, 0xBFFEC6F584552702,
+	0xBFCFD976BC8450B6, 0xBFC777BE20ED0C26, 0x3FD617B7067B73CC, 0xBFE0BF347CDCADBA,
+	0x3FF8C2652862D2AF, 0x3FFC0D7232354188, 0xBFE4985FC092B948, 0xBFF5D304C891BA30,
+];
+pub const ZIGGURAT_EXPONENTIAL_SEED: u64 = 2460;
+pub const ZIGGURAT_EXPONENTIAL: [u64; 2048] = [
+	0x400C846202CCB99E, 0x3FABE9FC8B9F0046, 0x3FF754E1C3777FC6, 0x401901957FCF354D,
+	0x3FFD3197D7732871, 0x3FB29E427EB28198, 0x3FA6928EFAF4856E, 0x3FF79F3A255D216F,
+	0x3FE7DECF1CDB02D6, 0x3FC93595985B226E, 0x3FC367AD5984E0D0, 0x3FE1744E2CCF4DAB,
+	0x400C1132E4D3CCCA, 0x3FF48BD1E017222B, 0x3FFCE8542A5135F1, 0x3FE961469FC84514,
+	0x3FBAC43F12F9170F, 0x3FE48237BA3DDF8A, 0x3FE9B58DB774FBDD, 0x3FE1769B39AFAEAA,
+	0x3FF4B054589E8470, 0x3FE6560E93C40B87, 0x3FF6557AB699AC85, 0x3FE83DD400A026A4,
+	0x3FECDABD7C6AFABD, 0x3FDDD73F3241DE27, 0x3FAC277226D8A21F, 0x3FE799D2A49476D9,
+	0x3FF0B94092A9B1FF, 0x3FBFF9C0FF88E895, 0x3FFA4B17C40E1A7B, 0x3FBF1C471E1B1208,
+	0x401110D026A0FB00, 0x3FE2C8F348E3D006, 0x3FF16C7FC5F22BCD, 0x3FE099DFAC579BA5,
+	0x3FEF0C80DDBB04CA, 0x3FCCEC48AF5DDB90, 0x3FE4F73A6B6737AB, 0x401323675EA3A146,
+	0x3F9FCA53FCAB7098, 0x3FF64B1A66380837, 0x3FE4953A2618BFA8, 0x3FC95E263C604102,
+	0x3FD3D2D153147D1B, 0x3FDA819763440AD7, 0x40039F4D07736719, 0x3FBB6D8C938246AF,
+	0x3FBF2FEDAD20D9A0, 0x3FEB4A583509FD36, 0x3FD19738CC276480, 0x3FA7EA7171EEE965,
+	0x3FFE8067608C916E, 0x3FD1D39874F8A234, 0x3FEBE935F4D014B7, 0x3FEA818010FC6C00,
+	0x3FFC554A3A89F7D8, 0x3FF4692D25DAC38F, 0x3FA53B3ADB3B8123, 0x3FF20BC75121D163,
+	0x3FEAC469628C7C18, 0x3FF3684777383FEB, 0x3FF3A27D960280CD, 0x400019E3C28C8E0C,
+	0x3FC3FA49666A4FD1, 0x3FE075D03B254887, 0x3FE3E4955DEACEA1, 0x40017BF57846FD20,
+	0x3FF70240ABC3BAE8, 0x3FF5EF1F49C9D6F7, 0x3FFA36E47886D426, 0x3FFCC14BADC45AF9,
+	0x3FDA850ADC1B2BDA, 0x3FF2F47DD46CF213, 0x3FC4D354ADD696DD, 0x400005E876989FBC,
+	0x3FFC2F609BEA0B97, 0x3FF3D249F58854F0, 0x3FBA8CCF4BC9D6A6, 0x3FECED2D3F484566,
+	0x3FEA2E1EDDCDFF79, 0x3FCA486A2B4DE368, 0x4000A228DA30BAAD, 0x3FE8A4460F9B5325,
+	0x3FC81E0F3B0A2F34, 0x3FD8E1AB81F411EF, 0x3FAA578017FBA98C, 0x3FF69A1A56A67246,
+	0x400A7AB790217B79, 0x3FA8449424A42F8A, 0x3FF3101568C18992, 0x3FDEC0DCB18F5413,
+	0x3FC89D956AEDB692, 0x3FCEF7F47A3A74BB, 0x400414317BCBEE3D, 0x4002279FE2801BF6,
+	0x400956B64C898BDB, 0x3FDB16D5F192B0B0, 0x3FF55D804793C6FD, 0x3FF7ED04D018BB43,
+	0x3FF06A173722AE45, 0x3FE19EE9749D4849, 0x4003242DCBDAE12E, 0x3FC0336D905EF579,
+	0x4005B412253E29D2, 0x40069CB82405781E, 0x3FDDEDB2DE97EA31, 0x3FD023BA8F1DD591,
+	0x3FF4A4DAE99A09D0, 0x3FB22212D8457781, 0x3FFEE07B594F9B87, 0x3FF91403E27D4043,
+	0x3FE61A93C8551E4C, 0x3FDFD2DE8B3EED90, 0x3FC6BF37F69ADF3C, 0x40018CF68C977ACE,
+	0x3FC743D2FBC95EE6, 0x4000626A17379ABE, 0x3FCCB0ACCCBE14DE, 0x3FCADA09A2E6D3E6,
+	0x40097C71095206F6, 0x3FBAC23439AB5E37, 0x3FD96720806F5DCA, 0x400835275ECEDDB2,
+	0x3FF3B68597FEA27C, 0x3F9EA4940C097F62, 0x3FBB9843101164AF, 0x3FB711AE2F433D3B,
+	0x3FFAE3C4398AC231, 0x3FE069EEC2D2A1F5, 0x3FD3A18E0CEE8671, 0x3FE06DEB83948370,
+	0x3FC06E98381936E3, 0x3FE09A75F80F2B21, 0x400F3F76E903A92B, 0x3FF81328870026B2,
+	0x3FF969A0441C0712, 0x3FC77D31DBE907C8, 0x400A9CC869C6BC63, 0x3FE3EC5754E16E8C,
+	0x3FFEFF18BB7E8809, 0x3FE36296EB40020E, 0x3FEC2CA2CFF6675A, 0x3FECDF5080CB53B0,
+	0x3FF1DBC7000376E0, 0x3FF08D2CF339DF52, 0x40098D0B908C94C1, 0x3FFE5E2E18F5F02E,
+	0x3FCC47A8EA32C072, 0x4004B12DF9BC0ECC, 0x3FE2D1143E11F3D0, 0x3FFAB95CC19F96BA,
+	0x3FF90A4744C07D80, 0x3FE22A43D63AF0F1, 0x3FF4F3132FAB9DC8, 0x3F987728934FF4D1,
+	0x3FFBD0A265AED2EC, 0x3FDA7DDD27902210, 0x3FE4863F1CC860FD, 0x3FED8BD21C05344F,
+	0x3FC02EC79C358824, 0x3FD32A86D9410ED8, 0x3FB0E2F0338488AF, 0x3F5A7276152E7A2E,
+	0x3FA55EAD1C47267C, 0x3FECADA44A3FDA4D, 0x40136D67C4CF6124, 0x3FD3492F6E9F7FEF,
+	0x3FCA55CE4B3F5080, 0x3FCDAC5127F44F81, 0x3FFEE1465A3EE34E, 0x3FF5CDCA77051F3C,
+	0x3FF6FC52AC5AE938, 0x3FF2D2A10F5E8A3A, 0x3FDBDC029A2FF234, 0x3FDFC27A1660F7B4,
+	0x3FD9DA5B637FAA32, 0x3FED7FD46C7AAC87, 0x3FB618B8637491B1, 0x3FE5F20B9C7C6B87,
+	0x3FE715A74905FE8B, 0x3FCCCD93EA8EEFC6, 0x3FE254BA3099B3C0, 0x3FE02FC9F46C67D3,
+	0x3FD14A870EBDAFC0, 0x3FD25A76731B12F6, 0x3FB62C760ECE4359, 0x4003141C06976069,
+	0x3FF047928702E55C, 0x3FD82BFF2FEE6432, 0x3F94A0D3D289C0AB, 0x3FF33D318D74ED94,
+	0x3FFDA76F5199C21B, 0x3FED6741C0988F8B, 0x3FAD8E7C4B138155, 0x3FF06690F7AD7C3E,
+	0x3FE72303FEE36646, 0x3FD2670A6BCDB169, 0x40077D5E8427206B, 0x401789441859DF04,
+	0x3FAD4B90DD528928, 0x3FC4AFA727D4234E, 0x4003AD30D5356D67, 0x3FF4B0D02B31E267,
+	0x3FD120AE0D5D1EC5, 0x3FFD33F2B3AA787E, 0x3FF0521C78578669, 0x3FE8C18814FD8199,
+	0x3FA8622A334B3F29, 0x3FD84628F5B00DA8, 0x4000BE7A0B8E693A, 0x3F903285D5F73E75,
+	0x3F6AE21766F01109, 0x3FD29784507BDC70, 0x3FABEE27D35CE04B, 0x3FE51BD359777E6E,
+	0x3FE806F0F43BB9CC, 0x3FAF6B725E18D6D8, 0x3FF153E1D6AB8BAC, 0x3FC361508685FDD6,
+	0x3FD918B713F2101B, 0x3FF6810BB4DDC49E, 0x3FEDDAE49BCCBD93, 0x3FDBFBE3E9C0B089,
+	0x4017E3C710961F8A, 0x3FC4CCEFD76F14AD, 0x3FE4116BDAD411C6, 0x3FF4674B5138566D,
+	0x3FD355777B0AF86A, 0x3FF90C27CB2AE86B, 0x3FF1B67733344E3A, 0x3FE645FB26D79CA5,
+	0x3FF1B8B4F5F100D0, 0x3FD03D3F7284FBE3, 0x3FB2EA56A8F56DA1, 0x400082D70576FD4E,
+	0x3FD085F88DEC9C86, 0x3FD4B9DB5E0094DB, 0x3FD7B56CD0BCF1C2, 0x3FFD906AA8AEC7AB,
+	0x4004E33A54E8BCF7, 0x3FEA81B77E70CD90, 0x3FF6178DB6AD32EC, 0x4005F04782BCB492,
+	0x3FD02FE971AB780A, 0x3FF811594572B063, 0x3FB54A1892B29F4E, 0x3FA70E53F9074E63,
+	0x3FCD7A9975D084A3, 0x3FE6189F4DAB3649, 0x400F66389D7F0A1E, 0x3FF2ACC5FA3E63A1,
+	0x3FC7E79A2E45AE12, 0x3FB1894EE499820F, 0x3FE8078D58ADBAEA, 0x3FD7B03F7AC5110E,
+	0x3FD663809F8C4AFB, 0x4000AAFD24DF5599, 0x400078201D40A34C, 0x3FE925CF09F1918A,
+	0x3FFAC8833175BF24, 0x3FF14F7A81199F31, 0x3FFEAA90A0EFD8F6, 0x4000E17815881228,
+	0x3FEE84F5D56887EF, 0x3FD0C013B6646971, 0x40069A1FB4B2ACDD, 0x3FE987ED92D7F604,
+	0x3F9CFEB4F93E2562, 0x3F93CBC35A2B84F5, 0x3FD8BEA64C039AC3, 0x3FBBC60A90A99854,
+	0x3FBE35E043302184, 0x3FCA846CFA50840B, 0x3FF774061A090335, 0x3FE9602EE1867577,
+	0x40033FB147A999EC, 0x3FDE41CAFE7F1EC0, 0x3FD9E4E7CB213F39, 0x3FFB3F7D773EEF3B,
+	0x3FFD73A0B2017F61, 0x3FFFD0BF26444952, 0x3FF0F7AC55FD0F7A, 0x3FCAAB2C79CCC93F,
+	0x3FF7C7B485A1E92D, 0x3FEAF30AFC2E7414, 0x3FF5E1D7A55E1BC2, 0x3FB3FAF4070B6580,
+	0x3FCDFD36E3D1A01B, 0x3FD5567CE137FF64, 0x40107DFE20FA9801, 0x3F94BD25B49878B8,
+	0x3FC26F01E9675B31, 0x3FFAA6C06669D140, 0x3FB35BCCB459FAC4, 0x40071B23C000E746,
+	0x3FB26134E82304F8, 0x3FF2BB8041174700, 0x3FED23D9028B844A, 0x3FEF8B44E334A907,
+	0x400A8D1525B7BAA4, 0x3FFD554D85941258, 0x3FED28929C5B52BB, 0x3FFB3593CF316259,
+	0x400053345F4FDF6E, 0x3FCC3610CE59D078, 0x40072957A8C535E0, 0x3FFE4E444E78DF3A,
+	0x3FF18B052240A74E, 0x4000473AE724D2C0, 0x3FA58ACECB8242DE, 0x3FCA4804E4477288,
+	0x3FE0844D12F44515, 0x3FF3501605239E02, 0x3FF730081DC328D9, 0x3FED4D6C9395CF82,
+	0x3FCAAA7007A12795, 0x3FF169D00E375A03, 0x3FCC16A2EB4EE08C, 0x3FFCAE019892B3C9,
+	0x3FA7809A302804AB, 0x3FC0D469645701A9, 0x3FF6ED32D887A182, 0x3FCF50A5C3889A43,
+	0x3FC3587C7B2D4C38, 0x3FE1770B0A495CFF, 0x4007B02CC20632F0, 0x3FDD8A4604BF6FC2,
+	0x4000F014A59C1C19, 0x3FF5C0A8DC053DF5, 0x3FC2100F9BF74649, 0x3FFFAE8B79BCA9AD,
+	0x3FF4AE0DF701D7C6, 0x3F9F606C7C409F2A, 0x3FD12318DEF915DE, 0x3FE0C72798506253,
+	0x3FF422AF5ECCF2BA, 0x3FF7EC9A1510247C, 0x3FDDF89341A43CC4, 0x3FEC11BE8CA4F11F,
+	0x3FD6DFECC488B9E6, 0x3FCFF91ECA4A11E0, 0x3FA79E4581D9B540, 0x3FDA7F16F5173151,
+	0x3FE87982A3F587D6, 0x3FBBA50F17C67F94, 0x3FC5F4405ADABA30, 0x3FE18CFBBACCDF4E,
+	0x3FC7C8C0EFDC0A32, 0x3FE79A9A66185E11, 0x400218A4A1ACCFB3, 0x3FBC9B66B37B9509,
+	0x3FF8241927AE667C, 0x3FE4495BB539548A, 0x3FC19E6BF1AE060E, 0x4003B7B276143CBB,
+	0x3FB3BC4C9F19F992, 0x3FE032970EFC5F89, 0x400481D42526A753, 0x3FEC4A3117032B4F,
+	0x3FD11ED663E95F5A, 0x3FE71958E01E0302, 0x40001F6BC32AA110, 0x3FE4DC9203ABBB41,
+	0x3FF2E2E5DAD1FDD2, 0x40021B64FD1FE440, 0x4005F2E46D75D842, 0x3FD310AAFB9AB7D7,
+	0x3FF6C38BA9CE8EF8, 0x3FF2EDEB4BF19C5C, 0x3FBBEDDC1D9AB1EF, 0x3FDB165A2783E94C,
+	0x3FC0D6659E0B85CD, 0x3FF11F448F0E2B95, 0x3FE04FBAA184308F, 0x3FC75E903C97887A,
+	0x3FD2DC11583CA0CF, 0x3FFA922B3BC2D418, 0x3FFAA55F584D26EB, 0x3FDB3D8F3F02D87A,
+	0x3FA58C4EEEC66EA1, 0x3FCBD40E55C82F90, 0x3FD5E3B546AB0E5C, 0x3FFBF4512F053BC1,
+	0x3FFDAF79004F89D3, 0x3FF8A91310A39BCF, 0x3FD31F86A214329D, 0x3FC59EF21E652073,
+	0x3FFCDFEDAD076806, 0x3FC1C9B0418EE88A, 0x3FEF061A81719F0D, 0x3FE170928DF3F305,
+	0x3FE0EB298CEB1109, 0x3FF42BCF2327CF3A, 0x3FF99E7A0DFD8B27, 0x3FD6355EF85EEB03,
+	0x3FF1F2827ADF5E11, 0x4013C1DB5357849B, 0x3FF3FF6ABD4BB17D, 0x3FE340F1F2608580,
+	0x3FD84DCCDEC1EE78, 0x4008339A74BF4C42, 0x3FC763E8AD91C417, 0x3FE92F5DFB43DAEE,
+	0x3FE1C3D7CDF61E3C, 0x3FFC203EF0FD46D1, 0x3FED16C6465EAC7C, 0x3FCFA645AD1E2577,
+	0x3FF10808FDF7D0BB, 0x40067D7EADD57ADB, 0x3FFBB98DBE77ECDF, 0x3FE4D9E1A80206F6,
+	0x3FFE80A36AC8D7BB, 0x401202F6FFBAC740, 0x3FBBA892D4CA277B, 0x3FB6D065E0C66E9E,
+	0x4007D0285E9EF2FD, 0x3FFBB801637DEDC7, 0x3FE869D51E261797, 0x3FF1CD75464DAE8C,
+	0x3FF17C1BBB0BFB13, 0x3FF88FE09A66CD7C, 0x3FD971D4A7D8B45C, 0x3FA029AF4C1EF205,
+	0x40072FD522EB45FB, 0x3FCCA43A1D589A76, 0x3FF4D284D6325312, 0x3FE6600CD04C8DEC,
+	0x3FA06F39AD768F7F, 0x3FC8A16BAC1A6782, 0x3FFE5C7D4190D425, 0x3FBFF8751AF04C38,
+	0x3FE23071D39F9D2E, 0x3FE7DA670C83CFB7, 0x3FE42CF54F41609C, 0x3FFB84ADDE429CFA,
+	0x3FC8BD7AD97D4B4D, 0x3FF104188216B36B, 0x3FEAD270C0004001, 0x3FCA4118862B7B81,
+	0x4008DC59BDCCDF61, 0x3FE10C033BCD7BFD, 0x3FB4CC30F464B079, 0x40043E4351A76F1B,
+	0x3FA52FE0B741DF24, 0x3FCD08EEE8E90D5D, 0x40017FE7EDFFC376, 0x3FD35BF09CA34657,
+	0x3FC5DCDC3599C326, 0x40048FC44B6F7463, 0x3FDFA729382DB42C, 0x3FD46372DE19CD87,
+	0x3FF25B6B0ADBCFFE, 0x3FD3FD6EA2C309EA, 0x3FF7369CCAA51343, 0x3F8A7E838AC58971,
+	0x3FE1C415266DCF8A, 0x3FDDB4AE2D926844, 0x3FE55F2792794BD5, 0x3FC1FA370ED43997,
+	0x3FF6934D1636B4F6, 0x4001A85D93217E21, 0x4008AAB2A7BE8E81, 0x400DDB0F02DA2A0B,
+	0x3FF7AB8D54E3061B, 0x3FF272BB08F05DC2, 0x3FECF26EF7674347, 0x3FD32511D14B8D6E,
+	0x3FDF978F24D81EC6, 0x3FD12DFF1B2C36C5, 0x3FF8FC443FF8E1BC, 0x3FD0FD04A68F102B,
+	0x3FF70C24F2B7338F, 0x3FEF00BFF50F34C2, 0x3FF19567E04ACEF6, 0x400CD89924DBA71A,
+	0x3FCF180BE54E5BAD, 0x3FDE0E65F20BB37B, 0x3FC845498D56A05A, 0x3FEAAC2713FD5723,
+	0x3FD7E42FBCE6446A, 0x3F54C0943AD2332C, 0x3FCB96A49F71427E, 0x3FD3DB73A9057BDF,
+	0x3FC259B8D53B0332, 0x3FDC54666420468E, 0x3FF2CA30CFB46D43, 0x3FF9A2191DD6456E,
+	0x401A2F19C8D7E93E, 0x3FFF21C449E700F4, 0x3FF32DE753C225DB, 0x3FFC92AB6F8AC3D2,
+	0x3FA916FB5EBEAB22, 0x3FC065780A139883, 0x3FD9D67B697A1E55, 0x3FE11AE2CFA7CF8F,
+	0x3FE3D14555E9D85E, 0x3FDE883AD82544ED, 0x40101FA53388E72F, 0x3FB40415FAA29FCF,
+	0x3FB6A36B99876D2D, 0x3FE0E1052ABC382C, 0x3FDC54694F5E486A, 0x3FE202DC5873B3D2,
+	0x3FD84DC1B8B7D059, 0x3FDA1234C59EDEAA, 0x3FB5CFEFD3912198, 0x4000994224F75B1C,
+	0x3F9774CD51717DF7, 0x3FF203B0AEA0C38C, 0x3FD70D6653CF77F0, 0x40009AC930575D3E,
+	0x3FFFAAF2CD504AAD, 0x3FED0BD01256F9C5, 0x3FEFF945A0474ED5, 0x3FF92A2396612F3D,
+	0x3FB15C8DBDFDA2DB, 0x400682998752EA6A, 0x3FD0FAD69FC16A3F, 0x3FC25D1B94502BDA,
+	0x3FFA69C00F94C2DE, 0x3FC0A378C7F4CA01, 0x3FB058F751BC1B5A, 0x3FDCAA7F05D51535,
+	0x3FD8E203B4296FFF, 0x3FFAF25BA0DEE7B2, 0x3FE54621542CB513, 0x3FDE11473608F68D,
+	0x3FE05666D7BFB28C, 0x3FDAECA60E091652, 0x3FD8F997EEB9053A, 0x400D5192B52A97D3,
+	0x3FFE2B898DDC7E19, 0x3FEF780260C176F3, 0x3FF10BEC6864721D, 0x3FC12C41277F4AB4,
+	0x3FD307F5EF12F829, 0x3FF41ABEFDA24A69, 0x4001A5EB6159DD22, 0x3F9FE39B6C91AEA3,
+	0x3FF8F177ED4E8052, 0x3FE402F1FCEE6602, 0x3FDAE8F2371D4B0B, 0x3FE18EF679B0B2F2,
+	0x3FE29BD8B549D2FF, 0x3FE5500B2E5856BF, 0x40006FDCF8AB028F, 0x3FD4363DC8E8DBF2,
+	0x4007C571B37DAC36, 0x3FE9A7E1D200D5F9, 0x3FF0BB1FD77BA834, 0x3FA00BA4E210B6AB,
+	0x3FDA2703384EE908, 0x40071EF61282F8BB, 0x3FF1C1F86034BF07, 0x3FE2A8EC4EC282CD,
+	0x3FDA061E99B73E4D, 0x3FF7A8EFB763D013, 0x3FEA77343C3416A1, 0x3FA5C7091A890722,
+	0x4000B347B704EBF1, 0x3FBE41E859CD8AB0, 0x3FFA3A7574756201, 0x3FC044B4D5CD5B3C,
+	0x3FF09FED64506461, 0x3FD7837CB53BC473, 0x3FA810D64C9FC26C, 0x3FD8B6ADB01CDB6F,
+	0x3FF6A3DB333793E5, 0x3FFDD55AE7553C4A, 0x4000B9416F07E291, 0x3FF62525266A012B,
+	0x3FD073A91DA5F064, 0x3FB1D8FD00FC5D11, 0x3FF8F00E50C99439, 0x3FFFC39EFC77164B,
+	0x3FEB7167316D64C9, 0x40007124E871C812, 0x3FF70A55A32CB4EA, 0x3FF11426A9C9DCB0,
+	0x400F6A1F1824C2C7, 0x3FEB1A699A01A407, 0x3FF75A216C3C3FAF, 0x4006749BAC47F00C,
+	0x3FB95CED7E5F976E, 0x3FE3DEEF719B0966, 0x3FBE8C881143DB15, 0x3FEC9243B0D5A9B3,
+	0x3FDBC48A3AC357F2, 0x3FE1D6818A8AD5FF, 0x3FF87B11CF945073, 0x3FC39582EA293F30,
+	0x401837E0D06140A2, 0x3FE23ED264E4F5E3, 0x3FE4C3B092B9662C, 0x3FE0097C87D3F380,
+	0x3F95E811F3FD052E, 0x3FF9701E3CE9376B, 0x3FF126126F028F62, 0x4006FFFB5A153DB2,
+	0x3FF592D8810C3FE5, 0x3FF008FD810A813E, 0x3FCB97A10A8AFE6A, 0x3FE548E5623C2820,
+	0x3FD0A1BF12466531, 0x3FEB04261A2A91FA, 0x3FFC12118BB29C00, 0x40020D4DEAE08737,
+	0x3FD2AC0C9DD6F42B, 0x3FFDB9F1491D091F, 0x400FB58291BCE4D1, 0x3FCC8B4933580AE4,
+	0x3F6DA7E309E4546F, 0x3FDAF27F9B82FE80, 0x3FDA02ED390210D2, 0x3FEBB579553347D6,
+	0x3FF1C4BA0B21EAC9, 0x3FD24C0E0BAF83F6, 0x3FF03D5049621BA1, 0x3FD0960A9E9EA696,
+	0x3FD8C976DC18B287, 0x3FCED967541147FC, 0x3FEFB8969BD28080, 0x4010656B8DC172EA,
+	0x3FDF0317737BA890, 0x3FE83E2B5E7E860A, 0x3FF7BF685D2006AA, 0x3FD5D263AF3C4717,
+	0x3FEC7E676E7D0155, 0x3FEFF83A604F24A7, 0x3FED614C5BFDE1E5, 0x3FE0E424B36A5DE5,
+	0x3FBADC5FAD4B7D09, 0x3FE6BF16C90B1843, 0x3FF0168BD057B05F, 0x3FEAF645AFA35B40,
+	0x3F833B90EC6E5687, 0x3FE7F304E35869A9, 0x3FFDAAA9014930C2, 0x3FF9DF1CCD185121,
+	0x3FFC3BB6303042D0, 0x4003283FF6A8F72A, 0x3FDE2B1D0698BBC7, 0x3FC8CE57DE3DFFB9,
+	0x3FFB9E8B0BF9041D, 0x3FD924064B979E9B, 0x3FC40E7AD595CD0C, 0x4001ECFF86E0B459,
+	0x3FE2B0B65AAA253C, 0x3FC8A26AC3DC76B5, 0x3FF051A577EBE352, 0x3FC6677F351854B0,
+	0x3FBC9EAB5C39FCBC, 0x3FE9F29BBC279156, 0x3FED21BAD7E8D9A9, 0x3FDD91D0858CB314,
+	0x3FADBB48290D54DC, 0x400ADC5A5F7505AE, 0x3FCC5D9D3CF55050, 0x3FDBC8BA68D55D1D,
+	0x3FCAE646E74F2C8E, 0x3FE4DD348E8A7CC0, 0x3FE4DED4ED0B732E, 0x3FD0A9CC7D5A3B75,
+	0x3FED7549D9FC54DF, 0x3FE31E63BE50EC42, 0x3FE18BD7FE937317, 0x3FE3B998D5970A23,
+	0x4004A2AD2F4560A3, 0x3FE93E59988179DB, 0x4020FA8267C0412A, 0x40078D35FDF8BF73,
+	0x3FDCC898563F57EE, 0x3FB2357D5A3874D2, 0x3FE28F98E8DCE7D8, 0x3FFED1655CD80982,
+	0x3FF421237235F4DC, 0x3FB560A4E04C38FF, 0x3FF404C8F8FFFD35, 0x3FE5F4687F867851,
+	0x3FF0CB0F82C2E967, 0x3FDB680D3FFA9E25, 0x3FF2CAD86E02333C, 0x3FDAB6018C7562AF,
+	0x3FE044EF6262F6D6, 0x3FE6AD5902084F87, 0x3FF8F9D2AECDD742, 0x3FD0991FF8A12422,
+	0x3FCC08395A8AB16B, 0x3FED2EE96210CDED, 0x3FAA179CBED98A15, 0x3FE7553BC05E0C08,
+	0x3FDE0D196A39C30D, 0x3FF86F5408E7A81D, 0x3FE3897BA4781D54, 0x3FE131C5B49658EF,
+	0x3FE1F5007A6C0DA3, 0x3FD71C0D9D1FE9D1, 0x3FE6E543F628CEC5, 0x3FED248CF8138F42,
+	0x3FE6894C50D806B6, 0x3FE29C00ECF8B12A, 0x3FF78D265ACD0818, 0x3FEDEFDC7E82DB9B,
+	0x3FB5DAF1458D5BF3, 0x3FDE2074A19D450B, 0x400698D664115427, 0x3FD7CD3DCF5CE484,
+	0x3FE34018897D3761, 0x3FEDC88516C5C4B6, 0x3FE8E248EFB93127, 0x3FC1B25AE31ADBD4,
+	0x40036A43D8AC4567, 0x3FCDBBDCF97C9392, 0x3FF27FD727D2D6D2, 0x400146B80DE49ABE,
+	0x3FF488BFCA50DBA8, 0x3FC5304DF786B0A0, 0x3FEFFC46D8A12DDF, 0x3FE88059E0E476D6,
+	0x3FE65D693D7E3107, 0x3FE52A359211288B, 0x3FD5F51EC16C5F77, 0x3FE0784505350331,
+	0x3FFC43332972540F, 0x3FE379B938BA9639, 0x3FE5ED1C40FF4C1B, 0x3FAE404CE8783075,
+	0x3FE859276B4029EB, 0x40029D4B89A90D50, 0x3FE2614DDA12B476, 0x3FCD77CC259BC0DE,
+	0x3FD975E06C67B90C, 0x3FCDB9723A4B3EDB, 0x3FCDA0A6C0D06258, 0x3FE4999D48455D23,
+	0x3FE76A3AD76AFF68, 0x3FD4F8BB1D2CFF73, 0x3FF78B6AD3E9477A, 0x3FE2312B48A472B0,
+	0x3FE882845D2265EB, 0x3FCA82FCC44D3D52, 0x3FF1F8CB2D127878, 0x3FCE7DF1409D43D8,
+	0x3FE383A6F16DD68B, 0x3FD81D28D89DF760, 0x3FD849B9507DBC5A, 0x3FFDAB4B28ADB2B2,
+	0x3FF8B59B67EFC18D, 0x3FF91AB266F8F47F, 0x3FF12F60C881405F, 0x3FC637408B874E00,
+	0x3FECCE39B773FA75, 0x3FEC01E333676C52, 0x3FF4A41D53EF36C0, 0x3FB8F0124C0D5653,
+	0x3FEB6312766DAA5D, 0x3FFE58FCA7BB820C, 0x3FB4B25612BB46E1, 0x3FE5A714B738FB75,
+	0x4004CB2F5E172AA4, 0x3FF358D86E807128, 0x3FDDCD6FB9352C25, 0x3FFB284A9CC7C02D,
+	0x3FF186A50E4DD382, 0x3FC1CA2F3B8619A4, 0x3FF0F0EC09DD839D, 0x400202DE0E353BA1,
+	0x40095348255F4935, 0x3FFC75AFB4F28A5C, 0x3FBCE52448E2307D, 0x3FE8FBC9B69D7477,
+	0x3FDFDD7A72BF6F1D, 0x3FF67E77DD4AAE60, 0x3FEB9AE7968425A7, 0x3FE35622CDEF458F,
+	0x3FF273B62F3CFDC8, 0x40152051B44229EC, 0x3FFD8A875F0AD47F, 0x3FD353F1B528B676,
+	0x3FD5C5F0BC400E03, 0x3FE7C32BAF67B5DF, 0x4001BC3259187EB7, 0x3FDE335EB727BAAF,
+	0x3FE2CC63D01CDE7A, 0x4005BC46FC04912D, 0x4002EAEDB3654931, 0x400116FD6D575E34,
+	0x3FCC510DA0659DD1, 0x3FF091E5BEAD9C68, 0x3FF57C8AE6F394A8, 0x3F995C9893C70BA4,
+	0x3FCA412CE18D5DDB, 0x3FEDCAB3B60ABF66, 0x3FE61A5771720767, 0x4001B18E793E1D12,
+	0x3FF941E09ECF498A, 0x3FE454B482F339A4, 0x3FB4E6AB1E526A6C, 0x3FFAED58114C3D92,
+	0x3FD88F8C4A2B1AB5, 0x3FD4E8C811D5426D, 0x3FF3447A8C63DC2F, 0x3FE1F0ED1C1D3033,
+	0x3F8A1F255C570CFC, 0x3FD0CDBA47CCE890, 0x3FF0CB2274EFC90B, 0x3FC436C7E3F6C7EF,
+	0x3FFCF78DC9F634C1, 0x3FDFE58C66434FDA, 0x3FF4BF2B631B34CD, 0x3FE6B3F4A7D0CE98,
+	0x3FD2B990726F69DF, 0x3FD5F25262A07BBD, 0x3FF8B00FE113647D, 0x3FF5A8E3E21A389C,
+	0x3FEA2C7E189FA940, 0x3FF1B9F35D1B2BE3, 0x3FD32DEC7F6185F8, 0x3FE4D0D924627EF0,
+	0x3FECFC089BC98F67, 0x3FF199CDF9AB01EC, 0x3FE8C29EC5D0167C, 0x3FF03EB1ACFE90BA,
+	0x3FFAE59219C814D2, 0x400044AB6C0B85AA, 0x3FE7FF0DE6ED9C1D, 0x3FD0AF5B98990551,
+	0x3FE34ABEE946F967, 0x3FFA9FC1854D94EB, 0x400297CA22E60064, 0x3FF818D4AF43C27C,
+	0x3FCD7D49F3B069FE, 0x3FB852AD0268C6E5, 0x3F9337B22B2E8A51, 0x40033EE17DBDE410,
+	0x3FFFAF28CBB31966, 0x3FBDA7DEF55E57B7, 0x4001C5AD0E8A971F, 0x3FC38F72B0760FA8,
+	0x3FD2803F4F3770B7, 0x3FF0DEB9C9844EAA, 0x3FF2692E21EC62AF, 0x3FF598F7B7CF2FBD,
+	0x3FF5DFA7372DA84F, 0x3FDD7787E3278968, 0x3FF98B1DC0EA64F8, 0x4009CAC140B0B891,
+	0x3FF638C1F7F8FCB9, 0x4005048A509D0C7E, 0x3FC8A664D3B8AF25, 0x3FD3F0FEAE15ECDF,
+	0x3FF154E5FF3ED8DD, 0x3FFB7E9BFA1BE22E, 0x3FE0EB57301613BE, 0x3FBDCEF9926BFED8,
+	0x3FE279D0BE8B0075, 0x3FF3B5CAAC3F355B, 0x40019310ACDC148A, 0x3FD06C04BD18CBD9,
+	0x40042EB5A8F02469, 0x3FF9F5BFE03532AB, 0x3FFE2DF035CC2834, 0x3FFEE3283EC6FC72,
+	0x3FE7F3E13116374C, 0x3FC8FCB032698DA4, 0x3FE281281C1BFFCE, 0x3FF41E55E6C335EC,
+	0x4006FA16D31DFD7D, 0x4000C640DD5D71C9, 0x3FF1C37404F097C2, 0x40023D18ABD3BB26,
+	0x3FF67DD91F0163CC, 0x3FE4D42F2EFC3BDB, 0x3FF2ED8216E87A02, 0x3FF065E1260A7B94,
+	0x3FDFC1A84B1F7239, 0x3FDB4DBFA8A44550, 0x3FE1B97D5451BA86, 0x3FF903AE61408227,
+	0x3FDB064AE5F5F856, 0x3FC4B79FE17EE1FA, 0x3FDA1BAEA77E963F, 0x3FAC853DAC47CFDD,
+	0x3FE4647E73B60D93, 0x3FDF734D577FEE31, 0x3FCEB8EA51B14638, 0x3FEDC8967DD839C7,
+	0x3FAB58C6C7275836, 0x3FE480A30558EDD5, 0x3FF09807EAF4D031, 0x3FF4C3E4D351FF14,
+	0x3FE1D1A89D807A6A, 0x3FE22592E25343E0, 0x3FE895BEF575FAF0, 0x3FF2670CCA61310C,
+	0x3FD7FE1DA5CE1061, 0x3FE91FBBB9CCA959, 0x3FD1212169A34A53, 0x3FEFC197A4C35C5C,
+	0x3FEB83DD0BF1F0C4, 0x3FFAEFAED451FE54, 0x3FFA1356478C0F18, 0x3FD1B32B92A476DE,
+	0x3F9F0E4EF51672EB, 0x3FF9B5B596EDFC8C, 0x3FF1AC7BC488236A, 0x40117720F89D5EE0,
+	0x3FFAC8D7688593C2, 0x3FF9084BD23FCEAD, 0x3FC5F1884C48803E, 0x3FF41E0BC8BB629D,
+	0x3FD727D09705CD00, 0x3FDB0F51BC7F8981, 0x3FE308C602C17A32, 0x3FF49FBA6D700418,
+	0x3FE07355D98A77D9, 0x3FFA90FD25EE23F7, 0x3FE0335AB7DBBA64, 0x3FD022C4176AEE3F,
+	0x3FD580F89027B6A5, 0x3FEFE376E408D566, 0x40005AAFEA6F2992, 0x40037CF196DA0DA8,
+	0x3FCD4138F69EE5AA, 0x3FFCACF4981471D3, 0x3FD0673D9309C20C, 0x3FD78679813C99DA,
+	0x3FDCB72FEF5E0FA2, 0x3F9D84483E0AD581, 0x3FD8A4F998DDC6DC, 0x3FD7E7093A1B84CD,
+	0x3FF80972D4E826DF, 0x3FD1C369F69DF87C, 0x3FC5242C57AD5517, 0x3FE0D009E8D763AD,
+	0x400A7183121C1A54, 0x3FA7F4BC34B3591E, 0x3FB23F999ED77E24, 0x3FF1913F4949D9A9,
+	0x3FE95595C5A60A04, 0x3FD3FA48BC6BADD9, 0x3FE23CE0AEA5EF10, 0x3FC746A4415B8002,
+	0x3FC36D275B531C22, 0x3FC1CD9715DE14D4, 0x3FF33FCDFDBAD1A5, 0x3FD7B67040A6F85C,
+	0x4005B1047E53FE39, 0x3FCB2BEB3D2725D7, 0x3FF1D40C5994FC5A, 0x3FD59DC74DA27194,
+	0x3FF22F3030391F59, 0x40149050C1C29FCA, 0x3FB7FA7D9CF91EF0, 0x3FC2C288DEC4D4E8,
+	0x3FEC74D754BDDFDA, 0x3FF2022ADF3ED735, 0x3FE5718249D8C8DA, 0x3FE8C59E0D7DB278,
+	0x3FBBF22295A45C0F, 0x3FDA65477A110A4A, 0x3FE203E1F968B096, 0x3FF5FE2D7633A454,
+	0x3FF152113D630F81, 0x3FED65864B4DD04D, 0x4005A03809EDC186, 0x3FA1A53836E62891,
+	0x3FD87FE25FFB12BE, 0x3FE372876A76EF23, 0x3FE0C62C6453008A, 0x4004B153D7A840D4,
+	0x3FCB826C7132B9C6, 0x3FF4675F699F74A4, 0x3FD469E1D489AB76, 0x3FF7EDC0695A1450,
+	0x3FE62A17EB525B04, 0x40004ED668EE718B, 0x3FFFEAD158EF0ED2, 0x4008352BF7031858,
+	0x3FF2A730DB1F669D, 0x3FAABF4D6A0029EC, 0x3FF94900F04583F9, 0x3FEE7291883F1170,
+	0x3FCF736373EAEECD, 0x3FE3191054AEE958, 0x3FC25A0B4F113D0F, 0x4011C689612A5555,
+	0x3FC913068C149EE8, 0x3FFD71CC5BB354DB, 0x3FB64D344B0C3D2D, 0x3FF48CDE061C27C0,
+	0x400A1DF63CEC405B, 0x3FBD74507C39B4DE, 0x4009A893289BAA55, 0x4002B0B0262DFCE7,
+	0x3FFE7F6ED771C951, 0x3FD35F303A8C5512, 0x400985E1894391BB, 0x3FBE5122D4D7DEFC,
+	0x3FEA06DC2ED0387F, 0x3F9264BC2F437613, 0x3FE512DD3AB0162F, 0x3FDEE182C5412087,
+	0x3FC3CA072FBB0575, 0x3FC2F771F4D82678, 0x3FFC9F3FFA3206CF, 0x3FD5B539D2002AB4,
+	0x3FE47E12D3287F58, 0x3FC4624CF650401F, 0x401542A52D711BA8, 0x400312F76F57B4FC,
+	0x3FFA34EF9E26D7C5, 0x3FE1E9A5B5E239E8, 0x3FEB555C5C2A77AD, 0x3FB62240EF804A0D,
+	0x3FF27929817AB090, 0x4010F6D16E81792E, 0x3FE4A2FB91A53AD5, 0x3FE784F7AC6218CF,
+	0x3FC9C18963690A6D, 0x3FC02A8858933BEC, 0x3FDEDC70324844D9, 0x3FD39492573C9B23,
+	0x3FEF9C2ACE260AB7, 0x3FF85D86C742D3B1, 0x3FEA8008D2C25763, 0x3FF53EA0CA61402C,
+	0x3FF87E31463DCE00, 0x400054107702643A, 0x400D7630517DA01F, 0x3FDAC064E02B5274,
+	0x400387CB5B10D8EA, 0x3FD983AEDCB7C729, 0x3FEF1A1764281DC3, 0x3FFAAE8F293F3059,
+	0x3FF6B0E6524D59B2, 0x3FE4CEDA6991A6C6, 0x3FD0E1C5BBF4F2AE, 0x3FF1D72EBC3A5E11,
+	0x3FD933B65A713B08, 0x3FF5B5A755984AAE, 0x3FFC79F6417FD9B5, 0x3FFC70B30550912A,
+	0x3FF94EA2608C8572, 0x3FCF98E19C4DC511, 0x3FDCFDB3EA9FC17C, 0x3FE3FB8B13531663,
+	0x3FC6BEC736B02BF9, 0x3FF1F39F98BE52C9, 0x3FC1B6CD6B791476, 0x3FF30DECFACA8285,
+	0x3FF552A2AE32FC5B, 0x3FF0CF2560853F75, 0x3FD945E105CD3C4C, 0x3FDDD90845A163ED,
+	0x4009F78C7C23A7EA, 0x3FDE38FF558CFA1A, 0x4002BE4088868970, 0x3FE3A47740A6E025,
+	0x3FDA1DF1EDBBEB2E, 0x3FFF5B3D35490F94, 0x3FF6A87532360EAE, 0x3FDEFC6EA6298558,
+	0x3FE60C81C3693F5D, 0x3FA1A0C97279D473, 0x3F74B2977264254B, 0x3FDA73FD62484A60,
+	0x3FC2E08249D21DD8, 0x4001D9C46BBEA1C2, 0x3FA7423135CE02BE, 0x3FD63BA5C5B1BF95,
+	0x3FD6DBD55DC32066, 0x3FDEF2DA406ABF5A, 0x3FFB711B3D70C3DE, 0x3FDDB70B0CCC2754,
+	0x3FD8648324080472, 0x3FFD7CC05BB371B6, 0x3FF0EDFD56C688AA, 0x3FEF3160C2C2C051,
+	0x3FEFBBC23AB3A508, 0x400D4B8897853E69, 0x3FD2188CEC7D8CF9, 0x3FFD08960B8107CE,
+	0x3FE5FC8FD0C70225, 0x3FF52E2FB71DC8D6, 0x4000F78F0ABB901E, 0x400AACFCBA30FE3C,
+	0x4009016761DA1ECA, 0x3F8B7C5288072486, 0x3FFC760EDE8AA3C3, 0x3FF0C892FA6481A4,
+	0x4016B61549F48C85, 0x4016E1858DD5D8B4, 0x3FB6611A7E07F072, 0x3FECC873BF14AF26,
+	0x3FD7FD5724E6E383, 0x3FC3F59E85EC32A9, 0x3FE5EBBD36AD5EFE, 0x3FFDC6CF96B08477,
+	0x3FA6A6D9A2FC7D1F, 0x3FDAE7876448B240, 0x3FF0CC8A39000D15, 0x3FEE50311DC36CFB,
+	0x3FF0CA7F181AC1AD, 0x3FCF39684467B1FF, 0x3FF0F3EE7B257F93, 0x400361F6DAFBA27D,
+	0x3FF79C6F67C98185, 0x3FD8691CAAE398CE, 0x3FE78B1E696733D2, 0x3FF2E96A1F69F1C4,
+	0x3FFB2BC460368E7B, 0x3FCFFBE368CDF344, 0x3FB3B278AEAFBA48, 0x3F88BCC8FAFE230D,
+	0x3FEEFEEF3CFE318E, 0x3FE2BD132BE4698C, 0x400668C46C186B12, 0x3FC3A988850ACFCA,
+	0x3FEB47F4E63C6020, 0x3FDF17584B952FFF, 0x3FF673F922C93C46, 0x3FF9AABE9D6E3B79,
+	0x3FAA5A6760182EAC, 0x400116909149136A, 0x3FF831CBDEBDDF75, 0x3FCC2EC8924BA0EF,
+	0x3FD9190662F75371, 0x3FF8C3786B55A3CB, 0x400AA14877DAB615, 0x3FF4107AE483FACD,
+	0x3FD7F07233E66BCE, 0x3FD99C45C1FD226F, 0x3FEFF4FF1216BE78, 0x3FEA29EDA6D9911F,
+	0x3FC2D0C96419935E, 0x3FF1E9E79636851B, 0x3FF258897AACB7AF, 0x3FDBDFD1DE25FC7F,
+	0x3F901CBA92C3F88C, 0x3FC51F4626349148, 0x3FF3AEDB8B29C010, 0x3FD28CB66F171059,
+	0x3FF4A0E675ED4FE1, 0x3FDDCB86DBFB6600, 0x3FF489E65C474ADB, 0x3FF1D472CE705825,
+	0x3FB872CAF91C567E, 0x3FE709EA122BA843, 0x3FC5AA6BA8CF4917, 0x400E23147D7828C9,
+	0x3FBFAE52A9D1C5A7, 0x3FC9B14F2E5355B9, 0x3FCB8C1A7F1695CC, 0x3FE69698BF0AA449,
+	0x3FC97858134E21D0, 0x3FA32B438972CCC4, 0x3FCC13DC32CE83A4, 0x3FDF49AB337309A5,
+	0x3FF1270952294363, 0x3FF5695CA41C3DCC, 0x3FE74B5B405B105B, 0x3F936B2546122108,
+	0x4002659D0267608D, 0x3FB49B33EEE56CC0, 0x400783986FA5BB3F, 0x3FE1A65C3A46DC7C,
+	0x3FB107EB5F9F3C57, 0x3FC2E6ACE779A27D, 0x3FF4995B1A2C84CD, 0x3FED4A97A5123F0F,
+	0x401100AA5118F625, 0x3FEAB31CD0C5ABAB, 0x3FEC54E2AE8712D3, 0x400628D11532BDE0,
+	0x3FF4958BB7585911, 0x3FDD785B4B72E898, 0x3FD26C744B5D58EF, 0x40026E0D722DFF71,
+	0x3FE121AAA936DB30, 0x3FF78BBA737EB1F0, 0x4002AB33B01F86F8, 0x3FFB2A5C993CB861,
+	0x3FED6CB6278C00D4, 0x3FD4FD4A8C245EAF, 0x3FB5CFA952EB624D, 0x3FBAD5B668DBF387,
+	0x3FD56130B72BBFE4, 0x3FD4CF99F3685D1F, 0x3FAE739112B6CD37, 0x3FCE2F81A271D097,
+	0x4011B0A150FAF259, 0x3FEDFB22CAADE692, 0x3FF954D6E4F1EF31, 0x3FF0C6BBDD2B966B,
+	0x3FF6C4E2613D499B, 0x3FF1552DD1E7AC6B, 0x3FEC073BA5423ADA, 0x3FE96080BD889177,
+	0x3FF70C358A3FC33A, 0x3FDA24850903D949, 0x3FF1B9DC9FDC6D48, 0x3FB7C96693DB77D9,
+	0x3FECC5C418387FAD, 0x3FDD189AAAA265AD, 0x3FC00B125ACD8361, 0x4014EE7603CDC01E,
+	0x3FD0633A97046CB0, 0x3FE0B2C2087814F7, 0x3FE633F7449E8884, 0x3FD8C97A4F45DDF6,
+	0x3FD623193BC23B53, 0x3FB45F49BFD7C66C, 0x4004FDE9362AF61D, 0x3FC8C9358AF87869,
+	0x3FB0A52EAC0DB543, 0x3FE7FB1B8D5D4698, 0x3FDFA5F46E3C7A8A, 0x3FEEA76A0410F8E1,
+	0x3FDDAC49FDA017EC, 0x3FEF71368A123E67, 0x3FBBEF82C0786C5D, 0x4000BBEB946EB40E,
+	0x3FD8A55117243C3C, 0x3FD779FEF0D6B30A, 0x3FCAECB4A37AF710, 0x3FD5760B35A51DB1,
+	0x3FBEB1048C2DB8E0, 0x3FB7FFECCE88141E, 0x3FE6A0F7FF4291F9, 0x3FB0D4DBE6D77EA4,
+	0x3FB97BE461DA53A3, 0x3FF41392F18D2829, 0x3FFED6CB1FA9B41A, 0x3FF9E59D086282AB,
+	0x3FBF27B00FDD92F1, 0x3FC07F165E286C7A, 0x3FF4A1025AE9C204, 0x3FDB76B092A51F89,
+	0x3FD670797100EF8C, 0x3FD56080BF2EE1E2, 0x3FA95AAB8B635F59, 0x3FFEA01211B4052A,
+	0x3FF28D90B8D7614B, 0x4005DB0650F0B872, 0x3FF0A462F0671135, 0x3FE16D191C6CCEA4,
+	0x3FF876D0A4E6CF9C, 0x3FE6C9AE9D9C3401, 0x400D5F9A15BE04ED, 0x3FC07218883BE173,
+	0x3FEE09291E6BFD70, 0x3FE9CDE93DAD1C95, 0x3FE5ADA71F4DADE6, 0x3FDB779F36655309,
+	0x3FE65971DA37DD3E, 0x3FCDC703A451B467, 0x3FF07AC3CE462CD7, 0x4001287C808AF74B,
+	0x3FDEBAB2CCD29D06, 0x3FD9020A8424DC46, 0x40084230F0136707, 0x3FE963BF9D5BB854,
+	0x3FE048BFDEDCE1EB, 0x3FF4A43E143708A5, 0x3F9E850EC1B78047, 0x3FDC5FAC0CD29D20,
+	0x3FA6918878487427, 0x3FDB08BD4E5A27E1, 0x3FE052293CE3E8E2, 0x3FD68A7DF265C7FA,
+	0x3FB627CC2EC0CA3F, 0x3FE5B24F5832E7F7, 0x3FECC33B9134ED2A, 0x3FE37FB24B9A6E4C,
+	0x3FDD72867E5ACBA2, 0x3FFBAA3E6C43ED83, 0x3FD1777561F2D90C, 0x3FE8304202582217,
+	0x4017B43A92C284E0, 0x4004C2B3B5491E5D, 0x3FF263D858688BD9, 0x3FF601E27C2E5140,
+	0x3FDC78F07C30743E, 0x3FF1196D503A6921, 0x3FD215394BB91874, 0x3FD8C5263D3303FA,
+	0x3FE63016152853E6, 0x3FC796E2C1123BFE, 0x3F916D16A60DD54C, 0x3FD1E2E272F117C6,
+	0x3FD1F9019BF687D9, 0x3FCCEB236C949D8D, 0x3FF4724DD6F5A8D2, 0x3FF0988DF53B1FA4,
+	0x3FD9129A3F65C2D8, 0x3FEF5A67AADE88B9, 0x3FD2C47CAD67B9A8, 0x3FD23E38F0F79DE6,
+	0x3FE442C19EBB210D, 0x3FE448DA5AA31C11, 0x3FF48F9BAAB02290, 0x3FF53E06DD2ED97E,
+	0x3FE620240A2C8BC4, 0x3FBC606E55D258BC, 0x3FD9D052BCF5265F, 0x3FD661895B5285B5,
+	0x3FD81325525C354A, 0x3FF0777679BF49B0, 0x3FE03AB4A5450AE9, 0x3FDC643C44DC5E15,
+	0x3FE9970E87982440, 0x3FF57CEB2F51E0CB, 0x3FE85527FE438515, 0x3FB52FBE52D44B0B,
+	0x3FF85B9D3D992D14, 0x3FF5A6D4EA5F041C, 0x3FF1D6BF2A5EB918, 0x3FE5F8B2B636B9DE,
+	0x3FEE13E38B227246, 0x3FD4FDF3D4F45FD9, 0x3FF9FA6865C3D80C, 0x3FF09729598AF107,
+	0x3FB99D0B030EDAAD, 0x3FF22FB65C11E77C, 0x3FD56B4346F7925A, 0x3FED0BE965633FA6,
+	0x3FF0C63BBC5F588B, 0x3FF053EA4442A561, 0x3FEE2783450B1488, 0x3FD3280049B35864,
+	0x3F9CD25D4BB8CE75, 0x3FBF1010778BEDB6, 0x3FDCD445CE022CDC, 0x3FB1E4A1F9DE661E,
+	0x3FD8D2980C1F714E, 0x3FF3A91DEFA172D1, 0x3FFBE255039B0286, 0x3FC19364D3B1A03E,
+	0x3FD673E57CF1037B, 0x3FE06CB80F855253, 0x3FDC6FA207E6A630, 0x3FD640E348BFA754,
+	0x3FF16D3125714A71, 0x3FEED718B29AC591, 0x3FCA779CC8BBA6F9, 0x3FD817C0F3091B78,
+	0x3FF2057695ED6803, 0x3FF3193427CFF8F5, 0x3FE0AB9FF6AF7C40, 0x3FB17F27D1834DD9,
+	0x3FF9FFF699B4DBF0, 0x400024CD06A5CD18, 0x3FE9C4C8A36367ED, 0x3FEE32B04746F394,
+	0x3FA76602CEF1D272, 0x3FDA26DCB04CE6C2, 0x3FF5A9A11524F5C5, 0x3FF37B5B9402D6C7,
+	0x4000C746CC7CABD2, 0x3FD5F7C8E368F583, 0x3FD04EB9BB575E42, 0x3FEAEC188ED49210,
+	0x3FD4A04B3E3B2153, 0x3FE50AF54169BE23, 0x3FEA9B4EC12EABD5, 0x3FE6B6DE07A241B6,
+	0x3FD12F77C5F081DB, 0x3FCBEA0C43D38A70, 0x3FD6CB335FDDC2AB, 0x3FDF69527DE308C4,
+	0x3F86EEE0D81AF55C, 0x3FB3537BF04B48F8, 0x3FDCC0B033D4E6A9, 0x3FE83C6845C0068B,
+	0x3FA99D7438B5ECEF, 0x3FF1DA9D57A2EBE1, 0x3FDDD8E13829EF62, 0x3FEAA49FD31AFDBD,
+	0x3FE26C2F4AED1243, 0x3FD09344E215DD7A, 0x3FF08D16CFC3B2CD, 0x3FE92ACEE8D4ABB5,
+	0x3FC222C2929B001F, 0x3FB381FEE0D3E8EB, 0x3FADBBED15E6425F, 0x3FE1543B451AF6C0,
+	0x3FEDE2E2D9CF3083, 0x3FC0461D8C4E793D, 0x3FC2DE109634A75D, 0x3FF00D7D88BDB7C7,
+	0x3FE0B20BF5391572, 0x3FF032747B689C1E, 0x3FB4BF7EC68B153E, 0x3FEC5BB5AAA922B2,
+	0x3FA03B27B7BFD17B, 0x3FFEE634A8257A56, 0x3FED331149854A97, 0x3FF6B7D8710D51C9,
+	0x4001D6C055ED5557, 0x3FF00F42DAC24A5C, 0x3FD37B7B924A8533, 0x3FDD532668853D50,
+	0x3FC377DDBDA1774D, 0x3FD60C4D14AF5E58, 0x3FC235FD46E674FF, 0x3FC7B0B20C13E776,
+	0x4000AA1D2EAFAB3E, 0x3FCFF7BC505C4BE7, 0x3FC6A9B59E183F6A, 0x3FD296DFA86E0326,
+	0x3FF1AD920A3E7FCB, 0x3FF2D285EBB5E73D, 0x3FFC2BF7CE30D9EB, 0x3FE3A5CA1B7ADBB8,
+	0x3FC78977A5EF4693, 0x3FAD60FD6DD9B310, 0x3FCDCCDA8532FEF6, 0x40051071FC00E41D,
+	0x3FD3034DFF0A45FB, 0x3FE9D14621B5E465, 0x3FB5A8414F0BDBFF, 0x3FF9D36FF4A3C4A3,
+	0x3FE91E269EDC97DC, 0x3FF89D0A3C35726B, 0x3FE50E5F2B0AC42F, 0x3FDF78630E6A7A35,
+	0x3FF5D0076449D1B3, 0x3FDD530F86095775, 0x3FF2882495201893, 0x3FFB64A6574B2A8C,
+	0x4006D6131A3056DA, 0x3FF8579F0B1E0E68, 0x4001C678C03E4CA9, 0x4003C439DD93B8BC,
+	0x3FF8E995D92EEDD9, 0x3FFBBE7595DB0EF9, 0x3FCD98D4AD2C3D73, 0x3FC259F75466F13B,
+	0x3FD97BD752F3F574, 0x3FDA2EE7B197BD09, 0x3FC983BD04704D0D, 0x3FF64E5A4EE08E31,
+	0x3FD1366D9EE84D15, 0x3FFA972C306D3247, 0x3FF8B32365562262, 0x3FEB841D3B04B3AC,
+	0x3FDA55B728ADA0A7, 0x3FE0CB2916F44C5F, 0x3FD4A0272E091E80, 0x3FE32E3533E553B6,
+	0x3FCED6BAEFE52B49, 0x3FBDFFF16D4D837A, 0x3FB555F5DB7B0CC4, 0x3FF5A9F63D1C1306,
+	0x3FE279F2B9E4D43C, 0x3FE1C09601F498AF, 0x3FE035465CE774F6, 0x3FF507FEEE29BC3A,
+	0x3FEC69F674BBF8E7, 0x3FBFA8A32B0E1936, 0x4002136D0E846777, 0x3FDF7B140C9000F9,
+	0x3FB58C6ECC291FFA, 0x3FD3B5D339DFC4C5, 0x3FEE074D442BBDAF, 0x3FE41A1E07F8AD7E,
+	0x3FD5C41B9F46A699, 0x3FDE14BCB98BD6E9, 0x3FE915FA93ECB0CC, 0x3FE6904E6B0A23CB,
+	0x3FF024D4F57FCB98, 0x3FFE98886F80B057, 0x3FD587C0319C843F, 0x4002603C79402958,
+	0x3FD0B8108EB7D4F2, 0x3FA71AB5947C1AA6, 0x3FF688532FCBE3E8, 0x3FF48FA060715D37,
+	0x3F8F049666CD79B1, 0x4002D40015C52396, 0x40023A16E1608DD0, 0x3FE3B3C8CB80BDBF,
+	0x3FD58A32F26B1AFB, 0x4003B13309A87F82, 0x3FB459A42C22DBEA, 0x3FCBC6C97BAD489F,
+	0x3FBF79B0BE2584C6, 0x3FE9D96417B223F5, 0x3FC3D870A9A63FCB, 0x3F9BFCA75126C038,
+	0x3FE79592D71FE966, 0x3FBE37C95C2AB785, 0x3FF84E556CC9C447, 0x3FF92F5EA01F345E,
+	0x3FD2C344E0F7F902, 0x3FF0B5446C69E0D0, 0x3FF820A9989932F8, 0x3FEC297B7FA802EF,
+	0x3FFABD9974385826, 0x3FEFB8B8AE9F90F1, 0x3FD54283BF6CB559, 0x3FE190BFDF2BA0E1,
+	0x3FF38DAC6395D640, 0x40024CADAA531D1D, 0x3FDB4618B2B1B2D0, 0x3FF48E7F31BB83E3,
+	0x3FCB8AF150DC93F9, 0x3FE8B07D3E760E72, 0x3FBAEB9F3E41ABB1, 0x4014DC3FC029B00F,
+	0x400BE3DBA9C7DDAA, 0x3FE7356014D50490, 0x3FF2BA41CA9FF5A2, 0x3FC7BB14B4B291EC,
+	0x3FEB28FFED18E8C3, 0x401355721A7AA6ED, 0x3FF89D2596EFAABD, 0x3FEB6F124B96A8F7,
+	0x3FEE626226C99E01, 0x3FF40A147DC4F615, 0x3FE07CDD43EB4478, 0x3FD543A9883017B2,
+	0x3FDBED48935FC24C, 0x3FE87FBEF6F4D572, 0x3FF7A8DEA84166FD, 0x3FDDB1E7D063335B,
+	0x3FE668B9855EE556, 0x3FC4C38CC89EB369, 0x3FDAFAB10BA49734, 0x3FD81E1482245258,
+	0x3FEC9F6D2DD2100F, 0x3FD5FFE78C2FC710, 0x3FB33B1922214D20, 0x3FD8059ECFBBD5B0,
+	0x3FE90DD5908CC63B, 0x3FD7B6AF3E8845BA, 0x3FBB6CAC5B191B46, 0x3FCA40D254610F93,
+	0x3FF3FE9F3AF23212, 0x3FD916744F12523A, 0x3FD50BA9B9845042, 0x3FFD3EA91E600A18,
+	0x3FF4E2355342E17F, 0x3FC40F46423B7B51, 0x3FDE4136F9ED1020, 0x3FE15DC622DE0DA2,
+	0x3FE3C487E79A13AF, 0x3FDEB910ACE1AE6C, 0x4011E0361D799A2E, 0x3FF20BFE46836335,
+	0x3FF7D32622F60B61, 0x3FD472B4FDC38227, 0x3FEE6C6E1983EACA, 0x3FC1645CAF702721,
+	0x3FD0BC7BE822BB21, 0x3FB407B27497B5E2, 0x4008569F5422C0B5, 0x3FC6196682325C66,
+	0x3FFBCEC81F68D531, 0x3FE2F44F29C920D4, 0x3FF1B50C96EC916B, 0x4010B7425A5C9CA8,
+	0x3FC7EC75916D0812, 0x3FE49FC0D6EB008E, 0x3FA5F38BDD73A464, 0x3FD79A604041324C,
+	0x3FC533F232695539, 0x3FD1D16DF9034549, 0x3FF63B07BE8FF655, 0x400221C12F6E5298,
+	0x3FEDDE9FB0FEF2F8, 0x3FEF0D2180C2B6B3, 0x3FBA59FAD11F6879, 0x3FD6060413F6C50E,
+	0x3FDFBAFCC75C306C, 0x3FD4035A13C85880, 0x3FEF042D340AAE23, 0x3FE6C7BB9C17CFEF,
+	0x3FEDDF65ECE2443B, 0x3FCAEA264DE2D5E4, 0x3FCF78842132547C, 0x3FBCF4DE43C8599D,
+	0x3FD789E995255077, 0x3FFA4727A8B298E9, 0x3FD1BE162BA6A22D, 0x3FCD6E4235D05F25,
+	0x3FE8592875E90E48, 0x3FC78D9D6791A662, 0x3FF43D811F46C8A7, 0x3FE7BF799EACD54B,
+	0x3FE0E26966A1CF7D, 0x3FF17E8A04B5E295, 0x3FD251415677D078, 0x3FD555D46DADAE5A,
+	0x3FA296E79694FFCA, 0x3FFAA605AE2C81B8, 0x3FC25E2EFFC5C89B, 0x4006A6506B1BCF65,
+	0x3FD854E23783C2F7, 0x3FD3F36633371188, 0x3FFF8EFE966910D1, 0x3FEC8FC9348D29BE,
+	0x3FF08450BD896B12, 0x3FE152FE9D28FDED, 0x3FEADE01276E64A2, 0x3F75F3D7CECE7516,
+	0x3FD017A3C01EF3B5, 0x3FCCFABB527D864E, 0x3FDF28E157D4A987, 0x3FC65FFE79EE3CD2,
+	0x3FF0F4995907221B, 0x3FD16BFC54E73A25, 0x3FC032CA4B22987A, 0x3FDD6FF0D8F18D8B,
+	0x3FE180A2DE8A67B9, 0x4007A47A22CE1632, 0x400B9511EDE91D4C, 0x40093F4DA11EAD0C,
+	0x3F7F715103654112, 0x3FF865296D18593C, 0x3FF7290934AEBDFA, 0x3FF342E86DEEE859,
+	0x3FD6EF6B6F80885A, 0x3FCADD484CAD5E3B, 0x3FD83D5F28B78FB3, 0x3F704C5E04386CA2,
+	0x3FF8800E1BBF61D7, 0x401418C9067E67D6, 0x3FF52C5E5A70D988, 0x3FF0F74ED320475F,
+	0x3FE4CF42E7C8DC5F, 0x3FFD179032C8A57C, 0x3FE533C032A8EB8A, 0x40028F63494B130A,
+	0x3FFC0C2F0C221BF6, 0x3FD0854E7F379BCF, 0x40000ED5D807C54F, 0x3FE6BD6A26CA3699,
+	0x3FE793FE64A76822, 0x3FD93785CED46BAF, 0x3FC88F2D0F7C761F, 0x3FFB7EF5CB1EB2C0,
+	0x4007B62E498DA62F, 0x3FDA38EFE8091051, 0x3FD8C797999A0680, 0x3FE6CB93DC70EBFB,
+	0x3FE1469102C0962B, 0x3FF909B25F5A8970, 0x3FDAEB867B1D1D1A, 0x3FF5537F3954848D,
+	0x3FE92B7158AA4319, 0x3FCEACC3C6FC96D0, 0x3FD36C617A1C4A70, 0x3FE149C6B7060176,
+	0x3FF9F92D009D266F, 0x3FE967C8558F4B21, 0x3FEB1DA11C6E87EB, 0x3FD5478EB5187F1A,
+	0x3FE135889060533A, 0x4002897813DC3BE6, 0x3FD08CB01C46116A, 0x3FD8743111826F95,
+	0x3FB85393D9A74DC3, 0x3FCDD18A74AFF9DC, 0x3FD4DE70D331D476, 0x3F97AE7966C881E7,
+	0x3FD702772C6D7D37, 0x3FE4492274FDD715, 0x3FF404A04F9CDF81, 0x3FF92BC5CA007CEE,
+	0x3FB8609E1B577BAA, 0x3FB11DC0FEDD4A91, 0x3FEF38D7CF7289C4, 0x3FC5CD9E5089801D,
+	0x3FD93747D31E2A16, 0x4008A39BDE5E9A60, 0x3FECF80FDF47D4F9, 0x3FE05CF2D050D4FA,
+	0x3FEFFDC14E22D584, 0x3FAC27315938B16B, 0x3FC53E8895EB0BC7, 0x3FE82C0DEC857F1C,
+	0x3FE4486D316242D6, 0x3FF23B15919C511F, 0x3FD8AEECB38EB3AF, 0x3F9199726F632107,
+	0x3FE0A610477FF351, 0x3FABE5DF74DFFC8F, 0x3F914B4B9370ADA9, 0x3FF3803AD22E6CA3,
+	0x3FE498E2DFE0330B, 0x3FD003FD7D649DE5, 0x400004F9F006CFDF, 0x3FA6991037530C7D,
+	0x3FE303DF88901B77, 0x3FB58D65C55C0317, 0x3FEC388B66903B73, 0x3FF75F27854506A5,
+	0x3FE5557374AB446C, 0x400B625EE3841F13, 0x3FD24C9DB165E713, 0x3FDE0D174CE93A85,
+	0x3FEE113C18761AC1, 0x3FEE201DB60DBAF5, 0x3FE437D980F83330, 0x3F8CFF09E020A687,
+	0x3F51C6702AB2609F, 0x3FF634D2E7B9FA4B, 0x3FF8BAC9CD3D9AAD, 0x3FD8B0C05762A175,
+	0x3FD27F1CA152D958, 0x3FEB053994DCF42C, 0x3FE3E51C00E23953, 0x3FD9AA691840344B,
+	0x401D97546CE72734, 0x3FC912DC4DE29A65, 0x3FE549A0E28B3E6B, 0x3FF190AC75949411,
+	0x3FC26E81EF2137EF, 0x40087550CAC834EB, 0x3FB7CAB0790472B1, 0x3FB78EADB953D905,
+	0x3FFE69208971E496, 0x4014C3318DDB0089, 0x3FDBB5F1BD2635B8, 0x3FF8719C4A0DCAE6,
+	0x3FB897A753BAD997, 0x3FE1BA43B3BCA46C, 0x3FE816914350530F, 0x3FEB3718BAF0D98C,
+	0x4009F30466590714, 0x3FF1E6C10A2705E9, 0x3FF854FA36B5D9A0, 0x3FC376ADD6C36267,
+	0x3FE33C76D3334913, 0x3FAE4D742E9C8BFA, 0x3FE524DD7BFC9B16, 0x40073DB3B05BA6A2,
+	0x3FF8EE84C547BE84, 0x3FBCE3F516251261, 0x3FB790C7671414D7, 0x3FDBB7320CD6DFB8,
+	0x3FD827BB32484AFF, 0x3FF8C4227BFF1E28, 0x3F8AFDAC9D2B452D, 0x3FF8624AC86DE5F6,
+	0x3FFE952A5C8623E5, 0x3FECCFEA4646382C, 0x3FE343619EB090BF, 0x3FEB7051B5944A73,
+	0x40115E4C29EFC008, 0x4002AB43A41460D6, 0x3FD7CF42B9CD9FC8, 0x3FEF3C3F733F06CC,
+	0x3FD90F569795D616, 0x3FFDE97773625506, 0x3FEBC6208FC73D6E, 0x3FD7262B046933B2,
+	0x3FE0A6AC460316EB, 0x3FD8A7138B8E54AB, 0x3FF8A3FCD8125C8F, 0x3FFBF749D7824B14,
+	0x3FD3BB5EE603BAC5, 0x3F87ED61B00020EC, 0x3FBEEE18AD17C35F, 0x3FE20AFB255D5EC8,
+	0x3FFD37C8C1E0A965, 0x3FF6D1CAFEB2EABE, 0x3FF7E90746FEF065, 0x3FDCB8D522C154E3,
+	0x3FE278544D9EDAFE, 0x400EAB156687DC0A, 0x3FB8D03B443BDEC3, 0x3FF16F1E275219CD,
+	0x3FD6DE4C4B93E47C, 0x3FD7B97D960C1FE2, 0x3FE40E553B7479D9, 0x3FD0E5AA83075759,
+	0x3FF7D4E9CA48C0EC, 0x3FE424F084D28F3C, 0x3FF71847D93FE697, 0x3FE94679D4E6AE03,
+	0x3FB70C239556C098, 0x3FB8E6758D950C75, 0x400930E7E230FFF7, 0x3FC4068E739CBB71,
+	0x3FE6FF98168CDB25, 0x3FF10D952AEC3550, 0x3FC6AAD5476C29D4, 0x3FF09280CFFEC9E5,
+	0x3FEFE010083C08D5, 0x3FCBAFE9CAF57827, 0x3FDA65850F8B91B9, 0x3FDE98758913FE15,
+	0x3FB5471FB65CD35F, 0x3FBB4B17A940FEE6, 0x3FDD5DEB5EE0EA8F, 0x3FF87DB51F8431D1,
+	0x4015BE1BE5CBAA8F, 0x3FEA39EADB130E3F, 0x3FF3C24796318467, 0x3FF26BAC5D9A2920,
+	0x3FF51B05A592A246, 0x3FB44BA15C29E6B7, 0x3FE34708B4458809, 0x3FD0EE34A4C93369,
+	0x3FD448A0CE6A6E41, 0x3FD4E380EEC6503B, 0x3FF3C329AC320578, 0x3FFD5832B40DEF72,
+	0x3F7F07A4AAE0E375, 0x3FF5A3DC3B9A84BF, 0x3FF91FC3AA71FA23, 0x3FE071896D54C0F0,
+	0x3FAD3A81DC918737, 0x3FE2E83D27497D35, 0x3FD08D508BAC4AFB, 0x3FFBCB0BD584EBA0,
+	0x3FA1A48455BC82B2, 0x3FE16AECB53370CF, 0x3FE8493C9D96120B, 0x3FF120CD8D93CAB1,
+	0x3FD731790A2E2B3B, 0x3FF7CD24CAC0FD1F, 0x3FD1A3F5546CD3AA, 0x3FC47D7E4DE9ACD4,
+	0x3FF3A75C0E1E490B, 0x3FB943516662B384, 0x4001CA119B4FA0F6, 0x3FFB493E9C362DD1,
+	0x3FDC621137AA6AAA, 0x3FE813707EFBED1C, 0x3FE4440CFF973DFB, 0x3FD6B8D84D4495C1,
+	0x3FEE6E22D7340E9B, 0x3FB2B4D15AD8F515, 0x3FF36427E64D4670, 0x3FF86E970F5464D3,
+	0x3FFE0BF22CCBF8A9, 0x3FF4317131A29557, 0x3FEBFBB2753B9664, 0x3FAEEAE8F8B2C31C,
+	0x400AF2C4DD145C83, 0x3F985921D672EB74, 0x3FBB7ABCECEEDE8E, 0x3FE75328CD0CFCF1,
+	0x3F691C4D4B96F6B9, 0x3FF231698765DB85, 0x3F9932C1B2EAD230, 0x3FFB7E1A77B6C8A6,
+	0x40058091B8C4B706, 0x3FF73DBD6AA3C491, 0x3F88D804D35BC4E4, 0x3FACA4862832439F,
+	0x3FEEC664F9B0BE2C, 0x3FDA99F123C18C29, 0x3FD155F7E6816FD8, 0x3FFBDB4EA48FFBC7,
+	0x3FDA51FE28081C78, 0x40054CC70EAEA269, 0x3FC715AD2C3A97A1, 0x3FF0DDDFD710890F,
+	0x3FC339887C3435D2, 0x3FE3899DC7A66A03, 0x3FC2B5778A3C18D7, 0x3FDAC53D8D8F651A,
+	0x3F794D375B26388E, 0x3FD18E33E388BB8C, 0x3FB8990FD54360D0, 0x3F82BDE854F20011,
+	0x3FEB32392196B588, 0x3FB639853B380C9C, 0x3FFDF07BCA87F37C, 0x3FBBBA1BB483B8E6,
+	0x401900EF42AF80C4, 0x3F84C91132D87CCD, 0x3FD7DD1D0F995B4B, 0x3FB4A1FDE5B4D58D,
+	0x3FEE339BDCB74BB8, 0x3FFE4D62A15DCC84, 0x3FF5B59041E18F02, 0x3FD8B665A72277BC,
+	0x3FC720E03DED9213, 0x3FE0830E0883B538, 0x3F78260309E3A2FB, 0x3FBD13D66C06645D,
+	0x40039A5EFF16CD9E, 0x3FE3D49386250318, 0x3FD93DB8638BF39B, 0x3FCFA75F3D67D373,
+	0x3FF25E6ED7AC0934, 0x3FFC899ED9D70F0E, 0x3FDD6449D8AFE009, 0x3FDCA1FBC0E24617,
+	0x3FEA5CE048DDBB8A, 0x3FEFB8BCFD738FEE, 0x3FF44ED71313E099, 0x3FEFA28F923C68F9,
+	0x3FF0765F22316396, 0x3FB850791AC2AF13, 0x3FE002F932F62FE7, 0x40008C0020928F70,
+	0x400E39D608E28262, 0x3FD4654DB35F7A11, 0x3FC85D2B653A78E0, 0x3FFC51677322DF03,
+	0x3FE8D8AB18BB7274, 0x3FC3303A56FF1DC0, 0x3FAB24E7BC2C4D76, 0x3FE6707BF4272A37,
+	0x3FE12ACED195C434, 0x400BB96B927E518F, 0x400ABC119DEF49C2, 0x3FE1967FDF392243,
+	0x3FB6F03D37CF4161, 0x3FEE486AF30F5F58, 0x3FE815BCEDA51674, 0x3FE67D9340AF4B7C,
+	0x3FF7C9E14B835FE3, 0x3FCCBF83A734092E, 0x3FDBEB6B8DE2CFE9, 0x3FE396B200798B12,
+	0x3FDE4F9E029F1EC2, 0x3FD582A04E263D26, 0x3FDD548EF5C4ABC6, 0x3FDE7A31044DA8D7,
+	0x3FDDF9CEDCF18C6E, 0x3FFBAA2245915A00, 0x3FEAF774507A4993, 0x3FE5D4DC0D577DA5,
+	0x3FA798330AAE2319, 0x3FD812679161AB06, 0x3FE73ED2DF7A94E1, 0x3FE8970BDF51CC8A,
+	0x3FFEDA6A5EFFEFEF, 0x3FF6FFE4FB58B13D, 0x3FF633B82F5D550E, 0x3FE7FED3EBFE8BA7,
+	0x3FF0C350C8133087, 0x3FC5E4C56F1FEF46, 0x4004625FE46F2E41, 0x3FC2FDD30B0C45BF,
+	0x3FF9DBCEF79B825D, 0x3FE882BED424F712, 0x3FD2EE8A2001F3FD, 0x3FF1029C31AC0070,
+	0x3FD24BB07AE57724, 0x3FB49DFBB4F974A3, 0x3FF0744349ADA707, 0x3FDCCECB05E71A3A,
+	0x3FBFBEA5F98F18B6, 0x3FF2590987EB0562, 0x3F906CFD0D294174, 0x3FE987AF8750826D,
+	0x3FBC3D4A2D0DA677, 0x3FDF917116F0A9D0, 0x3FBC888F7ACB5DA4, 0x3FF1E239CA4B8DCD,
+	0x3FD635AF4BC957FD, 0x3FD69AEFD4630398, 0x3FC1A9415CA7A3FB, 0x3F8A2E079D859F80,
+	0x3FB44E3D80D2DADB, 0x3FB69F52A6C3DACF, 0x3FE238A375D6EC47, 0x400938DF863F848A,
+	0x3FE174EE9C7AE577, 0x3FF412D0EDF7F038, 0x3FF1307DEA73B111, 0x3FE758708FE4C589,
+	0x3FF1878212EE31BC, 0x3FDCF5D105FF1D2A, 0x3FF927C4BF6D8779, 0x3FCAD1B14E563A46,
+	0x3FECC66A748373AA, 0x3F992AB018E4B240, 0x3FE9C6EE465265B1, 0x3FF182FDA4A0CE81,
+	0x3FD4646744E831AD, 0x3FEDE5308C9A1B66, 0x3FAB6B16054CD709, 0x3FF3A4CE0C9BD134,
+	0x3FC7F81A7781389A, 0x4008CF61B8161988, 0x3FE0A853127777CE, 0x3FE948F4417F0521,
+	0x3FE92E499ABEC247, 0x3FF069DBB2F1217A, 0x3FDEE104F6FFB9C6, 0x3FC719309C55F228,
+	0x3FF4D40154D53C95, 0x3FDC17AC339738A6, 0x3FE28514E8654656, 0x3FA31C77EC013C61,
+	0x3FE6435FBAE09B23, 0x3FE1FE174840CD4B, 0x3FF26F6ABC81319D, 0x3FF2A85030F4D1EF,
+	0x4002A6352A7C8028, 0x3FE9BC7E17DB879D, 0x3FE2EF75504F04A1, 0x3FDB155107266923,
+	0x3FC7C283D4A73BD9, 0x3FD0DC8DABD88637, 0x3FEF3BF26B4047D3, 0x3FDEC174FBE88A84,
+	0x3FC3121A9FEA6608, 0x3FE2D416F439E4EE, 0x3FE2D6F66428AFFC, 0x3FDF0A5E90DA3EE5,
+	0x3FDE8C6465517A4B, 0x3FFDFCE47B75C9D8, 0x4002B5FB14BF8FBD, 0x3FEDA96A7CB8EAF2,
+	0x3FE7E7CC01DF93F0, 0x3FEF7EE2D093C0E7, 0x3FE47A117297C27D, 0x3FC57F06E8E4F291,
+	0x3FFA8E911198ED42, 0x3FC7DFA9F43437F4, 0x3FC2DEA329441DCB, 0x3F98E5C9EC142BFB,
+	0x3FE4E2C043C7CE52, 0x3FD0CA56781C127D, 0x3FA35CE1FB66F94A, 0x3FF9321D1C65EF42,
+	0x3FD836DFC654D9B6, 0x3F9B017102268819, 0x3FF2F28F144D84E8, 0x3FD70130DA472FF7,
+	0x3FE777A3635D45C9, 0x3FDDC6BF19166E1C, 0x3FDB59DC79D4C8BB, 0x3F9D2B46E5E81071,
+	0x3FBC413FF180A2FF, 0x3FEB36980BBA1DB1, 0x3FE58F14C8544FE5, 0x4001150B84780B10,
+	0x3FE7103CEEAD2BB3, 0x3FF85B3471D7FACE, 0x3FDC1A2E3E88B8C5, 0x3FF0BA442D64ACC3,
+	0x3FD13C9BC7109109, 0x400F198EEF6EBADC, 0x3FC1EF8DA1C61D35, 0x3FC7DBF2BBD1144D,
+];
+pub const XOROSHIRO128PLUS_SEED: u64 = 2829;
+pub const XOROSHIRO128PLUS: [u64; 2048] = [
+	0x5F66F419CF35AC33, 0xFFFFFFFFAA9334FF, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F557A24, 0x3FEA952B0B3F49D7, 0x3FE80B39B8E28E1D, 0x3FAF33F6E1EB3242,
+	0x2429AE546FE7732B, 0xFFFFFFFF8D9229A7, 0x000000000000559C, 0x0000000000000000,
+	0x000000003F650DD6, 0x3FBDEBB82F185B90, 0xBFDB4610DFC14840, 0x3FE09C012CA74B77,
+	0x63E2884C37598F40, 0x000000003B4994D3, 0x0000000000000001, 0x0000000000000001,
+	0x000000003F37E325, 0x3F9475E197337BC0, 0xBFE6B7407780DCE0, 0x3FE7A130B8E374EB,
+	0x70EE29C837130983, 0x000000006B9BFAAC, 0x0000000000039C40, 0x0000000000000001,
+	0x000000003E1671BC, 0x3FD90E55CFB5B248, 0xBFDE8E6935B38F84, 0x3FF6A53AABDC9712,
+	0xDD13D94EACB17F75, 0xFFFFFFFFFB43EE3A, 0x0000000000000006, 0x0000000000000001,
+	0x000000003DE2A490, 0x3FDC4E5DA3D9B8EA, 0x3FF0A1D1F25771F1, 0x400BBF9DE96565AE,
+	0x51753F3F0E04C27D, 0xFFFFFFFFB44AEEFE, 0x0000000000031750, 0x0000000000000000,
+	0x000000003EA83800, 0x3FD61D39779A9814, 0xC0001F39FB998709, 0x3FD1367597ECF7DA,
+	0x27D73A27F24D7EAE, 0x0000000025DCD129, 0x0000000000000019, 0x0000000000000000,
+	0x000000003F71DA9C, 0x3FE2784CD793213F, 0x3FDA8E54ADFA06D5, 0x3FD7D24F8D0FD02D,
+	0x00C7FEF0F68A2187, 0x00000000158E9A6F, 0x00000000000A4373, 0x0000000000000000,
+	0x000000003EA2E9C2, 0x3FD7FB96A3E11D5E, 0xBFD519819E5FA259, 0x4002EA63F16CA42C,
+	0xABD4198A991232F1, 0x0000000034833E84, 0x00000000000000F0, 0x0000000000000000,
+	0x000000003F26FAE8, 0x3FE79729B2240D63, 0x3FF60A84D7540F30, 0x3FC99DD1FA533E21,
+	0xF9CA6FAB6B98D8F8, 0x000000007EA1B434, 0x0000000000047375, 0x0000000000000001,
+	0x000000003F146FEB, 0x3FA67FC4C4263380, 0x3FDB2E52C4AF0E05, 0x3FD01B0856FDE904,
+	0x2DC751F4EDF34360, 0xFFFFFFFFCFF3373C, 0x000000000000024F, 0x0000000000000000,
+	0x000000003EB58B84, 0x3FEEA8344F590513, 0x3FF03AECA6A5159E, 0x3FD62E39D887BDC2,
+	0x2375BE4E737260B7, 0x0000000069405CFB, 0x0000000000032B23, 0x0000000000000000,
+	0x000000003F6326C4, 0x3FEEA6FA3D94F08B, 0xC0012459A60033DB, 0x3FEFD82ED2AC33A4,
+	0x593E8438F2E5E752, 0x000000006824BE84, 0x00000000000001C3, 0x0000000000000000,
+	0x000000003ECCDBF2, 0x3FE2C00046547569, 0x3FE823B54ED4AFE6, 0x3FD9F42D85CC7E9F,
+	0xF1E126D44B77465F, 0x000000007B677F98, 0x00000000000DD46E, 0x0000000000000000,
+	0x000000003F2E53EB, 0x3FD35F3D32AD727A, 0x3FF432C0047286C0, 0x3FAE3648BB9F1695,
+	0xA773B67FC8974B3B, 0x000000007F9F43DE, 0x0000000000000849, 0x0000000000000000,
+	0x000000003F1339DE, 0x3FBE7F4325B041B0, 0xBFBF0D96BB6C07E6, 0x3FF7FC90C32BEF67,
+	0x3129EA63202CF445, 0xFFFFFFFF83ABF6D8, 0x0000000000031DCC, 0x0000000000000001,
+	0x000000003F3C9BBE, 0x3FE1F68F677F41DF, 0x3FD36FF8788B94D6, 0x3FEEF4905FB9DE73,
+	0x19B78B0D92C321A1, 0x000000002B3AB675, 0x000000000000DB79, 0x0000000000000000,
+	0x000000003F403427, 0x3FA59211BE605DB0, 0xBFE305A0E9EA36D9, 0x3FF114F4A8861AE9,
+	0x2B8E0283166C5241, 0x000000001261B653, 0x00000000000A5ACC, 0x0000000000000001,
+	0x000000003EB6E444, 0x3FCAAE6CE649D608, 0x3FD83120E39D6A54, 0x3FE8DD1F4ACD7D01,
+	0x2B2B9EC125C15864, 0x0000000021793621, 0x000000000003599F, 0x0000000000000001,
+	0x000000003DA74768, 0x3FEA7785A358BE85, 0x3FF5C3A0C90831A7, 0x3FE806A151095A74,
+	0x3655134F13B7A7EA, 0xFFFFFFFFF64966D1, 0x00000000001876BA, 0x0000000000000001,
+	0x000000003F6C8487, 0x3FD218CCE57F3936, 0x3FC43BB3A875B3C3, 0x3FF40B6B37CC0969,
+	0x7C13520B9EE4C77D, 0x00000000552F5797, 0x00000000000CF5CC, 0x0000000000000000,
+	0x000000003D5D1DC0, 0x3FB4C1BE3D0D1AF0, 0xBFBC9964F9ED2413, 0x3FD36FE0D6D7C49C,
+	0x11061F6150E474F3, 0x0000000028082DAA, 0x000000000001F837, 0x0000000000000001,
+	0x000000003F28C762, 0x3FDC2E2BCE55357C, 0x3FC00CAE15F0D213, 0x3FA5835B92ED151D,
+	0xA84BBBD22619284D, 0xFFFFFFFFA48BB78C, 0x000000000002E6D0, 0x0000000000000001,
+	0x000000003E5967A0, 0x3FCEE01BD854D7CC, 0xBFEF6EB5B47D63DD, 0x3FEF18F15AC4100B,
+	0xA2F26E02873C71EC, 0x00000000606A11D4, 0x0000000000198223, 0x0000000000000001,
+	0x000000003F63F11B, 0x3FE3946505ED678A, 0xBFF377FA80E1AC5C, 0x3FF6B8B231859C11,
+	0x09AB0F75E7614A05, 0x0000000042532518, 0x0000000000CF1ED9, 0x0000000000000000,
+	0x000000003EC016F6, 0x3FE16C6E95E73AEC, 0xBFDD273AD49F8B5B, 0x40095AF942DEF4FD,
+	0xEC355E262822CC91, 0xFFFFFFFF84D6A933, 0x00000000001F4985, 0x0000000000000000,
+	0x000000003E100AFC, 0x3FEB62E21C3E4BD7, 0x3FC548FE7D51DA26, 0x4000ECD4682A01C6,
+	0x3EE0BFC3004B952C, 0x000000003632FB14, 0x000000000265203C, 0x0000000000000000,
+	0x000000003E431818, 0x3FD05F0925DDABC2, 0xBFF1076F8D09DF9A, 0x3F8FA1242CA9FC7B,
+	0xA5BD8FA6A1EF9ECD, 0x00000000652E0519, 0x00000000002233C0, 0x0000000000000000,
+	0x000000003E76E180, 0x3FD5618227C95C08, 0x3FF3C998A631A69F, 0x3F954F05C4BAE2BE,
+	0x8CAAAFB0F9A9A8EE, 0xFFFFFFFFECF874AF, 0x000000000A05270A, 0x0000000000000001,
+	0x000000003F286578, 0x3FE19EB032B1FEE8, 0x3FE6A81ABB49406E, 0x3F9FC00D2A21FB18,
+	0xD07AB48E39A301D4, 0xFFFFFFFFD770F580, 0x00000000001854E4, 0x0000000000000001,
+	0x000000003ED0CD38, 0x3FE37BD87DD6A545, 0xBFD093C54661D963, 0x3FD3C6E72476469A,
+	0x96083B0F15DB01EE, 0xFFFFFFFFE82104D7, 0x000000002870121F, 0x0000000000000000,
+	0x000000003F66D736, 0x3FE26F86C036F26D, 0xBFF951DCA668B617, 0x40013A7DDC142F81,
+	0x0BB19EFDFE3A2C77, 0xFFFFFFFFC6168559, 0x000000000026868D, 0x0000000000000000,
+	0x000000003F604D3C, 0x3FD26839353FDD9A, 0x3FD405D43D4914B8, 0x3FC4153389CB8AE8,
+	0x29A418C9AACBEE4B, 0x000000005F3024EC, 0x0000000000000000, 0x0000000000000000,
+	0x000000003ED9477A, 0x3FCCADC30858ACE4, 0xBFEA97B4A2D683F3, 0x3FE66F5DB59F3961,
+	0xFB72CD732CC38703, 0xFFFFFFFFE2001359, 0x000000000033BBC9, 0x0000000000000000,
+	0x000000003EA61C9C, 0x3FDECC437EC178A0, 0x3FE7773E2128B42B, 0x3FE4E647BE62A6AB,
+	0x7EAE83B81446C656, 0xFFFFFFFFD1A4D718, 0x0000000000000005, 0x0000000000000001,
+	0x000000003DB864B0, 0x3FC926EC19B2F444, 0x3FF2641EEF3CBF52, 0x40035AB018EBC40D,
+	0xA042C65B3581CE30, 0xFFFFFFFFFAD3F051, 0x000000000028145F, 0x0000000000000000,
+	0x000000003F468D0B, 0x3FE2E9D9C99F682C, 0x3FB034BBDA90114B, 0x3F9F62AC1395571D,
+	0x668606BC30C91FBA, 0xFFFFFFFF95B18BE3, 0x0000000000000013, 0x0000000000000000,
+	0x000000003D05CF70, 0x3FEDA39F5332F3B1, 0xBFFEBBF9931D96BC, 0x3FDEDB17A7A6D09E,
+	0x2A2AAEE674F9B77B, 0x00000000203BC266, 0x000000000003A48B, 0x0000000000000001,
+	0x000000003F345044, 0x3FC7BCCD7BB98618, 0xBFDF7859FD4C2DCC, 0x3FD1A39C7F055A70,
+	0xE941DE72A73E1913, 0xFFFFFFFFD32E6F7B, 0x0000000000000047, 0x0000000000000000,
+	0x000000003E228200, 0x3FD84634183C23E6, 0xBFF70CB53ADC8145, 0x3FBE0E977CC2F947,
+	0x79086CF903E710C7, 0xFFFFFFFFF7D46525, 0x00000000001F91DA, 0x0000000000000000,
+	0x000000003F046AC6, 0x3FE17BD878F4FC2C, 0xBFD68E7487E41123, 0x3FE29AE80721E34A,
+	0x325057E754C707B2, 0xFFFFFFFFEF69F546, 0x0000000000000130, 0x0000000000000001,
+	0x000000003C0B7280, 0x3FCE4E6A79433304, 0xBFF124899F65B238, 0x3F60F7C98F347001,
+	0x2C1CC58695B71A03, 0x000000003D75AFAF, 0x0000000000305767, 0x0000000000000000,
+	0x000000003F344A40, 0x3FDCACF12CFDB7F4, 0xBFDB595F994A822F, 0x3FFB47D9764CE245,
+	0x1B2BA48412B26437, 0xFFFFFFFF9262F0D6, 0x0000000000000072, 0x0000000000000001,
+	0x000000003F5D49BC, 0x3FEC22FF6C409FB7, 0x3FF3E0F4D50373D5, 0x400BD92D6F89BB50,
+	0xF7A1A54807D7DEA5, 0x000000004E3E68C4, 0x0000000000005A68, 0x0000000000000001,
+	0x000000003EE7D2B6, 0x3FB23942A6393DC8, 0x40016DD25CE143F5, 0x3FF76AAA0466647C,
+	0x6ABD66378FD1B42B, 0x000000000FF7B1B8, 0x0000000000000C58, 0x0000000000000001,
+	0x000000003E315984, 0x3FE1D3D8D363B076, 0xBFE07E8702104832, 0x3FE0392C39A74F49,
+	0xD93CD79D3D40527F, 0x0000000010DD193D, 0x0000000000140BD2, 0x0000000000000001,
+	0x000000003C601900, 0x3FBB5E54EC494AA8, 0x3FD9BE3B1D83AEF0, 0x3FE7D9F7AE935F0B,
+	0x391BAB93DC7D5C39, 0xFFFFFFFFD4857098, 0x00000000000072BC, 0x0000000000000001,
+	0x000000003F6C3E02, 0x3FCB812AB5D8FEB0, 0xBFD35E5BE2E2FF68, 0x3FE3F2492A4975DC,
+	0x7AD95282FF83AD54, 0xFFFFFFFF8CC8EFDE, 0x00000000003BF165, 0x0000000000000001,
+	0x000000003F25FEEB, 0x3FEB8A2F553A0444, 0x3FDC8C5269F7B801, 0x3FE2A14BCC73A75E,
+	0x666B711F6955DBC8, 0xFFFFFFFFA165C5AE, 0x0000000000016F31, 0x0000000000000001,
+	0x000000003F3F3A34, 0x3FDAF677811C72E2, 0x3FFEE204505EED0F, 0x3FE7ED0EB4E12CB4,
+	0x2C04E5F284711B61, 0x0000000014BCF97E, 0x0000000000478EBB, 0x0000000000000001,
+	0x000000003F076F00, 0x3FD05A23BED045FA, 0xBFC3933402BC7F2E, 0x3FE13AED69A06983,
+	0x4D9FD4D3BBEE95B4, 0xFFFFFFFFA9DB3A95, 0x000000000001671B, 0x0000000000000001,
+	0x000000003F365148, 0x3FDDBD45F72EB09A, 0xBFF5D88BF3262552, 0x3FE5EADD15B00B3B,
+	0xDDFE18E4168A9AE3, 0xFFFFFFFF9AEEF85B, 0x00000000004DC9E0, 0x0000000000000000,
+	0x000000003F050A67, 0x3FD516208E52E93A, 0xBFCBD8AA2462801B, 0x3FD7C3B495EC8B2B,
+	0xC0391604FEFB1A52, 0xFFFFFFFFAD7A3906, 0x00000000000D4CA3, 0x0000000000000001,
+	0x000000003F0724D1, 0x3FE4A0EF286A0798, 0x3FE2E1616BA53AE9, 0x3FF1711828D352C3,
+	0xFFE6B440C7208C81, 0xFFFFFFFFC5CFD405, 0x0000000000162038, 0x0000000000000001,
+	0x000000003E89B166, 0x3FD0896A7DBE04CE, 0xBFE7E1581AEB9AE4, 0x3FEC02BBDDF85CAF,
+	0x07490F8A63D4BCE1, 0xFFFFFFFFB084E8A6, 0x00000000005204E1, 0x0000000000000001,
+	0x000000003E860928, 0x3FCB14C0B3760434, 0xBFE0C5D0EE8708F6, 0x3FF10B72FC55D5CC,
+	0x5B4AAD769920C2DE, 0xFFFFFFFFEBDDDC4A, 0x0000000000329B9C, 0x0000000000000001,
+	0x000000003F018D04, 0x3FE700588CCC2717, 0x3FDB715D032A4D95, 0x3FEAC8CF650460E9,
+	0x3394BE3C34A0E7A1, 0x000000001B689033, 0x000000000027293C, 0x0000000000000001,
+	0x000000003D5F6AE0, 0x3FB7315656FEAE10, 0x3FEBC4FB87455838, 0x3FC3E0DF1457622C,
+	0x88BC0B8C9A2CF93D, 0x000000003724F035, 0x0000000000296DEE, 0x0000000000000001,
+	0x000000003EC3004A, 0x3FE3BD72ED0F50EF, 0x3FF752C297653E20, 0x3FFCD92B84DDE3A5,
+	0xD29193DEE200DDE8, 0x000000005FD9989F, 0x00000000018DD171, 0x0000000000000000,
+	0x000000003D7AFF40, 0x3FBC8C07F9294E00, 0xBFE394B69B01C9A3, 0x3FE1268A1007B776,
+	0x379D6D9250E1B87A, 0xFFFFFFFFB6907C76, 0x00000000002BFC4F, 0x0000000000000000,
+	0x000000003F05FFB5, 0x3FE038211300F587, 0x3FF6DBD9E25F5FD6, 0x3FE2768737B24323,
+	0x900D5F86587A69ED, 0x00000000526F51B8, 0x0000000008E18044, 0x0000000000000000,
+	0x000000003E48B830, 0x3FE60120F203A5DC, 0xBFEB5737D5485DDC, 0x3FA3F5FF99C3722B,
+	0x41B484B6F6A23ABE, 0xFFFFFFFF87D81B0D, 0x0000000000241AD5, 0x0000000000000001,
+	0x000000003F651C4A, 0x3FE7556CE027621A, 0xBFF12D909FBF4CB3, 0x3FD6CC7708F8B75C,
+	0x4992A64075D43AF1, 0x000000001F37CC0D, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F6972A3, 0x3FDC03D3A01D7066, 0xBFF9EC82C23F2AD6, 0x3FC912D9F626DA7C,
+	0xB9D5CB0AD7059F5F, 0xFFFFFFFFFD852416, 0x000000000054A1A4, 0x0000000000000000,
+	0x000000003F01013E, 0x3FD7AAEF01B693DC, 0xBFDD4B2F1404EF5F, 0x3FDB33CF289421C5,
+	0xC53645E66EF5F06B, 0x0000000076557774, 0x0000000000000003, 0x0000000000000001,
+	0x000000003EEC4EE4, 0x3FD3B0CABB5C554A, 0xBFFE3B6F8823B1C1, 0x3FC789607957B82B,
+	0xCAB67D6DF6DBD9B9, 0xFFFFFFFFE2F7B9D0, 0x00000000002EE441, 0x0000000000000000,
+	0x000000003F1836D4, 0x3FC5341AA87DA34C, 0x3FE19F38DB662783, 0x3FCC3F3A79CAC07C,
+	0x623D0266CCEE1DAF, 0xFFFFFFFFDFEA74F9, 0x0000000000000005, 0x0000000000000001,
+	0x000000003F7B65B9, 0x3FDA529C46A43C06, 0x3FF7B07E20E0E6B3, 0x3FD406EAD8B698AF,
+	0x34DB2814EB6EDDDB, 0x000000003BD1C454, 0x0000000000148CBD, 0x0000000000000001,
+	0x000000003ED485DE, 0x3FC087F646937E90, 0xBFC028E8A6C60D9D, 0x3FDF15A00767EB9A,
+	0xB7747AECC4F5F398, 0x000000003E151492, 0x000000000000003F, 0x0000000000000001,
+	0x000000003F21520F, 0x3FBA05BB1C8295A8, 0x3FFB7D217443C3AB, 0x3FE85A15C3004730,
+	0x36B9390C98E1D07A, 0x000000002845A3F9, 0x0000000000622B11, 0x0000000000000001,
+	0x000000003EA43280, 0x3FE69C226189E9E5, 0xBFF4D77BCAD5FFE4, 0x3FD4DD51612016C8,
+	0x797678E8497993A0, 0xFFFFFFFFCC33A5F2, 0x00000000000000A5, 0x0000000000000000,
+	0x000000003F629588, 0x3FDE26E57F7685D4, 0xBFB42FAFE5DDE85A, 0x3FCB509A6AD2CDA5,
+	0x70061D9B4974C795, 0xFFFFFFFFEB43480C, 0x00000000001FBAF9, 0x0000000000000001,
+	0x000000003E55F080, 0x3FE4DD5D27D43C34, 0x3FD9AD837933B100, 0x3FDAA5D33EFEAC54,
+	0xAFE4F16AE76215D0, 0xFFFFFFFF954C8C0D, 0x0000000000000247, 0x0000000000000001,
+	0x000000003F3AB51F, 0x3FD0A327BB22C382, 0x3FFFA6905DD75768, 0x3FE14C9BC5FFD7D6,
+	0xABEF9C5794283553, 0xFFFFFFFF82C4862B, 0x0000000000181F5D, 0x0000000000000000,
+	0x000000003F430714, 0x3F9536F9E32EB700, 0x3FBF1B0F38A9D64B, 0x3FE2CFB24F9DEEF7,
+	0x30416DEDD553477B, 0xFFFFFFFF97BC4B2E, 0x0000000000000558, 0x0000000000000001,
+	0x000000003F509619, 0x3FE46D0566755ADF, 0x3FE4E202C8B2AF92, 0x3FDF72C7834C897B,
+	0xD777AB159FEA11F1, 0xFFFFFFFF9E2CCD8E, 0x0000000000158E45, 0x0000000000000000,
+	0x000000003E0BBFE4, 0x3FD83A719657B406, 0x3FC91E3DCA696EBC, 0x3FC4ECB70CCCA2A6,
+	0x88EE540C43BDD7A2, 0xFFFFFFFFA4C9CC89, 0x000000000000385C, 0x0000000000000000,
+	0x000000003F6C09C9, 0x3FDE2CC9A576A488, 0x3FF4027E87C57783, 0x3FF6C4BFC1392A88,
+	0xA1F9EFF3469CF8D6, 0xFFFFFFFFC586CBFA, 0x000000000004BD20, 0x0000000000000000,
+	0x000000003F694931, 0x3FE8ABBD5DD12D3C, 0x3FE5C5140E7408DE, 0x3FDDB7CEA7E53C1A,
+	0xCCB6557367D87C22, 0x00000000382B4F0A, 0x000000000000576D, 0x0000000000000000,
+	0x000000003ECB4302, 0x3FECBE68CAF031D9, 0xBFD0E1AB2E298ED1, 0x3FF2FA9BD91CA5DE,
+	0xD59F857114EC0A29, 0xFFFFFFFFCDC20CC8, 0x0000000000270542, 0x0000000000000001,
+	0x000000003F0D65D9, 0x3FE0DB231BDF6E06, 0xBFF79D013E7172CE, 0x3FAA4489939FC448,
+	0xAFF2F5CF611E1E95, 0x00000000273717E5, 0x000000000003C8AA, 0x0000000000000000,
+	0x000000003ED19A30, 0x3FE7E01854ED671E, 0x3FE73AFE7B777761, 0x3FF0ABD8B7353962,
+	0x3EEB7F450DD80EA2, 0x000000003F4C0EB3, 0x00000000007822C8, 0x0000000000000000,
+	0x000000003E9271EC, 0x3FD73B12E471D860, 0x3FC18568E5B6F567, 0x3FABD8C87E18AAFB,
+	0x529F6385AD0B2117, 0xFFFFFFFFAF9CA821, 0x00000000000D8A49, 0x0000000000000000,
+	0x000000003F5B3421, 0x3FC6E045C0ED3DE0, 0xBFE6B5CACA436809, 0x3FF5DDB2B61BE80E,
+	0x9F5D7EF1F15DA693, 0xFFFFFFFFCC0C8B6A, 0x0000000000470FAA, 0x0000000000000001,
+	0x000000003E6D6290, 0x3FEE987FD64AA23C, 0x3FDDA2569E14B840, 0x3FEBF390E217317A,
+	0x3C2FFD36DAC4FFF2, 0xFFFFFFFFC0711715, 0x00000000001895ED, 0x0000000000000000,
+	0x000000003C566840, 0x3FB40C85354F9C58, 0xBFF1EEA04C8368BE, 0x3FEAD1D3BC1F4137,
+	0x3D61EC4BA3886D5E, 0x000000005F2B4DBC, 0x000000000069D57D, 0x0000000000000000,
+	0x000000003F72764F, 0x3FDEB4844BB046BA, 0xBF901E7192C6A867, 0x3FF959D4B959BB8A,
+	0x4EC43CF621CAEDED, 0x0000000079F5AF69, 0x00000000007439A1, 0x0000000000000001,
+	0x000000003CA63020, 0x3FED2F91874A4DD3, 0xC00DB4383F421BE1, 0x3FC293EA85A9C3C2,
+	0xB96A2CDE34A1D9B8, 0xFFFFFFFFD1D9553C, 0x000000000029C693, 0x0000000000000000,
+	0x000000003EB31DCC, 0x3FEE5EB90C3B1C67, 0x3FBEFE056EB9711E, 0x3FF4F8E154758885,
+	0x902DC1F18D5EB38E, 0xFFFFFFFFA91698BF, 0x00000000004A58A5, 0x0000000000000000,
+	0x000000003ED46D3A, 0x3FAD3E1F58B70650, 0x3FE9AA26892E3F39, 0x3FD5704BDF4363A0,
+	0xECF4578C52AC329B, 0x0000000060B25E25, 0x000000000039E3E9, 0x0000000000000001,
+	0x000000003EC03AE0, 0x3FE6549F0E153B2A, 0x3FE8C391185479AD, 0x3FF45B785B0F7AD4,
+	0xBAF9588BA6DB631A, 0x00000000655B3A93, 0x00000000072FE019, 0x0000000000000001,
+	0x000000003F008AF2, 0x3FDA54110A03C490, 0x3FC11894239C7DF2, 0x3FD1238E73D0275F,
+	0xCC51684ABBD0670A, 0xFFFFFFFFE435A289, 0x00000000007A2975, 0x0000000000000001,
+	0x000000003F1DBC1B, 0x3FCB69255474E160, 0xC0017E21A756FA7B, 0x3FAE04D91617EA24,
+	0x13C3DAB566036CEF, 0xFFFFFFFFA7F88115, 0x000000002D598F80, 0x0000000000000001,
+	0x000000003DD21178, 0x3FEBDA31A919ECC6, 0xBFFEED0A4597626D, 0x3FEF1E29D8D56756,
+	0xEBBA7ADE65DCBC4C, 0x00000000536ABC44, 0x000000000067F875, 0x0000000000000001,
+	0x000000003CF204C0, 0x3FC3AF541626CFDC, 0xBFC2D86B6F3C0607, 0x40181B35D104047E,
+	0x7E75863A5B05602D, 0x000000007FC99701, 0x0000000000000001, 0x0000000000000001,
+	0x000000003EEF6E48, 0x3FCABA9FA2F76008, 0x3FE94CC13D743858, 0x3FE07D66506DA2A0,
+	0x3C414A2105CA4AF8, 0xFFFFFFFFA33F312D, 0x00000000007C90E8, 0x0000000000000000,
+	0x000000003F79402A, 0x3FE31E24BAAF442A, 0xBFDF824462C58A4D, 0x3FE9EBEA17C995F6,
+	0x9271418728597BE3, 0xFFFFFFFFDA2DD97C, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F5E0987, 0x3FE3D73EAF8E0886, 0xBFE85BC26CE43313, 0x4003F941BBB1B745,
+	0x292B785204674FF0, 0x000000004214DBB9, 0x000000000041E849, 0x0000000000000000,
+	0x000000003EF50402, 0x3FDBAB42A6609130, 0x3FEA16AB56137244, 0x3FCA3FBD1D301E4E,
+	0x5F817E02E500FE1E, 0x000000001498B47C, 0x000000000000000B, 0x0000000000000001,
+	0x000000003C3D8340, 0x3FB0E7A8BF93F8B8, 0x3FDCF74A07199E01, 0x3FF09D229527899E,
+	0x87BE44E4725B3B5B, 0x000000004B474109, 0x000000000097FEBA, 0x0000000000000001,
+	0x000000003C9CFA20, 0x3FC3E2F15DE57408, 0x3FF63BD49D5F2849, 0x3FDA9DBB33ED48CF,
+	0x2782F66EDCA22F59, 0x0000000041FB67AA, 0x0000000000000047, 0x0000000000000001,
+	0x000000003E667620, 0x3FB3230DC053D1B8, 0xBFD927A0427323B2, 0x3FE845BE9EB2CC48,
+	0xAF03AC2380049342, 0x000000006B0D9CBA, 0x00000000009ACE8B, 0x0000000000000000,
+	0x000000003E9ECEE0, 0x3FDF9EEBDF2EA708, 0x3FF0C89F5D97D144, 0x3FC18F2236072286,
+	0x4F6E4A032D9ED740, 0xFFFFFFFFDA246E88, 0x00000000000000E4, 0x0000000000000001,
+	0x000000003D251D80, 0x3FD1F0CCC1A50A72, 0xBFC066E9F8BCDDF7, 0x3FBC768101A3FFDB,
+	0xCC71FB3A014054F7, 0x000000002DB6746E, 0x00000000005947B8, 0x0000000000000000,
+	0x000000003E4E23A8, 0x3FE8FD287A46DC1C, 0x3FE8358E644AFD9E, 0x3FF9A58F47B96189,
+	0x8D8E0A91B44F0A60, 0xFFFFFFFF8E49A182, 0x0000000000000599, 0x0000000000000001,
+	0x000000003D98C5B0, 0x3FEDB7CBCB69AB7E, 0x3FEAAE02CCF9C1BD, 0x3FF3552DA268B27C,
+	0x9CFEE7D8DA15E3E0, 0xFFFFFFFFB69EEB93, 0x00000000008B2627, 0x0000000000000001,
+	0x000000003F7756B6, 0x3FDB2825C067DAE0, 0xBFE212FEEA7A1238, 0x3FF333940AB972A5,
+	0xABBA4D10306F1ADA, 0x0000000071755453, 0x0000000000001FDA, 0x0000000000000000,
+	0x000000003DB68CB8, 0x3FD1CDF38A5660B4, 0xBFB5506543552C54, 0x3FE827C01D94E20E,
+	0x43B6CEED71D84FB2, 0x0000000025432699, 0x00000000000D8892, 0x0000000000000000,
+	0x000000003F76EF29, 0x3FDC0AB51E78B9F0, 0xBFEEF48CF0883F08, 0x3FF4962976810B5B,
+	0x74647C95937B2059, 0x0000000069A45A66, 0x00000000000030A0, 0x0000000000000001,
+	0x000000003DF24578, 0x3FE85C185E6A1E72, 0x3FD8B5170C6B312B, 0x3FF555FEA38E1C4F,
+	0xB96787A89CD827D5, 0xFFFFFFFFF79EC455, 0x0000000000037EA1, 0x0000000000000001,
+	0x000000003F6DCEC7, 0x3FEDA47233D0D04F, 0xBFF3BF5D83AC00D9, 0x3FD93E864EC8B513,
+	0xF95169198B74E22D, 0x0000000030D6C6CF, 0x0000000000017EEC, 0x0000000000000000,
+	0x000000003F229786, 0x3FE95BE8C9EC0D81, 0x3FBEF8444A59AE74, 0x3FF48BE250DF459E,
+	0x7CDB8E26DCFE13DF, 0x0000000049B112EA, 0x000000000007D701, 0x0000000000000000,
+	0x000000003F67CD93, 0x3FE9D26D75CF493A, 0x3FB4A913A5A63B94, 0x3FF9797B9E4B98D3,
+	0x877016B21041BE9F, 0x000000003D955861, 0x00000000000401D6, 0x0000000000000001,
+	0x000000003F61BF07, 0x3FE970FB9006B561, 0xBFCE853AF3F8E219, 0x3FD1993CB21F44DC,
+	0x1A446813E63D0BD9, 0xFFFFFFFFFBEAC289, 0x000000000041B77F, 0x0000000000000000,
+	0x000000003F016FCB, 0x3FED16F6A5102042, 0xBF9BD2FBF3F20C25, 0x3FEFDE54B181B4F2,
+	0xEED2F867C14D44C6, 0x00000000397D748F, 0x00000000000596E6, 0x0000000000000001,
+	0x000000003F4659F4, 0x3FEE8E6FBBAE3B96, 0xBFE7C4036E2DCF67, 0x40117EC72C5B3947,
+	0x098B980E76D20CD9, 0x000000003847B150, 0x0000000000A539AB, 0x0000000000000000,
+	0x000000003F3AB43D, 0x3FE277419614FBAE, 0x3FAD79928CB84864, 0x3FB4740BAAE6D54E,
+	0x809FE61885C68D91, 0x000000007447FCDB, 0x00000000001E3183, 0x0000000000000000,
+	0x000000003F6ECDDE, 0x3FEAFB1D503A639B, 0x3FDE45AA3CB7AF38, 0x3FF332DBA17D5292,
+	0xAB72885F9EF594DA, 0x000000007C699F87, 0x00000000005DD221, 0x0000000000000000,
+	0x000000003EB9F9E8, 0x3FE6F3AB26A34E30, 0xBFE0C7DB28EC29DD, 0x3FC45B2AC56E6D9C,
+	0x823C7A2A603D4C82, 0xFFFFFFFFAD6AC1FA, 0x00000000011A09D3, 0x0000000000000000,
+	0x000000003F2D6648, 0x3FDA8B93294E4FFC, 0xBFF3D923F67FA410, 0x40035DB5C9813B8C,
+	0x8D0717042485B87F, 0xFFFFFFFFA209F099, 0x0000000000B90F46, 0x0000000000000000,
+	0x000000003ED7AD18, 0x3F936A1F9C0DDD20, 0x3FE7092942C93B85, 0x3FD72DB30D2EB039,
+	0xD2E51BEA250FFC9D, 0xFFFFFFFF971F984E, 0x0000000005B2CE78, 0x0000000000000000,
+	0x000000003F712184, 0x3FE5C0BAD1FD383A, 0x3FD40505871285CA, 0x4003CDC3801F2661,
+	0x7A1C38A3B23A1242, 0xFFFFFFFF8F26B155, 0x0000000000A4B868, 0x0000000000000000,
+	0x000000003DD17560, 0x3FDDD3CC23042D68, 0x3FF361F070C5FB9D, 0x3FE91AF618399139,
+	0x279E085BBF0F19E4, 0x00000000563D73E5, 0x00000000016D27AF, 0x0000000000000001,
+	0x000000003F1C7DB1, 0x3FE7A4FA4582D642, 0xBFBF720B295D1415, 0x3FFB0AE120086AC8,
+	0xAF8A22D40CDD87FE, 0x00000000544A3F23, 0x000000000070C4CB, 0x0000000000000000,
+	0x000000003F4C19DC, 0x3FE8B62E5015D54C, 0x3FE5D25873909ECA, 0x4011F990423E6897,
+	0xD0C93DF757880279, 0xFFFFFFFF89D5DD51, 0x0000000000000000, 0x0000000000000000,
+	0x000000003DEAC9D8, 0x3FD77D5BD4EBE1CE, 0xBFC7D08118E3560A, 0x3FFB5A62A15D1625,
+	0x45A22C48810B4A0B, 0xFFFFFFFFBC245C3E, 0x00000000003BDC51, 0x0000000000000001,
+	0x000000003F692BC7, 0x3FECE819043C6D94, 0xC000AE86C543F999, 0x3FC7DF5885DA9ED3,
+	0xFC86DB8E60570B8A, 0x000000006C9215F9, 0x0000000000000000, 0x0000000000000001,
+	0x000000003E3FB978, 0x3FE1C2A9AA67C4D7, 0x3FFFBEFE98C94A17, 0x3FD636702641DAF3,
+	0x681D4E9641322567, 0xFFFFFFFFECA0B09C, 0x00000000008BDDDA, 0x0000000000000001,
+	0x000000003E29EEB0, 0x3FC10BFFD1832F34, 0xBFE382B45A43AD05, 0x3FE543610F400016,
+	0x5BEE9F9D8F8A8288, 0x0000000071EE870A, 0x000000000000000D, 0x0000000000000000,
+	0x000000003EAA4B6C, 0x3FEBDBF4055B88B8, 0x3FF22ADE3CD46DE9, 0x3FC332E6AA2587BE,
+	0xFFE3FD0A30003D61, 0xFFFFFFFFD37F8184, 0x000000000097DC0B, 0x0000000000000000,
+	0x000000003F79551E, 0x3FEAD8749C92A096, 0xBFFC4EA6392E5787, 0x3FD4B74186C70CA2,
+	0x079F82A711498382, 0x0000000057B73B67, 0x0000000000000037, 0x0000000000000001,
+	0x000000003EA7F350, 0x3FE065E5F199E400, 0xBFD45168728B4525, 0x4004ACC7431F9D01,
+	0xB61D216D95DC107E, 0x000000000CD2088C, 0x00000000004E69A5, 0x0000000000000000,
+	0x000000003F46F737, 0x3FD7FE8DE6B736E8, 0xBFF2B12D36CF110C, 0x3FD14C19FF98A054,
+	0xCD98A8523FF99F6B, 0x000000006726152C, 0x0000000000000021, 0x0000000000000001,
+	0x000000003DAA7620, 0x3FC97CD2A4EAAEE8, 0x4002955FB1D4F6DA, 0x3FAAE497170DDF63,
+	0x925834EC73C8992C, 0xFFFFFFFFB8CB55B2, 0x0000000000C9E291, 0x0000000000000000,
+	0x000000003E388278, 0x3FEF9FB7850B1780, 0xBFE30675C5A49E0D, 0x3FFFAE4FC81311E0,
+	0x346A0DB764508800, 0xFFFFFFFFDD3D0144, 0x00000000000003F4, 0x0000000000000000,
+	0x000000003EE60E2E, 0x3FEDA8AC472DAC8C, 0x3FA7623042FD4FF5, 0x3FA8ED1537BED655,
+	0x5D5D75B5329DE33F, 0x000000003A024737, 0x00000000002ACD6D, 0x0000000000000001,
+	0x000000003ED6E6C8, 0x3FC7206D095511D8, 0xBFDF0D3C8A4F70DD, 0x400B68A6E95107B8,
+	0x34D0CCF69E9BDECE, 0xFFFFFFFF80289389, 0x0000000000000273, 0x0000000000000001,
+	0x000000003DFAF1B8, 0x3FDFFE5F6099B128, 0xBFB7C9E11F463A66, 0x40034B9220A3ACB8,
+	0x69CDF887D1135C50, 0xFFFFFFFFEA12954C, 0x000000000015E10E, 0x0000000000000000,
+	0x000000003F287904, 0x3FE4ED0321E01128, 0xBFE7A9EEDE1A243D, 0x3FD5B314C821FA07,
+	0xCBDB2ABD751329EE, 0x000000001E7D23FE, 0x0000000000002E2F, 0x0000000000000001,
+	0x000000003F1A805A, 0x3FE63AFB76DE0748, 0xC0000148E0D55FB3, 0x3FA840A87BBA9E21,
+	0xBECFE76FC4C4510E, 0x0000000004E72828, 0x0000000000D1123A, 0x0000000000000001,
+	0x000000003ED7E3D6, 0x3FEF43758A696E7A, 0xBFF1267B8D23B67D, 0x3FD4168B8A89823B,
+	0x8F9CD54AB2C45021, 0xFFFFFFFFF012F059, 0x0000000000005172, 0x0000000000000000,
+	0x000000003F30CA0C, 0x3FE9C21F93E53497, 0xBFE8DB15D99C53EE, 0x3FEBB0CF3971BB27,
+	0x26ADAEC5E1D96E7B, 0xFFFFFFFFA739086D, 0x0000000000C456E0, 0x0000000000000001,
+	0x000000003E656D3C, 0x3FE8FC86881B0FA4, 0xBFEDD73300D2CD9C, 0x3FE2F14A76CFFD73,
+	0xE1905B0E32D2BEB2, 0xFFFFFFFF9E901CA9, 0x000000000003C8E0, 0x0000000000000000,
+	0x000000003F564D8E, 0x3FDA60954D05A91E, 0xBFEC84BEC36D3EBE, 0x3FEA7F43C812B833,
+	0x9BCFF70E9E37B416, 0x0000000027FC3ED5, 0x00000000009E458E, 0x0000000000000001,
+	0x000000003EFAC82A, 0x3FD318F555684F78, 0x3FD382249ABDE5DC, 0x3FF851D30EB094CF,
+	0x1CECFF1413315540, 0xFFFFFFFF93458355, 0x000000000007A600, 0x0000000000000001,
+	0x000000003F5BA80D, 0x3FEA5D2442269B74, 0xBFC46F337C4F6A8A, 0x40020026BF63691E,
+	0x117FDD3A4F584FA0, 0x000000000A0A23D5, 0x00000000001A8678, 0x0000000000000001,
+	0x000000003F285B43, 0x3FA35ABD90AB1960, 0xBFF49C0E7C6C0884, 0x3FE692077B1EE5F0,
+	0x75893E8BC1EE7605, 0xFFFFFFFFFD092AAE, 0x0000000000354DE9, 0x0000000000000000,
+	0x000000003DC4B788, 0x3FE1D9F3E15EAEF6, 0xBFC63B925E430D27, 0x3FEA8D8142F9A8E9,
+	0x74257FF10D147943, 0x0000000073A2F22C, 0x00000000001E5FB8, 0x0000000000000000,
+	0x000000003F5F3EDA, 0x3FDCA988B8E18B6A, 0xBFD061075E80E31C, 0x3FF23B519C4EB1E9,
+	0x57446C331B2036E7, 0xFFFFFFFFA48A0E1A, 0x00000000008EF0BC, 0x0000000000000000,
+	0x000000003F536B3E, 0x3FEEB096797471AF, 0x3FE3C709A94D6FA9, 0x3FCED6BBD8922233,
+	0x3BBC8F1EA2902ECC, 0xFFFFFFFF973102D1, 0x0000000000733789, 0x0000000000000000,
+	0x000000003F0EB983, 0x3FE98A9E98D7E5EB, 0xBFE8F05A98CCC89C, 0x3FDE5EE60600732B,
+	0x6AE57CE93DB95515, 0x000000006D469CAF, 0x00000000038369FF, 0x0000000000000000,
+	0x000000003E13CBE0, 0x3FE395C9FEEFA13A, 0xBFCACD8A2EF9783A, 0x3FF20CE075474380,
+	0x47D930416477EEB3, 0xFFFFFFFFE66B3DB4, 0x0000000000CD641A, 0x0000000000000000,
+	0x000000003D954058, 0x3FCADFC1BFDB10CC, 0x3FD62441FDCAD82B, 0x3FAA8BB652B90985,
+	0x7744F41BB486E528, 0x000000005580BECB, 0x0000000006708F88, 0x0000000000000001,
+	0x000000003F465FC8, 0x3FD9966B668D6B84, 0x3FE4205419623C81, 0x400131EDA4F949EA,
+	0x73C44025BDE58804, 0xFFFFFFFFB187FD7D, 0x0000000000EA3197, 0x0000000000000000,
+	0x000000003E7A7F6C, 0x3FC3CEA7961DB168, 0xBFD52B3CF18C7FE4, 0x3FF5798999B5D1E0,
+	0x563D294F65CFEBC7, 0x0000000021665698, 0x0000000027D6D589, 0x0000000000000001,
+	0x000000003F3FD960, 0x3FE6B7822C658499, 0xBFE8D0CFCA55C004, 0x3F778E91558BD3B1,
+	0xF874F0960A1BCC4A, 0xFFFFFFFF9D64BA6A, 0x0000000000B60484, 0x0000000000000001,
+	0x000000003F22B0AF, 0x3FD6A6C490E4C6F6, 0x3FE2F5EDFBBD630B, 0x3FD5782A25E6049E,
+	0x32D8BC0B51B634D3, 0xFFFFFFFFC22B06D8, 0x0000000000000001, 0x0000000000000000,
+	0x000000003E80BCBE, 0x3FEF36D09C0F30AF, 0xC006FFE1C97A1628, 0x3FE9B788D5CD6652,
+	0xD17618A12FDC0CED, 0x000000000233CA02, 0x00000000002D8BA3, 0x0000000000000001,
+	0x000000003D7B9420, 0x3FE141FA3244A38F, 0xBFD1460F8A320573, 0x3FB5008442872CFD,
+	0x8F1A512983DD5650, 0x00000000457FFB71, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F71A7A0, 0x3FDEF35072177B80, 0xBFF20B9CA8EE5B04, 0x3FAEDF1409D21273,
+	0x19ADD788D7D5C2FC, 0xFFFFFFFFC0D86C08, 0x000000000071BEB7, 0x0000000000000000,
+	0x000000003CA23D00, 0x3FE1B692139B0050, 0x3FF74D9E00F6952B, 0x3FC1AF479467F96E,
+	0x8F0003F429A1126B, 0xFFFFFFFFE2D86D42, 0x000000000000001D, 0x0000000000000001,
+	0x000000003DC074E8, 0x3FBEF96100DFB468, 0x3FF99DB8584ACF0B, 0x4003D6D67A4AF254,
+	0x871BE9BF21370C88, 0x0000000078DD21F2, 0x000000000064A865, 0x0000000000000000,
+	0x000000003EC8FE4C, 0x3FE3604201F4A08D, 0x3FD2674A1BCD9E9A, 0x3FD444CAB3DE9405,
+	0xB0DD6CFD5C9600A6, 0xFFFFFFFF9D586B6F, 0x000000000000006A, 0x0000000000000001,
+	0x000000003F4FEA7C, 0x3FE23A5381D90F6B, 0xBFEB3B8050B3392D, 0x3FA03BD9CE987EE5,
+	0xA8F6B857F9BFFF6C, 0xFFFFFFFFF257DC85, 0x0000000000D7A437, 0x0000000000000001,
+	0x000000003F3C9FD1, 0x3FEE7741D4660ECC, 0xBFF1937808DA8CA3, 0x3FFCDE0F76D069BE,
+	0xB95B233A943884EB, 0x0000000009B75115, 0x000000000000004F, 0x0000000000000000,
+	0x000000003E2DA550, 0x3FE04646C4197347, 0xBFE051A6EDAB4D73, 0x3FE790B4A8F8E7C8,
+	0xC67B32ED0E15AF82, 0xFFFFFFFFA9E9ADF1, 0x00000000007AFC68, 0x0000000000000000,
+	0x000000003DA8EF00, 0x3FE0663AB52AFA2E, 0xBFF6B31D14F25C6D, 0x3FE16C542CA1729E,
+	0x8AAEF5264D81094C, 0xFFFFFFFFC63A9B93, 0x00000000000007EA, 0x0000000000000000,
+	0x000000003CBB5820, 0x3FDBAEE11F19689E, 0x3FE957711B593B5A, 0x3FF008935B0630DA,
+	0x8942FB685FAD46EC, 0xFFFFFFFFB4EC87BA, 0x000000000023E0D3, 0x0000000000000001,
+	0x000000003F0B71CA, 0x3FC1485E33F49B50, 0xBFF2965BE0F44A0D, 0x3FE78585558F2F5C,
+	0x613121A0063C5313, 0x000000000ADEE4AB, 0x0000000000001D1E, 0x0000000000000000,
+	0x000000003E799318, 0x3FEECAAFCE9F6385, 0xBFD64770AEB778A2, 0x400A4A70C17FAF44,
+	0xD0EF9078736AA7B1, 0xFFFFFFFFD1149E6B, 0x0000000000A3474A, 0x0000000000000001,
+	0x000000003E118408, 0x3FE03BB8C1A78C23, 0xBFDB3973998B2F4B, 0x3F949E82C0DFF961,
+	0xFF69EC232D7AB6AC, 0xFFFFFFFFF7D7F38B, 0x0000000000006324, 0x0000000000000001,
+	0x000000003D2157B0, 0x3FDD516DCAE00846, 0xBFFA25604B8F3DAB, 0x3FF930661BBECF27,
+	0x301C239BB1CFB161, 0x0000000070133330, 0x00000000003FEC72, 0x0000000000000000,
+	0x000000003C037C00, 0x3FE7AD8051A21ACA, 0x3FFB04104612E8B7, 0x3FDAB0A57DA33821,
+	0x6A828F6C6914D3BA, 0x00000000402A8BCA, 0x0000000000009768, 0x0000000000000000,
+	0x000000003EC8CD12, 0x3FE71016EEC02C5D, 0x3FD34450CB2F3328, 0x3FE0FB809164B879,
+	0xB1FAEA3A5B72712E, 0xFFFFFFFFFCF72CCE, 0x0000000000749C08, 0x0000000000000000,
+	0x000000003E175E3C, 0x3FE387EC40A9D0BE, 0x3FF3F76CA04EC8D9, 0x3FEBC98EC4A79D76,
+	0x16255CB1A5029324, 0x0000000019CF2700, 0x0000000000035A60, 0x0000000000000001,
+	0x000000003E96CD68, 0x3FD379B849C2413C, 0x40023B1385E9B710, 0x3FFB929A18389C5F,
+	0x0C105047937E6DB3, 0x0000000043CE2D4E, 0x00000000009AE27D, 0x0000000000000001,
+	0x000000003F78DFF6, 0x3FCDA16E03FF6590, 0x3FEF3B8BEDACB0B4, 0x4006C6ADA5F90063,
+	0xC0B4A079EF9B0D95, 0x0000000023821F55, 0x000000000003E960, 0x0000000000000001,
+	0x000000003F753115, 0x3FDAE66BED1B11AE, 0x3FC9710B3C136AB9, 0x3FCB3A879DB4E691,
+	0x78B86D090C757D8F, 0xFFFFFFFF8254F9E4, 0x000000000031495D, 0x0000000000000000,
+	0x000000003EEB4C72, 0x3FE984AC2CF0A100, 0x3FEA8F1D656AD3AB, 0x3FE5CD52755773AB,
+	0x9B2CA0686ED8142C, 0x000000002C0CC36C, 0x00000000003914F6, 0x0000000000000000,
+	0x000000003E21F77C, 0x3FC2C707970E0A80, 0xBFD6D53C603FE55A, 0x3FC84A30B00A220C,
+	0xC08DCA1B46A5F47A, 0x0000000060E4BE8A, 0x0000000000E8DCA6, 0x0000000000000000,
+	0x000000003F329131, 0x3FE69273FB43B5A3, 0x3FEDD987F53810E9, 0x3FF4D251867DCDB8,
+	0xF36B6C35018F9C6E, 0x0000000063DAA7F2, 0x0000000000914567, 0x0000000000000001,
+	0x000000003F647689, 0x3FC2DF85D9DEBFFC, 0xBFDED6E7A9DA12F6, 0x3FF5A62C58EC8304,
+	0x35556351FFE47DEF, 0xFFFFFFFF961300C3, 0x0000000000C2A188, 0x0000000000000001,
+	0x000000003E84710C, 0x3FE616613D2851BC, 0x3FF460766A8CD5EF, 0x3FDC93FE1C4679BA,
+	0x9C230AB02A41E67C, 0xFFFFFFFFF5A6D30A, 0x000000000624A6EA, 0x0000000000000001,
+	0x000000003F15BF04, 0x3FEACBBEF4FFEDED, 0x3FF65E8737A42A7C, 0x3FD26840D1B072B1,
+	0x31F718619608363B, 0xFFFFFFFFA729EE4F, 0x0000000000416CCD, 0x0000000000000001,
+	0x000000003F331AD7, 0x3FD5532FAB60C61C, 0xC0057A63548256FF, 0x3FEA666D963F3789,
+	0x15F36008DA8AC039, 0xFFFFFFFFA178C145, 0x000000001D2E27F3, 0x0000000000000000,
+	0x000000003EC43230, 0x3FCB4B83E31248F0, 0xBFF5F3743E6F3372, 0x400152D4BEC61119,
+	0x09ED63B52361F14A, 0xFFFFFFFF9EC36CE3, 0x00000000007FD64F, 0x0000000000000000,
+	0x000000003F0E60BB, 0x3FDD9970D0E0B27A, 0x3FD13B101E7C12BE, 0x3FF572D76BBC7006,
+	0x177BC1054C1F22FB, 0x00000000502AF189, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F6EEEF7, 0x3FEDFCCB52FE088A, 0x3FCE092D74FD095A, 0x3FE375ED63DD4616,
+	0x7E834CB8CE02CF34, 0xFFFFFFFF81F5D051, 0x00000000002A7D39, 0x0000000000000001,
+	0x000000003F26CDAB, 0x3F178C779FB68000, 0xBFF0457408284EDB, 0x3FDAAF297CA012B1,
+	0x68CF56F1C8E41D7A, 0xFFFFFFFFECE94F8C, 0x0000000000000002, 0x0000000000000000,
+	0x000000003F7A35C7, 0x3FB01F8120D36BF8, 0x3FF340B2EF3042D3, 0x3FCF6F012B843BE7,
+	0xB46CC296B57440EB, 0xFFFFFFFFC33F7F91, 0x00000000001B1566, 0x0000000000000000,
+	0x000000003EAA4DB2, 0x3FE9B707B56CFD7F, 0x3FF0745483A8238C, 0x400B976E6FFFD12C,
+	0xB396FC5FB2631101, 0x000000007C6B2E6B, 0x0000000000000000, 0x0000000000000001,
+	0x000000003DA2AED0, 0x3FDA3FE0F51BB1A8, 0xBF93E27CA09F0E39, 0x3FE50F9E9D136181,
+	0x9522EE6CD29F46F5, 0xFFFFFFFFAFCB295A, 0x0000000000D29B68, 0x0000000000000000,
+	0x000000003EEE19A6, 0x3FA31269629592D0, 0xBFEA73233D1A1C09, 0x3FEC7001D139F189,
+	0x875FB6D9365F4E0A, 0xFFFFFFFFB6AEA1DD, 0x0000000000000033, 0x0000000000000000,
+	0x000000003F641368, 0x3FC6B636C7D91EA8, 0x3FF27C952907845C, 0x40060BE59C66D670,
+	0xAFD1C797AF6C3E82, 0xFFFFFFFFCFD6A779, 0x000000000083E78E, 0x0000000000000001,
+	0x000000003F2C2C90, 0x3FD6DADCE3FDD95A, 0x3FF3064CDE47588E, 0x4005648FA94D314B,
+	0x9FAF83021483BCC6, 0x000000006F2D4011, 0x00000000000000ED, 0x0000000000000000,
+	0x000000003DC9E5D0, 0x3FE607DE2ABE56FF, 0xBFE5F380A5FCBA4E, 0x40114B7085DEB6CB,
+	0x8D2A78499D2DD1DA, 0x0000000022B84F34, 0x0000000000CF4031, 0x0000000000000000,
+	0x000000003DDC5150, 0x3FBCF8AB24756738, 0x3FE6E2A431C4AC5F, 0x3FBBE8E4C9090D91,
+	0x1486E09ECD26AA61, 0xFFFFFFFFEB60A6E4, 0x000000000000038A, 0x0000000000000001,
+	0x000000003E9C293E, 0x3FC5264DDBCBA38C, 0xBFD9CFA776D8DD1E, 0x3FF826CEE50ABF40,
+	0x8C899AE32B144BAA, 0x0000000003CCF0FD, 0x000000000014B5B0, 0x0000000000000000,
+	0x000000003EBF8A70, 0x3FD60896A1CA0714, 0x3FD898A8D5348009, 0x3FF1B942B251CF32,
+	0x042679DB9666AEFC, 0xFFFFFFFFE1EA4812, 0x00000000000000BF, 0x0000000000000000,
+	0x000000003F4A57E8, 0x3FE6877113C83A48, 0xBFE4CD8AB8F3FEDA, 0x3FC5FB2BF44580C6,
+	0xA23A05855D9CB8F2, 0x00000000068125BF, 0x00000000011C7672, 0x0000000000000001,
+	0x000000003F53CDFB, 0x3FDA73249CFFF3CE, 0x3FB0847441C3EA62, 0x40077097C3D00798,
+	0xC66610E197B8694F, 0xFFFFFFFFAADF6AE7, 0x0000000000001E1D, 0x0000000000000001,
+	0x000000003F5949BA, 0x3FB87F97F1C2AB38, 0x3FE5DBDB3D7A20CE, 0x3FFAE33C27B827A2,
+	0x928E6B36E81EDD80, 0x000000000620A224, 0x00000000001D6511, 0x0000000000000000,
+	0x000000003F5179E0, 0x3FED3C55FC869874, 0xBFC13DC336519752, 0x40049E58257315E3,
+	0x96D2EC23C053D5EF, 0xFFFFFFFF9FD45976, 0x000000000000791F, 0x0000000000000001,
+	0x000000003DF9BA60, 0x3FBA195C2B8D1850, 0x3FEB3426BB59A2E9, 0x3FB34E25E821A1F4,
+	0x8B1A12127A3E1ACC, 0x000000001B1D4C12, 0x0000000000630762, 0x0000000000000000,
+	0x000000003E5BE368, 0x3FD7ACE1DF96C5EA, 0x3FED21C654CEC557, 0x3FBF8934479F72B0,
+	0x560B8A56CD59EAEF, 0xFFFFFFFF86A8DC69, 0x0000000000018FA8, 0x0000000000000000,
+	0x000000003EC82CAE, 0x3FD35540A9CCD8D8, 0x3FF46D268CBBF126, 0x3FE78CF59F0E0D0E,
+	0xAC1BE3BEFE3F2E60, 0x0000000054AF27D2, 0x0000000000CD1A25, 0x0000000000000001,
+	0x000000003DBB3870, 0x3F95DE74BC9B10E0, 0xBFF607EC03A8DA7B, 0x3FFDB0A806CD8104,
+	0x0138970BEBB22406, 0x0000000009E97E5D, 0x00000000000BAC26, 0x0000000000000000,
+	0x000000003F2D7806, 0x3FE5FBBDE8CD1237, 0x3FE5500F6EA507FE, 0x3FA12CDD755C3A11,
+	0x5ECAB5A9EF308503, 0x0000000052056216, 0x0000000000860E2C, 0x0000000000000001,
+	0x000000003F01726B, 0x3FCFCB5E6F60EF14, 0xBFF0C98BA8BA75E2, 0x3FD73328D27986FF,
+	0xE4C891CCA071B67E, 0x000000006D20ED4F, 0x00000000000E8E75, 0x0000000000000000,
+	0x000000003E6F2F68, 0x3FE9CEFFCAC551FE, 0xBFCEBC0B8C3E146B, 0x3FDF9F24AAF96B8C,
+	0x3D3616107E79D589, 0xFFFFFFFFD95FD151, 0x000000000023A14D, 0x0000000000000000,
+	0x000000003E3BD588, 0x3FE07B59ADB422AC, 0xBFE11298F0779945, 0x3FD39D9F7E9E52AD,
+	0x7172712BB1A73A40, 0x000000000A92FEEB, 0x000000000061999E, 0x0000000000000000,
+	0x000000003F741504, 0x3FE39A8E4FB90B05, 0x3FF1310961F166C7, 0x40091E9EEB319B1A,
+	0xC253CECE6B75F524, 0x000000007372BB3A, 0x0000000000693A67, 0x0000000000000000,
+	0x000000003F7208FC, 0x3FE5D311622FB903, 0xBFE5482554CF35BC, 0x3FF63B1AB4082EDF,
+	0x68C9192C41E91652, 0xFFFFFFFFD79A5EB4, 0x000000000227291C, 0x0000000000000000,
+	0x000000003F546ED3, 0x3FE971CA498853A2, 0xBFFE87B3908CF966, 0x3FF5ED60E8E8A36F,
+	0xEA8A607F3273AA37, 0x0000000079B41540, 0x0000000000BAA3B2, 0x0000000000000001,
+	0x000000003F272E8B, 0x3FE624F473E1A584, 0x3FEA271A9E72C331, 0x3FE09AC92BA36D7E,
+	0xABA6D1A7A043D23A, 0xFFFFFFFFEFB4BB7C, 0x0000000000F19FE1, 0x0000000000000000,
+	0x000000003F3F0FF5, 0x3FCBE87BD84C797C, 0xBFC89A147406DD09, 0x4000C4326C2C22DA,
+	0xAB3CC9BC9BBB31B7, 0x0000000039FDBA9B, 0x0000000000AF4A34, 0x0000000000000001,
+	0x000000003F12EB8E, 0x3FD198A153EC5F3C, 0xBFCE43ECE567086C, 0x3FF177601EC641C5,
+	0x7DAFC37E7D789785, 0xFFFFFFFFFEB527B0, 0x0000000016279DC1, 0x0000000000000000,
+	0x000000003F0D2AE2, 0x3FD11CE806258316, 0x3FE2C759A069E647, 0x3FD6AF8CDAD7F6CA,
+	0xB40B7F93900997B9, 0xFFFFFFFFE1B308DF, 0x00000000006F0009, 0x0000000000000001,
+	0x000000003F758C1F, 0x3FC8EFCAA3C84FA4, 0x3FF442C0CDC929EA, 0x400EDF182F30FBE8,
+	0x037893861B440778, 0x00000000761A2CC9, 0x0000000000000001, 0x0000000000000001,
+	0x000000003F74BDB1, 0x3FE7F3BFC5F1C037, 0xBFF149F0FFD6221C, 0x3FD0B27BFF1B77D7,
+	0xA49BD0B60322B868, 0x0000000071021517, 0x000000000028D15E, 0x0000000000000001,
+	0x000000003E4D19E4, 0x3FB2C901028F7708, 0x3FCC2194C57CC417, 0x40003CB05515CF37,
+	0xE1D792458F997063, 0x000000005ABB561B, 0x0000000000000002, 0x0000000000000001,
+	0x000000003F27855A, 0x3FDAC76095ABBF02, 0xBFE1A2AF0925E4A7, 0x3FC8AC142FD0040A,
+	0xF342C837601DB832, 0xFFFFFFFF84F49CDB, 0x0000000000C2FCBB, 0x0000000000000001,
+	0x000000003EDAD5DC, 0x3FD901F7DF359844, 0x4001F83D899876DF, 0x40056760FD08B650,
+	0x07A316500A256490, 0xFFFFFFFFF0BAE6D4, 0x0000000000000014, 0x0000000000000001,
+	0x000000003EDF7FD0, 0x3FB12E98604CA878, 0xBFF7A261E2AC6C51, 0x3FF55DA87C28E643,
+	0x38A25EBA3CCB0A5A, 0x000000004BAFE594, 0x0000000000ACBD67, 0x0000000000000000,
+	0x000000003F26E5DC, 0x3FE0DF890091E67F, 0xBFF4C7D60589EEB5, 0x3FE0A5E2DB07F08B,
+	0xDF0C8DCC57FC40AA, 0xFFFFFFFFE108E294, 0x000000000000005A, 0x0000000000000000,
+	0x000000003F743883, 0x3FE7CD621D753EAF, 0x3FDA39D95B0006DA, 0x3FE8747DEBEB40EE,
+	0x53B818FD392013F7, 0xFFFFFFFFDF78D1CE, 0x00000000010B2535, 0x0000000000000001,
+	0x000000003DE66170, 0x3FD91090525AE61E, 0x3FDC98DB8EC67162, 0x4001D07FEEFAB2BB,
+	0xC8628629D27041DC, 0x000000007608A912, 0x0000000000000175, 0x0000000000000000,
+	0x000000003F13B5A7, 0x3FD30D09020F6764, 0x3FE7DA725E48155C, 0x3FE1B8E5FD88E30D,
+	0x544719995EFA9ABB, 0x000000006950E621, 0x000000000110CF8B, 0x0000000000000001,
+	0x000000003DD150C8, 0x3FD86F8A87827968, 0xBFEA1DC8CC8ECEB5, 0x3FEE5BF84E1FA902,
+	0xB2B442D398A00D08, 0x000000004DAA9B77, 0x000000000000046E, 0x0000000000000001,
+	0x000000003E2F8B28, 0x3FE7412696ECDDC0, 0x3FFA686CA9717268, 0x3FD6EEB56F681C67,
+	0x301242A6C9FE9806, 0xFFFFFFFFA0B62F9C, 0x0000000000B65EEA, 0x0000000000000001,
+	0x000000003F2AFFFC, 0x3FEAF642139E8CC9, 0x3FCBB8D6AE5A72A2, 0x3FF65FDDB7BFE15A,
+	0x02233D2E3684A10D, 0x000000002E8B8D85, 0x0000000000000CF2, 0x0000000000000000,
+	0x000000003E4B7CEC, 0x3F7CCD076E7F3000, 0xBFD3B12B0589813E, 0x3FB9181D50276556,
+	0x72C8EF71E34BFF05, 0xFFFFFFFFAD1B9572, 0x0000000000A0243C, 0x0000000000000000,
+	0x000000003F4A41A1, 0x3FDD33EB3E89C1F0, 0xBFBD6CC49196AB15, 0x3FD22F1FA05E584A,
+	0x8D497C37EA29B8AE, 0x0000000035416CBE, 0x0000000000004137, 0x0000000000000000,
+	0x000000003F7EDCC7, 0x3FE0A2A314EC3426, 0xBFE6C395299252C4, 0x3FC4F1C70F4E871B,
+	0x4DB225402DCEB947, 0x0000000031D8E29B, 0x000000000004F88C, 0x0000000000000001,
+	0x000000003F0D2898, 0x3FCB6E5911DECC30, 0xBFB683CD29DE48E1, 0x3FEDA5F4ED9C7D96,
+	0x102F962226F5546A, 0x0000000017880F5E, 0x000000000000582D, 0x0000000000000001,
+	0x000000003F191594, 0x3FED4269E26A963A, 0xBFD79E0B5F963F7C, 0x3F941080B8DEAD2F,
+	0x937F01AB18C5391B, 0xFFFFFFFFD6F0CFBE, 0x00000000007EF0CD, 0x0000000000000001,
+	0x000000003F485EA2, 0x3FBA5CB5455DB518, 0xBFD6E5660B77CD82, 0x3FE142F043129542,
+	0x2350B4859AB5BFE7, 0x0000000054AF6129, 0x0000000000038EEF, 0x0000000000000001,
+	0x000000003EA4E772, 0x3FDFE3E091F5CEF2, 0xBFE5D0768B2E4580, 0x3FE054B237813732,
+	0x72FB235DCCC616DC, 0x0000000024C21C42, 0x0000000000BE2E5F, 0x0000000000000000,
+	0x000000003EC57864, 0x3FD3E89F87020472, 0xBF61A4857C9B95E2, 0x3FEAE35EDBA165DA,
+	0xD2AFC96D0649CF9C, 0x000000007330398B, 0x00000000000ECE15, 0x0000000000000001,
+	0x000000003D043560, 0x3FE2208F5D044DD7, 0xBFCE283B5FE1E43D, 0x3FE8E1420D176E83,
+	0xD4E203EBC6F20278, 0xFFFFFFFF8DBFF02E, 0x0000000000558C14, 0x0000000000000000,
+	0x000000003F440397, 0x3FE6D48A3B09B6BE, 0x3FE62A7089DACDAB, 0x4003505DDEB24BF9,
+	0x8DFA86E32414189C, 0x00000000492107B1, 0x00000000002518EC, 0x0000000000000000,
+	0x000000003E506A48, 0x3FD56C242CEB1056, 0xC0003053DA73CC1A, 0x3FF47F1D1F1F5FD2,
+	0x54E612DAFFDAC2BD, 0x0000000030E4E60A, 0x000000000175AC8A, 0x0000000000000000,
+	0x000000003ECC7B36, 0x3FE3C6A40939C8CB, 0x3FF7232526B5F33E, 0x400A95EA8ADFEA31,
+	0x3EA578D347024228, 0xFFFFFFFFBB135FCC, 0x00000000012AFA48, 0x0000000000000001,
+	0x000000003F0FB83F, 0x3FCFD493041FC074, 0x3FC923B96BEF551F, 0x3FE14656A891886D,
+	0xD8A5861C884E40DC, 0x000000007E19C967, 0x0000000000F9C156, 0x0000000000000001,
+	0x000000003F09442B, 0x3FA2286158F5E000, 0x400154290844CDF1, 0x3FDB37A7417A7756,
+	0xF094CA8FF3AFA099, 0xFFFFFFFFDD8C51F4, 0x000000000298C1A6, 0x0000000000000000,
+	0x000000003E9C16E6, 0x3FDAEB1A635764C0, 0xBFC98C9A78A36FD9, 0x3FF0DC0332F2E588,
+	0x702DE76AFB6D4480, 0x00000000469CBF52, 0x00000000007DEC80, 0x0000000000000001,
+	0x000000003F3B4490, 0x3FD230BEE1BA88EE, 0x3FF34BADDBB7DBA0, 0x3FEF48A5BDE2AB0B,
+	0xBB8EB0EB27593461, 0xFFFFFFFF8D402345, 0x0000000013C09A79, 0x0000000000000000,
+	0x000000003DC31AD0, 0x3FB88F51F6F4F710, 0x3FFDAE12483A84AF, 0x3FD98FC2262CFF54,
+	0x5CC28B4C18DADCF9, 0xFFFFFFFFCB3BA220, 0x0000000000FA82E1, 0x0000000000000000,
+	0x000000003F1153E9, 0x3F95CB844BE98C80, 0x3FE6868F4335FE89, 0x3FFF56ACF14F150B,
+	0xEDCF82B47B0CE322, 0xFFFFFFFFAE5D4210, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F58DCE8, 0x3FE07CDC4DF8B1E1, 0x3FD7A75D13259B71, 0x3FB0E16F145B2492,
+	0xA1FA54053818A103, 0xFFFFFFFFE0ACD302, 0x0000000000A3010E, 0x0000000000000001,
+	0x000000003E852712, 0x3FDFDFDB2428D04A, 0x3FE0C72CA48320E3, 0x3FFAAD20A91131DF,
+	0xE6C1747C3B4EB7B3, 0xFFFFFFFFAA778DB6, 0x0000000000000002, 0x0000000000000000,
+	0x000000003EE284F4, 0x3FD47ADFA72DF696, 0xBFF876E87A2F46B7, 0x3FE5696BA5E3A9A6,
+	0xD9137CA700AA02FF, 0x000000006764FD84, 0x0000000000F0D77E, 0x0000000000000001,
+	0x000000003F459EA0, 0x3FCA421A200CCEFC, 0x400C9B4011D91639, 0x3FC61341FE12C7A9,
+	0x78533F801A272981, 0x000000002DAF39A9, 0x000000000000000A, 0x0000000000000001,
+	0x000000003D556210, 0x3FE24DA19EA050F2, 0xBFFD7ED11466E15B, 0x4006CD6B330432FB,
+	0xC1C02F1D23ED4C5F, 0x0000000037530F5E, 0x0000000000CF2352, 0x0000000000000000,
+	0x000000003F0E71AF, 0x3FC5A78F9B4A5F7C, 0xBFF3F11D1BEAE867, 0x3FE5A3FBF6701970,
+	0x6579B86AAE047D4A, 0xFFFFFFFF83C89A84, 0x0000000000000031, 0x0000000000000001,
+	0x000000003EF82528, 0x3FD3CFF1ACBB0776, 0xC0037323E735002A, 0x3FC5CB0E11D10486,
+	0x3F686026084AA1D1, 0x000000003FC4F51B, 0x00000000014E059E, 0x0000000000000000,
+	0x000000003EF8A20E, 0x3FE092F754B5E93D, 0x3FEEBC516AAEBFE8, 0x3FCADBBE63AF4C9B,
+];
+pub const XOROSHIRO128PLUS_RAW: [u64; 8] = [0x0000000000000003, 0x0000006001030003, 0x20C102C302000C03, 0x810180670D23AD61, 0x26D13A4941333A42, 0x538A501C02F58B2E, 0x2AB2076DEE382F7E, 0x30DFCFB722FECD9C, ];
+pub const XOROSHIRO128PLUSPLUS_SEED: u64 = 2952;
+pub const XOROSHIRO128PLUSPLUS: [u64; 2048] = [
+	0x22A0149C5867548E, 0xFFFFFFFFDED3766F, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F67B034, 0x3FE30F1188380BBE, 0xBFD86053174F9600, 0x3FB35F807E436612,
+	0xB4CC59838B855327, 0x000000003841CD1E, 0x0000000000015BA0, 0x0000000000000000,
+	0x000000003F279671, 0x3FD4EE9E4BCC2E40, 0xBFDE352E6B14856B, 0x3FEFEEA773442754,
+	0x2804845A05F6164C, 0x0000000042785EBE, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F5213A4, 0x3FE213558FEE0898, 0xBFDE1E49B5119DEA, 0x3FF031ABD827A289,
+	0x4DEAC8168751515D, 0x000000002C78C581, 0x0000000000036B6A, 0x0000000000000001,
+	0x000000003F251857, 0x3FD37571D7104164, 0xBFF69CFDA5D6E5B8, 0x3FBA598E78B82858,
+	0xA7D6CB01DCD6D4E7, 0xFFFFFFFFC54D4E7B, 0x000000000000000F, 0x0000000000000001,
+	0x000000003EFD7DBE, 0x3FE324365213857F, 0xBFED3ED6C7AAA40C, 0x400C6C7B2A52AB5D,
+	0xA711B73F98B28456, 0x000000005A0160D8, 0x0000000000038833, 0x0000000000000000,
+	0x000000003F078B74, 0x3FE2CBD35F245B5E, 0x3FC6182DE0A3564E, 0x4018460BDD8384A4,
+	0x1DAEEA2CEB679C3B, 0x000000000B08B867, 0x0000000000000035, 0x0000000000000000,
+	0x000000003F5F24E7, 0x3FE6D2FEB2A50C05, 0x3FF1C54B3379FDC9, 0x3FEF3C1CBC983F68,
+	0x147F909576E310EE, 0xFFFFFFFFF3636D16, 0x000000000006CC9C, 0x0000000000000001,
+	0x000000003F023ECB, 0x3FBBF3E35D56AD78, 0xBFF1AEB0CBB4EBF4, 0x3FCD93E0EF8CA90A,
+	0x46B762D9FE4EBF94, 0xFFFFFFFFD43B78CC, 0x00000000000000AE, 0x0000000000000001,
+	0x000000003F57D976, 0x3FB39917D198A5F0, 0xBFEE470EAE921B28, 0x3FD3F609980A5A41,
+	0x889748DD3CE6D0A5, 0x000000001EE5B3A3, 0x00000000000BCE24, 0x0000000000000000,
+	0x000000003F00A871, 0x3FE2B88D2EEDB1D9, 0xBFF99A3E0035A78F, 0x3FCE0BCE88238540,
+	0xB3257E4C2B113574, 0xFFFFFFFF8BEEA3AC, 0x00000000000001E5, 0x0000000000000000,
+	0x000000003F6E4949, 0x3FEB071546CBAC3C, 0xBFD4A2BE2FAADDE1, 0x3FAB9B4D7494ACAF,
+	0x4F7CF4729F739D3F, 0x000000004C39A9F1, 0x00000000000C567B, 0x0000000000000001,
+	0x000000003EB5EBA8, 0x3FEC703D5EDCE1FA, 0xBFE29D9B851CF788, 0x3FE715CF3C4F3143,
+	0x68C94057AF5BC6A4, 0x000000007AD6E79A, 0x00000000000004D2, 0x0000000000000001,
+	0x000000003F2D4AAE, 0x3FE645D5C15B3F6A, 0x3FDEAACD1AAE6701, 0x3FBA17C954B2EB96,
+	0x1A871CDAA31A9C56, 0xFFFFFFFFB26E739B, 0x00000000000A446E, 0x0000000000000001,
+	0x000000003F4C25AF, 0x3FE06A90AB25E757, 0x3FF07FB39BC54EB3, 0x3FF0AEAADF374B95,
+	0x1C9B58B90761BD89, 0x000000006DC6FE3D, 0x0000000000001224, 0x0000000000000001,
+	0x000000003F2EB8C5, 0x3FD0FC36B0442C28, 0x3FECC0EAFF3B85E6, 0x3FEE18861A208C80,
+	0x9B11978D30D622E6, 0xFFFFFFFFD676B92C, 0x000000000007CC94, 0x0000000000000000,
+	0x000000003F6BB151, 0x3FD8445EA1754B9A, 0x3FD431DBC45A45B5, 0x3FEAF746EBAEF511,
+	0xAFC2229EFDAF3530, 0x00000000739C4182, 0x0000000000002800, 0x0000000000000001,
+	0x000000003E5934B0, 0x3FEF8C2B6EF3162E, 0x3FED897DB9F5E831, 0x3FE30D0656E69105,
+	0xF8BC52017FBCBF48, 0x000000006D386E15, 0x000000000018CB30, 0x0000000000000001,
+	0x000000003F570A8D, 0x3FEB21F55C30302F, 0xBFB389D70C6BF4E7, 0x3FE1EBDD2F4BAD63,
+	0x91DDC28FDF06D3FA, 0x00000000248C468E, 0x00000000000133FE, 0x0000000000000001,
+	0x000000003F106CD1, 0x3F934283A6082000, 0x3FE24CCCC82AD23B, 0x3FD0E724F11BD410,
+	0x7C7BC4DE65A2A720, 0xFFFFFFFFF9201F64, 0x00000000001BC0CD, 0x0000000000000001,
+	0x000000003F7AA288, 0x3FD1432A22A6C032, 0x3FF114E28BB4F505, 0x3FE7EAB3F4F2347E,
+	0x037195684EE9A04B, 0x0000000053C53333, 0x000000000009CA5B, 0x0000000000000001,
+	0x000000003E970A68, 0x3FEAB6D88D2B0775, 0xBFC809E471420022, 0x40141C36DEE1DD44,
+	0xA16E7029324A73C7, 0x000000007E83A76B, 0x0000000000079489, 0x0000000000000000,
+	0x000000003F3B8F15, 0x3FEA88870680CA13, 0x3FF50E98EF2DFD62, 0x3FD529D9C358FBDD,
+	0x3AF82893A9D613A2, 0x000000003C539F3E, 0x00000000000A9EB5, 0x0000000000000000,
+	0x000000003E0DE898, 0x3FDFF1C9B86F7E46, 0x3F9D5F3A3895DA5B, 0x3FFCF889EE385C52,
+	0x1FE064A6594ABD83, 0x0000000003899FC6, 0x0000000000136146, 0x0000000000000000,
+	0x000000003F370279, 0x3FE6B4308515F583, 0xBFF8150157004869, 0x401785EDC1394C4A,
+	0x134589F28A49CCC2, 0xFFFFFFFFCF6C1245, 0x0000000000B3D1E9, 0x0000000000000001,
+	0x000000003EE8A5DE, 0x3FA411C9C86E14A0, 0x3FF406AD478D4A37, 0x3FDF2C7183FFCDDC,
+	0x197F7D623C1F8AAD, 0x000000004B2EE0BB, 0x0000000000159686, 0x0000000000000001,
+	0x000000003C8181A0, 0x3FDAD5A95F9A9F62, 0x3F92AED9EC5E6BED, 0x3FCF7190B11E3A6C,
+	0x3ED5E19998DBE1CA, 0x000000001341EDEE, 0x0000000002D7E5B4, 0x0000000000000000,
+	0x000000003F0A375A, 0x3FBFD579439CFBF8, 0x3FFC67783C0B37D9, 0x3FF6C61DED9F6E23,
+	0xCE42A9170EFD4F3C, 0x00000000720A7626, 0x00000000000D2177, 0x0000000000000000,
+	0x000000003E63AC58, 0x3FA8575D2AF12C80, 0xBFD276185180830C, 0x3FE7FA7142F08E2A,
+	0xB5518865B779ED87, 0xFFFFFFFFB4324E1F, 0x000000000065D3BF, 0x0000000000000001,
+	0x000000003E857F76, 0x3FEEA6B67B32CA78, 0xBFE1A9830CCE4132, 0x3FB59BD77D16C614,
+	0xD403A6390646B6B2, 0x0000000006B0120B, 0x000000000023109A, 0x0000000000000000,
+	0x000000003EBE91EC, 0x3FD3C58B5AC53036, 0x3FB1744358DD5D21, 0x3FF36F63DA7A4130,
+	0xA935D76018F57022, 0x000000005E9168E7, 0x0000000037528C2F, 0x0000000000000000,
+	0x000000003F68080D, 0x3FEA3EE3A339A668, 0xBFBC0BBE4CC116E6, 0x3F75282E85F358E3,
+	0x29F5766E499CDD0C, 0x000000006A6CDEA8, 0x00000000002AFC6F, 0x0000000000000000,
+	0x000000003F58AB56, 0x3FE625ACAC74F86F, 0x3FE24A3C8EA8B721, 0x3FE72DC44FA3080B,
+	0x15720803E9A5B02B, 0x00000000595C157A, 0x0000000000000001, 0x0000000000000000,
+	0x000000003ED85006, 0x3FE7E5779F3E8F02, 0x3FF51ED04FDACEB9, 0x3FD40A4A72CF1207,
+	0x119E2B9DC73FBF3B, 0xFFFFFFFFDEEE58A1, 0x000000000010CDA5, 0x0000000000000001,
+	0x000000003E8DA3B6, 0x3FE18890C901E267, 0xBF9D6FB3B0AF9B39, 0x3FF225922BB5FA6C,
+	0xE417F28F30BC3224, 0xFFFFFFFFA798C223, 0x0000000000000005, 0x0000000000000001,
+	0x000000003DC9E1D0, 0x3FE415C5BA0728E6, 0x3FEE0BD7482804DA, 0x3FFACF1CD0996DB5,
+	0x09701F0320E5013D, 0xFFFFFFFFB8CEF142, 0x00000000002F3A31, 0x0000000000000001,
+	0x000000003E9A8A4E, 0x3FEDC44CA1A6554C, 0x3FF54F7252ACD29D, 0x3FF7CAD5C5D94091,
+	0x6DAF8ADFD9D36901, 0x0000000001DDFB66, 0x000000000000001E, 0x0000000000000001,
+	0x000000003EE6AB08, 0x3FC1749D6A411DBC, 0xBFD3924BC79D6901, 0x3FE16D3494E1E6DE,
+	0xC803940E6268226D, 0x000000006B2E5C61, 0x000000000034B6F8, 0x0000000000000000,
+	0x000000003E674994, 0x3FEF75E438C4BFA3, 0xBFC4BBA600A70DE5, 0x3F6548B58AA77BD8,
+	0x2BF8964894CA9026, 0xFFFFFFFFCACBFE0C, 0x0000000000000005, 0x0000000000000001,
+	0x000000003E5CDBBC, 0x3FD6EAFD7114F720, 0xBFF4690CCB18E7D1, 0x4000B6DC0534EEA8,
+	0xA0B665AEB4971815, 0x00000000193D7F17, 0x00000000001DB12D, 0x0000000000000000,
+	0x000000003F7FD3E1, 0x3FED3AE5EBDDFED7, 0xBFE919B727447317, 0x3FF4885A9A97C0FC,
+	0xCF2C289C17A6D356, 0x000000000B118D61, 0x00000000000001C0, 0x0000000000000000,
+	0x000000003F762425, 0x3FCBCD4465026B70, 0x3FF0032FD01F215A, 0x3FF5F35270B81697,
+	0xABE74A2A38A4C9C4, 0x0000000034A6F319, 0x00000000003D3765, 0x0000000000000000,
+	0x000000003DB2F7C8, 0x3FEFDD3D5F363953, 0x3FFB5DAE89E59F77, 0x3FD6184431E2264E,
+	0x016FD38A83C4AE51, 0x000000005DFA89F4, 0x0000000000000305, 0x0000000000000001,
+	0x000000003ED10DC0, 0x3FE0273E07D73699, 0xBFE88E68821650E2, 0x3FE2A6EA1FCB6893,
+	0xB18332AFFA3C12AF, 0x000000006B1B9D55, 0x0000000000000E89, 0x0000000000000000,
+	0x000000003F48B931, 0x3FE52AD70E6128EB, 0xBFF5FD165615923A, 0x3FD3F2A714033350,
+	0x1686FABB2298E9FA, 0x000000001C938B46, 0x00000000000016C9, 0x0000000000000000,
+	0x000000003D5D2C10, 0x3FCFCB0BDEAB0E48, 0x3FC443F962574C91, 0x3FE7C856313DDC38,
+	0xBACD01DBAF5E19F0, 0xFFFFFFFFAAF5A59A, 0x000000000017C24F, 0x0000000000000001,
+	0x000000003F434F4E, 0x3FB4BF6DEA0A7A78, 0x3FE3C52407D66464, 0x3F9CE0CFAA166A8B,
+	0x2CE09483F40A84D8, 0xFFFFFFFFA87AB223, 0x00000000000031B0, 0x0000000000000001,
+	0x000000003F38358A, 0x3FEC4DC4F9ADB98C, 0xBFE38EAE66AB0661, 0x3FF8F25525922AFB,
+	0x0278FF5E1E2137DF, 0xFFFFFFFFD01CBEA1, 0x000000000045C7AA, 0x0000000000000000,
+	0x000000003DA38A70, 0x3FBA341FD3CD6848, 0x3FF02316E1C2F897, 0x3FFE287D7D16202B,
+	0xAF76ABA912FFC020, 0xFFFFFFFFA180580C, 0x0000000000018D5A, 0x0000000000000000,
+	0x000000003EF1417E, 0x3FDC63F472D9AAD4, 0x3FDC90BDE0E0E382, 0x3FE4DE55F6A2916D,
+	0xAFD91BB16FE3D9FA, 0xFFFFFFFFF8F9DB04, 0x000000000008E829, 0x0000000000000001,
+	0x000000003DBEB4B0, 0x3FD57EA8F626A836, 0x3FD3DF979689B44B, 0x3FF404EC0D19A8B1,
+	0x0A33A028ED97077D, 0x000000000ECAD9C7, 0x000000000004FF4F, 0x0000000000000000,
+	0x000000003ED3AFBA, 0x3FC9D30CF137114C, 0x4002F24C35C6BABC, 0x3F75E80AB3F6D9B6,
+	0x999CEBA32EEBDF2E, 0x0000000032AA8E55, 0x00000000002C3989, 0x0000000000000000,
+	0x000000003F43B537, 0x3FB406C4BD5EA860, 0x3FF69D2617163CF5, 0x3FFB45B8B303AD29,
+	0x506882AA4C950A13, 0xFFFFFFFFDB4B54C6, 0x00000000001B21A9, 0x0000000000000001,
+	0x000000003F013DC2, 0x3FCC5D1589FF1094, 0x3F8310B9CA525BE9, 0x3FAA288ABDFDC061,
+	0xDD0D512A350805A9, 0xFFFFFFFFAC099946, 0x00000000003F09ED, 0x0000000000000000,
+	0x000000003F2C6F41, 0x3FE5EDB5B92038D9, 0x3FF24166600486E7, 0x3FBB921A40EE03BE,
+	0x433B2135EBF39A6E, 0xFFFFFFFFD4F4E92C, 0x0000000000365D50, 0x0000000000000001,
+	0x000000003D5937B0, 0x3FEF1B4DA3FCE831, 0xBFD60AAD38041511, 0x400B0366B163D707,
+	0xFB24F8E62F8E5062, 0x0000000011EC6E54, 0x000000000010EACD, 0x0000000000000000,
+	0x000000003F756844, 0x3FD3B75B29D96D1E, 0x3FC4C4158448D1B2, 0x3FAC3EAAF1AF563D,
+	0x457ED764451C8E61, 0x000000001996E33D, 0x00000000011C320D, 0x0000000000000000,
+	0x000000003F2493AC, 0x3FDD98E020CDC828, 0xBFC932B7D1D128B6, 0x3FE781B84D36F33B,
+	0xA5FEEB3E98CF7490, 0x0000000019C56AD5, 0x0000000000170073, 0x0000000000000000,
+	0x000000003F0777AF, 0x3FDC3EC722671EBC, 0x3FD3B6C7E0C190E6, 0x3FD92EB0A451BDA4,
+	0xC2C5C4B5595EA345, 0x000000002C6A1DD1, 0x0000000006C9C482, 0x0000000000000000,
+	0x000000003E8D85DA, 0x3FE3EA7A435D422A, 0x3FDC919F1AEA1EBE, 0x3FCB402961AD082F,
+	0x1F6420F5AB3396AE, 0x00000000522FAE09, 0x0000000000407534, 0x0000000000000000,
+	0x000000003F19147C, 0x3FDB4482F6401D0E, 0x3FE5FEFFEC74C81A, 0x400F9713F1FA3A3F,
+	0x8E7015E358C2DDBD, 0x00000000469140F5, 0x0000000006C1816E, 0x0000000000000000,
+	0x000000003EC5BC76, 0x3FEAFCAE6C97FE5F, 0x3FF35DE2DF362C1D, 0x3F51D0B4F42BD71C,
+	0x00865DDE7DA35A3F, 0x000000001424E020, 0x000000000007825B, 0x0000000000000001,
+	0x000000003F7BDA63, 0x3FE5B2647C36577D, 0xBFEC1A5FFD46C3F9, 0x4002C4A2B1F59CC4,
+	0xAB4F1FE4101B694C, 0xFFFFFFFFCD0F57AB, 0x0000000000000000, 0x0000000000000000,
+	0x000000003E59230C, 0x3FD705998CD91D9C, 0xBFE33D17A7E0C0D0, 0x3FF18345CC4EE085,
+	0x9162BBD319ABC953, 0xFFFFFFFFBAA820EE, 0x0000000000059485, 0x0000000000000000,
+	0x000000003E17C598, 0x3FA19DEF22A06370, 0xBFEE7B4C3B5B90E7, 0x3FCF9EC811943C63,
+	0x532354EFBC244088, 0x0000000041E784CD, 0x0000000000000002, 0x0000000000000000,
+	0x000000003E9D6D2C, 0x3FD598C51BF37B8E, 0xBFC0050A916DAC7C, 0x3FC3EB71B70E62E2,
+	0x7864C3E6C601D626, 0xFFFFFFFF9EF4218D, 0x000000000030B030, 0x0000000000000001,
+	0x000000003E5843B8, 0x3FD272D95919852C, 0x3FFA1CC9B4F56308, 0x3FE674A152DDA4B4,
+	0x3656F0C31BFEB1DC, 0x0000000041494A4D, 0x000000000000000D, 0x0000000000000001,
+	0x000000003F094E10, 0x3FE2FBA9E0634648, 0x3FF05C96E9BC9A7E, 0x3FE2F0C51B4AE34E,
+	0xDCCB83DD9A967A98, 0x00000000161B1353, 0x00000000000446D5, 0x0000000000000001,
+	0x000000003F3E88C4, 0x3FD341C3D0F0FE0A, 0xBFF07DA62663847C, 0x3FEC489F356061EA,
+	0xC1A10035295DC9FC, 0xFFFFFFFFC9ECAF1E, 0x0000000000000009, 0x0000000000000001,
+	0x000000003E961B6E, 0x3FE65F48E9D0BEE1, 0x3FC9442CE44D9B82, 0x4009E2395E7FEED1,
+	0x59BF6878E44126E5, 0xFFFFFFFFA06BC473, 0x0000000000379C6E, 0x0000000000000000,
+	0x000000003E23B968, 0x3FE590F8FF44988B, 0x3FEC00572F30A5E8, 0x3FFB8EF57DFD3EF3,
+	0xA6C3DF4F550D868D, 0xFFFFFFFFF6AEEE68, 0x000000000000003A, 0x0000000000000001,
+	0x000000003E0D3014, 0x3FEFBB32279A9D8D, 0xBFC2966BF2A7914F, 0x3FFA76160BA87E32,
+	0x3357D56C10DA59D7, 0xFFFFFFFFBEC0276F, 0x000000000070F606, 0x0000000000000000,
+	0x000000003F1B10F3, 0x3FE95E8110989E46, 0x3FF108EE78D5F308, 0x3FCE0A9E8A92BF79,
+	0x794375AA5E22C7B5, 0x0000000079440BD9, 0x0000000000000194, 0x0000000000000000,
+	0x000000003DC8BEC0, 0x3FEB2BEB36A6560C, 0x400449607B049BB6, 0x3F54A6B82A9D425A,
+	0xA0CB90B825F6E5B5, 0x0000000028FF25FD, 0x00000000001839DC, 0x0000000000000001,
+	0x000000003F2AEAB3, 0x3FDFECCC81834CC4, 0x3FE54EBC8DE1FF68, 0x3FFC3DB1ED95D72A,
+	0x52B1F06F332FA681, 0xFFFFFFFF8F1CF153, 0x0000000000000B6C, 0x0000000000000001,
+	0x000000003F300DCD, 0x3FE04D252E9C975D, 0xBFE1E65EB376D639, 0x4002710C5BCC21AD,
+	0x692AAF17221294CC, 0x000000004D1906FA, 0x00000000005AD746, 0x0000000000000001,
+	0x000000003EE59DB8, 0x3FB35175DE987D00, 0xBFF2036484CEAE28, 0x3FE42290FDDF5528,
+	0x3F9C996C5CD55D2B, 0x00000000750C9EA9, 0x0000000000003633, 0x0000000000000001,
+	0x000000003F72D996, 0x3FD527391280B3DA, 0xBFE38AEEEC2ACCF5, 0x3F6A40F8F48DC4E1,
+	0xCAF2B0939ABABF42, 0xFFFFFFFFFAAEA646, 0x00000000002EF186, 0x0000000000000001,
+	0x000000003C5E3A80, 0x3FDE87249C211136, 0x3FAA3615D677FE2C, 0x3FE2F77B544BC98E,
+	0x0F6C72DA3E4757AF, 0xFFFFFFFFF1978B67, 0x00000000000004F3, 0x0000000000000001,
+	0x000000003F7DDF13, 0x3FEC57F8631A0E56, 0x3FD69EA9A505635C, 0x3FF23FBA09B0B7A7,
+	0x9CC579EC9EBED555, 0x000000002228EF03, 0x0000000000022929, 0x0000000000000000,
+	0x000000003F52A581, 0x3FEFDCE7384F9DA5, 0xBFD7D9F0556A2B4F, 0x40028E861743A580,
+	0xBCC003CA7D88A2CE, 0x000000003A486E2E, 0x000000000002122E, 0x0000000000000001,
+	0x000000003E82A646, 0x3FEBF82B98AB6361, 0x3FBF0506B8CDD4BD, 0x3FF64E9504500819,
+	0x4984F5A3D4BD8F4C, 0xFFFFFFFF8A5369D3, 0x0000000000470AA2, 0x0000000000000000,
+	0x000000003E9D3DD4, 0x3FE0375EC55612C4, 0x3FF1E79AB5184714, 0x3FDC8BF9F2D40233,
+	0xDB1BC128CBAB46E2, 0xFFFFFFFF9E64FD02, 0x00000000000293B1, 0x0000000000000001,
+	0x000000003EFD6D1A, 0x3FE2D25299A85DA3, 0x3FD42D4DFBBE0621, 0x3FE9E6F061554F3F,
+	0xE21DFE600C2780CD, 0xFFFFFFFFEE208F86, 0x000000000069D09A, 0x0000000000000000,
+	0x000000003C984B80, 0x3FBD8E7573CBDFD8, 0xBFFBEF4E5953DBD0, 0x400426D639E660EA,
+	0x688C8AE3190C45D5, 0x000000002C9597D2, 0x0000000000122499, 0x0000000000000000,
+	0x000000003DDE7DD0, 0x3FE1EBCCE13D7D59, 0x3FF30623A5537E96, 0x3FD1F97785E2F0E5,
+	0xCDEE63AFB2FA7A85, 0x00000000127D764B, 0x0000000000414561, 0x0000000000000000,
+	0x000000003F368864, 0x3FDED5CBEF9054C8, 0xBFF41DB936D14981, 0x3FEE2315E9E3DDF0,
+	0x95B8E3FE8D75B3DE, 0x000000005EADC8F4, 0x00000000008BBF3C, 0x0000000000000001,
+	0x000000003F719696, 0x3FC7511BA41D18D4, 0x3FD14645269360A3, 0x3FF195CB75D06357,
+	0xBAA0D85FFE072A83, 0xFFFFFFFFA6DFC9D3, 0x00000000003CD989, 0x0000000000000001,
+	0x000000003DA63550, 0x3FEA884970917687, 0x3FE4DCDB554D159B, 0x3FCF825474662CEA,
+	0x75F184EC7EE8A2FD, 0xFFFFFFFFB19AA07D, 0x0000000003362E28, 0x0000000000000001,
+	0x000000003F2A1A4E, 0x3FE91AEDDBB74091, 0xBFC58C227A6A3DC1, 0x400FB547DD1BC53A,
+	0x4942272582E46EE8, 0x000000000AA99CED, 0x00000000008D649F, 0x0000000000000001,
+	0x000000003F4410CC, 0x3FE05F330A531394, 0x3FF8B424B23E7134, 0x3FE09ECC4AE5748C,
+	0x4D9C330688EF8382, 0xFFFFFFFFBAD2CB9F, 0x000000000B70C633, 0x0000000000000000,
+	0x000000003F4F069F, 0x3FE0E042967645FE, 0xBFE52BE68A7CD326, 0x3FFA17825B97AC0B,
+	0x97C72BF320C1C545, 0x000000003B9576EA, 0x0000000000161EF5, 0x0000000000000000,
+	0x000000003E0AC03C, 0x3FEBB018E4FDFC29, 0xBFC2D46194600C5C, 0x3FE5115F4DAC6632,
+	0xD4D142FEE8631978, 0x0000000044CD2DCE, 0x00000000123210C4, 0x0000000000000001,
+	0x000000003F04891F, 0x3FE4D4696AD7499E, 0x3FFB6BE22C6EFEC1, 0x3FE7D1AB6ECAFC1F,
+	0xD5B7CF574C3434F7, 0xFFFFFFFFE81CDFBF, 0x00000000004723C3, 0x0000000000000001,
+	0x000000003F72165E, 0x3FD067E41A2CDB92, 0xBFCE749CDDBEA2EE, 0x400BC58248F21EBD,
+	0xC9ADD2FA65C30A88, 0xFFFFFFFF9C474EB5, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F2AE556, 0x3FEAD599FCABB4FE, 0x3FE8E0C48815DCD6, 0x3FE0C33BB1FAC395,
+	0x8C1B49560656E4B4, 0xFFFFFFFFA15D97B1, 0x000000000006CE9D, 0x0000000000000000,
+	0x000000003F388977, 0x3FE312D3E5A26E68, 0xBFE2FA5A2EAAAA86, 0x3FFA7493C22BF544,
+	0x9C053BF88E61AB82, 0x00000000477B203D, 0x0000000000000003, 0x0000000000000000,
+	0x000000003EB974D0, 0x3FC8EC36F6DAFEF4, 0x3FD56857181E8C4F, 0x3FE6245216066821,
+	0x09D6DF5A54082077, 0x0000000063E810C6, 0x00000000008BB677, 0x0000000000000000,
+	0x000000003F22A1B4, 0x3FBFEFC198671E98, 0x3FB5531573C04D60, 0x3FE85364E0CC5D96,
+	0xF9A1364C66A61E98, 0x00000000014D7B16, 0x0000000000000002, 0x0000000000000001,
+	0x000000003EC4A930, 0x3FE6CFC361A3C0C9, 0xBFF59C3A0FDB5A6E, 0x3FE997D1A3306DB3,
+	0xE183C45366FAA8CF, 0x0000000067196B5E, 0x0000000000163C7E, 0x0000000000000001,
+	0x000000003EC8BD08, 0x3FE61F8A4ED43D58, 0x3FE1F9F98E5A02DA, 0x3FEB0EB11092D846,
+	0x240BCB10DD99E4DF, 0x000000003CE0070C, 0x000000000000006D, 0x0000000000000000,
+	0x000000003E587FB0, 0x3FE57915EFDFDCF0, 0xC00141B88F8BFBD2, 0x3FE7D0E098C405F4,
+	0x5546E69BF01DA557, 0x000000005F69AD73, 0x00000000005FB0E1, 0x0000000000000001,
+	0x000000003EB69982, 0x3FE08F8503FFC4E0, 0xBFF9B77DE29C843E, 0x3FE7E3CB7B00735E,
+	0xCC7260AAD365C3C2, 0xFFFFFFFFEF426247, 0x0000000000000078, 0x0000000000000001,
+	0x000000003ED67F48, 0x3FC46F0411CE6BD8, 0xBFC7DF997286ED6C, 0x3FF8FBBF053CF29A,
+	0x83820BCE0BF4296D, 0xFFFFFFFF8E331A1F, 0x000000000010ABE0, 0x0000000000000001,
+	0x000000003D3D19A0, 0x3FBE73EE655E0460, 0xBFD81079241FD3EB, 0x3FD07791A6A07A45,
+	0x63C5CEC6159F8EAC, 0xFFFFFFFFA063ADD1, 0x0000000000000658, 0x0000000000000001,
+	0x000000003EADCFD2, 0x3FA87ED869BB9F20, 0x3FDA2467BEC9D91E, 0x3FDE132E72181643,
+	0x3CD064B257563A94, 0xFFFFFFFFA9B3CF35, 0x00000000006235AB, 0x0000000000000000,
+	0x000000003EBF66AC, 0x3FDAA18817AE726C, 0xBFE0E52C32A82480, 0x4011D1712FBCD487,
+	0x696451EE8F9C0C80, 0xFFFFFFFFCFB9A3F6, 0x0000000000000B51, 0x0000000000000001,
+	0x000000003E9E9486, 0x3FD7C37789684C42, 0x3FE4691A72B97F72, 0x3FF0C9B0BCF9161A,
+	0x788255011D993925, 0x0000000021EF94C6, 0x000000000044FEA9, 0x0000000000000001,
+	0x000000003F51C04C, 0x3FEE6ADD32898254, 0xBFD07BB07B073BDE, 0x400537677649E9A4,
+	0xFAC7FA74488969CD, 0xFFFFFFFFD4DC8CB9, 0x0000000000000AC3, 0x0000000000000001,
+	0x000000003EBB9A22, 0x3FE306F508874A06, 0xBFDCAFF0327BAD38, 0x3FE80976297CD71D,
+	0xBEEA2A0D06831415, 0xFFFFFFFFC0E305CD, 0x0000000000808C17, 0x0000000000000001,
+	0x000000003F383AE7, 0x3FE55D0774BD07FE, 0xBFF6F483374A06CF, 0x4001518511D95100,
+	0x060234A5FAD6ADB6, 0x00000000575AD360, 0x000000000000063B, 0x0000000000000000,
+	0x000000003F1E6A80, 0x3FE6F67B87F76BCE, 0x3FE559D06ECF8DD7, 0x3FC363C4471DC197,
+	0x53BF346035572D4B, 0x000000003F461132, 0x0000000000222050, 0x0000000000000000,
+	0x000000003D8C9340, 0x3FE05B4B3AA4EE66, 0x3FFCDA83E6FB4F01, 0x3FADFF2718AABF19,
+	0xD4D376B32759291E, 0xFFFFFFFFF9E04D86, 0x0000000000025078, 0x0000000000000001,
+	0x000000003F0B57CC, 0x3FC6019529EDBEE4, 0x3FE0488E65A07FEA, 0x3FAE66A04D419378,
+	0xC86EFCFBBD440CC4, 0xFFFFFFFFFA194B26, 0x0000000000340120, 0x0000000000000000,
+	0x000000003F77720D, 0x3FDAF31FE08F22AC, 0xBFFA8870C9A7C0D4, 0x3FE48FE0831B4517,
+	0xF5C9D2B4D3D9A331, 0xFFFFFFFFA94B8A3E, 0x0000000000097442, 0x0000000000000000,
+	0x000000003F04CD4A, 0x3FC163F474E36F7C, 0x3FC20E98CC156770, 0x3FD07B9FF7BD603E,
+	0x0332B6CD06C86A25, 0xFFFFFFFFE6C5768C, 0x00000000008E1342, 0x0000000000000001,
+	0x000000003ED2E84C, 0x3FECE95C721354C3, 0x3FE98C5CA8CF4C49, 0x3FB02AC73EA3B2FF,
+	0x56A86D1CE270B518, 0xFFFFFFFF9074D4F3, 0x000000000075C5C1, 0x0000000000000000,
+	0x000000003ECFD018, 0x3FD6D9D47CAA76D4, 0x4000C4D991E4CA7B, 0x40043FA8A9E7A107,
+	0x4DC03E100CFA4618, 0x000000003994A8F2, 0x00000000009F23C9, 0x0000000000000001,
+	0x000000003EF2310C, 0x3FE27E29F5F87FEA, 0x3FEAD87397E33325, 0x3FE22B073F9FF26F,
+	0xBBA2BB2419CA3E8C, 0xFFFFFFFF9F665032, 0x0000000001F96286, 0x0000000000000000,
+	0x000000003F3DDD7C, 0x3FD9D5B8022912D6, 0x3FE75B43817B727E, 0x3FF707574700581E,
+	0xA0D798394518B290, 0xFFFFFFFFD3BC972D, 0x000000000045EDE8, 0x0000000000000001,
+	0x000000003F029B21, 0x3FDE2DBE9D9C9144, 0x3FF298126A65F6BC, 0x3FD756E2BA28ABC1,
+	0x6009569BEF26ADC7, 0xFFFFFFFFAD362AED, 0x0000000003566A7E, 0x0000000000000001,
+	0x000000003EAD2B1A, 0x3FB5061B6D708EA0, 0xBFEEFBAA13EAEE1B, 0x3FD3519C78F92CAC,
+	0xFBC99DBDB67E0F37, 0xFFFFFFFF80D7A736, 0x000000000090F6EA, 0x0000000000000001,
+	0x000000003F14DDD9, 0x3FE501BBB97FA34D, 0xBFEA61978C90EE1D, 0x3FFDD346657F44EE,
+	0xFB3821682AEDDBC6, 0xFFFFFFFF9FDE0058, 0x000000001D3979E2, 0x0000000000000001,
+	0x000000003F025690, 0x3FDA93B72AE19548, 0xBFE146F3CD517E60, 0x3FFAD948FF126510,
+	0x7C7CCBDD0F2D8570, 0xFFFFFFFFD490F369, 0x00000000001CD6A0, 0x0000000000000000,
+	0x000000003C9DE7C0, 0x3FE24A7354830877, 0x3FC22D8F0612F74E, 0x3FE5F9FA82573889,
+	0xE11574CD9FA02112, 0xFFFFFFFFDB65A36B, 0x0000000000000000, 0x0000000000000001,
+	0x000000003ED79790, 0x3FA7665B51948BA0, 0xBFCBE4D81062F630, 0x400222FB4D3DF98F,
+	0x0F183D73A3DCD4FF, 0x000000007501895B, 0x00000000009A9610, 0x0000000000000000,
+	0x000000003ED6C578, 0x3FEE54279B339405, 0x3FC3B72D524ADAAC, 0x3FF4502A92182FE8,
+	0x6C74A4EB1DA3C7D0, 0x0000000026B3EB7C, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F0BC102, 0x3FE67F86F4A8F3C7, 0x3FD011D3CB2B896E, 0x4001078648976242,
+	0x4FF0C94C06662B2D, 0xFFFFFFFF90C7A69A, 0x000000000047F9EC, 0x0000000000000001,
+	0x000000003EA961BE, 0x3FEDB1F0819F6E99, 0x3FCCE6AB4CBAA921, 0x3FD74FF55A9BCFD8,
+	0x48DD4A4BEFFA5AF0, 0xFFFFFFFF82A7663F, 0x0000000000000007, 0x0000000000000001,
+	0x000000003E74817C, 0x3FE05B81D4E29230, 0x3FED4B9C74305D12, 0x3FED9328CA605E0E,
+	0x14B8D3D702CC49C8, 0xFFFFFFFFF663C49C, 0x0000000000452A9A, 0x0000000000000000,
+	0x000000003BE9FC00, 0x3FE52CED2C0CC3C7, 0xBFF31A679EFCA80C, 0x3FBA466523BF4350,
+	0xCBFFE184B81079E2, 0xFFFFFFFFF1419D0A, 0x0000000000000016, 0x0000000000000000,
+	0x000000003E81C74C, 0x3FBA4A1D598A1478, 0xBFEFA948A021D428, 0x3FF4F8EDC9DAD52A,
+	0x3BD177C286399F14, 0x000000006531F838, 0x00000000007C83CA, 0x0000000000000001,
+	0x000000003E97874C, 0x3FC8B68B5EDDABFC, 0xBFE2FE969B4DF2FB, 0x3FDAD934D389521F,
+	0x5641E6E61430B5E6, 0xFFFFFFFF9B61619D, 0x00000000000000BE, 0x0000000000000000,
+	0x000000003D86F050, 0x3FC77D5B5770D80C, 0x3FC3481729EFC6CB, 0x3FF70AD2D7E1203D,
+	0x714FB0F25EB1C94D, 0xFFFFFFFFEF082CDB, 0x00000000003FD484, 0x0000000000000001,
+	0x000000003DF28598, 0x3FCB5201361AFBEC, 0x3FF49668745CC02A, 0x3FC97793F230A3CA,
+	0xFCCFD8D96700C083, 0x000000002EDE6569, 0x00000000000003E5, 0x0000000000000000,
+	0x000000003EAA41DE, 0x3FEE29AE60113A05, 0x3FD9ED829AADF61F, 0x3FF35D3245DBE423,
+	0xC21BD61F02306B4F, 0x0000000067616A27, 0x000000000053F8BC, 0x0000000000000000,
+	0x000000003E8C2C72, 0x3FE1E28DFC221B12, 0x3FF7D417CA727A0A, 0x3FE392C69E7DB51C,
+	0xD8FE65BC3CD1B8EE, 0x0000000020E52181, 0x0000000000000A48, 0x0000000000000001,
+	0x000000003BEFCB00, 0x3FD3F3DFE4B3FB78, 0x3FEDD7DA171C0826, 0x3FFACDB037D420CB,
+	0xFEE22BAECEC6882A, 0x00000000378621B7, 0x0000000000A9139C, 0x0000000000000000,
+	0x000000003F198958, 0x3FDED58C731A408E, 0xC000FE2C35D07C0F, 0x3FC8EF4C66AF5950,
+	0x33A70EA0548F3B8A, 0x00000000697D1A46, 0x00000000000016F1, 0x0000000000000001,
+	0x000000003C91AF80, 0x3FC61A9552E77310, 0xBFEA38793FC50E92, 0x3FE67A0E661A8650,
+	0xC3152795A887CBB4, 0xFFFFFFFF8699793C, 0x0000000000067792, 0x0000000000000000,
+	0x000000003EB292DE, 0x3FD1AAF40BD8ADA0, 0x3FEEAC1CE3DB760F, 0x3FEFAE6581A99067,
+	0x43E7FE6EA18153F7, 0x000000006AF5D426, 0x000000000000E694, 0x0000000000000001,
+	0x000000003F238F23, 0x3FC2A6FF0DEABF3C, 0x3FBB8CF32AC3A0D3, 0x3FDF911029151BE3,
+	0x03C398A388CD441E, 0xFFFFFFFFEFF9D735, 0x0000000000005414, 0x0000000000000001,
+	0x000000003F50A2E5, 0x3FE1CF5A8556431E, 0xBFDB82012D4F5424, 0x3FCC77F77E1148EC,
+	0x0F637799445E626A, 0xFFFFFFFFCA683DB7, 0x0000000000004AD1, 0x0000000000000001,
+	0x000000003F7EFD53, 0x3FD180932E1A3DE6, 0x3FE9BD1152AE8BC1, 0x3FE59840E84ED4C5,
+	0x80CE760DA06237C9, 0x000000001360964E, 0x0000000000414849, 0x0000000000000000,
+	0x000000003DDB1500, 0x3FE0B7F2111A0029, 0xBFECBC4324644385, 0x400BD3F01528D3E3,
+	0x61D1FBDBD174C477, 0x0000000063E0808C, 0x0000000000066D0A, 0x0000000000000000,
+	0x000000003ED18E12, 0x3FEB7D5EE040D3DB, 0xBFE5875F06CFB088, 0x3FB829671BF44285,
+	0x9620D688CC158BA9, 0xFFFFFFFFE55ABDE8, 0x0000000000DE23FD, 0x0000000000000001,
+	0x000000003E93493E, 0x3F81249EC1C28EC0, 0x3FFEA2C3BFA5EFAD, 0x400337447FD19B05,
+	0xE7BA217E12F9B553, 0x000000002D88A9D1, 0x00000000001324FC, 0x0000000000000000,
+	0x000000003F28734B, 0x3FE0AEB0C773002B, 0x3FD63DF52C6FACF8, 0x3FCF5C7379BD3923,
+	0xA161C47D325F50FC, 0xFFFFFFFF9372A3DB, 0x0000000000748EE5, 0x0000000000000001,
+	0x000000003E44A3E0, 0x3FD3DF2E53204D6E, 0xBFC2F6FE90C96A5C, 0x3FFFF4C3F2F16A1E,
+	0xD39918998F6565E6, 0x000000004AEBDE5D, 0x0000000000530D65, 0x0000000000000001,
+	0x000000003F648710, 0x3FC0F8A000B18318, 0xC0048335F68CFA9E, 0x400196E2CB6B5302,
+	0x98BB5129F902BDCB, 0xFFFFFFFFE1818106, 0x00000000000D49BF, 0x0000000000000000,
+	0x000000003F05A6D8, 0x3FEE491CA2D1AE8B, 0x3FE1B8D6F33A7D84, 0x3FE7E0886C09BFD3,
+	0x9DD87A3C88347307, 0xFFFFFFFFD8FE3EDA, 0x0000000002B1A223, 0x0000000000000001,
+	0x000000003F51F70F, 0x3FCAAE7D8A92BC48, 0x3FF79EBC70557BDF, 0x3FF34515913E0693,
+	0x373DC3FB23446E2C, 0x000000003AEF1551, 0x00000000004B5FCD, 0x0000000000000000,
+	0x000000003EFA81E2, 0x3FAF693F29460290, 0x3FAD1365DC3A38F9, 0x3FE76D9A3C5B31F6,
+	0xD6B3EB1E9C926BD5, 0xFFFFFFFFEBD38F65, 0x000000000F8C4937, 0x0000000000000000,
+	0x000000003F2D1C58, 0x3FE0A2E28A6DAB0E, 0xBFE25D619719E185, 0x4008851F9D257003,
+	0xE7AA04EC2E308097, 0xFFFFFFFFAD2CDEB6, 0x00000000001E0222, 0x0000000000000001,
+	0x000000003F3FA887, 0x3FE008667FC19B0C, 0x4009AF24CD719DCD, 0x3FEC51D15C8FB701,
+	0x84DA2117664C0392, 0x0000000035D41FB2, 0x0000000019610A10, 0x0000000000000000,
+	0x000000003F2314CC, 0x3FA27C200C069A40, 0xBFF24375803582A0, 0x3FE4E24B540355A9,
+	0x521FD8EEEB454083, 0x0000000025B9C9D8, 0x000000000069B160, 0x0000000000000001,
+	0x000000003EA88E82, 0x3FC58F90EBB90674, 0x3FD46828B2CB6D4C, 0x4008DC10C9620E2A,
+	0x7DEC92833D3715D9, 0xFFFFFFFF800D6F2F, 0x0000000000000001, 0x0000000000000000,
+	0x000000003DC9DD98, 0x3FE8F8EFD63BD778, 0xBFF874D3BC080215, 0x3FF4986EB35B8C98,
+	0x8545BEC61E68BDEE, 0x00000000667DA0B9, 0x00000000008EA5EC, 0x0000000000000000,
+	0x000000003F735587, 0x3FD56E2CBEA4BB16, 0xBFE4EE91AA6329DF, 0x3FF130294A6DAA14,
+	0x43E5862E98DB6462, 0x000000003BC784D1, 0x0000000000000006, 0x0000000000000001,
+	0x000000003EAA15B8, 0x3FE6B251006CE4BB, 0x3FD1C971454B5743, 0x3FC7D81874CCC51A,
+	0xB6DAC44C2A18B9C0, 0xFFFFFFFFB24D90FF, 0x000000000040916A, 0x0000000000000000,
+	0x000000003F324398, 0x3FEF030C986DE678, 0x3FF88BE94DEEB365, 0x400A3A515693248B,
+	0x494068C9FB6CADC2, 0xFFFFFFFFFAB01BCF, 0x0000000000000016, 0x0000000000000001,
+	0x000000003F274F9D, 0x3FE1324A034DE57D, 0xBFC196A463F7506C, 0x3FCA4CF8495DAC1C,
+	0xEA944D53C54D263E, 0x000000004A61D519, 0x000000000053ABE7, 0x0000000000000001,
+	0x000000003E66D8F4, 0x3FEB83914CAADCE6, 0x3FF18E591BAE8150, 0x3FFEBB1BD2824FF8,
+	0xC5F15973927DC492, 0x0000000031C9D741, 0x000000000000000B, 0x0000000000000001,
+	0x000000003E4477F0, 0x3FD451A89878EF66, 0xBFEC5F1F5C768F2A, 0x3F89BA32493B4011,
+	0xD62AD7DB2D3F0971, 0xFFFFFFFF938B91C7, 0x000000000075AFF0, 0x0000000000000001,
+	0x000000003F19D613, 0x3FD02CBA45E1C524, 0xBFEF7591DFFE2769, 0x3FEF52DBCE8446AE,
+	0x63620494731FED8A, 0x000000000DEDC8FA, 0x00000000000000E1, 0x0000000000000000,
+	0x000000003F330EC2, 0x3FEC60BE5698B619, 0xBFE4881E7B343C4F, 0x3FE311B55C2C2B09,
+	0x81F387DF71A708F6, 0xFFFFFFFFBBDF8982, 0x000000000070E1D8, 0x0000000000000000,
+	0x000000003E8A7B76, 0x3FE456EC880BD858, 0xBFF01576A65DA2B5, 0x3FE3C07CF95A6811,
+	0xB92371016B20AD87, 0x0000000047C9F651, 0x000000000000059B, 0x0000000000000001,
+	0x000000003EAF6746, 0x3FE346A4036F410E, 0x3FE9D6DBD6BBCB40, 0x3FDA805BFB3BCE3F,
+	0x7D1EE2CD698A37B9, 0xFFFFFFFFBB6AAAE8, 0x00000000001C5838, 0x0000000000000001,
+	0x000000003F516043, 0x3FEDDE983C986609, 0x3FF4CCB360953DE9, 0x3FF76F0D95E0B6AD,
+	0x388EABEF09705063, 0x0000000003555EEE, 0x000000000000194A, 0x0000000000000000,
+	0x000000003E940E82, 0x3FC5CE5E701A06C8, 0x3FEF1EA873922936, 0x3FE7694442A47E79,
+	0x90B48637D0FA1EFB, 0x00000000476CBC46, 0x0000000000FD619E, 0x0000000000000000,
+	0x000000003F5425DA, 0x3FEBAD822CCA1653, 0xBFF0C686BE1C2685, 0x3FA1E332BFB179D6,
+	0x4DCCE251672828AA, 0xFFFFFFFFE5120B4F, 0x0000000000001871, 0x0000000000000001,
+	0x000000003F5BA0DA, 0x3FDE3B23AAF2E726, 0xBFE93A5D4655D8E3, 0x3FB493B4F0A022B5,
+	0xF40042D868A990CE, 0x0000000016889F3F, 0x0000000000C69DC4, 0x0000000000000000,
+	0x000000003F33AFFF, 0x3FD720B88A964A2E, 0xBFE4DAE40DE6396C, 0x3FF6F9D366D810C0,
+	0x407A4BA9D7472BF9, 0x0000000063CAE431, 0x000000000000E157, 0x0000000000000001,
+	0x000000003D868FE0, 0x3FDF909CFCD3D300, 0x3FC144844BE48E88, 0x3FEFF7ECD639EE7E,
+	0x0BC813A2D83FDD17, 0xFFFFFFFFCEE9C485, 0x00000000010932CA, 0x0000000000000001,
+	0x000000003CCE8020, 0x3FD6EE9E222E4B96, 0x3FD8B439D5114222, 0x3FE2D29FCE469FE9,
+	0xD9C14B46761F2DD5, 0xFFFFFFFF9E6EABC3, 0x000000000004A508, 0x0000000000000001,
+	0x000000003D322000, 0x3FC56A50D225456C, 0xBFB7BA951182E237, 0x3FD3DDE4EDB0D5F1,
+	0x1F735CE0C5ED1A02, 0x000000001EEAEBA9, 0x0000000000C8A493, 0x0000000000000001,
+	0x000000003ED3E60C, 0x3FD7D48D3D9A77BE, 0x3FF1EB5AD1E46748, 0x3FEB6E31F62C0BCA,
+	0x7DC69E1BCE97DF06, 0x0000000000174611, 0x00000000000D67F5, 0x0000000000000000,
+	0x000000003F6A1FF3, 0x3FE3889FFD72E94A, 0xBFE056DA6810B3C6, 0x3FCBC262A806264B,
+	0xD95A989B164DF8C6, 0xFFFFFFFF9BB65ECC, 0x00000000001D499F, 0x0000000000000000,
+	0x000000003CB56D20, 0x3FE77F659496C4F2, 0xBFD9774E1080225C, 0x3FE2631CE1040C48,
+	0xBFF3691C6D9F313B, 0xFFFFFFFFED6EC5C3, 0x00000000006F6364, 0x0000000000000001,
+	0x000000003F1DE5CC, 0x3FD8837A3409FA1C, 0x3FC45EC419332FD5, 0x3F7F343DDBACC099,
+	0xCA050B9FA687F389, 0x000000000DDF0A64, 0x000000000005627A, 0x0000000000000000,
+	0x000000003F109900, 0x3FEBADB5BC9B9C4A, 0x3FE8A89A37B84A6F, 0x3FD496DD90450D58,
+	0x07FF7B73F052D833, 0xFFFFFFFFFAEAF156, 0x0000000000C85709, 0x0000000000000000,
+	0x000000003EA17ADC, 0x3FEA6941D47B54BA, 0x3FF31690EA96A113, 0x3FC04B961959AFAB,
+	0xAD9D56BB7E320EAD, 0x00000000070CC045, 0x000000000026F160, 0x0000000000000001,
+	0x000000003DD69530, 0x3FEC4D593A47356A, 0x3FEB06138155EB4D, 0x400105E73BD4B300,
+	0x241E9400D5FAADD6, 0xFFFFFFFFA2D7F77C, 0x0000000001147414, 0x0000000000000000,
+	0x000000003E98B2A0, 0x3FE4710B585FBFC6, 0x3F61AB2312FCD200, 0x3FEAE22A6E378D43,
+	0x19718854F9BD6E7C, 0x000000007FDC71A7, 0x0000000000A3F3EF, 0x0000000000000000,
+	0x000000003E146AB4, 0x3FE7AE010EA0355B, 0xBFC3F0710EE951D0, 0x3FCFBF927C9AC8C8,
+	0x8E5C28E6AC57B7C1, 0x00000000626A38D6, 0x0000000009FBA04F, 0x0000000000000000,
+	0x000000003E9EFD8A, 0x3FD6DF5586D5F5AA, 0x3FDF56A7C3A30AF7, 0x3FCBE4DFB8599BA2,
+	0xEE53CE6452587D1D, 0xFFFFFFFFB2205B64, 0x0000000001157C27, 0x0000000000000000,
+	0x000000003E26FDBC, 0x3FDC81EBB1E5C900, 0xC000FC0AAE0CCCD1, 0x40012FA092F0F3D8,
+	0x93B17E74B08E25C8, 0xFFFFFFFFB728C904, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F613802, 0x3FE4245EAF427D63, 0x3F9698C17A8248FF, 0x3FF44AF939655D8C,
+	0xC6BD4F70521218BD, 0xFFFFFFFFBBC4918D, 0x0000000000D60E32, 0x0000000000000000,
+	0x000000003F705049, 0x3FEDA0260112E0B5, 0x3FFD6B7D71AC8C19, 0x3FE4B80BFC89A0FA,
+	0x980AE9798713A92F, 0x0000000009099056, 0x0000000000000003, 0x0000000000000000,
+	0x000000003F0047DC, 0x3FEBECB71B454DB0, 0xBFA46E067E1E1D33, 0x3FDDF2921D8E1099,
+	0x5A67B76F5E86B563, 0x0000000006503A1D, 0x000000000117609C, 0x0000000000000001,
+	0x000000003F73CF86, 0x3FC57C7B43FD7964, 0xBFFEB60328533DCA, 0x3FCD7579CCB40C4A,
+	0xEF90F3C53890EB80, 0x000000001FA01E2F, 0x0000000000000001, 0x0000000000000000,
+	0x000000003DD5EB48, 0x3FD2344AEADAF7F2, 0xBFF3250B45CB4725, 0x3FC03448848CDDB1,
+	0x607213E3249509BD, 0x0000000053A6D755, 0x00000000009D9385, 0x0000000000000001,
+	0x000000003EC5A6B6, 0x3FD5289A58834064, 0x3FE467F36AE23735, 0x3F98E564BF982E65,
+	0x652B46AED8FE1BEF, 0x00000000534188F2, 0x0000000000000015, 0x0000000000000001,
+	0x000000003E98155C, 0x3F96AC580ED07160, 0x3FF7B12BD9E76058, 0x3FD996C063ABC30F,
+	0x44474A2C52CCB4EE, 0xFFFFFFFFD8337A1F, 0x0000000000800AD1, 0x0000000000000000,
+	0x000000003EC68352, 0x3FA428BC11B9EB90, 0x3FC04671CEE2AF77, 0x3FB68BF76A8E203D,
+	0x459C6BA7A793426F, 0x0000000075D5A918, 0x000000000000007C, 0x0000000000000001,
+	0x000000003E23E8DC, 0x3FD5C3CE67F161C8, 0x3FF354D7545DB200, 0x3FEA8E27B635D0EE,
+	0x99A5B20B3BE53CBD, 0xFFFFFFFF91A3DE01, 0x000000000044FE9A, 0x0000000000000000,
+	0x000000003F7C9D3A, 0x3FD9A345B4BAC7CC, 0x3FF137CE0122ABA2, 0x3FBA0FC949B08FF6,
+	0x63C2513FFAD707A8, 0x0000000063254543, 0x00000000000002D9, 0x0000000000000000,
+	0x000000003F485FBA, 0x3FB978F90BC6FA38, 0x3FE3AABE27DDC234, 0x3FF06E9BA4665B82,
+	0xEC759AC93AE98F85, 0xFFFFFFFFDD21B1BA, 0x000000000073798B, 0x0000000000000001,
+	0x000000003F4DBC2E, 0x3FC25338F6FC9FD4, 0xBFFEB93B4A2A7E3B, 0x3FE8E34AA7F536FE,
+	0xE06FC4E2FEDD9A64, 0xFFFFFFFFA29F7A84, 0x0000000000000049, 0x0000000000000001,
+	0x000000003ED2EE3A, 0x3FD45628FCE09A7C, 0x3FDDEE03B45DCF26, 0x4000D6F4EAC5D191,
+	0xEA7AC0C493036F16, 0xFFFFFFFFFA880019, 0x0000000000044E3B, 0x0000000000000001,
+	0x000000003F39B29D, 0x3FE34AB14BF1E4F2, 0xBFCE219ABC1ABCF3, 0x3FE7272A73F69D19,
+	0xDFA54DCAABA08351, 0xFFFFFFFFC32119BD, 0x0000000000001442, 0x0000000000000001,
+	0x000000003CF32D40, 0x3FCB2D7F083AAD3C, 0xBFEE96AF20323AC7, 0x3FDE4E6FC2C8BF3F,
+	0xA806F6F2F7262659, 0x000000007532173C, 0x00000000000F7758, 0x0000000000000000,
+	0x000000003F694574, 0x3FD57C1BDC9E3DB8, 0xBFFC06214E6A338B, 0x4009608C08A8A038,
+	0x00DCFDCC8FFE2CAE, 0x0000000010B6B207, 0x000000000000DCDD, 0x0000000000000001,
+	0x000000003F4C3F37, 0x3FBBA0A428E7F720, 0xBFFD65775841F4C8, 0x4001A20316828E5B,
+	0x65CB11C2DCC882CB, 0xFFFFFFFFC1A47E37, 0x0000000000B359DB, 0x0000000000000001,
+	0x000000003F4DDF96, 0x3FE6D69332793124, 0xBFEA1F62ACFA976D, 0x3FCC7932DDE80085,
+	0x84F46D78ED02BE8D, 0x00000000303723D1, 0x000000000002C599, 0x0000000000000000,
+	0x000000003F7A0368, 0x3FEB2FCF7F78DF2F, 0xBFD309C1FF59698F, 0x3FDB3A5359809E31,
+	0x56376900333BF224, 0x0000000058C6BD4C, 0x000000000119B5D7, 0x0000000000000001,
+	0x000000003F111C44, 0x3FC3E9B4A218E534, 0xBFD35674A9F5FF34, 0x3FD6F24501F88923,
+	0xD0DF7100AB13EBB2, 0x000000003DF8937B, 0x00000000000DD8AF, 0x0000000000000000,
+	0x000000003EBDFC38, 0x3FD115104E28630C, 0xBFC5BA8CBA703339, 0x401CFC08B3C5ED0A,
+	0xB989F0044EECBEF8, 0x000000005F1034DA, 0x0000000000A9D1B0, 0x0000000000000000,
+	0x000000003F349DC2, 0x3FE3ED8C1585AD7C, 0xBFD71A857399C04D, 0x3FF842FB923B69E3,
+	0x10E7FFE9460F5625, 0x0000000068B9A115, 0x00000000001E40CF, 0x0000000000000001,
+	0x000000003F1E74D9, 0x3FE8F6A35415F2DF, 0x3FF014629FAC465B, 0x3FE0AF669D096442,
+	0x379F5C43F35C03C3, 0x000000001E01B504, 0x00000000011E4AA8, 0x0000000000000001,
+	0x000000003EE8227E, 0x3FDA1383F23CC0B6, 0xBFCE3D1BDC589C9D, 0x40105BE6501A4805,
+	0xF79A89168F67B4A6, 0xFFFFFFFF8E6AD571, 0x0000000000D3E438, 0x0000000000000001,
+	0x000000003F145059, 0x3FE95BADB672E48D, 0x3FD8A59998EC0921, 0x3FE7D1AE4D49EEC7,
+	0x5487DABB9B096799, 0xFFFFFFFFA1A77901, 0x0000000000C70184, 0x0000000000000001,
+	0x000000003EE662F4, 0x3FE6A683A04B0516, 0x3FF32E7CB930D707, 0x401378D8C9E793BC,
+	0xFBEB761B69800E6A, 0xFFFFFFFFDC0059E8, 0x0000000001BEDE1D, 0x0000000000000000,
+	0x000000003F47D14C, 0x3FD6E4C810FCBA84, 0x3FC62E0AC978F806, 0x3FE06B2FD7E11C62,
+	0x63F3D887DA28F5AC, 0x000000000616F9F2, 0x000000000082CF87, 0x0000000000000000,
+	0x000000003DFD36D0, 0x3FEE4D3C8F372221, 0xBFE46A5207B5AAF5, 0x3FE17CACCE35CFB2,
+	0x21F7A598FEC31E22, 0x000000006BCA5149, 0x000000000600A297, 0x0000000000000001,
+	0x000000003E81A46A, 0x3FE50F9F7F320CA1, 0xBFFAE8DEF0ACA3A8, 0x3FF81072C36AFEA4,
+	0x558593F8BEDCDDA9, 0x000000001B64E63D, 0x0000000001077C77, 0x0000000000000000,
+	0x000000003E55EDC8, 0x3FA1E5F6E0BA2D50, 0x3FE297F0FD3CF19B, 0x3FC048769DD61957,
+	0xF2BA848B47685949, 0x00000000518EE4D5, 0x0000000004CA8685, 0x0000000000000000,
+	0x000000003F4AD2A7, 0x3FC17BCACFEC7BE0, 0x3FF93FCE86EE1C3F, 0x3FFA628D000D8D75,
+	0xBD1BD0A6277E45EF, 0xFFFFFFFFD1D1902A, 0x0000000000F17730, 0x0000000000000001,
+	0x000000003ECECCD8, 0x3FDAF34553ED186E, 0x3FDAEE7BE7731477, 0x3F6C14D234ACCC9E,
+	0xC8BA82171DBA46CD, 0xFFFFFFFF92AE1FF1, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F3DA0D1, 0x3FD1F55DD80FD8AA, 0x3FD95975EA7574D6, 0x3FD7B5D7384A1D2F,
+	0x31108861C1B406FB, 0xFFFFFFFFCE311D43, 0x0000000001345EDE, 0x0000000000000001,
+	0x000000003F0E8CF3, 0x3FD5543CA357A9B0, 0x3FF9320CACEBD736, 0x3FCAB1605C7AA315,
+	0x5DDB062C2DC18C1B, 0x000000004BC5A903, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F21DB85, 0x3FD2488152302360, 0xBFDB305586D0E6BC, 0x3FDA23E399493B4B,
+	0x5ABF4E3B42F18728, 0x0000000003BA7D13, 0x0000000001382DB1, 0x0000000000000001,
+	0x000000003B9C8600, 0x3FE31471EF9634C4, 0x3FEC7004356E9C0D, 0x3FBE9A78BA664CC5,
+	0xACE62B92EE020280, 0xFFFFFFFFCBFA452C, 0x0000000000000002, 0x0000000000000001,
+	0x000000003F7B8330, 0x3FA26B3C6B78BAC0, 0xBFEF914DD912C763, 0x3FE8EC0BC6265644,
+	0x799CBD3CF1EA4A86, 0xFFFFFFFFF07AB426, 0x00000000001E12EA, 0x0000000000000001,
+	0x000000003F7B2CA6, 0x3FC019E196D0B5FC, 0xBFFF5771264A3577, 0x3FB7DC5CEBF35E2A,
+	0x454A589E9F378C70, 0xFFFFFFFFEC7CEF1D, 0x000000000000004A, 0x0000000000000000,
+	0x000000003F5DC141, 0x3FC10D997940C504, 0xBFDD0083509889BF, 0x3F62F886BDFBB147,
+	0xC6EA74C93D580A17, 0x000000002767CCFD, 0x0000000000DEA982, 0x0000000000000001,
+	0x000000003F0D402B, 0x3FED32E4D4EAF73F, 0x3FE701E25A5494C4, 0x3FC6888779290CA0,
+	0xC46DC4B230D139B6, 0xFFFFFFFFC1ECC3B6, 0x0000000000000139, 0x0000000000000000,
+	0x000000003DBB4810, 0x3FEBF52E3657D13F, 0xBFECF244B0423D63, 0x3FDF1F0BA7612A9B,
+	0xEC621BD18FBFB03C, 0xFFFFFFFFE293AA44, 0x00000000003ACF2D, 0x0000000000000000,
+	0x000000003F6B4573, 0x3FAC976C4FA5A640, 0x3FC2C95DF9BAE610, 0x3F9A630B7FAE3679,
+	0x0E0003218F441517, 0x000000001B3D3088, 0x0000000000000556, 0x0000000000000001,
+	0x000000003E5F84DC, 0x3FE3D9C5BBE972F9, 0xBFC66C69F8C5A7D5, 0x3FF6370B2F636EE5,
+	0x344B43FE65FF2CF6, 0x0000000075E23D80, 0x000000000073C913, 0x0000000000000000,
+	0x000000003F749270, 0x3FA2052EAA7586D0, 0xBFC999196C155E41, 0x3FFA037795E07585,
+	0xBE69D62CB76F38C9, 0x00000000513EB2DC, 0x00000000000016BC, 0x0000000000000000,
+	0x000000003F51EF68, 0x3FEBB4763E6AD309, 0xBFEE2598455A2A82, 0x3FCDE3C6C5D7D34A,
+	0xB287B6D2F0140018, 0xFFFFFFFFCB8A8B49, 0x0000000000EEE6A8, 0x0000000000000001,
+	0x000000003F1AA128, 0x3FC0112732BF5DE4, 0x3FE8EE7BF715526E, 0x4002965E37ED3C9B,
+	0x5F0C50E639068547, 0x000000003E57032B, 0x0000000000003FAC, 0x0000000000000000,
+	0x000000003EDD9AE0, 0x3F9A7811BE158A00, 0x3FADF0429EE16905, 0x3FE7DD6ED52A64E6,
+	0xCF8D3226F317497D, 0xFFFFFFFFA3F8E050, 0x000000000027E1A0, 0x0000000000000000,
+	0x000000003F34812A, 0x3FD28C33592B935A, 0x3FFAB6950315E8B1, 0x3FDBEDA9C629B82F,
+	0x0E07A5ACAFE2E088, 0x000000006E6AD8F2, 0x00000000000144A9, 0x0000000000000000,
+	0x000000003EC76A4C, 0x3FE0229BD904C7AA, 0x3FCAF6599BDF3F98, 0x3FF1452A8AB47023,
+	0xF0065E8132D6FC26, 0x0000000064CDD12E, 0x0000000000A5F212, 0x0000000000000000,
+	0x000000003EFC57A4, 0x3FE3ED03320495F9, 0x3FE6397111F2CBAD, 0x3FBE7BA187266411,
+	0x4C33A10006CC4592, 0xFFFFFFFF85E265E6, 0x000000000004C35C, 0x0000000000000000,
+	0x000000003F271ABC, 0x3FADDAF78D66C460, 0x3FE0CF264A16612E, 0x3FC28BC14E97E834,
+	0x6E68B6A3C8B7C9A5, 0xFFFFFFFFD2A32966, 0x0000000001739B60, 0x0000000000000000,
+	0x000000003F44C89C, 0x3FD4296C25C80878, 0x3FD16CD4BDFAE54A, 0x4003EE2249B5B7A5,
+	0x50BDE3771225201A, 0x000000003611377F, 0x0000000000099B4C, 0x0000000000000001,
+	0x000000003F6100E4, 0x3FD96033AC70D986, 0xBFF63780FF8F6166, 0x3FFE6BE58D37B4E0,
+	0xC1E16C6DBC5DE731, 0xFFFFFFFFE618A54F, 0x0000000000FBCC65, 0x0000000000000001,
+	0x000000003DC51750, 0x3FD6DCF656144AB8, 0x3FE74C934AD50E32, 0x3FDED666A0757C97,
+	0x1D310A803D3FF1C3, 0xFFFFFFFF9C8E800A, 0x00000000003816D8, 0x0000000000000000,
+	0x000000003DF50818, 0x3FE1EC188D8FCB6B, 0x3FEC6C5B5C005C6B, 0x40005CBBFF5A3517,
+	0x5FDEA1F705EEACCA, 0xFFFFFFFF9DF9CF31, 0x000000000172DDC9, 0x0000000000000000,
+	0x000000003F14A698, 0x3FEA4C9F484BDC59, 0xBFDEC86E58F799F8, 0x3FD57546AE30790E,
+	0x2E5FAEFF5282DECC, 0x000000006998B12C, 0x0000000000ECA783, 0x0000000000000001,
+	0x000000003ECF2C0C, 0x3FE984FC0D1CEB03, 0x3FC97239BBE02991, 0x3FD8071C2FF5AE75,
+	0x2CC7331C990E7E33, 0x00000000062FFBF4, 0x0000000000F265AB, 0x0000000000000000,
+	0x000000003EA7F69C, 0x3FD49FB66DBA6924, 0xBFDA129C54B31559, 0x3FE3775DB36156D1,
+	0xE2E870F48B95A411, 0x0000000005AD8958, 0x00000000019F9CE5, 0x0000000000000001,
+	0x000000003F2B2350, 0x3FBD28DE5F9AE328, 0xBFFF9302DD969256, 0x3FF28538C50A3957,
+	0x9717F237F2B39394, 0x000000007E6D8D25, 0x0000000000DA6927, 0x0000000000000000,
+	0x000000003E9E4B8E, 0x3FC67F97B16ED0F0, 0x3FF331091D35DED5, 0x3FCB6D6D6C476698,
+	0x8646FB51E256E7B2, 0x000000005CFF91E9, 0x00000000059AE230, 0x0000000000000001,
+	0x000000003F637211, 0x3FD02F74378BC914, 0x3FC3E0815F40ACD4, 0x3FF982B188D16D69,
+	0xF4025D6D4D937EB5, 0x000000000E0AE48E, 0x00000000011C4382, 0x0000000000000001,
+	0x000000003E000DCC, 0x3FED4F1407C38025, 0x3FF610B940A1EC97, 0x3FDA7A60D0D620A2,
+	0xAEB61677DBBA128C, 0x000000003518C0B4, 0x0000000000000000, 0x0000000000000000,
+	0x000000003E853904, 0x3FCE38C39EAA8744, 0x3FF9C83660C8C99B, 0x3FF5FDA7FDCC5448,
+	0xFAE3C9F32920E585, 0xFFFFFFFFFDFAAE91, 0x00000000013583A9, 0x0000000000000001,
+	0x000000003F704190, 0x3FD31D96F04BC368, 0x3FF26B63B167A345, 0x3FF14FD277044189,
+	0xD71B00F6D0C28EAA, 0xFFFFFFFFAE4ED562, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F5A7F64, 0x3FD026DEBDCCA97C, 0xBFFD50994306AC90, 0x3FA83EA11F33DD74,
+	0xE6DE7485D023FBAB, 0x000000005A702D1C, 0x0000000000CBCEA9, 0x0000000000000001,
+	0x000000003F17D1E5, 0x3FC9D8E42FB576D4, 0x3FEF21902ED451A5, 0x3FE0B88D45D08BDD,
+	0xC3B19D628C891353, 0x000000006F090FFD, 0x0000000000000007, 0x0000000000000000,
+	0x000000003F304042, 0x3FEB0EDE5FAE3750, 0xBFC485001F168F62, 0x3FE5C49A4A632CB2,
+	0xA69C93C704A24AF2, 0xFFFFFFFFD41F461C, 0x0000000001545CA8, 0x0000000000000000,
+	0x000000003F1674C4, 0x3FD59D336B9E7302, 0xBFC88E13D69766A7, 0x3FF217F2B50F0FA0,
+	0x06F82318BDFC8CA9, 0x00000000634CF884, 0x000000000000002C, 0x0000000000000000,
+	0x000000003F76A43F, 0x3FD60F325920C39C, 0xBFF2598B5ED172BF, 0x3FCDB67F2941BF85,
+	0xECDCBC04F3234502, 0x00000000786EB920, 0x00000000004A2A78, 0x0000000000000000,
+	0x000000003F0CBFC7, 0x3FE28DD294C0E865, 0xBFF0282E5DC7DF47, 0x3FF22197889B59A6,
+];
+pub const XOROSHIRO128PLUSPLUS_RAW: [u64; 8] = [0x0000000000060001, 0x000260C000660007, 0x180ACC04718606D3, 0x9E226D35036FC4C7, 0x849BC9AC6B960BE4, 0x31C5870FC130361B, 0x17790D7CD5B2E061, 0x94FC9BB11DA24A91, ];
+pub const XOROSHIRO128STARSTAR_SEED: u64 = 3075;
+pub const XOROSHIRO128STARSTAR: [u64; 2048] = [
+	0xCC4A2D48BCAF8970, 0x000000002A8C7E54, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F2A70D0, 0x3FD58616DE4AED4A, 0xBFE433DA5C488CD8, 0x3FF12F54419ED103,
+	0x885942B75BF7B735, 0x0000000042A401DA, 0x000000000000772A, 0x0000000000000000,
+	0x000000003F3C9467, 0x3FA8B2AA22F9AC40, 0xBFEEE2032644AC38, 0x3FF0D74937079E4C,
+	0x3A0AB8F4F4ADCC99, 0x00000000762576E0, 0x0000000000000003, 0x0000000000000000,
+	0x000000003F40CD60, 0x3FE2BFB5388D2AD5, 0x3FBC81BF2D10D57E, 0x4000378384E2E65B,
+	0x7B99C27B0B641F33, 0x00000000737FB9BC, 0x0000000000011779, 0x0000000000000000,
+	0x000000003EB46C86, 0x3FEA1C3E8E4A3862, 0xBFFD4F1362FEDFE7, 0x3FDB3C4C0ACFCA41,
+	0xEF16D48CC4941BB3, 0x00000000089135E4, 0x0000000000000008, 0x0000000000000001,
+	0x000000003EAF87F0, 0x3FECC135A4D585D7, 0xBFF03243293B64E9, 0x4012668564CB3B1E,
+	0x4CB5C7CEA7E5E81E, 0xFFFFFFFF863B5CF0, 0x000000000001FC64, 0x0000000000000000,
+	0x000000003EAB1816, 0x3FDF0EE8DF902738, 0x3FE97AE9B9E85EC7, 0x3FF9519F47569104,
+	0x5FD91BE4FE22B058, 0xFFFFFFFFC48AC3B6, 0x0000000000000002, 0x0000000000000001,
+	0x000000003F66DF5B, 0x3FBD610736144C70, 0x3FD50D09FF666DDF, 0x3FD0E57854F4A9FE,
+	0x5D6BC6E825F4B5F2, 0x00000000436D5FEA, 0x0000000000060B22, 0x0000000000000000,
+	0x000000003F400315, 0x3FC6BED51E8A9024, 0xBFF3186C27950D90, 0x3FC6EA2C6F05931C,
+	0xAFEE91450CC91FA9, 0x000000000AD288E2, 0x00000000000000B2, 0x0000000000000000,
+	0x000000003F3C7B32, 0x3FDD339A70C1B994, 0x3FF0D78E671420DD, 0x3FDFF2DF507FC180,
+	0xDF1BED464CA020CD, 0xFFFFFFFF8DB18127, 0x000000000006A445, 0x0000000000000000,
+	0x000000003EBC1CD0, 0x3FB822649A9D3C90, 0xBFFD952401302674, 0x3FA2D685AD3069AF,
+	0xA32A2524FB4AEBAB, 0xFFFFFFFFCD9A0164, 0x0000000000000197, 0x0000000000000001,
+	0x000000003F11168A, 0x3FEAE6B102670F62, 0xBFA13D85D537A5ED, 0x3FF7EF7B19599514,
+	0xDF41377D938D38A7, 0x0000000008978CF6, 0x0000000000118D07, 0x0000000000000000,
+	0x000000003E9501B0, 0x3FE3B29A20A4CCAD, 0xBFF38D69AF2A59EF, 0x3FB90817D649D39A,
+	0xFAF749EC0FA0F2A0, 0xFFFFFFFFD778B6BB, 0x00000000000002E9, 0x0000000000000000,
+	0x000000003F3C4A51, 0x3FE7B894CE5D281A, 0xBFE5A131FE6C3238, 0x4004364A2AB3DC01,
+	0xA1CCA0F7D4531FBD, 0x00000000041D5175, 0x00000000000550A6, 0x0000000000000001,
+	0x000000003EDF024A, 0x3FE114F48A3C6F83, 0xBFF59F6095EF44DD, 0x3FE3BAB4A7D09223,
+	0x7261D8DCC0FF9705, 0x0000000076EE3811, 0x000000000000226A, 0x0000000000000000,
+	0x000000003E88CE08, 0x3FE8ECF817EDEA9C, 0xBFF6850FF6CE0F7A, 0x3FC2F952B3E665FE,
+	0x697E932823C5EA7C, 0x000000001BE37C7F, 0x0000000000169418, 0x0000000000000001,
+	0x000000003F22A9BE, 0x3FEEFD2FFD94EE47, 0xBFE2D3D4AB7A0C3A, 0x3FBCF25C84FA51CC,
+	0xC63F653EC4637871, 0xFFFFFFFF88188F5D, 0x0000000000003A88, 0x0000000000000000,
+	0x000000003E8E33EA, 0x3FAC31A973D58EF0, 0x3FEA3374E5535391, 0x3FF643CCE9BDE286,
+	0x0DA42AD2543B5FA8, 0x000000001C617C12, 0x0000000000156D39, 0x0000000000000001,
+	0x000000003EA72C1E, 0x3FED830645A98DBB, 0x3FE70D6930B721CA, 0x400901DFB19F0304,
+	0x625DC1C7023A1F39, 0x0000000049604B84, 0x00000000000052B8, 0x0000000000000000,
+	0x000000003EC01A72, 0x3FEC0D7B8C4D0D24, 0xBFE88261E11B25BE, 0x3FE2C224A54699AF,
+	0x05910168349299BA, 0x000000005FA46377, 0x00000000001477F2, 0x0000000000000001,
+	0x000000003F5219D6, 0x3FE330FC92A443F9, 0x400068911CA32C7A, 0x3FB130D267A24B2D,
+	0x7F88147C6585C4E6, 0xFFFFFFFF8E106BDB, 0x00000000000CD93E, 0x0000000000000000,
+	0x000000003EE4F62A, 0x3FCE176D48D0B640, 0x3FF21BCD16C2EB2C, 0x3FD184E76958FEC1,
+	0x974016F41AB2EAAC, 0xFFFFFFFFD022AACA, 0x000000000000E012, 0x0000000000000001,
+	0x000000003CC4E200, 0x3FE2995E874EA759, 0xBFE2E6831AF782E6, 0x3FC4839A2621132A,
+	0x0C0492148D5F2B38, 0xFFFFFFFF8D1744A5, 0x000000000031984A, 0x0000000000000000,
+	0x000000003E030348, 0x3FEADD101F16651B, 0x3FE84B4D272AA5BC, 0x3FF7B08C7F278ACD,
+	0xD7929F52E3EAA331, 0x0000000020B8BFEC, 0x0000000000235995, 0x0000000000000001,
+	0x000000003E482FAC, 0x3FE9D519D5EEC50E, 0xBFD1186244681845, 0x3FEE6D1DE2DC200B,
+	0x82C59E2CD8EBA9C2, 0x0000000054225F04, 0x00000000007100E4, 0x0000000000000001,
+	0x000000003EC8B65E, 0x3FD5A08D2EAB78A4, 0xBFEFF560C5C880C8, 0x3FDF294E3BDE640D,
+	0x1738F5496220A75D, 0x000000000FC6543C, 0x000000000025F93F, 0x0000000000000001,
+	0x000000003D315410, 0x3FEC5B9A195643EA, 0x3FE3EBDEC123DDF4, 0x3FC753445DBD9281,
+	0xCC2711CE8388DC28, 0xFFFFFFFFA55792E8, 0x0000000001D01824, 0x0000000000000000,
+	0x000000003F1891EC, 0x3FE578113AC7DB2A, 0xBFD4874EFB27595D, 0x3FE8FC716752E197,
+	0x82E9E450B75F1FCC, 0x000000001006EDD8, 0x0000000000106106, 0x0000000000000000,
+	0x000000003D8C8198, 0x3FD30A4FF7220EE8, 0x3FDC9324932297DA, 0x40073A6F7D557942,
+	0x8CC8704E2652DF3B, 0x000000005AFAA4A2, 0x00000000044C77F8, 0x0000000000000001,
+	0x000000003EBCBCF4, 0x3FE2F8671F7A7CA2, 0xBFF072A855C28592, 0x3FE42CDFCCA35973,
+	0x2028B18CC2ECA473, 0xFFFFFFFFB9C7D219, 0x000000000015730B, 0x0000000000000000,
+	0x000000003F24D967, 0x3FE56F2B46086926, 0x3FF07CD37911F8CC, 0x3FE0B5C468FBE847,
+	0x65863021E246D7B1, 0x000000000813C7EB, 0x000000002A752A6B, 0x0000000000000001,
+	0x000000003F54092F, 0x3FA0A737B9CA2060, 0xBFD7A5517CB40398, 0x3FCA246A9BADA20A,
+	0x4C849850FAE0ADD4, 0x000000006E450B15, 0x000000000000CC86, 0x0000000000000000,
+	0x000000003F750FE9, 0x3FEE6DBEE664AA44, 0x3FF7F74E19719795, 0x3FC1A2A486669BE2,
+	0xBFEF49C5DFE525BA, 0xFFFFFFFFDD0E1E81, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F77A7DA, 0x3FE3F3E05A8E59AC, 0x3FD1064840EE728B, 0x3FEA215F034DBDC3,
+	0xED299BF89BEE4422, 0xFFFFFFFFBC1BA0C6, 0x0000000000282A9A, 0x0000000000000001,
+	0x000000003DB10FE8, 0x3FA712CE17DC4880, 0x3FF14A6B8D0A729C, 0x3FC171903B5F39A2,
+	0x67DB21ED5199EAF1, 0x0000000040BC99CB, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F33254B, 0x3FCE942BEC127B90, 0x3FE6866D3FE1A97B, 0x3FE4F4535EA138A9,
+	0x668EF81FD0960C55, 0x000000003FFF2E1B, 0x000000000021414F, 0x0000000000000000,
+	0x000000003DD9CF38, 0x3FEFAB80F4E07478, 0x3FF4D839D003991E, 0x3FF0771535715C9D,
+	0x6BA27A7FD0F646F5, 0xFFFFFFFFB88F796E, 0x0000000000000001, 0x0000000000000000,
+	0x000000003E00D7E0, 0x3FEDB0F1ADFEED35, 0x3FE2C36ED54C3723, 0x3FE0854C7837147B,
+	0x266A41FEAB9A52B7, 0x00000000331020EF, 0x0000000000043083, 0x0000000000000001,
+	0x000000003D35B1E0, 0x3FE50CE226D9D323, 0xBFFE87CFC342A2F2, 0x3FCC40CA435741F9,
+	0xF2C037D37252605A, 0xFFFFFFFF893FA05A, 0x0000000000000024, 0x0000000000000001,
+	0x000000003F203DC3, 0x3FD7F07562470856, 0xBFDBB1DF8DE3E975, 0x3FCAF049EE2955EB,
+	0xFB3F7D732826F895, 0xFFFFFFFFC5C5807F, 0x00000000001CF467, 0x0000000000000000,
+	0x000000003EF0BBE4, 0x3FA97EC314A44D60, 0xBFE3D21A94BA8A3F, 0x3FEE31B0C176F17B,
+	0xE6D6DC47CB1040DE, 0xFFFFFFFFB8F8B215, 0x000000000000011B, 0x0000000000000000,
+	0x000000003E36ADEC, 0x3FD1382B5FC13D9E, 0x3FF7251F0051A3A5, 0x3FB979E1027FFE14,
+	0x08B8A8C2299912B8, 0x00000000677072A9, 0x000000000035C136, 0x0000000000000000,
+	0x000000003D2BF8B0, 0x3FEB5484C8C75FA5, 0xBFE57C38F0CA950C, 0x3FD7EB40E2D1A5AB,
+	0xB2100FC07B157585, 0x00000000764CEDCA, 0x000000000000078A, 0x0000000000000000,
+	0x000000003E1A5854, 0x3FE6093A1A74F511, 0xBFF18F19877F2C8F, 0x3FFB535955E55806,
+	0x013D41AE8B905308, 0x0000000053EF8572, 0x00000000002B435A, 0x0000000000000001,
+	0x000000003E66BC0C, 0x3FE0EBA0FFBCBE24, 0x3FD1C9AC9110F33B, 0x3FC7047B2A668692,
+	0x158286370DDDE3AB, 0x000000004EB95967, 0x0000000000000B28, 0x0000000000000001,
+	0x000000003F5742C2, 0x3FE28ED9859FA1E5, 0x3FF136182A6BCA80, 0x3FDC7CF3122D8ED3,
+	0xE21CBBB2281EF37C, 0xFFFFFFFF81783FD8, 0x00000000002819BE, 0x0000000000000001,
+	0x000000003F2439FA, 0x3FBD32403C5FAB60, 0xBFF7C338E0980E7A, 0x3FE2AED24E1BFA1B,
+	0xBF1EC9FBD1AF600A, 0x000000005194A4EB, 0x000000000000238A, 0x0000000000000000,
+	0x000000003F219EF3, 0x3FE74A5CB887EEA9, 0x3FBAF8D93B3E8214, 0x3FF2B6671F796F01,
+	0xE331B1BE4ECAEA93, 0xFFFFFFFFF3C7F07C, 0x0000000000414975, 0x0000000000000001,
+	0x000000003F4A6B06, 0x3FECC745C0F40775, 0xBFF1F80444729C3E, 0x3FE3CFA4B6986481,
+	0x048FA2422670EDC3, 0xFFFFFFFFB84B8AF7, 0x000000000001704D, 0x0000000000000000,
+	0x000000003D1B0830, 0x3FEF4F44EDAFC8C8, 0xBFD08669FB5FB266, 0x3FE88B7CF6579F15,
+	0x23E81684961C0B99, 0x000000003F0E8D6E, 0x000000000024CF67, 0x0000000000000000,
+	0x000000003F130A13, 0x3FE9B1A34E6EE859, 0xBFCA7C4B04ADDACA, 0x3FD2B3795E84029E,
+	0x36CDE7BEAF840C89, 0x00000000659D63FC, 0x0000000000065ADB, 0x0000000000000000,
+	0x000000003F4D0670, 0x3FC5967CB417230C, 0x3FF30F8399BFFECE, 0x3FBD9A4D5A2C9161,
+	0x9A66B3E0CE6AB59B, 0x0000000010A778E8, 0x000000000008AA54, 0x0000000000000001,
+	0x000000003F5847C6, 0x3FB95D4340E1ED58, 0x3FFD412168D956F5, 0x3FD57DD89056DB37,
+	0xC66E7414A1DF3A7C, 0x000000004AD25874, 0x0000000000025598, 0x0000000000000001,
+	0x000000003F1ED325, 0x3FCDEDF153860A74, 0xBFDEDDCCF8ED5756, 0x3F7CEE491274AA79,
+	0x27CF73FA75D65DDB, 0xFFFFFFFFE2E57B6E, 0x000000000025906E, 0x0000000000000000,
+	0x000000003F3B3D66, 0x3FEEF226FD019772, 0xBFA54096D2962BC4, 0x3FA7C20874937AB2,
+	0x7199B03AB273B1A7, 0x00000000174220E7, 0x000000000001E5A3, 0x0000000000000001,
+	0x000000003EA546AE, 0x3FEBD4975F0F396A, 0x3F77213C502E79DE, 0x400228C776D12D00,
+	0x95D9EF09CCCD23DD, 0xFFFFFFFFC6B31789, 0x0000000000064AD5, 0x0000000000000000,
+	0x000000003F1A5965, 0x3FE7C7A64370662E, 0xBFF9194162DC2332, 0x3FFB24ACD5C69079,
+	0x1628EAF1D9AFA6D3, 0x00000000333D91E7, 0x0000000001D521DB, 0x0000000000000000,
+	0x000000003F26C6B2, 0x3FE11DC4A14FBA69, 0xBFD78CD57B7BC718, 0x3FE0E34500520EE9,
+	0x2C460FCB8A3B608C, 0xFFFFFFFF8739007A, 0x000000000002978E, 0x0000000000000001,
+	0x000000003D112F20, 0x3FD8F9D371ED0F26, 0x3F94B61085AE7BA1, 0x3FF0ACBA1AEBEDB2,
+	0xF32D4A5FF4049A64, 0xFFFFFFFFDA9B16A2, 0x00000000016F0ACA, 0x0000000000000001,
+	0x000000003E6C40D0, 0x3FCE004435AD4548, 0xBFF2ADDDB9BC68F0, 0x3FF66FD04976BDD6,
+	0x135B21436119DA12, 0xFFFFFFFF8753FD2E, 0x00000000004EADD7, 0x0000000000000001,
+	0x000000003F551866, 0x3FD4D5EEEF871700, 0xBFF638390A2ED098, 0x3FF2E5F186101C2E,
+	0x393581CA8A9AA211, 0x000000007CF0E735, 0x0000000009CAE44A, 0x0000000000000001,
+	0x000000003E5857A0, 0x3FEE42BF0D383069, 0x3FE169B4878DA808, 0x3FB9C441AC1FC00B,
+	0x7C54567F96781886, 0x0000000068CAC80E, 0x000000000034F05E, 0x0000000000000001,
+	0x000000003F3071F2, 0x3FEE0A6155DF6F4C, 0xBFD4D8ECEF010CD3, 0x3FD154905D9F2120,
+	0x1C9B22F8039A2CFF, 0x00000000570C8548, 0x0000000000000000, 0x0000000000000001,
+	0x000000003EF48B50, 0x3FEC1804EA883E07, 0xBFC05D62E9F96D38, 0x3FC9A6EF5D663891,
+	0xF15AE12EC8400321, 0xFFFFFFFF80EAB5D2, 0x00000000001A7098, 0x0000000000000000,
+	0x000000003E43AD5C, 0x3FEF2F65E78E8488, 0x3FD13381E54FC1B8, 0x3FE32CD47BB36C88,
+	0xAD68CF919C832BBE, 0xFFFFFFFFE009D48F, 0x0000000000000003, 0x0000000000000001,
+	0x000000003F064304, 0x3FCEA1E879A36F44, 0xBFA804E4891B87D8, 0x3FF3366DD9522697,
+	0xA9A44E2A375BBBE0, 0x000000002C630BCE, 0x0000000000518CB9, 0x0000000000000000,
+	0x000000003F7546FA, 0x3FA23544A87D1F70, 0xBFE53B6B5A04FEA8, 0x3FC110C587CCE940,
+	0x62F7DF70BF0DD06C, 0x0000000049559E51, 0x000000000000000B, 0x0000000000000001,
+	0x000000003DB0B5D8, 0x3FE06D3932A5554B, 0x3FEF71536E06A6E4, 0x3FFED106E7BD64D9,
+	0x23F666E57E0599F3, 0xFFFFFFFF8800EAE3, 0x000000000012404D, 0x0000000000000000,
+	0x000000003E85372A, 0x3FD4575D5C6C4468, 0x3FD3002E5432A1B1, 0x3FD08F2D1B11B10D,
+	0x25D16336B96AC233, 0xFFFFFFFFA0C20C52, 0x0000000000000023, 0x0000000000000000,
+	0x000000003E79BC1C, 0x3FCD6105429930B4, 0xBFEF0AAC235964D1, 0x3FDEAB6928E71421,
+	0x93430EC58E15E599, 0x0000000000B2FDD0, 0x00000000001D0C30, 0x0000000000000001,
+	0x000000003E4CA6D4, 0x3FE270D8162D58F8, 0x3FF6B067798D9721, 0x4005AFC689E96633,
+	0x2C2F54B275FF3169, 0xFFFFFFFF8A21C901, 0x00000000000000BE, 0x0000000000000000,
+	0x000000003F5F47D5, 0x3FE68573562494D1, 0x3FD35C55F855D66E, 0x3FD7E0D4724EB9E0,
+	0xEA18E16D9CB426E2, 0xFFFFFFFFB7D580F3, 0x00000000006E7C4A, 0x0000000000000001,
+	0x000000003EB2F336, 0x3FEDA96A6D872EFC, 0x40065D0D3AFC7496, 0x3FE92E9D8DF73868,
+	0xB7BBA5470BB00520, 0x0000000024BDAE24, 0x00000000000002CA, 0x0000000000000000,
+	0x000000003EFE67F6, 0x3FEC476EB7530AE2, 0x3FEF89C2005D30D7, 0x3FD91132D444D4F1,
+	0x4571AD5C2BE52278, 0x0000000066299275, 0x000000000012B163, 0x0000000000000000,
+	0x000000003F5ABA71, 0x3FB97D979C43D660, 0xBFE5A859AE023553, 0x3FE02BDD2CC57953,
+	0xEEE0C9D6B88DA635, 0x0000000004406B19, 0x0000000000000FCD, 0x0000000000000001,
+	0x000000003E3C3A60, 0x3FCE44F22A9F5C38, 0x3FECDF9F359AA541, 0x3FE493963D12165F,
+	0xF9D2D5D5548E90D8, 0x00000000454C6910, 0x00000000004649BB, 0x0000000000000001,
+	0x000000003EB5FAC6, 0x3FEA8DDD0A2319A8, 0x3FE1ABCC60D24827, 0x3FD3BB131946EA45,
+	0xAD2B87341AA8C721, 0x000000007FCAD155, 0x0000000000003910, 0x0000000000000001,
+	0x000000003F243D9A, 0x3FE21E65E4DC59C7, 0xBFC65C93FD35A53C, 0x3FFC1A3C3D6E99D9,
+	0xBA47938AD3A4C4B3, 0xFFFFFFFF8A35027C, 0x00000000007A217D, 0x0000000000000001,
+	0x000000003F3206D0, 0x3FCE70AEC9105F64, 0xBFF143D5C6D53510, 0x40132F21B9B457AA,
+	0x29935B478F01957D, 0x000000006CEA126C, 0x000000000000C38E, 0x0000000000000001,
+	0x000000003DFE8620, 0x3FE0E0F90FACC8C4, 0x3FC5262BD218CEA0, 0x3FF3AF0BDADA5660,
+	0xD4E57214BC5246C9, 0x0000000043E5FF36, 0x00000000002C28EB, 0x0000000000000000,
+	0x000000003F373DBB, 0x3FA5D136CA1442D0, 0xBFEECFC003DFA39D, 0x3FD78BA43CC75723,
+	0x2D64C78DFFCB10A3, 0xFFFFFFFFE9930494, 0x000000000003E0E4, 0x0000000000000000,
+	0x000000003EB4CF40, 0x3FE3B4093F803CC5, 0x3FDEF95B94515E01, 0x3FA8D66DA6009C32,
+	0xC163AE49882EC8CC, 0x000000002BF7428A, 0x00000000001E8CEE, 0x0000000000000000,
+	0x000000003ECC764E, 0x3FC45272C99095CC, 0x3FFD39E86C6EF382, 0x3FF059F017FAD5AC,
+	0xD84B971532416B11, 0xFFFFFFFF8188AA4D, 0x0000000000029479, 0x0000000000000000,
+	0x000000003EE05804, 0x3FE2CE0BD2E7B0DA, 0x3FE3263D0815DBE9, 0x3FE301BDF88A54A0,
+	0x72D989EDF94256B1, 0xFFFFFFFFF03F9DB6, 0x000000000083060A, 0x0000000000000000,
+	0x000000003F5CE7D0, 0x3FE088908FE6C37E, 0x3FE4B597DE72F63A, 0x3FE0643E5CAFA183,
+	0x43782FE5CF79BB20, 0x000000007ACDCB4F, 0x0000000000272157, 0x0000000000000001,
+	0x000000003EBFA7B0, 0x3FE4E2B582743775, 0xBFE1415263AB2EE5, 0x3F976E20EBCC1B64,
+	0xFEA7832F9BE1F960, 0xFFFFFFFF923F33D3, 0x000000000075E77E, 0x0000000000000000,
+	0x000000003F070A63, 0x3FD21E7F93BE4342, 0xBFD6B3F09DB29B56, 0x3FFD48CBC410DD86,
+	0xE7FADE5578A3744B, 0xFFFFFFFFF4A8D64F, 0x0000000000354276, 0x0000000000000001,
+	0x000000003F75E1FD, 0x3FE5E51558E064E4, 0x3FF328B0AEF1AEC7, 0x3FE057FEF7EF7EB9,
+	0xD214A3EF9D4634DC, 0x000000002222DFAB, 0x00000000001C2133, 0x0000000000000001,
+	0x000000003F52BF9C, 0x3FC1970DA84BE814, 0x3FF6838CD781F76B, 0x3FB1041C36032251,
+	0x527447466593BD1E, 0xFFFFFFFF9D608FF4, 0x0000000000FF3DCA, 0x0000000000000001,
+	0x000000003E8B9FAA, 0x3FE2D3946A5F31AF, 0x3FD11FBFBB12B304, 0x40004B56A4D00FA6,
+	0x10AA9A6F166B5437, 0x00000000559B2707, 0x000000000053B7AA, 0x0000000000000000,
+	0x000000003EBA9978, 0x3FD45EB9918272AA, 0xBFF4245BD968193A, 0x3FF1F192F9FA03C5,
+	0xE422802B99CF58CC, 0x000000005D1BEC43, 0x0000000004552044, 0x0000000000000001,
+	0x000000003E8F8842, 0x3FEAA60583BEB9AA, 0xBFE8B1BD41C1DA87, 0x3FFAFCDBFEAF647C,
+	0xF1D1D80CF1CAE828, 0xFFFFFFFFC61E13E9, 0x000000000001B66B, 0x0000000000000001,
+	0x000000003F1B62AB, 0x3F89FC8588E3DD40, 0xBFF147ADF9A606AE, 0x400150ACE7A98E06,
+	0x95760E02906E6198, 0x000000000AC27728, 0x000000000304A718, 0x0000000000000000,
+	0x000000003F2DDABD, 0x3FC4352314041754, 0x3FC05577021E741C, 0x3FE5F239F491D2C5,
+	0x622589B463C62A0C, 0x0000000007E59B30, 0x000000000003A3A5, 0x0000000000000001,
+	0x000000003F2D709F, 0x3FD186624582AD7A, 0x3FBDC0C2409B1E8A, 0x3FFE968BBC138890,
+	0x7D13B20CB661096E, 0x000000004E94CE41, 0x0000000000000001, 0x0000000000000000,
+	0x000000003E8895AE, 0x3FE192FAD1357BCA, 0x3FC101FFF3097796, 0x3FD574B83C6D825D,
+	0x3FC0FC1CC3E4B5EC, 0x000000003338ABB0, 0x00000000000703C1, 0x0000000000000001,
+	0x000000003F14D25B, 0x3FDCB475DC686F58, 0x3FE8EE98239F2E66, 0x3FDA1E463BB35D34,
+	0xD118DB486FB838CF, 0x000000003B1471DF, 0x0000000000000000, 0x0000000000000000,
+	0x000000003F02219C, 0x3FED3CC28157B926, 0x3FFC7057D7DC7ED6, 0x3FF1AE1F564671D9,
+	0x594D912CBCE2BC85, 0xFFFFFFFFD908B506, 0x000000000008E318, 0x0000000000000000,
+	0x000000003E6A5D18, 0x3FE9CC8F1D326895, 0x3FC640A86789EA74, 0x3FF63974089340F8,
+	0x71578E1C4A8F5309, 0x000000001A979863, 0x000000000000001E, 0x0000000000000000,
+	0x000000003F45404B, 0x3FE696A5242BE177, 0xBFEEC014EC80EB92, 0x3FD3C70A8C825E96,
+	0xD9353BC783C4BC33, 0x000000007ACEFC92, 0x000000000093230D, 0x0000000000000000,
+	0x000000003F659A9C, 0x3FE21D2607674793, 0xBFBA04FDCDC04CA3, 0x3FEBB2FB3675F4DC,
+	0x2EE984FDF9C3C390, 0xFFFFFFFFD35BE345, 0x0000000000000072, 0x0000000000000000,
+	0x000000003E46523C, 0x3FE44BB78B16581C, 0xBFBB26C2AFFCC809, 0x3FBCEAF337DED8ED,
+	0x36991D3CDD52FBF7, 0xFFFFFFFFF2F815EA, 0x00000000003E12FD, 0x0000000000000000,
+	0x000000003F74F5B8, 0x3FEC566856B3E2A6, 0xBFE212F51EDAE3FD, 0x3FACBC3A55D66F19,
+	0x483455B4BDC2F1E1, 0x00000000041DEFEB, 0x0000000000000125, 0x0000000000000001,
+	0x000000003ECC6AA4, 0x3FED84B5081D45E1, 0x3FDF324E77552524, 0x3FCE6C1B962B6645,
+	0x5BC5A102061E2EE1, 0xFFFFFFFFC7555F89, 0x0000000000310ABB, 0x0000000000000000,
+	0x000000003F292AEF, 0x3FDBDF4FB78C7300, 0x3FFA90FBDFB2D2A9, 0x3FED27B43745944B,
+	0x9EB5D1DDD42CECA9, 0xFFFFFFFFC042C641, 0x00000000000003C7, 0x0000000000000000,
+	0x000000003F24307C, 0x3FE128F04AD6595D, 0x3FD997CFDCFB5A2E, 0x3FEE8D8DFEB4F2F1,
+	0x86344D083088EABA, 0x0000000011221BFD, 0x00000000001CD6CE, 0x0000000000000000,
+	0x000000003F49ADF1, 0x3F88D6D22E9AC5C0, 0xBFFE6545D9973AA6, 0x400960819B7B4A95,
+	0xA834F51B0B8F086B, 0x00000000724687E7, 0x00000000000018C6, 0x0000000000000001,
+	0x000000003EEFC44C, 0x3F97BD0557268460, 0xBFE4003F109DD48B, 0x3FD89CCC53A013B6,
+	0x451E558DD21C0D04, 0xFFFFFFFF8564B7A8, 0x000000000050A560, 0x0000000000000001,
+	0x000000003EDD4CC2, 0x3FE5DC98AD8A516F, 0xBFC611E117C684B6, 0x3FEE730D9C7695D7,
+	0x41842CC98002C10A, 0xFFFFFFFFF99F5462, 0x000000000000521B, 0x0000000000000001,
+	0x000000003CAF8E40, 0x3FDE546E94F85662, 0x3FF9B260B3737C4B, 0x3FD90A6C178B1DC6,
+	0x5E7C903CB08CB4EA, 0x000000006FB1A5EC, 0x00000000001983FE, 0x0000000000000000,
+	0x000000003EC57E8C, 0x3FE3E539CD18F7A8, 0xBFF1FBA95DE8907F, 0x3FD6C8991DC1FB47,
+	0x9F96FED3492BF611, 0xFFFFFFFFACF9427B, 0x000000000000497A, 0x0000000000000000,
+	0x000000003E2B58E4, 0x3FE638EE3A952D3B, 0xBFF69625E3387D48, 0x3FAE23D826885734,
+	0x01A5DEB9CDA8E6E0, 0x0000000069B98272, 0x00000000001353E0, 0x0000000000000001,
+	0x000000003F2C05F7, 0x3FE1EDA7F1504819, 0x3FF45CAB3C506FD0, 0x3FF6C0385B258E0A,
+	0x46A7A4DC6FAB205B, 0x00000000248E1F56, 0x000000000000F495, 0x0000000000000000,
+	0x000000003F7D5829, 0x3FD344E9C543E7A8, 0x400709A5AA8ABC05, 0x4005586E1013E70B,
+	0x8495965B4847D4A0, 0x000000003C0BEA21, 0x0000000000896DCA, 0x0000000000000001,
+	0x000000003F729943, 0x3FEE7185ACC4C9F1, 0xBFEEA9BC8BF1E39C, 0x3FF7411F49E203FD,
+	0x14529D0F9AFB7BDF, 0xFFFFFFFFDC87F59B, 0x00000000001DBA82, 0x0000000000000001,
+	0x000000003F59B746, 0x3FCD891BB6AB9524, 0xBFFA119433080B4A, 0x3FF0A1D46670D117,
+	0x7DCAFBE808AE857D, 0xFFFFFFFFD309E436, 0x000000000039CADB, 0x0000000000000001,
+	0x000000003E298534, 0x3FEEDDD340EDB847, 0xBFD50233EDD2B59E, 0x3FF0143AC7687E7A,
+	0xCD83B47972082ADF, 0xFFFFFFFF831751FF, 0x000000000030CE50, 0x0000000000000000,
+	0x000000003F6A3EDD, 0x3FE2EACCC914EDCA, 0x3FDF907BC17EDAD7, 0x400025324D22E58B,
+	0x78EC67AB60840459, 0xFFFFFFFFF3579C94, 0x0000000000626D57, 0x0000000000000000,
+	0x000000003E744780, 0x3FE996AC04DF17A7, 0x3FC72B1F36CEE0DC, 0x3FF8D834B952C28E,
+	0x86DAC94FC66EFA89, 0xFFFFFFFF8F59C92F, 0x000000000196836A, 0x0000000000000001,
+	0x000000003EBDD47E, 0x3FED42957FF9E1EE, 0x3FF1098850E85233, 0x400519307571D999,
+	0xB3A6234931F11B79, 0xFFFFFFFF89DCBE2D, 0x000000000038D389, 0x0000000000000001,
+	0x000000003F2633B3, 0x3FC2D7DF5428C330, 0x3FDC1CF7342EC94F, 0x3FD276DCF334B40F,
+	0x372EC68BE21B8BF7, 0x0000000066C8D724, 0x00000000073D35CE, 0x0000000000000001,
+	0x000000003DBAF8D0, 0x3FE5F556D36E78F6, 0xBFE73B60233235C9, 0x3FEF4953EA08FEF5,
+	0xB2A2E9B7874E1DC8, 0x00000000062BCF31, 0x000000000005F5E5, 0x0000000000000001,
+	0x000000003F2E2386, 0x3FD6086FA66EF614, 0x4002256B2F2CF13C, 0x3FF45CD626B94C4E,
+	0x9FC12F9DF2AA8AB0, 0x00000000232FDCA1, 0x0000000017F70F2C, 0x0000000000000001,
+	0x000000003F022A11, 0x3FE3D8AA92BAFE24, 0xBFF0BD39BA40A3EE, 0x3FF0F9C6805F10C0,
+	0x8F6F88ED230D2142, 0xFFFFFFFFBB3B5219, 0x0000000000180EC9, 0x0000000000000000,
+	0x000000003E36AED0, 0x3FE0B5B089547AE6, 0xBFD15EC3F036F13E, 0x3FF611C3E3F8CF4C,
+	0x9D495CB6F9992FB1, 0xFFFFFFFF9DFA42A7, 0x0000000000000000, 0x0000000000000000,
+	0x000000003EFB7E8C, 0x3FAFFA1251F2B620, 0x3FEC1193B4F6D218, 0x3FE40B19568EB2CC,
+	0xC71E75A44D680CAE, 0xFFFFFFFF945E4C65, 0x0000000000886348, 0x0000000000000000,
+	0x000000003F19DF23, 0x3FC9D778DF9F3CE0, 0xBFDC356138837EE9, 0x3FFE57E274566C38,
+	0x5196D7035A004DAE, 0x00000000219F11CD, 0x0000000000000003, 0x0000000000000000,
+	0x000000003EBC7E62, 0x3FC0C4C7CE0E3AFC, 0xBFC206231FFF4B44, 0x3FF43B5A67A294CB,
+	0x7721C49841C60097, 0xFFFFFFFFEFFBADE7, 0x00000000000FA2CB, 0x0000000000000000,
+	0x000000003EE7630C, 0x3FE11D2F2A9C852F, 0x3FBA8F8775037E90, 0x3FC15B2DF7C9EE92,
+	0x3F879A94F3E9C38C, 0xFFFFFFFF971566A0, 0x0000000000000002, 0x0000000000000001,
+	0x000000003F3D03CC, 0x3FE670D43CA1798F, 0x3FCF355B92B314F8, 0x3FD3E3B160D09053,
+	0x20E2AB6B9F4D8ED9, 0x0000000024EF574E, 0x00000000008FEB0C, 0x0000000000000001,
+	0x000000003F579928, 0x3FE856AD9A7D37D4, 0xBFC2F32F1DF0ED96, 0x3FBD86F67C2369D0,
+	0xCE76D8D03430DBE4, 0xFFFFFFFFF8C5E81E, 0x0000000000000013, 0x0000000000000000,
+	0x000000003F54EDAD, 0x3FE56819EB736339, 0xBFDFF8C174E7A01F, 0x3FD695F3F4BBD5B9,
+	0xA4ECFB1172C96213, 0x0000000053EFA5CF, 0x000000000082C6BF, 0x0000000000000000,
+	0x000000003F5EB91F, 0x3FE3D407760B4747, 0xBFECE33B5CED4C54, 0x3FD9098A09013D27,
+	0x3CF5FF362AA27D9E, 0xFFFFFFFFD79CD9ED, 0x0000000000000086, 0x0000000000000000,
+	0x000000003F36352F, 0x3FE8C6224488AA2E, 0x3FE3A3294BA187B3, 0x3FF12ABBC60CAEDE,
+	0x78008398451F35D1, 0x0000000000189021, 0x0000000000BA8E2D, 0x0000000000000001,
+	0x000000003F38B2E2, 0x3FE561269698DF8C, 0x3FE5C68977387F45, 0x3FF6E5BC21D177BD,
+	0x1C3839456513CF5F, 0xFFFFFFFFAB9530BF, 0x0000000000000048, 0x0000000000000000,
+	0x000000003F68492B, 0x3FD66AF20BF97292, 0x3FC524DFF2558BC6, 0x3F55708ABCAA16CD,
+	0xF34250C5B485CFCD, 0x0000000025610403, 0x00000000006EB2E9, 0x0000000000000001,
+	0x000000003CB78AC0, 0x3FEEBD8542D29821, 0x3FF92CDBF0A3EC15, 0x3FD3115B86B01993,
+	0xD67ED76C59C55EF6, 0xFFFFFFFFA38D0B2C, 0x0000000000000A31, 0x0000000000000000,
+	0x000000003EB21A9C, 0x3FEDE1E8E759FEDF, 0xBFD20D3964F620FC, 0x3FD8BC59DC9F30A7,
+	0x59A44B7AADD00522, 0xFFFFFFFFFA2C6E04, 0x0000000000471F5D, 0x0000000000000000,
+	0x000000003F1CE68D, 0x3FCA2824109F3200, 0xBFFFD0B9E3AA15AA, 0x3FB4867D3AB9DFE3,
+	0x8DDB3D2E129232BF, 0x000000007C8556B7, 0x0000000000002073, 0x0000000000000000,
+	0x000000003E3B099C, 0x3FD4BA59C9F24B62, 0xBFF2916861CA91BC, 0x4003638087EFFCD5,
+	0x21583EC1E4E5DF2C, 0x0000000059873D0C, 0x0000000000C2775C, 0x0000000000000001,
+	0x000000003F7A1EA7, 0x3FC0685B68FF9BF0, 0x3FF7EF8B44EE26FA, 0x400A45E2AF2F359F,
+	0xB6A6EA3EE08C4552, 0x0000000069E8A712, 0x0000000000008F3C, 0x0000000000000000,
+	0x000000003D842148, 0x3FD90CE075D9AA4E, 0x3FF324DB7A349AD5, 0x4004F2769C79973A,
+	0x4F1C355AD417C1F8, 0xFFFFFFFFB3ED72E4, 0x000000000059E217, 0x0000000000000000,
+	0x000000003E123CA4, 0x3FE911CDD9EE2C53, 0x3FB826F607401EF5, 0x3FECCEA4522D604E,
+	0x2AA70D164EC6030C, 0xFFFFFFFF9C1777B9, 0x0000000000039CDB, 0x0000000000000000,
+	0x000000003F3136F8, 0x3FE19F63B13B1032, 0x3FC16D85511F5BCD, 0x3FF9A1DA82D2251E,
+	0x89D5FA9584D10A1E, 0xFFFFFFFFE3A10463, 0x00000000008CEE6D, 0x0000000000000000,
+	0x000000003E1AD9A4, 0x3FD3761232E3C3C8, 0xBFCB71CBC1C0FCA7, 0x3FE41702B81C0B82,
+	0x1A271A01E0AFD1B9, 0x00000000785A7E5E, 0x000000000004612D, 0x0000000000000001,
+	0x000000003EE67414, 0x3FED9138301734F9, 0x3FE4D3739A3E0425, 0x3FCA53900DE0996F,
+	0xB0A8EAB8CE27329A, 0x000000001D16DF8F, 0x00000000005BEACB, 0x0000000000000000,
+	0x000000003F679C6D, 0x3FEEAA3072A1C184, 0xBFD1205FC74C11E6, 0x400E601A84EB4D84,
+	0x981E40ED97E9923C, 0xFFFFFFFFF7E84AFB, 0x000000000003A885, 0x0000000000000001,
+	0x000000003F093EA6, 0x3FE8B87AFE054158, 0xBFCF4955453DB530, 0x3FF7F7864D9CF289,
+	0xB6396B36FADAEB49, 0xFFFFFFFFA7A6F157, 0x0000000000A9231F, 0x0000000000000000,
+	0x000000003F553BE7, 0x3FEAF38F11C95FDB, 0xBFFFE590723423F7, 0x3FD9A7DFE4B3D2BD,
+	0x35D1B7BB3FC8E31F, 0x000000007C2D8E2C, 0x0000000000AA5CE5, 0x0000000000000000,
+	0x000000003F3F12AA, 0x3FCED0970EE1D300, 0xBFDA37E735E93C72, 0x3FE088F4BE00073F,
+	0x371186363E803B01, 0xFFFFFFFF98C41942, 0x0000000000A41249, 0x0000000000000000,
+	0x000000003F1C0EBA, 0x3FE9B35BF79219DB, 0x3FF2F3C6E025FB94, 0x3FAE0EE505D6020C,
+	0xB784A12F7E257BC6, 0xFFFFFFFFE783E74B, 0x00000000035F0CB2, 0x0000000000000000,
+	0x000000003F451E1F, 0x3FA32222751C51F0, 0xBFDB396F6F6BD513, 0x3FFBB5EC17882F4E,
+	0xA213E7AAFE76F53F, 0x0000000066321329, 0x00000000007A763F, 0x0000000000000001,
+	0x000000003E1CE3AC, 0x3FE585D11D8CD533, 0xBFE3C5622F19EA51, 0x3FEF48497343329C,
+	0x1D87F4AA9A556791, 0xFFFFFFFF80CFD185, 0x000000000416AA60, 0x0000000000000001,
+	0x000000003E9087F8, 0x3FE8499AE5FCD072, 0xBFE3995F1082373C, 0x400D1E332EDF7C37,
+	0xD46B8BAD4522AD31, 0x000000003C6DF44A, 0x0000000000EEE1D1, 0x0000000000000000,
+	0x000000003F753306, 0x3FEAF16C55B6BADD, 0x4000A96E640C2E53, 0x3FCBAD858B60703E,
+	0x2AD5EDD21ED9D7C6, 0x000000006517F029, 0x0000000011ED4920, 0x0000000000000001,
+	0x000000003F0F3FEF, 0x3FEB842EB3E66705, 0xBFE5EA9BFFA79FDE, 0x3FF2E7845F704A15,
+	0xC117F4A18967FADD, 0xFFFFFFFF9A501BB1, 0x000000000083377E, 0x0000000000000001,
+	0x000000003C6C32C0, 0x3FDDF7AD063626FE, 0xBFE263E00ED49B21, 0x3FCE440FF13951EC,
+	0x9879F770660A0A90, 0x000000003FE733F9, 0x0000000000000000, 0x0000000000000001,
+	0x000000003EDEC674, 0x3FE038A9CB0E9457, 0x3FE0702F2E7156DA, 0x3FE2DEE0E7775B42,
+	0x9C001FA686768462, 0x000000007B0EE67C, 0x00000000000BB38E, 0x0000000000000001,
+	0x000000003F05EBDB, 0x3FE1B1FC8E581228, 0x3FE8378E076C5810, 0x3FB169ADEA0CFEAD,
+	0x05C90536381A8F10, 0xFFFFFFFFBFDF9862, 0x0000000000000000, 0x0000000000000000,
+	0x000000003EA3DB2A, 0x3FE19FF449C2401F, 0xBFCA6B534E04C021, 0x3FE92BAC369BFC53,
+	0x0011CA708ECBD6D2, 0x00000000318320B0, 0x00000000000DD7B1, 0x0000000000000001,
+	0x000000003DA7E7A8, 0x3FECA953CF696453, 0xBFF6D77EF75E45FE, 0x3F75788834C830AE,
+	0xFE5CD133F263C7C6, 0x0000000029573876, 0x000000000000001D, 0x0000000000000001,
+	0x000000003C980EA0, 0x3FE3ADB9E8766CB9, 0x3FD8307F91A8BDD1, 0x3FD68CF8C56E0B09,
+	0xEFA9C9AD61CA2010, 0x00000000353660B8, 0x00000000000B3577, 0x0000000000000000,
+	0x000000003F6660E5, 0x3FE3C7B399C46751, 0xBFF132315A788E61, 0x3FFF5D597C55CDE3,
+	0x697EDF848A44FA5C, 0x0000000078D0DF04, 0x0000000000000041, 0x0000000000000001,
+	0x000000003D81B828, 0x3FCC0DB35B030A10, 0x3FEC06409A3456B6, 0x3FD3C19F2E3BB611,
+	0xB834B3AA5E905096, 0xFFFFFFFFD4187ED0, 0x0000000000CB2065, 0x0000000000000001,
+	0x000000003F1A0957, 0x3FE0775E7EA677FF, 0xBFB648CD4FB02A14, 0x3FE728449F29F7DF,
+	0x5B7894CFFA69882A, 0xFFFFFFFFB0D91413, 0x0000000000000178, 0x0000000000000000,
+	0x000000003F493B29, 0x3FD30A0C014964A6, 0xBFE0972343467A36, 0x3FFE67E7B0BD154E,
+	0x05F75584F0189215, 0x000000004A012DE1, 0x0000000000F676FA, 0x0000000000000000,
+	0x000000003F75F46E, 0x3FD0FA1D63AF5F6A, 0x3FE0D60E02508194, 0x3FA158F44E7C12A8,
+	0xE1C1C11868EB4282, 0x000000002797D10D, 0x000000000000077D, 0x0000000000000000,
+	0x000000003DE96D10, 0x3FDF823FE2D96AD6, 0xBFF160900593A6C1, 0x3FCDA0A237459AA8,
+	0x47E2612657F8A9C8, 0xFFFFFFFFAD57354B, 0x0000000000A53FDE, 0x0000000000000001,
+	0x000000003F482229, 0x3FDDED99FFC8552E, 0x3FE1E056BAB05AC5, 0x3FD3F2D0E29491D5,
+	0xE73603F39AE99C7F, 0x000000003BE52CD2, 0x0000000000000149, 0x0000000000000001,
+	0x000000003F405066, 0x3FCFC649D820EE68, 0xBFD3F21A2F310815, 0x3FCAEACF8317D773,
+	0xB9AEA0D66249AE86, 0x000000001AFB70A4, 0x000000000009421A, 0x0000000000000000,
+	0x000000003EB3DF50, 0x3FD225E84EE3C96E, 0xBFC15EEE669165EF, 0x3F998380AF1D82DD,
+	0x7B8D3DACDB69C5A0, 0x000000006E099C01, 0x000000000000488C, 0x0000000000000001,
+	0x000000003F11FE10, 0x3FE4E68DAF968B8D, 0x3FF28334E77368A2, 0x4003D208A303802E,
+	0x81F3593B43D476E8, 0xFFFFFFFFD80C4A0A, 0x0000000000C38B89, 0x0000000000000001,
+	0x000000003F1546DC, 0x3FEA6E741C0F4C5C, 0x3FD2DEEB49193051, 0x3FD3DCD13DF6E14C,
+	0xB4916A0C6A1B64F7, 0xFFFFFFFF9C572DF8, 0x000000000000256C, 0x0000000000000001,
+	0x000000003D684EE0, 0x3FB9B8B1F883B4A8, 0xBFFA946E5A52C34F, 0x3FF52D1355A4431B,
+	0x4CB1F8886F31E6CD, 0x000000001F565E56, 0x00000000007C74DB, 0x0000000000000001,
+	0x000000003F34C786, 0x3FBA9313E60C7FB8, 0x3FF604E5F88F450F, 0x3FDAF0D14FA386DC,
+	0xD08D459E2747CB30, 0xFFFFFFFFF3B98237, 0x000000000007B0B2, 0x0000000000000001,
+	0x000000003D2E76E0, 0x3FE3643F893DEEA6, 0xC004070780FCB148, 0x3FD1361A406ACDAF,
+	0x940F4B49D8E896DC, 0xFFFFFFFFD6C0275E, 0x0000000000A74168, 0x0000000000000000,
+	0x000000003F2EBEF9, 0x3FE28665A7966462, 0x3FE4134C55C9A213, 0x3FF5CABAD53A8A43,
+	0xD536BA2B843E61AF, 0xFFFFFFFFB328A021, 0x0000000000048CCF, 0x0000000000000000,
+	0x000000003F2F21FE, 0x3FEDDB6E79616B91, 0x3FD1BFD8084454C0, 0x3FF13F535D502B64,
+	0x6A93E4726A48BCD3, 0xFFFFFFFFE733E5F2, 0x000000000045C473, 0x0000000000000000,
+	0x000000003F065FB9, 0x3FE2B1D5EA4D8657, 0xBFFA6E5EF568194D, 0x3FD751A9395395F7,
+	0x18877950614980AC, 0x00000000719C413D, 0x0000000000433C52, 0x0000000000000000,
+	0x000000003E82BACE, 0x3FCEEE4677D590D0, 0x3FDA99FD81D0CA7A, 0x401542D5624739F3,
+	0x0AEB17B5858A864D, 0xFFFFFFFFDDAF1D6B, 0x00000000010869BA, 0x0000000000000001,
+	0x000000003F753881, 0x3FC9BB70348B12B8, 0xBFF7379D278D8AFB, 0x3FDB4D6AD88036FB,
+	0xBCD69A5EFF9804E4, 0x000000002416284D, 0x000000000042D82B, 0x0000000000000000,
+	0x000000003F68ABD5, 0x3FCCB4B8AFA477A4, 0xBFF15A6F208171E4, 0x3FE540AE786AA7BF,
+	0x5C6A728CEE005C1C, 0x00000000453E8F7F, 0x00000000002E796A, 0x0000000000000001,
+	0x000000003EE23596, 0x3FDBE55BA06EDC22, 0x3FD629C3733B3A8E, 0x3FF7AA82D14C6247,
+	0x72D294DF62E7903B, 0x00000000443C36D9, 0x00000000018DC3E6, 0x0000000000000000,
+	0x000000003D9B92E0, 0x3FE77828B95D98BA, 0x3FC9A7269061E043, 0x3FB6E8CCBA15307A,
+	0x116AFA8D5F084B0A, 0xFFFFFFFF8EFBA9EA, 0x0000000000CFF56D, 0x0000000000000001,
+	0x000000003E6AE664, 0x3FD07A30DC1A3AB2, 0xBFDA55231D0A54AA, 0x3FED992586C06FD2,
+	0xB9915168435E0E07, 0x000000004F278B85, 0x00000000087D129C, 0x0000000000000001,
+	0x000000003F5F4D20, 0x3FC2510D1B3A3718, 0xBFF17E04D4E2536C, 0x400BEBFF3CBA6D7B,
+	0x2A620C5CBB328A76, 0x000000002132D6E5, 0x0000000000CFD321, 0x0000000000000001,
+	0x000000003E27D5F8, 0x3FD59BAA4AC076E0, 0x3FF6C2B798734576, 0x3F8F7CEC336F6F2B,
+	0x606DB143667AD218, 0x000000003B30DC23, 0x0000000000000000, 0x0000000000000001,
+	0x000000003F162685, 0x3FE4E8E0F04BBE31, 0xBFF947B8AB22CFD3, 0x400473A936B20DF8,
+	0xA00AAA1A1F89F6C9, 0x0000000011A8297B, 0x0000000000B9CAD9, 0x0000000000000000,
+	0x000000003E0E7F5C, 0x3FB7210541C856E0, 0x3FD28DBBBCB22DDA, 0x3FC3471DE30738FE,
+	0x3B82BEA52854DAC0, 0xFFFFFFFF814D7098, 0x0000000000000003, 0x0000000000000000,
+	0x000000003F27B91E, 0x3FC010AFB1318AC0, 0xBFF08137E0C01F5E, 0x3FFE87BF553457EB,
+	0x7CB37AB63BC72775, 0xFFFFFFFFB3237683, 0x000000000101C75F, 0x0000000000000001,
+	0x000000003DD5D9C0, 0x3FD47F3D6DFEFECE, 0xBFE37D7DF03E3D6D, 0x3FD26EBA9B545F67,
+	0xF48B6FF4148B2CC9, 0x00000000792CA2E4, 0x0000000000000009, 0x0000000000000000,
+	0x000000003E4EB60C, 0x3FE72CB59A64086F, 0xBFE44AA004CD7AE3, 0x3FB2BD19ACC17F68,
+	0x67C3220F49AC4C2B, 0xFFFFFFFFF5AA68A1, 0x0000000000C68436, 0x0000000000000000,
+	0x000000003E898A4C, 0x3FD58F4D82FB5CD0, 0xBFEBF385934F15AB, 0x3FB6227EE4CEC32A,
+	0xFB39D1F3639BAC15, 0xFFFFFFFFF9681CCE, 0x000000000000000E, 0x0000000000000000,
+	0x000000003EDF0DBE, 0x3FD32C35A65B4214, 0xBFB04746C8126E19, 0x3FEE23C15B9CA9D6,
+	0x5A49BB9D10FFBE8F, 0xFFFFFFFFCC940C34, 0x00000000000AD076, 0x0000000000000001,
+	0x000000003F32F2AF, 0x3FEDE5B94FE8905D, 0xBFAB5D617A4545C8, 0x40127E8665337107,
+	0x46082E96512BA539, 0x000000001898C4BE, 0x00000000000000C9, 0x0000000000000001,
+	0x000000003DFB9CC8, 0x3FE7F1AF2FAFA6B4, 0x3FD2DAC4E8ECC58D, 0x3FCF66610EE8999F,
+	0xC7B4FB8541A21F99, 0xFFFFFFFFAB3B67A1, 0x0000000000276AB1, 0x0000000000000000,
+	0x000000003EE77AAE, 0x3FE748DB82DE6306, 0xBFD9794EEF0C59A9, 0x40029C646451DC40,
+	0x2EE2C9B507D9638E, 0xFFFFFFFF90BAC6D8, 0x0000000000000361, 0x0000000000000000,
+	0x000000003EC3280E, 0x3FD4B953A9600334, 0xBFDBCE5371015923, 0x3FEF793CE73F5B95,
+	0x5149B4727E06D904, 0x000000004DD18956, 0x0000000000E6E961, 0x0000000000000000,
+	0x000000003F25A0F5, 0x3FC0AF76E10E5144, 0xBFDC0C48F1448812, 0x3FE52E208D9DAE18,
+	0x8CDF6653F194A049, 0xFFFFFFFF854B2276, 0x000000000000008C, 0x0000000000000001,
+	0x000000003F380F34, 0x3FE57AC6DE4A6F4F, 0x3FD355DD0168FC34, 0x4005257DBCEDEE99,
+	0x9986FC49A7ADB046, 0x0000000031A9CB3B, 0x0000000000D66D1B, 0x0000000000000001,
+	0x000000003E3A2B18, 0x3FE05BACE149EABA, 0x3FE29FB6DF708F9E, 0x40030C62AD624FF9,
+	0xA7366CBA6AB12E5E, 0xFFFFFFFFACF79E1E, 0x00000000000025AD, 0x0000000000000000,
+	0x000000003ED877BA, 0x3FEFDE7DC20259D0, 0xBFEA2C7A0EB8CE1B, 0x3FFD8418A5E496D9,
+	0x95B08E73EBEBB665, 0x000000004D560249, 0x000000000106772C, 0x0000000000000001,
+	0x000000003DD7F5A0, 0x3FE4D92D49DDDE66, 0x3FDE34B768B50E58, 0x40080617F7466251,
+	0x8B975E04BF093AD1, 0x0000000063ED9555, 0x000000000000E984, 0x0000000000000000,
+	0x000000003E23091C, 0x3FE8BC59FBBE33B0, 0xBFED53981BB7D35A, 0x3FF9D9BE546E7B5E,
+	0xE6BBE17AA9774F38, 0xFFFFFFFF9A58A2C4, 0x000000000115FDE9, 0x0000000000000001,
+	0x000000003E59C674, 0x3FEAC198DC278853, 0xBFF326D3DE56A44F, 0x3FFAAF984693156C,
+	0x1CF80872F4F0DE6D, 0x00000000436CAA8E, 0x000000000001D95A, 0x0000000000000001,
+	0x000000003F57C656, 0x3FEF3A2FFCDB9109, 0x3FF5F85F50C68F63, 0x3FC38A8D84F3DD54,
+	0xF625455685FD9FEE, 0xFFFFFFFF80E04AF6, 0x000000000117DBF7, 0x0000000000000000,
+	0x000000003F534436, 0x3FE60AB98220B8D0, 0x3FE9F61D3CBD6B0E, 0x3F9352B94A5867F3,
+	0xBB0EFC82E202454D, 0x0000000026AB7517, 0x0000000000041705, 0x0000000000000001,
+	0x000000003F1816CE, 0x3FC24FCBFFDD451C, 0x3FF124A266D15178, 0x3FC2D3B4F1C1ED83,
+	0x11557F3B41D75508, 0x0000000006263452, 0x0000000000A70CDC, 0x0000000000000000,
+	0x000000003F710C65, 0x3FD40EA0483F7FBC, 0xBFDA2CE2F5E153E6, 0x3FFFBACE68152C3D,
+	0xE11665402D756EE3, 0xFFFFFFFFA145EB0F, 0x00000000002DF378, 0x0000000000000000,
+	0x000000003F367E63, 0x3FBC739914FAC4D8, 0x3FF1F586AB19B0ED, 0x3FD2F2456FCF4F91,
+	0x4701C5DB7E76F59E, 0xFFFFFFFF9583978B, 0x00000000001CA5D2, 0x0000000000000001,
+	0x000000003E00BCC8, 0x3FE36937AC2068D8, 0xBFF2507760651962, 0x400292BA91E2A340,
+	0xA357DE175B2126B8, 0x00000000229B9AEB, 0x0000000000941A19, 0x0000000000000001,
+	0x000000003E9221C4, 0x3FD803673312B0CC, 0xBFEFDB9B759F352F, 0x3FBA87A5D1929527,
+	0x3A9C64083AE087A7, 0x000000006E638BA1, 0x0000000000E62C20, 0x0000000000000000,
+	0x000000003F57A68B, 0x3FC5ECBFD29EA6F8, 0x3FFAD6DB5D67C454, 0x3FA247E8C21FB3BE,
+	0x9C67A84B7BDFFAF1, 0xFFFFFFFF87F360DB, 0x0000000001180AB7, 0x0000000000000001,
+	0x000000003F7C44D8, 0x3FEC35215A1CDFA8, 0xBFF2B61DAA37F7A5, 0x3FBC84E6FBB0BF43,
+	0x3ADFB32D519433C0, 0x000000007098EE50, 0x00000000002B53E6, 0x0000000000000001,
+	0x000000003F38F88C, 0x3FD418ABCC9B526C, 0xBFE25629D8D59E73, 0x3FD77EFC02622DB0,
+	0xE058A87A4C4A1D8F, 0xFFFFFFFF82AC7054, 0x00000000091FE9C6, 0x0000000000000001,
+	0x000000003F03C2DD, 0x3FD95D2498CAAC00, 0x3FEA916E6F2F5FA7, 0x4011119E4D09BAF3,
+	0x41A43121934F807F, 0xFFFFFFFFAF4F99FC, 0x000000000071BE07, 0x0000000000000001,
+	0x000000003DB63C88, 0x3FC06036168D583C, 0xBFBC4B804DF400E4, 0x3FD59232923AB710,
+	0xD445D5CCBD2AEC7F, 0x00000000186943C6, 0x00000000066B0EAC, 0x0000000000000001,
+	0x000000003D9AFF10, 0x3FE8641C56B1F6C2, 0x3F871202FC10BBD6, 0x3FF845A4C75348DC,
+	0x089EB09303EBF773, 0xFFFFFFFFD472A3A6, 0x0000000000865B09, 0x0000000000000001,
+	0x000000003F1E20FA, 0x3FDD2D0E6C295110, 0x3FD82C30CC7395FC, 0x3FAA7F3B9B59E4B4,
+	0x7F730F63F2195AA7, 0xFFFFFFFFD125316D, 0x0000000000000000, 0x0000000000000000,
+	0x000000003C015180, 0x3FE247074AD9C439, 0xBFE551F84981B9E5, 0x3F87E465187E6967,
+	0x2BBFDE16531E7A05, 0xFFFFFFFFE8AC447F, 0x00000000010617E0, 0x0000000000000001,
+	0x000000003F0C3FE1, 0x3FC9DE63E08A37D4, 0x3FC0317285357924, 0x3FF6FC59C8EA3896,
+	0x3D94C2F12DED0B87, 0x000000000870D6E8, 0x0000000000000007, 0x0000000000000000,
+	0x000000003E215D90, 0x3FD9BBAEE1180F20, 0xBFE2586F9FA23B32, 0x3FE206F1CDEC1402,
+	0x5A23CE8C3BC698AF, 0xFFFFFFFFCFAC2B7C, 0x0000000000E8C9C5, 0x0000000000000000,
+	0x000000003EDBCB08, 0x3FE1F0609C0E9C65, 0xBFFD5755D38EB46A, 0x3FE6DAC332489AD6,
+	0x9C592EFCEF69993E, 0x00000000067FA64A, 0x0000000000000016, 0x0000000000000001,
+	0x000000003F71C8E2, 0x3FBA74A73998BEA0, 0x4000887E0E680AA5, 0x4004703C690B9BC6,
+	0x5348A0F439DE29A4, 0xFFFFFFFFC760FDB1, 0x000000000018024D, 0x0000000000000001,
+	0x000000003F3A9561, 0x3FE5935D21BA86B4, 0xBFAF51469C6850F5, 0x3FE328E598AFFF33,
+	0x88494A944FE4088E, 0xFFFFFFFFB7DA59F7, 0x000000000000004A, 0x0000000000000000,
+	0x000000003DABD908, 0x3FEB3E3F3FF4DCE1, 0xBFE5553AB861E014, 0x3FD48B4BD0835958,
+	0x1A8C4DEEDC1F0776, 0xFFFFFFFF97E0361B, 0x0000000000DD3B9A, 0x0000000000000001,
+	0x000000003ED57064, 0x3FD111E41E378AE2, 0xBFF4EC60E91D14D8, 0x3FD001BAC54A07E3,
+	0x0EF6639A77ECA523, 0x000000007F310604, 0x00000000000001DE, 0x0000000000000001,
+	0x000000003EA23B78, 0x3FDEC2CB3C92326C, 0xBFDDC77D9BEB7CD8, 0x3FD2B9ECB3D638B8,
+	0x1C19AA7B8D0D7F11, 0xFFFFFFFFA3A3EC73, 0x0000000000B044BA, 0x0000000000000000,
+	0x000000003EA2BA7C, 0x3FECF75F4EFD4A9F, 0x3FC1FB37982F250E, 0x3FD95A4F5D41F092,
+	0x0693E312A5F2A58E, 0xFFFFFFFFDDCFC61F, 0x0000000000000383, 0x0000000000000000,
+	0x000000003F1ECD8D, 0x3FAEDB9207A66150, 0x3FD3C110BE927AE2, 0x3FEBB899581D05B7,
+	0xAA37A595DE68AAF7, 0x0000000041F3871B, 0x00000000006BEF90, 0x0000000000000000,
+	0x000000003F2ED2EB, 0x3FBC4EAAF88405E8, 0xBFD57543179DD677, 0x3F97390489D27DBB,
+	0xD5FDFA6BD8D96120, 0x000000007E1FB936, 0x000000000000192B, 0x0000000000000001,
+	0x000000003E37BE70, 0x3FC6D62109F0D614, 0x3FE1622663C2A680, 0x3FFDDBEC34314C25,
+	0x8C65769E3FA61DC4, 0x000000005608A65D, 0x0000000000AF3EC0, 0x0000000000000001,
+	0x000000003E5FEFC8, 0x3FEC00F072A45C8E, 0x3FE59B53BE184CCA, 0x3FE6C0A47112BE3D,
+	0x7C0309E71D97CE39, 0xFFFFFFFF91262640, 0x0000000000003F5C, 0x0000000000000000,
+	0x000000003D364A10, 0x3FDDCE91B4B20E94, 0x3FFFB7CC17498136, 0x3FF52C9CB54C926D,
+	0xCB1EE3A1FE82BCC6, 0x0000000048CA0520, 0x00000000007747C7, 0x0000000000000001,
+	0x000000003ED6A936, 0x3FE1FEAE3FE87315, 0xBFF0DD33464D8168, 0x3FF0A2FEF4619EEA,
+	0x04A0363F91B8F960, 0xFFFFFFFF8CFDFC35, 0x0000000000007B47, 0x0000000000000000,
+	0x000000003F17C788, 0x3FEA91E1F37EB34C, 0xBFD151D8D356CBD4, 0x3FDBB89AF3611FC4,
+	0x540501240B2BA63D, 0xFFFFFFFF8FF1D236, 0x00000000005E45D0, 0x0000000000000000,
+	0x000000003E3D188C, 0x3FEE3C441ACAF154, 0x3FF1942558D1DD41, 0x3FD8223176B27FE6,
+	0xFAC4BB771F281BA2, 0x0000000008DCA5AA, 0x000000000000A8F5, 0x0000000000000000,
+	0x000000003F7FA670, 0x3FE00DBD62058B75, 0xBFCCD0833FD4F2CA, 0x3FE762D5F6AB0A37,
+	0x6C6C4CDC089F7348, 0xFFFFFFFFF02D1613, 0x0000000000B5EEF6, 0x0000000000000000,
+	0x000000003EDEAB50, 0x3FE7D8C133193CA9, 0x3FA3FBE465EC145E, 0x3FE1E3BD3928E751,
+	0xDC0DD81241E94E5D, 0x000000006BFA5887, 0x0000000000113C45, 0x0000000000000001,
+	0x000000003E283864, 0x3FDF53E002F288C2, 0xBFF929B175CA762A, 0x3FF5243DBCFE3CCC,
+	0xBFE6F0521E023A83, 0x000000005C8571E4, 0x000000000070E311, 0x0000000000000001,
+	0x000000003F411673, 0x3FEF4EF5A5EE2697, 0xBFEEA53187A39240, 0x3FD555191C416C0D,
+	0xF6E471C21DFA220E, 0x0000000070395A05, 0x00000000004F8657, 0x0000000000000000,
+	0x000000003EE36B62, 0x3FD0B2B13263941A, 0x3FF56F942410AD3D, 0x3FDEBDE166FF11F1,
+	0x7DE7037C9817463C, 0x0000000078B7700C, 0x00000000013C5D4D, 0x0000000000000000,
+	0x000000003F7583EB, 0x3FEBB28FF1D416A8, 0xBFE02721BC6F7E86, 0x400300814122EBCE,
+	0xDBDC1472EA91C681, 0x00000000067FFF01, 0x0000000001F0516B, 0x0000000000000000,
+	0x000000003D845B70, 0x3FD8281160C2B7BE, 0x3F6A9F7D15E8A347, 0x3FF5FAD59F20699E,
+	0x6B61FF2C020488A1, 0xFFFFFFFFF3AE7ACC, 0x000000000160F900, 0x0000000000000001,
+	0x000000003F0BA03E, 0x3FA4C01FE1330330, 0xBFED4A78ED5E05E0, 0x3FC82E6C5A055822,
+	0x1936CC2B12D48D02, 0xFFFFFFFFC474E492, 0x00000000054C951B, 0x0000000000000000,
+	0x000000003E946ECE, 0x3FDD6022F297B814, 0xBFDE0375460E6AFD, 0x3FE43FE410B05A93,
+	0x76EBF7B00C93FF3C, 0x00000000276C3CF0, 0x0000000001280D58, 0x0000000000000000,
+	0x000000003E6ECFCC, 0x3FD1FB32F0FC71D0, 0x3FEAFAB5982BA457, 0x3FEBE99393D8735F,
+	0x14391E0F774F2DBC, 0x0000000011A27BF4, 0x000000000B8C87A4, 0x0000000000000001,
+	0x000000003F6BA472, 0x3FB0F3A460FEB8F0, 0xBFBE1E139C11836B, 0x3FE896ABDD6CA4D1,
+	0x6B14C34C204E72F0, 0x000000007FC7E3A0, 0x0000000000810BE4, 0x0000000000000000,
+	0x000000003F0C30DC, 0x3FE22A28F54ACE34, 0xBFD4C6B52A1FA36D, 0x3F8DFFAD75DBAA4E,
+	0xB03F5FBB072B88D2, 0x000000004C107AA3, 0x0000000000000000, 0x0000000000000000,
+	0x000000003E662CC8, 0x3FE5282A0D95A457, 0xBFB3C7F6979EFEC3, 0x3FD43BAE362FDEE8,
+	0x3A4A1CC6E232A6BC, 0x0000000015B7C3DB, 0x000000000144E072, 0x0000000000000001,
+	0x000000003F663592, 0x3FC9C9E744C140A4, 0xBFA701ECC10614EE, 0x3F54A171F777A8A4,
+	0xEF8E4FCFF114C599, 0x000000006945B694, 0x0000000000000001, 0x0000000000000000,
+	0x000000003F753CA0, 0x3FC204BD25F1A550, 0xBFF218FF45F00C0F, 0x3FE14BC455057E1F,
+	0xFDA79B294DC05650, 0xFFFFFFFFD0A796BE, 0x00000000009E9186, 0x0000000000000000,
+	0x000000003EA21660, 0x3FE8BBD5EBB3C697, 0xBFE92DAA1593FEDD, 0x3FEE9C172CD9A890,
+	0x081B4E3FD2A36BF7, 0xFFFFFFFF98CD84E2, 0x000000000000000B, 0x0000000000000000,
+	0x000000003F34AA64, 0x3FD28F0FFB34BA18, 0x3FD2D331657A5F64, 0x3FE883C5D272031F,
+	0x2BC33D4469A1FAFA, 0xFFFFFFFFBB89DDA0, 0x00000000012614AE, 0x0000000000000000,
+	0x000000003DB96A28, 0x3FEE8780C74695DE, 0xBFD2978F02F669F5, 0x3FEA7B53D1DF398F,
+	0x3FB5E71558F07374, 0x000000001376CABD, 0x0000000000000021, 0x0000000000000001,
+	0x000000003E8260FA, 0x3FE3AC5826F0A758, 0x3FEDA4D439A732A5, 0x3FC953817F9F82A4,
+	0xCB9072C445AE4A76, 0x000000000AD72FFA, 0x0000000001590B20, 0x0000000000000001,
+	0x000000003E336FDC, 0x3FE51C206396DE97, 0x3FB2AF5EFD33E977, 0x4005BAD09D6F9B51,
+];
+pub const XOROSHIRO128STARSTAR_RAW: [u64; 8] = [0x0000000000001680, 0x00000016C3804380, 0x86B5B3AD00004380, 0x800044A4CD1497B2, 0x73FE9D66C77D08F6, 0xD9D20B3AD5023EF0, 0x7635A9C622F5BC0E, 0xE62F03FF6C9D1B39, ];
+pub const SUBCLASS_WEYL: [u64; 1024] = [
+	0x000000003C6EF372, 0x0000000000000000, 0x78DDE6E41715609D, 0x0000000000000001,
+	0x000000003EA708A8, 0x3FEE3779BC7F9A3C, 0xBFF3ED2FF81E8F3D, 0x00000000003779B9,
+	0x00000000454021D7, 0x000000000000E06A, 0x81AF15491FE68F02, 0x0000000000000001,
+	0x000000003EB8AB04, 0x3FEF519F84C623AF, 0xBFD4D7E8514759DD, 0x000000000008A81E,
+	0xFFFFFFFFD5336958, 0x0000000000000001, 0x11A25CC9AFD9D683, 0x0000000000000000,
+	0x000000003F6C48C9, 0x3FE150087145BDEB, 0xBFFA80E21DB70DD4, 0x0000000000FBEF9F,
+	0x0000000056E27EA1, 0x000000000000356D, 0x935172133188EBCC, 0x0000000000000001,
+	0x000000003EDBEFBE, 0x3FA85EB1D5336950, 0xBFB8DF1C59E8F0F4, 0x0000000000AB04E8,
+	0xFFFFFFFFE6D5C622, 0x0000000000000008, 0x2344B993C17C334D, 0x0000000000000000,
+	0x000000003F7DEB26, 0x3FE3845409D2D0D1, 0xBFFE2F4A986DEAC0, 0x00000000009E4C69,
+	0x000000006884DB6B, 0x00000000000736E8, 0xA4F3CEDD432B4896, 0x0000000000000001,
+	0x000000003EFF3478, 0x3FBDD1B5EF024BD8, 0x3FE1FB05EAA1F1BC, 0x00000000004D61B2,
+	0xFFFFFFFFF87822EC, 0x0000000000000025, 0x34E7165DD31E9017, 0x0000000000000000,
+	0x000000003D78D830, 0x3FE5B89FAA5FE3B7, 0xBFF6A94831E465C4, 0x000000000040A933,
+	0x000000007A273835, 0x0000000000098FD7, 0xB6962BA754CDA560, 0x0000000000000001,
+	0x000000003F113C98, 0x3FC7BA0959B57188, 0x3FF8AD31B835491D, 0x0000000000EFBE7C,
+	0x000000000A1A7FB6, 0x00000000000000A8, 0x46897327E4C0ECE1, 0x0000000000000001,
+	0x000000003E04BF80, 0x3FE7ECEB42ECF69E, 0xBFDBAE2E614CA387, 0x0000000000E305FD,
+	0xFFFFFFFF8BC994FF, 0x00000000000BE912, 0xC83888716670022A, 0x0000000000000000,
+	0x000000003F22DEF5, 0x3FD0459BDDF4DE90, 0x3FFE1A2054C0A765, 0x0000000000921B46,
+	0x000000001BBCDC80, 0x00000000000002E7, 0x582BCFF1F66349AB, 0x0000000000000001,
+	0x000000003E4B48F4, 0x3FEA2136DB7A0984, 0xBFC7BBECAD149DB0, 0x00000000008562C7,
+	0x00000000248E0AE5, 0x0000000000048B87, 0x60FCFE56FF347810, 0x0000000000000001,
+	0x000000003E6E8DAC, 0x3FEB3B5CA3C092F7, 0xBFB66BACF9C3A3DF, 0x000000000056912C,
+	0xFFFFFFFFB4815266, 0x000000000000052B, 0xF0F045D78F27BF91, 0x0000000000000000,
+	0x000000003F4B96B3, 0x3FDA738B20805A66, 0x3FD42CD982C48AC3, 0x000000000049D8AD,
+	0xFFFFFFFFBD5280CB, 0x0000000000002B0A, 0xF9C1743C97F8EDF6, 0x0000000000000000,
+	0x000000003F5467E1, 0x3FDCA7D6C10D6D4C, 0x3FEA07BF19D34188, 0x00000000001B0712,
+	0x000000004D45C84C, 0x0000000000003ADF, 0x89B4BBBE27EC3577, 0x0000000000000001,
+	0x000000003EC8B650, 0x3F84951541943880, 0xBFFA3B503CB7421F, 0x00000000000E4E93,
+	0x000000005616F6B1, 0x00000000000D94F5, 0x9285EA2330BD63DC, 0x0000000000000001,
+	0x000000003EDA58AE, 0x3FA6C7A254CDA560, 0xBFBD73104E734911, 0x0000000000DF7CF8,
+	0xFFFFFFFFE60A3E32, 0x0000000000008441, 0x227931A3C0B0AB5D, 0x0000000000000000,
+	0x000000003F7D1F9E, 0x3FE36AE311CC7492, 0xBFFE2F2CC217CAFD, 0x0000000000D2C479,
+	0x0000000067B9537B, 0x00000000001AE6AC, 0xA42846ED425FC0A6, 0x0000000000000001,
+	0x000000003EFD9D68, 0x3FBD062DEECF69E0, 0x3FE0AA3E67DAB967, 0x000000000081D9C2,
+	0xFFFFFFFFF7AC9AFC, 0x0000000000025790, 0x341B8E6DD2530827, 0x0000000000000000,
+	0x000000003D6C1FB0, 0x3FE59F2EAA598778, 0xBFF74F6C52C5E589, 0x0000000000752143,
+	0x00000000795BB045, 0x00000000000BABEB, 0xB5CAA3B754021D70, 0x0000000000000001,
+	0x000000003F107110, 0x3FC75445599C0088, 0x3FF814B4010AAA16, 0x000000000024368C,
+	0x00000000094EF7C6, 0x00000000000A7867, 0x45BDEB37E3F564F1, 0x0000000000000001,
+	0x000000003E019160, 0x3FE7D37A42E69A5E, 0xBFDE2A7E0F3658CE, 0x0000000000177E0D,
+	0xFFFFFFFF8AFE0D0F, 0x00000000000607CE, 0xC76D008165A47A3A, 0x0000000000000000,
+	0x000000003F22136D, 0x3FD012B9DDE82610, 0x3FFE26F27AF010D9, 0x0000000000C69356,
+	0x000000001AF15490, 0x00000000002E4A33, 0x57604801F597C1BB, 0x0000000000000001,
+	0x000000003E481AD4, 0x3FEA07C5DB73AD44, 0xBFB59E799BAAC463, 0x0000000000B9DAD7,
+	0x0000000023C282F5, 0x0000000000137297, 0x60317666FE68F020, 0x0000000000000001,
+	0x000000003E6B5F8C, 0x3FEB21EBABBA36B8, 0xBFA49474E425EFEF, 0x00000000008B093C,
+	0xFFFFFFFFB3B5CA76, 0x000000000051ED44, 0xF024BDE78E5C37A1, 0x0000000000000000,
+	0x000000003F4ACB2B, 0x3FDA40A93073A1E8, 0x3FD474628704040F, 0x00000000007E50BD,
+	0xFFFFFFFFBC86F8DB, 0x00000000001D7226, 0xF8F5EC4C972D6606, 0x0000000000000000,
+	0x000000003F539C59, 0x3FDC74F4C100B4CE, 0x3FEABBE6B0878670, 0x00000000004F7F22,
+	0x000000004C7A405C, 0x0000000003AAC6E8, 0x88E933CE2720AD87, 0x0000000000000001,
+	0x000000003EC71F40, 0x3F7C71AA7FFA5180, 0xBFF9F4B4D63034A7, 0x000000000042C6A3,
+	0x00000000554B6EC1, 0x0000000000114BB5, 0x91BA62332FF1DBEC, 0x0000000000000001,
+	0x000000003ED8C19E, 0x3FA530925467E160, 0xBFC0E738B04AFD30, 0x000000000013F508,
+	0xFFFFFFFFE53EB642, 0x00000000083762FF, 0x21ADA9B3BFE5236D, 0x0000000000000000,
+	0x000000003F7C5416, 0x3FE3517211C61852, 0xBFFE292F37DBDC9F, 0x0000000000073C89,
+	0x0000000066EDCB8B, 0x000000000009D4EE, 0xA35CBEFD419438B6, 0x0000000000000001,
+	0x000000003EFC0658, 0x3FBC3AA5EE9C87E0, 0x3FDEC331C97EF145, 0x0000000000B651D2,
+	0xFFFFFFFFF6E1130C, 0x0000000025462331, 0x3350067DD1878037, 0x0000000000000000,
+	0x000000003D5F6730, 0x3FE585BDAA532B38, 0xBFF7EFC21EF039CE, 0x0000000000A99953,
+	0x0000000078902855, 0x00000000002A8B37, 0xB4FF1BC753369580, 0x0000000000000001,
+	0x000000003F0FA588, 0x3FC6EE8159828F8C, 0x3FF775CFAC4B45DD, 0x000000000058AE9C,
+	0x0000000008836FD6, 0x0000000000000001, 0x44F26347E329DD01, 0x0000000000000001,
+	0x000000003DFCC680, 0x3FE7BA0942E03E1F, 0xBFE05BEC3112DD05, 0x00000000004BF61D,
+	0xFFFFFFFF8A32851F, 0x000000000004F138, 0xC6A1789164D8F24A, 0x0000000000000000,
+	0x000000003F2147E5, 0x3FCFBFAFBBB6DB24, 0x3FFE2E4765D33ABD, 0x0000000000FB0B66,
+	0x000000001A25CCA0, 0x0000000000000005, 0x5694C011F4CC39CB, 0x0000000000000001,
+	0x000000003E44ECB4, 0x3FE9EE54DB6D5105, 0x3FBE7F6268BEE8DC, 0x0000000000EE52E7,
+	0xFFFFFFFF9BD4E1E9, 0x00000000002F3E21, 0xD843D55B767B4F14, 0x0000000000000000,
+	0x000000003F32EA42, 0x3FD4486F0EF5935E, 0x3FFA2ABCA2DCF84D, 0x00000000009D6830,
+	0x000000002BC8296A, 0x0000000000000019, 0x68371CDC066E9695, 0x0000000000000001,
+	0x000000003E85BB14, 0x3FEC22A07BFA63EB, 0xBFEA327990748E88, 0x000000000090AFB1,
+	0x00000000349957CF, 0x0000000000164F54, 0x71084B410F3FC4FA, 0x0000000000000001,
+	0x000000003E975D70, 0x3FED3CC64440ED5E, 0xBFD43E5029067419, 0x000000000061DE16,
+	0xFFFFFFFFC48C9F50, 0x0000000000000031, 0x00FB92C19F330C7B, 0x0000000000000000,
+	0x000000003F5BA1FF, 0x3FDE765E61810F34, 0x3FB43D82971A8CF3, 0x0000000000552597,
+	0xFFFFFFFFCD5DCDB5, 0x00000000003B78F7, 0x09CCC126A8043AE0, 0x0000000000000000,
+	0x000000003F64732E, 0x3FE055550107110E, 0x3FC5630DCBF88EB2, 0x00000000002653FC,
+	0x000000005D511536, 0x00000000000001F7, 0x99C008A837F78261, 0x0000000000000001,
+	0x000000003EE8CCEA, 0x3FB29DEFAC355A50, 0xBFFE1DA19C181BC0, 0x0000000000199B7D,
+	0x000000006622439B, 0x000000000020B69A, 0xA291370D40C8B0C6, 0x0000000000000001,
+	0x000000003EFA6F48, 0x3FBB6F1DEE69A5E8, 0x3FDC42CFAC52E0B1, 0x0000000000EAC9E2,
+	0xFFFFFFFFF6158B1C, 0x00000000000004A2, 0x32847E8DD0BBF847, 0x0000000000000000,
+	0x000000003D52AEB0, 0x3FE56C4CAA4CCEF9, 0xBFF889CD0F1C9E00, 0x0000000000DE1163,
+	0x0000000077C4A065, 0x0000000000416CEF, 0xB43393D7526B0D90, 0x0000000000000001,
+	0x000000003F0EDA01, 0x3FC688BD59691E8C, 0x3FF6D0FEBFE39C6B, 0x00000000008D26AC,
+	0x0000000007B7E7E6, 0x00000000000014BD, 0x4426DB57E25E5511, 0x0000000000000001,
+	0x000000003DF66A40, 0x3FE7A09842D9E1DF, 0xBFE1AAD06CDDC1E9, 0x0000000000806E2D,
+	0xFFFFFFFF8966FD2F, 0x0000000000256990, 0xC5D5F0A1640D6A5A, 0x0000000000000000,
+	0x000000003F207C5D, 0x3FCF59EBDB9D6A24, 0x3FFE2FCF4827E8CC, 0x00000000002F8376,
+	0x00000000195A44B0, 0x0000000000005BC8, 0x55C93821F400B1DB, 0x0000000000000001,
+	0x000000003E41BE94, 0x3FE9D4E3E366F4C5, 0x3FB9F8EC20B8D45F, 0x000000000022CAF7,
+	0xFFFFFFFF9B0959F9, 0x00000000002AF541, 0xD7784D6B75AFC724, 0x0000000000000000,
+	0x000000003F321EBA, 0x3FD4158D1EE8DAE0, 0x3FFA708F7BA38B1C, 0x0000000000D1E040,
+	0x000000002AFCA17A, 0x0000000000019268, 0x676B94EC05A30EA5, 0x0000000000000001,
+	0x000000003E842404, 0x3FEC092F7BF407AC, 0xBFE97C8A310F27C3, 0x0000000000C527C1,
+	0x0000000033CDCFDF, 0x0000000000186B26, 0x703CC3510E743D0A, 0x0000000000000001,
+	0x000000003E95C660, 0x3FED2355443A911F, 0xBFD3F1D9A042EA3B, 0x0000000000965626,
+	0xFFFFFFFFC3C11760, 0x0000000000030FC4, 0x00300AD19E67848B, 0x0000000000000000,
+	0x000000003F5AD677, 0x3FDE437C617456B6, 0x3FBC4CEB42B80CD6, 0x0000000000899DA7,
+	0xFFFFFFFFCC9245C5, 0x00000000003A625B, 0x09013936A738B2F0, 0x0000000000000000,
+	0x000000003F63A7A6, 0x3FE03BE40100B4CE, 0x3FCE234DEC5D0370, 0x00000000005ACC0C,
+	0x000000005C858D46, 0x00000000001F57A0, 0x98F480B8372BFA71, 0x0000000000000001,
+	0x000000003EE735DA, 0x3FB1D267AC027850, 0xBFFE0CD347E7968D, 0x00000000004E138D,
+	0x000000006556BBAB, 0x00000000001F9FCC, 0xA1C5AF1D3FFD28D6, 0x0000000000000001,
+	0x000000003EF8D838, 0x3FBAA3962E36C3E8, 0x3FD9D3DCF6A0622A, 0x00000000001F41F2,
+	0xFFFFFFFFF54A032C, 0x000000000049C0BE, 0x31B8F69DCFF07057, 0x0000000000000000,
+	0x000000003D45F630, 0x3FE552DBB24672B9, 0xBFF91D1B375E15DA, 0x0000000000128973,
+	0x0000000076F91875, 0x00000000004B85D7, 0xB3680BE7519F85A0, 0x0000000000000001,
+	0x000000003F0E0E79, 0x3FC622F9794FAD90, 0x3FF626C5136FD195, 0x0000000000C19EBC,
+	0x0000000006EC5FF6, 0x00000000014A47B3, 0x435B5367E192CD21, 0x0000000000000000,
+	0x000000003DF00E00, 0x3FE787274AD385A0, 0xBFE3018CAEF28198, 0x0000000000B4E63D,
+	0xFFFFFFFF889B753F, 0x000000000032B4B0, 0xC50A68B16341E26A, 0x0000000000000000,
+	0x000000003F1FB0D5, 0x3FCEF427DB83F928, 0x3FFE2B3AE9A0D1D7, 0x000000000063FB86,
+	0x00000000188EBCC0, 0x0000000005B631B3, 0x54FDB031F33529EB, 0x0000000000000001,
+	0x000000003E3E9074, 0x3FE9BB72E3609886, 0x3FB538ABE63B2A04, 0x0000000000574307,
+	0xFFFFFFFF9A3DD209, 0x00000000003D0BB1, 0xD6ACC57B74E43F34, 0x0000000000000000,
+	0x000000003F315332, 0x3FD3E2AB1EDC2260, 0x3FFAB54465D7D1E5, 0x0000000000065850,
+	0x000000002A31198A, 0x00000000190D1268, 0x66A00CFC04D786B5, 0x0000000000000001,
+	0x000000003E828CF4, 0x3FEBEFBE7BEDAB6C, 0xBFE8C40E88056E8F, 0x0000000000F99FD1,
+	0x00000000330247EF, 0x0000000000474588, 0x6F713B610DA8B51A, 0x0000000000000001,
+	0x000000003E942F50, 0x3FED09E44C3434DF, 0xBFD39ECD196D366C, 0x0000000000CACE36,
+	0xFFFFFFFFC2F58F70, 0x0000000000000000, 0xFF6482E19D9BFC9B, 0x0000000000000000,
+	0x000000003F5A0AF0, 0x3FDE109A71679E36, 0x3FC135A14E6F315F, 0x0000000000BE15B7,
+	0xFFFFFFFFCBC6BDD5, 0x00000000004C78D7, 0x0835B146A66D2B00, 0x0000000000000000,
+	0x000000003F62DC1E, 0x3FE0227300FA588F, 0x3FD27939433746AE, 0x00000000008F441C,
+	0x000000005BBA0556, 0x0000000000000003, 0x9828F8C836607281, 0x0000000000000001,
+	0x000000003EE59ECA, 0x3FB106DFABCF9658, 0xBFFDF71298F6A9D8, 0x0000000000828B9D,
+	0x00000000648B33BB, 0x000000000029B8AC, 0xA0FA272D3F31A0E6, 0x0000000000000001,
+	0x000000003EF74128, 0x3FB9D80E2E03E1F0, 0x3FD776C0C4846FFD, 0x000000000053BA02,
+	0xFFFFFFFFF47E7B3C, 0x0000000000000009, 0x30ED6EADCF24E867, 0x0000000000000000,
+	0x000000003D393DB0, 0x3FE5396AB240167A, 0xBFF9A946F6A6F25C, 0x0000000000470183,
+	0x00000000762D9085, 0x000000000028DFFF, 0xB29C83F750D3FDB0, 0x0000000000000001,
+	0x000000003F0D42F1, 0x3FC5BD3579363C90, 0x3FF577AED3214A67, 0x0000000000F616CC,
+	0x000000000620D806, 0x0000000000000029, 0x428FCB77E0C74531, 0x0000000000000000,
+	0x000000003DE9B1C0, 0x3FE76DB64ACD2960, 0xBFE45FB09D016224, 0x0000000000E95E4D,
+	0xFFFFFFFF87CFED4F, 0x0000000000100EDC, 0xC43EE0C162765A7A, 0x0000000000000000,
+	0x000000003F1EE54D, 0x3FCE8E63DB6A8828, 0x3FFE203BCB031DB2, 0x0000000000987396,
+	0x0000000017C334D0, 0x00000000000000B5, 0x54322841F269A1FB, 0x0000000000000001,
+	0x000000003E3B6254, 0x3FE9A201E35A3C46, 0x3FB03D0C0D1015B3, 0x00000000008BBB17,
+	0xFFFFFFFF99724A19, 0x00000000005A51C9, 0xD5E13D8B7418B744, 0x0000000000000000,
+	0x000000003F3087AA, 0x3FD3AFC91ECF69E2, 0x3FFAF8BE75E51107, 0x00000000003AD060,
+	0x000000002965919A, 0x000000000000031E, 0x65D4850C040BFEC5, 0x0000000000000001,
+	0x000000003E80F5E4, 0x3FEBD64D7BE74F2D, 0xBFE808C00C9772A3, 0x00000000002E17E1,
+	0x000000003236BFFF, 0x0000000000515E94, 0x6EA5B3710CDD2D2A, 0x0000000000000001,
+	0x000000003E929840, 0x3FECF0734C2DD8A0, 0xBFD344FB58EF7FD7, 0x0000000000FF4646,
+	0xFFFFFFFFC22A0780, 0x0000000000000606, 0xFE98FAF19CD074AB, 0x0000000000000000,
+	0x000000003F593F68, 0x3FDDDDB8715AE5B8, 0x3FC3C0A7CA09F802, 0x0000000000F28DC7,
+	0xFFFFFFFFCAFB35E5, 0x000000000029D30F, 0x076A2956A5A1A310, 0x0000000000000000,
+	0x000000003F621096, 0x3FE0090200F3FC4F, 0x3FD5607D1E45896F, 0x0000000000C3BC2C,
+	0x000000005AEE7D66, 0x0000000000003E49, 0x975D70D83594EA91, 0x0000000000000001,
+	0x000000003EE407BC, 0x3FB03B57AB9CB458, 0xBFFDDCAC8C14C7D9, 0x0000000000B703AD,
+	0x0000000063BFABCB, 0x00000000000579B6, 0xA02E9F3D3E6618F6, 0x0000000000000001,
+	0x000000003EF5AA18, 0x3FB90C862DD0FFF0, 0x3FD52BC9925B8E7A, 0x0000000000883212,
+	0xFFFFFFFFF3B2F34C, 0x00000000000091EA, 0x3021E6BDCE596077, 0x0000000000000000,
+	0x000000003D2C8530, 0x3FE51FF9B239BA3A, 0xBFFA2DF7335FA8BA, 0x00000000007B7993,
+	0x0000000075620895, 0x00000000006E197F, 0xB1D0FC07500875C0, 0x0000000000000001,
+	0x000000003F0C7769, 0x3FC55771791CCB94, 0x3FF4C44ED8BD3FDE, 0x00000000002A8EDC,
+	0x0000000005555016, 0x0000000000028E33, 0x41C44387DFFBBD41, 0x0000000000000000,
+	0x000000003DE35580, 0x3FE754454AC6CD21, 0xBFE5C4B9BD685364, 0x00000000001DD65D,
+	0xFFFFFFFF8704655F, 0x00000000006A0EA4, 0xC37358D161AAD28A, 0x0000000000000001,
+	0x000000003F1E19C5, 0x3FCE289FDB51172C, 0x3FFE0E8566C436B4, 0x0000000000CCEBA6,
+	0x0000000016F7ACE0, 0x00000000000B52F2, 0x5366A051F19E1A0B, 0x0000000000000001,
+	0x000000003E383434, 0x3FE98890E353E007, 0x3FA608F58C347293, 0x0000000000C03327,
+	0xFFFFFFFF98A6C229, 0x00000000007DFC41, 0xD515B59B734D2F54, 0x0000000000000000,
+	0x000000003F2FBC22, 0x3FD37CE71EC2B162, 0x3FFB3ADE6BFEE84E, 0x00000000006F4870,
+	0x00000000289A09AA, 0x000000000031B460, 0x6508FD1C034076D5, 0x0000000000000001,
+	0x000000003E7EBDA8, 0x3FEBBCDC83E0F2ED, 0xBFE74A4E81826D5C, 0x0000000000628FF1,
+	0x00000000316B380F, 0x000000000059DE7A, 0x6DDA2B810C11A53A, 0x0000000000000001,
+	0x000000003E910130, 0x3FECD7024C277C60, 0xBFD2E429E86A0E6F, 0x000000000033BE56,
+	0xFFFFFFFFC15E7F90, 0x00000000005F95F9, 0xFDCD73019C04ECBB, 0x0000000000000000,
+	0x000000003F5873E0, 0x3FDDAAD6714E2D38, 0x3FC5F53D35058DE6, 0x00000000002705D7,
+	0xFFFFFFFFCA2FADF5, 0x000000000010C3D7, 0x069EA166A4D61B20, 0x0000000000000000,
+	0x000000003F61450E, 0x3FDFDF2211DB4020, 0x3FD7F66B753CB48B, 0x0000000000F8343C,
+	0x000000005A22F576, 0x0000000003E169BC, 0x9691E8E834C962A1, 0x0000000000000001,
+	0x000000003EE270AC, 0x3FAEDF9FD6D3A4C0, 0xBFFDBDEC3969871E, 0x0000000000EB7BBD,
+	0x0000000062F423DB, 0x0000000000079518, 0x9F63174D3D9A9106, 0x0000000000000001,
+	0x000000003EF41308, 0x3FB840FE2D9E1DF8, 0x3FD2F32C5E0DE5F4, 0x0000000000BCAA22,
+	0xFFFFFFFFF2E76B5C, 0x000000000911EE51, 0x2F565ECDCD8DD887, 0x0000000000000000,
+	0x000000003D1FCCB0, 0x3FE50688B2335DFB, 0xBFFAAAE092429ACF, 0x0000000000AFF1A3,
+	0x00000000749680A5, 0x0000000000503EEF, 0xB10574174F3CEDD0, 0x0000000000000001,
+	0x000000003F0BABE1, 0x3FC4F1AD79035A94, 0x3FF40D3CF83A2414, 0x00000000005F06EC,
+	0x000000000489C826, 0x0000000028B05077, 0x40F8BB97DF303551, 0x0000000000000000,
+	0x000000003DDCF940, 0x3FE73AD44AC070E1, 0xBFE73013B5A6DEBC, 0x0000000000524E6D,
+	0xFFFFFFFF8638DD6F, 0x00000000003906EA, 0xC2A7D0E160DF4A9A, 0x0000000000000001,
+	0x000000003F1D4E3E, 0x3FCDC2DBFB37A62C, 0x3FFDF5CDAFBCFD90, 0x00000000000163B6,
+	0x00000000162C24F0, 0x0000000000000001, 0x529B1861F0D2921B, 0x0000000000000001,
+	0x000000003E350614, 0x3FE96F1FEB4D83C7, 0x3F963595FAE7C7E6, 0x0000000000F4AB37,
+	0xFFFFFFFF97DB3A39, 0x000000000059BD51, 0xD44A2DAB7281A764, 0x0000000000000000,
+	0x000000003F2EF09A, 0x3FD34A052EB5F8E4, 0x3FFB7B82755EF835, 0x0000000000A3C080,
+	0x0000000027CE81BA, 0x0000000000000006, 0x643D752C0274EEE5, 0x0000000000000001,
+	0x000000003E7B8F88, 0x3FEBA36B83DA96AE, 0xBFE6885DF10DED9A, 0x0000000000970801,
+	0x00000000309FB01F, 0x00000000007BEFE8, 0x6D0EA3910B461D4A, 0x0000000000000001,
+	0x000000003E8F6A20, 0x3FECBD914C212021, 0xBFD27C11B4DA3346, 0x0000000000683666,
+	0xFFFFFFFFC092F7A0, 0x000000000000000B, 0xFD01EB119B3964CB, 0x0000000000000000,
+	0x000000003F57A858, 0x3FDD77F4714174BA, 0x3FC7EAF867E18DCE, 0x00000000005B7DE7,
+	0xFFFFFFFFC9642605, 0x000000000082BC1B, 0x05D31976A40A9330, 0x0000000000000000,
+	0x000000003F607986, 0x3FDFAC4011CE87A0, 0x3FDA532C146A27A4, 0x00000000002CAC4C,
+	0x0000000059576D86, 0x000000000000007B, 0x95C660F833FDDAB1, 0x0000000000000001,
+	0x000000003EE0D99C, 0x3FAD488FD66DE0C0, 0xBFFD9B1A88179085, 0x00000000001FF3CD,
+	0x0000000062289BEB, 0x0000000000300AD2, 0x9E978F5D3CCF0916, 0x0000000000000001,
+	0x000000003EF27BF8, 0x3FB775766D6B3BF8, 0x3FD0CD084EE8C5C3, 0x0000000000F12232,
+	0xFFFFFFFFF21BE36C, 0x0000000000000120, 0x2E8AD6DDCCC25097, 0x0000000000000000,
+	0x000000003D131440, 0x3FE4ED17BA2D01BB, 0xBFFB1FC5B3D7D2BE, 0x0000000000E469B3,
+	0x0000000073CAF8B5, 0x000000000000DA57, 0xB039EC274E7165E0, 0x0000000000000001,
+	0x000000003F0AE059, 0x3FC48BE998E9E998, 0x3FF35313DAA0D18C, 0x0000000000937EFC,
+	0x0000000003BE4036, 0x000000000000050F, 0x402D33A7DE64AD61, 0x0000000000000000,
+	0x000000003DD69D00, 0x3FE7216352BA14A2, 0xBFE8A116E884D8B3, 0x000000000086C67D,
+	0xFFFFFFFF856D557F, 0x00000000001FF7A6, 0xC1DC48F16013C2AA, 0x0000000000000001,
+	0x000000003F1C82B6, 0x3FCD5D17FB1E3530, 0x3FFDD5CEC283D342, 0x000000000035DBC6,
+	0x0000000015609D00, 0x0000000000001673, 0x51CF9071F0070A2B, 0x0000000000000001,
+	0x000000003E31D7F4, 0x3FE955AEEB472788, 0xBF44DCCA3F30259B, 0x0000000000292347,
+	0xFFFFFFFF970FB249, 0x000000000096F955, 0xD37EA5BB71B61F74, 0x0000000000000000,
+	0x000000003F2E2512, 0x3FD317232EA94064, 0x3FFBBA8653F0A757, 0x0000000000D83890,
+	0x000000002702F9CA, 0x000000000000629D, 0x6371ED3C01A966F5, 0x0000000000000001,
+	0x000000003E786168, 0x3FEB89FA83D43A6E, 0xBFE5C283AF34C519, 0x0000000000CB8011,
+	0x000000002FD4282F, 0x000000000047B60A, 0x6C431BA10A7A955A, 0x0000000000000001,
+	0x000000003E8DD310, 0x3FECA420541AC3E1, 0xBFD20C5BBFFDEF04, 0x00000000009CAE76,
+	0xFFFFFFFFBFC76FB0, 0x000000000000BBFD, 0xFC3663219A6DDCDB, 0x0000000000000000,
+	0x000000003F56DCD0, 0x3FDD45128134BC3A, 0x3FC9AFE549A6A41C, 0x00000000008FF5F7,
+	0xFFFFFFFFC8989E15, 0x000000000021C377, 0x05079186A33F0B40, 0x0000000000000000,
+	0x000000003F5FADFE, 0x3FDF795E11C1CF22, 0x3FDC8519EBA60F3C, 0x000000000061245C,
+	0x00000000588BE596, 0x000000000007B61A, 0x94FAD908333252C1, 0x0000000000000001,
+	0x000000003EDF428C, 0x3FABB17FD6081CD0, 0xBFFD747DB70A7130, 0x0000000000546BDD,
+	0x00000000615D13FB, 0x000000000099D360, 0x3C038125DA3AFADF, 0x0000000000000000,
+	0x000000003DB54F70, 0x3FE69C2D0298C70E, 0x3FCD72CC6C63A5E1, 0x0000000000259A42,
+	0xFFFFFFFF8F87D535, 0x000000000005B7E9, 0xCBF6C8A76A2E4260, 0x0000000000000000,
+	0x000000003F269D35, 0x3FD1352BEE30C294, 0x3FA1EB09490A7DEE, 0x0000000000505B7C,
+	0xFFFFFFFF9859039A, 0x000000000000D1C1, 0xD4C7F70C72FF70C5, 0x0000000000000000,
+	0x000000003F2F6E64, 0x3FD369777EBDD57A, 0x3FFB53BE2BAB8477, 0x00000000002189E1,
+	0x00000000284C4B1B, 0x00000000006341E2, 0x64BB3E8D02F2B846, 0x0000000000000001,
+	0x000000003E7D86AC, 0x3FEBB324ABDE84F9, 0xBFE700A8F84F17DE, 0x000000000014D162,
+	0x00000000311D7980, 0x0000000000AFA506, 0x6D8C6CF20BC3E6AB, 0x0000000000000001,
+	0x000000003E9065B4, 0x3FECCD4A7C250E6C, 0xBFD2BD4717266487, 0x0000000000E5FFC7,
+	0xFFFFFFFFC110C101, 0x0000000000BE9075, 0xFD7FB4729BB72E2C, 0x0000000000000000,
+	0x000000003F582621, 0x3FDD9766D1495150, 0x3FC6BB6706D851B9, 0x0000000000D94748,
+	0xFFFFFFFFC9E1EF66, 0x00000000000D0CCF, 0x0650E2D7A4885C91, 0x0000000000000000,
+	0x000000003F60F750, 0x3FDFCBB261D66436, 0x3FD8E33EA86A8508, 0x0000000000AA75AD,
+	0x0000000059D536E7, 0x0000000007C06585, 0x96442A59347BA412, 0x0000000000000001,
+	0x000000003EE1D52E, 0x3FAE442256ACC570, 0xBFFDB1166D800177, 0x00000000009DBD2E,
+	0x0000000062A6654C, 0x00000000006EEF82, 0x9F1558BE3D4CD277, 0x0000000000000001,
+	0x000000003EF3778A, 0x3FB7F33FAD8AAE50, 0x3FD21ED8F4270C8F, 0x00000000006EEB93,
+	0xFFFFFFFFF299ACCD, 0x00000000121A24D0, 0x2F08A03ECD4019F8, 0x0000000000000000,
+	0x000000003D1AF0D0, 0x3FE4FCD0E230F006, 0xBFFAD87C6BAC84E3, 0x0000000000623314,
+	0x000000007448C216, 0x00000000000969B7, 0xB0B7B5884EEF2F41, 0x0000000000000001,
+	0x000000003F0B5E22, 0x3FC4CACE38F9A2C4, 0x3FF3C675CF5BB119, 0x000000000011485D,
+	0x00000000043C0997, 0x0000000000000000, 0x40AAFD08DEE276C2, 0x0000000000000000,
+	0x000000003DDA8B50, 0x3FE7311C7ABE02ED, 0xBFE7BC66D2C79348, 0x0000000000048FDE,
+	0xFFFFFFFF85EB1EE0, 0x00000000001EF072, 0xC25A125260918C0B, 0x0000000000000001,
+	0x000000003F1D007F, 0x3FCD9BFC9B2DEE5C, 0x3FFDEA749783A367, 0x0000000000B3A527,
+	0x0000000015DE6661, 0x0000000000000002, 0x524D59D2F084D38C, 0x0000000000000001,
+	0x000000003E33CF18, 0x3FE96568134B15D3, 0x3F8B314687BE17ED, 0x0000000000A6ECA8,
+	0xFFFFFFFF978D7BAA, 0x0000000000B91A49, 0xD3FC6F1C7233E8D5, 0x0000000000000000,
+	0x000000003F2EA2DC, 0x3FD336958EB11CFA, 0x3FFB93C7B9D945E6, 0x00000000005601F1,
+];
+pub const SUBCLASS_WEYL_SHUFFLE: [u8; 52] = [10, 47, 6, 43, 38, 15, 22, 19, 3, 17, 24, 29, 51, 14, 21, 12, 13, 42, 35, 50, 23, 8, 16, 7, 1, 4, 0, 30, 48, 31, 36, 28, 27, 41, 9, 33, 5, 26, 45, 39, 18, 2, 44, 46, 40, 20, 25, 11, 34, 32, 49, 37];
+pub const RAND_SHUFFLE_SEED: u64 = 3198;
+pub const RAND_SHUFFLE: [u8; 52] = [46, 47, 32, 27, 12, 50, 11, 17, 16, 41, 51, 44, 37, 21, 28, 25, 31, 0, 34, 29, 43, 23, 8, 30, 1, 26, 13, 5, 40, 14, 22, 45, 18, 38, 6, 49, 15, 2, 10, 9, 20, 7, 36, 35, 42, 3, 39, 4, 33, 24, 19, 48];
+pub const MC_POPULATION_SEEDS: [(i64, i32, i32, i64, i64, i64); 64] = [
+	(-3206178037542007805, -14520, -26290, -4357916706396060667, -3184709632474035101, -5212846955248670237),
+	(7586378457714819858, -7483, 14573, -5660054134255517960, -1514151501500235246, 7754223553921638418),
+	(1174185793357346688, -6011, -4727, 9179100165607939564, -2100831811746504288, 4995746521504971200),
+	(7804455303314506011, 7949, 24791, 1787543156920237013, 2615189751433670139, 1653340735082619771),
+	(-7710636699738579807, 18295, 9205, 2622158529383778799, -7122680271865312543, 7104871513820494369),
+	(6543192474638934185, 16471, 9335, 7618188730545778059, 7907366896374948841, 4050202764577938793),
+	(7406930037181816003, 1033, 2271, 5626549005012417829, -1496952616207933885, 1352012092002248195),
+	(8450137699688171230, -12486, -3355, 8135230605838006033, 3415995905541921326, -6531996079793352626),
+	(-5310082034276689174, -1969, 9014, -2516700217925642387, 255617906457297562, 6201279792521692346),
+	(1077166801000829118, -27851, -18951, 6186980724253501594, -4893678303084539138, -4937363890804254146),
+	(7366419916998151226, 3270, 14285, -177981715900446073, -2455498765494786070, 1797944123579013162),
+	(-6288729946546934864, -2667, 12654, -8622334781025712121, -6461569593170533536, 5061419230365648448),
+	(-4637659961137031041, 25558, 10678, 7522770942341005075, -4324527958024098113, -6041025867438540801),
+	(6105836211150290433, -17235, 25216, -6410002841544744284, 8055063241564161105, -7561412557715872079),
+	(1003805393999694502, -3119, 10183, -5130497588582979200, 6899931699637860550, -4136555410386481562),
+	(-7980817123180961952, 13181, 17325, 6430922516035235978, 1237082051289092192, 8189768355279566240),
+	(8032988495186661698, 15507, 22233, -3568650577125793902, 9173230696251423810, 2373627820618469634),
+	(5807520940330295651, -9907, 12632, 437257963085785690, 4037495897179493107, -8740829249617975853),
+	(-2054040083703787768, -13247, -22781, 4793036336022472508, -228749217366084568, -6801474064990355672),
+	(1387058328196032513, 25136, 993, -6556306554315345744, 8140238499343378161, -1999596063694740911),
+	(-9173716479307482285, 28588, -9597, 8159713533816424970, -7465563416923345213, -8944605862738091101),
+	(-3444081243956112873, -23292, 3725, 580817764314434500, 6017131497337777831, 5382327732188568583),
+	(-3767332842196217183, 3565, 8053, -7066316659476841423, -5892340451530343999, -7949720213874264159),
+	(-1776437326112787997, -9266, 6980, 6312490482979901569, -1481012107921365053, -6049843233139783165),
+	(6306118180976826131, 29160, 5273, 5047152025252258434, 5697023056032387811, -7441354702333569533),
+	(-9185882257390234228, -6464, -24467, 2582593686018390095, -5357796236708558404, 8670175074608444636),
+	(3816612144264536305, -19893, 17233, 6707372499031622879, -6136188684711353839, -4588534060792209999),
+	(-8550686752670244968, -17355, 5107, -9137375992852912566, 1085419929388450488, 2973137951128666008),
+	(4220330054449856292, -13587, 28896, 358017690539891417, -2524670054899048204, 3718893804242950548),
+	(-357859643262226573, 10481, 2787, -430441811813659193, -1446043074600557997, 2639413405903355475),
+	(-2882313737156253765, 2353, -10773, 6734186426934392163, 9172343963370097211, 4511927367013139675),
+	(3525553006542756335, 2147, -13301, -511749244578592975, -5735518965036582801, 810086555904725647),
+	(-234071201591740314, -15326, -1093, 9051773855743752725, -1647408045715513514, 7401517170854042294),
+	(-2362928733663055426, 6083, -18020, -4141251387086019219, 4905301075559015790, -1900092849867345010),
+	(-7764037158679312642, 27127, 9945, 5425706397887025602, 7275213042841558622, 6179519773243367710),
+	(-6256699888838696966, 28118, -9175, -7379532514246896573, -6702955556075756438, 9110183050310464394),
+	(4405168115195593211, -11698, 7376, -3928810996614974887, 8693757733457066971, 7775695397305943707),
+	(7863715882797845662, 15943, -18171, 5883132530352287782, -6527786388900813186, 3853365947952164478),
+	(3211016406550470908, -8878, -9745, 5250746152790548849, 7607890412436748332, 4014087625945156972),
+	(1987343210913978123, -8882, 11592, 6345536316648416601, 2463167177327702571, -7519948271083072917),
+	(-6788559782353230525, -4074, -6684, 5958224725443001421, -7524142496399706717, -4665572339898465053),
+	(6223565035225620100, -566, 5290, 2281410979076673088, -3952737119543014012, -916767968277224316),
+	(-1631662033522021834, -13287, 7969, 5403625521139062822, -3228692887645177898, 2716843249461966198),
+	(2591143794117925554, -14136, -29242, -8023646653589015292, 1641721804190783506, -2090017478096578222),
+	(-5578528001370913312, 9441, 3481, -4816814854515772808, 5311914031483518048, -2804650067025596352),
+	(-5042430526341659616, -28325, 15544, 6058962756174924491, 6557973534726883184, -5691629993769174320),
+	(7298772968639166829, -14398, 259, 190441882523227412, 1991744405660513853, -837491656057069923),
+	(-4471938817804055361, 22563, -7425, -3211620557319214595, -1722820528652150625, 5786781499396405471),
+	(4100362886035714481, 28919, 19958, -1241196508015107518, 6554966083222081153, 5579422998994873569),
+	(-9152640249730897820, -23287, 5883, -1255844993214422968, 7379872334344964868, 637461792811050020),
+	(673016289620531106, -21499, 5995, 3652779674089794932, -5245568302591657278, -9036213494344317758),
+	(-200969611318303418, 15998, -9265, 8832995685260295725, -8716826926744284170, -390513423035261962),
+	(5286756265357816026, 540, 3806, -6818643116513930892, -3941183259994681350, 3776045288733324730),
+	(-8114117785785543552, -18367, -17921, 3089300271309086534, -3107639182182684448, 2712205472879484544),
+	(-191631804513447914, -8041, -22931, -5224738457751777706, 6901151823665493014, 6039281323002411062),
+	(5870134353702912661, -29129, 18995, 4009444665746521197, 4350164475861560597, -1870371292764028939),
+	(6662241202214262201, -9320, -28810, 3884447523828552515, -4206116634547733991, 4211413523359434137),
+	(-9199892219196375066, -14381, 13586, -5464504315699808211, -4051230877531307178, -1800229829041407370),
+	(-7927074593135181750, -18577, -29155, -1048326822891184606, -7417109890533251382, 6202224618172514570),
+	(3965430709289204030, 25901, 13255, -2022555823994323534, 8750400223020052990, -847986791811585634),
+	(1954023074289994993, 27762, 18755, 2554713398200702388, -7080244318168018623, 5087112348719347745),
+	(-2260308132474412985, -18036, 22207, 1458222504123301858, -6235299295123580393, -2126982134067404169),
+	(7587318745314060286, 8739, 19343, -3593613854131913708, 2304720230086045598, -4227526574730398370),
+	(6717894044410143762, 26302, 18633, -4775675580791478367, 6125058657439407906, 6618637251287871874),
+];
+pub const MC_RANDOM_SEQUENCES: [(i64, &str, [i64; 4], [i64; 4]); 18] = [
+	(0, "minecraft:chests/simple_dungeon", [-7509090172973561982, 8431096442898752500, -5299148278949906928, -5940341443053023236, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(0, "minecraft:entities/zombie", [7993438629715425849, -7802830811864583081, 5744626119936031736, 2881017630995750375, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(0, "minecraft:gameplay/fishing", [-69915297885018887, -2321578257773348427, 476759293464214540, -313036125512925449, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(0, "", [-6999601057908354806, 5496150707918029470, 498324244901027497, 2248133166756849333, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(0, "custom:\u{fc}n\u{ef}code/path", [7936988963974705021, 6964817823387102282, 1082234591547646855, 2495222434677280014, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(0, "minecraft:a/rather/long/resource/location/that/needs/two/blocks", [-8035609036320709382, -1106340135287285156, 2451778297724798439, 3008522226711220044, ], [3038984756725240190, -3694039286755638414, 4633751808701151732, 2160572957309072155, ]),
+	(3444, "minecraft:chests/simple_dungeon", [5960439251358486858, -5739761187972080833, 6255365499331605294, -2943937780376649885, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(3444, "minecraft:entities/zombie", [4803068409052878034, -5043524868789606991, -3631220505763944345, -2119845703562598781, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(3444, "minecraft:gameplay/fishing", [-3196229916080836053, 2395939381641724913, 2352085522834469186, -2587402975192252942, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(3444, "", [6874174394578384505, 3905812453167479403, 2577546243548425120, -8664110119850461434, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(3444, "custom:\u{fc}n\u{ef}code/path", [-4194585220050832048, -2075438816100644725, 1235378715288854069, 5445893124232989747, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(3444, "minecraft:a/rather/long/resource/location/that/needs/two/blocks", [6888734175102269148, -6604368981738723323, 7186190090972480788, 3191233795471952959, ], [-2062420189046068623, 1885648388526653115, 8999743014921866775, -4259007555655456971, ]),
+	(-4172144997902289642, "minecraft:chests/simple_dungeon", [1427891962796759513, 309051494640195820, -9061685028857652198, 5252585117378783695, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+	(-4172144997902289642, "minecraft:entities/zombie", [8385821041652704014, 3433168640667573535, 5744003592589879410, -6092444684606614672, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+	(-4172144997902289642, "minecraft:gameplay/fishing", [-9209464278412331176, -1633858433935894812, 6311913652250754805, 8972729639729280472, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+	(-4172144997902289642, "", [4037377633942783835, -4054153286238488810, 4281126520543375309, -6528047614902486251, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+	(-4172144997902289642, "custom:\u{fc}n\u{ef}code/path", [1969430138480367238, -409441270607305030, -5901364658428341810, 6422250021363799923, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+	(-4172144997902289642, "minecraft:a/rather/long/resource/location/that/needs/two/blocks", [2596904605589378123, -6092505771085029276, -3705403167088314974, 4297422373034615721, ], [-517336468233864830, 3486191825353438332, 7544346269521381194, -1149845637576337820, ]),
+];
+pub const MIX_64: [[u64; 4]; 16] = [
+	[0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+	[0x0000000000000001, 0xB456BCFC34C2CB2C, 0x5692161D100B05E5, 0xC6CAF8CBA3316ACC],
+	[0x958496BB0B55C9F5, 0xE73C587FBCC442B2, 0x58A1BBAC281B8F05, 0x2F80E702A2B4D474],
+	[0x4B18BE7E66C5FC7D, 0xEC35D26368143075, 0xDA1107B6D11E6FE8, 0xE1FB71582D2CF556],
+	[0xECD929FEE463C05E, 0xBF963BDB4A559139, 0x91182D1CA672C859, 0x5D9AF90B07DCB67F],
+	[0x7D5CFDECC4B89BFF, 0x82D273C4E85F1E5A, 0x8D8378806E88D4FC, 0x6F6C3CF1FBE8E27C],
+	[0xBE331D69AD7FEBD4, 0x683D7FA783E4EFE3, 0x354CCEC4A172B085, 0xD8B3298E8B0FAD38],
+	[0xE32155742FEDC455, 0xF194A21B100AEA71, 0xA2BE8AE9976B1706, 0xB3C1C59472B8FA6D],
+	[0x269BB39BF1417141, 0x4EEE528484E12DD8, 0x19D1CFCDE3956740, 0x513359278AF3D578],
+	[0x3955D10CD660282A, 0x425F67E0FCDDF56B, 0xB0A160BFF34ABCCB, 0xD7E236C6A3867527],
+	[0x6B65236520ADC797, 0x10BC3C3CD7BB9399, 0x553746666CF53553, 0x386309944617AA76],
+	[0x1B7886FD65B69145, 0xA79F47DA15E2DA71, 0xB150DDC29E6F6998, 0xF3A06BA4AC21D950],
+	[0xB762C288FF3F0549, 0xD7077A81FB8F805C, 0x3CB2635F0395D719, 0x7102B9CE9E8EA118],
+	[0xAACACA3834602D21, 0x6324843DB600A65D, 0x3D7EC0DF057DAEC3, 0x2E05E61493822F32],
+	[0xF93298C361FD10FC, 0x402427E8487E5551, 0x8583848DC0009F9B, 0xB5D2977A72676731],
+	[0x8BBC9F0372508EBC, 0x4D47CC9D3D1337AB, 0x8A556939D691FFD4, 0xE5BAB5383DE329FA],
+];
+pub const MIX_32: [[u32; 3]; 16] = [
+	[0x00000000, 0x00000000, 0x00000000],
+	[0x00000001, 0x514E28B7, 0x2AA3CE81],
+	[0x0B55C9F5, 0xA469932D, 0xBB629E55],
+	[0x66C5FC7D, 0xE48F16A9, 0xD9A07FE2],
+	[0xE463C05E, 0x791B9652, 0xD391928F],
+	[0xC4B89BFF, 0xA7F17B06, 0xAD95E86E],
+	[0xAD7FEBD4, 0x59A0EFB2, 0xABC566BB],
+	[0x2FEDC455, 0x66FA34AB, 0xE6CA0565],
+	[0xF1417141, 0xC208DDDC, 0xA38DEF9D],
+	[0xD660282A, 0x348B971C, 0x18AF5400],
+	[0x20ADC797, 0x0766BD9F, 0x05F74900],
+	[0x65B69145, 0xB08810A5, 0xF5B0EDB0],
+	[0xFF3F0549, 0xE7A77643, 0x533E6EED],
+	[0x34602D21, 0xBDF6FD57, 0x342880AF],
+	[0x61FD10FC, 0x00553AD2, 0xA5486D22],
+	[0x72508EBC, 0xACA421F3, 0xE21D1A80],
+];
+pub const MIX_GAMMA: [[u64; 2]; 16] = [
+	[0x0000000000000000, 0xAAAAAAAAAAAAAAAB],
+	[0x0000000000000001, 0xB456BCFC34C2CB2D],
+	[0x958496BB0B55C9F5, 0xE73C587FBCC442B3],
+	[0x4B18BE7E66C5FC7D, 0xEC35D26368143075],
+	[0xECD929FEE463C05E, 0xBF963BDB4A559139],
+	[0x7D5CFDECC4B89BFF, 0x82D273C4E85F1E5B],
+	[0xBE331D69AD7FEBD4, 0xC297D50D294E4549],
+	[0xE32155742FEDC455, 0xF194A21B100AEA71],
+	[0x269BB39BF1417141, 0x4EEE528484E12DD9],
+	[0x3955D10CD660282A, 0x425F67E0FCDDF56B],
+	[0x6B65236520ADC797, 0x10BC3C3CD7BB9399],
+	[0x1B7886FD65B69145, 0xA79F47DA15E2DA71],
+	[0xB762C288FF3F0549, 0xD7077A81FB8F805D],
+	[0xAACACA3834602D21, 0x6324843DB600A65D],
+	[0xF93298C361FD10FC, 0x402427E8487E5551],
+	[0x8BBC9F0372508EBC, 0x4D47CC9D3D1337AB],
+];
+pub const SPLITMIX_SEED: i64 = -90210;
+pub const SPLITMIX_DRAWS: [u64; 20] = [
+	0xEF78226F6652B128, 0x00000000D985E5B5, 0x3FE24507274C13AE, 0x000000003F1D7ACA,
+	0x2D85CBC2DFFC3B79, 0x000000003DCED56C, 0x3FE1507D242B8F78, 0x000000003C67D880,
+	0x51DBEE6B0C48D6A9, 0x00000000D31D9532, 0x3FE61543B17C2BF4, 0x000000003F72C42C,
+	0x2CAF4ACCF2960370, 0x00000000D5BE9C94, 0x3FC189EF7608C410, 0x000000003F4F5FA1,
+	0x2CD473B2038D05EE, 0x00000000A2C1674A, 0x3FE2002BD9150BBD, 0x000000003EC5B3CC,
+];
+pub const SPLITMIX_SPLIT: [[i64; 2]; 6] = [
+	[-8827750526700184171, -724010837257635570],
+	[-8398838644778121019, -7582860973291714326],
+	[3810061233197035377, 2252041555637943095],
+	[-5294767258295893686, -6960851309879910264],
+	[6376031684950923853, -462500780066386160],
+	[-4722451476545383232, 5589430029607906264],
+];
+pub const NEXT_BYTES_SEED: u64 = 4711;
+pub const SPLITMIX_NEXT_BYTES: [u8; 153] = [
+	0xDB, 0xB4, 0xA1, 0x4B, 0xFD, 0x28, 0x2C, 0x21, 0x68, 0xEE, 0xAA, 0x96, 0x49, 0x00, 0xAA, 0x6E,
+	0x9E, 0xAB, 0x27, 0xCD, 0x6E, 0xBC, 0x1F, 0x15, 0xAE, 0x6A, 0x9D, 0x92, 0x07, 0x78, 0xCA, 0xF4,
+	0xDC, 0x55, 0x8F, 0x68, 0xD1, 0x42, 0xE3, 0x93, 0x47, 0x33, 0x55, 0x6E, 0x60, 0xD1, 0x08, 0x74,
+	0xDE, 0xBC, 0xE8, 0xA9, 0x0E, 0x68, 0xB1, 0x17, 0xD9, 0xC7, 0x47, 0xC4, 0xCE, 0x24, 0x71, 0xBD,
+	0x47, 0xFC, 0xB7, 0x62, 0x63, 0x48, 0xD3, 0x81, 0x23, 0x3F, 0x51, 0x49, 0x6D, 0xA0, 0x36, 0x7D,
+	0x63, 0x27, 0x1B, 0x8C, 0x5A, 0xF5, 0x2B, 0x8C, 0xC1, 0xB0, 0x14, 0x31, 0x78, 0x6F, 0x29, 0x35,
+	0x70, 0x6A, 0x60, 0x0C, 0xFA, 0x3B, 0xED, 0x0F, 0x3B, 0xE9, 0x0D, 0x4B, 0xAC, 0x0D, 0xA0, 0x1B,
+	0x1D, 0x70, 0x86, 0x33, 0x52, 0xE5, 0xD0, 0xAF, 0xE4, 0x95, 0xA4, 0x09, 0xEB, 0x40, 0x3D, 0xAA,
+	0xBD, 0x24, 0x72, 0xAC, 0x7A, 0x06, 0xC1, 0xF4, 0xA9, 0x62, 0x8E, 0xF1, 0xC7, 0x36, 0xA8, 0xAB,
+	0x07, 0x45, 0xAD, 0x60, 0x96, 0xF9, 0xBA, 0x1F, 0x8D,
+];
+pub const XOROSHIRO128PLUSPLUS_NEXT_BYTES: [u8; 153] = [
+	0xCF, 0x52, 0xCC, 0xA5, 0xDF, 0x84, 0x10, 0x60, 0x6B, 0x97, 0x0B, 0x81, 0x6F, 0xB3, 0x4F, 0x40,
+	0x71, 0x63, 0x67, 0x2D, 0x25, 0x43, 0x9E, 0xF0, 0x23, 0xCC, 0x43, 0x20, 0xA1, 0x0A, 0xB1, 0x66,
+	0x45, 0x3F, 0x2B, 0xC9, 0x07, 0x3D, 0x82, 0x76, 0x87, 0x9B, 0x9B, 0x8C, 0x11, 0x60, 0x1A, 0xD0,
+	0x80, 0x7A, 0x75, 0x92, 0x43, 0x25, 0x4C, 0xC6, 0x06, 0x14, 0xF9, 0xC4, 0x1F, 0xC9, 0x2A, 0x75,
+	0x94, 0x46, 0x38, 0xAF, 0xED, 0x99, 0x39, 0x3E, 0x00, 0x6B, 0x66, 0xB2, 0xF9, 0x28, 0x00, 0xC7,
+	0x42, 0x54, 0x9F, 0x3F, 0x71, 0x11, 0xAC, 0x41, 0x23, 0xC3, 0x4C, 0x99, 0x15, 0x1B, 0x50, 0x86,
+	0x54, 0x65, 0x52, 0x44, 0x11, 0x58, 0xD4, 0x87, 0x43, 0xD4, 0x3F, 0xF5, 0x33, 0xD6, 0xE2, 0xB6,
+	0x0D, 0xE7, 0x19, 0x38, 0xC3, 0x02, 0x78, 0x57, 0xC5, 0xA8, 0x6D, 0x6B, 0xE5, 0x62, 0xAE, 0x9E,
+	0xD9, 0x79, 0xBA, 0xE9, 0x82, 0xB5, 0x4D, 0x8C, 0x5A, 0xB8, 0x84, 0x93, 0xCA, 0x72, 0xE3, 0xC5,
+	0xEC, 0xF2, 0x62, 0x6E, 0x07, 0x40, 0x40, 0x5F, 0x57,
+];
+pub const RAND_NEXT_BITS_SEED: u64 = 555;
+pub const RAND_NEXT_BITS: [(u8, i32); 128] = [
+	(1, 1), (2, 0), (3, 5), (4, 0),
+	(5, 27), (6, 63), (7, 75), (8, 174),
+	(9, 298), (10, 792), (11, 205), (12, 535),
+	(13, 1206), (14, 12416), (15, 6651), (16, 48700),
+	(17, 46401), (18, 62574), (19, 338276), (20, 626946),
+	(21, 1296825), (22, 2008060), (23, 4940953), (24, 6869002),
+	(25, 13237800), (26, 31829256), (27, 39125118), (28, 149367076),
+	(29, 102543002), (30, 62709627), (31, 209382435), (32, -834751437),
+	(1, 0), (2, 0), (3, 4), (4, 6),
+	(5, 14), (6, 38), (7, 4), (8, 74),
+	(9, 9), (10, 554), (11, 1438), (12, 1845),
+	(13, 2753), (14, 1177), (15, 453), (16, 25197),
+	(17, 55319), (18, 109270), (19, 453414), (20, 861660),
+	(21, 1567858), (22, 1520439), (23, 3485020), (24, 14514984),
+	(25, 10580708), (26, 53470247), (27, 29937032), (28, 192671131),
+	(29, 138285497), (30, 23929616), (31, 1024774981), (32, -1616690431),
+	(1, 1), (2, 1), (3, 7), (4, 6),
+	(5, 15), (6, 50), (7, 37), (8, 249),
+	(9, 132), (10, 530), (11, 1226), (12, 3655),
+	(13, 472), (14, 6625), (15, 8279), (16, 13208),
+	(17, 89776), (18, 238354), (19, 161798), (20, 351280),
+	(21, 881040), (22, 2749986), (23, 5659982), (24, 3880577),
+	(25, 24737729), (26, 19608641), (27, 4391199), (28, 144254637),
+	(29, 381655517), (30, 430079493), (31, 1618148497), (32, -1901119905),
+	(1, 0), (2, 2), (3, 7), (4, 10),
+	(5, 8), (6, 29), (7, 77), (8, 166),
+	(9, 453), (10, 800), (11, 778), (12, 691),
+	(13, 7919), (14, 11841), (15, 27967), (16, 25115),
+	(17, 3897), (18, 177257), (19, 404345), (20, 922274),
+	(21, 27658), (22, 434224), (23, 1084437), (24, 3784235),
+	(25, 4099552), (26, 37200569), (27, 53274849), (28, 139426627),
+	(29, 375729920), (30, 646126028), (31, 1874692890), (32, 1131961685),
+];
+pub const RAND_NEXT32_BOUND_SMALL_SEED: u64 = 666;
+pub const RAND_NEXT32_BOUND_SMALL: [(i32, i32); 128] = [
+	(1, 0), (2, 0), (3, 0), (4, 0), (5, 0), (6, 1), (7, 3), (8, 6),
+	(9, 1), (10, 7), (11, 4), (12, 5), (13, 1), (14, 0), (15, 8), (16, 10),
+	(17, 8), (18, 8), (19, 6), (20, 8), (21, 1), (22, 18), (23, 2), (24, 2),
+	(25, 15), (26, 25), (27, 9), (28, 5), (29, 21), (30, 23), (31, 3), (32, 6),
+	(33, 20), (34, 3), (35, 20), (36, 0), (37, 31), (38, 31), (39, 23), (40, 20),
+	(41, 5), (42, 6), (43, 21), (44, 13), (45, 0), (46, 8), (47, 23), (48, 11),
+	(49, 42), (50, 45), (51, 32), (52, 5), (53, 46), (54, 43), (55, 45), (56, 38),
+	(57, 8), (58, 11), (59, 21), (60, 26), (61, 42), (62, 54), (63, 16), (64, 11),
+	(65, 52), (66, 48), (67, 35), (68, 61), (69, 48), (70, 51), (71, 20), (72, 19),
+	(73, 51), (74, 68), (75, 45), (76, 8), (77, 48), (78, 48), (79, 75), (80, 61),
+	(81, 3), (82, 29), (83, 52), (84, 67), (85, 64), (86, 76), (87, 67), (88, 45),
+	(89, 0), (90, 21), (91, 28), (92, 48), (93, 0), (94, 86), (95, 89), (96, 25),
+	(97, 42), (98, 23), (99, 60), (100, 17), (101, 75), (102, 26), (103, 67), (104, 15),
+	(105, 59), (106, 99), (107, 93), (108, 82), (109, 95), (110, 8), (111, 58), (112, 57),
+	(113, 50), (114, 21), (115, 15), (116, 106), (117, 106), (118, 115), (119, 29), (120, 3),
+	(121, 38), (122, 9), (123, 47), (124, 16), (125, 105), (126, 94), (127, 34), (128, 100),
+];
+pub const BIG_INTEGER_RANDOM_SEED: u64 = 777;
+pub const BIG_INTEGER_RANDOM: [(u32, &str); 20] = [
+	(0, "0"),
+	(1, "1"),
+	(7, "4d"),
+	(8, "55"),
+	(9, "13"),
+	(15, "6eeb"),
+	(16, "fbb4"),
+	(17, "29ca"),
+	(31, "1167c7b5"),
+	(32, "2b6e0fb4"),
+	(33, "10a5705e5"),
+	(63, "7bbdffbdcabf1bb1"),
+	(64, "b5dd5d722816f010"),
+	(65, "37645d9a1b08e729"),
+	(100, "29a44d64396ca9ec554d1a943"),
+	(127, "5f253d10904452147e34e738894c9a9e"),
+	(128, "46be52152bbf73b7760759642d3cec77"),
+	(129, "1524691106955f8930e3949b692aa7222"),
+	(255, "780a6c5de3c6f9c5df2177692dfe35175c800d28ce3c439cbec02c9d590a82c1"),
+	(1000, "376000388bdeed52281fafe0ebfc231410947bb7d581b36353eb5972824a412bb732b50edd90fe9bbd2d8a5f20f5f0e14e1850da82924a924dff06e18971a55f0b03ec86bf8108d9b7fdbc63d00dbf3e3b38142e64a82c102422922a2eadc5dd89f5040a08f1b2f611de4dca15ed61057f32807e3de4c13076a2eb7984"),
+];
+pub const BIG_INTEGER_PRIMES_SEED: u64 = 888;
+pub const BIG_INTEGER_PRIMES: [(u32, i32, &str); 21] = [
+	(2, 100, "2"),
+	(3, 100, "7"),
+	(4, 100, "b"),
+	(5, 1, "1f"),
+	(6, 100, "2b"),
+	(7, 100, "43"),
+	(8, 0, "83"),
+	(16, 100, "955f"),
+	(31, 20, "47e1a913"),
+	(32, 100, "98d60049"),
+	(33, 100, "1ba146745"),
+	(64, 100, "84ce2e64fb0cb4fb"),
+	(94, 100, "2b277d625ae339a6c8101101"),
+	(95, 100, "72c599415363743dc69bdf73"),
+	(96, 1, "e45a7ce2bf62160168d40ebf"),
+	(99, 100, "5f812b8a0e779238be86e0927"),
+	(100, 100, "a17405d5f43abc4b61dd41f79"),
+	(128, 100, "f0cece5b083f8b1657b62302bea78525"),
+	(160, 3, "85a0d9177b1d9d9dd8cc3fd2aa3e986d886cc5ab"),
+	(256, 100, "b3e65b926ef3a50791d40446076bae301ff0daf8861144fa329da040b6ae6b41"),
+	(300, 50, "a6148f4678e3393eb44e5ee5352bb8ded8b725b3877df3e912571b264a63a1d4f0ed7a3c25d"),
+];
+pub const BIG_INTEGER_PRIMES_NEXT: i32 = -1604166244;
+pub const COMMONS_RNG_SEED: i64 = -8361;
+pub const COMMONS_RNG_DRAWS: [u64; 24] = [
+	0x00000000FEB26811, 0x33D5230C06447CFA, 0x000000003EF48128, 0x3FEF18D413F5DF77,
+	0x00000000D1AA62B6, 0x2925C402A3A784C8, 0x000000003F477B40, 0x3FE44BFCC845058D,
+	0x00000000C423A17C, 0xE40E290991ACA072, 0x000000003E83CF18, 0x3FE19D7D64EBD74A,
+	0x000000005437AA83, 0x7DCF2721201A2C18, 0x000000003F21129D, 0x3FCCFFAD87BF3460,
+	0x00000000A4AF0DC2, 0xACCCA2FA02DB5F0A, 0x000000003F21E977, 0x3FC4300677FA30E8,
+	0x00000000970B9F7A, 0x56BC360B42BEF07B, 0x000000003F2F417B, 0x3F9226B70EA2AF80,
+];
+pub const COMMONS_RNG_BOOLEANS: [bool; 40] = [
+	true, true, true, false, true, true, true, true,
+	true, false, true, false, true, false, false, true,
+	true, false, false, true, false, false, true, false,
+	true, false, true, true, true, true, false, true,
+	false, false, true, false, false, false, false, false,
+];
+pub const COMMONS_RNG_STATE: [u8; 112] = [
+	0x00, 0x00, 0x00, 0x68, 0xAC, 0xED, 0x00, 0x05,
+	0x73, 0x72, 0x00, 0x10, 0x6A, 0x61, 0x76, 0x61,
+	0x2E, 0x75, 0x74, 0x69, 0x6C, 0x2E, 0x52, 0x61,
+	0x6E, 0x64, 0x6F, 0x6D, 0x36, 0x32, 0x96, 0x34,
+	0x4B, 0xF0, 0x0A, 0x53, 0x03, 0x00, 0x03, 0x5A,
+	0x00, 0x14, 0x68, 0x61, 0x76, 0x65, 0x4E, 0x65,
+	0x78, 0x74, 0x4E, 0x65, 0x78, 0x74, 0x47, 0x61,
+	0x75, 0x73, 0x73, 0x69, 0x61, 0x6E, 0x44, 0x00,
+	0x10, 0x6E, 0x65, 0x78, 0x74, 0x4E, 0x65, 0x78,
+	0x74, 0x47, 0x61, 0x75, 0x73, 0x73, 0x69, 0x61,
+	0x6E, 0x4A, 0x00, 0x04, 0x73, 0x65, 0x65, 0x64,
+	0x78, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+	0x00, 0x00, 0x00, 0x00, 0x00, 0xA5, 0x7E, 0x96,
+	0x04, 0xC7, 0xE8, 0x78, 0x01, 0xA5, 0x7E, 0x96,
+];
+pub const COMMONS_RNG_AFTER_STATE: [bool; 32] = [
+	false, true, true, false, true, false, false, true,
+	false, true, true, true, true, true, true, false,
+	true, false, true, false, false, true, false, true,
+	true, true, false, false, false, false, false, true,
+];
+pub const COMMONS_MATH_SEED: u64 = 7151;
+pub const COMMONS_MATH_POISSON: [(u64, [i64; 6]); 8] = [
+	(0x3FD0000000000000, [0, 0, 0, 0, 0, 3215122793294440360]),
+	(0x3FF0000000000000, [4, 0, 3, 0, 1, 2728524530747757802]),
+	(0x401E000000000000, [6, 9, 9, 6, 7, -9130124404065118973]),
+	(0x4043FEB851EB851F, [30, 39, 54, 35, 39, 9060432205673022718]),
+	(0x4044000000000000, [33, 49, 42, 43, 34, 5329543779548829443]),
+	(0x405EDD2F1A9FBE77, [116, 116, 125, 122, 135, 5472359505702807602]),
+	(0x40B3880000000000, [4959, 4855, 4902, 5059, 4867, -1700648093391720034]),
+	(0x414312D000000000, [2502349, 2498641, 2499955, 2498791, 2497438, -6717776837607183431]),
+];
+pub const COMMONS_MATH_EXPONENTIAL: [(u64, [u64; 6]); 8] = [
+	(0x3FD0000000000000, [0x3FE238776ECC8624, 0x3FE9AF67C170C77F, 0x3FD8052B493DD553, 0x3FD977A60533A443, 0x3FA019164156C412, 0xBEDD50DD5A375EDA]),
+	(0x3FF0000000000000, [0x400304591A270D42, 0x3FFC80901D893857, 0x3FFD46BEE83EDDD7, 0x3FB42B9FC1E15EC0, 0x3F6B612F621B35E8, 0x6D33E185D0EE5CB9]),
+	(0x401E000000000000, [0x3FD3CEBAD92A1A54, 0x40083EC6364306DB, 0x40144183D1019B11, 0x40309C1262E7AC5E, 0x3FCFBD87212DA610, 0x9CD72B30A5CDF702]),
+	(0x4043FEB851EB851F, [0x402BD70D53BC94D7, 0x4037A4D442100AE1, 0x40335C7CCC94502C, 0x40375E6E38843044, 0x404EFE605B83378F, 0x1CAF6356B08CE60F]),
+	(0x4044000000000000, [0x405887BCF43B3128, 0x4010A229A389B000, 0x4024B73437FB11DE, 0x405649832A9BA268, 0x404FDD59D5003525, 0x52E4FEADDC58CF94]),
+	(0x405EDD2F1A9FBE77, [0x4059F08B4B86E1B5, 0x406AB15711D041DB, 0x4055105FF2D12D88, 0x403234E47759B85B, 0x4070CC3899CF67B9, 0xA333E8C7C4536142]),
+	(0x40B3880000000000, [0x40C74F7ED5959C40, 0x40B34404F0ABFE2D, 0x40C8F66D4CDC6B6F, 0x40A725D2CFF70114, 0x40AFE0BE7DE21AEB, 0x6EC869B21A5B755D]),
+	(0x414312D000000000, [0x41217B9A3E944DD3, 0x40F4D9E515A10FA3, 0x4153990ABED4A415, 0x4150E9D8F042FE79, 0x41477F6D51288139, 0x6F144A509A46FC3A]),
+];
+pub const COMMONS_MATH_GAMMA: [([u64; 2], [u64; 6]); 5] = [
+	([0x3FD3333333333333, 0x3FF0000000000000], [0x3FC2C7B9F231877D, 0x3F07DC035BBCFA45, 0x3FD1AA8F01CD676F, 0x3FC79DF1085146BF, 0x3FA04DC8AEC652A9, 0xBC27C5DAC218733B]),
+	([0x3FECCCCCCCCCCCCD, 0x4004000000000000], [0x3FBFDD791CB3BC48, 0x400DD5C55393B033, 0x4002B5E13A4262AA, 0x3FD811261C9F17A1, 0x400B2468C7156F90, 0xEF7BD89840E0406D]),
+	([0x3FF0000000000000, 0x3FF0000000000000], [0x3FF56C10E31A1CE6, 0x3FF10D44AE122201, 0x3FF54E52424E4A57, 0x3FD64B3517395C9D, 0x3FE98CDC773C0E11, 0x74BCB811FF606E8A]),
+	([0x4004000000000000, 0x3FE0000000000000], [0x3FEAE261DCEC6902, 0x3FEEAA9F31BE22C4, 0x3FD01DD8C4ED48F4, 0x3FF2C745ABDD957D, 0x3FFB50E9ED5ED7D6, 0x81ACB109CD6C6A8B]),
+	([0x4044000000000000, 0x4008000000000000], [0x4060FC689858B8F2, 0x406029EC86DBA2E9, 0x40586D3E88BCD094, 0x405E11DE9BFADE2B, 0x405C88F52AE8D1D2, 0x0EFDA3912A50D6F4]),
+];
+pub const COMMONS_MATH_HEX: [&str; 4] = ["4", "19", "85adcbd", "e3f974b8d63f31a0a19c110f16d711ce"];
+pub const COMMONS_MATH_NEXT: i64 = -251707784576261679;
+pub const MC_IMPROVED_NOISE_SEED: u64 = 3690;
+pub const MC_IMPROVED_NOISE_OFFSETS: [u64; 3] = [0x406890F77C39D198, 0x40674651D142B26E, 0x406A185E394E63E1];
+pub const MC_IMPROVED_NOISE_PERMUTATION: [u8; 256] = [
+	208, 194, 117, 110, 160, 25, 152, 29, 91, 95, 242, 96, 39, 222, 74, 70,
+	240, 236, 133, 71, 23, 220, 163, 118, 86, 12, 19, 237, 255, 140, 225, 0,
+	24, 35, 155, 126, 65, 4, 198, 169, 159, 203, 75, 206, 128, 87, 2, 212,
+	67, 251, 180, 60, 190, 249, 106, 246, 16, 10, 216, 186, 49, 185, 78, 228,
+	181, 32, 113, 132, 6, 254, 178, 101, 112, 83, 195, 172, 46, 199, 134, 146,
+	230, 142, 158, 69, 20, 187, 84, 182, 243, 53, 175, 156, 42, 63, 105, 161,
+	76, 50, 174, 253, 149, 85, 123, 179, 245, 82, 27, 248, 31, 209, 40, 88,
+	99, 210, 1, 59, 157, 131, 227, 224, 66, 207, 103, 139, 202, 141, 197, 241,
+	193, 127, 200, 211, 58, 41, 166, 114, 215, 89, 205, 102, 120, 26, 56, 104,
+	125, 250, 64, 38, 229, 37, 221, 247, 153, 17, 77, 213, 73, 34, 239, 68,
+	170, 54, 235, 171, 129, 150, 173, 57, 62, 165, 145, 238, 219, 5, 61, 151,
+	196, 45, 81, 90, 93, 168, 72, 217, 116, 232, 51, 226, 22, 3, 111, 204,
+	233, 124, 154, 231, 98, 52, 100, 48, 107, 11, 189, 164, 122, 15, 92, 176,
+	177, 43, 192, 214, 138, 136, 252, 33, 167, 94, 148, 28, 109, 218, 18, 108,
+	244, 188, 9, 121, 13, 14, 135, 130, 183, 30, 162, 137, 147, 234, 191, 119,
+	21, 201, 8, 36, 7, 80, 184, 79, 47, 115, 143, 44, 223, 97, 55, 144,
+];
+pub const MC_IMPROVED_NOISE_NEXT: i64 = 765709457765800197;
+pub const MC_IMPROVED_NOISE_SKIPPED: i64 = -4952043501829187106;
+pub const MC_NOISE_SEED: u64 = 9054;
+pub const MC_NOISE_NEXT: i64 = -3298537501042919324;
+pub const MC_NOISE_EDGE_VALUES: [u64; 2] = [0x3FFAAAAAAAAAAAAB, 0x3FFBBBBBBBBBBBBC];
+pub const MC_NOISE: [([u64; 3], [u64; 7]); 64] = [
+	([0x403E027287D0F5DA, 0x400C03AD424C20A0, 0xC035EA2D5879C4C8], [0x3FCB27C1F514A97A, 0x3FCB27C1F514A97A, 0xBFD6C2927A27D38E, 0x3FC020F8BE048842, 0xBFC0CDBE92C1A384, 0x3FAA0A2FA8511432, 0x3FE9D364BB54BD07]),
+	([0xC017E0E351931890, 0x403EAD233874949C, 0x402772CA06CEB508], [0x3FC0A644EBE1C112, 0x3FC088AF70138912, 0x3FC5406581CBA22B, 0xBFAAB51479B2659C, 0x3FCFA0CDCA83EC46, 0x3FDB6A0DDF0A62CE, 0x3FE88DAA44BA77E4]),
+	([0x403453AA70C61C16, 0xC020488A4BD423F8, 0xC0042795F06F4C00], [0xBFDBD105F4C99047, 0xBFD64E314E879B0C, 0x3FC3D1CABC496CB6, 0x3FCD1B10CA2B11BB, 0x3FAE4461D8739C40, 0xBFBF3CE699177871, 0x3FDCCB13598E07B3]),
+	([0xBFDFB1DCD3544800, 0xC03FC5310C04F2CE, 0x403BF4ACD7FEF2DE], [0xBFA963CC3C8B3D1C, 0xBFDD4277F1872250, 0xBFCBBDE415C3A5DA, 0x3FD1467AD9DC0BD8, 0x3FC766A4136D1F02, 0xBFD88AB60310DA71, 0x3FC6E7943D8E7F97]),
+	([0x40095CEF71B2FA00, 0x403F878CA310C0E8, 0x403DACE1503EBE8A], [0xBFC0C99F3CACBEE9, 0xBFC0C99F3CACBEE9, 0xBF8531D24295B1CA, 0x3F6063E16A03270F, 0xBFAA0DF04AE7DC4C, 0x3FDC8BBEC69CD802, 0x3FB5A9D67E8C2FB4]),
+	([0xC03E7DDB9DBDBDBE, 0x403A354D0CEE10B8, 0x403CD8802A91616C], [0x3FCF4691AE86F832, 0x3FDA3825DDEBA8C8, 0xBF6F16A02CD5ADD8, 0x3FBAC5E0DBD9EACE, 0xBFC735544D6D1D0A, 0x3FE1C8B09C51DBE1, 0xBFCEC832FDE1F190]),
+	([0xC037896F2D5D8F72, 0x40230D4E832499DC, 0x403568887E298FB6], [0xBFD8FC3B36494E4D, 0xBFD8FC3B36494E4D, 0x3FA729A82626F850, 0x3FC381A4BF002945, 0x3FAC2929CE817DEF, 0xBFED2C79988FAAAD, 0x3FCF1920160AE476]),
+	([0x4025C73279C97078, 0xC031EE5FECC682A2, 0xC0385BB7E7E8E6F6], [0x3FDAB671BA12B664, 0x3FE6E564636972A2, 0x3FAF118A6DEA309D, 0x3FC51C5144574322, 0x3FC9089DD1A48F57, 0x3FE230E0EC90F9BF, 0x3FC609F4EBF03666]),
+	([0xC036978C069E263E, 0x4015FA76D6C30CF8, 0x403D45FECE20C318], [0xBFC888D3870DF4FC, 0xBFC888D3870DF4FC, 0xBFBD140432DA8BB4, 0x3FC6ED48552E26BA, 0x3FC9D1A17DFA4C87, 0xBFE33051746F7E73, 0x3F977331AB9B8E18]),
+	([0x403230690A9FA9BE, 0xC039A0035C38B41C, 0x4025DDBFCF495CE8], [0x3FA1C81E8A3D3196, 0xBFD5208D1915A19C, 0x3FC46D11534B7CAE, 0x3FD36BEAF46F0D30, 0x3F851E5B92F8B026, 0xBFE2AB412E5CDC5E, 0x3FC3F9446D5A25F5]),
+	([0xC0330788A321F0E0, 0x403BC132BDF5CFA6, 0x4035A9C0A0C77A66], [0xBFDC54C2716880B4, 0xBFD4488E52EDFF1D, 0xBFB4E631DD20A819, 0x3FA91A15679AF7CA, 0x3FC1277767616D9C, 0x3FE2E82E1F6B5285, 0xBFE6B0B41A795213]),
+	([0x4030D37F28CC24FE, 0x4032C57088B6AA60, 0xC03FC8C197277D96], [0xBFC16A14D4E1BF50, 0xBFBCB9384CB0EE78, 0x3F9C47EC2E82DFBE, 0x3F8B2A1F3EAB30E0, 0xBFB769D3D882810B, 0xBFB8D47B59D481F1, 0x3FD078A032FFAD97]),
+	([0xC0308EB533C4C654, 0xC03464E3893744D6, 0x401D590BDD809A78], [0x3FA503E51E816520, 0x3FA503E51E816520, 0x3FC57F243628A573, 0x3FD418BC4A970CD8, 0x3FB074256BF95761, 0x3FD067B710164418, 0xBFD53B52E9B4CAA0]),
+	([0xC0075057A3D8E610, 0xC0395BDE014E0812, 0xC01401F32711BA28], [0x3FC3832AC7331EA2, 0x3FC3832AC7331EA2, 0xBFC4422710734EAE, 0x3FD19EADEFEA920B, 0x3FBE41906E130456, 0x3FD53E88729A9289, 0x3FC56B3CDD19301C]),
+	([0x4035AFA300B28CB8, 0x40133485DA6821B0, 0x402BB25A73A71000], [0xBFE643FA8DFCEC30, 0xBFE4507EDC2CEDED, 0x3FCAAC0FA5492FE1, 0x3FC71048AD98180F, 0x3FA571FB8A07107A, 0x3FC2AFB11DF48946, 0x3FED17C95DD009F4]),
+	([0xC02A5C74B8655EC8, 0xC033F861D238F410, 0x403E66377C87B1E0], [0xBFDB4C6BD0A75455, 0xBFE28A033599FC44, 0x3FB870456A34B29A, 0x3FD42A8612EF04F4, 0xBFDC3184A19AC756, 0xBFBF7E8BA12AB8B4, 0xBFE0A1EF75C8D6CE]),
+	([0x403624EAF4D57D24, 0x4018C536439A7A60, 0xC01FF94D1F36D728], [0x3FB240F98A17EA4E, 0x3FBE0040BCCEFCF6, 0x3FB94EF75B973A8E, 0x3FC06500B09C64FC, 0xBFB7DDFB37487B2D, 0xBFCBB40059DAD6D2, 0xBFC525168072443B]),
+	([0x402DAF9C45380D54, 0xC03EDF03287AC836, 0xC027CF3E0B61A6A4], [0xBFC36BD9E2FCC234, 0xBF94739A58777F80, 0xBF8D61388DE43763, 0x3FD2F03C409BFAC9, 0xBFA4E2567C7AE1F9, 0x3FE0367FFA8254A7, 0xBFE2370695075CB3]),
+	([0x4015FD5DEB35D5B0, 0x403F11CE89287B84, 0x4031BD87356BC35C], [0xBFCABAAA71138C88, 0xBFE40E6F591EA559, 0xBFAA6369B76B97C1, 0xBFA1CC442E0CEA25, 0xBFB569C7B6FFA433, 0x3FDC55ED4298DAF5, 0xBFD2BDD76F0EB3CB]),
+	([0xC0220ECAC8E50310, 0x403E83F134C535B4, 0xC02CB65385CBFB38], [0xBFB94AFEFF0FD320, 0xBFB94AFEFF0FD320, 0x3FC081BEB0AF3819, 0xBFB966A7DADCFFA5, 0xBFBC7F5496A967A0, 0x3FE3CFFC1FD87F4C, 0xBFCD1378C54DECBF]),
+	([0xC0287A366BD6B5FC, 0xC03A0E2948FA761A, 0x4027F3C946D34160], [0x3FE15EEF20A9C21E, 0x3FE640B2025B9E61, 0xBF9B30ECB03C14D1, 0x3FD3479A702A429E, 0xBFC26BE7D6494EB7, 0x3FB1B8ADF8E0B954, 0x3FE9F786D583A46D]),
+	([0xC03B48B1C73B5D04, 0xC01F174671ED1250, 0xC03301EC7F87AFB4], [0xBF70EC27A08A0AE0, 0xBFD5DD6BCDCBD9F6, 0x3FD1EB999F6E414D, 0x3FC8EB6C712BC0BD, 0x3FAFE539CCAC2931, 0xBFD3F9D8958F6C3F, 0x3FDF9E459E962470]),
+	([0x403278159F7E4DD2, 0x4030CAB535869C46, 0xC03ABCE98F8DB064], [0xBFD21B6E6D6E717A, 0xBFC9B8960599345A, 0xBFD5ADEDFF0FB42D, 0x3F9FC26BB1FB9D6E, 0xBFD7B8D32B30827E, 0xBFC9F7BA28E2A23E, 0xBF62C60BDB041324]),
+	([0xC03DC33A02556F94, 0x403B75D6E78DAB9C, 0x4032A39E844B2F06], [0xBFB4A56E405397C8, 0xBFB4A56E405397C8, 0xBFD37B42AED67D13, 0x3FB83222AD9A848A, 0xBFBFA33F2F731BBA, 0xBFE1EED0E99388D9, 0x3FE1B73DAB76D778]),
+	([0xC02BA4CEA65912A8, 0xC036A7DDA8634E8E, 0xC035540BFDF94A10], [0x3FC0A4CD127E35DA, 0x3FE2ADAC25FE3C30, 0xBFC56B4197DEDAC6, 0x3FC5DD03F8C2D12D, 0xBFC7C187817DC4FF, 0xBFC88BBE1FA2D396, 0x3FE8C3D876A1BF6A]),
+	([0x403676E48FD9315C, 0x403CFDFDA8DBE496, 0x40259DB19BC40E1C], [0xBFC03B483F8ABCF3, 0xBFD407C8F3D1EBA4, 0xBFD5494F93014961, 0x3F8362D0297937E4, 0xBFBC5666E71E9322, 0xBFDEC764A96C1F3D, 0xBFD033DB115002EE]),
+	([0x4021629D2F605AA8, 0x40289D1E827B1680, 0x4034D1B264874FFC], [0xBFCD69C6B6F7A900, 0xBFE5A2815E9F061E, 0xBFC9F083B4BF198D, 0x3FBB09F3BD10F359, 0x3FB9D712358EF892, 0xBFD49FC770AE006D, 0xBFADA47CFA88C71E]),
+	([0xC03C4339FAB67194, 0xC030CE6FDEBF90C8, 0x4022D1DE990B782C], [0x3FA9B011F004AEC0, 0xBFD70D2F702E7112, 0xBFA85E3C0802A6C6, 0x3FD1F8197C30A7CE, 0xBFB316A03D4E01B5, 0x3FC5E0F0D9105C73, 0xBFB0E5A171460BA5]),
+	([0x403CEB74545186EE, 0x403B8D17E32E4440, 0x3FDB1BE46B43D080], [0xBF9F8B7B6E90B600, 0xBF9F8B7B6E90B600, 0xBFD94F761CCE7BC7, 0xBF889748558EBDF0, 0x3FB45D0624C74772, 0xBF8E6E08AA5D24D2, 0x3FB07386BE9C2AB5]),
+	([0xC03FDB940D9D76D2, 0x402CFAA0B582A974, 0x403FD0661F6933D8], [0x3FD2FB1CC0F86426, 0x3FD2FB1CC0F86426, 0xBF854C6885A28073, 0x3FC05352CD1AD814, 0xBFD8CDE82BC52BE4, 0xBFD9131FB5CCBA61, 0xBFDEB5BB889D288E]),
+	([0xC03503F505F6AE6C, 0xC03825798DD10AB4, 0x403B96F6F5EF58A8], [0xBFD3BEAC75596AA4, 0xBFC50D6F9F9E001C, 0xBFBE2CB3A0D51B0D, 0x3FD2D4E73A6F6003, 0x3FC1ABE0CA0DD847, 0x3FCFD11C1F2FB31B, 0xBFEC8A906B0CB127]),
+	([0x400A09F6001AF8C0, 0x402861A9F71D4C24, 0x401F72407CEE84D8], [0x3F90951185F89184, 0x3FBBD705471DC45C, 0xBFD315ECB278A00C, 0x3FC05751C9566617, 0x3FA859AF6907ADAB, 0xBFE8DDF5166593AA, 0x3FE01380B8CD8AE0]),
+	([0xC034111C7FCA1232, 0x400E8D38DB4CFC20, 0xC0315E695751BB50], [0x3FAE78080DC40070, 0xBFC860820C1776E2, 0xBFB4DAC0C8E90B69, 0x3FC7FE243AEDC26C, 0x3FBFC4CE25864185, 0xBFD448D73F2E461A, 0x3FE0DC2FFF11D95C]),
+	([0x403C9494EC22B534, 0x403C8CD82FBB43CE, 0xC03393F017F75FB2], [0xBFC2EA13E566E1B9, 0xBFC2EA13E566E1B9, 0x3FD1396F831C2B75, 0xBFAF9E14B840C8D1, 0x3FB2D38989EC1C84, 0x3FBDBB55B0CBE721, 0x3FBCFCFA8B813E60]),
+	([0xC03821FAF930F54C, 0xC0118C1522CE3178, 0x4035AC2D398AC8A2], [0x3FD1AA54199ABC24, 0x3FD1AA54199ABC24, 0x3FC4700D814508BD, 0x3FC944DA1C1D1DDC, 0x3F96E7553A187661, 0xBFE2E68E7BB9F9E0, 0xBF9220DF1F7EDBA0]),
+	([0x3FFE23E5ADB115A0, 0x4037826AB79EB900, 0xC0291D59769ED724], [0x3FCB339CF6130656, 0x3FCB339CF6130656, 0xBFB4FCF97EA4303F, 0xBFB3DBD6747A3A8A, 0x3FCAABBDBB1BE010, 0x3FE2B68F09230535, 0x3FE47340EB2771D3]),
+	([0x402CF8E9E4EE06CC, 0xC030AB579E67CCBE, 0xC03CC51A755E4CA4], [0xBFE830F61FAF628D, 0xBFF04ABC83BABE0D, 0x3FD1C3884025F694, 0x3FC24FC0C2CB5768, 0x3FA5F84938EEF28F, 0xBFC4FA484C964BDA, 0x3FD6088DF9EAFB78]),
+	([0xC03AD51CF5BB765C, 0xC0269219ABFDFFBC, 0xC025A6745E564730], [0x3FD3E55E961703DE, 0x3FD48F522902D206, 0xBFD1CDFD79D92B1A, 0x3FCE9D0ABFD45019, 0xBFC00DE4F608DD7D, 0xBFD9E9905BA09240, 0xBFC598E68C96E520]),
+	([0xBFF6B47EA0F9F440, 0x40045ADF335667C0, 0x40193DA2A819B218], [0x3FA8D92A271CF108, 0x3FA8D92A271CF108, 0x3FBC67191DAA8178, 0x3FC7DC69986CC218, 0x3FB1B0225395F4DF, 0xBFE36F349C04C813, 0xBFD587B314AA4C0D]),
+	([0xC022D6574AE5D214, 0x403ACC2F77D54C84, 0x4030684BC21B564E], [0x3FE84850EE16388A, 0x3FE4D0BAD20983F4, 0x3FB1DC80AABD3C64, 0xBFA105ED9F495B8F, 0x3FAF2A61FC2F72CD, 0x3FCBA846662F842A, 0x3FEBB12C13D39405]),
+	([0xC0014A667823D200, 0xC03BD86F61CF881A, 0x4000ACE6D21946E0], [0xBFD04FF2803781E2, 0xBFDDD72FDCBFF7B2, 0x3FCE2ABC0914515E, 0x3FD3392EE647286D, 0x3FB397782FBE5B93, 0xBFD227167A5CCF47, 0xBFDE01BDE7EDB16A]),
+	([0x402AB37E26C2B674, 0xBFF8A3F1C0608C40, 0xC0309D9D7F90B0F2], [0xBFC21A8A645CD03D, 0xBFC21A8A645CD03D, 0xBFD0885B033D3DF6, 0x3FC161524E8538E6, 0x3F9504883E53A36B, 0xBFD396A41543E29A, 0xBFE026856CD3513D]),
+	([0xC03CD01CD3222E18, 0x40197A5E40B9DE58, 0xC02A91506C1C6B1C], [0x3FA7DA656342CE28, 0x3FE17B2DD7F8E5E8, 0xBFBF7CB3CEC1450B, 0x3FCABD6609946E34, 0xBFC73D5A7F3A5B9B, 0xBFD29B491A79AF4C, 0x3FB32649995FC75C]),
+	([0xC036CB81C9552FD6, 0xC032A0E7756A8DE0, 0xC0220DD255314208], [0xBFC8A0FEBE34DDEC, 0x3F95F97369E69F28, 0xBFC6FA60B1EB0D3B, 0x3FD06313C2695E04, 0x3FD1E5E89199DDE4, 0x3FE0D1AD478820E9, 0x3FD3DF9F9EAE1439]),
+	([0x4031A73191CD87A4, 0xC0312A877FF1AB28, 0x40323DBC3496E78A], [0xBFD45108603925D4, 0xBFC8B2F6580E18D9, 0x3FD51719C7E3E141, 0x3FD0CD8335CEF409, 0x3FA6CA50E0D68BCC, 0xBFDE25FA8AC6EA01, 0x3FD2AE37649DF517]),
+	([0x4030161CF2723756, 0xC01F8C5276856138, 0x4032DDCF64FBD7AE], [0x3FA6F214BD1093CC, 0x3FB898025FB5FA3E, 0x3FCF3F1F4671F35C, 0x3FD0B4165DF29D3F, 0x3F9E3C17FAD771BD, 0x3FE434BAB1AB1FAA, 0x3FB0CB713C1E3330]),
+	([0x403222FFF5988A0A, 0xC0371E64E18D4398, 0xC01E9ADBCE5224A8], [0x3FE137A36BE88F26, 0x3FE1C67E5F9B7E75, 0xBFA400C1137F05D8, 0x3FD53604474F6799, 0xBFCF89F58FDC29CC, 0xBFD4E88C35C31FBE, 0x3FD13794F2D56B85]),
+	([0x4015AF9B526720F8, 0xC03ED35A51B18D54, 0xC0318545D63BB3B0], [0xBFDBFD24AFB8160E, 0xBFE5EE7E6BA2DE02, 0x3FB6B7BC2E3D5FC6, 0x3FCD0AD76F06475B, 0xBFC26277D2DF8DDB, 0x3FCFEA881D160CA5, 0x3FE630B4089F9E4A]),
+	([0xC17736228BF472A9, 0x41858F5EE8631BD0, 0xC16CEE0C85918199], [0xBFC65F4F7F34320E, 0xBFD235559FEEC06C, 0xBFCDA8AB43336BEE, 0x3FCA89A5F3A2EE04, 0x3FB182C3A03C00D9, 0x3F8CB57C4F55F11F, 0xBFE071407CB974B3]),
+	([0xC1856405772F95C1, 0xC187B7BF2BF6939B, 0x416DE9AAE4A1E536], [0x3FDC3D888856E6B5, 0x3FDC3D888856E6B5, 0x3FC1D35462CA19F5, 0xBF7B0F410548E48E, 0xBFB12DC0F6CF731A, 0x3FC7E4783B48F4F5, 0x3FC47690FBAC9271]),
+	([0xC173634F91E3172D, 0xC184733778B13AAB, 0x4183360BBCA4178A], [0x3FD28071EB7CA858, 0x3FE1BCF23600DC46, 0xBFB98E0FCB857749, 0x3FB73B486F25552A, 0x3FCFE7B8B2EDB452, 0x3FDDC86F493C2980, 0x3FD2785161CB066A]),
+	([0xC17258225F595C35, 0x416D068DAA9D0497, 0xC15508C80E9473B7], [0x3F801E52A2FAD2EC, 0xBF5F4E3226E48560, 0x3FA9976623D545A2, 0xBFD69E11780BE709, 0xBFC8C78A4DBCAED0, 0x3FE2F8C89BF5149F, 0x3FD0F72E7F819AA8]),
+	([0x415AE5183526543E, 0xC16EABD6C21C68B3, 0x4183AD51E87120C9], [0xBFCD3A111D091914, 0xBFBFD547883BEE80, 0x3FA323F5A57AE4C0, 0xBFACFF7FE675A208, 0x3FC0709406230A7A, 0xBF900F56ADC872AB, 0xBFE369EA66853DFD]),
+	([0x413B15E3DD907F64, 0xC13728E27AFBB79E, 0x414D22A5D6E8A720], [0xBFBCF92A489FCD03, 0xBFBCF92A489FCD03, 0x3FB1BAC08BA4F9A3, 0x3FDAEDA3377751BC, 0x3FC2D3DA2A6C446B, 0x3F8F422599CEA8F4, 0xBFCA924B5AC521CD]),
+	([0xC1826B6CF9A9E6ED, 0xC184AE2F0BEE28A6, 0xC12030A22C82F625], [0x3FD97B94A92F09BC, 0x3FD97B94A92F09BC, 0xBFC7361D10512FC9, 0x3FAE077A19C268C4, 0xBFB15138A9CA522B, 0x3F84CCFFC433C3A9, 0xBFA96F2BA6B86C64]),
+	([0xC163E1539C89410D, 0x416F9019703333AA, 0xC183CB355B6E472B], [0xBFC8DECAECAF36E0, 0xBFC8DECAECAF36E0, 0xBFD5349FC8C90952, 0x3FA8CBA218E93390, 0x3FAB95AA2ED5711C, 0xBFDFE12A9DB19805, 0x3FA72115CE230458]),
+	([0x4183E6194B1C6961, 0x4186EBCAAA3E894C, 0xC17200F31008F511], [0xBFB4E5E64AA030EB, 0xBFC5F9FE6CF1534C, 0x3FC18EE575324FD8, 0x3FB7BC6CF6F8C108, 0xBFC89A2C685D2843, 0xBFE142B85A93D672, 0xBFC5F02B6035B52A]),
+	([0x4179295C3088B16C, 0x418485C6A4ACB2DA, 0x4163BEBBC6C45F7A], [0x3FD4ADCE73C8BE9A, 0x3FD4ADCE73C8BE9A, 0x3FC52ABB63F952F9, 0xBFC946BE9DC7229D, 0x3FCC669D742B1CEB, 0x3FE34A78AE2209E4, 0x3FC507473C2C988F]),
+	([0xC17B10D7ADD0FF1F, 0x41801AB8577689B4, 0x418475863569599B], [0x3FB394DB9ADB4763, 0x3FD50ADE36EB2A91, 0x3FD07DF9E6FF306B, 0x3FD414FB8996B44E, 0x3FA6D8E4E5E068F3, 0x3FD990D3CCBD5C2A, 0xBFBF65418ECEE6A6]),
+	([0xC17598890F5391B1, 0xC10FC7CCAA130D7B, 0x416FF8FCEFCD5B13], [0x3FC5888EACA1BFDC, 0x3FC5888EACA1BFDC, 0xBFA47F279ECF380B, 0x3FB5179F58C5537A, 0xBFC316054E1615ED, 0x3FE3D476C418573D, 0xBFC4F6D8F54225BE]),
+	([0xC1635B63A6B85BA1, 0x4180197EEEDEE2E3, 0xC17B6B76B7CA1946], [0x3FD626E52D947906, 0x3FCA3F86BDB7C043, 0x3FBEE0C670A56BA0, 0xBFBBB20B30F654B9, 0x3FB7C631F541C993, 0xBFAEA378943B7877, 0xBFB45AAEDB9678C4]),
+	([0xC176C5FCF70E7659, 0x416AACE4987FD823, 0x4176578D579E65E4], [0xBFDC7208C976B954, 0xBFDD48C8AA525685, 0xBFB02517F86D5B17, 0xBF7EBA22681DF80C, 0x3FA4911C3A30D1F1, 0x3F82B69B2C4D6F11, 0xBFE0C1E3718E4D9C]),
+	([0x4185040D9F44669C, 0x41849D322AC63CA0, 0xC16720EF4B74A165], [0xBFAF4F4FF1825162, 0xBFE1F4F450543B96, 0x3FD263C28C16D8F1, 0x3FAA64AC72104ADE, 0x3FCD90733E59B4BC, 0xBFE08F413824286D, 0xBFDB330A3A378679]),
+	([0xC115F276E3EC44D3, 0x4164D49DC460EEB7, 0xC1515B696F13981E], [0x3FD1F64E8F94BE32, 0x3FBCFD8289D0E588, 0x3FC95F090D36C776, 0x3FA6BDAD9B303824, 0xBF9BC0A338C424DF, 0xBFA838311D01289F, 0xBFE63E364A79BD38]),
+];
+pub const MC_LEGACY_NOISE_SEED: u64 = 6514;
+pub const MC_LEGACY_NOISE_SCALE_OFFSETS: [u64; 3] = [0x40522BA5676B80D0, 0x406C8C58613FC80D, 0x405189D9D048577C];
+pub const MC_LEGACY_NOISE_NEXT: i64 = -3731742700533601501;
+pub const MC_RANDOM_SOURCE_LEGACY: [(i64, [i64; 24]); 6] = [
+	(0, [-1155484576, 8, 252220, 1302116447, 1368843515, 663681053, -3, 2158390814503909950, 1, 1051368346, 4598225230516304930, 4586141108197883046, 4595554050704677907, -4614189550581370147, 4618170605344191870, 1927512926176735975, -5330354477009584734, 6179152228863951287, 503382500776308262, -8109671562565861673, -4967725919621401576, 52871691179874, -4633156250783234591, -171134583860878546]),
+	(1, [-1155869325, 8, 430000, 874970313, 446064254, 77814904, 1, -6292788870224356907, 1, 1002992256, 4594670921886116724, -4615267846072139239, 4596062109974423407, 4606139315665922117, 4618313693881579277, 2139215297105423308, -6372645163175059827, -244621570164513458, -369547182206388553, -8217561212159637440, -4967725919621401576, 52871691179874, 4585462001057065683, 6976596177944619528]),
+	(-1, [1155099827, 5, 12866, 1176895439, 1421815604, 1290198438, 5, 7365572370171403210, 0, 1062099061, 4599251894041877504, -4621571470094357502, 4596349162353688360, 4602154878029376951, 4615609522517109782, 3247304054509887768, 2238762263636269865, 8506099787577831191, -8928713651632540751, 8009820553286581281, -4967725919621401576, 52871691179874, -4627796512395114873, -7447080493522954834]),
+	(8828, [-244768606, 1, 579910, 73620502, 1345791161, 1042938026, -1, -549941592403295104, 0, 1050293344, 4576123895367128256, -4624528369887047108, -4617277291788648473, -4615314433277697581, 4615931243263478573, 3604075631123613562, 4720611859376874092, -8873069419913229893, 5649566315496434504, 713788638663726118, -4967725919621401576, 52871691179874, -4626445059686061435, 3298206991291138105]),
+	(-4172144997902289642, [1377526227, 0, 115608, 1344358158, 1274640479, 351993879, -5, -2234600713039436738, 0, 1057967015, 4606021991777585291, -4629131430112513429, 4612266581140762494, -4616819635415787588, 4612204834869971586, -2252705674683447351, 7174046550493310142, -5301591728979405527, -3037888971548270861, -8989745887321822898, -4967725919621401576, 52871691179874, -4623901636496975880, 6577567247730760275]),
+	(6711501738592066281, [-1824756252, 1, 450103, 1304178330, 289925315, 29905493, 0, -681623014678427135, 1, 1042421692, 4602898752982901723, -4623167249198096801, -4621765815465432428, 4610465616973309294, 4616210372702462032, 4345885692022000556, 3205989317368559809, -4786201786655389010, -46794428388791129, 5689181730462293232, -4967725919621401576, 52871691179874, 4590084333014706012, 7428279054709870488]),
+];
+pub const MC_RANDOM_SOURCE_XOROSHIRO: [(i64, [i64; 24]); 6] = [
+	(0, [-160476802, 1, 159563, 622831715, 1764433238, 1977982797, -5, -1069045159880208415, 1, 1058809582, 4594207565143516912, -4638151158664037883, -4617923806443781886, 4595802384628393646, 4616233575688967814, -1341736371625369067, -2117279032163087521, -3655797151753492459, 1531431458371977510, 2695763049457145597, 8456951256435118209, 52871691179874, 4595312644831958136, -7124105922753911898]),
+	(1, [1734564350, 1, 201480, 1336018134, 357362667, 1061443312, 4, 2631759950516672506, 1, 1062728301, 4592588788084132896, 4593268082769623169, 4599176035531869184, 4602229782655749922, 4616209800711933168, -6710911167801544845, 7695855384716550729, -6497993498392225500, 9140831928602256609, 4627150877034011484, -7012492912842247225, 52871691179874, -4624637910109882110, -6659048520209298168]),
+	(-1, [-1451817517, 4, 223735, 1975861230, 434718845, 1832506883, 2, -3423761802310783585, 0, 1046659832, 4603015272092802281, 4602704972221149077, 4603933450703596041, 4603756370219488921, 4614548425148796784, -7693177143003798843, 7272190543772378429, -9203711841999040375, 4807631883925842129, -8948379548135374751, 7121449171298222132, 52871691179874, -4622033083586272687, -2215274226404964554]),
+	(8828, [-1331334991, 4, 919449, 546431231, 436429541, 1463793531, -4, -3444710570707278385, 0, 1061953838, 4605275081814661279, 4604374684512490451, -4632187859049603913, -4622772796095494849, 4616222495624798304, 6556204192918312650, -6003375736878837374, -7829338082165041470, 5205703830818593270, 8607225769993651800, -1706164377296951461, 52871691179874, -4624802863501810618, 748060888410025495]),
+	(-4172144997902289642, [496023938, 5, 923096, 201014326, 581738108, 558429230, 3, -3250495615074906935, 1, 1051744304, 4603478906719566200, -4638318032390807292, -4614510553829521441, -4624877335972955828, 4617112096474778272, 3161506360314859283, -1196893985726338125, -5003729170651803442, -855590047953225907, 5021894502917501012, -3173892328786205006, 52871691179874, 4584992345615979104, -6221566330490055242]),
+	(6711501738592066281, [1193287632, 8, 137580, 615036301, 1670042073, 1114105860, 5, -5771433606735191820, 1, 1064113124, 4604564689899056681, -4616326891653259478, 4608795937784033275, 4608294622986760400, 4616547450802421157, 182189001237837043, -2539580729882488474, -3282590845507412373, -8102343364882550082, -6035109246422892332, -5806681369209091613, 52871691179874, -4630064511129712657, 197252210476096803]),
+];
+pub const MC_SLIME_CHUNKS: [(i64, [u32; 32]); 3] = [
+	(0, [0x90001110, 0x001010A0, 0x00068084, 0x00800002, 0x00808842, 0x00000222, 0x01400906, 0x000B0040, 0x48001008, 0x05003000, 0x0A000020, 0x00800000, 0x00800010, 0x00260008, 0x21000000, 0x10400002, 0x80004134, 0x40800300, 0x08140000, 0x40000001, 0x88040130, 0x00000800, 0x40008000, 0x02000000, 0x18081100, 0x28000108, 0x00050000, 0x21040481, 0x00000000, 0x50014081, 0xF0021001, 0x04000240]),
+	(12345, [0x01004001, 0x00000000, 0x00032000, 0x00100120, 0x10004001, 0x00401000, 0x00000402, 0x03000001, 0x48000014, 0x00000180, 0x10110000, 0x001000E0, 0x41004000, 0x00300018, 0x00410180, 0x00000090, 0x03081041, 0x10104292, 0x84008880, 0x00000220, 0x04004001, 0x8A080002, 0x20000000, 0x00412008, 0x0C000060, 0x84010088, 0x28080000, 0x0C042040, 0x00000040, 0x40008000, 0x00028300, 0x00012000]),
+	(-8838183100465624396, [0x00804808, 0x00400300, 0x00400108, 0x00918422, 0x44200002, 0x02000040, 0x12021050, 0x0A088012, 0x04020048, 0x40080402, 0x14848408, 0x00240100, 0x00430000, 0x00080028, 0x00100002, 0x01800200, 0x00008800, 0x000B0001, 0x00008001, 0x00002005, 0x00202001, 0x00000002, 0x00040204, 0x08800000, 0x21001001, 0x00100002, 0x00400000, 0x00020000, 0x01800000, 0x00912800, 0x40080040, 0x00000002]),
+];
+pub const MC_SLIME_CHUNKS_BEDROCK: [u32; 32] = [0x80080008, 0x00000080, 0x00810490, 0x08000002, 0x09400050, 0x02040020, 0x00440000, 0x00800A04, 0x08800001, 0x30000410, 0x00100000, 0x10010009, 0x02400132, 0x00800000, 0x04032000, 0x43003108, 0x00088400, 0x30080090, 0x880010C1, 0x00000214, 0x10010100, 0x20400000, 0x00010220, 0x00100008, 0x04000400, 0x00A14200, 0x10004420, 0x48000080, 0x13000201, 0x80000020, 0x20402002, 0x03000100];
+pub const MC_SLIME_CHUNKS_DISTANT: [(i32, i32, [bool; 4]); 5] = [
+	(1875000, -1875000, [false, false, false, false]),
+	(-1875000, 1875000, [true, false, false, false]),
+	(2147483647, -2147483648, [false, false, true, true]),
+	(46341, 46341, [false, false, false, true]),
+	(-100000, 3, [false, false, false, false]),
+];
+pub const MC_END_SPIKES: [(i64, u16, [[i32; 5]; 10]); 7] = [
+	(0, 20792, [[42, 0, 4, 94, 0], [33, 24, 3, 91, 0], [12, 39, 2, 79, 1], [-13, 39, 4, 97, 0], [-34, 24, 3, 85, 0], [-42, -1, 5, 103, 0], [-34, -25, 2, 76, 0], [-13, -40, 2, 82, 1], [12, -40, 3, 88, 0], [33, -25, 4, 100, 0]]),
+	(1, 40152, [[42, 0, 3, 85, 0], [33, 24, 4, 94, 0], [12, 39, 2, 76, 0], [-13, 39, 2, 82, 1], [-34, 24, 4, 100, 0], [-42, -1, 3, 91, 0], [-34, -25, 5, 103, 0], [-13, -40, 3, 88, 0], [12, -40, 4, 97, 0], [33, -25, 2, 79, 1]]),
+	(-1, 8899, [[42, 0, 5, 103, 0], [33, 24, 2, 79, 1], [12, 39, 4, 94, 0], [-13, 39, 2, 82, 1], [-34, 24, 3, 85, 0], [-42, -1, 4, 100, 0], [-34, -25, 3, 91, 0], [-13, -40, 4, 97, 0], [12, -40, 2, 76, 0], [33, -25, 3, 88, 0]]),
+	(12345, 45873, [[42, 0, 4, 97, 0], [33, 24, 5, 103, 0], [12, 39, 3, 91, 0], [-13, 39, 2, 76, 0], [-34, 24, 4, 94, 0], [-42, -1, 4, 100, 0], [-34, -25, 2, 79, 1], [-13, -40, 3, 88, 0], [12, -40, 3, 85, 0], [33, -25, 2, 82, 1]]),
+	(65536, 23457, [[42, 0, 3, 88, 0], [33, 24, 5, 103, 0], [12, 39, 2, 82, 1], [-13, 39, 2, 76, 0], [-34, 24, 2, 79, 1], [-42, -1, 4, 97, 0], [-34, -25, 4, 94, 0], [-13, -40, 4, 100, 0], [12, -40, 3, 91, 0], [33, -25, 3, 85, 0]]),
+	(-6998877665544332211, 44481, [[42, 0, 2, 79, 1], [33, 24, 2, 82, 1], [12, 39, 3, 85, 0], [-13, 39, 3, 88, 0], [-34, 24, 4, 97, 0], [-42, -1, 5, 103, 0], [-34, -25, 3, 91, 0], [-13, -40, 4, 100, 0], [12, -40, 2, 76, 0], [33, -25, 4, 94, 0]]),
+	(3257840388504953787, 47191, [[42, 0, 2, 82, 1], [33, 24, 3, 85, 0], [12, 39, 5, 103, 0], [-13, 39, 2, 79, 1], [-34, 24, 4, 97, 0], [-42, -1, 2, 76, 0], [-34, -25, 4, 94, 0], [-13, -40, 3, 91, 0], [12, -40, 4, 100, 0], [33, -25, 3, 88, 0]]),
+];
+pub const MC_ENCHANTING: [([i32; 4], [i32; 3]); 32] = [
+	([2147483646, 9, 27, 1], [5, 12, 18]),
+	([-2147483648, 10, 25, 0], [3, 13, 20]),
+	([509012045, 1, 13, 1], [1, 2, 0]),
+	([-444235535, 18, 0, 0], [0, 0, 0]),
+	([775700073, 6, 2, 0], [4, 9, 12]),
+	([-1355876613, 8, 8, 0], [3, 7, 16]),
+	([-1044461336, 7, 26, 0], [4, 5, 14]),
+	([-1083858081, 1, 13, 0], [1, 3, 5]),
+	([-1711208034, 10, 15, 1], [5, 13, 20]),
+	([-1917535541, 18, 14, 0], [8, 19, 30]),
+	([1907327747, 13, 5, 0], [4, 7, 26]),
+	([1514375603, 15, 9, 0], [6, 14, 30]),
+	([-469454553, 18, 23, 0], [4, 16, 30]),
+	([-1347101856, 1, 16, 0], [1, 3, 9]),
+	([-1427167191, 13, 2, 1], [3, 18, 26]),
+	([1728086124, 19, 27, 0], [7, 19, 30]),
+	([929891009, 17, 1, 0], [6, 14, 30]),
+	([830940487, 0, 28, 1], [1, 2, 7]),
+	([-1753833289, 4, 2, 0], [4, 5, 8]),
+	([945453237, 10, 23, 0], [3, 12, 20]),
+	([-300763799, 2, 28, 0], [1, 6, 7]),
+	([-2064407986, 12, 9, 1], [4, 14, 24]),
+	([-76700087, 9, 15, 0], [5, 10, 18]),
+	([1959921917, 12, 13, 0], [4, 12, 24]),
+	([1410218333, 0, 11, 1], [1, 3, 5]),
+	([1857841538, 18, 14, 1], [6, 15, 30]),
+	([-276720706, 19, 15, 0], [4, 14, 30]),
+	([1839086832, 19, 18, 1], [5, 15, 30]),
+	([-1474787303, 0, 13, 1], [2, 4, 3]),
+	([-1170510033, 18, 16, 0], [6, 16, 30]),
+	([512767281, 17, 9, 1], [6, 14, 30]),
+	([1513383291, 5, 11, 1], [3, 7, 10]),
+];
+pub const MC_ENCHANTING_OFFERS: [[[i32; 9]; 3]; 32] = [
+	[[2, -1, -1, -1, -1, -1, -1, -1, 2], [1, 5, -1, -1, -1, -1, -1, -1, 5], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[2, -1, -1, -1, -1, -1, -1, -1, 2], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, 2, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [-1, -1, -1, -1, -1, -1, -1, -1, -1]],
+	[[-1, -1, -1, -1, -1, -1, -1, -1, -1], [-1, -1, -1, -1, -1, -1, -1, -1, -1], [-1, -1, -1, -1, -1, -1, -1, -1, -1]],
+	[[0, 1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [1, 0, -1, -1, -1, -1, -1, -1, 1]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [1, 0, -1, -1, -1, -1, -1, -1, 1], [1, 4, -1, -1, -1, -1, -1, -1, 4]],
+	[[1, 0, -1, -1, -1, -1, -1, -1, 0], [1, -1, -1, -1, -1, -1, -1, -1, 1], [0, 2, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, 1, -1, -1, -1, -1, -1, -1, 1], [1, 0, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [5, 0, 1, -1, -1, -1, -1, -1, 1]],
+	[[2, 0, -1, -1, -1, -1, -1, -1, 2], [0, 5, 1, -1, -1, -1, -1, -1, 1], [4, 2, 5, 3, 0, -1, -1, -1, 2]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [3, 4, -1, -1, -1, -1, -1, -1, 4]],
+	[[2, -1, -1, -1, -1, -1, -1, -1, 2], [0, -1, -1, -1, -1, -1, -1, -1, 0], [4, 6, -1, -1, -1, -1, -1, -1, 6]],
+	[[1, 0, -1, -1, -1, -1, -1, -1, 1], [1, 4, 5, -1, -1, -1, -1, -1, 1], [5, 2, 0, -1, -1, -1, -1, -1, 5]],
+	[[1, -1, -1, -1, -1, -1, -1, -1, 1], [0, -1, -1, -1, -1, -1, -1, -1, 0], [1, -1, -1, -1, -1, -1, -1, -1, 1]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[1, -1, -1, -1, -1, -1, -1, -1, 1], [4, 5, -1, -1, -1, -1, -1, -1, 5], [0, 5, -1, -1, -1, -1, -1, -1, 5]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [5, -1, -1, -1, -1, -1, -1, -1, 5]],
+	[[2, -1, -1, -1, -1, -1, -1, -1, 2], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [1, 0, -1, -1, -1, -1, -1, -1, 0], [0, 1, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [5, 2, -1, -1, -1, -1, -1, -1, 2], [0, 1, 4, -1, -1, -1, -1, -1, 1]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [2, -1, -1, -1, -1, -1, -1, -1, 2]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[1, -1, -1, -1, -1, -1, -1, -1, 1], [4, -1, -1, -1, -1, -1, -1, -1, 4], [5, -1, -1, -1, -1, -1, -1, -1, 5]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [1, -1, -1, -1, -1, -1, -1, -1, 1], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [5, -1, -1, -1, -1, -1, -1, -1, 5]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [2, 0, -1, -1, -1, -1, -1, -1, 2]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [2, 0, -1, -1, -1, -1, -1, -1, 2], [0, 1, -1, -1, -1, -1, -1, -1, 1]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0]],
+	[[2, -1, -1, -1, -1, -1, -1, -1, 2], [1, -1, -1, -1, -1, -1, -1, -1, 1], [1, 0, -1, -1, -1, -1, -1, -1, 1]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [5, -1, -1, -1, -1, -1, -1, -1, 5]],
+	[[0, -1, -1, -1, -1, -1, -1, -1, 0], [0, -1, -1, -1, -1, -1, -1, -1, 0], [1, -1, -1, -1, -1, -1, -1, -1, 1]],
+];
+pub const MC_XOROSHIRO_GAUSSIANS: [(i64, [u64; 48], u64); 6] = [
+	(0, [0xBFDF10E821B3B757, 0x3FDBC687897270BA, 0x0000000026F4B9F4, 0x3FE7A0EDCBF3D3F5, 0x3FE911B5C4DAB678, 0x00000000547D4FE1, 0xBFDB4733071FA416, 0x40034A5FA7D78001, 0xFFFFFFFF954DE613, 0xBFA1FA185D437A05, 0xBFE9D6C3CD194102, 0x000000006FB2A297, 0x3FD93F4DB18AFA69, 0x3FB60C03CFD13542, 0x000000002B18A125, 0x3FE6AFEC50CD3E3E, 0x3FE573329C9F5C9D, 0x00000000456A41DD, 0x3FC22F60AEF86783, 0xBFC846D8ABFA14CF, 0xFFFFFFFFFB0C6215, 0xBFE2836F6DF1004C, 0xBFDA7C7D6697D750, 0x000000002110B3F7, 0x3FF2BF5DDF0426CD, 0xBFFAEB9ECAD088D6, 0x0000000071E4A483, 0x3FE433C0C9E41528, 0xBFF66B46A77FE55F, 0x000000003559A6BF, 0x3FF56E20021E2314, 0xBFED2E8585658327, 0x00000000546814DE, 0x3FD73E6B163DAB3C, 0xBFF2089B89CCD28B, 0x000000006EDB89BD, 0x3FE82F363CC7328D, 0xBFDE520334D2D6FA, 0x000000002869813E, 0xBFE20230B605BEF5, 0x3FDADBE631B62DC2, 0xFFFFFFFFAD11F2C2, 0x3FDB7D86F2CFC03F, 0xBFEE48039B864225, 0xFFFFFFFFBEB36261, 0xBFE292DB1F3B47D8, 0xBFEB2FD96AA789AA, 0xFFFFFFFFDF16DE51], 0x3FDD62CE3DD7A9E7),
+	(1, [0x3FDED382E2824E77, 0xBFC4DD8F4DF26E81, 0x0000000031308A62, 0xBFE34725CB3A5A80, 0xBFB4E2BEAA634235, 0xFFFFFFFF87DD5FDB, 0xBFE6E9ABC9BB1F9C, 0x3FD4D059EB24295E, 0xFFFFFFFFF522E886, 0x3FDE679A89EB4F22, 0x3FF7C298557DBC39, 0x000000007EA42C5F, 0x40018AADA9A56289, 0x3FFF0ECD0BFE6032, 0x0000000052AAA4F6, 0xBFD03C94A8575656, 0x400216B66710FBBE, 0xFFFFFFFFDA833FAE, 0x3FF1126C500FC3E4, 0x3FD6858BDC41A9C8, 0x00000000281A5A58, 0x40003AA318955323, 0x3FEBED53C5825D0D, 0xFFFFFFFFEAD58D08, 0x3FDF3FF76A7AC6B6, 0x3FC49ADA9CE6F80D, 0x000000004DBBA34E, 0x3FFD699847175AE5, 0x3FF12A9F0F0BAF1B, 0x0000000051A01633, 0x3FD91AC063E0A925, 0x3FF4F0AA540F510C, 0xFFFFFFFFB993CA8F, 0xBFF907372EDDB4B8, 0x3FF0E658BAED7295, 0xFFFFFFFFA2382D13, 0x3FF9F32F83CCF9C7, 0xBFE7D48C647DECDF, 0x000000006885A671, 0xC002493E1D54B136, 0xBFE4E28506A073EE, 0xFFFFFFFFF42473F1, 0xBFF3769FE5DCCDBF, 0xBFD088F2B6236584, 0x00000000402951CF, 0xBFF42F8DBA4DB527, 0x3FFD648814D429DD, 0xFFFFFFFFEB151746], 0x3FD43929478D3620),
+	(-1, [0x3FA4CE4899270884, 0x3FE3DDCABEF94609, 0x00000000369F765A, 0x3FE57DA612B6F63F, 0x3FD59C377D95653E, 0xFFFFFFFFEA8F95E5, 0x3FD8AB24CDC84702, 0xBFF6EADE93DB3221, 0x0000000050E74E18, 0x3FE017C93B2A6F95, 0x3FE47514AAB9EA09, 0x00000000714B3A74, 0xBFF90EC32C2722B2, 0xBFF3EBE382CB1028, 0x0000000024714FCA, 0xBFE0EAED6C9529E9, 0x3FB886F67B04D186, 0xFFFFFFFFA70DAD83, 0xBFEDFD1E3FEA7BC6, 0x3FE28CA3A3260FD2, 0x000000007EE8A9FB, 0x3FD50BF370030D64, 0x3FF260F81AE7FC03, 0x000000007C6E9C80, 0xC001C555072E0CE6, 0x3FEB11408A0F51C5, 0xFFFFFFFFD375AC11, 0x3FEA202ADC9AA1AF, 0xBFE656B430F88236, 0x000000000EE8B37F, 0x3FEECEAD1C7F9B0C, 0xBFE10ED07CCBA111, 0x000000001441939C, 0x3FE7C0918603458A, 0xBFFB093C96CF332B, 0xFFFFFFFFCD556BC9, 0xBFD972981ED9553B, 0xBFEBC048D22569C5, 0xFFFFFFFFA432C728, 0xC00A660CF585F4F2, 0x3FC7C205D919D4FD, 0x00000000004CF320, 0x3FEEE9DCF57AEA77, 0x3FDDFF3D587DEA65, 0xFFFFFFFF8B40CC54, 0xBFC491257EAF5395, 0xBFE4FFBB426EC6F3, 0xFFFFFFFF93686341], 0x3FE4EC92B69DC8C5),
+	(2305, [0x3FE83ADA8B87FFEC, 0x3FF110E6F599A734, 0xFFFFFFFF983D36BB, 0xBFE1AED7099CB37E, 0xBFE36EC986506398, 0x0000000024A49307, 0x3FD4D5806F1E1F75, 0x3FF3C2812929E7D7, 0xFFFFFFFFE04694F3, 0xBFF508DEAA9930FD, 0xBFD81804D965D762, 0x000000005AA0780C, 0x3FA48B2F1757106A, 0xBFD46674EF933BD4, 0xFFFFFFFFB2D02F13, 0x3FD58852399813FE, 0xBFDC0ABACCCC8555, 0x000000007C73D09F, 0x3FFA413E6929D836, 0xBFF3769AA551F6EC, 0x00000000462CBA89, 0xBFF149932572AB87, 0xBFEFC4CA9098A2F7, 0x000000002B430A3D, 0xBFB0DFC8ED3DBBC7, 0x3FDEF21CB8270C51, 0x000000005FC8012F, 0x3FD772D0E2559145, 0x3FCF39D5ECAC15F4, 0x000000002D456B4F, 0xBFF185886A9FF411, 0xBFFF9EB0B79DD9F7, 0x000000002DDF628F, 0x3F867FB928F2E4E1, 0xBFD5FD9B8227F05E, 0xFFFFFFFFAC890F10, 0xBFF0D71F08FAB10A, 0x3FF5E4ACC2B35A50, 0xFFFFFFFF8E92D7AE, 0x3FF541EED4415EE1, 0x3FF894208547CCD9, 0x0000000076A092AA, 0xBFCD73DE088731C1, 0x3FF61EA9E915352E, 0x000000003B566DF7, 0xBFF2A77EFFDB31A2, 0x3FDA9CA46577A7F1, 0x0000000045798EDD], 0x3FD295190AD3C66B),
+	(-2990443614668268781, [0xBFF1E07D0226BE63, 0xBFE247349AF6A294, 0xFFFFFFFFDFB1B7D8, 0xBFFA0CA666E29F1B, 0x3FED0960BE343234, 0xFFFFFFFFC2480E8E, 0x3FE40361BBAEC100, 0x3FB00AF6E7E9AD63, 0xFFFFFFFF9B0C941E, 0xBFC8AE4C81B7E802, 0xBFF8BD8D4A2173CF, 0xFFFFFFFFDCD8A073, 0xBFF259A7703888B0, 0xBFF9EA296E0B4FF2, 0x000000006A3AAA19, 0xBFD7D70C45D09E00, 0xBFCD9F2EFD4FA3B5, 0x0000000027021BAC, 0xBFFEDEEE1EE100DD, 0xBFA411934EA46E2D, 0xFFFFFFFFCC83F383, 0x3F8A0D3F9FA30633, 0x3FF2792FBA7A1948, 0xFFFFFFFF9D7E01C5, 0xBFAECA37FA7B3D99, 0x3FF01AEE46C70038, 0xFFFFFFFF84A81E41, 0xBFF01496FF95647C, 0xBFFC7BCC623F9376, 0xFFFFFFFFFF897F19, 0xBFE554B84FAC447F, 0x3FB3B340D5D4A649, 0x0000000002B0E9FC, 0x4000DB5FC1B8D8F1, 0xBFF56D892FE3C16F, 0x0000000059691112, 0x3FEF1C7254F0F5C8, 0x3FFB79FD390D5FBC, 0xFFFFFFFFE1D67D53, 0xBFF298ADAC170FCA, 0x3FE0D635581DB25C, 0x00000000640D5820, 0x3FE4B1D00FD520B2, 0x3FF30CE592E6189E, 0x0000000071DB2D8E, 0xC00125CF2BEAB744, 0xC003C4B7C2788FC2, 0x00000000083760FC], 0xBF9FAECC8A952846),
+	(7597751424082460988, [0xBFF4203ECC7CBD41, 0x3FCED546B5BDCEA0, 0x000000004CB085D7, 0xBFDC49B9753F5265, 0xBFE6D4E0F80FE3C7, 0x000000004BE16005, 0xBFCF8A59EED8EEC9, 0x3FE5EC2EAFD6CFA9, 0xFFFFFFFF819480A1, 0xBFEEAE2507E5B950, 0x3FDFB71D54A431B5, 0x000000007EF47CD5, 0x3FF26896D1200BBE, 0xBFD5D3ED3E7E6AEC, 0xFFFFFFFFFE8676F4, 0xBFE965D1FE2A42A9, 0x3FF43935783A3749, 0x000000002169DA2C, 0x4002839BFEB44C4D, 0x3FE86F2C391E6796, 0x000000001A7F5000, 0xBFF114C5252BAD63, 0x3FBBF476D0C16B7E, 0xFFFFFFFFCDC0DC80, 0xBFF0B8CA75EC4F0E, 0x3FD547F91846EFDA, 0x000000003B176AEE, 0x3FF0C6FCD3C659C1, 0x3FEF524CCDE64A59, 0x000000003235E039, 0xBFE3D82C3DFBDC6A, 0x3FF0836B5BE7A0F4, 0xFFFFFFFFFFBB50D8, 0x3FD1B3756DDD76D1, 0xBFF4ACE5D6CE97B1, 0xFFFFFFFFCA4CCBB0, 0x3FCE4A3324B463E7, 0xBFEFF26A44B5E427, 0xFFFFFFFF90440299, 0x3FFCA0CF5BA81CBC, 0x3FD29ED37AB7270D, 0x000000006790093B, 0xBFE0666BD79C463A, 0x3FFB78688CF281B6, 0xFFFFFFFFD484333D, 0xBFFC9C4607C9A6E4, 0xBFC29A24ADD1517C, 0x0000000039DF6F47], 0xBFC0CB65113E59A5),
+];
+pub const MC_RANDOM_SPREAD: [(i64, i32, i32, usize, i32, i32); 96] = [
+	(-2812886855877526195, 76218, 72190, 0, 76205, 72190),
+	(-5894249615177684595, -93586, 6010, 1, -93588, 6019),
+	(-857323499096256853, 36089, -41630, 2, 36105, -41636),
+	(-4863267410460878891, 74760, 33876, 0, 74747, 33889),
+	(7287483890773417801, -97919, 50367, 1, -97906, 50357),
+	(4911640673368598297, 36983, -1633, 2, 36987, -1658),
+	(5665908998758554157, -76535, 19523, 0, -76556, 19532),
+	(-332461906007238258, -33576, 90185, 1, -33560, 90177),
+	(-8735302148628964895, -43870, 25132, 2, -43896, 25161),
+	(-3397806521246837456, -41408, 99636, 0, -41396, 99622),
+	(-3804887122031012624, -32968, 84748, 1, -32964, 84763),
+	(1264497085900740671, -85824, 96944, 2, -85832, 96917),
+	(3633842367906175596, 74512, -42555, 0, 74497, -42563),
+	(629479799477042620, -82142, 22914, 1, -82134, 22913),
+	(-7706365000538448277, -67191, -97780, 2, -67179, -97801),
+	(1982346721419153905, -4501, -31456, 0, -4506, -31471),
+	(1290543597272127109, 3101, 36266, 1, 3112, 36264),
+	(2245628314243462886, 153, 36786, 2, 110, 36734),
+	(2448877202694628952, 60062, 59702, 0, 60045, 59695),
+	(-3571373434958423828, -61659, -97902, 1, -61679, -97914),
+	(-1600443475171812347, 47739, 6235, 2, 47703, 6170),
+	(-3517484006585060586, 66176, 97484, 0, 66183, 97487),
+	(-7539768508985033552, -41414, 19288, 1, -41419, 19291),
+	(-5823647704280390725, 65366, 76894, 2, 65408, 76891),
+	(-6539113436323880701, -16259, -71493, 0, -16277, -71494),
+	(-7203947565070692106, -84081, -94407, 1, -84091, -94408),
+	(-3745118806505627221, 81599, -65756, 2, 81544, -65727),
+	(6874097887058271526, 9931, 81469, 0, 9930, 81486),
+	(4990349229603132378, 33110, 10120, 1, 33110, 10108),
+	(6205294805475257198, -30429, -38208, 2, -30467, -38190),
+	(-4325429277816580668, -25552, -49173, 0, -25556, -49174),
+	(-5927294178165394378, 1389, -76472, 1, 1375, -76488),
+	(3460838327298583330, -72808, 87460, 2, -72862, 87497),
+	(3581340035340544424, -38986, -80116, 0, -38996, -80127),
+	(-4401872181369853518, -87633, -34212, 1, -87647, -34221),
+	(6207204153691089972, -90937, -25354, 2, -90923, -25320),
+	(-7069139135912888908, -82379, -99249, 0, -82363, -99278),
+	(-286444335257006324, 57058, 16931, 1, 57066, 16923),
+	(5953189465779342958, -36353, -46520, 2, -36356, -46514),
+	(-6979056070782899031, 2563, -4128, 0, 2559, -4133),
+	(2336492893814500010, 11456, -87255, 1, 11459, -87246),
+	(4330306974956047172, -11039, 20591, 2, -11015, 20606),
+	(-2729594136217918157, 69762, -14240, 0, 69740, -14246),
+	(4709253631346430302, 57708, -40000, 1, 57701, -40006),
+	(-9157730395608155813, -8213, 26483, 2, -8220, 26498),
+	(5478369767331678776, 53070, -97288, 0, 53043, -97293),
+	(-6721196649216635016, -61125, 437, 1, -61123, 432),
+	(5038579190122445403, 27692, 20076, 2, 27723, 20006),
+	(8299602205697431907, 30218, -66419, 0, 30201, -66416),
+	(-5190536396767181806, 58020, -922, 1, 58008, -936),
+	(7484071373762707191, -99444, -60106, 2, -99509, -60131),
+	(-3951709848003240981, 46798, 96223, 0, 46802, 96235),
+	(-2757691668924556648, -30834, -61191, 1, -30828, -61200),
+	(8040538095480180026, 31613, 6211, 2, 31647, 6170),
+	(-3548173677876410967, -41928, -80908, 0, -41939, -80918),
+	(-3590858145632432490, -39171, -16529, 1, -39174, -16535),
+	(-7480409697112738540, 36682, -84010, 2, 36671, -84046),
+	(-7554565840621148582, 59476, -61066, 0, 59467, -61094),
+	(7129591262387267015, 29793, -50080, 1, 29791, -50074),
+	(-8026864234849018075, 97154, 95767, 2, 97153, 95804),
+	(3538955477610158385, -37391, 70245, 0, -37396, 70246),
+	(376759058533157979, 43139, 3080, 1, 43137, 3081),
+	(-823358370978864695, -5623, -53174, 2, -5628, -53179),
+	(107950360648441505, -91511, 32814, 0, -91509, 32812),
+	(-6045352263230652537, -32108, 48710, 1, -32104, 48701),
+	(-5056609018074019740, -94076, -85726, 2, -94026, -85738),
+	(8474173787898317929, 14612, -55838, 0, 14603, -55846),
+	(6639175193300316755, -91364, 77677, 1, -91360, 77674),
+	(-9132780759316858640, -66073, -99782, 2, -66034, -99795),
+	(-2573247106191949855, -5646, -51701, 0, -5659, -51704),
+	(-5565633937356121952, -45116, 57402, 1, -45118, 57397),
+	(3868856429257668, 28965, -68372, 2, 29007, -68377),
+	(-8723323446012346748, -41348, 22626, 0, -41362, 22613),
+	(911851982202258553, 43400, -44434, 1, 43393, -44440),
+	(-8836071046057039449, 36027, 31554, 2, 36019, 31576),
+	(-210745917402713923, -26066, 5156, 0, -26066, 5136),
+	(2226107555585278364, -48763, 48893, 1, -48749, 48902),
+	(1557970900015473685, -33823, 36177, 2, -33800, 36168),
+	(6557806463716926198, -13765, 97930, 0, -13769, 97936),
+	(-111206574329603257, -8476, 95213, 1, -8486, 95208),
+	(2282059594393942993, 39702, -25554, 2, 39716, -25579),
+	(5483492923309357279, -11083, 53726, 0, -11066, 53745),
+	(3454092152546932808, 28328, -10174, 1, 28320, -10161),
+	(-9072752105719809621, 47887, 47808, 2, 47865, 47806),
+	(3991250730697260796, 17824, -79373, 0, 17820, -79388),
+	(2978237721109395776, -48095, 69533, 1, -48085, 69533),
+	(6445004308678409620, 59844, 16572, 2, 59866, 16608),
+	(-8589666077300652429, 98522, -80651, 0, 98522, -80667),
+	(-4263487454400133833, -9099, 69165, 1, -9114, 69153),
+	(-1397909245244258336, -8161, 68215, 2, -8218, 68174),
+	(-4831174921459583213, -84368, -33689, 0, -84384, -33681),
+	(3219536131198414950, 48720, -51259, 1, 48723, -51258),
+	(7195150532619632858, -3894, -37218, 2, -3889, -37249),
+	(5872954779003765672, -17400, -12556, 0, -17405, -12577),
+	(5835901441716693312, -86863, 35413, 1, -86869, 35406),
+	(-3848239997056357746, -56932, -84407, 2, -56925, -84464),
+];
+pub const EXT_SAMPLES_SEED: i64 = 2718281828;
+pub const EXT_SAMPLES: [(u64, u64, i64); 256] = [
+	(0x3FF7E6F1E4216ED2, 0x3FFEDC5463201E1E, 47), (0x3FEE5D37236AF1D9, 0x3FFAA0C1933D5F5F, 15),
+	(0x3FE7F9891A6D8980, 0x4001167878AF038F, 15), (0x3FE2645862E6864F, 0x4005EE1136B0E30A, 7),
+	(0x3FE7DCFCB2C905A4, 0x400BA3FEF5389D2C, 12), (0x3FEF1510FBC7A858, 0x40004366F85DE042, 13),
+	(0x3FFC5FDA65E10FFE, 0x3FF946BFA5B38F01, 62), (0x3FE6B666D984D22A, 0x3FFFB4D57894CB29, 43),
+	(0x3FFB9F8736C50AE9, 0x4011D64D17093838, 3), (0x40123114A14471E8, 0x40053173247272AE, 27),
+	(0x3FFE978DD69D8029, 0x3FF81CE338140742, 1), (0x3FE02630C746A055, 0x3FFCA89CE47C826F, 4),
+	(0x3FF1FA59C247115E, 0x400D3C7CEBE3F2D9, 59), (0x4004583941CC958B, 0x3FFF80228244BE52, 3),
+	(0x3FFA3A77A66BB6EE, 0x4008DA69C8A1AA50, 33), (0x3FE45EAB22219B6C, 0x3FFC5E00538BF2A1, 3),
+	(0x3FE4327FA1C20092, 0x4005DEAB89BAD09C, 4), (0x3FE36432A46B5746, 0x3FFADB00C03A574B, 21),
+	(0x4005C355D48680FB, 0x4003AC16A29AFCA6, 6), (0x3FF11091235B60E0, 0x3FFCCD33DD9CD1AE, 19),
+	(0x4006F966AFF26975, 0x4005DA4D14A1CA69, 8), (0x4013B45C11CB8082, 0x3FFCF4DF44BB27F3, 3),
+	(0x3FDF84937585C574, 0x4010B76CEEED68F0, 9), (0x3FF5DA3DAFBA636A, 0x400122C3C410A416, 15),
+	(0x3FE6E9034C801914, 0x4005CC9EF7FEDDB2, 33), (0x400D645934A99DBC, 0x4008962FD51AC19C, 11),
+	(0x3FFCB724A84BFC7E, 0x40032B412D10207B, 39), (0x3FFBEBD534647CC2, 0x4002D6B6D9C0063A, 15),
+	(0x3FED0FA9E5BDEC95, 0x401A067346F5585C, 13), (0x3FF918F05CFDAA7D, 0x4001C92A1E38E8B0, 29),
+	(0x3FF0CC3ED50F85BE, 0x3FFE94C53AAE0D66, 17), (0x3FF2C194DA7D1923, 0x3FF95F8610E8D0AF, 11),
+	(0x3FDDEED32FAB35E2, 0x3FFC61312CCABBF6, 20), (0x3FFC1A8AEAAE3E24, 0x4018E30002129E64, 17),
+	(0x3FF242563D0CFD33, 0x3FFC5B3A0A4530BF, 30), (0x3FF6A5DE5D351C66, 0x3FFBF5B360BD268A, 4),
+	(0x3FFFDB10E2E60D43, 0x3FFE356D872556AA, 25), (0x3FCB8CACDF231B22, 0x3FFAEAA9BFFFD8EB, 4),
+	(0x3FFD6A03F4CBC9D4, 0x40005FA074411C0B, 1), (0x3FF3A3A04D7098E9, 0x3FFC3DE9C6900D8B, 1),
+	(0x3FE7E8E12FAB4F08, 0x3FFA3A2DEDCEDBEB, 15), (0x4007160ACD6E2BD2, 0x4001D87B10BFF4E4, 0),
+	(0x3FF67E780BF0F5CE, 0x3FFE2FE51FF5A4E2, 7), (0x3FE5C527276D31FC, 0x3FFDDC70CFA9362B, 17),
+	(0x4009FAEE4DD97200, 0x3FFAF36648B7E438, 5), (0x3FD8D081CC971EE3, 0x3FF813325AA9755F, 2),
+	(0x3FEA8004FA92708D, 0x400A439107C2A469, 1), (0x3FF550695FE04D4F, 0x3FF8F00BB6EBF444, 28),
+	(0x400827AF1A5C6D4E, 0x3FFA6EDA00529DB6, 36), (0x3FEA8245F779CE79, 0x3FF9AC1B0F5A57CA, 0),
+	(0x3FE71954CC344052, 0x4020D7447963026A, 23), (0x3FE08E23C025D562, 0x400A645CD1B48198, 27),
+	(0x3FF17BD874BB4F76, 0x4007108AF559AE88, 16), (0x4010EE0CF2DBCDDB, 0x4008FFB3A8603BC4, 9),
+	(0x3FE53B9CD877E992, 0x3FFE0D23BFD72050, 9), (0x3FF6578D662C626A, 0x3FFA3C53177467D6, 10),
+	(0x3FF6F7EDA2B4CB74, 0x3FFAA5D3F4844B7A, 45), (0x4006E129D991D5A3, 0x3FFBC188A0440608, 3),
+	(0x3FFFC001F6538F19, 0x3FFF25A8DCA4E904, 11), (0x3FE66CB194F35A97, 0x3FFCC6880B8339A2, 18),
+	(0x3FD96E00D4A91289, 0x4005B47B48EA6752, 51), (0x3FFD7D722628B966, 0x40037E712805FD5A, 2),
+	(0x3FF618956836B904, 0x3FF8745F4B31B898, 12), (0x3FF79723CBAA0CA8, 0x3FFC19EA0E500C8C, 33),
+	(0x3FEFC78D1021AB2D, 0x4004453926A009B4, 2), (0x3FFCDC00E2948447, 0x3FFD98E94D40AC53, 22),
+	(0x3FF488B00E967E40, 0x4001B588C06D435B, 3), (0x3FF93E3BB55B08D7, 0x3FFD65F042E7CA55, 51),
+	(0x3FF37B7F48A09A6A, 0x3FF84AF13C4475B6, 16), (0x3FFC647C0A79DAB6, 0x400085F156410D50, 17),
+	(0x3FE0402D92AD01DC, 0x3FF9C779DF903EAE, 13), (0x3FE31AEF8008E2F9, 0x40030591CA1B433A, 7),
+	(0x3FF8BA6FE9A4BF72, 0x400608D7965BAD18, 19), (0x3FE7E42B9AC067A7, 0x4000B36C192C9A20, 4),
+	(0x3FE824B778B459BF, 0x40004F3EC267AED0, 5), (0x400542367A872E2C, 0x401DE8D9FA422DE8, 129),
+	(0x3FE28BC1179D8CEE, 0x3FFD3839EA17286B, 4), (0x4013C738268D52FD, 0x3FFDA193FD68FC7A, 9),
+	(0x3FE189CD92C29FC1, 0x4001C4576E5E4936, 13), (0x3FFE5FC46C0AAA94, 0x400B918DB1D2FDA2, 1),
+	(0x4001F346AAF3C037, 0x3FF8A68E75EC2470, 0), (0x400884A2294137D9, 0x400EE9D8BC5265EC, 102),
+	(0x3FD3AE8958B47B1B, 0x3FFDF7DC4158F670, 7), (0x3FF8A01A3622462A, 0x3FF9526F22FFCD8A, 62),
+	(0x3FF1925B8F2EDE18, 0x4001D5C0A67979D3, 8), (0x3FE8E0DE35B8C06A, 0x3FFBAB3CE793D8F8, 11),
+	(0x3FE4302C38467F53, 0x4000F8868678E7FB, 3), (0x3FF0920633AFC1E5, 0x3FF8345F27040316, 0),
+	(0x3FD5D0CDD76767F5, 0x40008AD4DD827C02, 28), (0x3FF1A21474408719, 0x3FFC43DAD0570F6C, 22),
+	(0x3FF5EAB819342886, 0x3FFE683945629626, 11), (0x4008F228B3E4EF5F, 0x40025CF64802F402, 15),
+	(0x401924DAEF0E3D80, 0x4005648AB89C8438, 2), (0x3FED7B3B88BA3C0D, 0x3FFB08A1CE20DFF8, 49),
+	(0x3FF7271619EAB4A1, 0x4006F94FD2BA78D4, 5), (0x3FF8B4F561076359, 0x40041ABDF007F6FC, 19),
+	(0x3FF4D96C09815E26, 0x4002C32CE61BBB4A, 22), (0x400CBB848B825D65, 0x400BFD478DFD496A, 9),
+	(0x3FF08D590ED7320F, 0x4004088F7EF9AE0E, 6), (0x3FECBA9F96ADBDD7, 0x4015FC844CA109E6, 3),
+	(0x3FF39B71FF908028, 0x401CB4BBD647DB70, 11), (0x4001FC1B242D869D, 0x3FFAAC0D245A5C06, 29),
+	(0x3FF34505ADB028EF, 0x3FFDFD0ADAB439E1, 8), (0x3FE66B4F9DA55BC1, 0x3FFB2B49919A05EE, 17),
+	(0x3FEF0179DDCB0A27, 0x4011DFCCB7F4F39B, 38), (0x40129532B9658F9B, 0x3FFD2B3ECC6850A8, 34),
+	(0x3FF5A51B14D7E361, 0x3FFA3DFB4AAEAA94, 3), (0x400B00B503BB8B7B, 0x400108F7B3D4AE0F, 34),
+	(0x400B007543BCDFBC, 0x3FFAD9C971029D66, 3), (0x3FF2A89BE3A30A91, 0x400B93B7BD245844, 37),
+	(0x3FE1A4A51C603B74, 0x4004C99CDEE93138, 19), (0x3FE2ABE2C5910032, 0x3FFFB80E0926C340, 21),
+	(0x3FFD4CD02DC3CC91, 0x4000974CC2657294, 28), (0x3FE856F6BA017069, 0x400255C2E8E89D52, 5),
+	(0x3FF665CEF130BC5C, 0x3FF87F965DDB2BB6, 28), (0x3FE81681A9164967, 0x400BFB8F1EF64922, 49),
+	(0x3FE577B185013187, 0x3FF834C51D5FC17F, 8), (0x3FF37AC357566B53, 0x3FFDF7EB215CA98E, 65),
+	(0x3FF12A117C7E586A, 0x3FFBFBD553B6BDF9, 24), (0x3FF9D4B2AA3D425E, 0x4000D20EC49BCAC6, 18),
+	(0x3FEF3347C7566B44, 0x3FFE4A08025EBCF4, 1), (0x3FE847EE6E7982AF, 0x4002599956809102, 37),
+	(0x3FCA5C4E6CF7F487, 0x3FFA13D7E5BB3C5A, 2), (0x3FE926F8AED13D43, 0x3FFBA335D751C49F, 180),
+	(0x3FF7E13E354A6D89, 0x3FF9E7680BA8925F, 3), (0x3FF2A7409EAD87BC, 0x3FF861FC2203F288, 12),
+	(0x3FE40CFF9B13DCDC, 0x3FF998DDDAAFAB00, 1), (0x3FFAB5CEA05097AA, 0x3FF8F7354DA714FF, 2),
+	(0x3FE625EEA5B9405F, 0x40096CCCBB8B69E4, 88), (0x4016E41361928566, 0x4017EDB7D878E0E5, 12),
+	(0x3FEA1F9BD14E88C7, 0x3FFC72E3B56700D9, 11), (0x3FE59CB586089F68, 0x4001411FC3830D89, 7),
+	(0x3FFC1B2755351B58, 0x3FF958C7E9B09672, 3), (0x3FE3EDC4F9F2D9D7, 0x3FFA9E318601E8F8, 14),
+	(0x3FF9F3044B7F7B7D, 0x3FFDBBE34A2F5D28, 7), (0x3FDF7F2B4C9CBEA0, 0x40053C315CCE9AB6, 46),
+	(0x3FE2D94C52263F82, 0x400CF1F0C7DDF1D6, 40), (0x3FE8CB1EB81F598E, 0x3FF952F16A2893F0, 28),
+	(0x3FE1DAAB51732CB1, 0x400028388C9D720A, 3), (0x3FEFE8ED6F7889DD, 0x4001F3FB066BB020, 22),
+	(0x3FCBAF16D3D7FB88, 0x4003F432396FD0E2, 11), (0x3FF175A766AE29E0, 0x3FFAB89B7DCA38E2, 12),
+	(0x3FF371FA50D42096, 0x3FFDECFF135516AE, 7), (0x3FEF42D1FDAF3D28, 0x400375CE2C53369F, 3),
+	(0x3FF4B4A8455595B9, 0x4011FEB7402FD660, 6), (0x3FF669D899B119EF, 0x3FF8FAFAF843C4DA, 3),
+	(0x4015DD432BC5FC7D, 0x3FFCED2EDE213D48, 3), (0x3FF09D8CC1A6A8C7, 0x3FFC1423D104EF29, 17),
+	(0x40031D9B27B890E2, 0x4009B53D4B4D1802, 30), (0x4003BE1B9EFEFE10, 0x3FFAC4AA22635B34, 34),
+	(0x3FF041430A27B6D7, 0x4001B58105A3F82A, 33), (0x3FE57F774B53E63D, 0x400280E249450E57, 21),
+	(0x3FEE5551B532BDAC, 0x4002DDFDE0313103, 23), (0x40047F73BCFA98A0, 0x4011DC2D76458150, 28),
+	(0x3FDA77F2E01968D2, 0x40011F83DF487539, 24), (0x3FF31F1A63F8F02E, 0x3FF9FE5050AC3C1E, 11),
+	(0x3FECA3BC548F51E7, 0x3FF848D2C0F8207C, 2), (0x3FF2326BEEC96F2D, 0x402409566A72738D, 8),
+	(0x3FF02974A977E0F2, 0x4009B950D22E8B9A, 2), (0x3FE129E152D2450A, 0x3FFA1FC0D3B11448, 50),
+	(0x3FFDB5949CB43251, 0x4004EB3079C0472C, 69), (0x40176CB435436500, 0x3FFF1E9DEDEE628B, 25),
+	(0x3FE351AB4969D83F, 0x400B8555FD81CABE, 5), (0x3FF0FC6784F35871, 0x3FFE8F11CCFD79C8, 37),
+	(0x3FF0070F1B33CF19, 0x3FFCFD064A77DE42, 15), (0x3FEF53F0B8B09231, 0x4009C8985BC1DD34, 57),
+	(0x3FF979B35CC08B0D, 0x40020FDD004FDFF9, 4), (0x400BC920ED2D3502, 0x3FFDE9E6045E34BC, 11),
+	(0x3FF8B3CE72B06C6B, 0x4016C1164F9A46A0, 23), (0x3FE29B77E24B966F, 0x4000C7F97393FEA4, 39),
+	(0x3FC4D2452BDD2B7F, 0x400683B38D6F5202, 25), (0x400768EEEF5DC6C8, 0x3FFCE8F7FA2391B6, 12),
+	(0x3FF72591AC95D048, 0x3FF93B134CF890E3, 4), (0x3FE7AC3E27D8D39E, 0x4021BD8FFCF05200, 9),
+	(0x3FF4728A25B70A5A, 0x3FFB5604DE3EBD2C, 16), (0x3FFE37AB86760CF8, 0x3FFE800FF803D8C0, 11),
+	(0x3FC42B933FF965CA, 0x3FF83BCCE96DD370, 31), (0x3FF40704A904FE67, 0x3FF83FA7D80261AA, 0),
+	(0x3FF4095775254156, 0x4023CE30BD29A797, 4), (0x3FFB25D4C9B61C7A, 0x3FF9B7E4042BD14A, 12),
+	(0x4011AEB28209EFD4, 0x3FFFE54805E46E54, 76), (0x3FFBD6DA7C1020FC, 0x4000B9D45F76AFA4, 73),
+	(0x3FE57ED49DE78810, 0x3FFDA43CA022214C, 28), (0x4000817550C2DB2A, 0x3FFAA366028AFA4E, 3),
+	(0x3FDFDE470AB1CD42, 0x4004CE6E19B4152E, 15), (0x3FEBF171E05FF734, 0x3FFD217C45B69DE2, 19),
+	(0x4007C3A815A563F0, 0x4000F304E188E95B, 1), (0x3FDAD38E751FDE24, 0x3FFAD605F2670ED1, 8),
+	(0x3FE601E8B09B2C34, 0x3FFDA2B9548F1FB4, 12), (0x3FFD237176C1656E, 0x3FFCFA85BCF843E6, 27),
+	(0x4005BD4ADF1167E2, 0x3FF9093548A521EA, 2), (0x3FFC2323BF8031B1, 0x40067D5B8D599AE8, 32),
+	(0x4000B9CA82332A3D, 0x3FFC64499BCE683F, 21), (0x3FE794DC0DAAD582, 0x3FFB4BFED28BA30E, 24),
+	(0x401023C49732F6E8, 0x3FFD3B31E32C92E2, 14), (0x4005711DC991684F, 0x4002C278411BA0A8, 26),
+	(0x3FFE89DC91ED4DEA, 0x4008128F45EB09D8, 39), (0x3FEC9FB57193934C, 0x3FFA42595BBF6AF2, 20),
+	(0x400E4092F503AC83, 0x4001648BF4483061, 17), (0x3FF58DBD3572746D, 0x3FFA6E0A2E70598A, 14),
+	(0x3FF4E396718FB1A8, 0x3FF9A4A9CBFBB516, 30), (0x3FF1CF661D190A22, 0x400185EC6ECCF95A, 10),
+	(0x40167A2989E0FAFA, 0x3FFA790F20BC7F6C, 2), (0x4004816B84EC6066, 0x3FFBE18724B96672, 6),
+	(0x3FE0AD257B755589, 0x40004EFF4B85625A, 16), (0x400145D637E5341B, 0x4007AF397ABBEA13, 16),
+	(0x402775A0E3E48E32, 0x400A04EC1B97EEDE, 24), (0x40095EC149554E96, 0x3FFA2E2CED95EEDE, 15),
+	(0x3FFF2D093830E014, 0x3FFAADE985FBEF37, 2), (0x3FDB9EE0A9570222, 0x4000F0E07A2BE2C6, 9),
+	(0x400776DBD054C94F, 0x40087AF4394AAA46, 18), (0x3FD4E23CF11BF47D, 0x4000D25F5CF39707, 16),
+	(0x3FDC473E5823944E, 0x3FFB40963A963CEB, 17), (0x4006CE89ABE4E7BE, 0x40009E3454D20ADA, 7),
+	(0x3FF2B8991DBE336E, 0x3FF8E35F4469D6EC, 16), (0x4000F666CCDE8221, 0x3FF8560ABB049F92, 1),
+	(0x3FF0EBAFE4D42A2F, 0x4000F05D08CBE54A, 10), (0x40046E87BEBAE44E, 0x3FFABC5A96F8D8B0, 5),
+	(0x4014E11FBAAB4B52, 0x3FFD08C3490D25C4, 10), (0x3FEE36016B9BDAFD, 0x3FFF95DA296CEE7E, 52),
+	(0x3FE52EDFCEA33C70, 0x3FF838DBED891D52, 20), (0x3FE9FE1ABC2351E8, 0x400BFBB94938BAC2, 11),
+	(0x400A3D9E7361D050, 0x3FFD6C780C58CBFD, 1), (0x4003C6FE5D6E3CE1, 0x3FFECF232764C5E0, 27),
+	(0x3FF5412197D59432, 0x400437E3255D60EA, 15), (0x3FF26C69D1008AD0, 0x40007EA456231C1A, 1),
+	(0x3FF1F93D525F82FC, 0x400890A1BD7DC59A, 3), (0x3FE2833F1C892E50, 0x3FFC8C7ED07BCB1E, 15),
+	(0x3FE2EC74D3553347, 0x40047C847E73492D, 5), (0x3FF4CD92D0D80B36, 0x3FFD2571AC9BA9A2, 32),
+	(0x3FEF3BCE4FE7EA1E, 0x3FFCFBF2D27E0B2C, 13), (0x3FFA9F719795D4B1, 0x3FFB0BBA3F696A0A, 5),
+	(0x400B2AD10DBB7816, 0x4003A8A59C8273EA, 4), (0x3FF7E4396934D512, 0x3FFE23E1C2957FD7, 11),
+	(0x3FF1681F5A4D9B43, 0x4016C4D6CC023BC1, 7), (0x3FE4C4D739B33105, 0x3FFAB7B135C2A80A, 0),
+	(0x3FE7BEA8A14CF166, 0x40103AA46356EE60, 53), (0x3FF02D4CA8FEF448, 0x3FFD39ED9CC872CA, 6),
+	(0x3FE5738DE31EDC56, 0x401DB744C216B726, 56), (0x4019BF94C569EB6B, 0x4000AADCD2B8A1BF, 28),
+	(0x3FE3D4020FA2D95B, 0x3FFE54E661DD6402, 2), (0x3FEACCA2A281C142, 0x3FFA99F75AF907BC, 32),
+	(0x3FEFE69647E0FD82, 0x3FFA14E00AB271F4, 10), (0x3FF3045E828181C2, 0x40032A159221CFC2, 3),
+	(0x3FE41B3BE7F1F585, 0x4003DB9F42B6BAA1, 0), (0x3FFEE883228A9214, 0x4002D8C264320D4A, 31),
+	(0x3FFCD3EFC59B1A39, 0x400AC66408BB5CEB, 14), (0x4007178F39D94C1C, 0x3FFDBF18229DCD05, 9),
+	(0x3FF26CD5DB2171C5, 0x40010A44DDC0DD34, 41), (0x3FE8AA35293582C2, 0x3FF8B347C6F812AB, 54),
+	(0x3FF0655626835BFF, 0x3FFD386C47CF02BB, 29), (0x3FE6178C0627DFCB, 0x4006C0BD43643CB5, 5),
+	(0x3FE11A41738D59F9, 0x40090D2E121919D3, 16), (0x3FF9130478DD4F7C, 0x3FF99953246C1700, 9),
+	(0x3FF1DDB5B8C44736, 0x3FFB471B2536DF65, 20), (0x3FEC3A0600F92B7F, 0x40032F807507F468, 11),
+];
+pub const EXT_SAMPLES_END: i64 = -4840059048085201788;
+pub const MC_LAYER_BOUNDS: [i32; 8] = [2, 3, 4, 6, 10, 100, 1048576, 2147483647];
+pub const MC_LAYER: [(i64, i64, i64, i64, [i32; 8]); 64] = [
+	(-6987061295172669018, 1, -639303, -887819, [1, 0, 3, 1, 8, 90, 237653, 549362751]),
+	(4644129158178693317, 10, 368541, -253506, [1, 2, 3, 5, 1, 1, 805092, 833309217]),
+	(1947216027679110614, 100, -998849, 420983, [1, 2, 2, 2, 2, 47, 571103, 584907766]),
+	(1232780447723426897, 200, 253373, -818212, [1, 2, 0, 0, 0, 53, 406312, 904069731]),
+	(3585450255006807596, 1000, -354252, 101791, [1, 2, 2, 2, 2, 16, 395768, 1105877724]),
+	(-94083897571808823, 2001, 533955, 547242, [1, 2, 0, 2, 9, 25, 315417, 1401776799]),
+	(-4068526733594441202, 2002, 265784, 544384, [0, 0, 0, 2, 0, 75, 27208, 1706394672]),
+	(-2762863485677808427, 4242, -668198, -594367, [1, 1, 2, 4, 6, 96, 73741, 788619638]),
+	(8706296424194212743, 1, -186157, 422860, [1, 0, 3, 2, 0, 55, 993810, 1499572766]),
+	(8908751462196726275, 10, -451913, 241279, [1, 0, 3, 3, 0, 6, 261417, 950458971]),
+	(-6150261758543683645, 100, -368459, -74598, [0, 0, 0, 5, 7, 28, 476615, 441244024]),
+	(-4494230494083287231, 200, 108505, -389823, [0, 0, 3, 4, 6, 36, 179752, 169937599]),
+	(172677106977523607, 1000, -982917, 17184, [0, 2, 3, 3, 5, 88, 770380, 1574956670]),
+	(8062696913694755941, 2001, -510648, 151081, [0, 0, 3, 3, 4, 70, 753805, 914764825]),
+	(100550485807292406, 2002, 178538, -131592, [0, 1, 0, 1, 0, 63, 627324, 308753404]),
+	(-2846607427662638171, 4242, 82519, 411992, [0, 2, 1, 1, 0, 25, 52810, 1227602138]),
+	(-2020964646090315139, 1, -383949, -472481, [1, 2, 0, 0, 8, 63, 653182, 1910969321]),
+	(8426591297166924319, 10, 233315, -963148, [0, 1, 0, 2, 4, 45, 657887, 1545968073]),
+	(7982452199529805288, 100, 91696, 343153, [1, 0, 1, 0, 2, 33, 200066, 1800857370]),
+	(-6750604910550325166, 200, 113299, 70947, [0, 2, 2, 2, 3, 25, 402115, 1071793175]),
+	(643848816390020762, 1000, -170077, -617490, [1, 1, 0, 0, 1, 74, 861095, 973840531]),
+	(6252422217816672931, 2001, -531944, 333646, [1, 0, 1, 3, 4, 24, 997019, 1497829080]),
+	(6611323797771460378, 2002, 315515, -777864, [0, 2, 2, 3, 0, 90, 174189, 1512829116]),
+	(-692377519984973350, 4242, 929132, -980001, [0, 1, 1, 2, 6, 21, 336476, 638490161]),
+	(-5379729408501092208, 1, -636273, -129326, [0, 2, 1, 5, 1, 69, 794840, 1245318045]),
+	(7259253039359898530, 10, 58644, -739390, [0, 2, 0, 5, 8, 88, 465675, 865759445]),
+	(-7662058691737945268, 100, 149602, -303198, [0, 2, 3, 0, 2, 81, 416473, 1667236361]),
+	(1123923221915947788, 200, -872352, -367129, [0, 1, 3, 3, 6, 2, 1000507, 248558772]),
+	(2398320394871068480, 1000, 570720, -545233, [0, 1, 3, 3, 8, 15, 7094, 1189125738]),
+	(-671450733546522356, 2001, -53976, -528898, [1, 2, 3, 5, 9, 93, 325439, 4246889]),
+	(-5750584200111252351, 2002, -297443, -41309, [0, 1, 1, 2, 3, 63, 670446, 1042256714]),
+	(-6669572639560649477, 4242, 554254, 308025, [0, 2, 0, 1, 5, 85, 676139, 495801966]),
+	(5944060312143381450, 1, -206182, -413495, [0, 2, 1, 3, 5, 63, 752682, 993220035]),
+	(-7267892778749698797, 10, 774208, -27379, [1, 0, 2, 5, 8, 92, 862666, 1382440174]),
+	(-1177347850538117384, 100, 228609, 605135, [0, 2, 2, 5, 5, 79, 373415, 798309117]),
+	(1499301397991393014, 200, 971828, -195600, [0, 1, 1, 1, 0, 61, 57583, 916136874]),
+	(-7438754846231645809, 1000, 87782, 563845, [1, 1, 0, 2, 4, 42, 532765, 1246204246]),
+	(-6638943032970588120, 2001, -539669, -854628, [1, 0, 1, 4, 3, 50, 790254, 1853934566]),
+	(-4548955791912201933, 2002, 555525, -147812, [1, 1, 1, 1, 1, 72, 410861, 699122109]),
+	(1687154854245906064, 4242, -997840, -906806, [0, 0, 0, 4, 2, 63, 48414, 992129615]),
+	(5937741871625352752, 1, 93448, -665496, [1, 1, 1, 4, 4, 80, 198715, 369145375]),
+	(397026318685716731, 10, -193192, -440840, [0, 0, 0, 1, 2, 85, 388575, 1885752764]),
+	(242427578738070516, 100, -662720, -629346, [0, 2, 3, 2, 5, 76, 887889, 554651943]),
+	(6411096878998435737, 200, -620158, 540707, [1, 1, 2, 3, 2, 92, 110001, 2074178522]),
+	(-2483021823120174705, 1000, 269161, -921194, [0, 0, 0, 4, 7, 44, 847269, 1275480537]),
+	(-4648969746666754946, 2001, -273578, -808051, [1, 2, 2, 4, 7, 60, 972665, 177201670]),
+	(-6115026125458646470, 2002, -205592, -77431, [0, 0, 1, 5, 1, 78, 758626, 1161961306]),
+	(363317248556241954, 4242, 84295, -898697, [1, 2, 3, 2, 6, 95, 731813, 1894258261]),
+	(-2630614547156058675, 1, -105586, -266285, [1, 1, 2, 4, 8, 7, 640328, 871997623]),
+	(4687800547573968354, 10, -954669, 680935, [1, 0, 1, 3, 5, 8, 642662, 196653294]),
+	(9137301234120155462, 100, 992211, 715569, [0, 2, 1, 3, 8, 11, 255248, 814666496]),
+	(4078562261427846269, 200, 870847, -340893, [1, 1, 1, 5, 2, 3, 245827, 143176564]),
+	(3099098468141399881, 1000, 629149, 196484, [1, 1, 1, 4, 4, 97, 482561, 508670078]),
+	(-9176436895121174889, 2001, -731043, 729268, [1, 1, 0, 3, 3, 97, 397728, 1997777571]),
+	(-631792276331202405, 2002, -513287, -30836, [0, 2, 3, 4, 4, 21, 448533, 386547562]),
+	(-5021760078285187271, 4242, -458937, -93404, [1, 0, 3, 5, 6, 81, 67864, 1273975974]),
+	(4503784584969745574, 1, 178014, 653742, [1, 1, 3, 1, 3, 95, 964058, 717637809]),
+	(-5020256877542517169, 10, -956637, -507969, [0, 2, 0, 2, 1, 24, 388680, 1811415504]),
+	(-8819908535559056903, 100, -418705, 679214, [1, 2, 0, 3, 6, 2, 348729, 86474523]),
+	(-8324358871124520663, 200, 894942, 124181, [0, 0, 3, 0, 6, 69, 488890, 1670713284]),
+	(-5531905211320654139, 1000, 676023, 104949, [1, 2, 1, 4, 5, 63, 98683, 1491749142]),
+	(756072549561932538, 2001, -913933, -644490, [1, 2, 1, 5, 5, 96, 799853, 68496925]),
+	(-4255860543400504992, 2002, 20460, -281653, [1, 0, 3, 1, 3, 17, 1039419, 2078552930]),
+	(3295895132371643740, 4242, 250787, 670644, [0, 2, 0, 2, 2, 70, 540767, 2037359405]),
+];
+pub const MC_HASHED_SEEDS: [(i64, i64); 32] = [
+	(0, 8794265229978523055),
+	(-1, 6759447113877070610),
+	(108181935, 6121593118902127521),
+	(-5093314699923970155, -1059142633439210464),
+	(-6741478352998127091, 2405896639127170983),
+	(-5701635314880880513, 3928938523603503783),
+	(6466691700437485671, 2112534157865730867),
+	(-2874288295170239306, -58497581480258035),
+	(9079902422519813827, -933661927500620586),
+	(1621902614980814420, -5475795085275209361),
+	(7963942288749382539, -3542244413424483537),
+	(4651564287966974334, -4392931847633739565),
+	(-7156354709577316686, -569843057089865917),
+	(-1284581258344032024, -8846924887559270456),
+	(7377728523739216849, 4509915125318891805),
+	(-3770941741490293091, -763383339949488277),
+	(3682562031907887762, 5422775512422133058),
+	(-8933772786413267712, 642850149546110624),
+	(-6597292313930311343, -2810968100329768363),
+	(-345866700016643059, -3892337759946287855),
+	(-6980489078338124289, 9158194315743184255),
+	(-3027900472235647260, -281142314280267184),
+	(9018496699261452618, -513356410831342377),
+	(-6926500939938605688, 4083695014038740299),
+	(-7215763573600647174, -9106634670189117054),
+	(3507827672496901859, 5687144122003230956),
+	(2616064401268633246, -4564637681567644096),
+	(472266145775613709, -7437986913073376170),
+	(-3941210078815014041, 574244507300019836),
+	(-479964058429239859, 7651354597143842432),
+	(-2366726685629265325, 3886943514126580527),
+	(2649188415072012639, -2846341262519237737),
+];
+pub const SPLITMIX_DERIVED_SEED: i64 = 1717;
+// (nextInt bound, value, nextLong bound, value, nextGaussian bits, nextExponential bits)
+pub const SPLITMIX_DERIVED: [(i32, i32, i64, i64, u64, u64); 64] = [
+	(1, 0, 1, 0, 0xBFECFEA9C454C392, 0x3FE1377181D50C2D),
+	(2, 0, 1, 0, 0x3FBEF610FE893410, 0x400203663F1D617E),
+	(7, 5, 1, 0, 0xBFA79D39C7EFA80D, 0x3FD429ECCED206B3),
+	(64, 13, 1, 0, 0x3FD9C520A05490E7, 0x3FE26F859F77B0CC),
+	(100, 5, 1, 0, 0xBFE3D2E65FBD8EDD, 0x3FF2ECF51D3B21F1),
+	(1073741824, 910184411, 1, 0, 0x3FEA618F853B9B8A, 0x3FE3E2ABC9A0E286),
+	(1073741825, 844021703, 1, 0, 0xBFDFD4B5F5A5072F, 0x3FBF904F0639F3B3),
+	(2147483647, 1923253008, 1, 0, 0x3F8C84DDE2645EE4, 0x3FE980C50AF0FC17),
+	(1, 0, 2, 1, 0x3FC819417DCED27C, 0x4000C140FDE65448),
+	(2, 0, 2, 1, 0xBFB44B22FA757D3D, 0x3FB771893B2DF112),
+	(7, 4, 2, 1, 0xBFD8A57B45692A51, 0x3FF5ED557E72AAAC),
+	(64, 54, 2, 0, 0xBFE86B6EEF70D1F3, 0x3FE6D12DD5E90ADD),
+	(100, 72, 2, 0, 0xBFB06CD56F81938D, 0x3FF45AB6CCB63F33),
+	(1073741824, 973483513, 2, 1, 0xBFE119E15263E698, 0x3FE209CF9FA5DAEF),
+	(1073741825, 400669975, 2, 0, 0xBFF4A5F990721213, 0x3FF742A4B2D096CB),
+	(2147483647, 2129882676, 2, 1, 0x3FFA4DD0A39D784E, 0x3FB5804F56766469),
+	(1, 0, 1000, 457, 0x3FD2C1526012B4FF, 0x3FD9CD81635DE70D),
+	(2, 0, 1000, 339, 0x3FE894D563071504, 0x4002790981161EF8),
+	(7, 0, 1000, 146, 0xBFF1912520A37A0F, 0x40048C68240F70B4),
+	(64, 18, 1000, 599, 0xBFDF02046112D239, 0x3FD92AF28F1BFA38),
+	(100, 12, 1000, 959, 0xBFC45A70E83D4B1A, 0x4005D24F78AFFAFE),
+	(1073741824, 643571180, 1000, 468, 0x3FF322E8ED5A5D95, 0x3FC91D1A4576C367),
+	(1073741825, 189072353, 1000, 716, 0xBFF98296E4ADC81B, 0x3FE308DCC715C187),
+	(2147483647, 1058545861, 1000, 130, 0xBFEE1435D4266283, 0x3FA9D3EE1BB5C3DC),
+	(1, 0, 1099511627776, 705657852370, 0xBFE6EE030F4E72B5, 0x3F54DFC7D5D84216),
+	(2, 0, 1099511627776, 761519352951, 0xBFF312CF102C8CB5, 0x3FD10017264F3789),
+	(7, 1, 1099511627776, 434023609985, 0x3FE896151875B32E, 0x3FBD43ADC25CE92E),
+	(64, 3, 1099511627776, 151773086631, 0x3FE5DC55AA5F4422, 0x3FB278E532F26533),
+	(100, 63, 1099511627776, 230280455864, 0x3FD97E246957667D, 0x3FF11873834FA8D3),
+	(1073741824, 253121656, 1099511627776, 246393714641, 0xBFF748056E1BA792, 0x3FDE75994BCE497B),
+	(1073741825, 1004655177, 1099511627776, 129407953749, 0x3FB96465B1A6E1C4, 0x3FB83DEBC4F08479),
+	(2147483647, 1607102727, 1099511627776, 885521679447, 0x3FE3AD2C3764191A, 0x3FC0CF6BA70C8DC2),
+	(1, 0, 4611686018427387905, 2406668965145729521, 0x3FCC243293E3F5A7, 0x3FCF532C27516E42),
+	(2, 0, 4611686018427387905, 1884721540199709757, 0x3FFD65E380548923, 0x3F4A696E6D3BF0A9),
+	(7, 4, 4611686018427387905, 538789582659891373, 0xC00A15BE13F802FE, 0x40020B7A3104E008),
+	(64, 40, 4611686018427387905, 2766588780772629007, 0x3FE8BC2BA0D9A46A, 0x3FE8422AE94E5A13),
+	(100, 34, 4611686018427387905, 3815991650874992027, 0xBFFA143EE6550E0E, 0x3FB9D10BFC8C17D6),
+	(1073741824, 820178378, 4611686018427387905, 1783008608695541980, 0x3FFC9BDF29BC5EDB, 0x3FFC579942512662),
+	(1073741825, 211833303, 4611686018427387905, 4038261665040753148, 0x3FFEDF85EB053023, 0x3FDDCB2A62AE2043),
+	(2147483647, 1142413602, 4611686018427387905, 3071956900198826625, 0xBFF6FD952E21282E, 0x40011DB9D2FA9A82),
+	(1, 0, 3000000000000000000, 1962208501336968724, 0xBFD7C970B1FD1F85, 0x3FF2ED8B19B9D9EA),
+	(2, 1, 3000000000000000000, 718542597547063713, 0xBFE4E202D72A3D56, 0x3FE986AEED641E32),
+	(7, 4, 3000000000000000000, 2686738535759316567, 0x3FDD576FB0BA75C2, 0x3F7847DCD77372D3),
+	(64, 5, 3000000000000000000, 2640673169356760645, 0x3FEE7825BB705F1C, 0x3FE01672B96B5092),
+	(100, 60, 3000000000000000000, 2816206384293131995, 0x3FE1210A73129324, 0x3FFCDE59BFE72BCC),
+	(1073741824, 288427940, 3000000000000000000, 268837209116524145, 0xBFEFF8693E200E68, 0x3FF199A79188C420),
+	(1073741825, 205647091, 3000000000000000000, 140788981163655449, 0xBF71E4BE71E9646C, 0x3FFBBD0525A84292),
+	(2147483647, 549917363, 3000000000000000000, 43704935019412166, 0x3FD53C49D2F77402, 0x3FC1049C775FD4EE),
+	(1, 0, 9223372036854775807, 5141034442572609814, 0x3FFD123350E79A46, 0x3FE79E9A5E0BEAEE),
+	(2, 0, 9223372036854775807, 5779050715484906025, 0xBFF78FCB4208C2A5, 0x4015DD112D867BBE),
+	(7, 6, 9223372036854775807, 3563338739339947574, 0xBFCE2F83763037FE, 0x3FB8EE1A5B1727D5),
+	(64, 48, 9223372036854775807, 4908798919190422830, 0xBFE1CDD51EF2A2DF, 0x3FFEFC8ECEF9D138),
+	(100, 40, 9223372036854775807, 1527858749894272702, 0xBFFA7F6CF43CA456, 0x3FE817009C8C0E95),
+	(1073741824, 935267133, 9223372036854775807, 1915108333485014875, 0x3FE7CEB491BF6F1D, 0x3FF496054AC232C9),
+	(1073741825, 420858210, 9223372036854775807, 671054461430827861, 0xBFE70C383628CEC9, 0x3FF36FB2452B0374),
+	(2147483647, 1651958147, 9223372036854775807, 5130353549946529651, 0xBFD5CB3D632603C1, 0x401505572A428D7B),
+	(1, 0, 6, 4, 0xBFE2BA7DDE3CDB9C, 0x40012A61836710B1),
+	(2, 1, 6, 5, 0xBFF2F20DB7C85C40, 0x3FD2752BB2E6B4C9),
+	(7, 4, 6, 0, 0xBFC7F538D64DAC56, 0x3FD3DA832D29A402),
+	(64, 17, 6, 1, 0xBFC2A520C4AEECB9, 0x3FD73513D8884C38),
+	(100, 79, 6, 5, 0xBFE1026573173744, 0x3F9D46913238BD84),
+	(1073741824, 1007279501, 6, 5, 0x3FD17DAE10ABD114, 0x3FC33AF9F019F4C8),
+	(1073741825, 98007321, 6, 3, 0x3FB3508A23BACF7B, 0x3FE4CA05F3510EDB),
+	(2147483647, 710714797, 6, 1, 0x3FEF06C086E4306E, 0x4000250A8EBA208B),
+];
+pub const SPLITMIX_SPLITS_SEED: i64 = 1818;
+// (nextLong, nextInt) of the generator at each index of the split streams
+pub const SPLITMIX_SPLITS: [(i64, i32); 20] = [
+	(6819865605246698183, -114110485),
+	(8106380855873625001, -1885404137),
+	(-5512942871344155996, -781566816),
+	(4720679967056807857, -445880749),
+	(8875638109480091804, 2130587263),
+	(4151732108173531068, 1589813524),
+	(-1194500822579607708, -410091931),
+	(4783558840059122284, 1590656837),
+	(5012396348946019872, 1751678567),
+	(-7108976327010695300, -1103234033),
+	(-4932924941310803456, 1067477012),
+	(2592190147912634807, 1621987034),
+	(-2710610811881692499, -2018163145),
+	(-2408060375855740710, -1268913420),
+	(-4237077240337405338, 985363070),
+	(3043089100362278084, -906954143),
+	(-3814901325234379157, -542179330),
+	(4553232690291302707, -927626685),
+	(-4536065180581141846, -280830406),
+	(6736266061905654146, -464277042),
+];
+pub const FASTMATH_EXP_LOG: [(u64, u64, u64); 96] = [
+	(0x0000000000000000, 0x3FF0000000000000, 0xFFF0000000000000),
+	(0x8000000000000000, 0x3FF0000000000000, 0xFFF0000000000000),
+	(0x3FF0000000000000, 0x4005BF0A8B145769, 0x0000000000000000),
+	(0xBFF0000000000000, 0x3FD78B56362CEF38, 0x7FF8000000000000),
+	(0x3FE0000000000000, 0x3FFA61298E1E069C, 0xBFE62E42FEFA39EF),
+	(0x4000000000000000, 0x401D8E64B8D4DDAE, 0x3FE62E42FEFA39EF),
+	(0x3FEFD70A3D70A3D7, 0x4005A34683F3EC52, 0xBF748807F33B3513),
+	(0x3FF0147AE147AE14, 0x4005DAF23343EF6C, 0x3F746DD0FAD671FA),
+	(0xC0874C0000000000, 0x0000000000000000, 0x7FF8000000000000),
+	(0xC0862C0000000000, 0x00054E90C99FB878, 0x7FF8000000000000),
+	(0x40862C0000000000, 0x7FE81E9B4B52D0C9, 0x401A421C25960B33),
+	(0x4086300000000000, 0x7FF0000000000000, 0x401A42D4D1FF8154),
+	(0xC089000000000000, 0x0000000000000000, 0x7FF8000000000000),
+	(0x000012688B70E62B, 0x3FF0000000000000, 0xC0864E69394D9508),
+	(0x0000000000000001, 0x3FF0000000000000, 0xC0874385446D71C3),
+	(0x7FEFFFFFFFFFFFFF, 0x7FF0000000000000, 0x40862E42FEFA39EF),
+	(0x7FF0000000000000, 0x7FF0000000000000, 0x7FF0000000000000),
+	(0xFFF0000000000000, 0x0000000000000000, 0x7FF8000000000000),
+	(0x7FF8000000000000, 0x7FF8000000000000, 0x7FF8000000000000),
+	(0x4D95BC824CB7C19E, 0x7FF0000000000000, 0x4062ED331003A56A),
+	(0x40716DB07F1218D2, 0x5913BF48F7BE7B24, 0x401685D4A9294284),
+	(0x41D95D5E86D10844, 0x7FF0000000000000, 0x40354153DFCD1856),
+	(0xC084CB8FE4C03426, 0x03EF3DFEB5564859, 0x7FF8000000000000),
+	(0x148C5215E8696D81, 0x3FF0000000000000, 0xC07E12A91DB71ADB),
+	(0x4081B1728C55EC43, 0x72FC5F5D8E42FEF7, 0x40195B0C3108039C),
+	(0x43BC2B617C339283, 0x7FF0000000000000, 0x404513C60371310C),
+	(0xC070A2D6C5719589, 0x27EFB746B536FC0A, 0x7FF8000000000000),
+	(0x107E5A541B3D1330, 0x3FF0000000000000, 0xC080713662D13C2F),
+	(0xC0797A37CD249032, 0x1B2DE4D71FC811BF, 0x7FF8000000000000),
+	(0x6ABCD7E0D67C82C7, 0x7FF0000000000000, 0x407DAB3B3A0495B3),
+	(0x4080C9A61AC732D0, 0x70604665B8707E0C, 0x4019254147506FF4),
+	(0x27B0B77C75A8DE18, 0x3FF0000000000000, 0xC070CE5B4676B314),
+	(0x4053CDAF9468514B, 0x4713723A285A368C, 0x40117D152A3AC4C5),
+	(0x79AD5D65EDE1980D, 0x7FF0000000000000, 0x4084030E73D3713F),
+	(0xC084BB8C7554D29A, 0x041CE79B069D8A18, 0x7FF8000000000000),
+	(0x479715BE23ADB489, 0x7FF0000000000000, 0x40553B8E16D99DC1),
+	(0xC052F4C59B654E1E, 0x3918641E0191059C, 0x7FF8000000000000),
+	(0x5C3C5BC3615020CA, 0x7FF0000000000000, 0x40739DFF6F3B0492),
+	(0xC0844E503B438BEB, 0x055776CA9414FC2D, 0x7FF8000000000000),
+	(0x3DD893258428DB34, 0x3FF00000000624C9, 0xC037234B8F268AC7),
+	(0xC04F48AACC8D022F, 0x3A4A9C0AC867E943, 0x7FF8000000000000),
+	(0x44D498E97B15F60A, 0x7FF0000000000000, 0x404B28B5ED558BC0),
+	(0x407C1FA4718AF9B4, 0x688221F75FF68F1E, 0x40186FD1939F2EFC),
+	(0x27684359A07880C1, 0x3FF0000000000000, 0xC070FFD8FBDC20B9),
+	(0xC058FE337485F1EB, 0x36EB4DDDE408D8B8, 0x7FF8000000000000),
+	(0x6ECE946F3E9515D0, 0x7FF0000000000000, 0x40803E85196E4C4B),
+	(0xC019E2958FDC0791, 0x3F5959A71F403B40, 0x7FF8000000000000),
+	(0x5A0C8587FB4B61E6, 0x7FF0000000000000, 0x407219ED5A56D9D0),
+	(0xC080D410A83B5549, 0x0F611CD2E984D0F1, 0x7FF8000000000000),
+	(0x7D266B9EEEB18546, 0x7FF0000000000000, 0x4085376D82BE84E7),
+	(0x406388DB5006D53A, 0x4E0601238FB1B2E0, 0x401434DE217ECC59),
+	(0x07DDC7DE6452F620, 0x3FF0000000000000, 0xC0836E996297FF92),
+	(0x405D6C441C00BE89, 0x4A8BB9DE2D48F29F, 0x401312807397D404),
+	(0x6D80E992FBB0B1D8, 0x7FF0000000000000, 0x407F95C18C49B46E),
+	(0x40766AB4A3583374, 0x6045DBD18B5B5409, 0x40178793EDA9C9AF),
+	(0x16C05C796E3B83F3, 0x3FF0000000000000, 0xC07C8C2FC9084EBF),
+	(0xC080AEACE8BAAFBA, 0x0FCCA29C575A77F6, 0x7FF8000000000000),
+	(0x627D580263636313, 0x7FF0000000000000, 0x4077F3946BF423C7),
+	(0xC086C7847FAA611F, 0x000000000052456A, 0x7FF8000000000000),
+	(0x21E4EBB3D720A281, 0x3FF0000000000000, 0xC074D22B97D7D3F2),
+	(0x405FC63DB4C8295B, 0x4B6493B232AA96CF, 0x4013613BC9C5A211),
+	(0x6970B389753CECC6, 0x7FF0000000000000, 0x407CC4AE99559785),
+	(0xC06F622B1FD42020, 0x294B959C72D9736C, 0x7FF8000000000000),
+	(0x0AF9C59ED2F1E8EA, 0x3FF0000000000000, 0xC0825A7F34085860),
+	(0x4061ECCA944A56DB, 0x4CDD7D0AA1E1C4D1, 0x4013DCCFB187C288),
+	(0x06B3CB62145E2DAC, 0x3FF0000000000000, 0xC083D5AE16719950),
+	(0xC0828E5438734DD4, 0x0A64424DDC34747F, 0x7FF8000000000000),
+	(0x66C9C400BAE944B0, 0x7FF0000000000000, 0x407AEEBBCF6337DE),
+	(0xC084D7C14750E9AB, 0x03CB384D19C37065, 0x7FF8000000000000),
+	(0x20A0C2DD1A9F2FDB, 0x3FF0000000000000, 0xC075B3862FACB625),
+	(0xC05F8EA97D98CC31, 0x348DA623DAD3E080, 0x7FF8000000000000),
+	(0x234976E7A6E17357, 0x3FF0000000000000, 0xC073DB09AB058919),
+	(0xC080DA3BBF73B8EA, 0x0F4FA913FAC3D4D5, 0x7FF8000000000000),
+	(0x0ED3E48D0279C1C2, 0x3FF0000000000000, 0xC08104C46F962D02),
+	(0x4083300763EC5047, 0x774C3EE88254B7B2, 0x4019AE14B0EB83BC),
+	(0x3430288E0B39427B, 0x3FF0000000000000, 0xC06049A87D4809D3),
+	(0x4086A5941A3943CC, 0x7FF0000000000000, 0x401A57CFEA5B319E),
+	(0x13960E16110E6DAB, 0x3FF0000000000000, 0xC07EBD044952A080),
+	(0xC03DAE7E51E6B8F5, 0x3D421B68F8DBFD94, 0x7FF8000000000000),
+	(0x696D57E48ADF1D04, 0x7FF0000000000000, 0x407CC29BE1F54927),
+	(0xC08239B04D006990, 0x0B5852BCD93709CA, 0x7FF8000000000000),
+	(0x3CA107DFE7962D77, 0x3FF0000000000001, 0xC0425651B21AF364),
+	(0xC0533BA8E7E07AEE, 0x39001D67328D31C6, 0x7FF8000000000000),
+	(0x3ADE5632EE1B23A8, 0x3FF0000000000000, 0xC04C1961DB475DC4),
+	(0x406A6ED9EA67C848, 0x5300E42D540A8AB7, 0x40156A8D758DC24C),
+	(0x1F24F9C144ECEC65, 0x3FF0000000000000, 0xC076BA1A9E128C83),
+	(0xC085DEF4FF50D8DB, 0x00D3B8384017E712, 0x7FF8000000000000),
+	(0x76ABC67F32D69F3B, 0x7FF0000000000000, 0x4082F8715850604A),
+	(0xC083A8D5C5C9C522, 0x07350720DA2788CC, 0x7FF8000000000000),
+	(0x4A61C3BB444C3FBA, 0x7FF0000000000000, 0x405CF70D76E6CD67),
+	(0x407C3ABAC604F979, 0x68A8A3DA561D3B70, 0x401873A9FDA4241B),
+	(0x2DD13E4A0D0A8D8E, 0x3FF0000000000000, 0xC0691E02D67A5D59),
+	(0x406B620999DA769C, 0x53B07935D1AA2F1F, 0x40158EB5062D9BDF),
+	(0x6F96C26DEC5802D6, 0x7FF0000000000000, 0x4080843E8E681D22),
+	(0xC0763D01E6843052, 0x1FD976D92966ED51, 0x7FF8000000000000),
+	(0x55783FFAE2733E59, 0x7FF0000000000000, 0x406DDB78762D7952),
+];
+pub const FASTMATH_POW: [(u64, u64, u64); 96] = [
+	(0x4000000000000000, 0x3FE0000000000000, 0x3FF6A09E667F3BCD),
+	(0x4000000000000000, 0x4024000000000000, 0x4090000000000000),
+	(0xC000000000000000, 0x4008000000000000, 0xC020000000000000),
+	(0xC000000000000000, 0x3FE0000000000000, 0x7FF8000000000000),
+	(0x0000000000000000, 0xBFE0000000000000, 0x7FF0000000000000),
+	(0x8000000000000000, 0x4008000000000000, 0x8000000000000000),
+	(0x3FF0000000000000, 0x7FF0000000000000, 0x7FF8000000000000),
+	(0x3FE0000000000000, 0xFFF0000000000000, 0x7FF0000000000000),
+	(0x4008000000000000, 0x7E37E43C8800759C, 0x7FF0000000000000),
+	(0x7FF0000000000000, 0xBFE0000000000000, 0x0000000000000000),
+	(0x7FF8000000000000, 0x0000000000000000, 0x3FF0000000000000),
+	(0x4000000000000000, 0x7FF8000000000000, 0x7FF8000000000000),
+	(0x4024000000000000, 0xC079000000000000, 0x0000000000000000),
+	(0x01A56E1FC2F8F359, 0x3FF8000000000000, 0x0000000000000000),
+	(0x3FF897E30BCA518C, 0x4028114600A6A0E3, 0x40660EED8EEA9E93),
+	(0x401E0EB00B4917BE, 0xC008000000000000, 0x3F634E9D75866A70),
+	(0x401EACBEF1BC8177, 0x402B606F3B7577E1, 0x4272C346F3080A0A),
+	(0x4016768EC03C5A0F, 0xBFDF7C0B9418DF90, 0x3FDB62869F5720FF),
+	(0x400D11842E963D5A, 0xC020000000000000, 0x3F01414FA395B2BD),
+	(0x4012D12A9F6D5604, 0xC02A700E4B06B812, 0x3E16275AD5ED51D9),
+	(0x40025D433AC652C8, 0x4037A8063D458710, 0x41B488E6A06599C0),
+	(0x401CDFBD4A77DE5D, 0x402A000000000000, 0x4240D1D509896A7B),
+	(0x40199145A0CF19E1, 0xC0363FC7B390DD39, 0x3C35F2AECA376ACA),
+	(0x401DAD67576012F4, 0xC0183A1156B5596C, 0x3ED671D48E47C4A7),
+	(0x3FE31AAC22A44CB8, 0xC030000000000000, 0x40AE003076710EF7),
+	(0x3FFEF5428C8A3400, 0x403A3FE35740D29C, 0x417FE9098104CFE8),
+	(0x3FF687BE0BA33234, 0xC02ABB4DBA55D97E, 0x3F851CFBE44DA46F),
+	(0x4010A0D66C4BAD7D, 0xC008000000000000, 0x3F8C82142836ADB9),
+	(0x40136C4FF5534745, 0x4036F63C5DDE7296, 0x433456C8AEC2E78E),
+	(0x4014E310AA6A1982, 0xC01485F82C6665FC, 0x3F2B326E256E082D),
+	(0x401B03D8E4F19C85, 0x4008000000000000, 0x407340F8A6BF19C4),
+	(0x4003790AC3EDD834, 0xC02C733A451B608C, 0x3ECACAA60301F294),
+	(0x4010AF3213B99EBF, 0x401CD2EA3205BEBE, 0x40DCC989C4B80745),
+	(0x3FFD1096716B2DB8, 0xC034000000000000, 0x3EDB65CFA6C73A87),
+	(0x4002C711CC1D5DFC, 0xC0110B5579B1D838, 0x3F9AFFED633FF679),
+	(0x3FDC89D2E8E4CE40, 0xC03D6A2F8B1EBCFA, 0x421355ED480804EF),
+	(0x3FEAA00C820547B0, 0xC014000000000000, 0x40040FE54D02E176),
+	(0x401082D45316FCC6, 0xC039F208214C7783, 0x3C9E881FA1CABFB0),
+	(0x4010494BFAEE5AB9, 0xBFE11364CF8937C8, 0x3FDE415434791A3A),
+	(0x40036B002A09A49E, 0x4018000000000000, 0x40698FD8FF5F3FE3),
+	(0x4016F32059E99E0C, 0x4030C1E7338602CC, 0x4292D64DB7E9611A),
+	(0x3FF445A2D8C722B8, 0x40148B4547ACADF1, 0x400AF977AA7D7733),
+	(0x4018E63CEF0CA00F, 0xBFF0000000000000, 0x3FC49011CB286923),
+	(0x3FDF6EE9E517F6A0, 0xC034650C171B5E22, 0x413E48C3511441A6),
+	(0x3FADBB6C5F1BB700, 0xC030D9B5D52E693A, 0x44423C5DE16D76C7),
+	(0x40018005ED89AE5C, 0x401C000000000000, 0x406DF61E8F7C3A3D),
+	(0x4007F2BDCF098162, 0xC034F836D404D8C6, 0x3DDC6E1A133E574D),
+	(0x401FC31CB91AADAB, 0x4022D2F07F515B45, 0x41B190C3F334030F),
+	(0x401955129F200F1D, 0x0000000000000000, 0x3FF0000000000000),
+	(0x401294CD2A9CA87C, 0xC029E836754FA6C5, 0x3E23AB464E6E9CD8),
+	(0x4017B6EC2EB78F1B, 0xC001A984965390AF, 0x3F9420A9D0600B1F),
+	(0x401F717A9FB90FE8, 0x4024000000000000, 0x41CAD958A5B61B88),
+	(0x4012C0618D6243D7, 0x3FE2AE4215EC85C0, 0x4003B6EC2DA2FEE1),
+	(0x400B7AA7FBBE001C, 0x403352FEE93776C5, 0x421524E5A43BCBA2),
+	(0x4002B8A8E690DFE2, 0x4018000000000000, 0x406487B10317DB14),
+	(0x401C106D3B64DA75, 0xC035CCD7F70E6DBF, 0x3C1A7C92D1C6E6EA),
+	(0x3FFC4F8BF0918C94, 0xC03D5160C126290F, 0x3E6D1B39404291DC),
+	(0x4016A5915EB1ECD2, 0xC02E000000000000, 0x3D9656E320BE932C),
+	(0x401E6535463C0DBB, 0xC02B3DBAC2097D5C, 0x3D71BE69A6EC1C78),
+	(0x3FD8D696180AD690, 0xC01623401F559E4E, 0x40678B5B77C9E194),
+	(0x400CC1A4932C4E54, 0x4024000000000000, 0x4115FAE2467D5A1C),
+	(0x401AAF5C6E5266D5, 0x402D4A63D67C7E94, 0x42712078A9D97CFB),
+	(0x4002808111F8A2B6, 0x403A102015BED0A9, 0x41E709D94E5127A4),
+	(0x3FBE8C18F1C3B940, 0xC018000000000000, 0x411524DB9CFC8F5A),
+	(0x4003FA4F392DA060, 0x3FF7C3103E5261AA, 0x400F24A5965EB111),
+	(0x401181958E311546, 0x3FFC7638ADE2CFD4, 0x402BA37730ABB833),
+	(0x401EDDE17013A09F, 0xC01C000000000000, 0x3EA49802CF392079),
+	(0x4011E01BAC3968D5, 0xC02DCB19513E02C2, 0x3DEC561F83C61584),
+	(0x4010F82EEB97FBC3, 0x4029052941468CAC, 0x4190EF328985617B),
+	(0x3FE13BDD702FAE58, 0x4008000000000000, 0x3FC3FE8C2FB29BC6),
+	(0x3FFBCE186D8F2D3C, 0x4038CBBB3D1BDB1F, 0x412B447510101019),
+	(0x400E8E69B8E204EA, 0x40069A195CA9B499, 0x40460B49ED272189),
+	(0x3FF4636A8F99576C, 0x4031000000000000, 0x404ECA7ED2F07D34),
+	(0x40106487E76A5A53, 0x403A3135273DCC79, 0x4343B522888C9544),
+	(0x400A2A04399E79F2, 0x40245444835F1B60, 0x4104C58A88C48C0C),
+	(0x400195004065EE6A, 0xC028000000000000, 0x3F14A4D15D8DC1F4),
+	(0x401DC78A14D19A45, 0x4005B3CC958CF93F, 0x406CFA80F6ECD087),
+	(0x3FE69B33D39DBB38, 0xC030C0B2B34443AE, 0x407518DBA2AA0628),
+	(0x4014705E79FEEA9A, 0x4033000000000000, 0x42BA3442D46838F0),
+	(0x3FD08FD177747120, 0x403615D975FC589D, 0x3D3E75D58EAA1C36),
+	(0x4012D496395A011D, 0xC032B53213CA380C, 0x3D523AC3E00981B5),
+	(0x401A01CCA63A4AEE, 0x4028000000000000, 0x41F54221D54941A9),
+	(0x3FEBAD4E75DF7920, 0xC026FEED8A4BFC04, 0x401538DA5B6993D6),
+	(0x3FF7406DE7A12074, 0xC023B819DE295ACB, 0x3F99B07987E2B2A9),
+	(0x4000539D394EB3D0, 0x3FF0000000000000, 0x4000539D394EB3D0),
+	(0x4010C38842EEC8CE, 0xC02182F323E616A7, 0x3ECDD84EB1EE543C),
+	(0x4018CEF50231D295, 0x401E0AD2636A8200, 0x412B59CD9250D2BE),
+	(0x401A8AC384D8118C, 0xC024000000000000, 0x3E39F46674E4BB3E),
+	(0x401768BC36AD1D6F, 0xC0344B04C476083B, 0x3CB3557923F093E9),
+	(0x3FFAF027A9D918C8, 0xC033D3B7E4F4A06E, 0x3F0121585B2BE5A4),
+	(0x400A61E8181044A4, 0xC031000000000000, 0x3E1A9E7C85EE037C),
+	(0x401B849D5F8E47A9, 0xC0219F22C01963DC, 0x3E66673817ABDE6F),
+	(0x401BFDA653AB2B1F, 0x403D7F003D9850C8, 0x451BB467A47638B8),
+	(0x401770203756CF02, 0xC018000000000000, 0x3EF9E880CCB0F14E),
+	(0x3FD2877ACAF8F2F0, 0xC0375F626F37FFA2, 0x428BCA52A3AB7033),
+	(0x400FCA305EA2755C, 0x4028657217D587EA, 0x41736E5A3DDCA96A),
+];
+pub const FASTMATH_POW_INT: [(u64, i32, u64); 96] = [
+	(0x3FF000001AD7F29B, 0, 0x3FF0000000000000),
+	(0xBFE0000000000000, 1, 0xBFE0000000000000),
+	(0x3FF000001AD7F29B, -1, 0x3FEFFFFFCA501B24),
+	(0xBFE0000000000000, 1074, 0x0000000000000001),
+	(0x3FF000001AD7F29B, -1074, 0x3FEFFF1EC729C2C1),
+	(0xBFE0000000000000, -2147483648, 0x7FF0000000000000),
+	(0x3FF000001AD7F29B, 2147483647, 0x534C2D024509ADCF),
+	(0xC0073BE873A1D73E, -40, 0x3C16368A058FE64A),
+	(0xC00595F8752A2768, 31, 0xC2B50038A5E0ECD3),
+	(0xBFCC90B0516D3A58, -16, 0x42189B7E5C651B51),
+	(0xBFF6C530BA0C59FD, 3, 0xC0070F07144BCE8B),
+	(0x3FF599E620DB45DD, 29, 0x40B78E98447E0D2F),
+	(0xBFF984A3908F4478, -24, 0x3EEC95CE77964639),
+	(0xC0065C02C0644312, 8, 0x40AD173715444D72),
+	(0xBFE72D929EC69CA2, -16, 0x4065C813AB9CEE5C),
+	(0x40070CD22B098270, -2, 0x3FBED652FFA195D5),
+	(0x4002661C22A89EA0, -32, 0x3D876F38DE758B06),
+	(0x4000734F78DDD12C, -33, 0x3DC99A5633DE34C8),
+	(0x3FB2A2B38D6BB780, 28, 0x3951DF22A4CBCCA0),
+	(0x3FF334C2C749DA61, 40, 0x4097423A6DA24637),
+	(0x3FF8066506228DE1, 9, 0x40436709202F8C25),
+	(0x3FF5E9D589B633ED, -21, 0x3F562EF452AD918D),
+	(0x3FF1A28EEE9CF2C0, 16, 0x4012F9309ED44B9E),
+	(0x4006B2B80C64A8F6, 12, 0x41109C409ACB71BE),
+	(0x4007C7F744326E42, 35, 0x43601F852C88F6A9),
+	(0x3FD2AA26D287FBFC, 15, 0x3E44296CFC28AE5C),
+	(0x3FF2D811FD96FA6E, 27, 0x4054B8508BE271A5),
+	(0xBFFC260A7590C854, -36, 0x3E1949740C1C0768),
+	(0xBFE824EB4D1F7724, 9, 0xBFB4495AD9598DD4),
+	(0xC004B39B4741313E, 10, 0x40CA4B6BD5AE7621),
+	(0x3FFEE9B70BC8EDB7, -21, 0x3EB087D97C8FED04),
+	(0xC003AB24EE28456E, 17, 0xC150B684D7C7AF8B),
+	(0xBFF53F065904670E, 8, 0x402355784AF80792),
+	(0x3FE4DB32E34FF4B2, -15, 0x4083361ADBC19B07),
+	(0xBFF10AF8EAF4AE21, -3, 0xBFEA7A5349830AEB),
+	(0x3FFB16975B5166A8, 26, 0x412AE61BFA8ECF03),
+	(0xC002E84DAFFBED28, -32, 0x3D73962089CB60B4),
+	(0xBFE9A4A04D908676, 24, 0x3F74239FE146967A),
+	(0xC00660E4FCE35B5A, 32, 0x42E675DEB8A28AAE),
+	(0x400600EAD38951E4, 20, 0x41C24B5ED8FF7897),
+	(0xBF92089CBF3A6280, 29, 0xB56010EB54FCFCB6),
+	(0x3FF65FF815C0BDBE, 19, 0x40824830FE56F61A),
+	(0x400332F9A98690EC, 15, 0x411ECAFC70D5AC2A),
+	(0x3FDED0883768CEF8, -23, 0x41730F9F14BDF234),
+	(0x3FBD2F14698CFA30, -37, 0x472E376809AEF117),
+	(0x4006666B04D1DD36, -34, 0x3CC68DE99D7790BD),
+	(0xC002FEB3B1CEE0A6, -28, 0x3DC0C81953D19881),
+	(0x3FFD3A3DD437C42B, 19, 0x40F6E0934BBE8817),
+	(0x4005D3B24A371B32, 8, 0x40A7FD31A886F30E),
+	(0xBFF10E4583BFDA23, 6, 0x3FF779DD68E0D4AF),
+	(0x3FF80BE0A5FD63C4, -8, 0x3FA3ABD08F5486F9),
+	(0xC000C36FFA43A48C, -24, 0x3E54E8EF21DFE397),
+	(0x3FD4B7D093F6F798, 37, 0x3C2BBEA7F1AF128E),
+	(0xBFF0184F6DD3B6D5, -38, 0x3FE98E4ED252BFE8),
+	(0xBFE02F6A35B0E28A, 4, 0x3FB0C0FA6D935715),
+	(0x3FAAE12226D731A0, 36, 0x365EC55E2E6110C6),
+	(0x3FF53E26AF98045B, 25, 0x4092AB110E492405),
+	(0xC006EE6CFB769805, -15, 0xBE8285EE34D7FBB1),
+	(0xBFFB363D1D762FE4, -27, 0xBEA3E269643E5756),
+	(0xBFC3F1ED1F4745E8, -4, 0x409A812EE618B685),
+	(0xBFD95C27A9E07A7C, -8, 0x4099B5406E5C3FFB),
+	(0x3FF5074423BC6CB6, -38, 0x3F00331BF2E3D0DD),
+	(0x3FFD1C216A27FC26, 33, 0x41B6864B1DA1E560),
+	(0x3FFF0D9CDF4D1375, -32, 0x3E04EAB8E1F8424B),
+	(0x3FF5B34FE53DFC91, 10, 0x40350FB1412146D5),
+	(0x40068FE69D3F3742, -8, 0x3F305FCF99A2DEC9),
+	(0xBFF5B470A3756E3B, -20, 0x3F6264A61555E011),
+	(0xBFFE03644B3DA5D6, 36, 0x41F9798673C86521),
+	(0xBFED2BBB92134D2A, -30, 0x4030121AD13D4A44),
+	(0xBFF0367BB53E0FE0, -8, 0x3FECCA374C20857A),
+	(0x4000E9674559EDED, -19, 0x3EA6547190158D74),
+	(0xBFC9B37C93187C70, -40, 0x45B919A7C0AC3E0C),
+	(0x4005B1F203B45DF0, -34, 0x3CE0B97A0EC4715A),
+	(0xBFFC853EAAC74A84, -11, 0xBF5C614BD66E3102),
+	(0xC0023B0EFCF474A1, -12, 0x3F0ABB0F4153F9E5),
+	(0x3FE9DF8459C01B80, -36, 0x40A06E11A6DEAE86),
+	(0x3FE0107FFF92BCCE, 33, 0x3DE24519BBC12928),
+	(0x4006C9F4956D6DF3, 23, 0x421AA5DB61189B8E),
+	(0xC00459BC125BDADB, 3, 0xC0307605EF8CD44D),
+	(0x4004168CB5B57244, -20, 0x3E45A0099122D2AE),
+	(0xBFED44806E5FAE08, -30, 0x402D1A1CA200239D),
+	(0x4001FD0D72E896FC, -32, 0x3D981DC89F782479),
+	(0x3FE2FAE23CED1CD8, -30, 0x415861B406F90A64),
+	(0xC0003EB49F3A3077, -35, 0xBDB2CD66C6D06320),
+	(0x3FFB1F0CE5EF6DE8, 6, 0x4037B8B47544B541),
+	(0x3FF29579F7793B50, -8, 0x3FD3525E0485BBA2),
+	(0xBFDA83A203C5A608, -7, 0xC07DD6E7DBEE1485),
+	(0xBFCD560A85A268B8, 31, 0xBBD14CFA11065DEA),
+	(0x4004B6CCBC5F13C3, -4, 0x3F96C8494068ECA3),
+	(0x40053C0B8CEC20B6, 18, 0x4184652574C44A49),
+	(0xBFF11271F4A95A9F, 4, 0x3FF4BD1FAE142A7D),
+	(0x40061039815995CE, 0, 0x3FF0000000000000),
+	(0x40062B195031642E, 17, 0x417FF0B8C730DB33),
+	(0x3FF1CA8E50D68174, -21, 0x3FBB91D7F03BBB5E),
+	(0x4001C6CE8362B950, -12, 0x3F12170A955F6D0E),
+	(0xBFF27DEF73AF549C, 11, 0xC013A886D4FB2A64),
+];
+pub const COMMONS_RNG_BOUNDED: [(i32, i32, i64, i64); 36] = [
+	(1, 0, 1, 0),
+	(2, 0, 1099511627776, 325333153660),
+	(3, 2, 9223372036854775807, 1482495722653991524),
+	(7, 5, 7, 2),
+	(256, 196, 6148914691236517205, 2374459470193751428),
+	(1000, 615, 3, 0),
+	(1073741825, 675587923, 4611686018427387905, 2089579646759522700),
+	(1431655765, 920978581, 2, 1),
+	(2147483647, 1358216155, 1000000007, 845823045),
+	(1, 0, 1, 0),
+	(2, 1, 1099511627776, 1052389758914),
+	(3, 2, 9223372036854775807, 5962566904789652161),
+	(7, 6, 7, 6),
+	(256, 12, 6148914691236517205, 3218182525051694610),
+	(1000, 654, 3, 1),
+	(1073741825, 303895133, 4611686018427387905, 946463955557114255),
+	(1431655765, 89217536, 2, 0),
+	(2147483647, 1931275294, 1000000007, 241974275),
+	(1, 0, 1, 0),
+	(2, 0, 1099511627776, 599101091154),
+	(3, 2, 9223372036854775807, 4227500844267090687),
+	(7, 3, 7, 4),
+	(256, 116, 6148914691236517205, 597665377582404566),
+	(1000, 943, 3, 2),
+	(1073741825, 299691366, 4611686018427387905, 1026538688282164171),
+	(1431655765, 1253738745, 2, 1),
+	(2147483647, 1714809180, 1000000007, 678303150),
+	(1, 0, 1, 0),
+	(2, 0, 1099511627776, 881006246460),
+	(3, 1, 9223372036854775807, 349097962675846377),
+	(7, 0, 7, 0),
+	(256, 200, 6148914691236517205, 914887194831738164),
+	(1000, 720, 3, 1),
+	(1073741825, 647926021, 4611686018427387905, 962986979820457089),
+	(1431655765, 344726837, 2, 0),
+	(2147483647, 130479075, 1000000007, 39627143),
+];
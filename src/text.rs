@@ -0,0 +1,72 @@
+//! Compact textual form of the generator state, such as `state:0x5deece66d;gauss:none` or
+//! `state:0x2a7f8e1c4b30;gauss:-0.8313453697589337`.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use {Random, MASK};
+
+/// Error returned when parsing the textual form of a `Random` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRandomError(());
+
+impl Display for ParseRandomError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("invalid Random state, expected the form state:0x<48-bit hex>;gauss:<none|f64>")
+	}
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ParseRandomError {}
+
+/// Formats the internal 48-bit state and the pending gaussian, if any. The gaussian is written with the shortest
+/// representation that round-trips, so parsing the output with `FromStr` reconstructs the generator exactly.
+impl Display for Random {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "state:{:#x};gauss:", self.state)?;
+
+		match self.next_gaussian {
+			Some(gaussian) => write!(f, "{:?}", gaussian),
+			None => f.write_str("none")
+		}
+	}
+}
+
+impl FromStr for Random {
+	type Err = ParseRandomError;
+
+	fn from_str(s: &str) -> Result<Self, ParseRandomError> {
+		let error = ParseRandomError(());
+
+		let mut parts = s.split(';');
+
+		let state = parts.next()
+			.and_then(|part| part.strip_prefix("state:0x"))
+			.and_then(|hex| u64::from_str_radix(hex, 16).ok())
+			.filter(|&state| state <= MASK)
+			.ok_or(error.clone())?;
+
+		let next_gaussian = match parts.next().and_then(|part| part.strip_prefix("gauss:")) {
+			Some("none") => None,
+			Some(gaussian) => {
+				let gaussian = gaussian.parse::<f64>().map_err(|_| error.clone())?;
+
+				if !gaussian.is_finite() {
+					return Err(error);
+				}
+
+				Some(gaussian)
+			},
+			None => return Err(error)
+		};
+
+		if parts.next().is_some() {
+			return Err(error);
+		}
+
+		Ok(Random {
+			state,
+			next_gaussian
+		})
+	}
+}
@@ -0,0 +1,144 @@
+//! A lazily seeded generator per thread, like `ThreadLocalRandom` in Java, and free functions drawing from it. The
+//! algorithms are still those of `java.util.Random`, so a thread generator reseeded with `set_seed` produces the same
+//! sequence as `new Random(seed)`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use builder::time_seed;
+use {Checkpoint, Error, Random};
+
+thread_local! {
+	static THREAD_RANDOM: Rc<RefCell<Random>> = Rc::new(RefCell::new(Random::new(time_seed())));
+}
+
+/// Handle to the generator of the current thread, returned by `thread_rng`. It cannot be sent to other threads.
+#[derive(Debug, Clone)]
+pub struct ThreadRandom {
+	random: Rc<RefCell<Random>>
+}
+
+/// Returns a handle to the generator of the current thread. On first use in each thread, the generator is seeded like
+/// `new Random()`, from the clock and a global uniquifier.
+pub fn thread_rng() -> ThreadRandom {
+	ThreadRandom {
+		random: THREAD_RANDOM.with(Rc::clone)
+	}
+}
+
+impl ThreadRandom {
+	/// Calls `f` with exclusive access to the generator.
+	///
+	/// # Panics
+	/// If `f` uses the thread generator itself, through this handle, another handle or the free functions, it is
+	/// already borrowed and the function panics.
+	pub fn with<T, F: FnOnce(&mut Random) -> T>(&self, f: F) -> T {
+		f(&mut self.random.borrow_mut())
+	}
+
+	/// Reseeds the generator of the current thread, equivalent to `Random::set_seed`.
+	pub fn set_seed(&self, seed: u64) {
+		self.with(|random| random.set_seed(seed))
+	}
+
+	/// Captures the state of the thread generator, see `Random::save`.
+	pub fn save(&self) -> Checkpoint {
+		self.with(|random| random.save())
+	}
+
+	/// Restores the thread generator from `checkpoint`, see `Random::restore`.
+	pub fn restore(&self, checkpoint: &Checkpoint) -> Result<(), Error> {
+		self.with(|random| random.restore(checkpoint))
+	}
+
+	/// Equivalent to `Random::next_bytes` on the thread generator.
+	pub fn next_bytes(&self, bytes: &mut [u8]) {
+		self.with(|random| random.next_bytes(bytes))
+	}
+
+	/// Equivalent to `Random::next_i32` on the thread generator.
+	pub fn next_i32(&self) -> i32 {
+		self.with(Random::next_i32)
+	}
+
+	/// Equivalent to `Random::next_i32_bound` on the thread generator.
+	///
+	/// # Panics
+	/// If `max` is less than 1, the function panics.
+	pub fn next_i32_bound(&self, max: i32) -> i32 {
+		self.with(|random| random.next_i32_bound(max))
+	}
+
+	/// Equivalent to `Random::next_i64` on the thread generator.
+	pub fn next_i64(&self) -> i64 {
+		self.with(Random::next_i64)
+	}
+
+	/// Equivalent to `Random::next_bool` on the thread generator.
+	pub fn next_bool(&self) -> bool {
+		self.with(Random::next_bool)
+	}
+
+	/// Equivalent to `Random::next_f32` on the thread generator.
+	pub fn next_f32(&self) -> f32 {
+		self.with(Random::next_f32)
+	}
+
+	/// Equivalent to `Random::next_f64` on the thread generator.
+	pub fn next_f64(&self) -> f64 {
+		self.with(Random::next_f64)
+	}
+
+	/// Equivalent to `Random::next_gaussian` on the thread generator.
+	pub fn next_gaussian(&self) -> f64 {
+		self.with(Random::next_gaussian)
+	}
+}
+
+/// Reseeds the generator of the current thread, see `ThreadRandom::set_seed`.
+pub fn set_seed(seed: u64) {
+	thread_rng().set_seed(seed)
+}
+
+/// Fills `bytes` from the generator of the current thread, see `Random::next_bytes`.
+pub fn next_bytes(bytes: &mut [u8]) {
+	thread_rng().next_bytes(bytes)
+}
+
+/// Returns `next_i32` from the generator of the current thread.
+pub fn next_i32() -> i32 {
+	thread_rng().next_i32()
+}
+
+/// Returns `next_i32_bound(max)` from the generator of the current thread.
+///
+/// # Panics
+/// If `max` is less than 1, the function panics.
+pub fn next_i32_bound(max: i32) -> i32 {
+	thread_rng().next_i32_bound(max)
+}
+
+/// Returns `next_i64` from the generator of the current thread.
+pub fn next_i64() -> i64 {
+	thread_rng().next_i64()
+}
+
+/// Returns `next_bool` from the generator of the current thread.
+pub fn next_bool() -> bool {
+	thread_rng().next_bool()
+}
+
+/// Returns `next_f32` from the generator of the current thread.
+pub fn next_f32() -> f32 {
+	thread_rng().next_f32()
+}
+
+/// Returns `next_f64` from the generator of the current thread.
+pub fn next_f64() -> f64 {
+	thread_rng().next_f64()
+}
+
+/// Returns `next_gaussian` from the generator of the current thread.
+pub fn next_gaussian() -> f64 {
+	thread_rng().next_gaussian()
+}
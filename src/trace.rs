@@ -0,0 +1,84 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+use {NextBits, Random, Subclass};
+
+/// A single call to `next`: the internal state before and after the step, the number of bits requested, and the
+/// value returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step {
+	pub old_state: u64,
+	pub new_state: u64,
+	pub bits: u8,
+	pub output: i32
+}
+
+/// Formats the step as `0x5deece66d -> 0xbb20b4600a74 next(32) = -1155484576`.
+impl Display for Step {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{:#x} -> {:#x} next({}) = {}", self.old_state, self.new_state, self.bits, self.output)
+	}
+}
+
+/// Core passing every step of a borrowed generator to a callback, returned by `Random::traced` inside a `Subclass`.
+pub struct Tracer<'a, F> {
+	random: &'a mut Random,
+	callback: F
+}
+
+impl<'a, F: FnMut(Step)> Tracer<'a, F> {
+	pub fn new(random: &'a mut Random, callback: F) -> Self {
+		Tracer { random, callback }
+	}
+}
+
+impl<'a, F: FnMut(Step)> NextBits for Tracer<'a, F> {
+	fn next(&mut self, bits: u8) -> i32 {
+		let old_state = self.random.state;
+		let output = self.random.next(bits);
+
+		(self.callback)(Step {
+			old_state,
+			new_state: self.random.state,
+			bits,
+			output
+		});
+
+		output
+	}
+
+	fn next_gaussian_slot(&mut self) -> Option<&mut Option<f64>> {
+		Some(&mut self.random.next_gaussian)
+	}
+}
+
+impl<'a, F> Debug for Tracer<'a, F> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("Tracer").field("random", &self.random).finish_non_exhaustive()
+	}
+}
+
+impl Random {
+	/// Returns a view of this generator that calls `callback` with every step taken by the draws made through it,
+	/// for teaching how the generator works or finding the exact call where a port diverges from Java. The view has
+	/// all the methods of `Subclass`, which return the same values as the methods of `Random`.
+	///
+	/// The view shares the pending gaussian of the generator, so gaussians can be drawn through it and directly in
+	/// any order. Only the steps taken by the view are traced: a gaussian returned from the pending value takes none.
+	///
+	/// ```
+	/// use java_rand::Random;
+	///
+	/// let mut random = Random::new(0);
+	/// let mut steps = Vec::new();
+	///
+	/// let value = random.traced(|step| steps.push(step)).next_i32_bound(100);
+	///
+	/// assert_eq!(value, 60);
+	/// assert_eq!(steps.len(), 1);
+	/// assert_eq!(steps[0].bits, 31);
+	/// assert_eq!(steps[0].to_string(), "0x5deece66d -> 0xbb20b4600a74 next(31) = 1569741360");
+	/// ```
+	pub fn traced<F: FnMut(Step)>(&mut self, callback: F) -> Subclass<Tracer<'_, F>> {
+		Subclass::new(Tracer::new(self, callback))
+	}
+}
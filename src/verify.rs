@@ -0,0 +1,150 @@
+//! Parallel confirmation of candidate seeds, the usual second stage of a search after a lattice, sieve or GPU filter
+//! has narrowed the seeds down to candidates that may still contain false positives.
+//!
+//! A `Verifier` pulls the candidates from an iterator in batches, checks each batch on one of its threads, and returns
+//! the confirmed seeds in the order the iterator produced them. With a limit, the threads stop taking new batches as
+//! soon as enough seeds have been confirmed, and the seeds returned are still the first ones in that order.
+//!
+//! Candidates are seeds as passed to `Random::new`. Searches over internal states, such as those of `sweep` and
+//! `crack`, can map a state `s` to the seed `s ^ 0x5DEECE66D` creating it.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::vec::Vec;
+
+use crack::Cracker;
+use Random;
+
+/// Default number of candidates checked per batch
+pub const DEFAULT_BATCH_SIZE: usize = 4096;
+
+/// Checks candidate seeds with a closure on several threads, see the module documentation.
+///
+/// ```
+/// use java_rand::verify::Verifier;
+/// use java_rand::Random;
+///
+/// // Seeds whose first nextInt(100) is 42
+/// let seeds = Verifier::new(|mut random: Random| random.next_i32_bound(100) == 42).limit(3).verify(0..1_000_000);
+///
+/// assert_eq!(seeds.len(), 3);
+/// assert!(seeds.iter().all(|&seed| Random::new(seed).next_i32_bound(100) == 42));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Verifier<F> {
+	check: F,
+	threads: usize,
+	batch_size: usize,
+	limit: Option<usize>
+}
+
+impl<F: Fn(Random) -> bool + Sync> Verifier<F> {
+	/// Creates a verifier confirming the seeds for which `check` returns true when given `Random::new(seed)`, using
+	/// as many threads as the machine can run in parallel.
+	pub fn new(check: F) -> Self {
+		Verifier {
+			check,
+			threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+			batch_size: DEFAULT_BATCH_SIZE,
+			limit: None
+		}
+	}
+
+	/// Sets the number of threads checking batches.
+	///
+	/// # Panics
+	/// If `threads` is 0, the function panics.
+	pub fn threads(mut self, threads: usize) -> Self {
+		if threads == 0 {
+			panic!("At least 1 thread is required")
+		}
+
+		self.threads = threads;
+		self
+	}
+
+	/// Sets the number of candidates a thread takes at once. Larger batches spend less time waiting for the shared
+	/// iterator, and smaller ones stop sooner after the limit is reached.
+	///
+	/// # Panics
+	/// If `batch_size` is 0, the function panics.
+	pub fn batch_size(mut self, batch_size: usize) -> Self {
+		if batch_size == 0 {
+			panic!("Batches must hold at least 1 candidate")
+		}
+
+		self.batch_size = batch_size;
+		self
+	}
+
+	/// Stops once `limit` seeds have been confirmed, returning only the first `limit` of them.
+	pub fn limit(mut self, limit: usize) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	/// Checks the candidates, returning the confirmed seeds in the order of `candidates`.
+	pub fn verify<I: IntoIterator<Item = u64, IntoIter: Send>>(&self, candidates: I) -> Vec<u64> {
+		let limit = self.limit.unwrap_or(usize::MAX);
+
+		if limit == 0 {
+			return Vec::new();
+		}
+
+		// The index of each batch is taken together with its candidates, so that batches are numbered in order
+		let candidates = Mutex::new((candidates.into_iter(), 0));
+		let confirmed = AtomicUsize::new(0);
+
+		let mut batches: Vec<(usize, Vec<u64>)> = thread::scope(|scope| {
+			let workers: Vec<_> = (0..self.threads).map(|_| scope.spawn(|| {
+				let mut results = Vec::new();
+				let mut batch = Vec::with_capacity(self.batch_size);
+
+				while confirmed.load(Ordering::Relaxed) < limit {
+					let index = {
+						let mut candidates = candidates.lock().unwrap();
+						let (ref mut iter, ref mut next_index) = *candidates;
+
+						batch.clear();
+						batch.extend(iter.by_ref().take(self.batch_size));
+
+						*next_index += 1;
+						*next_index - 1
+					};
+
+					if batch.is_empty() {
+						break;
+					}
+
+					let seeds: Vec<u64> = batch.iter()
+						.cloned()
+						.filter(|&seed| (self.check)(Random::new(seed)))
+						.collect();
+
+					confirmed.fetch_add(seeds.len(), Ordering::Relaxed);
+					results.push((index, seeds));
+				}
+
+				results
+			})).collect();
+
+			workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+		});
+
+		// Every batch before the last one taken was completed, so the first seeds in order are all present
+		batches.sort_unstable_by_key(|&(index, _)| index);
+
+		let mut seeds: Vec<u64> = batches.into_iter().flat_map(|(_, seeds)| seeds).collect();
+		seeds.truncate(limit);
+
+		seeds
+	}
+}
+
+/// Creates a verifier confirming the seeds whose generators produce the observations recorded on `cracker`, checked
+/// as by `Cracker::matches`. See `Verifier::new` for the defaults.
+pub fn matching(cracker: &Cracker) -> Verifier<impl Fn(Random) -> bool + Sync + '_> {
+	Verifier::new(move |random: Random| cracker.matches(&random))
+}
@@ -0,0 +1,209 @@
+//! The xoroshiro128 family of generators by Blackman and Vigna, with the `+`, `++` and `**` scramblers.
+//!
+//! Seeding from a single `u64` follows the JDK implementation of `Xoroshiro128PlusPlus`: the seed is expanded to 128
+//! bits with the Stafford variant 13 mixer, so `Xoroshiro128PlusPlus::new(seed)` produces the same sequence as
+//! `RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(seed)`, and the other scramblers are seeded the same
+//! way. The derived draws use the default methods of `java.util.random.RandomGenerator`, which differ from those of
+//! `Random`: for example, `next_i32` takes the high half of `next_i64`, and `next_gaussian` uses the ziggurat method.
+
+use {float, ziggurat};
+use {Algorithm, Checkpoint, Error};
+use mix::{mix_stafford13, GOLDEN_RATIO_64, SILVER_RATIO_64};
+
+macro_rules! xoroshiro128 {
+	(
+		$(#[$attr:meta])*
+		$name:ident, $algorithm:ident, rotations: ($a:expr, $b:expr, $c:expr), output: |$s0:ident, $s1:ident| $output:expr
+	) => {
+		$(#[$attr])*
+		#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+		pub struct $name {
+			x0: u64,
+			x1: u64
+		}
+
+		impl $name {
+			/// Creates a generator seeded like the JDK, by expanding `seed` to 128 bits.
+			#[inline]
+			pub const fn new(seed: u64) -> Self {
+				let seed = seed ^ SILVER_RATIO_64;
+
+				$name::from_state(mix_stafford13(seed), mix_stafford13(seed.wrapping_add(GOLDEN_RATIO_64)))
+			}
+
+			/// Creates a generator with the raw 128-bit state `(x0, x1)`. The all-zero state, from which the generator
+			/// would only ever output zeroes, is replaced with the same nonzero state as in the JDK.
+			#[inline]
+			pub const fn from_state(x0: u64, x1: u64) -> Self {
+				if x0 == 0 && x1 == 0 {
+					$name { x0: GOLDEN_RATIO_64, x1: SILVER_RATIO_64 }
+				} else {
+					$name { x0, x1 }
+				}
+			}
+
+			/// Returns the raw 128-bit state `(x0, x1)`.
+			#[inline]
+			pub const fn state(&self) -> (u64, u64) {
+				(self.x0, self.x1)
+			}
+
+			/// Returns a uniformly distributed unsigned 64-bit integer, stepping the generator once.
+			#[inline]
+			pub const fn next_u64(&mut self) -> u64 {
+				let $s0 = self.x0;
+				let $s1 = self.x1;
+				let result = $output;
+
+				let s1 = $s1 ^ $s0;
+
+				self.x0 = $s0.rotate_left($a) ^ s1 ^ (s1 << $b);
+				self.x1 = s1.rotate_left($c);
+
+				result
+			}
+
+			/// Steps the generator backwards, undoing one call to `next_u64`, and returns the value that call returned,
+			/// which the next call to `next_u64` returns again. Every step of the generator is an invertible linear
+			/// map of the state, so this works from any state.
+			#[inline]
+			pub const fn previous(&mut self) -> u64 {
+				let s1 = self.x1.rotate_right($c);
+				let $s0 = (self.x0 ^ s1 ^ (s1 << $b)).rotate_right($a);
+				let $s1 = s1 ^ $s0;
+
+				self.x0 = $s0;
+				self.x1 = $s1;
+
+				$output
+			}
+
+			/// Returns a uniformly distributed signed 64-bit integer, equivalent to `nextLong()`.
+			#[inline]
+			pub const fn next_i64(&mut self) -> i64 {
+				self.next_u64() as i64
+			}
+
+			/// Returns a uniformly distributed unsigned 32-bit integer, the high half of `next_u64`.
+			#[inline]
+			pub const fn next_u32(&mut self) -> u32 {
+				(self.next_u64() >> 32) as u32
+			}
+
+			/// Returns a uniformly distributed signed 32-bit integer, equivalent to `nextInt()`.
+			#[inline]
+			pub const fn next_i32(&mut self) -> i32 {
+				self.next_u32() as i32
+			}
+
+			/// Returns a random number in the range [0, max), equivalent to `nextInt(bound)`.
+			///
+			/// # Panics
+			/// If `max` is less than 1, the function panics.
+			#[inline]
+			pub const fn next_i32_bound(&mut self, max: i32) -> i32 {
+				match self.try_next_i32_bound(max) {
+					Ok(value) => value,
+					Err(_) => panic!("Maximum must be > 0")
+				}
+			}
+
+			/// Like `next_i32_bound`, but returns `Error::InvalidBound` instead of panicking, without stepping the
+			/// generator.
+			#[inline]
+			pub const fn try_next_i32_bound(&mut self, max: i32) -> Result<i32, Error> {
+				if max <= 0 {
+					return Err(Error::InvalidBound);
+				}
+
+				Ok(generator_next_bound!(self, max, next_i32, i32, u32))
+			}
+
+			/// Returns a boolean value that has an equal chance of being true or false, the sign of `next_i32`.
+			#[inline]
+			pub const fn next_bool(&mut self) -> bool {
+				self.next_i32() < 0
+			}
+
+			/// Returns a f32 uniformly distributed between 0.0 and 1.0, from the high 24 bits of `next_u32`.
+			#[inline]
+			pub const fn next_f32(&mut self) -> f32 {
+				float::unit_f32(self.next_u32() >> 8)
+			}
+
+			/// Returns a f64 uniformly distributed between 0.0 and 1.0, from the high 53 bits of `next_u64`.
+			#[inline]
+			pub const fn next_f64(&mut self) -> f64 {
+				float::unit_f64(self.next_u64() >> 11)
+			}
+
+			/// Fills the byte array with random bytes, equivalent to `nextBytes(bytes)`. Each `next_u64` fills 8 bytes
+			/// low byte first, and a final partial block takes the low bytes of one more value, unlike the 4 byte
+			/// blocks of `Random::next_bytes`.
+			pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+				for chunk in bytes.chunks_mut(8) {
+					chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+				}
+			}
+
+			/// Returns a gaussian-distributed number with a mean of 0.0 and standard deviation of 1.0, equivalent to
+			/// `nextGaussian()`.
+			pub fn next_gaussian(&mut self) -> f64 {
+				ziggurat::next_gaussian(|| self.next_i64())
+			}
+
+			/// Returns an exponentially distributed number with a mean of 1.0, equivalent to `nextExponential()`.
+			pub fn next_exponential(&mut self) -> f64 {
+				ziggurat::next_exponential(|| self.next_i64())
+			}
+
+			/// Captures the state of the generator.
+			pub fn save(&self) -> Checkpoint {
+				Checkpoint::new(Algorithm::$algorithm, [self.x0, self.x1], None).expect("State is never all zero")
+			}
+
+			/// Returns the generator to the state captured in `checkpoint`, see `Random::restore`.
+			pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+				if checkpoint.algorithm() != Algorithm::$algorithm {
+					return Err(Error::AlgorithmMismatch);
+				}
+
+				let [x0, x1] = checkpoint.state();
+				*self = $name::from_state(x0, x1);
+
+				Ok(())
+			}
+
+			/// Encodes the state in the versioned binary form, see `Checkpoint::to_versioned_bytes`.
+			pub fn to_bytes(&self) -> [u8; 27] {
+				self.save().to_versioned_bytes()
+			}
+
+			/// Decodes a generator encoded by `to_bytes`, see `Random::from_bytes`.
+			pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+				let mut random = $name::from_state(0, 0);
+				random.restore(&Checkpoint::from_versioned_bytes(bytes)?)?;
+
+				Ok(random)
+			}
+		}
+	}
+}
+
+xoroshiro128! {
+	/// xoroshiro128+, the fastest of the family. The lowest bits of its output are of low linear complexity, which
+	/// the floating point draws avoid by using the high bits.
+	Xoroshiro128Plus, Xoroshiro128Plus, rotations: (24, 16, 37), output: |s0, s1| s0.wrapping_add(s1)
+}
+
+xoroshiro128! {
+	/// xoroshiro128++, the `Xoroshiro128PlusPlus` generator of the JDK and the one used by Minecraft since 1.18.
+	Xoroshiro128PlusPlus, Xoroshiro128PlusPlus, rotations: (49, 21, 28),
+	output: |s0, s1| s0.wrapping_add(s1).rotate_left(17).wrapping_add(s0)
+}
+
+xoroshiro128! {
+	/// xoroshiro128**, with a multiplicative scrambler that makes all output bits of high quality.
+	Xoroshiro128StarStar, Xoroshiro128StarStar, rotations: (24, 16, 37),
+	output: |s0, s1| s0.wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+}